@@ -2,7 +2,12 @@
 //!
 //! This module defines the command-line interface for the td CLI.
 
-use clap::{Parser, Subcommand, ValueEnum};
+use std::path::PathBuf;
+
+use clap::parser::ValueSource;
+use clap::{CommandFactory, FromArgMatches, Parser, Subcommand, ValueEnum};
+
+use crate::output::{OutputFormat, TaskField};
 
 /// td - A Rust CLI for the Todoist API
 #[derive(Parser, Debug)]
@@ -18,11 +23,31 @@ pub struct Cli {
     #[arg(short, long, global = true, conflicts_with = "verbose")]
     pub quiet: bool,
 
-    /// Force JSON output (auto-detected when not a TTY)
-    #[arg(long, global = true)]
+    /// Force JSON output (shortcut for --format json)
+    #[arg(long, global = true, conflicts_with = "format")]
     pub json: bool,
 
-    /// Disable colors in output
+    /// Force table output, overriding a configured default (shortcut for --format table)
+    #[arg(long, global = true, conflicts_with_all = ["json", "format"])]
+    pub table: bool,
+
+    /// Output format (default: config output.format, then table)
+    #[arg(long, global = true, value_enum, value_name = "FORMAT")]
+    pub format: Option<OutputFormat>,
+
+    /// Print JSON on a single line without extra whitespace (default: pretty-printed)
+    #[arg(long, global = true)]
+    pub compact: bool,
+
+    /// Config profile: layer <config dir>/<PROFILE>/config.toml over the base config
+    #[arg(long, global = true, env = "TD_PROFILE", value_name = "PROFILE")]
+    pub profile: Option<String>,
+
+    /// When to color output: auto (terminal without NO_COLOR), always, or never; give it before the subcommand
+    #[arg(long, value_enum, value_name = "WHEN", default_value_t = ColorChoice::Auto)]
+    pub color: ColorChoice,
+
+    /// Disable colors in output (deprecated: use --color never)
     #[arg(long, global = true)]
     pub no_color: bool,
 
@@ -30,14 +55,55 @@ pub struct Cli {
     #[arg(long, global = true, env = "TODOIST_TOKEN", hide_env_values = true)]
     pub token: Option<String>,
 
+    /// Whether `token` was read from `TODOIST_TOKEN` rather than given as `--token`
+    #[arg(skip)]
+    pub token_from_env: bool,
+
     /// Sync with Todoist before executing the command
     #[arg(long, global = true)]
     pub sync: bool,
 
+    /// Show absolute dates (YYYY-MM-DD, plus HH:MM when timed) instead of relative ones
+    #[arg(long, global = true)]
+    pub plain_dates: bool,
+
+    /// Show complete IDs instead of the shortest unique prefix
+    #[arg(long, global = true)]
+    pub full_ids: bool,
+
+    /// Print long table output directly instead of through $PAGER
+    #[arg(long, global = true)]
+    pub no_pager: bool,
+
+    /// Request timeout in seconds (default: 30)
+    #[arg(long, global = true, value_name = "SECONDS", value_parser = clap::value_parser!(u64).range(1..))]
+    pub timeout: Option<u64>,
+
+    /// Retry a change once (after a sync) if another client invalidated the sync token mid-flight
+    #[arg(long, global = true)]
+    pub retry_on_conflict: bool,
+
+    /// Load environment variables (e.g., TODOIST_TOKEN) from a KEY=VALUE file; the real environment takes precedence
+    #[arg(long, global = true, value_name = "PATH")]
+    pub env_file: Option<PathBuf>,
+
     #[command(subcommand)]
     pub command: Option<Commands>,
 }
 
+impl Cli {
+    /// Parses the process arguments, exiting with usage on error.
+    ///
+    /// Unlike [`Parser::parse`], this records whether the token came from
+    /// `TODOIST_TOKEN`, so `td setup` can avoid persisting it.
+    pub fn parse_args() -> Self {
+        let matches = Self::command().get_matches();
+        let mut cli = Self::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+        cli.token_from_env = matches.value_source("token") == Some(ValueSource::EnvVariable);
+        cli
+    }
+}
+
 /// Available commands
 #[derive(Subcommand, Debug)]
 pub enum Commands {
@@ -48,13 +114,21 @@ pub enum Commands {
         #[arg(short, long)]
         filter: Option<String>,
 
+        /// Error if the filter names an unknown project, section, or label
+        #[arg(long, requires = "filter")]
+        strict_filter: bool,
+
         /// Filter by project name or ID
         #[arg(short, long)]
         project: Option<String>,
 
-        /// Filter by label
-        #[arg(short, long)]
-        label: Option<String>,
+        /// Filter by label (repeatable; see --label-match)
+        #[arg(short, long, action = clap::ArgAction::Append)]
+        label: Vec<String>,
+
+        /// With several --label values, require all of them or any one
+        #[arg(long, value_enum, default_value_t = LabelMatch::All)]
+        label_match: LabelMatch,
 
         /// Filter by priority (1=highest, 4=lowest)
         #[arg(short = 'P', long, value_parser = clap::value_parser!(u8).range(1..=4))]
@@ -72,10 +146,35 @@ pub enum Commands {
         #[arg(long)]
         no_due: bool,
 
+        /// Show only tasks due on a date or in a range: today, tomorrow,
+        /// 2025-02-01, or 2025-02-01..2025-02-07 (either end may be omitted)
+        #[arg(long, value_name = "DATE|RANGE", conflicts_with = "no_due")]
+        due: Option<String>,
+
         /// Filter by assignee (name, email, "me", or "others")
         #[arg(long, value_name = "USER")]
         assigned_to: Option<String>,
 
+        /// Show only tasks updated within a window (e.g., 30m, 2h, 3d, 1w) or since a date/time
+        #[arg(long, value_name = "WHEN")]
+        changed_since: Option<String>,
+
+        /// Show subtasks as independent rows instead of nested under their parent
+        #[arg(long)]
+        flat: bool,
+
+        /// Omit the table header row (useful when piping)
+        #[arg(long)]
+        no_header: bool,
+
+        /// Print only full task IDs, one per line (for piping into xargs)
+        #[arg(long, alias = "output-ids-only", conflicts_with_all = ["fields", "no_header"])]
+        ids: bool,
+
+        /// Extra table columns to show (comma-separated: assignee, age)
+        #[arg(long, value_enum, value_delimiter = ',', value_name = "FIELDS")]
+        fields: Vec<TaskField>,
+
         /// Limit results (default: 50)
         #[arg(long, default_value = "50")]
         limit: u32,
@@ -95,6 +194,14 @@ pub enum Commands {
         /// Reverse sort order
         #[arg(long)]
         reverse: bool,
+
+        /// With --sort due, list tasks without a due date first
+        #[arg(long, conflicts_with = "no_due_last")]
+        no_due_first: bool,
+
+        /// With --sort due, list tasks without a due date last (default)
+        #[arg(long)]
+        no_due_last: bool,
     },
 
     /// Add a new task
@@ -115,31 +222,71 @@ pub enum Commands {
         #[arg(short, long)]
         due: Option<String>,
 
+        /// Language of the due date (e.g., "es"; default: config due_lang, then your Todoist language)
+        #[arg(long, value_name = "CODE", requires = "due")]
+        lang: Option<String>,
+
+        /// Timezone for a timed due date (e.g., "America/Los_Angeles")
+        #[arg(long, value_name = "TZ", requires = "due")]
+        due_tz: Option<String>,
+
         /// Add label (repeatable)
         #[arg(short, long, action = clap::ArgAction::Append)]
         label: Vec<String>,
 
+        /// Create labels that don't exist yet (default: warn and attach the name anyway)
+        #[arg(long)]
+        create_labels: bool,
+
         /// Target section within project
         #[arg(long)]
         section: Option<String>,
 
-        /// Parent task ID (creates subtask)
+        /// Parent task ID or ID prefix (creates subtask)
         #[arg(long)]
         parent: Option<String>,
 
-        /// Task description/notes
+        /// Task description/notes ("-" reads from stdin)
         #[arg(long)]
         description: Option<String>,
 
+        /// Read the task description from a file
+        #[arg(long, value_name = "PATH", conflicts_with = "description")]
+        description_file: Option<PathBuf>,
+
         /// Assign task to user
         #[arg(long, value_name = "USER")]
         assign: Option<String>,
+
+        /// Add a reminder: a time ("09:00"), a date/time ("2025-01-15 09:00"), or an offset ("30m before")
+        #[arg(long, value_name = "WHEN")]
+        remind: Option<String>,
+
+        /// Add a reminder at the default_reminder_offset config before the due time
+        #[arg(long, conflicts_with = "remind")]
+        remind_default: bool,
+
+        /// Place the task first among its siblings
+        #[arg(long, conflicts_with = "at_bottom")]
+        at_top: bool,
+
+        /// Place the task last among its siblings
+        #[arg(long)]
+        at_bottom: bool,
+
+        /// Read @labels, #project, and p1-p4 from the content (explicit flags win)
+        #[arg(long)]
+        parse: bool,
+
+        /// Show the resolved task (project, section, labels, due) without creating it
+        #[arg(long)]
+        dry_run: bool,
     },
 
     /// Show task details
     #[command(alias = "s")]
     Show {
-        /// Task ID
+        /// Task ID, ID prefix, or content text
         task_id: String,
 
         /// Include comments
@@ -149,12 +296,34 @@ pub enum Commands {
         /// Include reminders
         #[arg(long)]
         reminders: bool,
+
+        /// Include comments, reminders, and the full subtask tree
+        #[arg(long)]
+        full: bool,
+
+        /// Print the cached task exactly as stored, as JSON (all fields)
+        #[arg(long)]
+        raw: bool,
+
+        /// Show a timeline of creation, last update, completion, and comments
+        #[arg(long)]
+        history: bool,
+    },
+
+    /// Open a task in the Todoist web app
+    Open {
+        /// Task ID, ID prefix, or content text
+        task_id: String,
+
+        /// Print the URL instead of launching a browser
+        #[arg(long)]
+        print: bool,
     },
 
     /// Edit a task
     #[command(alias = "e")]
     Edit {
-        /// Task ID
+        /// Task ID, ID prefix, or content text
         task_id: String,
 
         /// Update content
@@ -169,18 +338,38 @@ pub enum Commands {
         #[arg(short = 'P', long, value_parser = clap::value_parser!(u8).range(1..=4))]
         priority: Option<u8>,
 
-        /// Change due date
+        /// Change due date, or shift it with +Nd, +Nw or +Nmo (from today if undated)
         #[arg(short, long)]
         due: Option<String>,
 
+        /// Language of the due date (e.g., "es"; default: config due_lang, then your Todoist language)
+        #[arg(long, value_name = "CODE", requires = "due")]
+        lang: Option<String>,
+
+        /// Timezone for a timed due date (e.g., "America/Los_Angeles")
+        #[arg(long, value_name = "TZ", requires = "due")]
+        due_tz: Option<String>,
+
         /// Remove due date
         #[arg(long)]
         no_due: bool,
 
+        /// Make the task repeat (e.g., "every weekday"); Todoist parses the pattern
+        #[arg(long, value_name = "PATTERN", conflicts_with_all = ["due", "no_due"])]
+        recurring: Option<String>,
+
+        /// Stop the task repeating, keeping its next due date as a fixed date
+        #[arg(long, conflicts_with_all = ["due", "no_due", "recurring"])]
+        no_recurring: bool,
+
         /// Set labels (replaces existing)
         #[arg(short, long, action = clap::ArgAction::Append)]
         label: Vec<String>,
 
+        /// Set labels from a comma- or newline-separated list ("-" reads from stdin; empty clears them)
+        #[arg(long, value_name = "LIST", conflicts_with_all = ["label", "add_label", "remove_label"])]
+        labels: Option<String>,
+
         /// Add label
         #[arg(long)]
         add_label: Option<String>,
@@ -189,14 +378,30 @@ pub enum Commands {
         #[arg(long)]
         remove_label: Option<String>,
 
+        /// Create labels that don't exist yet (default: warn and attach the name anyway)
+        #[arg(long)]
+        create_labels: bool,
+
         /// Move to section
         #[arg(long)]
         section: Option<String>,
 
-        /// Update description
+        /// Update description ("-" reads from stdin)
         #[arg(long)]
         description: Option<String>,
 
+        /// Read the new description from a file
+        #[arg(long, value_name = "PATH", conflicts_with = "description")]
+        description_file: Option<PathBuf>,
+
+        /// Add text to the end of the current description
+        #[arg(long, value_name = "TEXT", conflicts_with_all = ["description", "description_file"])]
+        append_description: Option<String>,
+
+        /// Add text to the start of the current description
+        #[arg(long, value_name = "TEXT", conflicts_with_all = ["description", "description_file"])]
+        prepend_description: Option<String>,
+
         /// Assign task to user
         #[arg(long, value_name = "USER", conflicts_with = "unassign")]
         assign: Option<String>,
@@ -204,12 +409,24 @@ pub enum Commands {
         /// Remove task assignment
         #[arg(long, conflicts_with = "assign")]
         unassign: bool,
+
+        /// Move under a parent task (ID, prefix, or content)
+        #[arg(
+            long,
+            alias = "move-to-parent",
+            conflicts_with_all = ["no_parent", "project", "section"]
+        )]
+        parent: Option<String>,
+
+        /// Promote to a top-level task
+        #[arg(long, conflicts_with_all = ["project", "section"])]
+        no_parent: bool,
     },
 
     /// Complete task(s)
     #[command(alias = "d")]
     Done {
-        /// Task ID(s)
+        /// Task ID(s), ID prefix(es), or content text
         #[arg(required = true)]
         task_ids: Vec<String>,
 
@@ -217,17 +434,29 @@ pub enum Commands {
         #[arg(long)]
         all_occurrences: bool,
 
-        /// Skip confirmation for multiple tasks
+        /// Skip confirmation for multiple tasks and the open-subtask warning
         #[arg(short, long)]
         force: bool,
+
+        /// Also complete all open subtasks explicitly, in the same batch
+        #[arg(long)]
+        subtasks: bool,
     },
 
     /// Reopen completed task(s)
     Reopen {
-        /// Task ID(s)
-        #[arg(required = true)]
+        /// Task ID(s), ID prefix(es), or content text
+        #[arg(required_unless_present = "filter", conflicts_with = "filter")]
         task_ids: Vec<String>,
 
+        /// Reopen every completed task in the cache matching a filter expression
+        #[arg(long, value_name = "QUERY")]
+        filter: Option<String>,
+
+        /// Allow a --filter run to match up to N tasks (default: config bulk.max_results, or 50)
+        #[arg(long, value_name = "N", conflicts_with = "task_ids")]
+        max_results: Option<usize>,
+
         /// Skip confirmation for multiple tasks
         #[arg(short, long)]
         force: bool,
@@ -236,13 +465,25 @@ pub enum Commands {
     /// Delete task(s)
     #[command(alias = "rm")]
     Delete {
-        /// Task ID(s)
-        #[arg(required = true)]
+        /// Task ID(s), ID prefix(es), or content text
+        #[arg(required_unless_present = "filter", conflicts_with = "filter")]
         task_ids: Vec<String>,
 
+        /// Delete every task matching a filter expression (requires typed confirmation)
+        #[arg(long, value_name = "QUERY")]
+        filter: Option<String>,
+
+        /// Allow a --filter run to match up to N tasks (default: config bulk.max_results, or 50)
+        #[arg(long, value_name = "N", conflicts_with = "task_ids")]
+        max_results: Option<usize>,
+
         /// Skip confirmation prompt
         #[arg(short, long)]
         force: bool,
+
+        /// Move subtasks up a level instead of deleting them with their parent
+        #[arg(long)]
+        keep_subtasks: bool,
     },
 
     /// Show today's agenda
@@ -255,6 +496,43 @@ pub enum Commands {
         /// Include tasks due within N days
         #[arg(long)]
         include_upcoming: Option<u32>,
+
+        /// Print task count and total estimated duration
+        #[arg(long)]
+        summary: bool,
+    },
+
+    /// Search open tasks by title and description
+    Search {
+        /// Text to search for (case-insensitive)
+        query: String,
+
+        /// Sort results (default: relevance)
+        #[arg(long, value_enum, default_value_t = SearchSort::Relevance)]
+        sort: SearchSort,
+
+        /// Limit results (default: 50)
+        #[arg(long, default_value = "50")]
+        limit: u32,
+
+        /// Show all matches (no limit)
+        #[arg(long)]
+        all: bool,
+    },
+
+    /// List completed tasks still in the local cache, newest first
+    Completed {
+        /// Only tasks in this project (name or ID)
+        #[arg(short, long)]
+        project: Option<String>,
+
+        /// Limit results (default: 50)
+        #[arg(long, default_value = "50")]
+        limit: u32,
+
+        /// Show all completed tasks (no limit)
+        #[arg(long)]
+        all: bool,
     },
 
     /// Quick add with natural language
@@ -267,9 +545,28 @@ pub enum Commands {
         #[arg(long)]
         auto_reminder: bool,
 
-        /// Add a note/comment to the created task
+        /// Add a note/comment to the created task (sent in the same request)
         #[arg(long)]
         note: Option<String>,
+
+        /// Upload a file and attach it to the created task as a comment
+        #[arg(long, value_name = "PATH")]
+        file: Option<PathBuf>,
+    },
+
+    /// Show task statistics
+    Stats {
+        /// Also fetch completion history from the server
+        #[arg(long)]
+        server: bool,
+
+        /// Break active task counts down by project
+        #[arg(long)]
+        by_project: bool,
+
+        /// Break active task counts down by label
+        #[arg(long)]
+        by_label: bool,
     },
 
     /// Sync local cache with Todoist
@@ -277,6 +574,39 @@ pub enum Commands {
         /// Force full sync (ignore cache)
         #[arg(long)]
         full: bool,
+
+        /// Only fetch these resource types (comma-separated, e.g. items,labels)
+        #[arg(long, conflicts_with = "full")]
+        resources: Option<String>,
+
+        /// Remove archived projects and sections from the cache after syncing
+        #[arg(long)]
+        prune_archived: bool,
+
+        /// Incremental sync from this sync token instead of the cached one (debugging)
+        #[arg(
+            long,
+            alias = "since-token",
+            value_name = "TOKEN",
+            conflicts_with_all = ["full", "resources"]
+        )]
+        from_token: Option<String>,
+
+        /// Write the sync response body, as received, to this file before applying it (debugging)
+        #[arg(long, value_name = "FILE", conflicts_with = "resources")]
+        dump_response: Option<PathBuf>,
+
+        /// Skip the sync and print nothing when the cache is still fresh (for cron jobs)
+        #[arg(long, conflicts_with_all = ["full", "resources", "from_token", "dump_response"])]
+        quiet_if_fresh: bool,
+
+        /// With --full: discard the local cache first and rebuild it from scratch
+        #[arg(long, requires = "full", conflicts_with = "dump_response")]
+        reset: bool,
+
+        /// Skip the --reset confirmation prompt
+        #[arg(long, requires = "reset")]
+        force: bool,
     },
 
     /// List and manage projects
@@ -313,6 +643,18 @@ pub enum Commands {
         #[arg(long)]
         project: Option<String>,
 
+        /// List newest comments first (default)
+        #[arg(long, conflicts_with = "oldest_first")]
+        newest_first: bool,
+
+        /// List oldest comments first
+        #[arg(long)]
+        oldest_first: bool,
+
+        /// Print complete comment bodies instead of the first line
+        #[arg(long)]
+        full: bool,
+
         #[command(subcommand)]
         command: Option<CommentsCommands>,
     },
@@ -334,6 +676,12 @@ pub enum Commands {
         command: Option<FiltersCommands>,
     },
 
+    /// Inspect and clean up the local cache
+    Cache {
+        #[command(subcommand)]
+        command: CacheCommands,
+    },
+
     /// View and edit configuration
     Config {
         #[command(subcommand)]
@@ -347,11 +695,36 @@ pub enum Commands {
         project: String,
     },
 
+    /// Configure the API token (interactive, or non-interactive with --token)
+    Setup {
+        /// Where to store the token when --token is given
+        #[arg(long, value_enum, default_value_t = SetupStorage::Config)]
+        storage: SetupStorage,
+    },
+
     /// Generate shell completions
     Completions {
         /// Shell to generate completions for
         shell: Shell,
     },
+
+    /// Show version and supported features (use --json for scripts)
+    Info,
+
+    /// Print cached names, one per line, for dynamic shell completion scripts
+    #[command(name = "__complete", hide = true)]
+    Complete {
+        /// Which names to print
+        resource: CompleteResource,
+    },
+}
+
+/// Resources whose cached names `td __complete` prints
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CompleteResource {
+    Projects,
+    Labels,
+    Sections,
 }
 
 /// Sort fields for list command
@@ -363,6 +736,58 @@ pub enum SortField {
     Project,
 }
 
+/// When to emit ANSI colors
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ColorChoice {
+    /// Color when stdout is a terminal and NO_COLOR is not set
+    #[default]
+    Auto,
+    /// Always color, even when piped
+    Always,
+    /// Never color
+    Never,
+}
+
+/// How multiple `--label` values combine for list
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LabelMatch {
+    /// Tasks must have every listed label
+    #[default]
+    All,
+    /// Tasks must have at least one listed label
+    Any,
+}
+
+/// Sort orders for search results
+#[derive(ValueEnum, Clone, Debug, PartialEq, Eq)]
+pub enum SearchSort {
+    /// Title word matches first, then title substrings, then descriptions
+    Relevance,
+    Due,
+    Priority,
+}
+
+/// Sort orders for labels list
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LabelSort {
+    /// Alphabetical by name
+    Name,
+    /// Most used by open tasks first
+    Usage,
+    /// Todoist label order (item_order)
+    #[default]
+    Order,
+}
+
+/// Token storage backends for setup
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SetupStorage {
+    /// OS keyring
+    Keyring,
+    /// Config file
+    Config,
+}
+
 /// Shell types for completions
 #[derive(ValueEnum, Clone, Debug)]
 pub enum Shell {
@@ -381,6 +806,10 @@ pub enum ProjectsCommands {
         #[arg(long)]
         tree: bool,
 
+        /// Render only N levels of the tree (1 = root projects only)
+        #[arg(long, value_name = "N", requires = "tree", value_parser = clap::value_parser!(u16).range(1..))]
+        depth: Option<u16>,
+
         /// Include archived projects
         #[arg(long)]
         archived: bool,
@@ -388,6 +817,10 @@ pub enum ProjectsCommands {
         /// Limit results
         #[arg(long)]
         limit: Option<u32>,
+
+        /// Count completed tasks too (deleted tasks are never counted)
+        #[arg(long)]
+        include_completed: bool,
     },
 
     /// Create a new project
@@ -406,6 +839,10 @@ pub enum ProjectsCommands {
         /// Mark as favorite
         #[arg(long)]
         favorite: bool,
+
+        /// Also create the sections and tasks defined in this JSON template
+        #[arg(long, value_name = "PATH")]
+        from_template: Option<PathBuf>,
     },
 
     /// Show project details
@@ -420,6 +857,18 @@ pub enum ProjectsCommands {
         /// List tasks in this project
         #[arg(long)]
         tasks: bool,
+
+        /// List only the project's tasks matching this filter (implies --tasks)
+        #[arg(short, long)]
+        filter: Option<String>,
+
+        /// Count completed tasks too (deleted tasks are never counted)
+        #[arg(long)]
+        include_completed: bool,
+
+        /// List recently completed tasks from the local cache
+        #[arg(long)]
+        completed: bool,
     },
 
     /// Edit a project
@@ -442,6 +891,28 @@ pub enum ProjectsCommands {
         /// View style (list, board)
         #[arg(long)]
         view_style: Option<String>,
+
+        /// Move under a parent project (name or ID)
+        #[arg(long, conflicts_with = "no_parent")]
+        parent: Option<String>,
+
+        /// Promote to a top-level project
+        #[arg(long)]
+        no_parent: bool,
+    },
+
+    /// Toggle a project's favorite status (or set it with --on/--off)
+    Favorite {
+        /// Project ID
+        project_id: String,
+
+        /// Mark as favorite
+        #[arg(long, conflicts_with = "off")]
+        on: bool,
+
+        /// Remove from favorites
+        #[arg(long)]
+        off: bool,
     },
 
     /// Archive a project
@@ -475,7 +946,11 @@ pub enum ProjectsCommands {
 #[derive(Subcommand, Debug)]
 pub enum LabelsCommands {
     /// List all labels (default)
-    List,
+    List {
+        /// Sort order
+        #[arg(long, value_enum, default_value_t = LabelSort::Order)]
+        sort: LabelSort,
+    },
 
     /// Create a new label
     Add {
@@ -489,6 +964,14 @@ pub enum LabelsCommands {
         /// Mark as favorite
         #[arg(long)]
         favorite: bool,
+
+        /// Create even if a label with the same name exists
+        #[arg(short, long)]
+        force: bool,
+
+        /// Also add the label to these tasks (reuses an existing label of the same name)
+        #[arg(long, num_args = 1.., value_name = "TASK")]
+        to_tasks: Vec<String>,
     },
 
     /// Edit a label
@@ -507,6 +990,24 @@ pub enum LabelsCommands {
         /// Toggle favorite
         #[arg(long)]
         favorite: Option<bool>,
+
+        /// Rename even if another label already has the new name
+        #[arg(short, long)]
+        force: bool,
+    },
+
+    /// Toggle a label's favorite status (or set it with --on/--off)
+    Favorite {
+        /// Label ID
+        label_id: String,
+
+        /// Mark as favorite
+        #[arg(long, conflicts_with = "off")]
+        on: bool,
+
+        /// Remove from favorites
+        #[arg(long)]
+        off: bool,
     },
 
     /// Delete a label
@@ -517,6 +1018,14 @@ pub enum LabelsCommands {
         /// Skip confirmation
         #[arg(short, long)]
         force: bool,
+
+        /// Relabel the label's tasks with this existing label before deleting
+        #[arg(long, value_name = "LABEL", conflicts_with = "remove")]
+        reassign: Option<String>,
+
+        /// Strip the label from its tasks before deleting
+        #[arg(long)]
+        remove: bool,
     },
 }
 
@@ -534,6 +1043,14 @@ pub enum SectionsCommands {
         /// Project for the section (required)
         #[arg(short, long, required = true)]
         project: String,
+
+        /// Place the new section before this one (name or ID)
+        #[arg(long, value_name = "SECTION", conflicts_with = "after")]
+        before: Option<String>,
+
+        /// Place the new section after this one (name or ID)
+        #[arg(long, value_name = "SECTION")]
+        after: Option<String>,
     },
 
     /// Edit a section
@@ -579,8 +1096,17 @@ pub enum CommentsCommands {
 
     /// Edit a comment
     Edit {
-        /// Comment ID
-        comment_id: String,
+        /// Comment ID (or use --task with --index)
+        #[arg(required_unless_present = "task", conflicts_with = "task")]
+        comment_id: Option<String>,
+
+        /// Task whose comment to edit
+        #[arg(long, requires = "index")]
+        task: Option<String>,
+
+        /// Comment number on the task (1 = oldest)
+        #[arg(long, requires = "task")]
+        index: Option<usize>,
 
         /// New text
         #[arg(long)]
@@ -589,8 +1115,17 @@ pub enum CommentsCommands {
 
     /// Delete a comment
     Delete {
-        /// Comment ID
-        comment_id: String,
+        /// Comment ID (or use --task with --index)
+        #[arg(required_unless_present = "task", conflicts_with = "task")]
+        comment_id: Option<String>,
+
+        /// Task whose comment to delete
+        #[arg(long, requires = "index")]
+        task: Option<String>,
+
+        /// Comment number on the task (1 = oldest)
+        #[arg(long, requires = "task")]
+        index: Option<usize>,
 
         /// Skip confirmation
         #[arg(short, long)]
@@ -638,15 +1173,24 @@ pub enum RemindersCommands {
         #[arg(long, conflicts_with = "offset")]
         due: Option<String>,
 
-        /// Minutes before task due time (for relative reminders)
-        #[arg(long, conflicts_with = "due")]
-        offset: Option<i32>,
+        /// Time before the task's due time: minutes (30) or a duration (45m, 1h, 2d, 1w)
+        #[arg(long, value_name = "OFFSET", conflicts_with = "due")]
+        offset: Option<String>,
     },
 
-    /// Delete a reminder
+    /// Delete a reminder, or all of a task's reminders with --task and --all
     Delete {
         /// Reminder ID
-        reminder_id: String,
+        #[arg(required_unless_present = "all", conflicts_with = "all")]
+        reminder_id: Option<String>,
+
+        /// Task whose reminders to delete (with --all)
+        #[arg(long, requires = "all")]
+        task: Option<String>,
+
+        /// Delete every reminder of --task in one batch
+        #[arg(long, requires = "task")]
+        all: bool,
 
         /// Skip confirmation
         #[arg(short, long)]
@@ -658,7 +1202,11 @@ pub enum RemindersCommands {
 #[derive(Subcommand, Debug)]
 pub enum FiltersCommands {
     /// List all filters (default)
-    List,
+    List {
+        /// Only show favorite filters
+        #[arg(long)]
+        favorites_only: bool,
+    },
 
     /// Create a new filter
     Add {
@@ -676,12 +1224,20 @@ pub enum FiltersCommands {
         /// Mark as favorite
         #[arg(long)]
         favorite: bool,
+
+        /// Reject queries the local filter engine cannot parse (default: warn)
+        #[arg(long)]
+        strict: bool,
     },
 
-    /// Show filter details
+    /// Show filter details and how many cached tasks it matches
     Show {
         /// Filter ID
         filter_id: String,
+
+        /// Also list the first few matching tasks
+        #[arg(long)]
+        preview: bool,
     },
 
     /// Edit a filter
@@ -704,6 +1260,20 @@ pub enum FiltersCommands {
         /// Toggle favorite
         #[arg(long)]
         favorite: Option<bool>,
+
+        /// Reject queries the local filter engine cannot parse (default: warn)
+        #[arg(long)]
+        strict: bool,
+    },
+
+    /// Mark a filter as favorite (or unmark with --off)
+    Favorite {
+        /// Filter ID
+        filter_id: String,
+
+        /// Remove the filter from favorites
+        #[arg(long)]
+        off: bool,
     },
 
     /// Delete a filter
@@ -739,6 +1309,24 @@ pub enum ConfigCommands {
     Path,
 }
 
+/// Cache subcommands
+#[derive(Subcommand, Debug)]
+pub enum CacheCommands {
+    /// Show cache path, size, sync freshness, and resource counts (no network)
+    Info,
+
+    /// Find tasks with the same content in the same project and delete the extras
+    Dedup {
+        /// Only list duplicates; don't delete anything
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Skip the confirmation prompt
+        #[arg(short, long)]
+        force: bool,
+    },
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -763,12 +1351,39 @@ mod tests {
         assert!(cli.json);
     }
 
+    #[test]
+    fn test_compact_flag() {
+        let cli = Cli::parse_from(["td", "list", "--json", "--compact"]);
+        assert!(cli.json);
+        assert!(cli.compact);
+
+        let cli = Cli::parse_from(["td", "--json", "list"]);
+        assert!(!cli.compact);
+    }
+
     #[test]
     fn test_no_color_flag() {
         let cli = Cli::parse_from(["td", "--no-color", "list"]);
         assert!(cli.no_color);
     }
 
+    #[test]
+    fn test_color_flag() {
+        let cli = Cli::parse_from(["td", "list"]);
+        assert_eq!(cli.color, ColorChoice::Auto);
+
+        let cli = Cli::parse_from(["td", "--color", "always", "list"]);
+        assert_eq!(cli.color, ColorChoice::Always);
+
+        assert!(Cli::try_parse_from(["td", "--color", "sometimes", "list"]).is_err());
+
+        // Subcommands keep their own --color option
+        let cli = Cli::parse_from([
+            "td", "--color", "never", "labels", "add", "x", "--color", "red",
+        ]);
+        assert_eq!(cli.color, ColorChoice::Never);
+    }
+
     #[test]
     fn test_token_flag() {
         let cli = Cli::parse_from(["td", "--token", "test-token", "list"]);
@@ -815,6 +1430,40 @@ mod tests {
         assert!(matches!(cli.command, Some(Commands::Edit { .. })));
     }
 
+    #[test]
+    fn test_edit_labels_list_conflicts_with_label() {
+        let cli = Cli::parse_from(["td", "edit", "task-id", "--labels", "-"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Edit { labels: Some(ref list), .. }) if list == "-"
+        ));
+        assert!(
+            Cli::try_parse_from(["td", "edit", "task-id", "--labels", "a", "-l", "b"]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_edit_recurring_conflicts_with_due() {
+        let cli = Cli::parse_from(["td", "edit", "task-id", "--recurring", "every weekday"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Edit { recurring: Some(ref p), .. }) if p == "every weekday"
+        ));
+        assert!(Cli::try_parse_from([
+            "td",
+            "edit",
+            "task-id",
+            "--recurring",
+            "every day",
+            "--due",
+            "today"
+        ])
+        .is_err());
+        assert!(
+            Cli::try_parse_from(["td", "edit", "task-id", "--no-recurring", "--no-due"]).is_err()
+        );
+    }
+
     #[test]
     fn test_delete_alias() {
         let cli = Cli::parse_from(["td", "rm", "task-id"]);
@@ -876,6 +1525,44 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_add_remind_flags() {
+        let remind_of = |args: &[&str]| match Cli::parse_from(args).command {
+            Some(Commands::Add {
+                content,
+                remind,
+                remind_default,
+                ..
+            }) => (content, remind, remind_default),
+            _ => panic!("Expected Add command"),
+        };
+
+        assert_eq!(
+            remind_of(&["td", "add", "Task", "--remind", "30m before"]),
+            ("Task".to_string(), Some("30m before".to_string()), false)
+        );
+        assert_eq!(
+            remind_of(&["td", "add", "--remind-default", "Task"]),
+            ("Task".to_string(), None, true)
+        );
+        assert_eq!(
+            remind_of(&["td", "add", "Task"]),
+            ("Task".to_string(), None, false)
+        );
+
+        // --remind always takes a value, so it never swallows the content
+        assert!(Cli::try_parse_from(["td", "add", "Task", "--remind"]).is_err());
+        assert!(Cli::try_parse_from([
+            "td",
+            "add",
+            "Task",
+            "--remind",
+            "09:00",
+            "--remind-default"
+        ])
+        .is_err());
+    }
+
     #[test]
     fn test_add_with_labels() {
         let cli = Cli::parse_from([
@@ -956,6 +1643,76 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_complete_is_hidden() {
+        let cli = Cli::parse_from(["td", "__complete", "labels"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Complete {
+                resource: CompleteResource::Labels
+            })
+        ));
+        assert!(Cli::command()
+            .find_subcommand("__complete")
+            .is_some_and(|cmd| cmd.is_hide_set()));
+    }
+
+    #[test]
+    fn test_list_repeatable_label_with_match_mode() {
+        let cli = Cli::parse_from([
+            "td",
+            "list",
+            "-l",
+            "work",
+            "--label",
+            "urgent",
+            "--label-match",
+            "any",
+        ]);
+        if let Some(Commands::List {
+            label, label_match, ..
+        }) = cli.command
+        {
+            assert_eq!(label, vec!["work", "urgent"]);
+            assert_eq!(label_match, LabelMatch::Any);
+        } else {
+            panic!("Expected List command");
+        }
+
+        let cli = Cli::parse_from(["td", "list", "-l", "work", "-l", "urgent"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::List {
+                label_match: LabelMatch::All,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_completed_command() {
+        let cli = Cli::parse_from(["td", "completed", "--project", "Work", "--all"]);
+        if let Some(Commands::Completed {
+            project,
+            limit,
+            all,
+        }) = cli.command
+        {
+            assert_eq!(project.as_deref(), Some("Work"));
+            assert_eq!(limit, 50);
+            assert!(all);
+        } else {
+            panic!("Expected Completed command");
+        }
+    }
+
+    #[test]
+    fn test_info_command() {
+        let cli = Cli::parse_from(["td", "info", "--json"]);
+        assert!(matches!(cli.command, Some(Commands::Info)));
+        assert!(cli.json);
+    }
+
     #[test]
     fn test_priority_range() {
         // Valid priorities
@@ -966,4 +1723,48 @@ mod tests {
         assert!(Cli::try_parse_from(["td", "add", "task", "-P", "0"]).is_err());
         assert!(Cli::try_parse_from(["td", "add", "task", "-P", "5"]).is_err());
     }
+
+    #[test]
+    fn test_delete_filter_conflicts_with_task_ids() {
+        assert!(Cli::try_parse_from(["td", "delete", "--filter", "p4"]).is_ok());
+        assert!(Cli::try_parse_from(["td", "delete", "abc", "--filter", "p4"]).is_err());
+        assert!(Cli::try_parse_from(["td", "delete"]).is_err());
+    }
+
+    #[test]
+    fn test_cache_dedup_parses() {
+        let cli = Cli::parse_from(["td", "cache", "dedup", "--dry-run"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Cache {
+                command: CacheCommands::Dedup {
+                    dry_run: true,
+                    force: false
+                }
+            })
+        ));
+        assert!(Cli::try_parse_from(["td", "cache"]).is_err());
+    }
+
+    #[test]
+    fn test_max_results_requires_filter() {
+        let cli = Cli::parse_from(["td", "delete", "--filter", "p4", "--max-results", "200"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Delete {
+                max_results: Some(200),
+                ..
+            })
+        ));
+        assert!(Cli::try_parse_from(["td", "reopen", "abc", "--max-results", "5"]).is_err());
+    }
+
+    #[test]
+    fn test_reopen_filter_conflicts_with_task_ids() {
+        assert!(
+            Cli::try_parse_from(["td", "reopen", "--filter", "completed & @recurring"]).is_ok()
+        );
+        assert!(Cli::try_parse_from(["td", "reopen", "abc", "--filter", "p4"]).is_err());
+        assert!(Cli::try_parse_from(["td", "reopen"]).is_err());
+    }
 }
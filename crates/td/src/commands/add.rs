@@ -1,28 +1,43 @@
 //! Add command implementation.
 //!
-//! Creates a new task via the Sync API's `item_add` command.
+//! Creates a new task via the Sync API's `item_add` command, optionally
+//! batched with a `reminder_add` linked through the task's temp ID.
 //! Uses SyncManager::execute_commands() to automatically update the cache.
 
-use todoist_api_rs::client::TodoistClient;
-use todoist_api_rs::sync::{SyncCommand, SyncCommandType};
-use todoist_cache_rs::{CacheStore, SyncManager};
+use chrono::{NaiveDateTime, NaiveTime};
+use todoist_api_rs::sync::{DueSpec, Item, SyncCommand, SyncCommandType};
+use todoist_cache_rs::{Cache, SyncManager};
 
-use super::{CommandContext, CommandError, Result};
-use crate::output::format_created_item;
+use super::labels::missing_label_commands;
+use super::{
+    due_arg, parse_duration_minutes, resolve_due_lang, resolve_section_in_project, validate_due_tz,
+    CommandContext, CommandError, Result,
+};
+use crate::output::helpers::format_fields_csv;
+use crate::output::{format_created_item, format_created_item_csv, OutputFormat};
 
 /// Options for the add command.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct AddOptions {
     /// Task content/title.
     pub content: String,
     /// Target project (name or ID).
     pub project: Option<String>,
+    /// Project (name or ID) used when `project` is not given, from the
+    /// `add_default_project` config. Falls back to the Inbox.
+    pub default_project: Option<String>,
     /// Priority level (1=highest, 4=lowest).
     pub priority: Option<u8>,
     /// Due date (natural language or ISO).
     pub due: Option<String>,
+    /// Language of the due date string (e.g., "es").
+    pub lang: Option<String>,
+    /// Timezone for a timed due date (IANA name).
+    pub due_tz: Option<String>,
     /// Labels to attach.
     pub labels: Vec<String>,
+    /// Create labels missing from the cache instead of warning.
+    pub create_labels: bool,
     /// Target section within project.
     pub section: Option<String>,
     /// Parent task ID (creates subtask).
@@ -31,67 +46,361 @@ pub struct AddOptions {
     pub description: Option<String>,
     /// Assign task to user.
     pub assign: Option<String>,
+    /// Reminder spec (time, date/time, or "<n>m/h/d before").
+    pub remind: Option<String>,
+    /// Place the task first among its siblings.
+    pub at_top: bool,
+    /// Place the task last among its siblings.
+    pub at_bottom: bool,
+    /// Read quick-add tokens (`@label`, `#project`, `p1`-`p4`) from the content.
+    pub parse: bool,
+    /// Without `parse`, still take trailing `p1`-`p4` and `@label` tokens
+    /// from the content (the `add_parse_tokens` config, on by default).
+    pub parse_tokens: bool,
+    /// Show the resolved task without creating it.
+    pub dry_run: bool,
 }
 
-/// Result of a successful add operation.
-#[derive(Debug)]
-pub struct AddResult {
-    /// The real ID of the created task.
-    pub id: String,
-    /// The content of the created task.
-    pub content: String,
-    /// The project ID.
-    pub project_id: String,
-    /// The project name (if found in cache).
-    pub project_name: Option<String>,
+/// Quick-add tokens pulled out of task content by `--parse`.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct QuickAddTokens {
+    /// Content with the consumed tokens removed.
+    content: String,
+    /// Project from the last `#project` token.
+    project: Option<String>,
+    /// Priority from the last `p1`-`p4` token.
+    priority: Option<u8>,
+    /// Labels from `@label` tokens, in order.
+    labels: Vec<String>,
 }
 
-/// Executes the add command.
+/// Extracts `@label`, `#project`, and `p1`-`p4` tokens from task content.
 ///
-/// # Arguments
+/// Only whole whitespace-separated words are consumed; the remaining words
+/// form the new content. Due dates are left in place since they can't be
+/// told apart from ordinary words locally.
+fn extract_quick_add_tokens(content: &str) -> QuickAddTokens {
+    let mut tokens = QuickAddTokens::default();
+    let mut words = Vec::new();
+
+    for word in content.split_whitespace() {
+        if let Some(label) = word.strip_prefix('@').filter(|l| !l.is_empty()) {
+            if !tokens.labels.iter().any(|l| l == label) {
+                tokens.labels.push(label.to_string());
+            }
+        } else if let Some(project) = word.strip_prefix('#').filter(|p| !p.is_empty()) {
+            tokens.project = Some(project.to_string());
+        } else if let Some(level) = parse_priority_token(word) {
+            tokens.priority = Some(level);
+        } else {
+            words.push(word);
+        }
+    }
+
+    tokens.content = words.join(" ");
+    tokens
+}
+
+/// Parses a `p1`-`p4` priority token (case-insensitive).
+fn parse_priority_token(word: &str) -> Option<u8> {
+    let level = word.strip_prefix(['p', 'P'])?.parse::<u8>().ok()?;
+    (1..=4).contains(&level).then_some(level)
+}
+
+/// Extracts the trailing run of `p1`-`p4` and `@label` words from content.
 ///
-/// * `ctx` - Command context with output settings
-/// * `opts` - Add command options
-/// * `token` - API token
+/// This is the conservative default used without `--parse`: scanning stops
+/// at the first word from the end that isn't a token, so "Upgrade to p4
+/// plan" keeps its words. Priority tokens are only taken when `priority` is
+/// true and labels only when `labels` is true. Content made up entirely of
+/// tokens is left alone.
+fn extract_trailing_tokens(content: &str, priority: bool, labels: bool) -> QuickAddTokens {
+    let words: Vec<&str> = content.split_whitespace().collect();
+    let mut tokens = QuickAddTokens::default();
+    let mut keep = words.len();
+
+    while keep > 0 {
+        let word = words[keep - 1];
+        if let Some(label) = word.strip_prefix('@').filter(|l| labels && !l.is_empty()) {
+            if !tokens.labels.iter().any(|l| l == label) {
+                tokens.labels.insert(0, label.to_string());
+            }
+        } else if let Some(level) = parse_priority_token(word).filter(|_| priority) {
+            // The last priority token wins, as with --parse
+            tokens.priority.get_or_insert(level);
+        } else {
+            break;
+        }
+        keep -= 1;
+    }
+
+    if keep == 0 {
+        return QuickAddTokens {
+            content: content.to_string(),
+            ..QuickAddTokens::default()
+        };
+    }
+    tokens.content = words[..keep].join(" ");
+    tokens
+}
+
+/// Applies trailing `p1`-`p4` and `@label` tokens from the content to `opts`.
+///
+/// An explicit `--priority` or `--label` turns off extraction of that kind
+/// of token, so it stays in the content as typed. Content without trailing
+/// tokens is returned unchanged.
+fn merge_trailing_tokens(opts: &AddOptions) -> AddOptions {
+    let tokens = extract_trailing_tokens(
+        &opts.content,
+        opts.priority.is_none(),
+        opts.labels.is_empty(),
+    );
+    if tokens.priority.is_none() && tokens.labels.is_empty() {
+        return opts.clone();
+    }
+
+    AddOptions {
+        content: tokens.content,
+        priority: opts.priority.or(tokens.priority),
+        labels: if opts.labels.is_empty() {
+            tokens.labels
+        } else {
+            opts.labels.clone()
+        },
+        ..opts.clone()
+    }
+}
+
+/// Applies quick-add tokens from the content to `opts`.
+///
+/// Explicit `--project` and `--priority` win over tokens; `@label` tokens
+/// are added to any `--label` values.
 ///
 /// # Errors
 ///
-/// Returns an error if project/section lookup fails or the API returns an error.
-pub async fn execute(ctx: &CommandContext, opts: &AddOptions, token: &str) -> Result<()> {
-    // Initialize sync manager (loads cache from disk)
-    let client = TodoistClient::new(token)?;
-    let store = CacheStore::new()?;
-    let mut manager = SyncManager::new(client, store)?;
+/// Returns an error if nothing is left of the content once tokens are removed.
+fn merge_quick_add_tokens(opts: &AddOptions) -> Result<AddOptions> {
+    let tokens = extract_quick_add_tokens(&opts.content);
+    if tokens.content.is_empty() {
+        return Err(CommandError::Config(
+            "Task content is empty after removing quick-add tokens".to_string(),
+        ));
+    }
+
+    let mut labels = opts.labels.clone();
+    for label in tokens.labels {
+        if !labels.contains(&label) {
+            labels.push(label);
+        }
+    }
+
+    Ok(AddOptions {
+        content: tokens.content,
+        project: opts.project.clone().or(tokens.project),
+        priority: opts.priority.or(tokens.priority),
+        labels,
+        ..opts.clone()
+    })
+}
+
+/// Picks the project for a task added without `--project`.
+///
+/// A configured default project is used when it resolves; otherwise the
+/// Inbox is used, along with a warning explaining why.
+async fn default_project_id(
+    manager: &mut SyncManager,
+    configured: Option<&str>,
+) -> Result<(String, Option<String>)> {
+    let mut warning = None;
+    if let Some(name) = configured {
+        match manager.resolve_project(name).await {
+            Ok(project) => return Ok((project.id.clone(), None)),
+            Err(e) => {
+                warning = Some(format!(
+                    "Warning: add_default_project '{name}' not usable ({e}); adding to Inbox."
+                ));
+            }
+        }
+    }
+
+    // Use inbox project if no project specified
+    let inbox_id = manager
+        .cache()
+        .inbox_project()
+        .map(|p| p.id.clone())
+        .ok_or_else(|| CommandError::Config("Inbox project not found".to_string()))?;
+    Ok((inbox_id, warning))
+}
+
+/// A parsed `--remind` value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum RemindSpec {
+    /// A time of day on the task's due date (e.g., "09:00").
+    TimeOfDay(NaiveTime),
+    /// A fixed date and time.
+    DateTime(NaiveDateTime),
+    /// Minutes before the task's due time.
+    Offset(i32),
+}
+
+/// Parses a `--remind` value.
+///
+/// Accepted forms:
+/// - `HH:MM` — at that time on the task's due date
+/// - `YYYY-MM-DD HH:MM` or `YYYY-MM-DDTHH:MM[:SS]` — at that date and time
+/// - `<n>m before`, `<n>h before`, `<n>d before`, `<n>w before` — relative
+///   to the due time
+fn parse_remind_spec(spec: &str) -> Result<RemindSpec> {
+    let spec = spec.trim();
+
+    if let Some(amount) = spec.strip_suffix("before") {
+        return parse_duration_minutes(amount)
+            .and_then(|minutes| i32::try_from(minutes).ok())
+            .map(RemindSpec::Offset)
+            .ok_or_else(|| invalid_remind_spec(spec));
+    }
+
+    if let Ok(time) = NaiveTime::parse_from_str(spec, "%H:%M") {
+        return Ok(RemindSpec::TimeOfDay(time));
+    }
+
+    ["%Y-%m-%d %H:%M", "%Y-%m-%dT%H:%M", "%Y-%m-%dT%H:%M:%S"]
+        .iter()
+        .find_map(|fmt| NaiveDateTime::parse_from_str(spec, fmt).ok())
+        .map(RemindSpec::DateTime)
+        .ok_or_else(|| invalid_remind_spec(spec))
+}
 
+/// Returns the `--remind` value used by `--remind-default`, built from the
+/// `default_reminder_offset` config.
+///
+/// # Errors
+///
+/// Returns an error if no default offset is configured.
+pub fn default_remind_spec(default_offset: Option<i32>) -> Result<String> {
+    default_offset
+        .map(|minutes| format!("{minutes}m before"))
+        .ok_or_else(|| {
+            CommandError::Config(
+                "--remind-default needs a default offset. Set one with `td config set default_reminder_offset 30`, or pass a value (e.g., --remind \"30m before\").".to_string(),
+            )
+        })
+}
+
+fn invalid_remind_spec(spec: &str) -> CommandError {
+    CommandError::Config(format!(
+        "Invalid --remind value '{spec}'. Use a time (09:00), a date/time (2025-01-15 09:00), or an offset (30m before)."
+    ))
+}
+
+/// Builds `reminder_add` arguments linked to the task's temp ID.
+///
+/// Time-of-day and offset reminders are anchored to the task's due date, so
+/// they require `--due`.
+fn build_reminder_args(
+    spec: &RemindSpec,
+    item_temp_id: &str,
+    due: Option<&str>,
+) -> Result<serde_json::Value> {
+    let args = match spec {
+        RemindSpec::DateTime(datetime) => serde_json::json!({
+            "item_id": item_temp_id,
+            "type": "absolute",
+            "due": DueSpec::from_datetime(*datetime),
+        }),
+        RemindSpec::TimeOfDay(time) => {
+            let due = due.ok_or_else(|| {
+                CommandError::Config(
+                    "A reminder at a time of day needs a due date. Add --due, or give a full date/time to --remind.".to_string(),
+                )
+            })?;
+            serde_json::json!({
+                "item_id": item_temp_id,
+                "type": "absolute",
+                "due": DueSpec::from_string(format!("{due} at {}", time.format("%H:%M"))),
+            })
+        }
+        RemindSpec::Offset(minutes) => {
+            if due.is_none() {
+                return Err(CommandError::Config(
+                    "A relative reminder needs a due date and time. Add --due (e.g., --due \"tomorrow 10am\").".to_string(),
+                ));
+            }
+            serde_json::json!({
+                "item_id": item_temp_id,
+                "type": "relative",
+                "minute_offset": minutes,
+            })
+        }
+    };
+    Ok(args)
+}
+
+/// Computes a `child_order` that places a new task first (`at_top`) or last
+/// among its siblings: the active tasks sharing its project, section, and
+/// parent.
+fn sibling_child_order(
+    cache: &Cache,
+    project_id: &str,
+    section_id: Option<&str>,
+    parent_id: Option<&str>,
+    at_top: bool,
+) -> i32 {
+    let orders = cache
+        .items
+        .iter()
+        .filter(|i| !i.is_deleted && !i.checked && i.project_id == project_id)
+        .filter(|i| i.parent_id.as_deref() == parent_id)
+        // Subtasks follow their parent's section, so only top-level tasks
+        // are grouped by section
+        .filter(|i| parent_id.is_some() || i.section_id.as_deref() == section_id)
+        .map(|i| i.child_order);
+
+    let extreme = if at_top { orders.min() } else { orders.max() };
+    match extreme {
+        Some(order) if at_top => order - 1,
+        Some(order) => order + 1,
+        None => 1,
+    }
+}
+
+/// Resolves project, section, and assignee names and builds the
+/// `item_add` arguments. Returns the arguments and the project ID.
+async fn build_item_args(
+    manager: &mut SyncManager,
+    opts: &AddOptions,
+    due_tz: Option<&str>,
+    quiet: bool,
+) -> Result<(serde_json::Value, String)> {
     // Resolve project name to ID using smart lookup (cache-first with auto-sync fallback)
     let project_id = if let Some(ref project_name) = opts.project {
         manager.resolve_project(project_name).await?.id.clone()
     } else {
-        // Use inbox project if no project specified
-        manager
-            .cache()
-            .projects
-            .iter()
-            .find(|p| p.inbox_project && !p.is_deleted)
-            .map(|p| p.id.clone())
-            .ok_or_else(|| CommandError::Config("Inbox project not found".to_string()))?
+        let (project_id, warning) =
+            default_project_id(manager, opts.default_project.as_deref()).await?;
+        if let Some(warning) = warning.filter(|_| !quiet) {
+            eprintln!("{warning}");
+        }
+        project_id
     };
 
     // Resolve section name to ID using smart lookup (cache-first with auto-sync fallback)
     let section_id = if let Some(ref section_name) = opts.section {
-        Some(
-            manager
-                .resolve_section(section_name, Some(&project_id))
-                .await?
-                .id
-                .clone(),
-        )
+        Some(resolve_section_in_project(manager, section_name, &project_id).await?)
     } else {
         None
     };
 
+    // Resolve the parent so an ID prefix works and its siblings can be found
+    let parent = match opts.parent {
+        Some(ref parent) => {
+            let item = manager.resolve_item_by_prefix(parent, None).await?;
+            Some((item.id.clone(), item.project_id.clone()))
+        }
+        None => None,
+    };
+
     // Build the item_add command arguments
-    let temp_id = uuid::Uuid::new_v4().to_string();
     let mut args = serde_json::json!({
         "content": opts.content,
         "project_id": project_id,
@@ -110,7 +419,8 @@ pub async fn execute(ctx: &CommandContext, opts: &AddOptions, token: &str) -> Re
 
     if let Some(ref due) = opts.due {
         // Use the "string" field to let Todoist parse natural language dates
-        args["due"] = serde_json::json!({"string": due});
+        let lang = resolve_due_lang(opts.lang.as_deref(), manager.cache());
+        args["due"] = due_arg(due, lang.as_deref(), due_tz);
     }
 
     if !opts.labels.is_empty() {
@@ -135,14 +445,206 @@ pub async fn execute(ctx: &CommandContext, opts: &AddOptions, token: &str) -> Re
         args["section_id"] = serde_json::json!(section_id);
     }
 
-    if let Some(ref parent_id) = opts.parent {
+    if let Some((ref parent_id, _)) = parent {
         args["parent_id"] = serde_json::json!(parent_id);
     }
 
-    // Create and execute the command via SyncManager
-    // This sends the command, applies the response to cache, and saves to disk
-    let command = SyncCommand::with_temp_id(SyncCommandType::ItemAdd, &temp_id, args);
-    let response = manager.execute_commands(vec![command]).await?;
+    if opts.at_top || opts.at_bottom {
+        // A subtask's siblings live in its parent's project
+        let (sibling_project_id, parent_id) = match parent {
+            Some((ref parent_id, ref parent_project_id)) => {
+                (parent_project_id.as_str(), Some(parent_id.as_str()))
+            }
+            None => (project_id.as_str(), None),
+        };
+        args["child_order"] = serde_json::json!(sibling_child_order(
+            manager.cache(),
+            sibling_project_id,
+            section_id.as_deref(),
+            parent_id,
+            opts.at_top,
+        ));
+    }
+
+    Ok((args, project_id))
+}
+
+/// Assembles the batch for one add: any `label_add` commands first, so the
+/// task can use the new labels, then `item_add`, then the optional
+/// `reminder_add`. Returns the commands and the reminder's temp ID.
+fn build_add_commands(
+    label_commands: Vec<SyncCommand>,
+    temp_id: &str,
+    args: serde_json::Value,
+    reminder_args: Option<serde_json::Value>,
+) -> (Vec<SyncCommand>, Option<String>) {
+    let mut commands = label_commands;
+    commands.push(SyncCommand::with_temp_id(
+        SyncCommandType::ItemAdd,
+        temp_id,
+        args,
+    ));
+
+    let reminder_temp_id = reminder_args.map(|reminder_args| {
+        let reminder_temp_id = uuid::Uuid::new_v4().to_string();
+        commands.push(SyncCommand::with_temp_id(
+            SyncCommandType::ReminderAdd,
+            &reminder_temp_id,
+            reminder_args,
+        ));
+        reminder_temp_id
+    });
+
+    (commands, reminder_temp_id)
+}
+
+/// Prints what `--dry-run` would create, without sending any command.
+fn print_dry_run(
+    ctx: &CommandContext,
+    cache: &Cache,
+    opts: &AddOptions,
+    args: &serde_json::Value,
+    reminder_args: Option<&serde_json::Value>,
+) -> Result<()> {
+    match ctx.output_format {
+        OutputFormat::Json => {
+            let output = dry_run_json(cache, args, reminder_args);
+            println!("{}", crate::output::to_json(&output, ctx.compact_json)?);
+        }
+        OutputFormat::Csv => {
+            let output = dry_run_json(cache, args, reminder_args);
+            print!("{}", format_fields_csv(&output)?);
+        }
+        OutputFormat::Table if !ctx.quiet => print!("{}", format_dry_run(cache, opts, args)),
+        OutputFormat::Table => {}
+    }
+    Ok(())
+}
+
+/// Builds the `--dry-run` JSON: the resolved command payloads plus the
+/// names of the project and section they point at.
+fn dry_run_json(
+    cache: &Cache,
+    args: &serde_json::Value,
+    reminder_args: Option<&serde_json::Value>,
+) -> serde_json::Value {
+    serde_json::json!({
+        "dry_run": true,
+        "project_name": args["project_id"].as_str().and_then(|id| cache.find_project(id)).map(|p| &p.name),
+        "section_name": args["section_id"].as_str().and_then(|id| cache.find_section(id, None)).map(|s| &s.name),
+        "item_add": args,
+        "reminder_add": reminder_args,
+    })
+}
+
+/// Formats the `--dry-run` preview for table output.
+fn format_dry_run(cache: &Cache, opts: &AddOptions, args: &serde_json::Value) -> String {
+    let with_name = |id: &str, name: Option<&str>| match name {
+        Some(name) => format!("{name} ({id})"),
+        None => id.to_string(),
+    };
+
+    let mut output = format!("Would create: {}\n", opts.content);
+    if let Some(id) = args["project_id"].as_str() {
+        let name = cache.find_project(id).map(|p| p.name.as_str());
+        output.push_str(&format!("  Project: {}\n", with_name(id, name)));
+    }
+    if let Some(id) = args["section_id"].as_str() {
+        let name = cache.find_section(id, None).map(|s| s.name.as_str());
+        output.push_str(&format!("  Section: {}\n", with_name(id, name)));
+    }
+    if let Some(ref parent) = opts.parent {
+        output.push_str(&format!("  Parent: {parent}\n"));
+    }
+    if !opts.labels.is_empty() {
+        output.push_str(&format!("  Labels: {}\n", opts.labels.join(", ")));
+    }
+    if let Some(priority) = opts.priority {
+        output.push_str(&format!("  Priority: p{priority}\n"));
+    }
+    if let Some(ref due) = opts.due {
+        output.push_str(&format!("  Due: {due}\n"));
+    }
+    if let Some(uid) = args["responsible_uid"].as_str() {
+        output.push_str(&format!("  Assignee: {uid}\n"));
+    }
+    if let Some(ref remind) = opts.remind {
+        output.push_str(&format!("  Reminder: {remind}\n"));
+    }
+    output
+}
+
+/// Result of a successful add operation.
+#[derive(Debug)]
+pub struct AddResult {
+    /// The real ID of the created task.
+    pub id: String,
+    /// The content of the created task.
+    pub content: String,
+    /// The project ID.
+    pub project_id: String,
+    /// The project name (if found in cache).
+    pub project_name: Option<String>,
+    /// The real ID of the reminder created alongside the task (if any).
+    pub reminder_id: Option<String>,
+    /// The created task as stored in the cache after the mutation.
+    pub item: Option<Item>,
+}
+
+/// Executes the add command.
+///
+/// # Arguments
+///
+/// * `ctx` - Command context with output settings
+/// * `opts` - Add command options
+/// * `token` - API token
+///
+/// # Errors
+///
+/// Returns an error if project/section lookup fails or the API returns an error.
+pub async fn execute(ctx: &CommandContext, opts: &AddOptions, token: &str) -> Result<()> {
+    let merged;
+    let opts = if opts.parse {
+        merged = merge_quick_add_tokens(opts)?;
+        &merged
+    } else if opts.parse_tokens {
+        merged = merge_trailing_tokens(opts);
+        &merged
+    } else {
+        opts
+    };
+
+    // Validate the reminder before touching the network. It references the
+    // task by temp ID so both commands land in one batch.
+    let temp_id = uuid::Uuid::new_v4().to_string();
+    let reminder_args = opts
+        .remind
+        .as_deref()
+        .map(|remind| {
+            build_reminder_args(&parse_remind_spec(remind)?, &temp_id, opts.due.as_deref())
+        })
+        .transpose()?;
+    let due_tz = opts.due_tz.as_deref().map(validate_due_tz).transpose()?;
+
+    // Initialize sync manager (loads cache from disk)
+    let mut manager = ctx.sync_manager(token)?;
+
+    let (args, project_id) =
+        build_item_args(&mut manager, opts, due_tz.as_deref(), ctx.quiet).await?;
+
+    let label_commands =
+        missing_label_commands(manager.cache(), &opts.labels, opts.create_labels, ctx.quiet);
+
+    if opts.dry_run {
+        return print_dry_run(ctx, manager.cache(), opts, &args, reminder_args.as_ref());
+    }
+
+    // Create and execute the command(s) via SyncManager
+    // This sends the commands, applies the response to cache, and saves to disk
+    let (commands, reminder_temp_id) =
+        build_add_commands(label_commands, &temp_id, args, reminder_args);
+
+    let response = manager.execute_commands(commands).await?;
 
     // Check for command errors in the response
     if response.has_errors() {
@@ -164,6 +666,7 @@ pub async fn execute(ctx: &CommandContext, opts: &AddOptions, token: &str) -> Re
             CommandError::Config("Task created but no ID returned in response".to_string())
         })?
         .clone();
+    let reminder_id = reminder_temp_id.and_then(|id| response.real_id(&id).cloned());
 
     // Get project name for output from the updated cache
     let project_name = manager
@@ -173,17 +676,23 @@ pub async fn execute(ctx: &CommandContext, opts: &AddOptions, token: &str) -> Re
         .find(|p| p.id == project_id)
         .map(|p| p.name.clone());
 
+    let item = manager.cache().find_item(&real_id).cloned();
+
     let result = AddResult {
         id: real_id,
         content: opts.content.clone(),
         project_id,
         project_name,
+        reminder_id,
+        item,
     };
 
     // Output
-    if ctx.json_output {
-        let output = format_created_item(&result)?;
+    if ctx.output_format.is_json() {
+        let output = format_created_item(&result, ctx.compact_json)?;
         println!("{output}");
+    } else if ctx.output_format == OutputFormat::Csv {
+        print!("{}", format_created_item_csv(&result));
     } else if !ctx.quiet {
         if ctx.verbose {
             println!("Created task: {} ({})", result.content, result.id);
@@ -196,6 +705,9 @@ pub async fn execute(ctx: &CommandContext, opts: &AddOptions, token: &str) -> Re
             if !opts.labels.is_empty() {
                 println!("  Labels: {}", opts.labels.join(", "));
             }
+            if let Some(ref remind) = opts.remind {
+                println!("  Reminder: {remind}");
+            }
         } else {
             println!(
                 "Created: {} ({})",
@@ -211,19 +723,30 @@ pub async fn execute(ctx: &CommandContext, opts: &AddOptions, token: &str) -> Re
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_support::{self, item, project};
 
     #[test]
     fn test_add_options_defaults() {
         let opts = AddOptions {
             content: "Test task".to_string(),
             project: None,
+            default_project: None,
             priority: None,
             due: None,
+            lang: None,
+            due_tz: None,
             labels: vec![],
+            create_labels: false,
             section: None,
             parent: None,
             description: None,
             assign: None,
+            remind: None,
+            at_top: false,
+            at_bottom: false,
+            parse: false,
+            parse_tokens: false,
+            dry_run: false,
         };
 
         assert_eq!(opts.content, "Test task");
@@ -236,13 +759,23 @@ mod tests {
         let opts = AddOptions {
             content: "Test task".to_string(),
             project: Some("Work".to_string()),
+            default_project: None,
             priority: Some(1),
             due: Some("tomorrow".to_string()),
+            lang: Some("es".to_string()),
+            due_tz: None,
             labels: vec!["urgent".to_string(), "important".to_string()],
+            create_labels: false,
             section: Some("In Progress".to_string()),
             parent: Some("parent-123".to_string()),
             description: Some("Task description".to_string()),
             assign: None,
+            remind: Some("30m before".to_string()),
+            at_top: true,
+            at_bottom: false,
+            parse: false,
+            parse_tokens: false,
+            dry_run: false,
         };
 
         assert_eq!(opts.content, "Test task");
@@ -255,6 +788,137 @@ mod tests {
         assert_eq!(opts.description, Some("Task description".to_string()));
     }
 
+    fn parse_options(content: &str) -> AddOptions {
+        AddOptions {
+            content: content.to_string(),
+            project: None,
+            default_project: None,
+            priority: None,
+            due: None,
+            lang: None,
+            due_tz: None,
+            labels: vec![],
+            create_labels: false,
+            section: None,
+            parent: None,
+            description: None,
+            assign: None,
+            remind: None,
+            at_top: false,
+            at_bottom: false,
+            parse: true,
+            parse_tokens: false,
+            dry_run: false,
+        }
+    }
+
+    #[test]
+    fn test_extract_quick_add_tokens() {
+        let tokens = extract_quick_add_tokens("Email boss @work p2 #Office  about it @");
+        assert_eq!(tokens.content, "Email boss about it @");
+        assert_eq!(tokens.labels, vec!["work".to_string()]);
+        assert_eq!(tokens.project, Some("Office".to_string()));
+        assert_eq!(tokens.priority, Some(2));
+
+        // Not priority tokens
+        let tokens = extract_quick_add_tokens("Ship p5 and P10 on-time");
+        assert_eq!(tokens.content, "Ship p5 and P10 on-time");
+        assert_eq!(tokens.priority, None);
+    }
+
+    #[test]
+    fn test_merge_quick_add_tokens_flags_take_precedence() {
+        let mut opts = parse_options("Email boss @work p2 #Work");
+        opts.project = Some("Inbox".to_string());
+        opts.priority = Some(1);
+        opts.labels = vec!["urgent".to_string()];
+
+        let merged = merge_quick_add_tokens(&opts).unwrap();
+        assert_eq!(merged.content, "Email boss");
+        assert_eq!(merged.project, Some("Inbox".to_string()));
+        assert_eq!(merged.priority, Some(1));
+        assert_eq!(
+            merged.labels,
+            vec!["urgent".to_string(), "work".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_merge_quick_add_tokens_applies_tokens_without_flags() {
+        let merged = merge_quick_add_tokens(&parse_options("Email boss @work p2 #Work")).unwrap();
+        assert_eq!(merged.content, "Email boss");
+        assert_eq!(merged.project, Some("Work".to_string()));
+        assert_eq!(merged.priority, Some(2));
+        assert_eq!(merged.labels, vec!["work".to_string()]);
+
+        assert!(merge_quick_add_tokens(&parse_options("@work p1")).is_err());
+    }
+
+    fn token_options(content: &str) -> AddOptions {
+        AddOptions {
+            parse: false,
+            parse_tokens: true,
+            ..parse_options(content)
+        }
+    }
+
+    #[test]
+    fn test_trailing_tokens_extracted_by_default() {
+        let merged = merge_trailing_tokens(&token_options("Call mom p1 @home  @errands"));
+        assert_eq!(merged.content, "Call mom");
+        assert_eq!(merged.priority, Some(1));
+        assert_eq!(
+            merged.labels,
+            vec!["home".to_string(), "errands".to_string()]
+        );
+
+        // The last priority token wins
+        let merged = merge_trailing_tokens(&token_options("Call mom p3 p2"));
+        assert_eq!(merged.content, "Call mom");
+        assert_eq!(merged.priority, Some(2));
+    }
+
+    #[test]
+    fn test_trailing_tokens_leave_other_words() {
+        for content in [
+            "Buy mp1 cable",
+            "Ship p1x today",
+            "Upgrade to p4 plan",
+            "Email @work boss",
+            "Write to me@example.com",
+            "Ship P5",
+            "p1 @work",
+        ] {
+            let merged = merge_trailing_tokens(&token_options(content));
+            assert_eq!(merged.content, content);
+            assert_eq!(merged.priority, None);
+            assert!(merged.labels.is_empty());
+        }
+
+        // Only the trailing run is taken; #project needs --parse
+        let merged = merge_trailing_tokens(&token_options("Plan p1 trip #Travel @home"));
+        assert_eq!(merged.content, "Plan p1 trip #Travel");
+        assert_eq!(merged.priority, None);
+        assert_eq!(merged.labels, vec!["home".to_string()]);
+    }
+
+    #[test]
+    fn test_trailing_tokens_flags_take_precedence() {
+        let mut opts = token_options("Call mom p1 @home");
+        opts.priority = Some(3);
+        let merged = merge_trailing_tokens(&opts);
+        assert_eq!(merged.content, "Call mom p1");
+        assert_eq!(merged.priority, Some(3));
+        assert_eq!(merged.labels, vec!["home".to_string()]);
+
+        let mut opts = token_options("Call mom @home p1");
+        opts.labels = vec!["family".to_string()];
+        let merged = merge_trailing_tokens(&opts);
+        assert_eq!(merged.content, "Call mom @home");
+        assert_eq!(merged.priority, Some(1));
+        assert_eq!(merged.labels, vec!["family".to_string()]);
+    }
+
     #[test]
     fn test_priority_conversion() {
         // User priority 1 (highest) -> API priority 4
@@ -262,4 +926,421 @@ mod tests {
         // User priority 4 (lowest) -> API priority 1
         assert_eq!(5 - 4, 1);
     }
+
+    #[test]
+    fn test_parse_remind_spec_absolute() {
+        assert_eq!(
+            parse_remind_spec("09:00").unwrap(),
+            RemindSpec::TimeOfDay(NaiveTime::from_hms_opt(9, 0, 0).unwrap())
+        );
+        let expected = NaiveDateTime::parse_from_str("2025-01-15 09:30", "%Y-%m-%d %H:%M").unwrap();
+        assert_eq!(
+            parse_remind_spec("2025-01-15 09:30").unwrap(),
+            RemindSpec::DateTime(expected)
+        );
+        assert_eq!(
+            parse_remind_spec("2025-01-15T09:30:00").unwrap(),
+            RemindSpec::DateTime(expected)
+        );
+    }
+
+    #[test]
+    fn test_parse_remind_spec_relative() {
+        assert_eq!(
+            parse_remind_spec("30m before").unwrap(),
+            RemindSpec::Offset(30)
+        );
+        assert_eq!(
+            parse_remind_spec("2h before").unwrap(),
+            RemindSpec::Offset(120)
+        );
+        assert_eq!(
+            parse_remind_spec("1d before").unwrap(),
+            RemindSpec::Offset(1440)
+        );
+        assert_eq!(
+            parse_remind_spec("1w before").unwrap(),
+            RemindSpec::Offset(7 * 1440)
+        );
+        assert_eq!(
+            parse_remind_spec("15 before").unwrap(),
+            RemindSpec::Offset(15)
+        );
+    }
+
+    #[test]
+    fn test_parse_remind_spec_invalid() {
+        assert!(parse_remind_spec("soon").is_err());
+        assert!(parse_remind_spec("25:00").is_err());
+        assert!(parse_remind_spec("xm before").is_err());
+        assert!(parse_remind_spec("-5m before").is_err());
+        assert!(parse_remind_spec("999999999w before").is_err());
+    }
+
+    #[test]
+    fn test_build_reminder_args_absolute_links_temp_id() {
+        let spec = parse_remind_spec("09:00").unwrap();
+        let args = build_reminder_args(&spec, "temp-123", Some("tomorrow")).unwrap();
+        assert_eq!(args["item_id"], "temp-123");
+        assert_eq!(args["type"], "absolute");
+        assert_eq!(args["due"]["string"], "tomorrow at 09:00");
+
+        let spec = parse_remind_spec("2025-01-15 09:30").unwrap();
+        let args = build_reminder_args(&spec, "temp-123", None).unwrap();
+        assert_eq!(args["type"], "absolute");
+        assert_eq!(args["due"]["date"], "2025-01-15T09:30:00");
+    }
+
+    #[test]
+    fn test_build_reminder_args_relative() {
+        let spec = parse_remind_spec("30m before").unwrap();
+        let args = build_reminder_args(&spec, "temp-123", Some("tomorrow 10am")).unwrap();
+        assert_eq!(args["item_id"], "temp-123");
+        assert_eq!(args["type"], "relative");
+        assert_eq!(args["minute_offset"], 30);
+    }
+
+    #[test]
+    fn test_default_remind_spec_from_config() {
+        let spec = default_remind_spec(Some(45)).unwrap();
+        assert_eq!(spec, "45m before");
+        assert_eq!(parse_remind_spec(&spec).unwrap(), RemindSpec::Offset(45));
+
+        let err = default_remind_spec(None).unwrap_err();
+        assert!(err.to_string().contains("default_reminder_offset"));
+    }
+
+    #[test]
+    fn test_build_reminder_args_requires_due() {
+        let relative = parse_remind_spec("30m before").unwrap();
+        let err = build_reminder_args(&relative, "temp-123", None).unwrap_err();
+        assert!(err.to_string().contains("--due"));
+
+        let time_of_day = parse_remind_spec("09:00").unwrap();
+        let err = build_reminder_args(&time_of_day, "temp-123", None).unwrap_err();
+        assert!(err.to_string().contains("--due"));
+    }
+
+    fn make_cache(items: Vec<Item>) -> Cache {
+        Cache::with_data(
+            "test".to_string(),
+            None,
+            None,
+            items,
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            None,
+        )
+    }
+
+    #[test]
+    fn test_sibling_child_order_top_and_bottom() {
+        let cache = make_cache(vec![
+            item("a", "a").child_order(3).build(),
+            item("b", "b").child_order(5).build(),
+            item("c", "c").child_order(8).build(),
+        ]);
+
+        let top = sibling_child_order(&cache, "proj-1", None, None, true);
+        let bottom = sibling_child_order(&cache, "proj-1", None, None, false);
+        assert!(cache.items.iter().all(|i| top < i.child_order));
+        assert!(cache.items.iter().all(|i| bottom > i.child_order));
+        assert_eq!(top, 2);
+        assert_eq!(bottom, 9);
+    }
+
+    #[test]
+    fn test_sibling_child_order_only_considers_siblings() {
+        let done = item("done", "done")
+            .section("sec-1")
+            .child_order(100)
+            .checked()
+            .build();
+        let cache = make_cache(vec![
+            item("in-section", "in-section")
+                .section("sec-1")
+                .child_order(4)
+                .build(),
+            item("other-section", "other-section")
+                .section("sec-2")
+                .child_order(50)
+                .build(),
+            item("no-section", "no-section").child_order(-10).build(),
+            item("subtask", "subtask")
+                .section("sec-1")
+                .parent("in-section")
+                .child_order(40)
+                .build(),
+            done,
+        ]);
+
+        assert_eq!(
+            sibling_child_order(&cache, "proj-1", Some("sec-1"), None, false),
+            5
+        );
+        assert_eq!(
+            sibling_child_order(&cache, "proj-1", Some("sec-1"), None, true),
+            3
+        );
+        // Subtasks are ordered among their parent's children
+        assert_eq!(
+            sibling_child_order(&cache, "proj-1", None, Some("in-section"), false),
+            41
+        );
+        // Other projects don't count
+        assert_eq!(sibling_child_order(&cache, "proj-2", None, None, true), 1);
+    }
+
+    fn make_manager_with_projects() -> (SyncManager, tempfile::TempDir) {
+        make_manager_at("http://localhost")
+    }
+
+    fn make_manager_at(base_url: &str) -> (SyncManager, tempfile::TempDir) {
+        let mut cache = make_cache(vec![]);
+        cache.projects = vec![
+            project("inbox-1", "Inbox").inbox().build(),
+            project("errands-1", "Errands").build(),
+        ];
+        cache.sections = vec![todoist_api_rs::sync::Section {
+            id: "sec-1".to_string(),
+            name: "Shops".to_string(),
+            project_id: "errands-1".to_string(),
+            section_order: 0,
+            is_collapsed: false,
+            is_deleted: false,
+            is_archived: false,
+            archived_at: None,
+            added_at: None,
+            updated_at: None,
+        }];
+        cache.rebuild_indexes();
+        test_support::sync_manager(&cache, base_url)
+    }
+
+    #[tokio::test]
+    async fn test_default_project_used_when_configured() {
+        let (mut manager, _dir) = make_manager_with_projects();
+        let project_id = default_project_id(&mut manager, Some("errands"))
+            .await
+            .unwrap();
+        assert_eq!(project_id, ("errands-1".to_string(), None));
+    }
+
+    #[tokio::test]
+    async fn test_default_project_falls_back_to_inbox_when_missing() {
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        // The cache miss triggers a sync, which doesn't bring the project back
+        let server = MockServer::start().await;
+        Mock::given(wiremock::matchers::any())
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "sync_token": "after_sync",
+                "full_sync": false
+            })))
+            .mount(&server)
+            .await;
+        let (mut manager, _dir) = make_manager_at(&server.uri());
+
+        let (project_id, warning) = default_project_id(&mut manager, Some("Archived Stuff"))
+            .await
+            .unwrap();
+        assert_eq!(project_id, "inbox-1");
+        let warning = warning.expect("a missing default project should warn");
+        assert!(
+            warning.starts_with("Warning: add_default_project 'Archived Stuff' not usable"),
+            "unexpected warning: {warning}"
+        );
+        assert!(warning.ends_with("adding to Inbox."));
+    }
+
+    #[tokio::test]
+    async fn test_default_project_falls_back_to_inbox_when_unset() {
+        let (mut manager, _dir) = make_manager_with_projects();
+        let project_id = default_project_id(&mut manager, None).await.unwrap();
+        assert_eq!(project_id, ("inbox-1".to_string(), None));
+    }
+
+    #[tokio::test]
+    async fn test_default_project_finds_unflagged_inbox_by_name() {
+        let mut cache = make_cache(vec![]);
+        cache.projects = vec![
+            project("errands-1", "Errands").build(),
+            project("inbox-1", "Inbox").build(),
+        ];
+        cache.rebuild_indexes();
+        let (mut manager, _dir) = test_support::sync_manager(&cache, "http://localhost");
+
+        let (project_id, _) = default_project_id(&mut manager, None).await.unwrap();
+        assert_eq!(project_id, "inbox-1");
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_resolves_names_without_requests() {
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(wiremock::matchers::any())
+            .respond_with(ResponseTemplate::new(500))
+            .expect(0)
+            .mount(&server)
+            .await;
+        let (mut manager, _dir) = make_manager_at(&server.uri());
+
+        let mut opts = parse_options("Buy stamps @errand p2 #errands");
+        opts.section = Some("shops".to_string());
+        opts.due = Some("tomorrow".to_string());
+        opts.dry_run = true;
+        let opts = merge_quick_add_tokens(&opts).unwrap();
+
+        let (args, project_id) = build_item_args(&mut manager, &opts, None, true)
+            .await
+            .unwrap();
+        assert_eq!(project_id, "errands-1");
+        assert_eq!(args["section_id"], "sec-1");
+        assert_eq!(args["priority"], 3);
+        assert_eq!(args["labels"], serde_json::json!(["errand"]));
+
+        let json = dry_run_json(manager.cache(), &args, None);
+        assert_eq!(json["dry_run"], true);
+        assert_eq!(json["project_name"], "Errands");
+        assert_eq!(json["section_name"], "Shops");
+        assert_eq!(json["item_add"]["content"], "Buy stamps");
+        assert!(json["reminder_add"].is_null());
+
+        let preview = format_dry_run(manager.cache(), &opts, &args);
+        assert!(preview.starts_with("Would create: Buy stamps\n"));
+        assert!(preview.contains("  Project: Errands (errands-1)\n"));
+        assert!(preview.contains("  Section: Shops (sec-1)\n"));
+        assert!(preview.contains("  Labels: errand\n"));
+        assert!(preview.contains("  Priority: p2\n"));
+        assert!(preview.contains("  Due: tomorrow\n"));
+
+        server.verify().await;
+    }
+
+    #[tokio::test]
+    async fn test_section_from_other_project_rejected_before_sending() {
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(wiremock::matchers::any())
+            .respond_with(ResponseTemplate::new(500))
+            .expect(0)
+            .mount(&server)
+            .await;
+        let (mut manager, _dir) = make_manager_at(&server.uri());
+
+        for section in ["Shops", "sec-1"] {
+            let mut opts = parse_options("Buy stamps");
+            opts.project = Some("Inbox".to_string());
+            opts.section = Some(section.to_string());
+
+            let err = build_item_args(&mut manager, &opts, None, true)
+                .await
+                .unwrap_err()
+                .to_string();
+            assert!(
+                err.contains("Section 'Shops' is in project 'Errands', not 'Inbox'"),
+                "unexpected error: {err}"
+            );
+            assert!(err.contains("--project \"Errands\""));
+        }
+
+        server.verify().await;
+    }
+
+    #[tokio::test]
+    async fn test_section_in_matching_project_accepted() {
+        let (mut manager, _dir) = make_manager_with_projects();
+        let mut opts = parse_options("Buy stamps");
+        opts.project = Some("Errands".to_string());
+        opts.section = Some("sec-1".to_string());
+
+        let (args, project_id) = build_item_args(&mut manager, &opts, None, true)
+            .await
+            .unwrap();
+        assert_eq!(project_id, "errands-1");
+        assert_eq!(args["section_id"], "sec-1");
+    }
+
+    #[tokio::test]
+    async fn test_at_bottom_orders_among_resolved_parent_children() {
+        let mut cache = make_cache(vec![
+            item("parent-abc123", "parent-abc123")
+                .project("errands-1")
+                .child_order(1)
+                .build(),
+            item("child-1", "child-1")
+                .project("errands-1")
+                .parent("parent-abc123")
+                .child_order(4)
+                .build(),
+            item("child-2", "child-2")
+                .project("errands-1")
+                .parent("parent-abc123")
+                .child_order(7)
+                .build(),
+            // Inbox tasks must not affect the subtask's order
+            item("inbox-task", "inbox-task")
+                .project("inbox-1")
+                .child_order(20)
+                .build(),
+        ]);
+        cache.projects = vec![
+            project("inbox-1", "Inbox").inbox().build(),
+            project("errands-1", "Errands").build(),
+        ];
+        cache.rebuild_indexes();
+        let (mut manager, _dir) = test_support::sync_manager(&cache, "http://localhost");
+
+        let mut opts = parse_options("Pack bags");
+        opts.parent = Some("parent-abc".to_string());
+        opts.at_bottom = true;
+
+        let (args, _) = build_item_args(&mut manager, &opts, None, true)
+            .await
+            .unwrap();
+        assert_eq!(args["parent_id"], "parent-abc123");
+        assert_eq!(args["child_order"], 8);
+    }
+
+    #[tokio::test]
+    async fn test_create_labels_batches_label_add_before_item_add() {
+        let (mut manager, _dir) = make_manager_with_projects();
+        let mut opts = parse_options("Buy stamps");
+        opts.labels = vec!["errand".to_string()];
+        opts.create_labels = true;
+
+        let (args, _) = build_item_args(&mut manager, &opts, None, true)
+            .await
+            .unwrap();
+        let label_commands = missing_label_commands(manager.cache(), &opts.labels, true, true);
+        let (commands, reminder_temp_id) =
+            build_add_commands(label_commands, "item-temp", args, None);
+
+        assert_eq!(commands.len(), 2);
+        assert_eq!(commands[0].command_type, SyncCommandType::LabelAdd);
+        assert_eq!(commands[0].args["name"], "errand");
+        assert_eq!(commands[1].command_type, SyncCommandType::ItemAdd);
+        assert_eq!(commands[1].temp_id.as_deref(), Some("item-temp"));
+        assert_eq!(commands[1].args["labels"], serde_json::json!(["errand"]));
+        assert!(reminder_temp_id.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_missing_labels_not_created_without_flag() {
+        let (manager, _dir) = make_manager_with_projects();
+        let labels = vec!["errand".to_string()];
+        let label_commands = missing_label_commands(manager.cache(), &labels, false, true);
+
+        let (commands, _) =
+            build_add_commands(label_commands, "item-temp", serde_json::json!({}), None);
+        assert_eq!(commands.len(), 1);
+        assert_eq!(commands[0].command_type, SyncCommandType::ItemAdd);
+    }
 }
@@ -0,0 +1,541 @@
+//! Cache maintenance commands.
+//!
+//! `td cache dedup` finds active tasks with the same (trimmed, case-folded)
+//! content in the same project. With `--dry-run` it only reports them;
+//! otherwise it keeps the oldest task in each group and deletes the rest via
+//! the Sync API's `item_delete` command.
+//!
+//! `td cache info` reports where the cache lives, its size on disk, when it
+//! was last synced, and how many resources it holds. It only reads the cache
+//! file and never touches the network.
+
+use std::io::IsTerminal;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use todoist_api_rs::sync::{Item, SyncCommand, SyncCommandType};
+use todoist_cache_rs::{Cache, CacheStore};
+
+use super::{
+    confirm_destructive_operation, ensure_fresh, BulkItem, CommandContext, CommandError,
+    ConfirmResult, Result,
+};
+use crate::output::helpers::{csv_row, format_fields_csv};
+use crate::output::OutputFormat;
+
+/// Options for the cache dedup command.
+#[derive(Debug)]
+pub struct CacheDedupOptions {
+    /// Only report duplicates, don't delete anything.
+    pub dry_run: bool,
+    /// Skip the confirmation prompt.
+    pub force: bool,
+}
+
+/// A group of duplicate tasks: the one to keep and the extras to delete.
+#[derive(Debug)]
+pub struct DedupGroup<'a> {
+    /// The task that is kept (the oldest in the group).
+    pub keep: &'a Item,
+    /// The duplicates to delete.
+    pub remove: Vec<&'a Item>,
+}
+
+/// Plans which duplicates to delete, keeping the oldest task of each group.
+///
+/// Tasks without a creation time are treated as newer than those with one;
+/// ties keep the earlier task in cache order.
+fn plan_dedup(cache: &Cache) -> Vec<DedupGroup<'_>> {
+    cache
+        .find_duplicates()
+        .into_iter()
+        .map(|group| {
+            let keep_idx = group
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, item)| (item.added_at.is_none(), item.added_at.clone()))
+                .map(|(idx, _)| idx)
+                .unwrap_or(0);
+            let keep = group[keep_idx];
+            let remove = group
+                .into_iter()
+                .enumerate()
+                .filter(|(idx, _)| *idx != keep_idx)
+                .map(|(_, item)| item)
+                .collect();
+            DedupGroup { keep, remove }
+        })
+        .collect()
+}
+
+/// JSON output for a duplicate group.
+#[derive(Serialize)]
+struct DedupGroupOutput<'a> {
+    project_id: &'a str,
+    content: &'a str,
+    keep: &'a str,
+    duplicates: Vec<&'a str>,
+}
+
+/// JSON output for the dedup command.
+#[derive(Serialize)]
+struct DedupOutput<'a> {
+    dry_run: bool,
+    groups: Vec<DedupGroupOutput<'a>>,
+    deleted: Vec<&'a str>,
+    failed: Vec<&'a str>,
+}
+
+/// Executes the cache dedup command.
+///
+/// # Arguments
+///
+/// * `ctx` - Command context with output settings
+/// * `opts` - Dedup command options
+/// * `token` - API token
+///
+/// # Errors
+///
+/// Returns an error if syncing fails, confirmation is refused, or the API
+/// returns an error.
+pub async fn execute_dedup(
+    ctx: &CommandContext,
+    opts: &CacheDedupOptions,
+    token: &str,
+) -> Result<()> {
+    let mut manager = ctx.sync_manager(token)?;
+
+    // Deleting from a stale plan could remove the wrong copy
+    ensure_fresh(ctx, &mut manager).await?;
+
+    // Snapshot the plan as owned data: deleting mutates the cache
+    let cache = manager.cache().clone();
+    let groups = plan_dedup(&cache);
+
+    if groups.is_empty() {
+        match ctx.output_format {
+            OutputFormat::Json => println!(
+                "{}",
+                format_dedup_json(&groups, opts.dry_run, &[], &[], ctx.compact_json)?
+            ),
+            OutputFormat::Csv => print!("{}", format_dedup_csv(&groups, &[], &[])),
+            OutputFormat::Table if !ctx.quiet => println!("No duplicate tasks found."),
+            OutputFormat::Table => {}
+        }
+        return Ok(());
+    }
+
+    if ctx.output_format == OutputFormat::Table && !ctx.quiet {
+        for group in &groups {
+            let project = cache.breadcrumb(group.keep);
+            let project = if project.is_empty() {
+                group.keep.project_id.as_str()
+            } else {
+                project.as_str()
+            };
+            println!(
+                "{} ({} copies) in {}",
+                group.keep.content,
+                group.remove.len() + 1,
+                project
+            );
+            println!("  keep    {}", short_id(&group.keep.id));
+            for item in &group.remove {
+                println!("  delete  {}", short_id(&item.id));
+            }
+        }
+    }
+
+    if opts.dry_run {
+        match ctx.output_format {
+            OutputFormat::Json => println!(
+                "{}",
+                format_dedup_json(&groups, true, &[], &[], ctx.compact_json)?
+            ),
+            OutputFormat::Csv => print!("{}", format_dedup_csv(&groups, &[], &[])),
+            OutputFormat::Table => {}
+        }
+        return Ok(());
+    }
+
+    let to_delete: Vec<&Item> = groups.iter().flat_map(|g| g.remove.clone()).collect();
+    let items_for_confirm: Vec<BulkItem> = to_delete
+        .iter()
+        .map(|item| BulkItem::from_item(item))
+        .collect();
+
+    match confirm_destructive_operation(
+        "delete",
+        &items_for_confirm,
+        ctx.date_style,
+        ctx.id_style,
+        opts.force,
+        std::io::stdin().is_terminal(),
+    )? {
+        ConfirmResult::Confirmed => {}
+        ConfirmResult::Aborted => {
+            if !ctx.quiet {
+                eprintln!("Aborted.");
+            }
+            return Ok(());
+        }
+    }
+
+    let commands: Vec<SyncCommand> = to_delete
+        .iter()
+        .map(|item| {
+            SyncCommand::new(
+                SyncCommandType::ItemDelete,
+                serde_json::json!({ "id": item.id }),
+            )
+        })
+        .collect();
+    let id_by_uuid: Vec<(String, &str)> = commands
+        .iter()
+        .zip(&to_delete)
+        .map(|(cmd, item)| (cmd.uuid.clone(), item.id.as_str()))
+        .collect();
+    let response = manager.execute_commands(commands).await?;
+
+    let mut failed: Vec<&str> = Vec::new();
+    let mut deleted: Vec<&str> = Vec::new();
+    for (uuid, id) in &id_by_uuid {
+        match response.sync_status.get(uuid) {
+            Some(status) if !status.is_ok() => failed.push(id),
+            _ => deleted.push(id),
+        }
+    }
+
+    match ctx.output_format {
+        OutputFormat::Json => println!(
+            "{}",
+            format_dedup_json(&groups, false, &deleted, &failed, ctx.compact_json)?
+        ),
+        OutputFormat::Csv => print!("{}", format_dedup_csv(&groups, &deleted, &failed)),
+        OutputFormat::Table if !ctx.quiet => {
+            println!("Deleted {} duplicate task(s)", deleted.len());
+            for id in &failed {
+                eprintln!("Failed to delete {}", short_id(id));
+            }
+        }
+        OutputFormat::Table => {}
+    }
+
+    if !failed.is_empty() && deleted.is_empty() {
+        return Err(CommandError::Config(format!(
+            "Failed to delete {} duplicate task(s)",
+            failed.len()
+        )));
+    }
+
+    Ok(())
+}
+
+/// Per-resource counts for the cache info command.
+#[derive(Debug, Serialize, PartialEq, Eq)]
+pub struct CacheCounts {
+    pub items: usize,
+    pub projects: usize,
+    pub sections: usize,
+    pub labels: usize,
+    pub filters: usize,
+    pub reminders: usize,
+    pub notes: usize,
+    pub project_notes: usize,
+    pub collaborators: usize,
+}
+
+/// JSON output for the cache info command.
+#[derive(Debug, Serialize)]
+pub struct CacheInfo {
+    /// Path to the cache file.
+    pub path: String,
+    /// Whether the cache file exists.
+    pub exists: bool,
+    /// Size of the cache file in bytes (0 if missing).
+    pub size_bytes: u64,
+    /// When the cache was last synced.
+    pub last_sync: Option<DateTime<Utc>>,
+    /// When the last full sync happened.
+    pub full_sync_date_utc: Option<DateTime<Utc>>,
+    /// Seconds since the last sync, if the cache has been synced.
+    pub age_seconds: Option<i64>,
+    /// Whether the cache holds an incremental sync token.
+    pub has_sync_token: bool,
+    /// Number of cached resources by type.
+    pub counts: CacheCounts,
+}
+
+/// Reads cache info from `store` without syncing.
+///
+/// A missing cache file is reported with `exists: false` and zero counts.
+fn cache_info(store: &CacheStore, now: DateTime<Utc>) -> Result<CacheInfo> {
+    let path = store.path();
+    let size_bytes = match std::fs::metadata(path) {
+        Ok(meta) => meta.len(),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => 0,
+        Err(e) => return Err(CommandError::Io(e)),
+    };
+    let cache = store.try_load()?;
+    let exists = cache.is_some();
+    let cache = cache.unwrap_or_default();
+
+    Ok(CacheInfo {
+        path: path.display().to_string(),
+        exists,
+        size_bytes,
+        last_sync: cache.last_sync,
+        full_sync_date_utc: cache.full_sync_date_utc,
+        age_seconds: cache.last_sync.map(|at| (now - at).num_seconds()),
+        has_sync_token: !cache.needs_full_sync(),
+        counts: CacheCounts {
+            items: cache.items.iter().filter(|i| !i.is_deleted).count(),
+            projects: cache.projects.iter().filter(|p| !p.is_deleted).count(),
+            sections: cache.sections.iter().filter(|s| !s.is_deleted).count(),
+            labels: cache.labels.iter().filter(|l| !l.is_deleted).count(),
+            filters: cache.filters.iter().filter(|f| !f.is_deleted).count(),
+            reminders: cache.reminders.iter().filter(|r| !r.is_deleted).count(),
+            notes: cache.notes.iter().filter(|n| !n.is_deleted).count(),
+            project_notes: cache.project_notes.iter().filter(|n| !n.is_deleted).count(),
+            collaborators: cache.collaborators.len(),
+        },
+    })
+}
+
+/// Executes the cache info command.
+///
+/// # Errors
+///
+/// Returns an error if the cache path cannot be determined or the cache file
+/// cannot be read.
+pub fn execute_info(ctx: &CommandContext) -> Result<()> {
+    let info = cache_info(&ctx.cache_store()?, Utc::now())?;
+
+    match ctx.output_format {
+        OutputFormat::Json => {
+            println!("{}", crate::output::to_json(&info, ctx.compact_json)?);
+            return Ok(());
+        }
+        OutputFormat::Csv => {
+            print!("{}", format_fields_csv(&info)?);
+            return Ok(());
+        }
+        OutputFormat::Table => {}
+    }
+    if ctx.quiet {
+        return Ok(());
+    }
+
+    println!("Path:        {}", info.path);
+    if !info.exists {
+        println!("Cache file does not exist. Run 'td sync' to create it.");
+        return Ok(());
+    }
+    println!("Size:        {} bytes", info.size_bytes);
+    match info.last_sync {
+        Some(at) => println!(
+            "Last sync:   {} ({}s ago)",
+            at.to_rfc3339(),
+            info.age_seconds.unwrap_or_default()
+        ),
+        None => println!("Last sync:   never"),
+    }
+    if let Some(at) = info.full_sync_date_utc {
+        println!("Full sync:   {}", at.to_rfc3339());
+    }
+    println!(
+        "Sync token:  {}",
+        if info.has_sync_token { "yes" } else { "no" }
+    );
+    let counts = &info.counts;
+    println!("Tasks:       {}", counts.items);
+    println!("Projects:    {}", counts.projects);
+    println!("Sections:    {}", counts.sections);
+    println!("Labels:      {}", counts.labels);
+    println!("Filters:     {}", counts.filters);
+    println!("Reminders:   {}", counts.reminders);
+    println!("Comments:    {}", counts.notes + counts.project_notes);
+    println!("Members:     {}", counts.collaborators);
+
+    Ok(())
+}
+
+fn short_id(id: &str) -> &str {
+    &id[..6.min(id.len())]
+}
+
+/// Formats the dedup plan and results as JSON.
+fn format_dedup_json(
+    groups: &[DedupGroup],
+    dry_run: bool,
+    deleted: &[&str],
+    failed: &[&str],
+    compact: bool,
+) -> Result<String> {
+    let output = DedupOutput {
+        dry_run,
+        groups: groups
+            .iter()
+            .map(|g| DedupGroupOutput {
+                project_id: &g.keep.project_id,
+                content: &g.keep.content,
+                keep: &g.keep.id,
+                duplicates: g.remove.iter().map(|i| i.id.as_str()).collect(),
+            })
+            .collect(),
+        deleted: deleted.to_vec(),
+        failed: failed.to_vec(),
+    };
+    Ok(crate::output::to_json(&output, compact)?)
+}
+
+/// Formats the dedup plan as CSV, one row per duplicate. `status` is
+/// `deleted` or `failed` once deletion ran, and `duplicate` before that.
+fn format_dedup_csv(groups: &[DedupGroup], deleted: &[&str], failed: &[&str]) -> String {
+    let mut output = csv_row(["project_id", "content", "keep", "duplicate", "status"]);
+    for group in groups {
+        for item in &group.remove {
+            let status = if deleted.contains(&item.id.as_str()) {
+                "deleted"
+            } else if failed.contains(&item.id.as_str()) {
+                "failed"
+            } else {
+                "duplicate"
+            };
+            output.push_str(&csv_row([
+                group.keep.project_id.as_str(),
+                &group.keep.content,
+                &group.keep.id,
+                &item.id,
+                status,
+            ]));
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::item;
+
+    fn make_cache(items: Vec<Item>) -> Cache {
+        let mut cache = Cache::new();
+        cache.items = items;
+        cache
+    }
+
+    #[test]
+    fn test_plan_dedup_keeps_oldest() {
+        let cache = make_cache(vec![
+            item("new", "Buy milk")
+                .added_at("2025-03-01T10:00:00Z")
+                .build(),
+            item("undated", "buy milk").build(),
+            item("old", "Buy milk ")
+                .added_at("2025-01-01T10:00:00Z")
+                .build(),
+            item("other", "Buy milk").project("proj-2").build(),
+        ]);
+
+        let groups = plan_dedup(&cache);
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].keep.id, "old");
+        let removed: Vec<&str> = groups[0].remove.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(removed, vec!["new", "undated"]);
+    }
+
+    #[test]
+    fn test_plan_dedup_without_dates_keeps_first() {
+        let cache = make_cache(vec![
+            item("a", "Call mom").build(),
+            item("b", "CALL MOM").build(),
+        ]);
+
+        let groups = plan_dedup(&cache);
+        assert_eq!(groups[0].keep.id, "a");
+        assert_eq!(groups[0].remove[0].id, "b");
+    }
+
+    #[test]
+    fn test_format_dedup_json_dry_run() {
+        let cache = make_cache(vec![
+            item("a", "Call mom").build(),
+            item("b", "call mom").build(),
+        ]);
+        let groups = plan_dedup(&cache);
+
+        let json = format_dedup_json(&groups, true, &[], &[], false).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["dry_run"], true);
+        assert_eq!(value["groups"][0]["keep"], "a");
+        assert_eq!(value["groups"][0]["duplicates"][0], "b");
+        assert!(value["deleted"].as_array().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_format_dedup_csv_reports_status_per_duplicate() {
+        let cache = make_cache(vec![
+            item("a", "Call mom").build(),
+            item("b", "call mom").build(),
+            item("c", "CALL MOM").build(),
+        ]);
+        let groups = plan_dedup(&cache);
+
+        assert_eq!(
+            format_dedup_csv(&groups, &["b"], &["c"]),
+            "project_id,content,keep,duplicate,status\n\
+             proj-1,Call mom,a,b,deleted\n\
+             proj-1,Call mom,a,c,failed\n"
+        );
+    }
+
+    #[test]
+    fn test_cache_info_reports_counts_and_file_size() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = CacheStore::with_path(dir.path().join("cache.json"));
+
+        let deleted = item("gone", "Old task").deleted().build();
+        let mut cache = make_cache(vec![
+            item("a", "Call mom").build(),
+            item("b", "Buy milk").build(),
+            deleted,
+        ]);
+        let synced_at = "2025-01-15T10:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        cache.sync_token = "token-abc".to_string();
+        cache.last_sync = Some(synced_at);
+        store.save(&cache).unwrap();
+
+        let now = synced_at + chrono::Duration::minutes(3);
+        let info = cache_info(&store, now).unwrap();
+
+        assert!(info.exists);
+        assert_eq!(
+            info.size_bytes,
+            std::fs::metadata(store.path()).unwrap().len()
+        );
+        assert!(info.size_bytes > 0);
+        assert_eq!(info.counts.items, 2);
+        assert_eq!(info.counts.projects, 0);
+        assert_eq!(info.last_sync, Some(synced_at));
+        assert_eq!(info.age_seconds, Some(180));
+        assert!(info.has_sync_token);
+
+        let value = serde_json::to_value(&info).unwrap();
+        assert_eq!(value["counts"]["items"], 2);
+        assert_eq!(value["size_bytes"], info.size_bytes);
+    }
+
+    #[test]
+    fn test_cache_info_missing_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = CacheStore::with_path(dir.path().join("missing.json"));
+
+        let info = cache_info(&store, Utc::now()).unwrap();
+        assert!(!info.exists);
+        assert_eq!(info.size_bytes, 0);
+        assert_eq!(info.counts.items, 0);
+        assert!(!info.has_sync_token);
+        assert!(!store.path().exists());
+    }
+}
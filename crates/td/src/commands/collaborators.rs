@@ -2,10 +2,12 @@
 //!
 //! Lists collaborators for a shared project.
 
-use todoist_api_rs::client::TodoistClient;
-use todoist_cache_rs::{CacheStore, SyncManager};
+use super::{ensure_fresh, CommandContext, CommandError, Result};
+use crate::output::helpers::csv_row;
+use crate::output::OutputFormat;
 
-use super::{CommandContext, CommandError, Result};
+/// CSV columns, matching the JSON output's fields.
+const CSV_HEADER: [&str; 4] = ["id", "name", "email", "status"];
 
 /// Options for the collaborators command.
 #[derive(Debug)]
@@ -16,16 +18,9 @@ pub struct CollaboratorsOptions {
 
 /// Executes the collaborators command.
 pub async fn execute(ctx: &CommandContext, opts: &CollaboratorsOptions, token: &str) -> Result<()> {
-    let client = TodoistClient::new(token)?;
-    let store = CacheStore::new()?;
-    let mut manager = SyncManager::new(client, store)?;
+    let mut manager = ctx.sync_manager(token)?;
 
-    if ctx.sync_first {
-        if ctx.verbose {
-            eprintln!("Syncing with Todoist...");
-        }
-        manager.sync().await?;
-    }
+    ensure_fresh(ctx, &mut manager).await?;
 
     let cache = manager.cache();
 
@@ -47,8 +42,10 @@ pub async fn execute(ctx: &CommandContext, opts: &CollaboratorsOptions, token: &
         .collect();
 
     if active_states.is_empty() {
-        if ctx.json_output {
+        if ctx.output_format.is_json() {
             println!("{{\"collaborators\": []}}");
+        } else if ctx.output_format == OutputFormat::Csv {
+            print!("{}", csv_row(CSV_HEADER));
         } else if !ctx.quiet {
             println!(
                 "No collaborators found for project \"{}\" — it may be a personal project.",
@@ -58,7 +55,7 @@ pub async fn execute(ctx: &CommandContext, opts: &CollaboratorsOptions, token: &
         return Ok(());
     }
 
-    if ctx.json_output {
+    if ctx.output_format.is_json() {
         let collabs: Vec<serde_json::Value> = active_states
             .iter()
             .filter_map(|state| {
@@ -77,7 +74,20 @@ pub async fn execute(ctx: &CommandContext, opts: &CollaboratorsOptions, token: &
             })
             .collect();
         let output = serde_json::json!({ "collaborators": collabs });
-        println!("{}", serde_json::to_string_pretty(&output)?);
+        println!("{}", crate::output::to_json(&output, ctx.compact_json)?);
+    } else if ctx.output_format == OutputFormat::Csv {
+        let mut output = csv_row(CSV_HEADER);
+        for state in &active_states {
+            if let Some(collab) = cache.collaborators.iter().find(|c| c.id == state.user_id) {
+                output.push_str(&csv_row([
+                    collab.id.as_str(),
+                    collab.full_name.as_deref().unwrap_or(""),
+                    collab.email.as_deref().unwrap_or(""),
+                    &state.state,
+                ]));
+            }
+        }
+        print!("{output}");
     } else if !ctx.quiet {
         // Table output
         println!("{:<25} {:<30} Status", "Name", "Email");
@@ -3,13 +3,14 @@
 //! Lists and manages comments (notes) via the Sync API.
 //! Uses SyncManager::execute_commands() to automatically update the cache.
 
-use todoist_api_rs::client::TodoistClient;
 use todoist_api_rs::sync::{Note, ProjectNote, SyncCommand, SyncCommandType};
-use todoist_cache_rs::{Cache, CacheStore, SyncManager};
+use todoist_cache_rs::{Cache, SyncManager};
 
-use super::{CommandContext, CommandError, Result};
+use super::{ensure_fresh, CommandContext, CommandError, Result};
 use crate::output::helpers::ID_DISPLAY_LENGTH;
-use crate::output::{format_comments_json, format_comments_table};
+use crate::output::{
+    format_comments_csv, format_comments_json, format_comments_table, OutputFormat,
+};
 
 /// Maximum length for content preview in compact output.
 const CONTENT_PREVIEW_LENGTH: usize = 30;
@@ -33,6 +34,10 @@ pub struct CommentsListOptions {
     pub task: Option<String>,
     /// Filter by project ID.
     pub project: Option<String>,
+    /// List oldest comments first (default is newest first).
+    pub oldest_first: bool,
+    /// Print complete comment bodies instead of truncating them.
+    pub full: bool,
 }
 
 /// A unified comment type that can be either a task note or project note.
@@ -103,17 +108,9 @@ pub async fn execute(ctx: &CommandContext, opts: &CommentsListOptions, token: &s
     }
 
     // Initialize sync manager
-    let client = TodoistClient::new(token)?;
-    let store = CacheStore::new()?;
-    let mut manager = SyncManager::new(client, store)?;
+    let mut manager = ctx.sync_manager(token)?;
 
-    // Only sync if explicitly requested with --sync flag
-    if ctx.sync_first {
-        if ctx.verbose {
-            eprintln!("Syncing with Todoist...");
-        }
-        manager.sync().await?;
-    }
+    ensure_fresh(ctx, &mut manager).await?;
 
     let cache = manager.cache();
 
@@ -132,7 +129,12 @@ pub async fn execute(ctx: &CommandContext, opts: &CommentsListOptions, token: &s
     };
 
     // Get comments
-    let comments = filter_comments(cache, task_id.as_deref(), project_id.as_deref());
+    let comments = filter_comments(
+        cache,
+        task_id.as_deref(),
+        project_id.as_deref(),
+        opts.oldest_first,
+    );
 
     // Get parent name for display
     let parent_name = if let Some(ref tid) = task_id {
@@ -152,12 +154,18 @@ pub async fn execute(ctx: &CommandContext, opts: &CommentsListOptions, token: &s
     };
 
     // Output
-    if ctx.json_output {
-        let output = format_comments_json(&comments, cache)?;
-        println!("{output}");
-    } else if !ctx.quiet {
-        let output = format_comments_table(&comments, parent_name.as_deref(), ctx.use_colors);
-        print!("{output}");
+    match ctx.output_format {
+        OutputFormat::Json => {
+            let output = format_comments_json(&comments, cache, ctx.compact_json)?;
+            println!("{output}");
+        }
+        OutputFormat::Csv => print!("{}", format_comments_csv(&comments, cache)),
+        OutputFormat::Table if !ctx.quiet => {
+            let output =
+                format_comments_table(&comments, parent_name.as_deref(), opts.full, ctx.use_colors);
+            print!("{output}");
+        }
+        OutputFormat::Table => {}
     }
 
     Ok(())
@@ -200,6 +208,18 @@ fn resolve_task_id(cache: &Cache, task: &str) -> Result<String> {
     Err(CommandError::Config(format!("Task not found: {task}")))
 }
 
+/// Resolves the `--task` argument of `comments add` to a task ID and content.
+///
+/// Accepts an exact ID, an ID prefix, or a content substring (syncing once if
+/// nothing matches). An ambiguous argument lists the candidate tasks.
+async fn resolve_comment_task(manager: &mut SyncManager, task: &str) -> Result<(String, String)> {
+    let item = manager
+        .resolve_item_arg(task, None)
+        .await
+        .map_err(|e| CommandError::Config(e.to_string()))?;
+    Ok((item.id.clone(), item.content.clone()))
+}
+
 /// Resolves a project name or ID to a project ID.
 fn resolve_project_id(cache: &Cache, project: &str) -> Result<String> {
     // First try exact ID match
@@ -304,22 +324,15 @@ pub async fn execute_add(
     }
 
     // Initialize sync manager (loads cache from disk)
-    let client = TodoistClient::new(token)?;
-    let store = CacheStore::new()?;
-    let mut manager = SyncManager::new(client, store)?;
+    let mut manager = ctx.sync_manager(token)?;
 
     // Resolve task/project ID and get parent name before mutation
     let (is_task_comment, parent_id, parent_name) = {
-        let cache = manager.cache();
         if let Some(ref task) = opts.task {
-            let task_id = resolve_task_id(cache, task)?;
-            let task_name = cache
-                .items
-                .iter()
-                .find(|i| i.id == task_id)
-                .map(|i| i.content.clone());
-            (true, task_id, task_name)
+            let (task_id, task_name) = resolve_comment_task(&mut manager, task).await?;
+            (true, task_id, Some(task_name))
         } else if let Some(ref project) = opts.project {
+            let cache = manager.cache();
             let project_id = resolve_project_id(cache, project)?;
             let project_name = cache
                 .projects
@@ -384,8 +397,8 @@ pub async fn execute_add(
     };
 
     // Output
-    if ctx.json_output {
-        let output = crate::output::format_created_comment(&result)?;
+    if ctx.output_format.is_json() {
+        let output = crate::output::format_created_comment(&result, ctx.compact_json)?;
         println!("{output}");
     } else if !ctx.quiet {
         let parent_type = if result.is_task_comment {
@@ -423,8 +436,12 @@ pub async fn execute_add(
 /// Options for the comments edit command.
 #[derive(Debug)]
 pub struct CommentsEditOptions {
-    /// Comment ID to edit.
-    pub comment_id: String,
+    /// Comment ID to edit (full ID or prefix).
+    pub comment_id: Option<String>,
+    /// Task whose comment to edit (used with `index`).
+    pub task: Option<String>,
+    /// 1-based index of the comment on `task`, oldest first.
+    pub index: Option<usize>,
     /// New content for the comment.
     pub content: String,
 }
@@ -461,14 +478,18 @@ pub async fn execute_edit(
     token: &str,
 ) -> Result<()> {
     // Initialize sync manager (loads cache from disk)
-    let client = TodoistClient::new(token)?;
-    let store = CacheStore::new()?;
-    let mut manager = SyncManager::new(client, store)?;
+    let mut manager = ctx.sync_manager(token)?;
 
     // Find the comment by ID and extract owned data before mutation
     let (comment_id, is_task_comment, parent_id, parent_name) = {
         let cache = manager.cache();
-        resolve_comment(cache, &opts.comment_id)?
+        let target = resolve_comment_target(
+            cache,
+            opts.comment_id.as_deref(),
+            opts.task.as_deref(),
+            opts.index,
+        )?;
+        resolve_comment(cache, &target)?
     };
 
     // Build the note_update command
@@ -505,8 +526,8 @@ pub async fn execute_edit(
     };
 
     // Output
-    if ctx.json_output {
-        let output = crate::output::format_edited_comment(&result)?;
+    if ctx.output_format.is_json() {
+        let output = crate::output::format_edited_comment(&result, ctx.compact_json)?;
         println!("{output}");
     } else if !ctx.quiet {
         let parent_type = if result.is_task_comment {
@@ -544,8 +565,12 @@ pub async fn execute_edit(
 /// Options for the comments delete command.
 #[derive(Debug)]
 pub struct CommentsDeleteOptions {
-    /// Comment ID to delete.
-    pub comment_id: String,
+    /// Comment ID to delete (full ID or prefix).
+    pub comment_id: Option<String>,
+    /// Task whose comment to delete (used with `index`).
+    pub task: Option<String>,
+    /// 1-based index of the comment on `task`, oldest first.
+    pub index: Option<usize>,
     /// Skip confirmation.
     pub force: bool,
 }
@@ -582,14 +607,18 @@ pub async fn execute_delete(
     token: &str,
 ) -> Result<()> {
     // Initialize sync manager (loads cache from disk)
-    let client = TodoistClient::new(token)?;
-    let store = CacheStore::new()?;
-    let mut manager = SyncManager::new(client, store)?;
+    let mut manager = ctx.sync_manager(token)?;
 
     // Find the comment by ID and extract owned data before mutation
     let (comment_id, is_task_comment, parent_id, parent_name, content_preview) = {
         let cache = manager.cache();
-        let (c_id, is_task, p_id, p_name) = resolve_comment(cache, &opts.comment_id)?;
+        let target = resolve_comment_target(
+            cache,
+            opts.comment_id.as_deref(),
+            opts.task.as_deref(),
+            opts.index,
+        )?;
+        let (c_id, is_task, p_id, p_name) = resolve_comment(cache, &target)?;
         let comment_content = get_comment_content(cache, &c_id);
         let preview = if comment_content.len() > CONTENT_DISPLAY_LENGTH {
             format!("{}...", &comment_content[..CONTENT_DISPLAY_LENGTH - 3])
@@ -649,8 +678,8 @@ pub async fn execute_delete(
     };
 
     // Output
-    if ctx.json_output {
-        let output = crate::output::format_deleted_comment(&result)?;
+    if ctx.output_format.is_json() {
+        let output = crate::output::format_deleted_comment(&result, ctx.compact_json)?;
         println!("{output}");
     } else if !ctx.quiet {
         let parent_type = if result.is_task_comment {
@@ -687,6 +716,56 @@ fn get_comment_content(cache: &Cache, comment_id: &str) -> String {
     String::new()
 }
 
+/// Determines which comment an edit/delete targets.
+///
+/// Either a comment ID (or prefix) is given directly, or a task plus a 1-based
+/// index into that task's comments ordered by `posted_at` (oldest first).
+/// Returns the comment ID or prefix to pass to [`resolve_comment`].
+fn resolve_comment_target(
+    cache: &Cache,
+    comment_id: Option<&str>,
+    task: Option<&str>,
+    index: Option<usize>,
+) -> Result<String> {
+    if let Some(id) = comment_id {
+        return Ok(id.to_string());
+    }
+
+    let (Some(task), Some(index)) = (task, index) else {
+        return Err(CommandError::Config(
+            "Specify a comment ID, or --task with --index".to_string(),
+        ));
+    };
+
+    let task_id = resolve_task_id(cache, task)?;
+    let mut notes: Vec<_> = cache
+        .notes
+        .iter()
+        .filter(|n| !n.is_deleted && n.item_id == task_id)
+        .collect();
+    notes.sort_by(|a, b| {
+        let a_time = a.posted_at.as_deref().unwrap_or("");
+        let b_time = b.posted_at.as_deref().unwrap_or("");
+        a_time.cmp(b_time)
+    });
+
+    if index == 0 || index > notes.len() {
+        let noun = if notes.len() == 1 {
+            "comment"
+        } else {
+            "comments"
+        };
+        return Err(CommandError::Config(format!(
+            "Comment index {} is out of range: task has {} {}",
+            index,
+            notes.len(),
+            noun
+        )));
+    }
+
+    Ok(notes[index - 1].id.clone())
+}
+
 /// Resolves a comment ID from either task notes or project notes.
 /// Returns (full_id, is_task_comment, parent_id, parent_name).
 fn resolve_comment(
@@ -791,7 +870,12 @@ fn resolve_comment(
 }
 
 /// Filters comments based on task_id or project_id.
-fn filter_comments(cache: &Cache, task_id: Option<&str>, project_id: Option<&str>) -> Vec<Comment> {
+fn filter_comments(
+    cache: &Cache,
+    task_id: Option<&str>,
+    project_id: Option<&str>,
+    oldest_first: bool,
+) -> Vec<Comment> {
     let mut comments = Vec::new();
 
     // Get task comments if filtering by task
@@ -812,19 +896,31 @@ fn filter_comments(cache: &Cache, task_id: Option<&str>, project_id: Option<&str
         }
     }
 
-    // Sort by posted_at (newest first)
+    sort_comments(&mut comments, oldest_first);
+    comments
+}
+
+/// Sorts comments by `posted_at`, newest first unless `oldest_first`.
+///
+/// Comments without a timestamp always sort last; ties are broken by ID so
+/// the order is deterministic.
+fn sort_comments(comments: &mut [Comment], oldest_first: bool) {
     comments.sort_by(|a, b| {
-        let a_time = a.posted_at().unwrap_or("");
-        let b_time = b.posted_at().unwrap_or("");
-        b_time.cmp(a_time)
+        let by_time = match (a.posted_at(), b.posted_at()) {
+            (Some(a_time), Some(b_time)) if oldest_first => a_time.cmp(b_time),
+            (Some(a_time), Some(b_time)) => b_time.cmp(a_time),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        };
+        by_time.then_with(|| a.id().cmp(b.id()))
     });
-
-    comments
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_support;
 
     #[test]
     fn test_comments_list_options_defaults() {
@@ -899,6 +995,7 @@ mod tests {
         let opts = CommentsListOptions {
             task: Some("task-123".to_string()),
             project: None,
+            ..Default::default()
         };
 
         assert_eq!(opts.task, Some("task-123".to_string()));
@@ -910,6 +1007,7 @@ mod tests {
         let opts = CommentsListOptions {
             task: None,
             project: Some("project-456".to_string()),
+            ..Default::default()
         };
 
         assert!(opts.task.is_none());
@@ -959,7 +1057,7 @@ mod tests {
     #[test]
     fn test_filter_comments_by_task() {
         let cache = make_test_cache();
-        let comments = filter_comments(&cache, Some("task-1"), None);
+        let comments = filter_comments(&cache, Some("task-1"), None, false);
 
         assert_eq!(comments.len(), 2);
         // Should be sorted newest first
@@ -967,10 +1065,74 @@ mod tests {
         assert_eq!(comments[1].content(), "First comment");
     }
 
+    fn make_note(id: &str, content: &str, posted_at: Option<&str>) -> Comment {
+        Comment::Task(Note {
+            id: id.to_string(),
+            item_id: "task-1".to_string(),
+            content: content.to_string(),
+            posted_at: posted_at.map(String::from),
+            is_deleted: false,
+            posted_uid: None,
+            file_attachment: None,
+        })
+    }
+
+    fn comment_ids(comments: &[Comment]) -> Vec<&str> {
+        comments.iter().map(Comment::id).collect()
+    }
+
+    #[test]
+    fn test_filter_comments_oldest_first() {
+        let cache = make_test_cache();
+        let comments = filter_comments(&cache, Some("task-1"), None, true);
+
+        assert_eq!(comments[0].content(), "First comment");
+        assert_eq!(comments[1].content(), "Second comment");
+    }
+
+    #[test]
+    fn test_sort_comments_untimed_last_in_both_orders() {
+        let mut comments = vec![
+            make_note("n-untimed-b", "b", None),
+            make_note("n-new", "new", Some("2025-01-27T10:00:00Z")),
+            make_note("n-untimed-a", "a", None),
+            make_note("n-old", "old", Some("2025-01-25T10:00:00Z")),
+        ];
+
+        sort_comments(&mut comments, false);
+        assert_eq!(
+            comment_ids(&comments),
+            vec!["n-new", "n-old", "n-untimed-a", "n-untimed-b"]
+        );
+
+        sort_comments(&mut comments, true);
+        assert_eq!(
+            comment_ids(&comments),
+            vec!["n-old", "n-new", "n-untimed-a", "n-untimed-b"]
+        );
+    }
+
+    #[test]
+    fn test_format_comments_table_full_keeps_all_lines() {
+        let long_line = "x".repeat(80);
+        let body = format!("First line\nSecond line\n{long_line}");
+        let comments = vec![make_note("note-1", &body, Some("2025-01-26T10:00:00Z"))];
+
+        let truncated = format_comments_table(&comments, None, false, false);
+        assert!(truncated.contains("First line"));
+        assert!(!truncated.contains("Second line"));
+
+        let full = format_comments_table(&comments, None, true, false);
+        let lines: Vec<&str> = full.lines().collect();
+        assert!(lines[1].ends_with("First line"));
+        assert_eq!(lines[2], format!("{:<30}Second line", ""));
+        assert!(lines[3].ends_with(&long_line));
+    }
+
     #[test]
     fn test_filter_comments_by_project() {
         let cache = make_test_cache();
-        let comments = filter_comments(&cache, None, Some("project-1"));
+        let comments = filter_comments(&cache, None, Some("project-1"), false);
 
         assert_eq!(comments.len(), 1);
         assert_eq!(comments[0].content(), "Project comment");
@@ -981,7 +1143,7 @@ mod tests {
         let mut cache = make_test_cache();
         cache.notes[0].is_deleted = true;
 
-        let comments = filter_comments(&cache, Some("task-1"), None);
+        let comments = filter_comments(&cache, Some("task-1"), None, false);
 
         assert_eq!(comments.len(), 1);
         assert_eq!(comments[0].content(), "Second comment");
@@ -1003,6 +1165,55 @@ mod tests {
         assert!(result.unwrap_err().to_string().contains("Task not found"));
     }
 
+    #[tokio::test]
+    async fn test_resolve_comment_task_by_prefix() {
+        let mut cache = make_test_cache();
+        cache.items.push(make_test_item(
+            "6Jf8VQXxpwv56VQ7",
+            "Write report",
+            "project-1",
+        ));
+        cache.items.push(make_test_item(
+            "7Kg9WRYyqxw67WR8",
+            "Call Alice",
+            "project-1",
+        ));
+        let (mut manager, _dir) = test_support::sync_manager(&cache, "http://localhost");
+
+        let (task_id, task_name) = resolve_comment_task(&mut manager, "6Jf8").await.unwrap();
+        assert_eq!(task_id, "6Jf8VQXxpwv56VQ7");
+        assert_eq!(task_name, "Write report");
+
+        let (task_id, _) = resolve_comment_task(&mut manager, "call alice")
+            .await
+            .unwrap();
+        assert_eq!(task_id, "7Kg9WRYyqxw67WR8");
+    }
+
+    #[tokio::test]
+    async fn test_resolve_comment_task_ambiguous_lists_candidates() {
+        let mut cache = make_test_cache();
+        cache.items.push(make_test_item(
+            "6Jf8VQXxpwv56VQ7",
+            "Write report",
+            "project-1",
+        ));
+        cache.items.push(make_test_item(
+            "6Jf8WRYyqxw67WR8",
+            "Review report",
+            "project-1",
+        ));
+        let (mut manager, _dir) = test_support::sync_manager(&cache, "http://localhost");
+
+        let err = resolve_comment_task(&mut manager, "6Jf8")
+            .await
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Ambiguous"));
+        assert!(err.contains("Write report"));
+        assert!(err.contains("Review report"));
+    }
+
     #[test]
     fn test_resolve_project_id_exact_match() {
         let cache = make_test_cache();
@@ -1131,11 +1342,13 @@ mod tests {
     #[test]
     fn test_comments_edit_options() {
         let opts = CommentsEditOptions {
-            comment_id: "note-123".to_string(),
+            comment_id: Some("note-123".to_string()),
+            task: None,
+            index: None,
             content: "Updated content".to_string(),
         };
 
-        assert_eq!(opts.comment_id, "note-123");
+        assert_eq!(opts.comment_id.as_deref(), Some("note-123"));
         assert_eq!(opts.content, "Updated content");
     }
 
@@ -1221,6 +1434,47 @@ mod tests {
             .contains("Comment not found"));
     }
 
+    #[test]
+    fn test_resolve_comment_target_by_index() {
+        let cache = make_test_cache();
+
+        // Index is 1-based, oldest first
+        let first = resolve_comment_target(&cache, None, Some("task-1"), Some(1)).unwrap();
+        assert_eq!(first, "note-1");
+        let second = resolve_comment_target(&cache, None, Some("task-1"), Some(2)).unwrap();
+        assert_eq!(second, "note-2");
+    }
+
+    #[test]
+    fn test_resolve_comment_target_skips_deleted() {
+        let mut cache = make_test_cache();
+        cache.notes[0].is_deleted = true;
+
+        let first = resolve_comment_target(&cache, None, Some("task-1"), Some(1)).unwrap();
+        assert_eq!(first, "note-2");
+    }
+
+    #[test]
+    fn test_resolve_comment_target_out_of_range() {
+        let cache = make_test_cache();
+
+        let result = resolve_comment_target(&cache, None, Some("task-1"), Some(3));
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("out of range"));
+        assert!(err.contains("task has 2 comments"));
+
+        assert!(resolve_comment_target(&cache, None, Some("task-1"), Some(0)).is_err());
+    }
+
+    #[test]
+    fn test_resolve_comment_target_prefers_comment_id() {
+        let cache = make_test_cache();
+        let target = resolve_comment_target(&cache, Some("note-2"), None, None).unwrap();
+        assert_eq!(target, "note-2");
+
+        assert!(resolve_comment_target(&cache, None, None, None).is_err());
+    }
+
     #[test]
     fn test_resolve_comment_prefix_match() {
         let mut cache = make_test_cache();
@@ -1250,22 +1504,26 @@ mod tests {
     #[test]
     fn test_comments_delete_options() {
         let opts = CommentsDeleteOptions {
-            comment_id: "note-123".to_string(),
+            comment_id: Some("note-123".to_string()),
+            task: None,
+            index: None,
             force: false,
         };
 
-        assert_eq!(opts.comment_id, "note-123");
+        assert_eq!(opts.comment_id.as_deref(), Some("note-123"));
         assert!(!opts.force);
     }
 
     #[test]
     fn test_comments_delete_options_with_force() {
         let opts = CommentsDeleteOptions {
-            comment_id: "note-456".to_string(),
+            comment_id: Some("note-456".to_string()),
+            task: None,
+            index: None,
             force: true,
         };
 
-        assert_eq!(opts.comment_id, "note-456");
+        assert_eq!(opts.comment_id.as_deref(), Some("note-456"));
         assert!(opts.force);
     }
 
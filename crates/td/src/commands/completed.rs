@@ -0,0 +1,171 @@
+//! Completed command implementation.
+//!
+//! Lists completed tasks still held in the local cache, most recently
+//! completed first. Like other read commands, it syncs first when the cache
+//! is stale or `--sync` is given.
+
+use todoist_api_rs::sync::Item;
+use todoist_cache_rs::Cache;
+
+use super::{ensure_fresh, CommandContext, CommandError, Result};
+use crate::output::{
+    format_completed_items_csv, format_completed_items_json, format_completed_items_table,
+    OutputFormat,
+};
+
+/// Options for the completed command.
+#[derive(Debug)]
+pub struct CompletedOptions {
+    /// Only tasks in this project (name or ID).
+    pub project: Option<String>,
+    /// Limit results.
+    pub limit: u32,
+    /// Show all completed tasks (no limit).
+    pub all: bool,
+}
+
+/// Executes the completed command.
+///
+/// # Arguments
+///
+/// * `ctx` - Command context with output settings
+/// * `opts` - Completed command options
+/// * `token` - API token
+///
+/// # Errors
+///
+/// Returns an error if syncing fails or the project is not in the cache.
+pub async fn execute(ctx: &CommandContext, opts: &CompletedOptions, token: &str) -> Result<()> {
+    let mut manager = ctx.sync_manager(token)?;
+
+    ensure_fresh(ctx, &mut manager).await?;
+
+    let cache = manager.cache();
+    let project_id = match &opts.project {
+        Some(project) => Some(
+            cache
+                .find_project(project)
+                .map(|p| p.id.as_str())
+                .ok_or_else(|| CommandError::Config(format!("Project not found: {project}")))?,
+        ),
+        None => None,
+    };
+
+    let mut items = completed_items(cache, project_id);
+    if !opts.all {
+        items.truncate(opts.limit as usize);
+    }
+
+    match ctx.output_format {
+        OutputFormat::Json => println!(
+            "{}",
+            format_completed_items_json(&items, cache, ctx.compact_json)?
+        ),
+        OutputFormat::Csv => print!("{}", format_completed_items_csv(&items, cache)),
+        OutputFormat::Table if !ctx.quiet => {
+            if items.is_empty() {
+                println!("No completed tasks in the local cache.");
+                println!(
+                    "The cache only keeps tasks completed since the last full sync; \
+                 older completions are on the Todoist server (see 'td stats --server')."
+                );
+            } else {
+                let output =
+                    format_completed_items_table(&items, cache, ctx.use_colors, ctx.id_style);
+                ctx.print_table(&output);
+            }
+            ctx.print_sync_footer(cache);
+        }
+        OutputFormat::Table => {}
+    }
+
+    Ok(())
+}
+
+/// Returns completed tasks in the cache, most recently completed first.
+///
+/// With `project_id`, only tasks directly in that project are returned.
+/// Tasks without a completion time sort last.
+pub fn completed_items<'a>(cache: &'a Cache, project_id: Option<&str>) -> Vec<&'a Item> {
+    let mut completed: Vec<&Item> = match project_id {
+        Some(project_id) => cache.items_in_project(project_id, false),
+        None => cache.items.iter().filter(|i| !i.is_deleted).collect(),
+    };
+    completed.retain(|i| i.checked);
+    completed.sort_by(|a, b| {
+        b.completed_at
+            .is_some()
+            .cmp(&a.completed_at.is_some())
+            .then_with(|| b.completed_at.cmp(&a.completed_at))
+    });
+    completed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{item, project};
+
+    fn make_cache() -> Cache {
+        let done = |id: &str, project_id: &str, completed_at: &str| {
+            item(id, &format!("Task {id}"))
+                .project(project_id)
+                .completed_at(completed_at)
+        };
+        let no_time = item("work-untimed", "Task work-untimed")
+            .project("proj-work")
+            .checked()
+            .build();
+        let deleted = done("work-deleted", "proj-work", "2025-01-16T09:00:00Z")
+            .deleted()
+            .build();
+
+        let mut cache = Cache::new();
+        cache.projects = vec![
+            project("proj-work", "Work").build(),
+            project("proj-home", "Home").build(),
+        ];
+        cache.items = vec![
+            done("work-old", "proj-work", "2025-01-10T09:00:00Z").build(),
+            no_time,
+            item("work-open", "Task work-open")
+                .project("proj-work")
+                .build(),
+            done("home-done", "proj-home", "2025-01-15T09:00:00Z").build(),
+            done("work-new", "proj-work", "2025-01-14T18:30:00Z").build(),
+            deleted,
+        ];
+        cache.rebuild_indexes();
+        cache
+    }
+
+    fn ids(items: Vec<&Item>) -> Vec<&str> {
+        items.into_iter().map(|i| i.id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_completed_items_in_project_newest_first() {
+        let cache = make_cache();
+        let project_id = cache.find_project("Work").map(|p| p.id.as_str());
+
+        assert_eq!(
+            ids(completed_items(&cache, project_id)),
+            vec!["work-new", "work-old", "work-untimed"]
+        );
+    }
+
+    #[test]
+    fn test_completed_items_across_projects() {
+        let cache = make_cache();
+
+        assert_eq!(
+            ids(completed_items(&cache, None)),
+            vec!["home-done", "work-new", "work-old", "work-untimed"]
+        );
+    }
+
+    #[test]
+    fn test_completed_items_empty_cache() {
+        assert!(completed_items(&Cache::new(), None).is_empty());
+    }
+}
@@ -1,13 +1,16 @@
 //! Shell completions command implementation.
 //!
-//! Generate shell completions for bash, zsh, fish, and powershell.
+//! Generate shell completions for bash, zsh, fish, and powershell, and
+//! print cached names for dynamic completion (`td __complete`).
 
 use std::io;
 
 use clap::CommandFactory;
 use clap_complete::{generate, Shell as ClapShell};
+use todoist_cache_rs::Cache;
 
-use crate::cli::{Cli, Shell};
+use super::{CommandContext, Result};
+use crate::cli::{Cli, CompleteResource, Shell};
 
 /// Generate shell completions for the given shell and write to stdout.
 ///
@@ -32,6 +35,50 @@ pub fn execute(shell: &Shell) -> io::Result<()> {
     Ok(())
 }
 
+/// Prints the cached names of `resource`, one per line.
+///
+/// Only the local cache is read, so this is fast and works offline; with no
+/// cache nothing is printed.
+///
+/// # Errors
+///
+/// Returns an error if the cache file exists but can't be read.
+pub fn execute_complete(ctx: &CommandContext, resource: CompleteResource) -> Result<()> {
+    let cache = ctx.cache_store()?.load_or_default()?;
+    for name in complete_names(&cache, resource) {
+        println!("{name}");
+    }
+    Ok(())
+}
+
+/// Returns the names to offer for `resource`: active (not deleted or
+/// archived) entries, sorted case-insensitively without duplicates.
+pub fn complete_names(cache: &Cache, resource: CompleteResource) -> Vec<&str> {
+    let mut names: Vec<&str> = match resource {
+        CompleteResource::Projects => cache
+            .projects
+            .iter()
+            .filter(|p| !p.is_deleted && !p.is_archived)
+            .map(|p| p.name.as_str())
+            .collect(),
+        CompleteResource::Labels => cache
+            .labels
+            .iter()
+            .filter(|l| !l.is_deleted)
+            .map(|l| l.name.as_str())
+            .collect(),
+        CompleteResource::Sections => cache
+            .sections
+            .iter()
+            .filter(|s| !s.is_deleted && !s.is_archived)
+            .map(|s| s.name.as_str())
+            .collect(),
+    };
+    names.sort_by_key(|name| name.to_lowercase());
+    names.dedup();
+    names
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -84,4 +131,31 @@ mod tests {
         };
         assert!(matches!(clap_shell, ClapShell::PowerShell));
     }
+
+    #[test]
+    fn test_complete_names_skips_deleted_and_archived() {
+        use todoist_api_rs::sync::Label;
+
+        let label = |name: &str, is_deleted: bool| Label {
+            id: name.to_string(),
+            name: name.to_string(),
+            color: None,
+            item_order: 0,
+            is_deleted,
+            is_favorite: false,
+        };
+        let mut cache = Cache::new();
+        cache.labels = vec![
+            label("work", false),
+            label("Errands", false),
+            label("old", true),
+            label("work", false),
+        ];
+
+        assert_eq!(
+            complete_names(&cache, CompleteResource::Labels),
+            vec!["Errands", "work"]
+        );
+        assert!(complete_names(&cache, CompleteResource::Projects).is_empty());
+    }
 }
@@ -1,11 +1,13 @@
 //! Config command implementation.
 //!
 //! View and manage configuration settings.
-//! Config file is located at ~/.config/td/config.toml.
+//! Config file is located at ~/.config/td/config.toml. With a profile
+//! (`--profile` or `TD_PROFILE`), ~/.config/td/<profile>/config.toml is
+//! layered over it.
 
 use std::env;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use tokio::process::Command;
 
@@ -13,6 +15,7 @@ use directories::BaseDirs;
 use serde::{Deserialize, Serialize};
 
 use super::{CommandContext, CommandError, Result};
+use crate::output::OutputFormat;
 
 /// Current config file version. Increment when making breaking changes to schema.
 const CONFIG_VERSION: u32 = 1;
@@ -36,14 +39,35 @@ version = 1
 # Token storage method: "config", "keyring", or "env"
 # token_storage = "config"
 
+# Language for parsing --due strings (defaults to your Todoist language)
+# due_lang = "en"
+
+# Minutes before the due time for reminders created without an explicit time
+# default_reminder_offset = 30
+
+# Project for `td add` when --project is omitted (name or ID; defaults to Inbox)
+# add_default_project = "Errands"
+
+# Take trailing p1-p4 and @label words from `td add` content without --parse
+# add_parse_tokens = true
+
 # Output preferences
 [output]
 # color = true              # Enable colors (respects NO_COLOR env)
 # date_format = "relative"  # "relative", "iso", "short"
+# id_display = "short"      # "short", "full"
+# format = "table"          # "table", "json", "csv"
+# use_pager = true          # Page long tables through $PAGER (default: less -R)
 
 # Cache settings
 [cache]
 # enabled = true
+# sort = false              # Save resources sorted by ID for stable diffs
+# pretty = false            # Save the cache file as indented JSON
+
+# Bulk --filter operations (td delete/reopen --filter)
+[bulk]
+# max_results = 50          # Refuse larger matches unless --max-results or --force
 "#;
 
 /// Configuration file structure.
@@ -62,6 +86,29 @@ pub struct Config {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub token_storage: Option<String>,
 
+    /// Language code for server-side due date parsing (e.g., "es").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_lang: Option<String>,
+
+    /// Minutes before the due time for `reminders add` without `--due`/`--offset`
+    /// and for `add --remind-default`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_reminder_offset: Option<i32>,
+
+    /// Project (name or ID) for `add` when `--project` is omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub add_default_project: Option<String>,
+
+    /// Whether `add` takes trailing `p1`-`p4` and `@label` words from the
+    /// content without `--parse`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub add_parse_tokens: Option<bool>,
+
+    /// Alternate API base URL (advanced; for mock servers and debugging).
+    /// Not part of the default template and not settable via `config set`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_base_url: Option<String>,
+
     /// Output settings.
     #[serde(default)]
     pub output: OutputConfig,
@@ -69,6 +116,10 @@ pub struct Config {
     /// Cache settings.
     #[serde(default)]
     pub cache: CacheConfig,
+
+    /// Bulk operation settings.
+    #[serde(default)]
+    pub bulk: BulkConfig,
 }
 
 /// Returns the current config version (used by serde default).
@@ -82,8 +133,14 @@ impl Default for Config {
             version: CONFIG_VERSION,
             token: None,
             token_storage: None,
+            due_lang: None,
+            default_reminder_offset: None,
+            add_default_project: None,
+            add_parse_tokens: None,
+            api_base_url: None,
             output: OutputConfig::default(),
             cache: CacheConfig::default(),
+            bulk: BulkConfig::default(),
         }
     }
 }
@@ -98,6 +155,18 @@ pub struct OutputConfig {
     /// Date format.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub date_format: Option<String>,
+
+    /// ID display in tables ("short" or "full").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id_display: Option<String>,
+
+    /// Default output format ("table", "json", or "csv").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+
+    /// Page table output taller than the terminal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_pager: Option<bool>,
 }
 
 /// Cache configuration.
@@ -106,6 +175,22 @@ pub struct CacheConfig {
     /// Enable caching.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub enabled: Option<bool>,
+
+    /// Save resource lists sorted by ID so the cache file diffs cleanly.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<bool>,
+
+    /// Save the cache file as indented JSON for easier reading.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pretty: Option<bool>,
+}
+
+/// Bulk operation configuration.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct BulkConfig {
+    /// Most tasks a `--filter` bulk command may touch without an override.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_results: Option<usize>,
 }
 
 /// Gets the config directory path.
@@ -129,8 +214,8 @@ fn get_config_dir() -> Result<PathBuf> {
         .ok_or_else(|| CommandError::Config("Could not determine config directory".to_string()))
 }
 
-/// Gets the config file path.
-pub fn get_config_path() -> Result<PathBuf> {
+/// Gets the base config file path, shared by all profiles.
+fn get_base_config_path() -> Result<PathBuf> {
     // Check for override env var first
     if let Ok(path) = env::var("TD_CONFIG") {
         return Ok(PathBuf::from(path));
@@ -140,24 +225,100 @@ pub fn get_config_path() -> Result<PathBuf> {
     Ok(config_dir.join("config.toml"))
 }
 
-/// Loads the configuration from disk.
-pub fn load_config() -> Result<Config> {
-    let path = get_config_path()?;
+/// Checks that `profile` is a plain directory name, since it names the
+/// profile's config and cache directories.
+///
+/// # Errors
+///
+/// Returns an error if the name contains anything but letters, digits,
+/// '-', '_' or '.', or is `.` or `..`.
+pub fn validate_profile_name(profile: &str) -> Result<()> {
+    let valid = profile != "."
+        && profile != ".."
+        && profile
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '-' | '_' | '.'));
+    if !valid {
+        return Err(CommandError::Config(format!(
+            "Invalid profile name '{profile}': use letters, digits, '-', '_' or '.'"
+        )));
+    }
+    Ok(())
+}
 
-    if !path.exists() {
-        return Ok(Config::default());
+/// Gets the config file path for `profile`: `<profile>/config.toml` in the
+/// config directory.
+///
+/// # Errors
+///
+/// Returns an error if the profile name isn't a plain directory name.
+fn get_profile_config_path(profile: &str) -> Result<PathBuf> {
+    validate_profile_name(profile)?;
+    Ok(get_config_dir()?.join(profile).join("config.toml"))
+}
+
+/// Gets the config file path that settings are written to: `profile`'s
+/// file, or the base file without a profile.
+pub fn get_config_path(profile: Option<&str>) -> Result<PathBuf> {
+    match profile {
+        Some(profile) => get_profile_config_path(profile),
+        None => get_base_config_path(),
     }
+}
 
-    let content = fs::read_to_string(&path)
-        .map_err(|e| CommandError::Config(format!("Failed to read config: {}", e)))?;
+/// Loads the configuration for `profile`, layered over the base config.
+///
+/// Keys set in the profile's file override the base file's; the base still
+/// supplies shared defaults for anything the profile leaves unset.
+pub fn load_config_for(profile: Option<&str>) -> Result<Config> {
+    let profile_path = profile.map(get_profile_config_path).transpose()?;
+    load_layered_config(&get_base_config_path()?, profile_path.as_deref())
+}
+
+/// Loads `base` with `overlay` merged over it, table by table. Missing files
+/// count as empty.
+fn load_layered_config(base: &Path, overlay: Option<&Path>) -> Result<Config> {
+    let mut table = read_config_table(base)?;
+    if let Some(overlay) = overlay {
+        merge_tables(&mut table, read_config_table(overlay)?);
+    }
 
-    let config: Config = toml::from_str(&content)
+    let config: Config = toml::Value::Table(table)
+        .try_into()
         .map_err(|e| CommandError::Config(format!("Failed to parse config: {}", e)))?;
 
     // Migrate config if needed (stub for future migrations)
     migrate_config(config)
 }
 
+/// Reads a config file as a TOML table; a missing file is an empty table.
+fn read_config_table(path: &Path) -> Result<toml::Table> {
+    if !path.exists() {
+        return Ok(toml::Table::new());
+    }
+
+    let content = fs::read_to_string(path)
+        .map_err(|e| CommandError::Config(format!("Failed to read config: {}", e)))?;
+
+    toml::from_str(&content)
+        .map_err(|e| CommandError::Config(format!("Failed to parse config: {}", e)))
+}
+
+/// Merges `overlay` into `base`: nested tables merge key by key, and any
+/// other value in `overlay` replaces the one in `base`.
+fn merge_tables(base: &mut toml::Table, overlay: toml::Table) {
+    for (key, value) in overlay {
+        match (base.get_mut(&key), value) {
+            (Some(toml::Value::Table(base_table)), toml::Value::Table(overlay_table)) => {
+                merge_tables(base_table, overlay_table);
+            }
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
 /// Migrates config to current version if needed.
 /// Returns the config as-is if already at current version.
 fn migrate_config(mut config: Config) -> Result<Config> {
@@ -178,9 +339,9 @@ fn migrate_config(mut config: Config) -> Result<Config> {
     Ok(config)
 }
 
-/// Saves the configuration to disk.
-fn save_config(config: &Config) -> Result<()> {
-    let path = get_config_path()?;
+/// Saves the configuration to `profile`'s config file.
+fn save_config(config: &Config, profile: Option<&str>) -> Result<()> {
+    let path = get_config_path(profile)?;
 
     // Ensure directory exists
     if let Some(parent) = path.parent() {
@@ -200,16 +361,18 @@ fn save_config(config: &Config) -> Result<()> {
 
 /// Executes the config show command.
 pub fn execute_show(ctx: &CommandContext) -> Result<()> {
-    let config = load_config()?;
-    let path = get_config_path()?;
+    let profile = ctx.profile.as_deref();
+    let config = load_config_for(profile)?;
+    let path = get_config_path(profile)?;
 
-    if ctx.json_output {
+    if ctx.output_format.is_json() {
         let output = serde_json::json!({
             "path": path.display().to_string(),
             "exists": path.exists(),
+            "profile": profile,
             "config": config,
         });
-        println!("{}", serde_json::to_string_pretty(&output)?);
+        println!("{}", crate::output::to_json(&output, ctx.compact_json)?);
     } else if !ctx.quiet {
         use owo_colors::OwoColorize;
 
@@ -220,10 +383,13 @@ pub fn execute_show(ctx: &CommandContext) -> Result<()> {
             println!("{}\n", header);
         }
 
+        if let Some(profile) = profile {
+            println!("Profile: {}", profile);
+        }
         println!("File: {}", path.display());
         println!("Exists: {}\n", path.exists());
 
-        if path.exists() {
+        if path.exists() || profile.is_some() {
             // Show current config values
             println!("Settings:");
             if let Some(ref storage) = config.token_storage {
@@ -232,6 +398,21 @@ pub fn execute_show(ctx: &CommandContext) -> Result<()> {
             if let Some(ref token) = config.token {
                 println!("  token: {}", mask_token(token));
             }
+            if let Some(ref lang) = config.due_lang {
+                println!("  due_lang: {}", lang);
+            }
+            if let Some(offset) = config.default_reminder_offset {
+                println!("  default_reminder_offset: {}", offset);
+            }
+            if let Some(ref project) = config.add_default_project {
+                println!("  add_default_project: {}", project);
+            }
+            if let Some(parse_tokens) = config.add_parse_tokens {
+                println!("  add_parse_tokens: {}", parse_tokens);
+            }
+            if let Some(ref url) = config.api_base_url {
+                println!("  api_base_url: {}", url);
+            }
 
             println!("\n[output]");
             if let Some(color) = config.output.color {
@@ -240,11 +421,31 @@ pub fn execute_show(ctx: &CommandContext) -> Result<()> {
             if let Some(ref format) = config.output.date_format {
                 println!("  date_format: {}", format);
             }
+            if let Some(ref display) = config.output.id_display {
+                println!("  id_display: {}", display);
+            }
+            if let Some(ref format) = config.output.format {
+                println!("  format: {}", format);
+            }
+            if let Some(use_pager) = config.output.use_pager {
+                println!("  use_pager: {}", use_pager);
+            }
 
             println!("\n[cache]");
             if let Some(enabled) = config.cache.enabled {
                 println!("  enabled: {}", enabled);
             }
+            if let Some(sort) = config.cache.sort {
+                println!("  sort: {}", sort);
+            }
+            if let Some(pretty) = config.cache.pretty {
+                println!("  pretty: {}", pretty);
+            }
+
+            println!("\n[bulk]");
+            if let Some(max_results) = config.bulk.max_results {
+                println!("  max_results: {}", max_results);
+            }
         } else {
             println!("(No config file exists. Run 'td config edit' to create one.)");
         }
@@ -255,7 +456,7 @@ pub fn execute_show(ctx: &CommandContext) -> Result<()> {
 
 /// Executes the config edit command.
 pub async fn execute_edit(ctx: &CommandContext) -> Result<()> {
-    let path = get_config_path()?;
+    let path = get_config_path(ctx.profile.as_deref())?;
 
     // Ensure directory exists
     if let Some(parent) = path.parent() {
@@ -269,7 +470,7 @@ pub async fn execute_edit(ctx: &CommandContext) -> Result<()> {
         fs::write(&path, DEFAULT_CONFIG)
             .map_err(|e| CommandError::Config(format!("Failed to create config file: {}", e)))?;
 
-        if !ctx.quiet && !ctx.json_output {
+        if !ctx.quiet && !ctx.output_format.is_json() {
             eprintln!("Created default config at: {}", path.display());
         }
     }
@@ -290,13 +491,13 @@ pub async fn execute_edit(ctx: &CommandContext) -> Result<()> {
         .await
         .map_err(|e| CommandError::Config(format!("Failed to open editor '{}': {}", editor, e)))?;
 
-    if ctx.json_output {
+    if ctx.output_format.is_json() {
         let output = serde_json::json!({
             "status": if status.success() { "success" } else { "error" },
             "editor": editor,
             "path": path.display().to_string(),
         });
-        println!("{}", serde_json::to_string_pretty(&output)?);
+        println!("{}", crate::output::to_json(&output, ctx.compact_json)?);
     } else if !ctx.quiet {
         if status.success() {
             println!("Config saved.");
@@ -318,8 +519,10 @@ pub struct ConfigSetOptions {
 
 /// Executes the config set command.
 pub fn execute_set(ctx: &CommandContext, opts: &ConfigSetOptions) -> Result<()> {
-    let mut config = load_config()?;
-    let path = get_config_path()?;
+    let path = get_config_path(ctx.profile.as_deref())?;
+    // Only the file being written is loaded, so a profile's file doesn't
+    // pick up copies of the base settings
+    let mut config = load_layered_config(&path, None)?;
 
     // Parse and set the value based on key
     let (section, field) = if opts.key.contains('.') {
@@ -344,6 +547,30 @@ pub fn execute_set(ctx: &CommandContext, opts: &ConfigSetOptions) -> Result<()>
             }
             config.token_storage = Some(opts.value.clone());
         }
+        (None, "due_lang") => {
+            config.due_lang = Some(opts.value.clone());
+        }
+        (None, "default_reminder_offset") => {
+            let value = opts
+                .value
+                .parse::<i32>()
+                .ok()
+                .filter(|minutes| *minutes >= 0)
+                .ok_or_else(|| {
+                    CommandError::Config(format!(
+                        "Invalid default_reminder_offset value '{}'. Use a whole number of minutes",
+                        opts.value
+                    ))
+                })?;
+            config.default_reminder_offset = Some(value);
+        }
+        (None, "add_default_project") => {
+            config.add_default_project = Some(opts.value.clone());
+        }
+        (None, "add_parse_tokens") => {
+            let value = parse_bool(&opts.value)?;
+            config.add_parse_tokens = Some(value);
+        }
         (Some("output"), "color") => {
             let value = parse_bool(&opts.value)?;
             config.output.color = Some(value);
@@ -359,13 +586,55 @@ pub fn execute_set(ctx: &CommandContext, opts: &ConfigSetOptions) -> Result<()>
             }
             config.output.date_format = Some(opts.value.clone());
         }
+        (Some("output"), "id_display") => {
+            let valid = ["short", "full"];
+            if !valid.contains(&opts.value.as_str()) {
+                return Err(CommandError::Config(format!(
+                    "Invalid id_display value '{}'. Valid values: {}",
+                    opts.value,
+                    valid.join(", ")
+                )));
+            }
+            config.output.id_display = Some(opts.value.clone());
+        }
+        (Some("output"), "format") => {
+            if OutputFormat::from_name(&opts.value).is_none() {
+                return Err(CommandError::Config(format!(
+                    "Invalid format value '{}'. Valid values: {}",
+                    opts.value,
+                    OutputFormat::NAMES.join(", ")
+                )));
+            }
+            config.output.format = Some(opts.value.clone());
+        }
+        (Some("output"), "use_pager") => {
+            let value = parse_bool(&opts.value)?;
+            config.output.use_pager = Some(value);
+        }
         (Some("cache"), "enabled") => {
             let value = parse_bool(&opts.value)?;
             config.cache.enabled = Some(value);
         }
+        (Some("cache"), "sort") => {
+            let value = parse_bool(&opts.value)?;
+            config.cache.sort = Some(value);
+        }
+        (Some("cache"), "pretty") => {
+            let value = parse_bool(&opts.value)?;
+            config.cache.pretty = Some(value);
+        }
+        (Some("bulk"), "max_results") => {
+            let value = opts.value.parse::<usize>().map_err(|_| {
+                CommandError::Config(format!(
+                    "Invalid max_results value '{}'. Use a whole number",
+                    opts.value
+                ))
+            })?;
+            config.bulk.max_results = Some(value);
+        }
         _ => {
             return Err(CommandError::Config(format!(
-                "Unknown config key '{}'. Valid keys: token, token_storage, output.color, output.date_format, cache.enabled",
+                "Unknown config key '{}'. Valid keys: token, token_storage, due_lang, default_reminder_offset, add_default_project, add_parse_tokens, output.color, output.date_format, output.id_display, output.format, output.use_pager, cache.enabled, cache.sort, cache.pretty, bulk.max_results",
                 opts.key
             )));
         }
@@ -378,16 +647,16 @@ pub fn execute_set(ctx: &CommandContext, opts: &ConfigSetOptions) -> Result<()>
         })?;
     }
 
-    save_config(&config)?;
+    save_config(&config, ctx.profile.as_deref())?;
 
-    if ctx.json_output {
+    if ctx.output_format.is_json() {
         let output = serde_json::json!({
             "status": "success",
             "key": opts.key,
             "value": opts.value,
             "path": path.display().to_string(),
         });
-        println!("{}", serde_json::to_string_pretty(&output)?);
+        println!("{}", crate::output::to_json(&output, ctx.compact_json)?);
     } else if !ctx.quiet {
         println!("Set {} = {}", opts.key, opts.value);
     }
@@ -397,14 +666,14 @@ pub fn execute_set(ctx: &CommandContext, opts: &ConfigSetOptions) -> Result<()>
 
 /// Executes the config path command.
 pub fn execute_path(ctx: &CommandContext) -> Result<()> {
-    let path = get_config_path()?;
+    let path = get_config_path(ctx.profile.as_deref())?;
 
-    if ctx.json_output {
+    if ctx.output_format.is_json() {
         let output = serde_json::json!({
             "path": path.display().to_string(),
             "exists": path.exists(),
         });
-        println!("{}", serde_json::to_string_pretty(&output)?);
+        println!("{}", crate::output::to_json(&output, ctx.compact_json)?);
     } else {
         println!("{}", path.display());
     }
@@ -489,13 +758,24 @@ mod tests {
             version: CONFIG_VERSION,
             token: None,
             token_storage: Some("config".to_string()),
+            due_lang: None,
+            default_reminder_offset: None,
+            add_default_project: None,
+            add_parse_tokens: None,
+            api_base_url: None,
             output: OutputConfig {
                 color: Some(true),
                 date_format: Some("relative".to_string()),
+                id_display: None,
+                format: None,
+                use_pager: None,
             },
             cache: CacheConfig {
                 enabled: Some(true),
+                sort: None,
+                pretty: None,
             },
+            bulk: BulkConfig::default(),
         };
 
         let toml_str = toml::to_string_pretty(&config).unwrap();
@@ -552,6 +832,13 @@ color = true
         assert!(config.cache.enabled.is_none());
     }
 
+    #[test]
+    fn test_config_deserialization_cache_pretty() {
+        let config: Config = toml::from_str("[cache]\npretty = true\n").unwrap();
+        assert_eq!(config.cache.pretty, Some(true));
+        assert!(config.cache.sort.is_none());
+    }
+
     #[test]
     fn test_mask_token_ascii() {
         // Long token gets masked with first 4 and last 4 visible
@@ -609,19 +896,31 @@ color = true
             version: 1,
             token: Some("test-token".to_string()),
             token_storage: Some("keyring".to_string()),
+            due_lang: Some("es".to_string()),
+            default_reminder_offset: None,
+            add_default_project: None,
+            add_parse_tokens: None,
+            api_base_url: None,
             output: OutputConfig {
                 color: Some(true),
                 date_format: Some("iso".to_string()),
+                id_display: None,
+                format: None,
+                use_pager: None,
             },
             cache: CacheConfig {
                 enabled: Some(true),
+                sort: None,
+                pretty: None,
             },
+            bulk: BulkConfig::default(),
         };
 
         let migrated = migrate_config(config).unwrap();
         assert_eq!(migrated.version, CONFIG_VERSION);
         assert_eq!(migrated.token, Some("test-token".to_string()));
         assert_eq!(migrated.token_storage, Some("keyring".to_string()));
+        assert_eq!(migrated.due_lang, Some("es".to_string()));
         assert_eq!(migrated.output.color, Some(true));
         assert_eq!(migrated.output.date_format, Some("iso".to_string()));
         assert_eq!(migrated.cache.enabled, Some(true));
@@ -638,4 +937,71 @@ token_storage = "env"
         assert_eq!(config.version, 999);
         assert_eq!(config.token_storage, Some("env".to_string()));
     }
+
+    fn write_config(path: &Path, content: &str) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn test_profile_config_overrides_base() {
+        let dir = tempfile::tempdir().unwrap();
+        let base = dir.path().join("config.toml");
+        let profile = dir.path().join("work").join("config.toml");
+        write_config(
+            &base,
+            "token = \"personal-token\"\nadd_default_project = \"Home\"\n\n[output]\nformat = \"json\"\nuse_pager = false\n",
+        );
+        write_config(
+            &profile,
+            "token = \"work-token\"\n\n[output]\nformat = \"table\"\n",
+        );
+
+        let config = load_layered_config(&base, Some(&profile)).unwrap();
+        assert_eq!(config.token.as_deref(), Some("work-token"));
+        assert_eq!(config.output.format.as_deref(), Some("table"));
+        // Unset in the profile, so the base value still applies
+        assert_eq!(config.output.use_pager, Some(false));
+        assert_eq!(config.add_default_project.as_deref(), Some("Home"));
+    }
+
+    #[test]
+    fn test_base_config_applies_without_profile_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let base = dir.path().join("config.toml");
+        let missing_profile = dir.path().join("work").join("config.toml");
+        write_config(
+            &base,
+            "token = \"personal-token\"\n\n[cache]\nsort = true\n",
+        );
+
+        let config = load_layered_config(&base, Some(&missing_profile)).unwrap();
+        assert_eq!(config.token.as_deref(), Some("personal-token"));
+        assert_eq!(config.cache.sort, Some(true));
+
+        let config = load_layered_config(&base, None).unwrap();
+        assert_eq!(config.token.as_deref(), Some("personal-token"));
+    }
+
+    #[test]
+    fn test_layered_config_missing_files_is_default() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = load_layered_config(
+            &dir.path().join("config.toml"),
+            Some(&dir.path().join("work").join("config.toml")),
+        )
+        .unwrap();
+        assert!(config.token.is_none());
+        assert_eq!(config.version, CONFIG_VERSION);
+    }
+
+    #[test]
+    fn test_profile_name_must_be_plain_directory_name() {
+        assert!(get_profile_config_path("work").is_ok());
+        assert!(get_profile_config_path("client_a.2").is_ok());
+        for name in ["..", ".", "a/b", "a\\b", "work profile"] {
+            let err = get_profile_config_path(name).unwrap_err().to_string();
+            assert!(err.contains("Invalid profile name"), "{name}: {err}");
+        }
+    }
 }
@@ -2,21 +2,33 @@
 //!
 //! Deletes task(s) via the Sync API's `item_delete` command.
 //! Uses SyncManager::execute_commands() to automatically update the cache.
-//! Uses resolve_item_by_prefix() for smart lookups with auto-sync fallback.
+//! Uses resolve_item_arg() to accept IDs, ID prefixes, or content substrings
+//! with auto-sync fallback, or `--filter` to select tasks by filter expression.
 
-use todoist_api_rs::client::TodoistClient;
-use todoist_api_rs::sync::{SyncCommand, SyncCommandType};
-use todoist_cache_rs::{CacheStore, SyncManager};
+use std::io::IsTerminal;
 
-use super::{confirm_bulk_operation, CommandContext, CommandError, ConfirmResult, Result};
+use todoist_api_rs::sync::{SyncCommand, SyncCommandType, SyncResponse};
+use todoist_cache_rs::filter::{FilterContext, FilterEvaluator, FilterParser};
+use todoist_cache_rs::Cache;
+
+use super::{
+    check_filter_result_cap, confirm_bulk_operation, confirm_destructive_operation, ensure_fresh,
+    BulkItem, CommandContext, CommandError, ConfirmResult, Result,
+};
 
 /// Options for the delete command.
 #[derive(Debug)]
 pub struct DeleteOptions {
-    /// Task IDs (full IDs or prefixes).
+    /// Task references (full IDs, ID prefixes, or content substrings).
     pub task_ids: Vec<String>,
+    /// Filter expression selecting the tasks to delete (instead of task_ids).
+    pub filter: Option<String>,
+    /// Most tasks a `filter` run may delete without `force`.
+    pub max_results: usize,
     /// Skip confirmation prompt.
     pub force: bool,
+    /// Move each task's subtasks up a level instead of deleting them.
+    pub keep_subtasks: bool,
 }
 
 /// Result of deleting a single task.
@@ -45,31 +57,62 @@ pub struct DeleteResult {
 /// Returns an error if syncing fails, task lookup fails, or the API returns an error.
 pub async fn execute(ctx: &CommandContext, opts: &DeleteOptions, token: &str) -> Result<()> {
     // Initialize sync manager (loads cache from disk)
-    let client = TodoistClient::new(token)?;
-    let store = CacheStore::new()?;
-    let mut manager = SyncManager::new(client, store)?;
-
-    // Resolve all task IDs using smart lookup (cache-first with auto-sync fallback)
-    // require_checked=None to match any task (delete works on completed and uncompleted)
-    let mut resolved_items: Vec<(String, String)> = Vec::new();
-    for task_id in &opts.task_ids {
-        let item = manager
-            .resolve_item_by_prefix(task_id, None)
-            .await
-            .map_err(|e| CommandError::Config(e.to_string()))?;
-        resolved_items.push((item.id.clone(), item.content.clone()));
-    }
+    let mut manager = ctx.sync_manager(token)?;
+
+    let resolved_items: Vec<(String, String)> = if let Some(ref filter) = opts.filter {
+        // A stale cache could select tasks that no longer match
+        ensure_fresh(ctx, &mut manager).await?;
+        let items = select_filter_targets(manager.cache(), filter)?;
+        if items.is_empty() {
+            if !ctx.quiet {
+                eprintln!("No tasks match filter: {filter}");
+            }
+            return Ok(());
+        }
+        check_filter_result_cap("delete", items.len(), opts.max_results, opts.force)?;
+        items
+    } else {
+        // Resolve all task references (ID, prefix, or content) with auto-sync fallback
+        // require_checked=None to match any task (delete works on completed and uncompleted)
+        let mut items = Vec::new();
+        for task_id in &opts.task_ids {
+            let item = manager
+                .resolve_item_arg(task_id, None)
+                .await
+                .map_err(|e| CommandError::Config(e.to_string()))?;
+            items.push((item.id.clone(), item.content.clone()));
+        }
+        items
+    };
 
-    // Prompt for confirmation if multiple tasks
-    let items_for_confirm: Vec<(&str, &str)> = resolved_items
+    let items_for_confirm: Vec<BulkItem> = resolved_items
         .iter()
-        .map(|(id, content)| {
-            let id_prefix = &id[..6.min(id.len())];
-            (id_prefix, content.as_str())
-        })
+        .map(|(id, content)| BulkItem::resolve(manager.cache(), id, content))
         .collect();
 
-    match confirm_bulk_operation("delete", &items_for_confirm, opts.force, ctx.quiet)? {
+    // Filter deletes always need typed confirmation (or --force); explicit
+    // task lists only prompt when there are several
+    let confirmation = if opts.filter.is_some() {
+        confirm_destructive_operation(
+            "delete",
+            &items_for_confirm,
+            ctx.date_style,
+            ctx.id_style,
+            opts.force,
+            std::io::stdin().is_terminal(),
+        )?
+    } else {
+        confirm_bulk_operation(
+            "delete",
+            &items_for_confirm,
+            ctx.date_style,
+            ctx.id_style,
+            opts.force,
+            ctx.quiet,
+        )?
+    };
+
+    match confirmation {
         ConfirmResult::Confirmed => {}
         ConfirmResult::Aborted => {
             if !ctx.quiet {
@@ -79,42 +122,54 @@ pub async fn execute(ctx: &CommandContext, opts: &DeleteOptions, token: &str) ->
         }
     }
 
-    // Build commands for all tasks using item_delete
-    let commands: Vec<SyncCommand> = resolved_items
+    // Build commands for all tasks using item_delete, preceded by any
+    // item_move commands that rescue subtasks
+    let ids: Vec<String> = resolved_items.iter().map(|(id, _)| id.clone()).collect();
+    let mut moves = build_delete_commands(manager.cache(), &ids, opts.keep_subtasks);
+    let moved_count = moves
         .iter()
-        .map(|(id, _)| {
-            SyncCommand::new(SyncCommandType::ItemDelete, serde_json::json!({ "id": id }))
-        })
-        .collect();
+        .filter(|c| c.command_type == SyncCommandType::ItemMove)
+        .count();
+    // The item_delete commands come last, one per resolved task in order
+    let deletes = moves.split_off(moved_count);
+    let delete_uuids: Vec<String> = deletes.iter().map(|c| c.uuid.clone()).collect();
+
+    // Move subtasks in a batch of their own: if a move fails, deleting its
+    // parent would take the subtask with it, so nothing is deleted
+    if !moves.is_empty() {
+        let response = manager.execute_commands(moves.clone()).await?;
+        let failures = failed_moves(&moves, &response, manager.cache());
+        if !failures.is_empty() {
+            return Err(CommandError::Config(format!(
+                "Failed to move {} subtask(s), so nothing was deleted:\n  {}",
+                failures.len(),
+                failures.join("\n  ")
+            )));
+        }
+    }
 
     // Execute the commands via SyncManager
     // This sends the commands, applies the response to cache, and saves to disk
-    let response = manager.execute_commands(commands).await?;
+    let response = manager.execute_commands(deletes).await?;
 
     // Process results
     let mut results: Vec<DeleteResult> = Vec::new();
     let mut success_count = 0;
     let mut error_count = 0;
 
-    for (id, content) in &resolved_items {
+    for ((id, content), uuid) in resolved_items.iter().zip(&delete_uuids) {
         // Check sync_status for this command
-        let has_error = response
-            .errors()
-            .iter()
-            .any(|(_, err)| err.error.contains(id));
-
-        if has_error {
-            let error_msg = response
-                .errors()
-                .iter()
-                .find(|(_, err)| err.error.contains(id))
-                .map(|(_, err)| format!("{}: {}", err.error_code, err.error));
+        let error = response
+            .sync_status
+            .get(uuid)
+            .and_then(|status| status.error());
 
+        if let Some(err) = error {
             results.push(DeleteResult {
                 id: id.clone(),
                 content: content.clone(),
                 success: false,
-                error: error_msg,
+                error: Some(format!("{}: {}", err.error_code, err.error)),
             });
             error_count += 1;
         } else {
@@ -129,8 +184,8 @@ pub async fn execute(ctx: &CommandContext, opts: &DeleteOptions, token: &str) ->
     }
 
     // Output results
-    if ctx.json_output {
-        let output = format_delete_results_json(&results)?;
+    if ctx.output_format.is_json() {
+        let output = format_delete_results_json(&results, ctx.compact_json)?;
         println!("{output}");
     } else if !ctx.quiet {
         for result in &results {
@@ -145,6 +200,15 @@ pub async fn execute(ctx: &CommandContext, opts: &DeleteOptions, token: &str) ->
             }
         }
 
+        if moved_count > 0 {
+            let subtask_word = if moved_count == 1 {
+                "subtask"
+            } else {
+                "subtasks"
+            };
+            println!("Kept {} {} (moved up a level)", moved_count, subtask_word);
+        }
+
         if ctx.verbose && results.len() > 1 {
             println!("\n{} deleted, {} failed", success_count, error_count);
         }
@@ -161,8 +225,95 @@ pub async fn execute(ctx: &CommandContext, opts: &DeleteOptions, token: &str) ->
     Ok(())
 }
 
+/// Builds the commands that delete `ids`.
+///
+/// With `keep_subtasks`, every direct subtask of a deleted task is first
+/// moved to the nearest ancestor that is not being deleted, or to the top
+/// level of the deleted task's section/project. Subtasks that are deleted
+/// themselves are not moved. All moves come before the deletes; the caller
+/// sends them first and only deletes once every move has succeeded.
+fn build_delete_commands(cache: &Cache, ids: &[String], keep_subtasks: bool) -> Vec<SyncCommand> {
+    let is_deleted = |id: &str| ids.iter().any(|d| d == id);
+    let mut commands = Vec::new();
+
+    if keep_subtasks {
+        for id in ids {
+            let Some(item) = cache.find_item(id) else {
+                continue;
+            };
+
+            // Walk up past ancestors that are also being deleted; `top` is
+            // the highest of them, whose section/project a promoted subtask joins
+            let mut top = item;
+            let mut new_parent = item.parent_id.as_deref();
+            while let Some(ancestor) = new_parent
+                .filter(|p| is_deleted(p))
+                .and_then(|p| cache.find_item(p))
+            {
+                top = ancestor;
+                new_parent = ancestor.parent_id.as_deref();
+            }
+
+            for child in cache.children_of(id) {
+                if is_deleted(&child.id) {
+                    continue;
+                }
+                let mut args = serde_json::json!({ "id": child.id });
+                match (new_parent, top.section_id.as_ref()) {
+                    (Some(parent_id), _) => args["parent_id"] = serde_json::json!(parent_id),
+                    (None, Some(section_id)) => args["section_id"] = serde_json::json!(section_id),
+                    (None, None) => args["project_id"] = serde_json::json!(top.project_id),
+                }
+                commands.push(SyncCommand::new(SyncCommandType::ItemMove, args));
+            }
+        }
+    }
+
+    commands.extend(
+        ids.iter().map(|id| {
+            SyncCommand::new(SyncCommandType::ItemDelete, serde_json::json!({ "id": id }))
+        }),
+    );
+    commands
+}
+
+/// Describes each `item_move` in `moves` that the response reports as failed,
+/// as "content (id): code: message".
+fn failed_moves(moves: &[SyncCommand], response: &SyncResponse, cache: &Cache) -> Vec<String> {
+    moves
+        .iter()
+        .filter_map(|command| {
+            let err = response.sync_status.get(&command.uuid)?.error()?;
+            let id = command.args["id"].as_str().unwrap_or_default();
+            let content = cache.find_item(id).map_or(id, |item| item.content.as_str());
+            Some(format!(
+                "{} ({}): {}: {}",
+                content, id, err.error_code, err.error
+            ))
+        })
+        .collect()
+}
+
+/// Returns (id, content) for every active task matching the filter expression.
+///
+/// Uses the same candidate set as `td list --filter` so the result can be
+/// previewed before deleting.
+fn select_filter_targets(cache: &Cache, filter_expr: &str) -> Result<Vec<(String, String)>> {
+    let filter = FilterParser::parse(filter_expr)?;
+    let context = FilterContext::from_cache(cache);
+    let evaluator = FilterEvaluator::new(&filter, &context);
+
+    Ok(cache
+        .items
+        .iter()
+        .filter(|i| !i.is_deleted && !i.checked)
+        .filter(|i| evaluator.matches(i))
+        .map(|i| (i.id.clone(), i.content.clone()))
+        .collect())
+}
+
 /// Formats delete results as JSON.
-fn format_delete_results_json(results: &[DeleteResult]) -> Result<String> {
+fn format_delete_results_json(results: &[DeleteResult], compact: bool) -> Result<String> {
     use serde::Serialize;
 
     #[derive(Serialize)]
@@ -212,18 +363,24 @@ fn format_delete_results_json(results: &[DeleteResult]) -> Result<String> {
         failed,
     };
 
-    serde_json::to_string_pretty(&output).map_err(CommandError::from)
+    crate::output::to_json(&output, compact).map_err(CommandError::from)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::commands::DEFAULT_MAX_FILTER_RESULTS;
+    use crate::test_support::item;
+    use todoist_api_rs::sync::Item;
 
     #[test]
     fn test_delete_options_single_task() {
         let opts = DeleteOptions {
             task_ids: vec!["abc123".to_string()],
+            filter: None,
+            max_results: DEFAULT_MAX_FILTER_RESULTS,
             force: false,
+            keep_subtasks: false,
         };
 
         assert_eq!(opts.task_ids.len(), 1);
@@ -238,7 +395,10 @@ mod tests {
                 "def456".to_string(),
                 "ghi789".to_string(),
             ],
+            filter: None,
+            max_results: DEFAULT_MAX_FILTER_RESULTS,
             force: true,
+            keep_subtasks: false,
         };
 
         assert_eq!(opts.task_ids.len(), 3);
@@ -271,9 +431,9 @@ mod tests {
         assert!(result.error.is_some());
     }
 
-    // Note: Tests for item lookup by prefix are now in SyncManager tests
-    // (resolve_item_by_prefix covers exact match, prefix match, not found,
-    // ambiguous, deleted items, and completion status filtering)
+    // Note: Tests for item lookup are in SyncManager tests
+    // (resolve_item_arg covers exact match, prefix match, content substring,
+    // not found, ambiguous, and completion status filtering)
 
     #[test]
     fn test_format_delete_results_json() {
@@ -292,11 +452,176 @@ mod tests {
             },
         ];
 
-        let json = format_delete_results_json(&results).unwrap();
+        let json = format_delete_results_json(&results, false).unwrap();
         assert!(json.contains("\"total_deleted\": 1"));
         assert!(json.contains("\"total_failed\": 1"));
         assert!(json.contains("Task 1"));
         assert!(json.contains("Task 2"));
         assert!(json.contains("Not found"));
     }
+
+    #[test]
+    fn test_select_filter_targets_matches_filter() {
+        let labelled = item("id-3", "Old chore").labels(&["cleanup"]).build();
+        let checked = item("id-4", "Done chore")
+            .labels(&["cleanup"])
+            .checked()
+            .build();
+        let deleted = item("id-5", "Gone chore")
+            .labels(&["cleanup"])
+            .deleted()
+            .build();
+
+        let cache = Cache::with_data(
+            "token".to_string(),
+            None,
+            None,
+            vec![
+                item("id-1", "Urgent").priority(4).build(),
+                item("id-2", "Someday").build(),
+                labelled,
+                checked,
+                deleted,
+            ],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            None,
+        );
+
+        let targets = select_filter_targets(&cache, "@cleanup | p1").unwrap();
+        let ids: Vec<&str> = targets.iter().map(|(id, _)| id.as_str()).collect();
+        assert_eq!(ids, vec!["id-1", "id-3"]);
+
+        assert!(select_filter_targets(&cache, "p2").unwrap().is_empty());
+        assert!(select_filter_targets(&cache, "today &").is_err());
+    }
+
+    fn cache_with_items(items: Vec<Item>) -> Cache {
+        Cache::with_data(
+            "token".to_string(),
+            None,
+            None,
+            items,
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            None,
+        )
+    }
+
+    fn child_of(id: &str, parent_id: &str, child_order: i32) -> Item {
+        item(id, id)
+            .parent(parent_id)
+            .child_order(child_order)
+            .build()
+    }
+
+    #[test]
+    fn test_build_delete_commands_keep_subtasks_moves_children_first() {
+        let cache = cache_with_items(vec![
+            item("root", "Root").build(),
+            child_of("parent", "root", 0),
+            child_of("child-b", "parent", 2),
+            child_of("child-a", "parent", 1),
+            child_of("grandchild", "child-a", 0),
+        ]);
+
+        let commands = build_delete_commands(&cache, &["parent".to_string()], true);
+        let summary: Vec<(SyncCommandType, serde_json::Value)> = commands
+            .iter()
+            .map(|c| (c.command_type, c.args.clone()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    SyncCommandType::ItemMove,
+                    serde_json::json!({"id": "child-a", "parent_id": "root"})
+                ),
+                (
+                    SyncCommandType::ItemMove,
+                    serde_json::json!({"id": "child-b", "parent_id": "root"})
+                ),
+                (
+                    SyncCommandType::ItemDelete,
+                    serde_json::json!({"id": "parent"})
+                ),
+            ]
+        );
+
+        // Without the flag only the delete is sent
+        let commands = build_delete_commands(&cache, &["parent".to_string()], false);
+        assert_eq!(commands.len(), 1);
+        assert_eq!(commands[0].command_type, SyncCommandType::ItemDelete);
+    }
+
+    #[test]
+    fn test_build_delete_commands_keep_subtasks_promotes_to_top_level() {
+        let parent = item("parent", "Parent").section("sec-1").build();
+        let cache = cache_with_items(vec![
+            parent,
+            child_of("child", "parent", 0),
+            child_of("doomed", "parent", 1),
+            child_of("orphan", "doomed", 0),
+        ]);
+
+        let ids = vec!["parent".to_string(), "doomed".to_string()];
+        let commands = build_delete_commands(&cache, &ids, true);
+        let moves: Vec<&serde_json::Value> = commands
+            .iter()
+            .filter(|c| c.command_type == SyncCommandType::ItemMove)
+            .map(|c| &c.args)
+            .collect();
+        // "doomed" is deleted too, so it isn't moved and its child skips past it
+        assert_eq!(
+            moves,
+            vec![
+                &serde_json::json!({"id": "child", "section_id": "sec-1"}),
+                &serde_json::json!({"id": "orphan", "section_id": "sec-1"}),
+            ]
+        );
+        assert_eq!(
+            commands
+                .iter()
+                .skip(2)
+                .map(|c| c.command_type)
+                .collect::<Vec<_>>(),
+            vec![SyncCommandType::ItemDelete, SyncCommandType::ItemDelete]
+        );
+    }
+
+    #[test]
+    fn test_failed_moves_reports_rejected_subtasks_only() {
+        let cache = cache_with_items(vec![
+            item("parent", "Parent").build(),
+            child_of("child-a", "parent", 0),
+            child_of("child-b", "parent", 1),
+        ]);
+        let commands = build_delete_commands(&cache, &["parent".to_string()], true);
+        let moves = &commands[..2];
+
+        let response: SyncResponse = serde_json::from_value(serde_json::json!({
+            "sync_token": "token",
+            "full_sync": false,
+            "sync_status": {
+                moves[0].uuid.clone(): "ok",
+                moves[1].uuid.clone(): {"error_code": 22, "error": "Item not found"},
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            failed_moves(moves, &response, &cache),
+            vec!["child-b (child-b): 22: Item not found"]
+        );
+    }
 }
@@ -2,25 +2,33 @@
 //!
 //! Completes task(s) via the Sync API's `item_close` command.
 //! Uses SyncManager::execute_commands() to automatically update the cache.
-//! Uses resolve_item_by_prefix() for smart lookups with auto-sync fallback.
+//! Uses resolve_item_arg() to accept IDs, ID prefixes, or content substrings
+//! with auto-sync fallback.
 
-use todoist_api_rs::client::TodoistClient;
-use todoist_api_rs::sync::{SyncCommand, SyncCommandType};
-use todoist_cache_rs::{CacheStore, SyncManager};
+use std::collections::HashSet;
 
-use super::{confirm_bulk_operation, CommandContext, CommandError, ConfirmResult, Result};
+use todoist_api_rs::sync::{Item, SyncCommand, SyncCommandType};
+use todoist_cache_rs::Cache;
+
+use super::{
+    confirm_bulk_operation, BulkItem, CommandContext, CommandError, ConfirmResult, Result,
+};
+use crate::output::helpers::{id_display_len, truncate_id_to};
+use crate::output::IdStyle;
 
 /// Options for the done command.
 #[derive(Debug)]
 pub struct DoneOptions {
-    /// Task IDs (full IDs or prefixes).
+    /// Task references (full IDs, ID prefixes, or content substrings).
     pub task_ids: Vec<String>,
     /// Complete all future occurrences (for recurring tasks).
     /// When false (default), uses `item_close` which schedules recurring tasks to next occurrence.
     /// When true, uses `item_complete` which fully completes the task including all future occurrences.
     pub all_occurrences: bool,
-    /// Skip confirmation for multiple tasks.
+    /// Skip confirmation for multiple tasks and the open-subtask warning.
     pub force: bool,
+    /// Also complete every open subtask, at any depth, in the same batch.
+    pub subtasks: bool,
 }
 
 /// Result of completing a single task.
@@ -49,31 +57,62 @@ pub struct DoneResult {
 /// Returns an error if syncing fails, task lookup fails, or the API returns an error.
 pub async fn execute(ctx: &CommandContext, opts: &DoneOptions, token: &str) -> Result<()> {
     // Initialize sync manager (loads cache from disk)
-    let client = TodoistClient::new(token)?;
-    let store = CacheStore::new()?;
-    let mut manager = SyncManager::new(client, store)?;
+    let mut manager = ctx.sync_manager(token)?;
 
-    // Resolve all task IDs using smart lookup (cache-first with auto-sync fallback)
+    // Resolve all task references (ID, prefix, or content) with auto-sync fallback
     // require_checked=Some(false) to only find uncompleted tasks
     let mut resolved_items: Vec<(String, String)> = Vec::new();
     for task_id in &opts.task_ids {
         let item = manager
-            .resolve_item_by_prefix(task_id, Some(false))
+            .resolve_item_arg(task_id, Some(false))
             .await
             .map_err(|e| CommandError::Config(e.to_string()))?;
         resolved_items.push((item.id.clone(), item.content.clone()));
     }
+    if opts.subtasks {
+        resolved_items = with_open_subtasks(manager.cache(), &resolved_items);
+    }
 
     // Prompt for confirmation if multiple tasks
-    let items_for_confirm: Vec<(&str, &str)> = resolved_items
+    let items_for_confirm: Vec<BulkItem> = resolved_items
         .iter()
-        .map(|(id, content)| {
-            let id_prefix = &id[..6.min(id.len())];
-            (id_prefix, content.as_str())
-        })
+        .map(|(id, content)| BulkItem::resolve(manager.cache(), id, content))
         .collect();
 
-    match confirm_bulk_operation("complete", &items_for_confirm, opts.force, ctx.quiet)? {
+    // Warn about open subtasks that completing a parent will also complete.
+    // Closing a recurring task only reschedules it, so its subtasks stay open.
+    if !opts.force && !ctx.quiet {
+        let cache = manager.cache();
+        let explicit: HashSet<&str> = resolved_items.iter().map(|(id, _)| id.as_str()).collect();
+        for (id, content) in &resolved_items {
+            let recurring = cache
+                .find_item(id)
+                .and_then(|item| item.due.as_ref())
+                .is_some_and(|due| due.is_recurring);
+            if recurring && !opts.all_occurrences {
+                continue;
+            }
+            let subtasks: Vec<&Item> = open_subtasks(cache, id)
+                .into_iter()
+                .filter(|item| !explicit.contains(item.id.as_str()))
+                .collect();
+            if !subtasks.is_empty() {
+                eprint!(
+                    "{}",
+                    format_subtask_warning(content, &subtasks, ctx.id_style)
+                );
+            }
+        }
+    }
+
+    match confirm_bulk_operation(
+        "complete",
+        &items_for_confirm,
+        ctx.date_style,
+        ctx.id_style,
+        opts.force,
+        ctx.quiet,
+    )? {
         ConfirmResult::Confirmed => {}
         ConfirmResult::Aborted => {
             if !ctx.quiet {
@@ -94,10 +133,7 @@ pub async fn execute(ctx: &CommandContext, opts: &DoneOptions, token: &str) -> R
         SyncCommandType::ItemClose
     };
 
-    let commands: Vec<SyncCommand> = resolved_items
-        .iter()
-        .map(|(id, _)| SyncCommand::new(command_type, serde_json::json!({ "id": id })))
-        .collect();
+    let commands = build_done_commands(&resolved_items, command_type);
 
     // Execute the commands via SyncManager
     // This sends the commands, applies the response to cache, and saves to disk
@@ -142,12 +178,13 @@ pub async fn execute(ctx: &CommandContext, opts: &DoneOptions, token: &str) -> R
     }
 
     // Output results
-    if ctx.json_output {
-        let output = format_done_results_json(&results)?;
+    if ctx.output_format.is_json() {
+        let output = format_done_results_json(&results, ctx.compact_json)?;
         println!("{output}");
     } else if !ctx.quiet {
+        let id_len = id_display_len(results.iter().map(|r| r.id.as_str()), ctx.id_style);
         for result in &results {
-            let id_prefix = &result.id[..6.min(result.id.len())];
+            let id_prefix = truncate_id_to(&result.id, id_len);
             if result.success {
                 println!("Completed: {} ({})", result.content, id_prefix);
             } else if let Some(ref err) = result.error {
@@ -174,8 +211,77 @@ pub async fn execute(ctx: &CommandContext, opts: &DoneOptions, token: &str) -> R
     Ok(())
 }
 
+/// Returns the open (uncompleted) subtasks at any depth below a task.
+fn open_subtasks<'a>(cache: &'a Cache, id: &str) -> Vec<&'a Item> {
+    let mut open = Vec::new();
+    let mut seen: HashSet<&str> = HashSet::from([id]);
+    let mut stack = cache.children_of(id);
+    stack.reverse();
+    while let Some(item) = stack.pop() {
+        // Guard against parent cycles in malformed data
+        if !seen.insert(item.id.as_str()) {
+            continue;
+        }
+        if !item.checked {
+            open.push(item);
+        }
+        stack.extend(cache.children_of(&item.id).into_iter().rev());
+    }
+    open
+}
+
+/// Adds each task's open subtasks to the tasks being completed.
+///
+/// Subtasks come before their parent, deepest first, so every task is
+/// closed explicitly before any server-side cascade from its parent.
+/// Tasks listed more than once are kept only at their first position.
+fn with_open_subtasks(cache: &Cache, tasks: &[(String, String)]) -> Vec<(String, String)> {
+    let mut seen: HashSet<String> = HashSet::new();
+    let mut expanded = Vec::new();
+    for (id, content) in tasks {
+        for item in open_subtasks(cache, id).into_iter().rev() {
+            if seen.insert(item.id.clone()) {
+                expanded.push((item.id.clone(), item.content.clone()));
+            }
+        }
+        if seen.insert(id.clone()) {
+            expanded.push((id.clone(), content.clone()));
+        }
+    }
+    expanded
+}
+
+/// Builds one completion command per task.
+fn build_done_commands(
+    tasks: &[(String, String)],
+    command_type: SyncCommandType,
+) -> Vec<SyncCommand> {
+    tasks
+        .iter()
+        .map(|(id, _)| SyncCommand::new(command_type, serde_json::json!({ "id": id })))
+        .collect()
+}
+
+/// Formats the warning listing open subtasks completed along with a parent.
+fn format_subtask_warning(content: &str, subtasks: &[&Item], id_style: IdStyle) -> String {
+    let id_len = id_display_len(subtasks.iter().map(|i| i.id.as_str()), id_style);
+    let mut output = format!(
+        "Warning: completing \"{}\" will also complete {} open subtask(s):\n",
+        content,
+        subtasks.len()
+    );
+    for item in subtasks {
+        output.push_str(&format!(
+            "  - {} ({})\n",
+            item.content,
+            truncate_id_to(&item.id, id_len)
+        ));
+    }
+    output
+}
+
 /// Formats done results as JSON.
-fn format_done_results_json(results: &[DoneResult]) -> Result<String> {
+fn format_done_results_json(results: &[DoneResult], compact: bool) -> Result<String> {
     use serde::Serialize;
 
     #[derive(Serialize)]
@@ -225,12 +331,13 @@ fn format_done_results_json(results: &[DoneResult]) -> Result<String> {
         failed,
     };
 
-    serde_json::to_string_pretty(&output).map_err(CommandError::from)
+    crate::output::to_json(&output, compact).map_err(CommandError::from)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_support::item;
 
     #[test]
     fn test_done_options_single_task() {
@@ -238,6 +345,7 @@ mod tests {
             task_ids: vec!["abc123".to_string()],
             all_occurrences: false,
             force: false,
+            subtasks: false,
         };
 
         assert_eq!(opts.task_ids.len(), 1);
@@ -255,6 +363,7 @@ mod tests {
             ],
             all_occurrences: false,
             force: true,
+            subtasks: false,
         };
 
         assert_eq!(opts.task_ids.len(), 3);
@@ -287,9 +396,125 @@ mod tests {
         assert!(result.error.is_some());
     }
 
-    // Note: Tests for item lookup by prefix are now in SyncManager tests
-    // (resolve_item_by_prefix covers exact match, prefix match, not found,
-    // ambiguous, deleted items, and completion status filtering)
+    #[test]
+    fn test_open_subtasks_includes_nested_open_children_only() {
+        let mut cache = Cache::new();
+        cache.items = vec![
+            item("parent", "Task parent").build(),
+            item("open-child", "Task open-child")
+                .parent("parent")
+                .build(),
+            item("done-child", "Task done-child")
+                .parent("parent")
+                .checked()
+                .build(),
+            item("grandchild", "Task grandchild")
+                .parent("open-child")
+                .build(),
+            item("under-done", "Task under-done")
+                .parent("done-child")
+                .build(),
+            item("unrelated", "Task unrelated").build(),
+        ];
+
+        let ids: Vec<&str> = open_subtasks(&cache, "parent")
+            .iter()
+            .map(|i| i.id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["open-child", "grandchild", "under-done"]);
+        assert!(open_subtasks(&cache, "unrelated").is_empty());
+    }
+
+    #[test]
+    fn test_open_subtasks_survives_parent_cycle() {
+        let mut cache = Cache::new();
+        cache.items = vec![
+            item("a", "Task a").parent("b").build(),
+            item("b", "Task b").parent("a").build(),
+        ];
+
+        let ids: Vec<&str> = open_subtasks(&cache, "a")
+            .iter()
+            .map(|i| i.id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["b"]);
+    }
+
+    #[test]
+    fn test_subtasks_commands_complete_all_open_descendants() {
+        let mut cache = Cache::new();
+        cache.items = vec![
+            item("parent", "Task parent").build(),
+            item("child", "Task child").parent("parent").build(),
+            item("done-child", "Task done-child")
+                .parent("parent")
+                .checked()
+                .build(),
+            item("grandchild", "Task grandchild")
+                .parent("child")
+                .build(),
+            item("other", "Task other").build(),
+        ];
+
+        let tasks =
+            with_open_subtasks(&cache, &[("parent".to_string(), "Task parent".to_string())]);
+        let commands = build_done_commands(&tasks, SyncCommandType::ItemClose);
+
+        let ids: Vec<&str> = commands
+            .iter()
+            .map(|c| c.args["id"].as_str().unwrap())
+            .collect();
+        assert_eq!(ids, vec!["grandchild", "child", "parent"]);
+        assert!(commands
+            .iter()
+            .all(|c| c.command_type == SyncCommandType::ItemClose));
+    }
+
+    #[test]
+    fn test_subtasks_skips_duplicates_across_explicit_tasks() {
+        let mut cache = Cache::new();
+        cache.items = vec![
+            item("parent", "Task parent").build(),
+            item("child", "Task child").parent("parent").build(),
+        ];
+
+        let tasks = with_open_subtasks(
+            &cache,
+            &[
+                ("child".to_string(), "Task child".to_string()),
+                ("parent".to_string(), "Task parent".to_string()),
+            ],
+        );
+
+        let ids: Vec<&str> = tasks.iter().map(|(id, _)| id.as_str()).collect();
+        assert_eq!(ids, vec!["child", "parent"]);
+    }
+
+    #[test]
+    fn test_format_subtask_warning_lists_subtasks() {
+        let child = item("child123456", "Task child123456")
+            .parent("parent")
+            .build();
+        let grandchild = item("grand123456", "Task grand123456")
+            .parent("child123456")
+            .build();
+
+        let warning = format_subtask_warning("Plan trip", &[&child, &grandchild], IdStyle::Short);
+        assert_eq!(
+            warning,
+            "Warning: completing \"Plan trip\" will also complete 2 open subtask(s):\n\
+             \x20 - Task child123456 (child1)\n\
+             \x20 - Task grand123456 (grand1)\n"
+        );
+
+        let warning = format_subtask_warning("Plan trip", &[&child, &grandchild], IdStyle::Full);
+        assert!(warning.contains("(child123456)"));
+        assert!(warning.contains("(grand123456)"));
+    }
+
+    // Note: Tests for item lookup are in SyncManager tests
+    // (resolve_item_arg covers exact match, prefix match, content substring,
+    // not found, ambiguous, and completion status filtering)
 
     #[test]
     fn test_format_done_results_json() {
@@ -308,7 +533,7 @@ mod tests {
             },
         ];
 
-        let json = format_done_results_json(&results).unwrap();
+        let json = format_done_results_json(&results, false).unwrap();
         assert!(json.contains("\"total_completed\": 1"));
         assert!(json.contains("\"total_failed\": 1"));
         assert!(json.contains("Task 1"));
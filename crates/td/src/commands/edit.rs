@@ -2,19 +2,23 @@
 //!
 //! Updates a task via the Sync API's `item_update` and/or `item_move` commands.
 //! Uses SyncManager::execute_commands() to automatically update the cache.
-//! Uses resolve_item_by_prefix(), resolve_project(), and resolve_section()
+//! Uses resolve_item_arg(), resolve_project(), and resolve_section()
 //! for smart lookups with auto-sync fallback.
 
-use todoist_api_rs::client::TodoistClient;
-use todoist_api_rs::sync::{SyncCommand, SyncCommandType};
-use todoist_cache_rs::{CacheStore, SyncManager};
+use chrono::{Local, Months, NaiveDate, TimeDelta};
+use todoist_api_rs::sync::{Due, DueSpec, SyncCommand, SyncCommandType};
+use todoist_cache_rs::Cache;
 
-use super::{CommandContext, CommandError, Result};
+use super::labels::missing_label_commands;
+use super::{
+    due_arg, resolve_due_lang, resolve_section_in_project, validate_due_tz, CommandContext,
+    CommandError, Result,
+};
 
 /// Options for the edit command.
 #[derive(Debug)]
 pub struct EditOptions {
-    /// Task ID (full ID or prefix).
+    /// Task ID, ID prefix, or content text.
     pub task_id: String,
     /// New content/title.
     pub content: Option<String>,
@@ -22,24 +26,43 @@ pub struct EditOptions {
     pub project: Option<String>,
     /// New priority level (1=highest, 4=lowest).
     pub priority: Option<u8>,
-    /// New due date (natural language or ISO).
+    /// New due date (natural language or ISO), or a bump like `+1d`
+    /// relative to the current due date.
     pub due: Option<String>,
+    /// Language of the due date string (e.g., "es").
+    pub lang: Option<String>,
+    /// Timezone for a timed due date (IANA name).
+    pub due_tz: Option<String>,
     /// Remove due date.
     pub no_due: bool,
-    /// Set labels (replaces existing).
-    pub labels: Vec<String>,
+    /// Recurrence pattern to set as the due string (e.g. "every weekday").
+    pub recurring: Option<String>,
+    /// Replace a recurring due date with its next occurrence as a fixed date.
+    pub no_recurring: bool,
+    /// Set labels (replaces existing; an empty list clears them).
+    pub labels: Option<Vec<String>>,
     /// Add a single label.
     pub add_label: Option<String>,
     /// Remove a single label.
     pub remove_label: Option<String>,
+    /// Create labels missing from the cache instead of warning.
+    pub create_labels: bool,
     /// Move to section within project.
     pub section: Option<String>,
     /// New description.
     pub description: Option<String>,
+    /// Text to add after the current description.
+    pub append_description: Option<String>,
+    /// Text to add before the current description.
+    pub prepend_description: Option<String>,
     /// Assign task to user.
     pub assign: Option<String>,
     /// Unassign task.
     pub unassign: bool,
+    /// Move under a new parent task (ID, prefix, or content).
+    pub parent: Option<String>,
+    /// Promote to a top-level task.
+    pub no_parent: bool,
 }
 
 /// Result of a successful edit operation.
@@ -65,36 +88,61 @@ pub struct EditResult {
 ///
 /// Returns an error if syncing fails, task lookup fails, or the API returns an error.
 pub async fn execute(ctx: &CommandContext, opts: &EditOptions, token: &str) -> Result<()> {
+    let due_tz = opts.due_tz.as_deref().map(validate_due_tz).transpose()?;
+
     // Initialize sync manager (loads cache from disk)
-    let client = TodoistClient::new(token)?;
-    let store = CacheStore::new()?;
-    let mut manager = SyncManager::new(client, store)?;
+    let mut manager = ctx.sync_manager(token)?;
 
     // Resolve task using smart lookup (cache-first with auto-sync fallback)
     // require_checked=None to match any task (edit works on completed and uncompleted)
     let item = manager
-        .resolve_item_by_prefix(&opts.task_id, None)
+        .resolve_item_arg(&opts.task_id, None)
         .await
         .map_err(|e| CommandError::Config(e.to_string()))?;
     let task_id = item.id.clone();
     let current_content = item.content.clone();
+    let current_description = item.description.clone();
     let current_labels = item.labels.clone();
     let current_project_id = item.project_id.clone();
     let current_section_id = item.section_id.clone();
+    let current_parent_id = item.parent_id.clone();
+    let current_due = item.due.clone();
+
+    if opts.no_recurring && !current_due.as_ref().is_some_and(|due| due.is_recurring) {
+        return Err(CommandError::Config(format!(
+            "Task '{}' is not recurring",
+            current_content
+        )));
+    }
 
     // Track what we're updating
     let mut updated_fields = Vec::new();
-    let mut commands = Vec::new();
+
+    // Labels that don't exist yet are created first, in the same batch
+    let requested_labels: Vec<String> = match opts.labels {
+        Some(ref labels) => labels.clone(),
+        None => opts.add_label.iter().cloned().collect(),
+    };
+    let mut commands = missing_label_commands(
+        manager.cache(),
+        &requested_labels,
+        opts.create_labels,
+        ctx.quiet,
+    );
 
     // Build item_update command if any update fields are specified
     let has_updates = opts.content.is_some()
         || opts.priority.is_some()
         || opts.due.is_some()
         || opts.no_due
-        || !opts.labels.is_empty()
+        || opts.recurring.is_some()
+        || opts.no_recurring
+        || opts.labels.is_some()
         || opts.add_label.is_some()
         || opts.remove_label.is_some()
         || opts.description.is_some()
+        || opts.append_description.is_some()
+        || opts.prepend_description.is_some()
         || opts.assign.is_some()
         || opts.unassign;
 
@@ -115,20 +163,19 @@ pub async fn execute(ctx: &CommandContext, opts: &EditOptions, token: &str) -> R
             updated_fields.push("priority".to_string());
         }
 
-        if opts.no_due {
-            // Remove due date by setting to null
-            args["due"] = serde_json::Value::Null;
-            updated_fields.push("due (removed)".to_string());
-        } else if let Some(ref due) = opts.due {
-            // Use the "string" field to let Todoist parse natural language dates
-            args["due"] = serde_json::json!({"string": due});
-            updated_fields.push("due".to_string());
-        }
+        set_due_arg(
+            &mut args,
+            &mut updated_fields,
+            opts,
+            current_due.as_ref(),
+            due_tz.as_deref(),
+            manager.cache(),
+        )?;
 
         // Handle labels
-        if !opts.labels.is_empty() {
+        if let Some(ref labels) = opts.labels {
             // Replace all labels
-            args["labels"] = serde_json::json!(opts.labels);
+            args["labels"] = serde_json::json!(labels);
             updated_fields.push("labels".to_string());
         } else if opts.add_label.is_some() || opts.remove_label.is_some() {
             // Modify existing labels
@@ -154,6 +201,14 @@ pub async fn execute(ctx: &CommandContext, opts: &EditOptions, token: &str) -> R
         if let Some(ref description) = opts.description {
             args["description"] = serde_json::json!(description);
             updated_fields.push("description".to_string());
+        } else if opts.append_description.is_some() || opts.prepend_description.is_some() {
+            let description = combine_description(
+                &current_description,
+                opts.prepend_description.as_deref(),
+                opts.append_description.as_deref(),
+            );
+            args["description"] = serde_json::json!(description);
+            updated_fields.push("description".to_string());
         }
 
         if opts.unassign {
@@ -204,16 +259,24 @@ pub async fn execute(ctx: &CommandContext, opts: &EditOptions, token: &str) -> R
 
         // Resolve section name to ID using smart lookup (cache-first with auto-sync fallback)
         if let Some(ref section_name) = opts.section {
-            let section = manager
-                .resolve_section(section_name, Some(&resolved_project_id))
-                .await
-                .map_err(|e| CommandError::Config(e.to_string()))?;
+            // With --project the section must belong to it; on its own,
+            // a section ID may point into another project to move the task there
+            let section_id = if opts.project.is_some() {
+                resolve_section_in_project(&mut manager, section_name, &resolved_project_id).await?
+            } else {
+                manager
+                    .resolve_section(section_name, Some(&resolved_project_id))
+                    .await
+                    .map_err(|e| CommandError::Config(e.to_string()))?
+                    .id
+                    .clone()
+            };
 
             // Only move if section is different
-            if current_section_id.as_ref() != Some(&section.id) {
+            if current_section_id.as_ref() != Some(&section_id) {
                 // Note: When moving to a section, we only set section_id
                 // The project will be implicitly set to the section's project
-                move_args["section_id"] = serde_json::json!(section.id);
+                move_args["section_id"] = serde_json::json!(section_id);
                 if !updated_fields.contains(&"project".to_string()) {
                     updated_fields.push("section".to_string());
                 } else {
@@ -230,16 +293,45 @@ pub async fn execute(ctx: &CommandContext, opts: &EditOptions, token: &str) -> R
         }
     }
 
+    // Build item_move command if re-parenting or promoting to top-level
+    if opts.parent.is_some() || opts.no_parent {
+        let new_parent_id = match opts.parent {
+            Some(ref parent) => Some(
+                manager
+                    .resolve_item_arg(parent, None)
+                    .await
+                    .map_err(|e| CommandError::Config(e.to_string()))?
+                    .id
+                    .clone(),
+            ),
+            None => None,
+        };
+
+        if let Some(move_args) = build_parent_move_args(
+            manager.cache(),
+            &task_id,
+            current_parent_id.as_deref(),
+            new_parent_id.as_deref(),
+        )? {
+            updated_fields.push(if new_parent_id.is_some() {
+                "parent".to_string()
+            } else {
+                "parent (removed)".to_string()
+            });
+            commands.push(SyncCommand::new(SyncCommandType::ItemMove, move_args));
+        }
+    }
+
     // Check if we have any changes to make
     if commands.is_empty() {
         if !ctx.quiet {
-            if ctx.json_output {
+            if ctx.output_format.is_json() {
                 let output = serde_json::json!({
                     "status": "no_changes",
                     "id": task_id,
                     "message": "No changes specified"
                 });
-                println!("{}", serde_json::to_string_pretty(&output)?);
+                println!("{}", crate::output::to_json(&output, ctx.compact_json)?);
             } else {
                 println!(
                     "No changes specified for task {}",
@@ -274,14 +366,14 @@ pub async fn execute(ctx: &CommandContext, opts: &EditOptions, token: &str) -> R
     };
 
     // Output
-    if ctx.json_output {
+    if ctx.output_format.is_json() {
         let output = serde_json::json!({
             "status": "updated",
             "id": result.id,
             "content": result.content,
             "updated_fields": result.updated_fields
         });
-        println!("{}", serde_json::to_string_pretty(&output)?);
+        println!("{}", crate::output::to_json(&output, ctx.compact_json)?);
     } else if !ctx.quiet {
         let content_display = result.content.as_deref().unwrap_or("(unknown)");
         if ctx.verbose {
@@ -299,9 +391,280 @@ pub async fn execute(ctx: &CommandContext, opts: &EditOptions, token: &str) -> R
     Ok(())
 }
 
+/// Parses a label list for `--labels`.
+///
+/// Labels are separated by commas or newlines; surrounding whitespace and
+/// empty entries are dropped, as are repeats. Empty input yields no labels.
+pub fn parse_label_list(input: &str) -> Vec<String> {
+    let mut labels: Vec<String> = Vec::new();
+    for label in input.split([',', '\n']).map(str::trim) {
+        if !label.is_empty() && !labels.iter().any(|l| l == label) {
+            labels.push(label.to_string());
+        }
+    }
+    labels
+}
+
+/// Reads the `--labels` value, taking the list from stdin when it is `-`.
+///
+/// # Errors
+///
+/// Returns `CommandError::Io` if reading stdin fails.
+pub fn read_label_list(value: &str) -> Result<Vec<String>> {
+    use std::io::Read;
+
+    if value == "-" {
+        let mut buf = String::new();
+        std::io::stdin().read_to_string(&mut buf)?;
+        return Ok(parse_label_list(&buf));
+    }
+    Ok(parse_label_list(value))
+}
+
+/// Sets `due` on an `item_update` payload from `--due`, `--no-due`,
+/// `--recurring` or `--no-recurring`.
+///
+/// `--no-due` sends an explicit `null`, which makes the API drop the whole due
+/// object (date, time, and recurrence string), so a recurring task becomes
+/// undated instead of keeping a stale rule. `--recurring` is sent as a due
+/// string for the server to parse, and `--no-recurring` pins the current
+/// occurrence as a plain date. A `--due` bump such as `+1w` shifts the
+/// current due date (or today, for an undated task).
+///
+/// # Errors
+///
+/// Returns an error if a bump moves the date out of range.
+fn set_due_arg(
+    args: &mut serde_json::Value,
+    updated_fields: &mut Vec<String>,
+    opts: &EditOptions,
+    current_due: Option<&Due>,
+    due_tz: Option<&str>,
+    cache: &Cache,
+) -> Result<()> {
+    if let Some(bump) = opts
+        .due
+        .as_deref()
+        .map(parse_due_bump)
+        .transpose()?
+        .flatten()
+    {
+        let today = Local::now().date_naive();
+        args["due"] = bumped_due_arg(current_due, bump, today).ok_or_else(|| {
+            CommandError::Config(format!(
+                "Due date bump '{}' is out of range",
+                opts.due.as_deref().unwrap_or_default()
+            ))
+        })?;
+        updated_fields.push("due".to_string());
+    } else if opts.no_due {
+        args["due"] = serde_json::Value::Null;
+        updated_fields.push("due (removed)".to_string());
+        if current_due.is_some_and(|due| due.is_recurring) {
+            updated_fields.push("recurrence (removed)".to_string());
+        }
+    } else if let Some(ref due) = opts.due {
+        // Use the "string" field to let Todoist parse natural language dates
+        let lang = resolve_due_lang(opts.lang.as_deref(), cache);
+        args["due"] = due_arg(due, lang.as_deref(), due_tz);
+        updated_fields.push("due".to_string());
+    } else if let Some(ref pattern) = opts.recurring {
+        let lang = resolve_due_lang(opts.lang.as_deref(), cache);
+        args["due"] = due_arg(pattern, lang.as_deref(), None);
+        updated_fields.push("recurrence".to_string());
+    } else if opts.no_recurring {
+        if let Some(due) = current_due {
+            args["due"] = fixed_due_arg(due);
+            updated_fields.push("recurrence (removed)".to_string());
+        }
+    }
+    Ok(())
+}
+
+/// A relative `--due` shift such as `+3d`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DueBump {
+    Days(u32),
+    Weeks(u32),
+    Months(u32),
+}
+
+impl DueBump {
+    /// Shifts `date` forward, or returns `None` if the result is out of range.
+    fn apply(self, date: NaiveDate) -> Option<NaiveDate> {
+        match self {
+            Self::Days(n) => date.checked_add_signed(TimeDelta::try_days(i64::from(n))?),
+            Self::Weeks(n) => date.checked_add_signed(TimeDelta::try_weeks(i64::from(n))?),
+            Self::Months(n) => date.checked_add_months(Months::new(n)),
+        }
+    }
+}
+
+/// Parses a `+N[d|w|mo]` due bump (days, weeks or months).
+///
+/// Returns `Ok(None)` for anything else, which is sent to Todoist as a date
+/// string.
+///
+/// # Errors
+///
+/// Returns an error for `+Nm`, since `m` means minutes in td's other
+/// durations.
+fn parse_due_bump(input: &str) -> Result<Option<DueBump>> {
+    let input = input.trim();
+    let Some(rest) = input.strip_prefix('+') else {
+        return Ok(None);
+    };
+    let (count, unit) = rest.split_at(rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(0));
+    let Ok(n) = count.parse::<u32>() else {
+        return Ok(None);
+    };
+    match unit {
+        "d" => Ok(Some(DueBump::Days(n))),
+        "w" => Ok(Some(DueBump::Weeks(n))),
+        "mo" => Ok(Some(DueBump::Months(n))),
+        "m" => Err(CommandError::Config(format!(
+            "Ambiguous due bump '{input}': use +{n}mo to shift by months"
+        ))),
+        _ => Ok(None),
+    }
+}
+
+/// Builds the due date for a task whose due date is shifted by `bump`.
+///
+/// Undated tasks are bumped from `today`. A timed due date keeps its time and
+/// timezone, and a recurring one keeps its rule, so the bump moves the next
+/// occurrence. Returns `None` if the new date is out of range.
+fn bumped_due_arg(
+    current: Option<&Due>,
+    bump: DueBump,
+    today: NaiveDate,
+) -> Option<serde_json::Value> {
+    let Some(due) = current else {
+        return Some(serde_json::json!(DueSpec::from_naive_date(
+            bump.apply(today)?
+        )));
+    };
+    // Timed dates carry their own date part, which may differ from `date` in UTC
+    let current = due.datetime.as_deref().unwrap_or(&due.date);
+    let base = NaiveDate::parse_from_str(current.get(..10)?, "%Y-%m-%d").ok()?;
+    let bumped = bump.apply(base)?.format("%Y-%m-%d").to_string();
+    let mut spec = DueSpec::from_date(format!("{bumped}{}", &current[10..]));
+    if let Some(ref timezone) = due.timezone {
+        spec = spec.with_timezone(timezone);
+    }
+    if due.is_recurring {
+        spec.string = due.string.clone();
+        spec.lang = due.lang.clone();
+        spec.is_recurring = Some(true);
+    }
+    Some(serde_json::json!(spec))
+}
+
+/// Builds a non-recurring due date for the occurrence `due` currently points at.
+///
+/// Timed occurrences keep their time and timezone.
+fn fixed_due_arg(due: &Due) -> serde_json::Value {
+    let mut spec = DueSpec::from_date(due.datetime.as_deref().unwrap_or(&due.date));
+    if let Some(ref timezone) = due.timezone {
+        spec = spec.with_timezone(timezone);
+    }
+    spec.is_recurring = Some(false);
+    serde_json::json!(spec)
+}
+
+/// Adds `prepend` before and `append` after the current description.
+///
+/// Each piece is joined to non-empty existing text with a newline.
+fn combine_description(current: &str, prepend: Option<&str>, append: Option<&str>) -> String {
+    let mut description = current.to_string();
+    if let Some(prepend) = prepend {
+        description = if description.is_empty() {
+            prepend.to_string()
+        } else {
+            format!("{prepend}\n{description}")
+        };
+    }
+    if let Some(append) = append {
+        if !description.is_empty() {
+            description.push('\n');
+        }
+        description.push_str(append);
+    }
+    description
+}
+
+/// Builds `item_move` arguments for changing a task's parent.
+///
+/// With `new_parent_id` set, the task is moved under that parent; with `None`
+/// it is promoted to the top level of its current section (or project).
+/// Returns `Ok(None)` when the task is already in the requested position.
+///
+/// # Errors
+///
+/// Returns an error if the move would make the task its own ancestor.
+fn build_parent_move_args(
+    cache: &Cache,
+    task_id: &str,
+    current_parent_id: Option<&str>,
+    new_parent_id: Option<&str>,
+) -> Result<Option<serde_json::Value>> {
+    match new_parent_id {
+        Some(parent_id) => {
+            if current_parent_id == Some(parent_id) {
+                return Ok(None);
+            }
+            if is_ancestor_or_self(cache, task_id, parent_id) {
+                return Err(CommandError::Config(format!(
+                    "Cannot move task {} under {}: a task cannot be nested under itself or its own subtasks",
+                    &task_id[..6.min(task_id.len())],
+                    &parent_id[..6.min(parent_id.len())]
+                )));
+            }
+            Ok(Some(serde_json::json!({
+                "id": task_id,
+                "parent_id": parent_id,
+            })))
+        }
+        None => {
+            if current_parent_id.is_none() {
+                return Ok(None);
+            }
+            // item_move to the task's own section/project places it at the top level
+            let item = cache.find_item(task_id);
+            let mut args = serde_json::json!({ "id": task_id });
+            match item.and_then(|i| i.section_id.as_ref()) {
+                Some(section_id) => args["section_id"] = serde_json::json!(section_id),
+                None => {
+                    let project_id = item.map(|i| i.project_id.as_str()).unwrap_or_default();
+                    args["project_id"] = serde_json::json!(project_id);
+                }
+            }
+            Ok(Some(args))
+        }
+    }
+}
+
+/// Returns true if `task_id` is `candidate_id` or one of its ancestors.
+///
+/// Walks the cached parent chain upward from `candidate_id`.
+fn is_ancestor_or_self(cache: &Cache, task_id: &str, candidate_id: &str) -> bool {
+    let mut current = Some(candidate_id);
+    // Bound the walk by the number of items to stay safe on corrupt (cyclic) caches
+    for _ in 0..=cache.items.len() {
+        match current {
+            Some(id) if id == task_id => return true,
+            Some(id) => current = cache.find_item(id).and_then(|i| i.parent_id.as_deref()),
+            None => return false,
+        }
+    }
+    false
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_support::{self, item};
+    use todoist_api_rs::sync::Item;
 
     #[test]
     fn test_edit_options_defaults() {
@@ -311,20 +674,29 @@ mod tests {
             project: None,
             priority: None,
             due: None,
+            lang: None,
+            due_tz: None,
             no_due: false,
-            labels: vec![],
+            recurring: None,
+            no_recurring: false,
+            labels: None,
             add_label: None,
             remove_label: None,
+            create_labels: false,
             section: None,
             description: None,
+            append_description: None,
+            prepend_description: None,
             assign: None,
             unassign: false,
+            parent: None,
+            no_parent: false,
         };
 
         assert_eq!(opts.task_id, "abc123");
         assert!(opts.content.is_none());
         assert!(!opts.no_due);
-        assert!(opts.labels.is_empty());
+        assert!(opts.labels.is_none());
     }
 
     #[test]
@@ -335,14 +707,23 @@ mod tests {
             project: Some("Work".to_string()),
             priority: Some(1),
             due: Some("tomorrow".to_string()),
+            lang: Some("es".to_string()),
+            due_tz: None,
             no_due: false,
-            labels: vec!["urgent".to_string(), "important".to_string()],
+            recurring: None,
+            no_recurring: false,
+            labels: Some(vec!["urgent".to_string(), "important".to_string()]),
             add_label: None,
             remove_label: None,
+            create_labels: false,
             section: Some("In Progress".to_string()),
             description: Some("New description".to_string()),
+            append_description: None,
+            prepend_description: None,
             assign: None,
             unassign: false,
+            parent: None,
+            no_parent: false,
         };
 
         assert_eq!(opts.task_id, "abc123def456");
@@ -351,7 +732,7 @@ mod tests {
         assert_eq!(opts.priority, Some(1));
         assert_eq!(opts.due, Some("tomorrow".to_string()));
         assert!(!opts.no_due);
-        assert_eq!(opts.labels.len(), 2);
+        assert_eq!(opts.labels.as_ref().map(Vec::len), Some(2));
         assert_eq!(opts.section, Some("In Progress".to_string()));
         assert_eq!(opts.description, Some("New description".to_string()));
     }
@@ -364,14 +745,23 @@ mod tests {
             project: None,
             priority: None,
             due: None,
+            lang: None,
+            due_tz: None,
             no_due: true,
-            labels: vec![],
+            recurring: None,
+            no_recurring: false,
+            labels: None,
             add_label: None,
             remove_label: None,
+            create_labels: false,
             section: None,
             description: None,
+            append_description: None,
+            prepend_description: None,
             assign: None,
             unassign: false,
+            parent: None,
+            no_parent: false,
         };
 
         assert!(opts.no_due);
@@ -386,21 +776,64 @@ mod tests {
             project: None,
             priority: None,
             due: None,
+            lang: None,
+            due_tz: None,
             no_due: false,
-            labels: vec![],
+            recurring: None,
+            no_recurring: false,
+            labels: None,
             add_label: Some("new-label".to_string()),
             remove_label: Some("old-label".to_string()),
+            create_labels: false,
             section: None,
             description: None,
+            append_description: None,
+            prepend_description: None,
             assign: None,
             unassign: false,
+            parent: None,
+            no_parent: false,
         };
 
-        assert!(opts.labels.is_empty());
+        assert!(opts.labels.is_none());
         assert_eq!(opts.add_label, Some("new-label".to_string()));
         assert_eq!(opts.remove_label, Some("old-label".to_string()));
     }
 
+    #[test]
+    fn test_parse_label_list_newlines() {
+        assert_eq!(
+            parse_label_list("work\nurgent\n"),
+            vec!["work".to_string(), "urgent".to_string()]
+        );
+        assert_eq!(
+            parse_label_list("work\r\n\nurgent"),
+            vec!["work".to_string(), "urgent".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_parse_label_list_commas() {
+        assert_eq!(
+            parse_label_list("work, urgent,,work"),
+            vec!["work".to_string(), "urgent".to_string()]
+        );
+        assert_eq!(
+            parse_label_list("home,errand\nsomeday"),
+            vec![
+                "home".to_string(),
+                "errand".to_string(),
+                "someday".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_label_list_empty_clears() {
+        assert!(parse_label_list("").is_empty());
+        assert!(parse_label_list(" \n\n, ").is_empty());
+    }
+
     #[test]
     fn test_priority_conversion() {
         // User priority 1 (highest) -> API priority 4
@@ -409,7 +842,411 @@ mod tests {
         assert_eq!(5 - 4, 1);
     }
 
-    // Note: Tests for item lookup by prefix are now in SyncManager tests
-    // (resolve_item_by_prefix covers exact match, prefix match, not found,
-    // ambiguous, deleted items, and completion status filtering)
+    /// Tree: root -> child -> grandchild, plus a standalone task and a
+    /// sectioned subtask.
+    fn make_test_cache() -> Cache {
+        let items = vec![
+            item("root", "Task root").build(),
+            item("child", "Task child").parent("root").build(),
+            item("grandchild", "Task grandchild")
+                .parent("child")
+                .build(),
+            item("other", "Task other").build(),
+            item("sub-in-section", "Task sub-in-section")
+                .parent("other")
+                .section("sec-1")
+                .build(),
+        ];
+        Cache::with_data(
+            "token".to_string(),
+            None,
+            None,
+            items,
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            None,
+        )
+    }
+
+    fn make_recurring_due() -> Due {
+        Due {
+            date: "2025-01-20".to_string(),
+            datetime: None,
+            string: Some("every monday".to_string()),
+            timezone: None,
+            is_recurring: true,
+            lang: Some("en".to_string()),
+        }
+    }
+
+    fn no_due_opts(task_id: &str) -> EditOptions {
+        EditOptions {
+            task_id: task_id.to_string(),
+            content: None,
+            project: None,
+            priority: None,
+            due: None,
+            lang: None,
+            due_tz: None,
+            no_due: true,
+            recurring: None,
+            no_recurring: false,
+            labels: None,
+            add_label: None,
+            remove_label: None,
+            create_labels: false,
+            section: None,
+            description: None,
+            append_description: None,
+            prepend_description: None,
+            assign: None,
+            unassign: false,
+            parent: None,
+            no_parent: false,
+        }
+    }
+
+    #[test]
+    fn test_recurring_sets_due_string() {
+        let opts = EditOptions {
+            no_due: false,
+            recurring: Some("every weekday".to_string()),
+            ..no_due_opts("other")
+        };
+        let mut args = serde_json::json!({ "id": "other" });
+        let mut fields = Vec::new();
+        set_due_arg(
+            &mut args,
+            &mut fields,
+            &opts,
+            None,
+            None,
+            &make_test_cache(),
+        )
+        .unwrap();
+
+        assert_eq!(args["due"], serde_json::json!({"string": "every weekday"}));
+        assert_eq!(fields, vec!["recurrence"]);
+    }
+
+    #[test]
+    fn test_no_recurring_pins_current_occurrence() {
+        let opts = EditOptions {
+            no_due: false,
+            no_recurring: true,
+            ..no_due_opts("weekly")
+        };
+        let mut args = serde_json::json!({ "id": "weekly" });
+        let mut fields = Vec::new();
+        set_due_arg(
+            &mut args,
+            &mut fields,
+            &opts,
+            Some(&make_recurring_due()),
+            None,
+            &make_test_cache(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            args["due"],
+            serde_json::json!({"date": "2025-01-20", "is_recurring": false})
+        );
+        assert_eq!(fields, vec!["recurrence (removed)"]);
+    }
+
+    #[test]
+    fn test_fixed_due_arg_keeps_time_and_timezone() {
+        let due = Due {
+            datetime: Some("2025-01-20T09:00:00Z".to_string()),
+            timezone: Some("Europe/Berlin".to_string()),
+            ..make_recurring_due()
+        };
+
+        assert_eq!(
+            fixed_due_arg(&due),
+            serde_json::json!({
+                "date": "2025-01-20T09:00:00Z",
+                "timezone": "Europe/Berlin",
+                "is_recurring": false
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_due_bump_units() {
+        let bump = |input| parse_due_bump(input).unwrap();
+        assert_eq!(bump("+1d"), Some(DueBump::Days(1)));
+        assert_eq!(bump("+2w"), Some(DueBump::Weeks(2)));
+        assert_eq!(bump(" +12mo "), Some(DueBump::Months(12)));
+        assert_eq!(bump("1d"), None);
+        assert_eq!(bump("+d"), None);
+        assert_eq!(bump("+1y"), None);
+        assert_eq!(bump("+-1d"), None);
+        assert_eq!(bump("+1 day"), None);
+        assert_eq!(bump("tomorrow"), None);
+    }
+
+    #[test]
+    fn test_parse_due_bump_rejects_minutes_unit() {
+        let err = parse_due_bump("+3m").unwrap_err().to_string();
+        assert!(err.contains("+3mo"), "{err}");
+    }
+
+    #[test]
+    fn test_due_bump_months_clamps_to_month_end() {
+        let jan31 = NaiveDate::from_ymd_opt(2025, 1, 31).unwrap();
+        assert_eq!(
+            DueBump::Months(1).apply(jan31),
+            NaiveDate::from_ymd_opt(2025, 2, 28)
+        );
+        assert_eq!(
+            DueBump::Weeks(1).apply(jan31),
+            NaiveDate::from_ymd_opt(2025, 2, 7)
+        );
+    }
+
+    #[test]
+    fn test_bump_dated_task_shifts_from_current_due() {
+        let today = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+        let due = Due::from_date("2025-01-20");
+
+        assert_eq!(
+            bumped_due_arg(Some(&due), DueBump::Days(3), today),
+            Some(serde_json::json!({"date": "2025-01-23"}))
+        );
+    }
+
+    #[test]
+    fn test_bump_timed_task_keeps_time_and_timezone() {
+        let today = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+        let due = Due {
+            date: "2025-01-20".to_string(),
+            datetime: Some("2025-01-20T09:00:00Z".to_string()),
+            string: None,
+            timezone: Some("Europe/Berlin".to_string()),
+            is_recurring: false,
+            lang: None,
+        };
+
+        assert_eq!(
+            bumped_due_arg(Some(&due), DueBump::Weeks(1), today),
+            Some(serde_json::json!({
+                "date": "2025-01-27T09:00:00Z",
+                "timezone": "Europe/Berlin"
+            }))
+        );
+    }
+
+    #[test]
+    fn test_bump_undated_task_shifts_from_today() {
+        let today = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+
+        assert_eq!(
+            bumped_due_arg(None, DueBump::Days(1), today),
+            Some(serde_json::json!({"date": "2025-03-02"}))
+        );
+    }
+
+    #[test]
+    fn test_bump_recurring_task_keeps_rule() {
+        let today = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+
+        assert_eq!(
+            bumped_due_arg(Some(&make_recurring_due()), DueBump::Weeks(1), today),
+            Some(serde_json::json!({
+                "date": "2025-01-27",
+                "string": "every monday",
+                "lang": "en",
+                "is_recurring": true
+            }))
+        );
+    }
+
+    #[test]
+    fn test_due_bump_sets_due_field() {
+        let opts = EditOptions {
+            no_due: false,
+            due: Some("+2d".to_string()),
+            ..no_due_opts("other")
+        };
+        let mut args = serde_json::json!({ "id": "other" });
+        let mut fields = Vec::new();
+        set_due_arg(
+            &mut args,
+            &mut fields,
+            &opts,
+            Some(&Due::from_date("2025-01-20")),
+            None,
+            &make_test_cache(),
+        )
+        .unwrap();
+
+        assert_eq!(args["due"], serde_json::json!({"date": "2025-01-22"}));
+        assert_eq!(fields, vec!["due"]);
+    }
+
+    #[test]
+    fn test_no_due_on_plain_task_sends_null_due() {
+        let mut args = serde_json::json!({ "id": "other" });
+        let mut fields = Vec::new();
+        set_due_arg(
+            &mut args,
+            &mut fields,
+            &no_due_opts("other"),
+            None,
+            None,
+            &make_test_cache(),
+        )
+        .unwrap();
+
+        assert!(args["due"].is_null());
+        assert_eq!(fields, vec!["due (removed)"]);
+    }
+
+    #[tokio::test]
+    async fn test_no_due_clears_recurring_due_in_cache() {
+        use wiremock::matchers::{body_string_contains, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mut cache = make_test_cache();
+        let recurring = item("weekly", "Task weekly")
+            .with_due(make_recurring_due())
+            .build();
+        cache.items.push(recurring.clone());
+        cache.rebuild_indexes();
+
+        let mut args = serde_json::json!({ "id": "weekly" });
+        let mut fields = Vec::new();
+        set_due_arg(
+            &mut args,
+            &mut fields,
+            &no_due_opts("weekly"),
+            recurring.due.as_ref(),
+            None,
+            &cache,
+        )
+        .unwrap();
+        assert_eq!(fields, vec!["due (removed)", "recurrence (removed)"]);
+
+        // The server echoes the task back without a due object
+        let command = SyncCommand::new(SyncCommandType::ItemUpdate, args);
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/sync"))
+            .and(body_string_contains("%22due%22%3Anull"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "sync_token": "after_edit",
+                "full_sync": false,
+                "items": [Item { due: None, ..recurring }],
+                "sync_status": { command.uuid.clone(): "ok" },
+                "temp_id_mapping": {}
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let (mut manager, _dir) = test_support::sync_manager(&cache, &server.uri());
+
+        manager.execute_commands(vec![command]).await.unwrap();
+
+        let item = manager.cache().find_item("weekly").unwrap();
+        assert!(
+            item.due.is_none(),
+            "recurrence should be gone: {:?}",
+            item.due
+        );
+    }
+
+    #[test]
+    fn test_parent_move_reparents_task() {
+        let cache = make_test_cache();
+        let args = build_parent_move_args(&cache, "grandchild", Some("child"), Some("other"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(args["id"], "grandchild");
+        assert_eq!(args["parent_id"], "other");
+    }
+
+    #[test]
+    fn test_parent_move_same_parent_is_noop() {
+        let cache = make_test_cache();
+        let args = build_parent_move_args(&cache, "child", Some("root"), Some("root")).unwrap();
+        assert!(args.is_none());
+    }
+
+    #[test]
+    fn test_parent_move_promotes_to_top_level() {
+        let cache = make_test_cache();
+
+        let args = build_parent_move_args(&cache, "child", Some("root"), None)
+            .unwrap()
+            .unwrap();
+        assert_eq!(args["project_id"], "proj-1");
+        assert!(args.get("parent_id").is_none());
+
+        // Sectioned subtasks stay in their section
+        let args = build_parent_move_args(&cache, "sub-in-section", Some("other"), None)
+            .unwrap()
+            .unwrap();
+        assert_eq!(args["section_id"], "sec-1");
+        assert!(args.get("project_id").is_none());
+
+        // Already top-level: nothing to do
+        let args = build_parent_move_args(&cache, "root", None, None).unwrap();
+        assert!(args.is_none());
+    }
+
+    #[test]
+    fn test_parent_move_rejects_cycles() {
+        let cache = make_test_cache();
+
+        // Under itself
+        let result = build_parent_move_args(&cache, "root", None, Some("root"));
+        assert!(result.is_err());
+
+        // Under its own descendant
+        let result = build_parent_move_args(&cache, "root", None, Some("grandchild"));
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("cannot be nested"));
+    }
+
+    #[test]
+    fn test_combine_description_append() {
+        assert_eq!(combine_description("", None, Some("Note")), "Note");
+        assert_eq!(
+            combine_description("Existing", None, Some("Note")),
+            "Existing\nNote"
+        );
+    }
+
+    #[test]
+    fn test_combine_description_prepend() {
+        assert_eq!(combine_description("", Some("Note"), None), "Note");
+        assert_eq!(
+            combine_description("Existing", Some("Note"), None),
+            "Note\nExisting"
+        );
+    }
+
+    #[test]
+    fn test_combine_description_prepend_and_append() {
+        assert_eq!(
+            combine_description("Body", Some("Top"), Some("Bottom")),
+            "Top\nBody\nBottom"
+        );
+        assert_eq!(
+            combine_description("", Some("Top"), Some("Bottom")),
+            "Top\nBottom"
+        );
+    }
+
+    // Note: Tests for item lookup are in SyncManager tests
+    // (resolve_item_arg covers exact match, prefix match, content substring,
+    // not found, ambiguous, and completion status filtering)
 }
@@ -3,14 +3,15 @@
 //! Lists and manages saved filters via the Sync API.
 //! Uses SyncManager::execute_commands() to automatically update the cache.
 
-use todoist_api_rs::client::TodoistClient;
-use todoist_api_rs::sync::{Filter, SyncCommand, SyncCommandType};
-use todoist_cache_rs::{Cache, CacheStore, SyncManager};
+use todoist_api_rs::sync::{Filter, Item, SyncCommand, SyncCommandType};
+use todoist_cache_rs::filter::{FilterContext, FilterEvaluator, FilterParser};
+use todoist_cache_rs::Cache;
 
-use super::{CommandContext, CommandError, Result};
+use super::{ensure_fresh, CommandContext, CommandError, Result};
 use crate::output::{
     format_created_filter, format_deleted_filter, format_edited_filter, format_filter_details_json,
-    format_filter_details_table, format_filters_json, format_filters_table,
+    format_filter_details_table, format_filters_csv, format_filters_json, format_filters_table,
+    OutputFormat,
 };
 
 /// Options for the filters list command.
@@ -18,6 +19,8 @@ use crate::output::{
 pub struct FiltersListOptions {
     /// Limit results.
     pub limit: Option<u32>,
+    /// Only show favorite filters.
+    pub favorites_only: bool,
 }
 
 /// Executes the filters list command.
@@ -33,41 +36,42 @@ pub struct FiltersListOptions {
 /// Returns an error if syncing fails.
 pub async fn execute(ctx: &CommandContext, opts: &FiltersListOptions, token: &str) -> Result<()> {
     // Initialize sync manager
-    let client = TodoistClient::new(token)?;
-    let store = CacheStore::new()?;
-    let mut manager = SyncManager::new(client, store)?;
+    let mut manager = ctx.sync_manager(token)?;
 
-    // Only sync if explicitly requested with --sync flag
-    if ctx.sync_first {
-        if ctx.verbose {
-            eprintln!("Syncing with Todoist...");
-        }
-        manager.sync().await?;
-    }
+    ensure_fresh(ctx, &mut manager).await?;
 
     let cache = manager.cache();
 
     // Get filters and apply filters
-    let filters = filter_filters(cache);
+    let filters = filter_filters(cache, opts.favorites_only);
 
     // Apply limit
     let filters = apply_limit(filters, opts);
 
     // Output
-    if ctx.json_output {
-        let output = format_filters_json(&filters)?;
-        println!("{output}");
-    } else if !ctx.quiet {
-        let output = format_filters_table(&filters, ctx.use_colors);
-        print!("{output}");
+    match ctx.output_format {
+        OutputFormat::Json => {
+            let output = format_filters_json(&filters, ctx.compact_json)?;
+            println!("{output}");
+        }
+        OutputFormat::Csv => print!("{}", format_filters_csv(&filters)),
+        OutputFormat::Table if !ctx.quiet => {
+            let output = format_filters_table(&filters, ctx.use_colors);
+            print!("{output}");
+        }
+        OutputFormat::Table => {}
     }
 
     Ok(())
 }
 
-/// Filters filters (excludes deleted).
-fn filter_filters(cache: &Cache) -> Vec<&Filter> {
-    let mut filters: Vec<&Filter> = cache.filters.iter().filter(|f| !f.is_deleted).collect();
+/// Filters filters (excludes deleted, and non-favorites if requested).
+fn filter_filters(cache: &Cache, favorites_only: bool) -> Vec<&Filter> {
+    let mut filters: Vec<&Filter> = cache
+        .filters
+        .iter()
+        .filter(|f| !f.is_deleted && (!favorites_only || f.is_favorite))
+        .collect();
 
     // Sort by item_order for consistent display
     filters.sort_by_key(|f| f.item_order);
@@ -99,6 +103,8 @@ pub struct FiltersAddOptions {
     pub color: Option<String>,
     /// Mark as favorite.
     pub favorite: bool,
+    /// Reject queries the local filter parser cannot understand.
+    pub strict: bool,
 }
 
 /// Result of a successful filter add operation.
@@ -141,10 +147,10 @@ pub async fn execute_add(
         }
     }
 
+    validate_query(&opts.query, opts.strict, ctx.quiet)?;
+
     // Initialize sync manager (loads cache from disk)
-    let client = TodoistClient::new(token)?;
-    let store = CacheStore::new()?;
-    let mut manager = SyncManager::new(client, store)?;
+    let mut manager = ctx.sync_manager(token)?;
 
     // Build the filter_add command arguments
     let temp_id = uuid::Uuid::new_v4().to_string();
@@ -199,8 +205,8 @@ pub async fn execute_add(
     };
 
     // Output
-    if ctx.json_output {
-        let output = format_created_filter(&result)?;
+    if ctx.output_format.is_json() {
+        let output = format_created_filter(&result, ctx.compact_json)?;
         println!("{output}");
     } else if !ctx.quiet {
         if ctx.verbose {
@@ -224,6 +230,25 @@ pub async fn execute_add(
     Ok(())
 }
 
+/// Validates a filter query against the local filter parser.
+///
+/// Todoist supports filter syntax beyond what the local parser understands,
+/// so an unparseable query only produces a warning on stderr by default.
+/// With `strict`, the parse error (including its position) is returned instead.
+fn validate_query(query: &str, strict: bool, quiet: bool) -> Result<()> {
+    match FilterParser::parse(query) {
+        Ok(_) => Ok(()),
+        Err(e) if strict => Err(CommandError::Filter(e)),
+        Err(e) => {
+            if !quiet {
+                eprintln!("Warning: query not understood by local filter engine: {e}");
+                eprintln!("  It will be saved as-is. Use --strict to reject such queries.");
+            }
+            Ok(())
+        }
+    }
+}
+
 /// Valid Todoist color names.
 const VALID_COLORS: &[&str] = &[
     "berry_red",
@@ -262,6 +287,25 @@ fn is_valid_color(color: &str) -> bool {
 pub struct FiltersShowOptions {
     /// Filter ID (full ID or prefix).
     pub filter_id: String,
+    /// Also list the first few matching tasks.
+    pub preview: bool,
+}
+
+/// Number of tasks listed by `filters show --preview`.
+pub const PREVIEW_LIMIT: usize = 5;
+
+/// What a saved filter's query currently matches in the cache.
+#[derive(Debug)]
+pub enum FilterMatches {
+    /// The query was evaluated against the cached open tasks.
+    Evaluated {
+        /// Number of matching open tasks.
+        count: usize,
+        /// The first matches, when a preview was requested.
+        preview: Vec<Item>,
+    },
+    /// The query uses syntax the local parser doesn't support.
+    Unsupported(String),
 }
 
 /// Result of a successful filter show operation.
@@ -269,6 +313,8 @@ pub struct FiltersShowOptions {
 pub struct FilterShowResult {
     /// The filter.
     pub filter: Filter,
+    /// What the filter's query matches in the cache.
+    pub matches: FilterMatches,
 }
 
 /// Executes the filters show command.
@@ -288,17 +334,9 @@ pub async fn execute_show(
     token: &str,
 ) -> Result<()> {
     // Initialize sync manager to resolve filter ID
-    let client = TodoistClient::new(token)?;
-    let store = CacheStore::new()?;
-    let mut manager = SyncManager::new(client, store)?;
+    let mut manager = ctx.sync_manager(token)?;
 
-    // Only sync if explicitly requested with --sync flag
-    if ctx.sync_first {
-        if ctx.verbose {
-            eprintln!("Syncing with Todoist...");
-        }
-        manager.sync().await?;
-    }
+    ensure_fresh(ctx, &mut manager).await?;
 
     let cache = manager.cache();
 
@@ -307,11 +345,12 @@ pub async fn execute_show(
 
     let result = FilterShowResult {
         filter: filter.clone(),
+        matches: evaluate_query(cache, &filter.query, opts.preview),
     };
 
     // Output
-    if ctx.json_output {
-        let output = format_filter_details_json(&result)?;
+    if ctx.output_format.is_json() {
+        let output = format_filter_details_json(&result, ctx.compact_json)?;
         println!("{output}");
     } else if !ctx.quiet {
         let output = format_filter_details_table(&result, ctx.use_colors);
@@ -321,6 +360,32 @@ pub async fn execute_show(
     Ok(())
 }
 
+/// Evaluates a saved filter's query against the cache's open tasks.
+///
+/// With `preview`, the first [`PREVIEW_LIMIT`] matches are kept as well.
+/// Queries the local parser can't handle are reported, not treated as errors.
+fn evaluate_query(cache: &Cache, query: &str, preview: bool) -> FilterMatches {
+    let filter = match FilterParser::parse(query) {
+        Ok(filter) => filter,
+        Err(e) => return FilterMatches::Unsupported(e.to_string()),
+    };
+    let context = FilterContext::from_cache(cache);
+    let evaluator = FilterEvaluator::new(&filter, &context);
+    let matching: Vec<&Item> = cache
+        .items
+        .iter()
+        .filter(|i| !i.is_deleted && !i.checked && evaluator.matches(i))
+        .collect();
+    FilterMatches::Evaluated {
+        count: matching.len(),
+        preview: if preview {
+            matching.into_iter().take(PREVIEW_LIMIT).cloned().collect()
+        } else {
+            Vec::new()
+        },
+    }
+}
+
 // ============================================================================
 // Filters Edit Command
 // ============================================================================
@@ -338,6 +403,8 @@ pub struct FiltersEditOptions {
     pub color: Option<String>,
     /// Set favorite status.
     pub favorite: Option<bool>,
+    /// Reject queries the local filter parser cannot understand.
+    pub strict: bool,
 }
 
 /// Result of a successful filter edit operation.
@@ -378,10 +445,12 @@ pub async fn execute_edit(
         ));
     }
 
+    if let Some(ref query) = opts.query {
+        validate_query(query, opts.strict, ctx.quiet)?;
+    }
+
     // Initialize sync manager (loads cache from disk)
-    let client = TodoistClient::new(token)?;
-    let store = CacheStore::new()?;
-    let mut manager = SyncManager::new(client, store)?;
+    let mut manager = ctx.sync_manager(token)?;
 
     // Find the filter by ID or prefix and extract owned data before mutation
     let (filter_id, filter_name) = {
@@ -399,35 +468,7 @@ pub async fn execute_edit(
         }
     }
 
-    // Build the filter_update command arguments
-    let mut args = serde_json::json!({
-        "id": filter_id,
-    });
-
-    let mut updated_fields = Vec::new();
-
-    if let Some(ref name) = opts.name {
-        args["name"] = serde_json::json!(name);
-        updated_fields.push("name".to_string());
-    }
-
-    if let Some(ref query) = opts.query {
-        args["query"] = serde_json::json!(query);
-        updated_fields.push("query".to_string());
-    }
-
-    if let Some(ref color) = opts.color {
-        args["color"] = serde_json::json!(color);
-        updated_fields.push("color".to_string());
-    }
-
-    if let Some(favorite) = opts.favorite {
-        args["is_favorite"] = serde_json::json!(favorite);
-        updated_fields.push("favorite".to_string());
-    }
-
-    // Create the command
-    let command = SyncCommand::new(SyncCommandType::FilterUpdate, args);
+    let (command, updated_fields) = build_filter_update_command(&filter_id, opts);
 
     // Execute the command via SyncManager
     // This sends the command, applies the response to cache, and saves to disk
@@ -453,8 +494,8 @@ pub async fn execute_edit(
     };
 
     // Output
-    if ctx.json_output {
-        let output = format_edited_filter(&result)?;
+    if ctx.output_format.is_json() {
+        let output = format_edited_filter(&result, ctx.compact_json)?;
         println!("{output}");
     } else if !ctx.quiet {
         if ctx.verbose {
@@ -469,6 +510,56 @@ pub async fn execute_edit(
     Ok(())
 }
 
+/// Builds the `filter_update` command for an edit, returning it along with
+/// the names of the fields it changes.
+fn build_filter_update_command(
+    filter_id: &str,
+    opts: &FiltersEditOptions,
+) -> (SyncCommand, Vec<String>) {
+    let mut args = serde_json::json!({
+        "id": filter_id,
+    });
+
+    let mut updated_fields = Vec::new();
+
+    if let Some(ref name) = opts.name {
+        args["name"] = serde_json::json!(name);
+        updated_fields.push("name".to_string());
+    }
+
+    if let Some(ref query) = opts.query {
+        args["query"] = serde_json::json!(query);
+        updated_fields.push("query".to_string());
+    }
+
+    if let Some(ref color) = opts.color {
+        args["color"] = serde_json::json!(color);
+        updated_fields.push("color".to_string());
+    }
+
+    if let Some(favorite) = opts.favorite {
+        args["is_favorite"] = serde_json::json!(favorite);
+        updated_fields.push("favorite".to_string());
+    }
+
+    (
+        SyncCommand::new(SyncCommandType::FilterUpdate, args),
+        updated_fields,
+    )
+}
+
+/// Builds edit options that only set or clear a filter's favorite flag.
+pub fn favorite_edit_options(filter_id: &str, favorite: bool) -> FiltersEditOptions {
+    FiltersEditOptions {
+        filter_id: filter_id.to_string(),
+        name: None,
+        query: None,
+        color: None,
+        favorite: Some(favorite),
+        strict: false,
+    }
+}
+
 /// Finds a filter by full ID or unique prefix.
 fn find_filter_by_id_or_prefix<'a>(cache: &'a Cache, id: &str) -> Result<&'a Filter> {
     // First try exact match
@@ -542,9 +633,7 @@ pub async fn execute_delete(
     token: &str,
 ) -> Result<()> {
     // Initialize sync manager (loads cache from disk)
-    let client = TodoistClient::new(token)?;
-    let store = CacheStore::new()?;
-    let mut manager = SyncManager::new(client, store)?;
+    let mut manager = ctx.sync_manager(token)?;
 
     // Find the filter by ID or prefix and extract owned data before mutation
     let (filter_id, filter_name) = {
@@ -597,8 +686,8 @@ pub async fn execute_delete(
     };
 
     // Output
-    if ctx.json_output {
-        let output = format_deleted_filter(&result)?;
+    if ctx.output_format.is_json() {
+        let output = format_deleted_filter(&result, ctx.compact_json)?;
         println!("{output}");
     } else if !ctx.quiet {
         if ctx.verbose {
@@ -615,6 +704,7 @@ pub async fn execute_delete(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_support::item;
 
     #[test]
     fn test_filters_list_options_defaults() {
@@ -625,7 +715,10 @@ mod tests {
 
     #[test]
     fn test_filters_list_options_with_values() {
-        let opts = FiltersListOptions { limit: Some(10) };
+        let opts = FiltersListOptions {
+            limit: Some(10),
+            favorites_only: false,
+        };
 
         assert_eq!(opts.limit, Some(10));
     }
@@ -637,6 +730,7 @@ mod tests {
             query: "today & p1".to_string(),
             color: Some("red".to_string()),
             favorite: true,
+            strict: false,
         };
 
         assert_eq!(opts.name, "Today & High Priority");
@@ -656,15 +750,79 @@ mod tests {
         assert!(!is_valid_color("Blue")); // Case-sensitive
     }
 
+    #[test]
+    fn test_validate_query_valid_passes_strict() {
+        assert!(validate_query("today & p1", true, true).is_ok());
+        assert!(validate_query("(overdue | today) & #Work", true, true).is_ok());
+    }
+
+    #[test]
+    fn test_validate_query_invalid_fails_strict() {
+        let result = validate_query("today & (p1", true, true);
+        assert!(matches!(result, Err(CommandError::Filter(_))));
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("position"));
+    }
+
+    #[test]
+    fn test_validate_query_invalid_warns_without_strict() {
+        assert!(validate_query("today & (p1", false, true).is_ok());
+    }
+
     #[test]
     fn test_filters_show_options() {
         let opts = FiltersShowOptions {
             filter_id: "filter-123".to_string(),
+            preview: false,
         };
 
         assert_eq!(opts.filter_id, "filter-123");
     }
 
+    #[test]
+    fn test_evaluate_query_counts_open_matching_tasks() {
+        let mut cache = make_test_cache_with_filters();
+        cache.items = vec![
+            item("item-1", "Urgent").priority(4).build(),
+            item("item-2", "Later").build(),
+            item("item-3", "Done p1").priority(4).checked().build(),
+        ];
+
+        match evaluate_query(&cache, "p1", false) {
+            FilterMatches::Evaluated { count, preview } => {
+                assert_eq!(count, 1);
+                assert!(preview.is_empty());
+            }
+            FilterMatches::Unsupported(e) => panic!("unexpected: {e}"),
+        }
+    }
+
+    #[test]
+    fn test_evaluate_query_preview_is_limited() {
+        let mut cache = make_test_cache_with_filters();
+        cache.items = (0..8)
+            .map(|n| item(&format!("item-{n}"), &format!("Task {n}")).build())
+            .collect();
+
+        match evaluate_query(&cache, "no date", true) {
+            FilterMatches::Evaluated { count, preview } => {
+                assert_eq!(count, 8);
+                let ids: Vec<&str> = preview.iter().map(|i| i.id.as_str()).collect();
+                assert_eq!(ids, vec!["item-0", "item-1", "item-2", "item-3", "item-4"]);
+            }
+            FilterMatches::Unsupported(e) => panic!("unexpected: {e}"),
+        }
+    }
+
+    #[test]
+    fn test_evaluate_query_unparseable_is_reported() {
+        let cache = make_test_cache_with_filters();
+        assert!(matches!(
+            evaluate_query(&cache, "today & (p1", true),
+            FilterMatches::Unsupported(_)
+        ));
+    }
+
     #[test]
     fn test_filters_edit_options() {
         let opts = FiltersEditOptions {
@@ -673,6 +831,7 @@ mod tests {
             query: Some("today".to_string()),
             color: Some("blue".to_string()),
             favorite: Some(true),
+            strict: false,
         };
 
         assert_eq!(opts.filter_id, "filter-123");
@@ -690,6 +849,7 @@ mod tests {
             query: None,
             color: None,
             favorite: Some(false),
+            strict: false,
         };
 
         assert_eq!(opts.filter_id, "filter-456");
@@ -789,6 +949,43 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_filter_filters_favorites_only() {
+        let mut favorite = make_test_filter("filter-1", "Starred", "p1");
+        favorite.is_favorite = true;
+        let mut deleted_favorite = make_test_filter("filter-2", "Gone", "p2");
+        deleted_favorite.is_favorite = true;
+        deleted_favorite.is_deleted = true;
+        let plain = make_test_filter("filter-3", "Plain", "today");
+        let mut cache = Cache::new();
+        cache.filters = vec![favorite, deleted_favorite, plain];
+
+        let names = |favorites_only| -> Vec<String> {
+            filter_filters(&cache, favorites_only)
+                .iter()
+                .map(|f| f.name.clone())
+                .collect()
+        };
+        assert_eq!(names(false), vec!["Starred", "Plain"]);
+        assert_eq!(names(true), vec!["Starred"]);
+    }
+
+    #[test]
+    fn test_favorite_toggle_builds_filter_update() {
+        let (command, fields) =
+            build_filter_update_command("filter-123", &favorite_edit_options("filter-123", true));
+        assert_eq!(command.command_type, SyncCommandType::FilterUpdate);
+        assert_eq!(
+            command.args,
+            serde_json::json!({"id": "filter-123", "is_favorite": true})
+        );
+        assert_eq!(fields, vec!["favorite"]);
+
+        let (command, _) =
+            build_filter_update_command("filter-123", &favorite_edit_options("filter-123", false));
+        assert_eq!(command.args["is_favorite"], false);
+    }
+
     // Helper function to create a test cache with filters
     fn make_test_cache_with_filters() -> Cache {
         Cache::with_data(
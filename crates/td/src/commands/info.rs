@@ -0,0 +1,115 @@
+//! Info command implementation.
+//!
+//! Reports the td version and what this build supports, so scripts and
+//! wrappers can detect features instead of parsing `--help`.
+
+use clap::CommandFactory;
+use serde::Serialize;
+use todoist_cache_rs::filter::FilterParser;
+
+use super::{CommandContext, Result};
+use crate::cli::Cli;
+use crate::output::helpers::format_fields_csv;
+use crate::output::OutputFormat;
+
+/// Features that scripts may want to check for before relying on them.
+///
+/// Names are stable: add new entries, but don't rename or remove existing ones.
+pub const CAPABILITIES: &[&str] = &[
+    "offline-cache",
+    "filter-expressions",
+    "strict-filter",
+    "add-dry-run",
+    "bulk-filter-targets",
+    "retry-on-conflict",
+    "env-file",
+    "csv-output",
+    "table-output",
+    "cache-info",
+];
+
+/// JSON output for the info command.
+#[derive(Debug, Serialize)]
+pub struct Info {
+    /// Binary name.
+    pub name: &'static str,
+    /// Crate version.
+    pub version: &'static str,
+    /// Top-level subcommands.
+    pub commands: Vec<String>,
+    /// Values accepted by `--format`.
+    pub output_formats: &'static [&'static str],
+    /// Keywords understood in `--filter` expressions.
+    pub filter_keywords: &'static [&'static str],
+    /// Feature names from [`CAPABILITIES`].
+    pub capabilities: &'static [&'static str],
+}
+
+/// Collects version and capability info for this build.
+pub fn info() -> Info {
+    let commands = Cli::command()
+        .get_subcommands()
+        .map(|cmd| cmd.get_name().to_string())
+        .collect();
+
+    Info {
+        name: "td",
+        version: env!("CARGO_PKG_VERSION"),
+        commands,
+        output_formats: OutputFormat::NAMES,
+        filter_keywords: FilterParser::KEYWORDS,
+        capabilities: CAPABILITIES,
+    }
+}
+
+/// Executes the info command.
+///
+/// # Errors
+///
+/// Returns an error if JSON serialization fails.
+pub fn execute(ctx: &CommandContext) -> Result<()> {
+    let info = info();
+
+    match ctx.output_format {
+        OutputFormat::Json => {
+            println!("{}", crate::output::to_json(&info, ctx.compact_json)?);
+            return Ok(());
+        }
+        OutputFormat::Csv => {
+            print!("{}", format_fields_csv(&info)?);
+            return Ok(());
+        }
+        OutputFormat::Table => {}
+    }
+    if ctx.quiet {
+        return Ok(());
+    }
+
+    println!("{} {}", info.name, info.version);
+    println!("Commands:         {}", info.commands.join(", "));
+    println!("Output formats:   {}", info.output_formats.join(", "));
+    println!("Filter keywords:  {}", info.filter_keywords.join(", "));
+    println!("Capabilities:     {}", info.capabilities.join(", "));
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_info_json_has_version_and_capabilities() {
+        let json = serde_json::to_value(info()).unwrap();
+
+        assert_eq!(json["version"], env!("CARGO_PKG_VERSION"));
+        assert!(!json["capabilities"].as_array().unwrap().is_empty());
+        let keywords = json["filter_keywords"].as_array().unwrap();
+        assert!(keywords.contains(&serde_json::json!("today")));
+        assert!(keywords.contains(&serde_json::json!("filter:")));
+        assert!(json["commands"]
+            .as_array()
+            .unwrap()
+            .contains(&serde_json::json!("info")));
+    }
+}
@@ -119,65 +119,159 @@ fn platform_failure_hint() -> &'static str {
 /// Service name for keyring entries.
 const SERVICE: &str = "td-todoist-cli";
 
-/// Username for the token entry.
+/// Username for the token entry. Profiles append `:<profile>` so each
+/// keeps its own token.
 const USERNAME: &str = "api_token";
 
-/// Stores token in OS keyring.
+/// Errors from keyring token operations.
+#[derive(Debug, thiserror::Error)]
+pub enum KeyringError {
+    /// No usable keyring backend (e.g., headless Linux without a Secret Service).
+    #[error("OS keyring is not available: {message}")]
+    Unavailable {
+        /// The underlying keyring error.
+        message: String,
+        /// Platform-specific advice for fixing the backend.
+        hint: &'static str,
+    },
+
+    /// The keyring works but holds no td token.
+    #[error("no token stored in the OS keyring")]
+    NotFound,
+
+    /// Any other keyring failure.
+    #[error("{0}")]
+    Other(String),
+}
+
+impl From<KeyringError> for CommandError {
+    fn from(error: KeyringError) -> Self {
+        match error {
+            KeyringError::Unavailable { hint, .. } => {
+                CommandError::Config(format!("{}\n\nHint: {}", error, hint))
+            }
+            other => CommandError::Config(other.to_string()),
+        }
+    }
+}
+
+/// A store for the token entry.
+///
+/// Abstracts the OS keyring so tests can simulate missing backends and
+/// empty keyrings without touching the user's credentials.
+pub trait KeyringBackend {
+    /// Reads the stored token.
+    fn get_password(&self) -> keyring::Result<String>;
+    /// Writes the token.
+    fn set_password(&self, token: &str) -> keyring::Result<()>;
+    /// Removes the stored token.
+    fn delete_credential(&self) -> keyring::Result<()>;
+}
+
+/// The OS-native credential manager, holding the entry for one profile.
+pub struct OsKeyring {
+    username: String,
+}
+
+impl OsKeyring {
+    /// Returns the keyring entry for `profile` (the default entry for `None`).
+    pub fn for_profile(profile: Option<&str>) -> Self {
+        let username = match profile {
+            Some(profile) => format!("{USERNAME}:{profile}"),
+            None => USERNAME.to_string(),
+        };
+        Self { username }
+    }
+}
+
+impl KeyringBackend for OsKeyring {
+    fn get_password(&self) -> keyring::Result<String> {
+        Entry::new(SERVICE, &self.username)?.get_password()
+    }
+
+    fn set_password(&self, token: &str) -> keyring::Result<()> {
+        Entry::new(SERVICE, &self.username)?.set_password(token)
+    }
+
+    fn delete_credential(&self) -> keyring::Result<()> {
+        Entry::new(SERVICE, &self.username)?.delete_credential()
+    }
+}
+
+/// Maps a keyring error to a [`KeyringError`], separating a missing backend
+/// from a missing entry.
+fn classify_error(error: keyring::Error, action: &str) -> KeyringError {
+    match error {
+        keyring::Error::NoEntry | keyring::Error::Ambiguous(_) => KeyringError::NotFound,
+        keyring::Error::NoStorageAccess(_) => KeyringError::Unavailable {
+            message: error.to_string(),
+            hint: platform_access_hint(),
+        },
+        keyring::Error::PlatformFailure(_) => KeyringError::Unavailable {
+            message: error.to_string(),
+            hint: platform_failure_hint(),
+        },
+        other => KeyringError::Other(format!(
+            "Failed to {} token: {}",
+            action,
+            platform_hint(&other)
+        )),
+    }
+}
+
+/// Stores the token for `profile` in OS keyring.
 ///
 /// # Errors
 ///
-/// Returns an error if the keyring is not available or the operation fails.
-/// Error messages include platform-specific hints for common issues.
-pub fn store_token(token: &str) -> Result<()> {
-    let entry = Entry::new(SERVICE, USERNAME)
-        .map_err(|e| CommandError::Config(format!("Keyring error: {}", platform_hint(&e))))?;
-    entry.set_password(token).map_err(|e| {
-        CommandError::Config(format!("Failed to store token: {}", platform_hint(&e)))
-    })?;
-    Ok(())
+/// Returns [`KeyringError::Unavailable`] if there is no usable keyring
+/// backend, or [`KeyringError::Other`] if the write fails.
+pub fn set_token(token: &str, profile: Option<&str>) -> std::result::Result<(), KeyringError> {
+    set_token_with(&OsKeyring::for_profile(profile), token)
 }
 
-/// Retrieves token from OS keyring.
-///
-/// Returns `Ok(None)` if no token is stored, `Ok(Some(token))` if found.
+/// Stores token using the given backend.
+pub fn set_token_with(
+    backend: &impl KeyringBackend,
+    token: &str,
+) -> std::result::Result<(), KeyringError> {
+    backend
+        .set_password(token)
+        .map_err(|e| classify_error(e, "store"))
+}
+
+/// Retrieves the token for `profile` from OS keyring.
 ///
 /// # Errors
 ///
-/// Returns an error if the keyring is not available or an unexpected error occurs.
-/// Error messages include platform-specific hints for common issues.
-pub fn get_token() -> Result<Option<String>> {
-    let entry = Entry::new(SERVICE, USERNAME)
-        .map_err(|e| CommandError::Config(format!("Keyring error: {}", platform_hint(&e))))?;
-    match entry.get_password() {
-        Ok(token) => Ok(Some(token)),
-        Err(keyring::Error::NoEntry) => Ok(None),
-        Err(keyring::Error::Ambiguous(_)) => Ok(None), // Multiple entries, treat as not found
-        Err(e) => Err(CommandError::Config(format!(
-            "Failed to read token: {}",
-            platform_hint(&e)
-        ))),
-    }
+/// Returns [`KeyringError::NotFound`] if no token is stored,
+/// [`KeyringError::Unavailable`] if there is no usable keyring backend,
+/// or [`KeyringError::Other`] for unexpected failures.
+pub fn get_token(profile: Option<&str>) -> std::result::Result<String, KeyringError> {
+    get_token_with(&OsKeyring::for_profile(profile))
+}
+
+/// Retrieves token using the given backend.
+pub fn get_token_with(backend: &impl KeyringBackend) -> std::result::Result<String, KeyringError> {
+    backend
+        .get_password()
+        .map_err(|e| classify_error(e, "read"))
 }
 
-/// Deletes token from OS keyring.
+/// Deletes the token for `profile` from OS keyring.
 ///
 /// Returns `Ok(())` even if no token was stored.
 ///
 /// # Errors
 ///
 /// Returns an error if the keyring is not available or an unexpected error occurs.
-/// Error messages include platform-specific hints for common issues.
 #[allow(dead_code)] // Available for future `td config migrate-token` command
-pub fn delete_token() -> Result<()> {
-    let entry = Entry::new(SERVICE, USERNAME)
-        .map_err(|e| CommandError::Config(format!("Keyring error: {}", platform_hint(&e))))?;
-    match entry.delete_credential() {
+pub fn delete_token(profile: Option<&str>) -> Result<()> {
+    match OsKeyring::for_profile(profile).delete_credential() {
         Ok(()) => Ok(()),
-        Err(keyring::Error::NoEntry) => Ok(()), // Already deleted
-        Err(e) => Err(CommandError::Config(format!(
-            "Failed to delete token: {}",
-            platform_hint(&e)
-        ))),
+        Err(e) => match classify_error(e, "delete") {
+            KeyringError::NotFound => Ok(()), // Already deleted
+            other => Err(other.into()),
+        },
     }
 }
 
@@ -190,10 +284,73 @@ pub fn is_available() -> bool {
     Entry::new(SERVICE, "test").is_ok()
 }
 
+/// In-memory keyring backend for tests.
+#[cfg(test)]
+pub enum MockKeyring {
+    /// A keyring holding the given token.
+    Token(String),
+    /// A working keyring with no entry.
+    Empty,
+    /// No keyring backend (e.g., missing Secret Service).
+    Unavailable,
+    /// The backend exists but fails.
+    PlatformFailure,
+    /// The stored entry can't be decoded.
+    BadEncoding,
+}
+
+#[cfg(test)]
+impl MockKeyring {
+    fn error(&self) -> Option<keyring::Error> {
+        match self {
+            MockKeyring::Token(_) => None,
+            MockKeyring::Empty => Some(keyring::Error::NoEntry),
+            MockKeyring::Unavailable => Some(keyring::Error::NoStorageAccess(Box::new(
+                std::io::Error::other("no secret service"),
+            ))),
+            MockKeyring::PlatformFailure => Some(keyring::Error::PlatformFailure(Box::new(
+                std::io::Error::other("dbus connection failed"),
+            ))),
+            MockKeyring::BadEncoding => Some(keyring::Error::BadEncoding(vec![0x80])),
+        }
+    }
+}
+
+#[cfg(test)]
+impl KeyringBackend for MockKeyring {
+    fn get_password(&self) -> keyring::Result<String> {
+        match (self, self.error()) {
+            (MockKeyring::Token(token), _) => Ok(token.clone()),
+            (_, Some(error)) => Err(error),
+            (_, None) => Err(keyring::Error::NoEntry),
+        }
+    }
+
+    fn set_password(&self, _token: &str) -> keyring::Result<()> {
+        match self.error() {
+            None | Some(keyring::Error::NoEntry) => Ok(()),
+            Some(error) => Err(error),
+        }
+    }
+
+    fn delete_credential(&self) -> keyring::Result<()> {
+        self.error().map_or(Ok(()), Err)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_os_keyring_username_is_per_profile() {
+        assert_eq!(OsKeyring::for_profile(None).username, "api_token");
+        assert_eq!(
+            OsKeyring::for_profile(Some("work")).username,
+            "api_token:work"
+        );
+    }
+
     #[test]
     fn test_is_available() {
         // This should succeed on macOS, Windows, and Linux with Secret Service
@@ -264,4 +421,53 @@ mod tests {
     // 3. Some systems require user interaction to unlock the keyring
     //
     // These functions should be tested manually on each target platform.
+    // The tests below use MockKeyring to cover error classification instead.
+
+    #[test]
+    fn test_get_token_with_returns_stored_token() {
+        let backend = MockKeyring::Token("secret".to_string());
+        assert_eq!(get_token_with(&backend).unwrap(), "secret");
+    }
+
+    #[test]
+    fn test_get_token_with_missing_entry_is_not_found() {
+        let result = get_token_with(&MockKeyring::Empty);
+        assert!(matches!(result, Err(KeyringError::NotFound)));
+    }
+
+    #[test]
+    fn test_get_token_with_no_backend_is_unavailable() {
+        let result = get_token_with(&MockKeyring::Unavailable);
+        assert!(matches!(result, Err(KeyringError::Unavailable { .. })));
+    }
+
+    #[test]
+    fn test_get_token_with_platform_failure_is_unavailable() {
+        let result = get_token_with(&MockKeyring::PlatformFailure);
+        assert!(matches!(result, Err(KeyringError::Unavailable { .. })));
+    }
+
+    #[test]
+    fn test_set_token_with_no_backend_is_unavailable() {
+        let result = set_token_with(&MockKeyring::Unavailable, "secret");
+        let err = result.unwrap_err();
+        assert!(matches!(err, KeyringError::Unavailable { .. }));
+
+        // Converting to a command error keeps the platform hint
+        let message = CommandError::from(err).to_string();
+        assert!(message.contains("OS keyring is not available"));
+        assert!(message.contains("Hint:"));
+    }
+
+    #[test]
+    fn test_set_token_with_succeeds() {
+        let backend = MockKeyring::Empty;
+        assert!(set_token_with(&backend, "secret").is_ok());
+    }
+
+    #[test]
+    fn test_other_errors_are_not_unavailable() {
+        let result = get_token_with(&MockKeyring::BadEncoding);
+        assert!(matches!(result, Err(KeyringError::Other(_))));
+    }
 }
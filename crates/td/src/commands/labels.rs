@@ -3,18 +3,22 @@
 //! Lists and manages labels via the Sync API.
 //! Uses SyncManager::execute_commands() to automatically update the cache.
 
-use todoist_api_rs::client::TodoistClient;
-use todoist_api_rs::sync::{Label, SyncCommand, SyncCommandType};
-use todoist_cache_rs::{Cache, CacheStore, SyncManager};
+use std::collections::HashMap;
 
-use super::{CommandContext, CommandError, Result};
-use crate::output::{format_labels_json, format_labels_table};
+use todoist_api_rs::sync::{Item, Label, SyncCommand, SyncCommandType};
+use todoist_cache_rs::Cache;
+
+use super::{ensure_fresh, CommandContext, CommandError, Result};
+use crate::cli::LabelSort;
+use crate::output::{format_labels_csv, format_labels_json, format_labels_table, OutputFormat};
 
 /// Options for the labels list command.
 #[derive(Debug, Default)]
 pub struct LabelsListOptions {
     /// Limit results.
     pub limit: Option<u32>,
+    /// Sort order.
+    pub sort: LabelSort,
 }
 
 /// Executes the labels list command.
@@ -30,48 +34,68 @@ pub struct LabelsListOptions {
 /// Returns an error if syncing fails.
 pub async fn execute(ctx: &CommandContext, opts: &LabelsListOptions, token: &str) -> Result<()> {
     // Initialize sync manager
-    let client = TodoistClient::new(token)?;
-    let store = CacheStore::new()?;
-    let mut manager = SyncManager::new(client, store)?;
+    let mut manager = ctx.sync_manager(token)?;
 
-    // Only sync if explicitly requested with --sync flag
-    if ctx.sync_first {
-        if ctx.verbose {
-            eprintln!("Syncing with Todoist...");
-        }
-        manager.sync().await?;
-    }
+    ensure_fresh(ctx, &mut manager).await?;
 
     let cache = manager.cache();
 
     // Get labels and apply filters
-    let labels = filter_labels(cache);
+    let labels = filter_labels(cache, opts.sort);
 
     // Apply limit
     let labels = apply_limit(labels, opts);
 
     // Output
-    if ctx.json_output {
-        let output = format_labels_json(&labels)?;
-        println!("{output}");
-    } else if !ctx.quiet {
-        let output = format_labels_table(&labels, ctx.use_colors);
-        print!("{output}");
+    match ctx.output_format {
+        OutputFormat::Json => {
+            let output = format_labels_json(&labels, ctx.compact_json)?;
+            println!("{output}");
+        }
+        OutputFormat::Csv => print!("{}", format_labels_csv(&labels)),
+        OutputFormat::Table if !ctx.quiet => {
+            let output = format_labels_table(&labels, ctx.use_colors);
+            print!("{output}");
+            ctx.print_sync_footer(cache);
+        }
+        OutputFormat::Table => {}
     }
 
     Ok(())
 }
 
-/// Filters labels (excludes deleted).
-fn filter_labels(cache: &Cache) -> Vec<&Label> {
+/// Filters labels (excludes deleted) and sorts them.
+fn filter_labels(cache: &Cache, sort: LabelSort) -> Vec<&Label> {
     let mut labels: Vec<&Label> = cache.labels.iter().filter(|l| !l.is_deleted).collect();
 
-    // Sort by item_order for consistent display
+    // Sort by item_order first so other orders break ties consistently
     labels.sort_by_key(|l| l.item_order);
+    match sort {
+        LabelSort::Order => {}
+        LabelSort::Name => labels.sort_by_key(|l| l.name.to_lowercase()),
+        LabelSort::Usage => {
+            let usage = label_usage(cache);
+            labels.sort_by_key(|l| {
+                std::cmp::Reverse(usage.get(l.name.as_str()).copied().unwrap_or(0))
+            });
+        }
+    }
 
     labels
 }
 
+/// Counts open tasks per label name. A task with several labels is counted
+/// under each of them.
+pub fn label_usage(cache: &Cache) -> HashMap<&str, usize> {
+    let mut usage = HashMap::new();
+    for item in cache.items.iter().filter(|i| !i.is_deleted && !i.checked) {
+        for label in &item.labels {
+            *usage.entry(label.as_str()).or_insert(0) += 1;
+        }
+    }
+    usage
+}
+
 /// Applies the limit to the labels.
 fn apply_limit<'a>(labels: Vec<&'a Label>, opts: &LabelsListOptions) -> Vec<&'a Label> {
     if let Some(limit) = opts.limit {
@@ -94,6 +118,10 @@ pub struct LabelsAddOptions {
     pub color: Option<String>,
     /// Mark as favorite.
     pub favorite: bool,
+    /// Create the label even if one with the same name already exists.
+    pub force: bool,
+    /// Tasks (IDs, prefixes, or content) to apply the label to.
+    pub to_tasks: Vec<String>,
 }
 
 /// Result of a successful label add operation.
@@ -107,6 +135,12 @@ pub struct LabelAddResult {
     pub color: Option<String>,
     /// Whether the label is a favorite.
     pub is_favorite: bool,
+    /// Whether the label was created (false when an existing one was reused).
+    pub created: bool,
+    /// IDs of tasks the label was added to.
+    pub labeled_task_ids: Vec<String>,
+    /// IDs of tasks that already had the label.
+    pub skipped_task_ids: Vec<String>,
 }
 
 /// Executes the labels add command.
@@ -122,9 +156,7 @@ pub struct LabelAddResult {
 /// Returns an error if the API returns an error.
 pub async fn execute_add(ctx: &CommandContext, opts: &LabelsAddOptions, token: &str) -> Result<()> {
     // Initialize sync manager (loads cache from disk)
-    let client = TodoistClient::new(token)?;
-    let store = CacheStore::new()?;
-    let mut manager = SyncManager::new(client, store)?;
+    let mut manager = ctx.sync_manager(token)?;
 
     // Validate color if provided
     if let Some(ref color) = opts.color {
@@ -135,32 +167,55 @@ pub async fn execute_add(ctx: &CommandContext, opts: &LabelsAddOptions, token: &
         }
     }
 
-    // Build the label_add command arguments
-    let temp_id = uuid::Uuid::new_v4().to_string();
-    let mut args = serde_json::json!({
-        "name": opts.name,
-    });
+    // With --to-tasks an existing label is reused instead of refused
+    let existing = if opts.to_tasks.is_empty() || opts.force {
+        None
+    } else {
+        find_label_by_name(manager.cache(), &opts.name).cloned()
+    };
 
-    // Add optional fields
-    if let Some(ref color) = opts.color {
-        args["color"] = serde_json::json!(color);
+    // Refuse duplicate names (checked locally against the cache)
+    if existing.is_none() {
+        check_duplicate_label_name(manager.cache(), &opts.name, None, opts.force)?;
     }
-
-    if opts.favorite {
-        args["is_favorite"] = serde_json::json!(true);
+    let label_name = existing
+        .as_ref()
+        .map_or_else(|| opts.name.clone(), |l| l.name.clone());
+
+    // Resolve tasks to label (ID, prefix, or content) with auto-sync fallback
+    let mut tasks: Vec<Item> = Vec::new();
+    for task in &opts.to_tasks {
+        let item = manager
+            .resolve_item_arg(task, Some(false))
+            .await
+            .map_err(|e| CommandError::Config(e.to_string()))?;
+        if !tasks.iter().any(|t| t.id == item.id) {
+            tasks.push(item.clone());
+        }
     }
+    let task_refs: Vec<&Item> = tasks.iter().collect();
+    let (label_commands, labeled_task_ids, skipped_task_ids) =
+        build_apply_label_commands(&label_name, &task_refs);
 
-    // Create the command
-    let command = SyncCommand::with_temp_id(SyncCommandType::LabelAdd, &temp_id, args);
+    // Create the label first so the item updates in the same batch can use it
+    let temp_id = uuid::Uuid::new_v4().to_string();
+    let mut commands = Vec::new();
+    if existing.is_none() {
+        commands.push(build_label_add_command(opts, &temp_id));
+    }
+    commands.extend(label_commands);
 
-    // Execute the command via SyncManager
-    // This sends the command, applies the response to cache, and saves to disk
-    let response = manager.execute_commands(vec![command]).await?;
+    // Execute the commands via SyncManager in one batch
+    // This sends the commands, applies the response to cache, and saves to disk
+    let response = if commands.is_empty() {
+        None
+    } else {
+        Some(manager.execute_commands(commands).await?)
+    };
 
     // Check for errors
-    if response.has_errors() {
-        let errors = response.errors();
-        if let Some((_, error)) = errors.first() {
+    if let Some(ref response) = response {
+        if let Some((_, error)) = response.errors().first() {
             return Err(CommandError::Api(todoist_api_rs::error::Error::Api(
                 todoist_api_rs::error::ApiError::Validation {
                     field: None,
@@ -170,27 +225,50 @@ pub async fn execute_add(ctx: &CommandContext, opts: &LabelsAddOptions, token: &
         }
     }
 
-    // Get the real ID from the temp_id_mapping
-    let real_id = response
-        .real_id(&temp_id)
-        .ok_or_else(|| {
-            CommandError::Config("Label created but no ID returned in response".to_string())
-        })?
-        .clone();
-
-    let result = LabelAddResult {
-        id: real_id,
-        name: opts.name.clone(),
-        color: opts.color.clone(),
-        is_favorite: opts.favorite,
+    let result = match existing {
+        Some(label) => LabelAddResult {
+            id: label.id,
+            name: label.name,
+            color: label.color,
+            is_favorite: label.is_favorite,
+            created: false,
+            labeled_task_ids,
+            skipped_task_ids,
+        },
+        None => {
+            // Get the real ID from the temp_id_mapping
+            let real_id = response
+                .as_ref()
+                .and_then(|r| r.real_id(&temp_id))
+                .ok_or_else(|| {
+                    CommandError::Config("Label created but no ID returned in response".to_string())
+                })?
+                .clone();
+
+            LabelAddResult {
+                id: real_id,
+                name: opts.name.clone(),
+                color: opts.color.clone(),
+                is_favorite: opts.favorite,
+                created: true,
+                labeled_task_ids,
+                skipped_task_ids,
+            }
+        }
     };
 
     // Output
-    if ctx.json_output {
-        let output = crate::output::format_created_label(&result)?;
+    if ctx.output_format.is_json() {
+        let output = crate::output::format_created_label(&result, ctx.compact_json)?;
         println!("{output}");
     } else if !ctx.quiet {
-        if ctx.verbose {
+        if !result.created {
+            println!(
+                "Using existing label: @{} ({})",
+                result.name,
+                &result.id[..6.min(result.id.len())]
+            );
+        } else if ctx.verbose {
             println!("Created label: {} ({})", result.name, result.id);
             if let Some(ref color) = result.color {
                 println!("  Color: {color}");
@@ -205,11 +283,157 @@ pub async fn execute_add(ctx: &CommandContext, opts: &LabelsAddOptions, token: &
                 &result.id[..6.min(result.id.len())]
             );
         }
+        if !result.labeled_task_ids.is_empty() {
+            println!(
+                "Labeled {} task(s) with @{}",
+                result.labeled_task_ids.len(),
+                result.name
+            );
+        }
+        if !result.skipped_task_ids.is_empty() {
+            println!(
+                "Skipped {} task(s) already labeled @{}",
+                result.skipped_task_ids.len(),
+                result.name
+            );
+        }
     }
 
     Ok(())
 }
 
+/// Checks whether a non-deleted label with the same name (case-insensitive) exists.
+///
+/// `exclude_id` skips the label being edited, so renaming a label to a different
+/// casing of its own name is allowed. Returns an error pointing at the existing
+/// label unless `force` is set.
+/// Builds the `label_add` command for `opts`.
+fn build_label_add_command(opts: &LabelsAddOptions, temp_id: &str) -> SyncCommand {
+    let mut args = serde_json::json!({
+        "name": opts.name,
+    });
+
+    // Add optional fields
+    if let Some(ref color) = opts.color {
+        args["color"] = serde_json::json!(color);
+    }
+
+    if opts.favorite {
+        args["is_favorite"] = serde_json::json!(true);
+    }
+
+    SyncCommand::with_temp_id(SyncCommandType::LabelAdd, temp_id, args)
+}
+
+/// Finds a non-deleted label by case-insensitive name.
+fn find_label_by_name<'a>(cache: &'a Cache, name: &str) -> Option<&'a Label> {
+    let name_lower = name.to_lowercase();
+    cache
+        .labels
+        .iter()
+        .find(|l| !l.is_deleted && l.name.to_lowercase() == name_lower)
+}
+
+/// Builds `label_add` commands for labels in `names` that aren't in the cache.
+///
+/// Names are compared case-insensitively and each missing label is created
+/// once. Without `create`, no commands are built: a warning naming the
+/// missing labels is printed instead (unless `quiet`), and the task keeps the
+/// names as plain strings.
+pub fn missing_label_commands(
+    cache: &Cache,
+    names: &[String],
+    create: bool,
+    quiet: bool,
+) -> Vec<SyncCommand> {
+    let mut missing: Vec<&str> = Vec::new();
+    for name in names {
+        let name_lower = name.to_lowercase();
+        if find_label_by_name(cache, name).is_none()
+            && !missing.iter().any(|m| m.to_lowercase() == name_lower)
+        {
+            missing.push(name);
+        }
+    }
+
+    if !create {
+        if !missing.is_empty() && !quiet {
+            eprintln!(
+                "Warning: no label named {} exists; use --create-labels to create it.",
+                missing.join(", ")
+            );
+        }
+        return Vec::new();
+    }
+
+    missing
+        .into_iter()
+        .map(|name| {
+            SyncCommand::with_temp_id(
+                SyncCommandType::LabelAdd,
+                uuid::Uuid::new_v4().to_string(),
+                serde_json::json!({ "name": name }),
+            )
+        })
+        .collect()
+}
+
+/// Builds `item_update` commands adding `label_name` to each task.
+///
+/// Tasks that already carry the label (compared case-insensitively) get no
+/// command. Returns the commands along with the IDs of the labeled and
+/// skipped tasks.
+fn build_apply_label_commands(
+    label_name: &str,
+    tasks: &[&Item],
+) -> (Vec<SyncCommand>, Vec<String>, Vec<String>) {
+    let name_lower = label_name.to_lowercase();
+    let mut commands = Vec::new();
+    let mut labeled = Vec::new();
+    let mut skipped = Vec::new();
+
+    for task in tasks {
+        if task.labels.iter().any(|l| l.to_lowercase() == name_lower) {
+            skipped.push(task.id.clone());
+            continue;
+        }
+        let mut labels = task.labels.clone();
+        labels.push(label_name.to_string());
+        commands.push(SyncCommand::new(
+            SyncCommandType::ItemUpdate,
+            serde_json::json!({ "id": task.id, "labels": labels }),
+        ));
+        labeled.push(task.id.clone());
+    }
+
+    (commands, labeled, skipped)
+}
+
+fn check_duplicate_label_name(
+    cache: &Cache,
+    name: &str,
+    exclude_id: Option<&str>,
+    force: bool,
+) -> Result<()> {
+    if force {
+        return Ok(());
+    }
+
+    let name_lower = name.to_lowercase();
+    let existing = cache.labels.iter().find(|l| {
+        !l.is_deleted && l.name.to_lowercase() == name_lower && exclude_id != Some(l.id.as_str())
+    });
+
+    match existing {
+        Some(label) => Err(CommandError::Config(format!(
+            "Label '@{}' already exists ({}). Use --force to create it anyway.",
+            label.name,
+            &label.id[..6.min(label.id.len())]
+        ))),
+        None => Ok(()),
+    }
+}
+
 /// Valid Todoist color names.
 const VALID_COLORS: &[&str] = &[
     "berry_red",
@@ -254,6 +478,8 @@ pub struct LabelsEditOptions {
     pub color: Option<String>,
     /// Set favorite status.
     pub favorite: Option<bool>,
+    /// Rename even if another label already has the new name.
+    pub force: bool,
 }
 
 /// Result of a successful label edit operation.
@@ -291,9 +517,7 @@ pub async fn execute_edit(
     }
 
     // Initialize sync manager (loads cache from disk)
-    let client = TodoistClient::new(token)?;
-    let store = CacheStore::new()?;
-    let mut manager = SyncManager::new(client, store)?;
+    let mut manager = ctx.sync_manager(token)?;
 
     // Find the label by ID or prefix and extract owned data before mutation
     let (label_id, label_name) = {
@@ -302,6 +526,11 @@ pub async fn execute_edit(
         (label.id.clone(), label.name.clone())
     };
 
+    // Refuse renaming onto another label's name
+    if let Some(ref name) = opts.name {
+        check_duplicate_label_name(manager.cache(), name, Some(&label_id), opts.force)?;
+    }
+
     // Validate color if provided
     if let Some(ref color) = opts.color {
         if !is_valid_color(color) {
@@ -360,8 +589,8 @@ pub async fn execute_edit(
     };
 
     // Output
-    if ctx.json_output {
-        let output = crate::output::format_edited_label(&result)?;
+    if ctx.output_format.is_json() {
+        let output = crate::output::format_edited_label(&result, ctx.compact_json)?;
         println!("{output}");
     } else if !ctx.quiet {
         if ctx.verbose {
@@ -410,6 +639,54 @@ fn find_label_by_id_or_prefix<'a>(cache: &'a Cache, id: &str) -> Result<&'a Labe
     }
 }
 
+// ============================================================================
+// Labels Favorite Command
+// ============================================================================
+
+/// Options for the labels favorite command.
+#[derive(Debug)]
+pub struct LabelsFavoriteOptions {
+    /// Label ID (full ID or prefix).
+    pub label_id: String,
+    /// Favorite state to set. `None` flips the cached state.
+    pub favorite: Option<bool>,
+}
+
+/// Executes the labels favorite command.
+///
+/// Resolves the target state from the cache, then applies it like
+/// `labels edit --favorite`.
+///
+/// # Errors
+///
+/// Returns an error if label lookup fails or the API returns an error.
+pub async fn execute_favorite(
+    ctx: &CommandContext,
+    opts: &LabelsFavoriteOptions,
+    token: &str,
+) -> Result<()> {
+    let favorite = {
+        let manager = ctx.sync_manager(token)?;
+        favorite_target(manager.cache(), opts)?
+    };
+
+    let edit_opts = LabelsEditOptions {
+        label_id: opts.label_id.clone(),
+        favorite: Some(favorite),
+        name: None,
+        color: None,
+        force: false,
+    };
+    execute_edit(ctx, &edit_opts, token).await
+}
+
+/// Returns the favorite state to set: the requested one, or the opposite of
+/// the label's cached state.
+fn favorite_target(cache: &Cache, opts: &LabelsFavoriteOptions) -> Result<bool> {
+    let label = find_label_by_id_or_prefix(cache, &opts.label_id)?;
+    Ok(opts.favorite.unwrap_or(!label.is_favorite))
+}
+
 // ============================================================================
 // Labels Delete Command
 // ============================================================================
@@ -421,6 +698,10 @@ pub struct LabelsDeleteOptions {
     pub label_id: String,
     /// Skip confirmation.
     pub force: bool,
+    /// Relabel the label's tasks with this label before deleting.
+    pub reassign: Option<String>,
+    /// Strip the label from its tasks before deleting.
+    pub remove: bool,
 }
 
 /// Result of a successful label delete operation.
@@ -430,6 +711,10 @@ pub struct LabelDeleteResult {
     pub id: String,
     /// The name of the deleted label.
     pub name: String,
+    /// The label the tasks were relabeled with, for `--reassign`.
+    pub reassigned_to: Option<String>,
+    /// IDs of tasks relabeled or stripped of the label.
+    pub retagged_task_ids: Vec<String>,
 }
 
 /// Executes the labels delete command.
@@ -449,15 +734,31 @@ pub async fn execute_delete(
     token: &str,
 ) -> Result<()> {
     // Initialize sync manager (loads cache from disk)
-    let client = TodoistClient::new(token)?;
-    let store = CacheStore::new()?;
-    let mut manager = SyncManager::new(client, store)?;
+    let mut manager = ctx.sync_manager(token)?;
 
     // Find the label by ID or prefix and extract owned data before mutation
-    let (label_id, label_name) = {
+    let (label_id, label_name, reassigned_to) = {
         let cache = manager.cache();
         let label = find_label_by_id_or_prefix(cache, &opts.label_id)?;
-        (label.id.clone(), label.name.clone())
+        let reassigned_to = opts
+            .reassign
+            .as_deref()
+            .map(|name| resolve_reassign_target(cache, label, name))
+            .transpose()?;
+        (label.id.clone(), label.name.clone(), reassigned_to)
+    };
+
+    // Retag the label's tasks in the same batch as the delete
+    let (retag_commands, retagged_task_ids) = if opts.remove || reassigned_to.is_some() {
+        let tasks: Vec<&Item> = manager
+            .cache()
+            .items
+            .iter()
+            .filter(|i| !i.is_deleted)
+            .collect();
+        build_retag_commands(&label_name, reassigned_to.as_deref(), &tasks)
+    } else {
+        (Vec::new(), Vec::new())
     };
 
     // Confirm if not forced
@@ -467,7 +768,18 @@ pub async fn execute_delete(
             label_name,
             &label_id[..6.min(label_id.len())]
         );
-        eprintln!("This will remove the label from all tasks.");
+        match &reassigned_to {
+            Some(target) => eprintln!(
+                "Its {} task(s) will be relabeled @{}.",
+                retagged_task_ids.len(),
+                target
+            ),
+            None if opts.remove => eprintln!(
+                "It will be removed from {} task(s).",
+                retagged_task_ids.len()
+            ),
+            None => eprintln!("This will remove the label from all tasks."),
+        }
         eprintln!("Use --force to skip this confirmation.");
         return Err(CommandError::Config(
             "Operation cancelled. Use --force to confirm.".to_string(),
@@ -479,12 +791,13 @@ pub async fn execute_delete(
         "id": label_id,
     });
 
-    // Create the command
-    let command = SyncCommand::new(SyncCommandType::LabelDelete, args);
+    // Create the command, after any retagging
+    let mut commands = retag_commands;
+    commands.push(SyncCommand::new(SyncCommandType::LabelDelete, args));
 
-    // Execute the command via SyncManager
-    // This sends the command, applies the response to cache, and saves to disk
-    let response = manager.execute_commands(vec![command]).await?;
+    // Execute the commands via SyncManager
+    // This sends the commands, applies the response to cache, and saves to disk
+    let response = manager.execute_commands(commands).await?;
 
     // Check for errors
     if response.has_errors() {
@@ -502,11 +815,13 @@ pub async fn execute_delete(
     let result = LabelDeleteResult {
         id: label_id,
         name: label_name,
+        reassigned_to,
+        retagged_task_ids,
     };
 
     // Output
-    if ctx.json_output {
-        let output = crate::output::format_deleted_label(&result)?;
+    if ctx.output_format.is_json() {
+        let output = crate::output::format_deleted_label(&result, ctx.compact_json)?;
         println!("{output}");
     } else if !ctx.quiet {
         if ctx.verbose {
@@ -515,27 +830,164 @@ pub async fn execute_delete(
             let prefix = &result.id[..6.min(result.id.len())];
             println!("Deleted: @{} ({})", result.name, prefix);
         }
+        if !result.retagged_task_ids.is_empty() {
+            match &result.reassigned_to {
+                Some(target) => println!(
+                    "Relabeled {} task(s) with @{}",
+                    result.retagged_task_ids.len(),
+                    target
+                ),
+                None => println!(
+                    "Removed @{} from {} task(s)",
+                    result.name,
+                    result.retagged_task_ids.len()
+                ),
+            }
+        }
     }
 
     Ok(())
 }
 
+/// Resolves the `--reassign` target to the name of an existing label.
+///
+/// The target must differ from the label being deleted.
+fn resolve_reassign_target(cache: &Cache, deleting: &Label, name: &str) -> Result<String> {
+    let target = find_label_by_name(cache, name)
+        .ok_or_else(|| CommandError::Config(format!("No label named '{name}' to reassign to")))?;
+    if target.id == deleting.id {
+        return Err(CommandError::Config(format!(
+            "Cannot reassign @{} to itself",
+            deleting.name
+        )));
+    }
+    Ok(target.name.clone())
+}
+
+/// Builds `item_update` commands that replace `label_name` on each task.
+///
+/// The label (compared case-insensitively) is swapped for `replacement` in
+/// place, or dropped when `replacement` is `None` or the task already has
+/// it. Tasks without the label get no command. Returns the commands along
+/// with the IDs of the updated tasks.
+fn build_retag_commands(
+    label_name: &str,
+    replacement: Option<&str>,
+    tasks: &[&Item],
+) -> (Vec<SyncCommand>, Vec<String>) {
+    let name_lower = label_name.to_lowercase();
+    let mut commands = Vec::new();
+    let mut retagged = Vec::new();
+
+    for task in tasks {
+        if !task.labels.iter().any(|l| l.to_lowercase() == name_lower) {
+            continue;
+        }
+        let mut labels: Vec<String> = Vec::with_capacity(task.labels.len());
+        for label in &task.labels {
+            let label = match replacement {
+                _ if label.to_lowercase() != name_lower => label.as_str(),
+                Some(new) => new,
+                None => continue,
+            };
+            let lower = label.to_lowercase();
+            if !labels.iter().any(|l| l.to_lowercase() == lower) {
+                labels.push(label.to_string());
+            }
+        }
+        commands.push(SyncCommand::new(
+            SyncCommandType::ItemUpdate,
+            serde_json::json!({ "id": task.id, "labels": labels }),
+        ));
+        retagged.push(task.id.clone());
+    }
+
+    (commands, retagged)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_support::item;
 
     #[test]
     fn test_labels_list_options_defaults() {
         let opts = LabelsListOptions::default();
 
         assert!(opts.limit.is_none());
+        assert_eq!(opts.sort, LabelSort::Order);
     }
 
     #[test]
     fn test_labels_list_options_with_values() {
-        let opts = LabelsListOptions { limit: Some(10) };
+        let opts = LabelsListOptions {
+            limit: Some(10),
+            sort: LabelSort::Name,
+        };
 
         assert_eq!(opts.limit, Some(10));
+        assert_eq!(opts.sort, LabelSort::Name);
+    }
+
+    fn make_sort_cache() -> Cache {
+        let mut cache = Cache::new();
+        let mut work = make_test_label("l1", "work");
+        work.item_order = 1;
+        let mut errands = make_test_label("l2", "Errands");
+        errands.item_order = 2;
+        let mut urgent = make_test_label("l3", "urgent");
+        urgent.item_order = 0;
+        let mut old = make_test_label("l4", "archive");
+        old.is_deleted = true;
+        cache.labels = vec![work, errands, urgent, old];
+
+        let done = item("4", "Task 4")
+            .labels(&["work", "work"])
+            .checked()
+            .build();
+        cache.items = vec![
+            item("1", "Task 1").labels(&["errands", "urgent"]).build(),
+            item("2", "Task 2").labels(&["Errands"]).build(),
+            item("3", "Task 3").labels(&["Errands", "work"]).build(),
+            done,
+        ];
+        cache
+    }
+
+    fn sorted_names(cache: &Cache, sort: LabelSort) -> Vec<&str> {
+        filter_labels(cache, sort)
+            .iter()
+            .map(|l| l.name.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_filter_labels_sort_order() {
+        let cache = make_sort_cache();
+        assert_eq!(
+            sorted_names(&cache, LabelSort::Order),
+            vec!["urgent", "work", "Errands"]
+        );
+    }
+
+    #[test]
+    fn test_filter_labels_sort_name() {
+        let cache = make_sort_cache();
+        assert_eq!(
+            sorted_names(&cache, LabelSort::Name),
+            vec!["Errands", "urgent", "work"]
+        );
+    }
+
+    #[test]
+    fn test_filter_labels_sort_usage() {
+        let cache = make_sort_cache();
+        // Errands: 2 open tasks; urgent and work: 1 each (tie keeps item_order);
+        // completed tasks and mismatched case don't count
+        assert_eq!(
+            sorted_names(&cache, LabelSort::Usage),
+            vec!["Errands", "urgent", "work"]
+        );
     }
 
     #[test]
@@ -544,6 +996,8 @@ mod tests {
             name: "urgent".to_string(),
             color: Some("red".to_string()),
             favorite: true,
+            force: false,
+            to_tasks: vec![],
         };
 
         assert_eq!(opts.name, "urgent");
@@ -551,6 +1005,89 @@ mod tests {
         assert!(opts.favorite);
     }
 
+    #[test]
+    fn test_build_label_add_command_with_task_updates() {
+        let opts = LabelsAddOptions {
+            name: "urgent".to_string(),
+            color: Some("red".to_string()),
+            favorite: false,
+            force: false,
+            to_tasks: vec!["task-1".to_string(), "task-2".to_string()],
+        };
+        let task1 = item("task-1", "Task task-1").build();
+        let task2 = item("task-2", "Task task-2").labels(&["work"]).build();
+
+        let mut commands = vec![build_label_add_command(&opts, "temp-1")];
+        let (updates, labeled, skipped) = build_apply_label_commands("urgent", &[&task1, &task2]);
+        commands.extend(updates);
+
+        assert_eq!(commands.len(), 3);
+        assert_eq!(commands[0].command_type, SyncCommandType::LabelAdd);
+        assert_eq!(commands[0].temp_id.as_deref(), Some("temp-1"));
+        assert_eq!(commands[0].args["name"], "urgent");
+        assert_eq!(commands[0].args["color"], "red");
+        assert_eq!(commands[1].command_type, SyncCommandType::ItemUpdate);
+        assert_eq!(
+            commands[1].args,
+            serde_json::json!({"id": "task-1", "labels": ["urgent"]})
+        );
+        assert_eq!(
+            commands[2].args,
+            serde_json::json!({"id": "task-2", "labels": ["work", "urgent"]})
+        );
+        assert_eq!(labeled, vec!["task-1", "task-2"]);
+        assert!(skipped.is_empty());
+    }
+
+    #[test]
+    fn test_build_apply_label_commands_skips_already_labeled() {
+        let has_label = item("task-1", "Task task-1")
+            .labels(&["work", "Urgent"])
+            .build();
+        let missing = item("task-2", "Task task-2").labels(&["work"]).build();
+
+        let (commands, labeled, skipped) =
+            build_apply_label_commands("urgent", &[&has_label, &missing]);
+
+        assert_eq!(commands.len(), 1);
+        assert_eq!(commands[0].args["id"], "task-2");
+        assert_eq!(labeled, vec!["task-2"]);
+        assert_eq!(skipped, vec!["task-1"]);
+    }
+
+    #[test]
+    fn test_find_label_by_name_case_insensitive() {
+        let cache = make_test_cache_with_labels();
+        let name = cache.labels[0].name.to_uppercase();
+        assert_eq!(
+            find_label_by_name(&cache, &name).map(|l| l.id.as_str()),
+            Some(cache.labels[0].id.as_str())
+        );
+        assert!(find_label_by_name(&cache, "no-such-label").is_none());
+    }
+
+    #[test]
+    fn test_missing_label_commands_creates_only_missing() {
+        let cache = make_test_cache_with_labels();
+        let existing = cache.labels[0].name.to_uppercase();
+        let names = vec![
+            existing,
+            "Someday".to_string(),
+            "someday".to_string(),
+            "waiting".to_string(),
+        ];
+
+        let commands = missing_label_commands(&cache, &names, true, true);
+        assert_eq!(commands.len(), 2);
+        assert!(commands
+            .iter()
+            .all(|c| c.command_type == SyncCommandType::LabelAdd && c.temp_id.is_some()));
+        assert_eq!(commands[0].args["name"], "Someday");
+        assert_eq!(commands[1].args["name"], "waiting");
+
+        assert!(missing_label_commands(&cache, &names, false, true).is_empty());
+    }
+
     #[test]
     fn test_is_valid_color() {
         assert!(is_valid_color("blue"));
@@ -569,6 +1106,7 @@ mod tests {
             name: Some("new-name".to_string()),
             color: Some("blue".to_string()),
             favorite: Some(true),
+            force: false,
         };
 
         assert_eq!(opts.label_id, "label-123");
@@ -584,6 +1122,7 @@ mod tests {
             name: None,
             color: None,
             favorite: Some(false),
+            force: false,
         };
 
         assert_eq!(opts.label_id, "label-456");
@@ -605,11 +1144,71 @@ mod tests {
         assert_eq!(result.updated_fields, vec!["name", "color"]);
     }
 
+    #[test]
+    fn test_build_retag_commands_reassign() {
+        let old_only = item("task-1", "Task task-1")
+            .labels(&["Old", "home"])
+            .build();
+        let has_both = item("task-2", "Task task-2")
+            .labels(&["new", "old"])
+            .build();
+        let untouched = item("task-3", "Task task-3").labels(&["home"]).build();
+
+        let (commands, retagged) =
+            build_retag_commands("old", Some("new"), &[&old_only, &has_both, &untouched]);
+
+        assert_eq!(retagged, vec!["task-1", "task-2"]);
+        assert_eq!(commands.len(), 2);
+        assert!(commands
+            .iter()
+            .all(|c| c.command_type == SyncCommandType::ItemUpdate));
+        // Replaced in place; not duplicated when the task already has it
+        assert_eq!(
+            commands[0].args["labels"],
+            serde_json::json!(["new", "home"])
+        );
+        assert_eq!(commands[1].args["labels"], serde_json::json!(["new"]));
+    }
+
+    #[test]
+    fn test_build_retag_commands_remove() {
+        let tagged = item("task-1", "Task task-1")
+            .labels(&["work", "OLD"])
+            .build();
+        let untouched = item("task-2", "Task task-2").labels(&["work"]).build();
+
+        let (commands, retagged) = build_retag_commands("old", None, &[&tagged, &untouched]);
+
+        assert_eq!(retagged, vec!["task-1"]);
+        assert_eq!(commands.len(), 1);
+        assert_eq!(commands[0].args["id"], "task-1");
+        assert_eq!(commands[0].args["labels"], serde_json::json!(["work"]));
+    }
+
+    #[test]
+    fn test_resolve_reassign_target() {
+        let mut cache = make_test_cache_with_labels();
+        cache.labels.push(make_test_label("label-456-def", "Later"));
+        let deleting = cache.labels[0].clone();
+
+        assert_eq!(
+            resolve_reassign_target(&cache, &deleting, "later").unwrap(),
+            "Later"
+        );
+        let err = resolve_reassign_target(&cache, &deleting, "missing").unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("No label named 'missing' to reassign to"));
+        assert!(resolve_reassign_target(&cache, &deleting, "URGENT").is_err());
+    }
+
     #[test]
     fn test_labels_delete_options() {
         let opts = LabelsDeleteOptions {
             label_id: "label-123".to_string(),
             force: false,
+            reassign: None,
+            remove: false,
         };
 
         assert_eq!(opts.label_id, "label-123");
@@ -621,6 +1220,8 @@ mod tests {
         let opts = LabelsDeleteOptions {
             label_id: "label-456".to_string(),
             force: true,
+            reassign: None,
+            remove: false,
         };
 
         assert_eq!(opts.label_id, "label-456");
@@ -632,6 +1233,8 @@ mod tests {
         let result = LabelDeleteResult {
             id: "label-789".to_string(),
             name: "deleted-label".to_string(),
+            reassigned_to: None,
+            retagged_task_ids: vec![],
         };
 
         assert_eq!(result.id, "label-789");
@@ -682,7 +1285,67 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_check_duplicate_label_name_rejects_existing() {
+        let cache = make_test_cache_with_labels();
+        let result = check_duplicate_label_name(&cache, "URGENT", None, false);
+        assert!(result.is_err());
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("already exists"));
+        assert!(err.contains("label-"));
+    }
+
+    #[test]
+    fn test_check_duplicate_label_name_force_bypasses() {
+        let cache = make_test_cache_with_labels();
+        assert!(check_duplicate_label_name(&cache, "urgent", None, true).is_ok());
+    }
+
+    #[test]
+    fn test_check_duplicate_label_name_allows_new_name() {
+        let cache = make_test_cache_with_labels();
+        assert!(check_duplicate_label_name(&cache, "someday", None, false).is_ok());
+    }
+
+    #[test]
+    fn test_check_duplicate_label_name_excludes_self_and_deleted() {
+        let mut cache = make_test_cache_with_labels();
+        // Renaming a label to a different casing of its own name is fine
+        assert!(check_duplicate_label_name(&cache, "Urgent", Some("label-123-abc"), false).is_ok());
+
+        // Deleted labels don't count as duplicates
+        cache.labels[0].is_deleted = true;
+        assert!(check_duplicate_label_name(&cache, "urgent", None, false).is_ok());
+    }
+
     // Helper function to create a test cache with labels
+    #[test]
+    fn test_favorite_target_toggles_cached_state() {
+        let mut cache = make_test_cache_with_labels();
+        let opts = LabelsFavoriteOptions {
+            label_id: "label-123".to_string(),
+            favorite: None,
+        };
+        assert!(favorite_target(&cache, &opts).unwrap());
+
+        cache.labels[0].is_favorite = true;
+        assert!(!favorite_target(&cache, &opts).unwrap());
+    }
+
+    #[test]
+    fn test_favorite_target_off_forces_unfavorite() {
+        let mut cache = make_test_cache_with_labels();
+        cache.labels[0].is_favorite = true;
+        let opts = LabelsFavoriteOptions {
+            label_id: "label-123-abc".to_string(),
+            favorite: Some(false),
+        };
+        assert!(!favorite_target(&cache, &opts).unwrap());
+
+        cache.labels[0].is_favorite = false;
+        assert!(!favorite_target(&cache, &opts).unwrap());
+    }
+
     fn make_test_cache_with_labels() -> Cache {
         Cache::with_data(
             "test".to_string(),
@@ -2,23 +2,31 @@
 //!
 //! Lists tasks from the local cache, optionally filtered by various criteria.
 
+use chrono::{DateTime, Duration, Local, NaiveDate, NaiveDateTime, TimeDelta, TimeZone, Utc};
 use todoist_api_rs::sync::Item;
-use todoist_cache_rs::filter::{FilterContext, FilterEvaluator, FilterParser};
-use todoist_cache_rs::{Cache, CacheStore, SyncManager};
+use todoist_cache_rs::filter::{Filter, FilterContext, FilterEvaluator, FilterParser};
+use todoist_cache_rs::Cache;
 
-use super::{CommandContext, Result};
-use crate::cli::SortField;
-use crate::output::{format_items_json, format_items_table};
+use super::{ensure_fresh, parse_duration_minutes, CommandContext, CommandError, Result};
+use crate::cli::{LabelMatch, SortField};
+use crate::output::{
+    format_items_csv, format_items_json, format_items_table_with_fields, OutputFormat, TaskField,
+    TaskTableOptions,
+};
 
 /// Options for the list command.
 #[derive(Debug)]
 pub struct ListOptions {
     /// Filter expression.
     pub filter: Option<String>,
+    /// Reject filters that name unknown projects, sections, or labels.
+    pub strict_filter: bool,
     /// Filter by project name or ID.
     pub project: Option<String>,
-    /// Filter by label name.
-    pub label: Option<String>,
+    /// Filter by label names.
+    pub labels: Vec<String>,
+    /// Whether tasks need all of `labels` or any one of them.
+    pub label_match: LabelMatch,
     /// Filter by priority (1-4).
     pub priority: Option<u8>,
     /// Filter by section name.
@@ -27,6 +35,8 @@ pub struct ListOptions {
     pub overdue: bool,
     /// Show only tasks without due date.
     pub no_due: bool,
+    /// Show only tasks due on a date or within a range (see [`parse_due_range`]).
+    pub due: Option<String>,
     /// Limit results.
     pub limit: u32,
     /// Show all tasks (no limit).
@@ -38,8 +48,20 @@ pub struct ListOptions {
     pub sort: Option<SortField>,
     /// Reverse sort order.
     pub reverse: bool,
+    /// Put tasks without a due date first when sorting by due date.
+    pub no_due_first: bool,
     /// Filter by assignee.
     pub assigned_to: Option<String>,
+    /// Only tasks updated since this time (relative like "2h" or absolute).
+    pub changed_since: Option<String>,
+    /// Show subtasks as independent rows instead of nesting them.
+    pub flat: bool,
+    /// Omit the table header row.
+    pub no_header: bool,
+    /// Print only full task IDs, one per line, whatever the output format.
+    pub ids: bool,
+    /// Extra table columns.
+    pub fields: Vec<TaskField>,
 }
 
 /// Executes the list command.
@@ -54,23 +76,23 @@ pub struct ListOptions {
 ///
 /// Returns an error if syncing fails or if the filter expression is invalid.
 pub async fn execute(ctx: &CommandContext, opts: &ListOptions, token: &str) -> Result<()> {
+    // Parse the filter first so a malformed one fails before any cache load
+    // or sync
+    let filter = opts
+        .filter
+        .as_deref()
+        .map(FilterParser::parse)
+        .transpose()?;
+
     // Initialize sync manager
-    let client = todoist_api_rs::client::TodoistClient::new(token)?;
-    let store = CacheStore::new()?;
-    let mut manager = SyncManager::new(client, store)?;
-
-    // Only sync if explicitly requested with --sync flag
-    if ctx.sync_first {
-        if ctx.verbose {
-            eprintln!("Syncing with Todoist...");
-        }
-        manager.sync().await?;
-    }
+    let mut manager = ctx.sync_manager(token)?;
+
+    ensure_fresh(ctx, &mut manager).await?;
 
     let cache = manager.cache();
 
     // Get items and apply filters
-    let items = filter_items(cache, opts)?;
+    let items = filter_items(cache, opts, filter.as_ref())?;
 
     // Sort items
     let items = sort_items(items, opts);
@@ -79,54 +101,86 @@ pub async fn execute(ctx: &CommandContext, opts: &ListOptions, token: &str) -> R
     let items = apply_limit(items, opts);
 
     // Output
-    if ctx.json_output {
-        let output = format_items_json(&items, cache)?;
-        println!("{output}");
-    } else if !ctx.quiet {
-        let output = format_items_table(&items, cache, ctx.use_colors);
-        print!("{output}");
+    if opts.ids {
+        print!("{}", format_ids(&items));
+        return Ok(());
+    }
+    match ctx.output_format {
+        OutputFormat::Json => {
+            let output = format_items_json(&items, cache, ctx.compact_json)?;
+            println!("{output}");
+        }
+        OutputFormat::Csv => print!("{}", format_items_csv(&items, cache)),
+        OutputFormat::Table if !ctx.quiet => {
+            let output = format_items_table_with_fields(
+                &items,
+                cache,
+                ctx.use_colors,
+                &TaskTableOptions {
+                    nested: !opts.flat,
+                    show_header: !opts.no_header,
+                    date_style: ctx.date_style,
+                    id_style: ctx.id_style,
+                    fields: &opts.fields,
+                },
+            );
+            ctx.print_table(&output);
+            ctx.print_sync_footer(cache);
+        }
+        OutputFormat::Table => {}
     }
 
     Ok(())
 }
 
-/// Filters items based on the provided options.
-fn filter_items<'a>(cache: &'a Cache, opts: &ListOptions) -> Result<Vec<&'a Item>> {
-    let mut items: Vec<&Item> = cache
-        .items
-        .iter()
-        .filter(|i| !i.is_deleted && !i.checked)
-        .collect();
-
-    // Apply filter expression if provided
-    if let Some(filter_expr) = &opts.filter {
-        let filter = FilterParser::parse(filter_expr)?;
-        let context = FilterContext::new(&cache.projects, &cache.sections, &cache.labels);
-        let evaluator = FilterEvaluator::new(&filter, &context);
-        items.retain(|i| evaluator.matches(i));
-    }
+/// Formats tasks as their full IDs, one per line, for `--ids`.
+///
+/// An empty list yields an empty string, so piping into `xargs` does nothing.
+fn format_ids(items: &[&Item]) -> String {
+    items.iter().map(|i| format!("{}\n", i.id)).collect()
+}
 
-    // Apply project filter
-    if let Some(project_name) = &opts.project {
-        let project_name_lower = project_name.to_lowercase();
-        let project_id = cache
-            .projects
-            .iter()
-            .find(|p| p.name.to_lowercase() == project_name_lower || p.id == *project_name)
-            .map(|p| &p.id);
+/// Filters items based on the provided options and the parsed `--filter`.
+fn filter_items<'a>(
+    cache: &'a Cache,
+    opts: &ListOptions,
+    filter: Option<&Filter>,
+) -> Result<Vec<&'a Item>> {
+    // Start from the project's tasks when --project is given
+    let mut items: Vec<&Item> = match &opts.project {
+        Some(project_name) => {
+            let project_name_lower = project_name.to_lowercase();
+            cache
+                .projects
+                .iter()
+                .find(|p| p.name.to_lowercase() == project_name_lower || p.id == *project_name)
+                // No matching project leaves nothing to list
+                .map_or_else(Vec::new, |p| cache.items_in_project(&p.id, false))
+        }
+        None => cache.items.iter().filter(|i| !i.is_deleted).collect(),
+    };
+    items.retain(|i| !i.checked);
 
-        if let Some(pid) = project_id {
-            items.retain(|i| &i.project_id == pid);
-        } else {
-            // No matching project, return empty
-            return Ok(vec![]);
+    // Apply filter expression if provided
+    if let Some(filter) = filter {
+        let context = FilterContext::from_cache(cache);
+        let evaluator = FilterEvaluator::new(filter, &context);
+        if opts.strict_filter {
+            evaluator.check_references()?;
         }
+        items.retain(|i| evaluator.matches(i));
     }
 
     // Apply label filter
-    if let Some(label_name) = &opts.label {
-        let label_lower = label_name.to_lowercase();
-        items.retain(|i| i.labels.iter().any(|l| l.to_lowercase() == label_lower));
+    if !opts.labels.is_empty() {
+        let wanted: Vec<String> = opts.labels.iter().map(|l| l.to_lowercase()).collect();
+        items.retain(|i| {
+            let has = |label: &String| i.labels.iter().any(|l| l.to_lowercase() == *label);
+            match opts.label_match {
+                LabelMatch::All => wanted.iter().all(has),
+                LabelMatch::Any => wanted.iter().any(has),
+            }
+        });
     }
 
     // Apply priority filter (convert user priority 1-4 to API priority 4-1)
@@ -168,6 +222,17 @@ fn filter_items<'a>(cache: &'a Cache, opts: &ListOptions) -> Result<Vec<&'a Item
         items.retain(|i| i.due.is_none());
     }
 
+    // Apply due date/range filter
+    if let Some(due) = &opts.due {
+        let (from, to) = parse_due_range(due, Local::now().date_naive())?;
+        items.retain(|i| {
+            i.due
+                .as_ref()
+                .and_then(|due| NaiveDate::parse_from_str(&due.date, "%Y-%m-%d").ok())
+                .is_some_and(|d| from.is_none_or(|f| d >= f) && to.is_none_or(|t| d <= t))
+        });
+    }
+
     // Apply assigned_to filter
     if let Some(assigned_to) = &opts.assigned_to {
         let assigned_to_lower = assigned_to.to_lowercase();
@@ -204,29 +269,135 @@ fn filter_items<'a>(cache: &'a Cache, opts: &ListOptions) -> Result<Vec<&'a Item
         }
     }
 
+    // Apply changed_since filter
+    if let Some(since) = &opts.changed_since {
+        let cutoff = parse_since(since, Utc::now())?;
+        items.retain(|i| updated_since(i, cutoff));
+    }
+
     Ok(items)
 }
 
+/// Parses a `--changed-since` value into a UTC cutoff.
+///
+/// Accepts a relative window counted back from `now` (`30m`, `2h`, `3d`, `1w`),
+/// an RFC 3339 timestamp, a local `YYYY-MM-DD HH:MM`, or a local `YYYY-MM-DD`
+/// (midnight).
+fn parse_since(input: &str, now: DateTime<Utc>) -> Result<DateTime<Utc>> {
+    let input = input.trim();
+
+    // Relative window: <number><unit>
+    let has_unit = input.ends_with(|c: char| c.is_ascii_alphabetic());
+    if let Some(minutes) = parse_duration_minutes(input).filter(|_| has_unit) {
+        return TimeDelta::try_minutes(minutes)
+            .and_then(|window| now.checked_sub_signed(window))
+            .ok_or_else(|| {
+                CommandError::Config(format!("--changed-since window '{input}' is out of range"))
+            });
+    }
+
+    if let Ok(dt) = DateTime::parse_from_rfc3339(input) {
+        return Ok(dt.with_timezone(&Utc));
+    }
+
+    let local_datetime = NaiveDateTime::parse_from_str(input, "%Y-%m-%d %H:%M")
+        .or_else(|_| NaiveDateTime::parse_from_str(input, "%Y-%m-%dT%H:%M"))
+        .ok()
+        .or_else(|| {
+            NaiveDate::parse_from_str(input, "%Y-%m-%d")
+                .ok()
+                .and_then(|d| d.and_hms_opt(0, 0, 0))
+        });
+    if let Some(naive) = local_datetime {
+        if let Some(local) = Local.from_local_datetime(&naive).earliest() {
+            return Ok(local.with_timezone(&Utc));
+        }
+    }
+
+    Err(CommandError::Config(format!(
+        "Invalid --changed-since value '{input}'. Use a window like 30m, 2h, 3d, 1w or a date like 2025-01-15"
+    )))
+}
+
+/// Parses a `--due` value into inclusive `(from, to)` date bounds.
+///
+/// A single date (`today`, `tomorrow`, `yesterday`, or `YYYY-MM-DD`) gives
+/// equal bounds. A range `START..END` may omit either end to leave it open.
+fn parse_due_range(
+    input: &str,
+    today: NaiveDate,
+) -> Result<(Option<NaiveDate>, Option<NaiveDate>)> {
+    let invalid = || {
+        CommandError::Config(format!(
+            "Invalid --due value '{input}'. Use today, tomorrow, a date like 2025-02-01, or a range like 2025-02-01..2025-02-07"
+        ))
+    };
+    let parse_date = |s: &str| -> Result<NaiveDate> {
+        match s.trim().to_lowercase().as_str() {
+            "today" => Ok(today),
+            "tomorrow" => Ok(today + Duration::days(1)),
+            "yesterday" => Ok(today - Duration::days(1)),
+            other => NaiveDate::parse_from_str(other, "%Y-%m-%d").map_err(|_| invalid()),
+        }
+    };
+
+    let Some((start, end)) = input.split_once("..") else {
+        let date = parse_date(input)?;
+        return Ok((Some(date), Some(date)));
+    };
+    let bound = |s: &str| {
+        if s.trim().is_empty() {
+            Ok(None)
+        } else {
+            parse_date(s).map(Some)
+        }
+    };
+    let (from, to) = (bound(start)?, bound(end)?);
+    match (from, to) {
+        (None, None) => Err(invalid()),
+        (Some(f), Some(t)) if f > t => Err(CommandError::Config(format!(
+            "Invalid --due range '{input}': the start is after the end"
+        ))),
+        _ => Ok((from, to)),
+    }
+}
+
+/// Returns true if the item's `updated_at` is at or after `cutoff`.
+///
+/// Items without an `updated_at` (or with an unparseable one) never match.
+fn updated_since(item: &Item, cutoff: DateTime<Utc>) -> bool {
+    item.updated_at
+        .as_deref()
+        .and_then(|ts| DateTime::parse_from_rfc3339(ts).ok())
+        .is_some_and(|ts| ts.with_timezone(&Utc) >= cutoff)
+}
+
 /// Sorts items based on the provided options.
 fn sort_items<'a>(mut items: Vec<&'a Item>, opts: &ListOptions) -> Vec<&'a Item> {
     if let Some(sort_field) = &opts.sort {
         match sort_field {
             SortField::Due => {
-                items.sort_by(|a, b| {
+                // Undated tasks stay where --no-due-first/--no-due-last put
+                // them; --reverse only flips the dated ones
+                let (mut dated, undated): (Vec<&Item>, Vec<&Item>) =
+                    items.into_iter().partition(|i| i.due.is_some());
+                dated.sort_by(|a, b| {
                     let a_date = a.due.as_ref().map(|d| d.date.as_str());
                     let b_date = b.due.as_ref().map(|d| d.date.as_str());
-                    // Items without due date go last
-                    match (a_date, b_date) {
-                        (None, None) => std::cmp::Ordering::Equal,
-                        (None, Some(_)) => std::cmp::Ordering::Greater,
-                        (Some(_), None) => std::cmp::Ordering::Less,
-                        (Some(a), Some(b)) => a.cmp(b),
-                    }
+                    a_date.cmp(&b_date)
                 });
+                if opts.reverse {
+                    dated.reverse();
+                }
+                return if opts.no_due_first {
+                    undated.into_iter().chain(dated).collect()
+                } else {
+                    dated.into_iter().chain(undated).collect()
+                };
             }
             SortField::Priority => {
                 // Higher API priority (4) = higher user priority (p1)
-                items.sort_by(|a, b| b.priority.cmp(&a.priority));
+                items.sort_by_key(|b| std::cmp::Reverse(b.priority));
             }
             SortField::Created => {
                 items.sort_by(|a, b| {
@@ -265,26 +436,360 @@ fn apply_limit<'a>(items: Vec<&'a Item>, opts: &ListOptions) -> Vec<&'a Item> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_support::item;
 
     #[test]
     fn test_list_options_defaults() {
         let opts = ListOptions {
             filter: None,
+            strict_filter: false,
             project: None,
-            label: None,
+            labels: vec![],
+            label_match: LabelMatch::All,
             priority: None,
             section: None,
             overdue: false,
             no_due: false,
+            due: None,
             limit: 50,
             all: false,
             cursor: None,
             sort: None,
             reverse: false,
+            no_due_first: false,
             assigned_to: None,
+            changed_since: None,
+            flat: false,
+            no_header: false,
+            ids: false,
+            fields: vec![],
         };
 
         assert!(!opts.all);
         assert_eq!(opts.limit, 50);
     }
+
+    fn fixed_now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 1, 15, 12, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_parse_since_relative() {
+        let now = fixed_now();
+        assert_eq!(
+            parse_since("30m", now).unwrap(),
+            now - Duration::minutes(30)
+        );
+        assert_eq!(parse_since("2h", now).unwrap(), now - Duration::hours(2));
+        assert_eq!(parse_since("3d", now).unwrap(), now - Duration::days(3));
+        assert_eq!(parse_since("1w", now).unwrap(), now - Duration::weeks(1));
+    }
+
+    #[test]
+    fn test_parse_since_absolute() {
+        let now = fixed_now();
+        assert_eq!(
+            parse_since("2025-01-14T08:00:00Z", now).unwrap(),
+            Utc.with_ymd_and_hms(2025, 1, 14, 8, 0, 0).unwrap()
+        );
+        assert!(parse_since("2025-01-14", now).is_ok());
+        assert!(parse_since("2025-01-14 08:30", now).is_ok());
+    }
+
+    #[test]
+    fn test_parse_since_invalid() {
+        let now = fixed_now();
+        assert!(parse_since("yesterday-ish", now).is_err());
+        assert!(parse_since("5x", now).is_err());
+        assert!(parse_since("", now).is_err());
+    }
+
+    #[test]
+    fn test_parse_since_overflow_is_error() {
+        let now = fixed_now();
+        let err = parse_since("99999999999w", now).unwrap_err().to_string();
+        assert!(err.contains("out of range"), "{err}");
+        assert!(parse_since("9223372036854775807m", now).is_err());
+    }
+
+    #[test]
+    fn test_updated_since_window_boundary() {
+        let cutoff = fixed_now() - Duration::hours(2);
+
+        // Exactly on the boundary is included
+        let on_boundary = item("1", "Task 1")
+            .updated_at("2025-01-15T10:00:00Z")
+            .build();
+        assert!(updated_since(&on_boundary, cutoff));
+
+        // Just inside and just outside the window
+        let inside = item("2", "Task 2")
+            .updated_at("2025-01-15T10:00:01.000000Z")
+            .build();
+        assert!(updated_since(&inside, cutoff));
+        let outside = item("3", "Task 3")
+            .updated_at("2025-01-15T09:59:59Z")
+            .build();
+        assert!(!updated_since(&outside, cutoff));
+    }
+
+    #[test]
+    fn test_updated_since_excludes_missing_timestamp() {
+        let cutoff = fixed_now() - Duration::days(365);
+        assert!(!updated_since(&item("1", "Task 1").build(), cutoff));
+        assert!(!updated_since(
+            &item("2", "Task 2").updated_at("not a date").build(),
+            cutoff
+        ));
+    }
+
+    fn due_sort_opts(no_due_first: bool, reverse: bool) -> ListOptions {
+        ListOptions {
+            filter: None,
+            strict_filter: false,
+            project: None,
+            labels: vec![],
+            label_match: LabelMatch::All,
+            priority: None,
+            section: None,
+            overdue: false,
+            no_due: false,
+            due: None,
+            limit: 50,
+            all: false,
+            cursor: None,
+            sort: Some(SortField::Due),
+            reverse,
+            no_due_first,
+            assigned_to: None,
+            changed_since: None,
+            flat: false,
+            no_header: false,
+            ids: false,
+            fields: vec![],
+        }
+    }
+
+    fn due_sorted_ids(no_due_first: bool, reverse: bool) -> Vec<String> {
+        let with_due = |id: &str, date: &str| item(id, &format!("Task {id}")).due(date).build();
+        let items = [
+            item("undated-1", "Task undated-1").build(),
+            with_due("later", "2025-03-01"),
+            item("undated-2", "Task undated-2").build(),
+            with_due("sooner", "2025-02-01"),
+        ];
+        let refs: Vec<&Item> = items.iter().collect();
+        sort_items(refs, &due_sort_opts(no_due_first, reverse))
+            .into_iter()
+            .map(|item| item.id.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_sort_due_puts_undated_last_by_default() {
+        assert_eq!(
+            due_sorted_ids(false, false),
+            vec!["sooner", "later", "undated-1", "undated-2"]
+        );
+    }
+
+    #[test]
+    fn test_sort_due_no_due_first() {
+        assert_eq!(
+            due_sorted_ids(true, false),
+            vec!["undated-1", "undated-2", "sooner", "later"]
+        );
+    }
+
+    #[test]
+    fn test_sort_due_reverse_keeps_undated_placement() {
+        assert_eq!(
+            due_sorted_ids(false, true),
+            vec!["later", "sooner", "undated-1", "undated-2"]
+        );
+        assert_eq!(
+            due_sorted_ids(true, true),
+            vec!["undated-1", "undated-2", "later", "sooner"]
+        );
+    }
+
+    fn label_filtered_ids(labels: &[&str], label_match: LabelMatch) -> Vec<String> {
+        let with_labels =
+            |id: &str, labels: &[&str]| item(id, &format!("Task {id}")).labels(labels).build();
+        let mut cache = Cache::new();
+        cache.items = vec![
+            with_labels("work", &["work"]),
+            with_labels("urgent", &["Urgent"]),
+            with_labels("both", &["work", "urgent"]),
+            with_labels("neither", &["home"]),
+        ];
+        let mut opts = due_sort_opts(false, false);
+        opts.sort = None;
+        opts.labels = labels.iter().map(|l| l.to_string()).collect();
+        opts.label_match = label_match;
+
+        filter_items(&cache, &opts, None)
+            .unwrap()
+            .into_iter()
+            .map(|item| item.id.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_label_match_all_requires_every_label() {
+        assert_eq!(
+            label_filtered_ids(&["work", "urgent"], LabelMatch::All),
+            vec!["both"]
+        );
+    }
+
+    #[test]
+    fn test_label_match_any_accepts_one_label() {
+        assert_eq!(
+            label_filtered_ids(&["work", "urgent"], LabelMatch::Any),
+            vec!["work", "urgent", "both"]
+        );
+    }
+
+    #[test]
+    fn test_single_label_ignores_match_mode() {
+        for mode in [LabelMatch::All, LabelMatch::Any] {
+            assert_eq!(
+                label_filtered_ids(&["URGENT"], mode),
+                vec!["urgent", "both"]
+            );
+        }
+    }
+
+    #[test]
+    fn test_format_ids_is_exactly_the_filtered_full_ids() {
+        let urgent = |id: &str| item(id, &format!("Task {id}")).priority(4).build();
+        let mut cache = Cache::new();
+        cache.items = vec![
+            urgent("6Jf8VQXxpwv56VQ7"),
+            item("6Jf8VQXxpwv56VQ8", "Task 6Jf8VQXxpwv56VQ8").build(),
+            urgent("6Jf8VQXxpwv56VQ9"),
+        ];
+        let mut opts = due_sort_opts(false, false);
+        opts.sort = None;
+        opts.ids = true;
+        opts.priority = Some(1);
+
+        let items = filter_items(&cache, &opts, None).unwrap();
+        assert_eq!(format_ids(&items), "6Jf8VQXxpwv56VQ7\n6Jf8VQXxpwv56VQ9\n");
+
+        opts.priority = Some(2);
+        let items = filter_items(&cache, &opts, None).unwrap();
+        assert_eq!(format_ids(&items), "");
+    }
+
+    fn due_filtered_ids(due: &str, today: NaiveDate) -> Vec<String> {
+        let with_due = |id: &str, date: NaiveDate| {
+            item(id, &format!("Task {id}"))
+                .due(&date.format("%Y-%m-%d").to_string())
+                .build()
+        };
+        let mut cache = Cache::new();
+        cache.items = vec![
+            with_due("yesterday", today - Duration::days(1)),
+            with_due("today", today),
+            with_due("tomorrow", today + Duration::days(1)),
+            with_due("next-week", today + Duration::days(7)),
+            item("undated", "Task undated").build(),
+        ];
+        let mut opts = due_sort_opts(false, false);
+        opts.sort = None;
+        opts.due = Some(due.to_string());
+
+        filter_items(&cache, &opts, None)
+            .unwrap()
+            .into_iter()
+            .map(|item| item.id.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_due_keyword_matches_single_day() {
+        let today = Local::now().date_naive();
+        assert_eq!(due_filtered_ids("today", today), vec!["today"]);
+        assert_eq!(due_filtered_ids("Tomorrow", today), vec!["tomorrow"]);
+    }
+
+    #[test]
+    fn test_due_single_date() {
+        let today = Local::now().date_naive();
+        let date = (today + Duration::days(7)).format("%Y-%m-%d").to_string();
+        assert_eq!(due_filtered_ids(&date, today), vec!["next-week"]);
+    }
+
+    #[test]
+    fn test_due_range_is_inclusive() {
+        let today = Local::now().date_naive();
+        let end = (today + Duration::days(1)).format("%Y-%m-%d").to_string();
+        assert_eq!(
+            due_filtered_ids(&format!("today..{end}"), today),
+            vec!["today", "tomorrow"]
+        );
+        assert_eq!(
+            due_filtered_ids("tomorrow..", today),
+            vec!["tomorrow", "next-week"]
+        );
+        assert_eq!(
+            due_filtered_ids("..today", today),
+            vec!["yesterday", "today"]
+        );
+    }
+
+    #[test]
+    fn test_due_combines_with_other_filters() {
+        let today = Local::now().date_naive();
+        let mut cache = Cache::new();
+        let today_str = today.format("%Y-%m-%d").to_string();
+        cache.items = vec![
+            item("urgent-today", "Task urgent-today")
+                .due(&today_str)
+                .priority(4)
+                .build(),
+            item("plain-today", "Task plain-today")
+                .due(&today_str)
+                .build(),
+        ];
+        let mut opts = due_sort_opts(false, false);
+        opts.due = Some("today".to_string());
+        let filter = FilterParser::parse("p1").unwrap();
+
+        let ids: Vec<&str> = filter_items(&cache, &opts, Some(&filter))
+            .unwrap()
+            .into_iter()
+            .map(|item| item.id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["urgent-today"]);
+    }
+
+    #[test]
+    fn test_parse_due_range_invalid() {
+        let today = NaiveDate::from_ymd_opt(2025, 2, 1).unwrap();
+        assert!(parse_due_range("someday", today).is_err());
+        assert!(parse_due_range("..", today).is_err());
+        let err = parse_due_range("2025-02-07..2025-02-01", today).unwrap_err();
+        assert!(err.to_string().contains("start is after the end"));
+    }
+
+    #[test]
+    fn test_strict_filter_rejects_unknown_project() {
+        let mut cache = Cache::new();
+        cache.items = vec![item("1", "Task 1").build()];
+        let mut opts = due_sort_opts(false, false);
+        let filter = FilterParser::parse("#Nowhere").unwrap();
+
+        let lenient = filter_items(&cache, &opts, Some(&filter)).unwrap();
+        assert!(lenient.is_empty());
+
+        opts.strict_filter = true;
+        let err = filter_items(&cache, &opts, Some(&filter)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "filter error: unknown project 'Nowhere' in filter"
+        );
+    }
 }
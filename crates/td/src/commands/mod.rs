@@ -3,30 +3,48 @@
 //! This module contains the actual command handlers that are invoked by the CLI.
 
 pub mod add;
+pub mod cache;
 pub mod collaborators;
 pub mod comments;
+pub mod completed;
 pub mod completions;
 pub mod config;
 pub mod delete;
 pub mod done;
 pub mod edit;
 pub mod filters;
+pub mod info;
 pub mod keyring;
 pub mod labels;
 pub mod list;
+pub mod open;
 pub mod projects;
 pub mod quick;
 pub mod reminders;
 pub mod reopen;
+pub mod search;
 pub mod sections;
 pub mod setup;
 pub mod show;
+pub mod stats;
 pub mod sync;
 pub mod today;
 
 use std::io::IsTerminal;
+use std::time::Duration;
 
-use crate::cli::Cli;
+use chrono::Utc;
+use owo_colors::OwoColorize;
+use todoist_api_rs::client::{TodoistClient, TodoistClientBuilder, DEFAULT_TIMEOUT_SECS};
+use todoist_api_rs::sync::{Due, DueSpec, Item, Section};
+use todoist_cache_rs::{Cache, CacheStore, SyncEvent, SyncManager};
+
+use crate::cli::{Cli, ColorChoice};
+use crate::output::helpers::{
+    format_due_styled, format_priority, format_sync_age, id_display_len, truncate_id_to,
+    MINUTES_PER_DAY, MINUTES_PER_HOUR,
+};
+use crate::output::{DateStyle, IdStyle, OutputFormat};
 
 /// Confirmation result for bulk operations.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -37,6 +55,96 @@ pub enum ConfirmResult {
     Aborted,
 }
 
+/// A task as shown in a bulk confirmation preview.
+#[derive(Debug, Clone, Copy)]
+pub struct BulkItem<'a> {
+    /// Task ID (shortened when displayed, per the preview's `IdStyle`).
+    pub id: &'a str,
+    /// Task content.
+    pub content: &'a str,
+    /// API priority (4 = p1, 1 = p4).
+    pub priority: i32,
+    /// Due date, if any.
+    pub due: Option<&'a Due>,
+}
+
+impl<'a> BulkItem<'a> {
+    /// Creates a preview entry with no priority or due context.
+    pub fn new(id: &'a str, content: &'a str) -> Self {
+        Self {
+            id,
+            content,
+            priority: 1,
+            due: None,
+        }
+    }
+
+    /// Creates a preview entry from a cached task.
+    pub fn from_item(item: &'a Item) -> Self {
+        Self {
+            priority: item.priority,
+            due: item.due.as_ref(),
+            ..Self::new(&item.id, &item.content)
+        }
+    }
+
+    /// Creates a preview entry for a resolved task, taking priority and due
+    /// from the cache when the task is there.
+    pub fn resolve(cache: &'a Cache, id: &'a str, content: &'a str) -> Self {
+        cache
+            .find_item(id)
+            .map_or_else(|| Self::new(id, content), Self::from_item)
+    }
+}
+
+/// Renders the list of tasks shown before a bulk confirmation prompt.
+///
+/// Each task gets one row with its ID, priority, due date, and content. IDs
+/// and dates follow `id_style` and `date_style`, like task tables.
+pub fn format_bulk_preview(
+    action: &str,
+    items: &[BulkItem],
+    date_style: DateStyle,
+    id_style: IdStyle,
+) -> String {
+    let id_len = id_display_len(items.iter().map(|i| i.id), id_style);
+    let rows: Vec<(String, String, String)> = items
+        .iter()
+        .map(|item| {
+            (
+                truncate_id_to(item.id, id_len),
+                format_priority(item.priority, false),
+                format_due_styled(item.due, date_style, false),
+            )
+        })
+        .collect();
+    let id_width = rows
+        .iter()
+        .map(|(id, _, _)| id.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max(2);
+    let due_width = rows
+        .iter()
+        .map(|(_, _, due)| due.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max(3);
+
+    let mut output = format!("About to {} {} tasks:\n", action, items.len());
+    output.push_str(&format!(
+        "  {:<id_width$}  Pri  {:<due_width$}  Content\n",
+        "ID", "Due"
+    ));
+    for (item, (id, priority, due)) in items.iter().zip(&rows) {
+        output.push_str(&format!(
+            "  {:<id_width$}  {:<3}  {:<due_width$}  {}\n",
+            id, priority, due, item.content
+        ));
+    }
+    output
+}
+
 /// Prompts for confirmation on bulk destructive operations.
 ///
 /// Returns `Ok(ConfirmResult::Confirmed)` if:
@@ -51,12 +159,15 @@ pub enum ConfirmResult {
 /// # Arguments
 ///
 /// * `action` - The action being performed (e.g., "delete", "complete", "reopen")
-/// * `items` - List of (id_prefix, content) tuples to display
+/// * `items` - Tasks to preview
+/// * `date_style`, `id_style` - How the preview renders due dates and IDs
 /// * `force` - If true, skip confirmation
 /// * `quiet` - If true, skip confirmation
 pub fn confirm_bulk_operation(
     action: &str,
-    items: &[(&str, &str)],
+    items: &[BulkItem],
+    date_style: DateStyle,
+    id_style: IdStyle,
     force: bool,
     quiet: bool,
 ) -> Result<ConfirmResult> {
@@ -84,11 +195,10 @@ pub fn confirm_bulk_operation(
     }
 
     // Display items to be affected
-    eprintln!("About to {} {} tasks:", action, items.len());
-    for (id_prefix, content) in items {
-        eprintln!("  {}  {}", id_prefix, content);
-    }
-    eprintln!();
+    eprintln!(
+        "{}",
+        format_bulk_preview(action, items, date_style, id_style)
+    );
 
     // Use dialoguer for interactive confirmation
     let confirmed = dialoguer::Confirm::new()
@@ -109,6 +219,209 @@ pub fn confirm_bulk_operation(
     }
 }
 
+/// Prompts for typed confirmation before a destructive bulk operation.
+///
+/// Unlike [`confirm_bulk_operation`], this never proceeds automatically:
+/// the user must type the item count or `DELETE`, and non-interactive
+/// sessions are refused unless `force` is set.
+///
+/// # Arguments
+///
+/// * `action` - The action being performed (e.g., "delete")
+/// * `items` - Tasks to preview
+/// * `date_style`, `id_style` - How the preview renders due dates and IDs
+/// * `force` - If true, skip confirmation
+/// * `stdin_is_tty` - Whether stdin is an interactive terminal
+pub fn confirm_destructive_operation(
+    action: &str,
+    items: &[BulkItem],
+    date_style: DateStyle,
+    id_style: IdStyle,
+    force: bool,
+    stdin_is_tty: bool,
+) -> Result<ConfirmResult> {
+    if force || items.is_empty() {
+        return Ok(ConfirmResult::Confirmed);
+    }
+
+    if !stdin_is_tty {
+        return Err(CommandError::Config(format!(
+            "Refusing to {} {} tasks without confirmation in non-interactive mode. Use --force to proceed.",
+            action,
+            items.len()
+        )));
+    }
+
+    eprintln!(
+        "{}",
+        format_bulk_preview(action, items, date_style, id_style)
+    );
+
+    let answer: String = dialoguer::Input::new()
+        .with_prompt(format!(
+            "Type {} or DELETE to {} these tasks",
+            items.len(),
+            action
+        ))
+        .allow_empty(true)
+        .interact_text()
+        .map_err(|e| {
+            CommandError::Io(std::io::Error::other(format!(
+                "Failed to read input: {}",
+                e
+            )))
+        })?;
+
+    let answer = answer.trim();
+    if answer == items.len().to_string() || answer == "DELETE" {
+        Ok(ConfirmResult::Confirmed)
+    } else {
+        Ok(ConfirmResult::Aborted)
+    }
+}
+
+/// Default cap on the number of tasks a `--filter` bulk command may touch.
+pub const DEFAULT_MAX_FILTER_RESULTS: usize = 50;
+
+/// Refuses a filter-selected bulk operation that matches more than
+/// `max_results` tasks, unless `force` is set.
+///
+/// This guards against an overly broad filter before any confirmation
+/// prompt is shown.
+pub fn check_filter_result_cap(
+    action: &str,
+    count: usize,
+    max_results: usize,
+    force: bool,
+) -> Result<()> {
+    if force || count <= max_results {
+        return Ok(());
+    }
+    Err(CommandError::Config(format!(
+        "Filter matches {count} tasks, over the limit of {max_results}; refusing to {action} them. Pass --max-results {count} or --force to proceed."
+    )))
+}
+
+/// Builds the `due` argument for `item_add`/`item_update` from a date string.
+///
+/// Todoist parses `string` server-side; `lang` tells it which language the
+/// string is in (e.g., "es" for "lunes"), and `timezone` pins a timed due
+/// date to a zone other than the account's.
+pub fn due_arg(due: &str, lang: Option<&str>, timezone: Option<&str>) -> serde_json::Value {
+    let mut spec = DueSpec::from_string(due);
+    if let Some(lang) = lang {
+        spec = spec.with_lang(lang);
+    }
+    if let Some(timezone) = timezone {
+        spec = spec.with_timezone(timezone);
+    }
+    serde_json::json!(spec)
+}
+
+/// Parses a duration such as `45m`, `2h`, `3d` or `1w` into minutes.
+///
+/// Units are case-insensitive and a bare number is minutes. Returns `None`
+/// for negative, malformed or overflowing values so each caller can word the
+/// error for its own flag.
+pub fn parse_duration_minutes(input: &str) -> Option<i64> {
+    let input = input.trim();
+    let (number, unit_minutes) = match input.char_indices().last() {
+        Some((i, unit)) => match unit.to_ascii_lowercase() {
+            'm' => (&input[..i], 1),
+            'h' => (&input[..i], i64::from(MINUTES_PER_HOUR)),
+            'd' => (&input[..i], i64::from(MINUTES_PER_DAY)),
+            'w' => (&input[..i], i64::from(MINUTES_PER_DAY) * 7),
+            _ => (input, 1),
+        },
+        None => (input, 1),
+    };
+    number
+        .trim()
+        .parse::<i64>()
+        .ok()
+        .filter(|n| *n >= 0)
+        .and_then(|n| n.checked_mul(unit_minutes))
+}
+
+/// Resolves a section name or ID to the ID of a section in `project_id`.
+///
+/// A section that only exists in another project is rejected before any
+/// command is sent, naming the project it belongs to; the API's own error
+/// for this doesn't say what is wrong.
+///
+/// # Errors
+///
+/// Returns an error if the section belongs to a different project or can't
+/// be found.
+pub async fn resolve_section_in_project(
+    manager: &mut SyncManager,
+    section: &str,
+    project_id: &str,
+) -> Result<String> {
+    // Check the cache first so a mismatch doesn't trigger a pointless sync
+    let cache = manager.cache();
+    if cache.find_section(section, Some(project_id)).is_none() {
+        if let Some(elsewhere) = cache.find_section(section, None) {
+            check_section_project(cache, elsewhere, project_id)?;
+        }
+    }
+
+    let found = manager
+        .resolve_section(section, Some(project_id))
+        .await?
+        .clone();
+    check_section_project(manager.cache(), &found, project_id)?;
+    Ok(found.id)
+}
+
+/// Checks that `section` belongs to `project_id`.
+///
+/// # Errors
+///
+/// Returns `CommandError::Config` naming the section's actual project.
+pub fn check_section_project(cache: &Cache, section: &Section, project_id: &str) -> Result<()> {
+    if section.project_id == project_id {
+        return Ok(());
+    }
+    let project_name = |id: &str| {
+        cache
+            .find_project(id)
+            .map_or_else(|| id.to_string(), |p| p.name.clone())
+    };
+    let actual = project_name(&section.project_id);
+    Err(CommandError::Config(format!(
+        "Section '{}' is in project '{}', not '{}'. Use --project \"{}\" or a section from '{}'.",
+        section.name,
+        actual,
+        project_name(project_id),
+        actual,
+        project_name(project_id)
+    )))
+}
+
+/// Validates a `--due-tz` value against the tz database.
+///
+/// Returns the canonical zone name (e.g., "America/Los_Angeles").
+pub fn validate_due_tz(tz: &str) -> Result<String> {
+    tz.parse::<chrono_tz::Tz>()
+        .map(|zone| zone.name().to_string())
+        .map_err(|_| {
+            CommandError::Config(format!(
+                "Unknown timezone \"{tz}\". Use an IANA name such as \"America/Los_Angeles\"."
+            ))
+        })
+}
+
+/// Picks the language for due date parsing.
+///
+/// An explicit language (from `--lang` or the `due_lang` config) wins;
+/// otherwise the cached user's Todoist language is used.
+pub fn resolve_due_lang(explicit: Option<&str>, cache: &Cache) -> Option<String> {
+    explicit
+        .map(String::from)
+        .or_else(|| cache.user.as_ref().and_then(|user| user.lang.clone()))
+}
+
 /// Error type for command execution.
 #[derive(Debug, thiserror::Error)]
 pub enum CommandError {
@@ -120,7 +433,7 @@ pub enum CommandError {
     #[error("cache error: {0}")]
     CacheStore(#[from] todoist_cache_rs::CacheStoreError),
 
-    /// Filter parsing error.
+    /// Filter parsing or reference error.
     #[error("filter error: {0}")]
     Filter(#[from] todoist_cache_rs::filter::FilterError),
 
@@ -139,6 +452,136 @@ pub enum CommandError {
     /// Serialization error.
     #[error("serialization error: {0}")]
     Json(#[from] serde_json::Error),
+
+    /// An API request exceeded the configured timeout (in seconds).
+    #[error("request timed out after {0}s")]
+    Timeout(u64),
+
+    /// No API token is available and setup can't prompt for one.
+    #[error("no token; set TODOIST_TOKEN or run td setup")]
+    MissingToken,
+
+    /// The command was interrupted with Ctrl-C before it finished.
+    #[error("interrupted")]
+    Interrupted,
+}
+
+impl CommandError {
+    /// Converts an HTTP timeout into [`CommandError::Timeout`].
+    ///
+    /// Timeouts surface from reqwest as opaque transport errors, so this
+    /// attaches the configured duration for a clearer message. Other errors
+    /// are returned unchanged.
+    pub fn with_timeout(self, timeout_secs: u64) -> Self {
+        let timed_out = match &self {
+            CommandError::Api(e) | CommandError::Sync(todoist_cache_rs::SyncError::Api(e)) => {
+                matches!(e, todoist_api_rs::error::Error::Http(req) if req.is_timeout())
+            }
+            _ => false,
+        };
+        if timed_out {
+            CommandError::Timeout(timeout_secs)
+        } else {
+            self
+        }
+    }
+}
+
+/// Resolves a task description from `--description` or `--description-file`.
+///
+/// A description of `-` is read from stdin. Content read from a file or
+/// stdin has its trailing newline removed.
+///
+/// # Errors
+///
+/// Returns `CommandError::Config` if the file can't be read, or
+/// `CommandError::Io` if reading stdin fails.
+pub fn read_description(
+    description: Option<&str>,
+    description_file: Option<&std::path::Path>,
+) -> Result<Option<String>> {
+    use std::io::Read;
+
+    let contents = match (description, description_file) {
+        (_, Some(path)) => std::fs::read_to_string(path).map_err(|e| {
+            CommandError::Config(format!(
+                "Failed to read description file '{}': {}",
+                path.display(),
+                e
+            ))
+        })?,
+        (Some("-"), None) => {
+            let mut buf = String::new();
+            std::io::stdin().read_to_string(&mut buf)?;
+            buf
+        }
+        (description, None) => return Ok(description.map(String::from)),
+    };
+
+    let trimmed = contents
+        .strip_suffix('\n')
+        .map(|s| s.strip_suffix('\r').unwrap_or(s))
+        .unwrap_or(&contents);
+    Ok(Some(trimmed.to_string()))
+}
+
+/// Environment variable that points the API client at an alternate host.
+///
+/// Advanced/test-only: intended for local mock servers and debugging.
+pub const API_BASE_URL_ENV: &str = "TODOIST_API_BASE_URL";
+
+/// Returns the API base URL override, if any.
+///
+/// `TODOIST_API_BASE_URL` takes precedence over the `api_base_url` config
+/// key (`configured`). Empty values are ignored.
+pub fn api_base_url_override(configured: Option<&str>) -> Option<String> {
+    std::env::var(API_BASE_URL_ENV)
+        .ok()
+        .or_else(|| configured.map(String::from))
+        .map(|url| url.trim().trim_end_matches('/').to_string())
+        .filter(|url| !url.is_empty())
+}
+
+/// Decides whether output is colored.
+///
+/// `--no-color` is a deprecated alias for `--color never`. In auto mode,
+/// colors are used only when stdout is a terminal and `NO_COLOR` is unset
+/// (or empty).
+pub fn resolve_use_colors(
+    choice: ColorChoice,
+    no_color_flag: bool,
+    stdout_is_tty: bool,
+    no_color_env: bool,
+) -> bool {
+    if no_color_flag {
+        return false;
+    }
+    match choice {
+        ColorChoice::Always => true,
+        ColorChoice::Never => false,
+        ColorChoice::Auto => stdout_is_tty && !no_color_env,
+    }
+}
+
+/// Picks the output format.
+///
+/// `--json`, `--table` and `--format` win over the `output.format` config
+/// key; unknown config values are ignored.
+pub fn resolve_output_format(
+    json: bool,
+    table: bool,
+    format: Option<OutputFormat>,
+    configured: Option<&str>,
+) -> OutputFormat {
+    if json {
+        OutputFormat::Json
+    } else if table {
+        OutputFormat::Table
+    } else {
+        format
+            .or_else(|| configured.and_then(OutputFormat::from_name))
+            .unwrap_or_default()
+    }
 }
 
 /// Result type for command execution.
@@ -146,10 +589,12 @@ pub type Result<T> = std::result::Result<T, CommandError>;
 
 /// Context for command execution, containing common dependencies.
 pub struct CommandContext {
-    /// Whether to output JSON.
-    pub json_output: bool,
+    /// Output format (table, JSON, or CSV).
+    pub output_format: OutputFormat,
     /// Whether to use colors.
     pub use_colors: bool,
+    /// Whether JSON output is minified (`--compact`) instead of pretty-printed.
+    pub compact_json: bool,
     /// Whether to be quiet (errors only).
     pub quiet: bool,
     /// Whether to be verbose.
@@ -157,61 +602,320 @@ pub struct CommandContext {
     /// Whether to sync before executing the command.
     /// Used by read commands with the --sync flag.
     pub sync_first: bool,
+    /// Request timeout in seconds for API clients.
+    pub timeout_secs: u64,
+    /// Whether command batches rejected for a stale sync token are retried once.
+    pub retry_on_conflict: bool,
+    /// Alternate API base URL, if overridden for testing or self-hosting.
+    pub base_url: Option<String>,
+    /// How dates are rendered in table output.
+    pub date_style: DateStyle,
+    /// How IDs are rendered in table output.
+    pub id_style: IdStyle,
+    /// Whether long table output may be piped through `$PAGER`.
+    pub use_pager: bool,
+    /// Config profile from `--profile` / `TD_PROFILE`, if any.
+    pub profile: Option<String>,
+    /// The profile's settings, read once at startup. Defaults if the config
+    /// file is missing or can't be read.
+    pub config: config::Config,
 }
 
 impl CommandContext {
     /// Creates a new command context from CLI arguments.
     pub fn from_cli(cli: &Cli) -> Self {
+        let profile = cli
+            .profile
+            .as_deref()
+            .map(str::trim)
+            .filter(|p| !p.is_empty());
+        let config = config::load_config_for(profile).unwrap_or_default();
         Self {
-            json_output: cli.json,
-            use_colors: !cli.no_color,
+            output_format: resolve_output_format(
+                cli.json,
+                cli.table,
+                cli.format,
+                config.output.format.as_deref(),
+            ),
+            use_colors: resolve_use_colors(
+                cli.color,
+                cli.no_color,
+                std::io::stdout().is_terminal(),
+                std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty()),
+            ),
+            compact_json: cli.compact,
             quiet: cli.quiet,
             verbose: cli.verbose,
             sync_first: cli.sync,
+            timeout_secs: cli.timeout.unwrap_or(DEFAULT_TIMEOUT_SECS),
+            retry_on_conflict: cli.retry_on_conflict,
+            base_url: api_base_url_override(config.api_base_url.as_deref()),
+            date_style: if cli.plain_dates {
+                DateStyle::Absolute
+            } else {
+                DateStyle::Relative
+            },
+            id_style: if cli.full_ids || config.output.id_display.as_deref() == Some("full") {
+                IdStyle::Full
+            } else {
+                IdStyle::Short
+            },
+            use_pager: !cli.no_pager && config.output.use_pager.unwrap_or(true),
+            profile: profile.map(String::from),
+            config,
+        }
+    }
+
+    /// Prints table output, through `$PAGER` when it is taller than the
+    /// terminal. JSON/CSV and non-TTY output are printed directly.
+    pub fn print_table(&self, output: &str) {
+        crate::pager::print_paged(self.use_pager, self.output_format, output);
+    }
+
+    /// Returns a client builder configured with the request timeout and any
+    /// base URL override.
+    pub fn client_builder(&self, token: &str) -> TodoistClientBuilder {
+        let builder = TodoistClientBuilder::new(token)
+            .request_timeout(Duration::from_secs(self.timeout_secs));
+        match self.base_url {
+            Some(ref url) => builder.base_url(url.clone()),
+            None => builder,
         }
     }
+
+    /// Builds an API client configured with the request timeout.
+    pub fn client(&self, token: &str) -> Result<TodoistClient> {
+        Ok(self.client_builder(token).build()?)
+    }
+
+    /// Prints how fresh the cached data is ("last synced 7m ago") on stderr.
+    ///
+    /// Read commands call this after table output; it stays silent in quiet
+    /// mode and for JSON/CSV so machine-readable output is unaffected.
+    pub fn print_sync_footer(&self, cache: &Cache) {
+        if self.quiet || self.output_format != OutputFormat::Table {
+            return;
+        }
+        let footer = format_sync_age(cache.last_sync_age(Utc::now()));
+        if self.use_colors {
+            eprintln!("{}", footer.dimmed());
+        } else {
+            eprintln!("{footer}");
+        }
+    }
+
+    /// Returns the cache store for the active profile.
+    ///
+    /// Each profile has its own cache file, so switching profiles never
+    /// mixes data from different accounts.
+    pub fn cache_store(&self) -> Result<CacheStore> {
+        if let Some(ref profile) = self.profile {
+            config::validate_profile_name(profile)?;
+        }
+        Ok(CacheStore::for_profile(self.profile.as_deref())?)
+    }
+
+    /// Creates a sync manager for the active profile's cache.
+    ///
+    /// Sync events such as the full-sync fallback are reported on stderr
+    /// according to the output settings (see [`format_sync_event`]).
+    pub fn sync_manager(&self, token: &str) -> Result<SyncManager> {
+        let manager = SyncManager::new(self.client(token)?, self.configured_cache_store()?)?;
+        Ok(self.configure_sync_manager(manager))
+    }
+
+    /// Creates a sync manager on an empty cache, leaving the cache file unread.
+    ///
+    /// Used by `td sync --full --reset`, which must work even when the cache
+    /// file is corrupt.
+    pub fn sync_manager_discarding_cache(&self, token: &str) -> Result<SyncManager> {
+        let manager =
+            SyncManager::discarding_cache(self.client(token)?, self.configured_cache_store()?);
+        Ok(self.configure_sync_manager(manager))
+    }
+
+    /// Returns the profile's cache store with the configured write options.
+    fn configured_cache_store(&self) -> Result<CacheStore> {
+        Ok(self
+            .cache_store()?
+            .with_sorted(self.config.cache.sort.unwrap_or(false))
+            .with_pretty(self.config.cache.pretty.unwrap_or(false)))
+    }
+
+    /// Applies the conflict-retry setting and the stderr event sink.
+    fn configure_sync_manager(&self, manager: SyncManager) -> SyncManager {
+        let output_format = self.output_format;
+        let quiet = self.quiet;
+        manager
+            .with_retry_on_conflict(self.retry_on_conflict)
+            .with_event_sink(move |event| {
+                if let Some(line) = format_sync_event(event, output_format, quiet) {
+                    eprintln!("{line}");
+                }
+            })
+    }
+}
+
+/// Brings the cache up to date before a read command.
+///
+/// Syncs when `--sync` was given or the cache is stale (see
+/// [`SyncManager::needs_sync`]), so repeated reads within the staleness
+/// window are answered from the cache without any request. A sync forced by
+/// `--sync` must succeed. One triggered only by staleness falls back to the
+/// existing cache with a warning, so reads keep working offline.
+///
+/// # Errors
+///
+/// Returns an error if a `--sync` sync fails, or if a sync fails and there
+/// is no cached data to fall back to.
+pub async fn ensure_fresh(ctx: &CommandContext, manager: &mut SyncManager) -> Result<()> {
+    if !ctx.sync_first && !manager.needs_sync(Utc::now()) {
+        return Ok(());
+    }
+
+    let can_fall_back = !ctx.sync_first && manager.cache().last_sync.is_some();
+    if ctx.verbose {
+        eprintln!("Syncing with Todoist...");
+    }
+    match manager.sync().await {
+        Ok(_) => Ok(()),
+        Err(e) if can_fall_back => {
+            if !ctx.quiet {
+                eprintln!("Warning: sync failed ({e}); showing cached data.");
+            }
+            Ok(())
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Formats a sync event for stderr.
+///
+/// Returns `None` in quiet mode. With `--json` the event is a single-line
+/// JSON object (`{"warning": <code>, "message": <text>}`) so scripts reading
+/// stderr can parse it.
+pub fn format_sync_event(
+    event: &SyncEvent,
+    output_format: OutputFormat,
+    quiet: bool,
+) -> Option<String> {
+    if quiet {
+        None
+    } else if output_format.is_json() {
+        Some(
+            serde_json::json!({
+                "warning": event.code(),
+                "message": event.to_string(),
+            })
+            .to_string(),
+        )
+    } else {
+        Some(format!("Warning: {event}"))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_duration_minutes_units() {
+        assert_eq!(parse_duration_minutes("30"), Some(30));
+        assert_eq!(parse_duration_minutes("45m"), Some(45));
+        assert_eq!(parse_duration_minutes(" 2H "), Some(120));
+        assert_eq!(parse_duration_minutes("3d"), Some(3 * 24 * 60));
+        assert_eq!(parse_duration_minutes("1w"), Some(7 * 24 * 60));
+    }
+
+    #[test]
+    fn test_parse_duration_minutes_rejects_invalid() {
+        for input in ["", "h", "-5m", "1.5h", "5x", "soon", "9223372036854775807w"] {
+            assert_eq!(parse_duration_minutes(input), None, "{input}");
+        }
+    }
+
     #[test]
     fn test_confirm_bulk_single_item_no_confirmation() {
-        let items = vec![("abc123", "Task 1")];
-        let result = confirm_bulk_operation("delete", &items, false, false).unwrap();
+        let items = vec![BulkItem::new("abc123", "Task 1")];
+        let result = confirm_bulk_operation(
+            "delete",
+            &items,
+            DateStyle::Relative,
+            IdStyle::Short,
+            false,
+            false,
+        )
+        .unwrap();
         assert_eq!(result, ConfirmResult::Confirmed);
     }
 
     #[test]
     fn test_confirm_bulk_empty_items_no_confirmation() {
-        let items: Vec<(&str, &str)> = vec![];
-        let result = confirm_bulk_operation("delete", &items, false, false).unwrap();
+        let items: Vec<BulkItem> = vec![];
+        let result = confirm_bulk_operation(
+            "delete",
+            &items,
+            DateStyle::Relative,
+            IdStyle::Short,
+            false,
+            false,
+        )
+        .unwrap();
         assert_eq!(result, ConfirmResult::Confirmed);
     }
 
     #[test]
     fn test_confirm_bulk_force_skips_confirmation() {
-        let items = vec![("abc123", "Task 1"), ("def456", "Task 2")];
-        let result = confirm_bulk_operation("delete", &items, true, false).unwrap();
+        let items = vec![
+            BulkItem::new("abc123", "Task 1"),
+            BulkItem::new("def456", "Task 2"),
+        ];
+        let result = confirm_bulk_operation(
+            "delete",
+            &items,
+            DateStyle::Relative,
+            IdStyle::Short,
+            true,
+            false,
+        )
+        .unwrap();
         assert_eq!(result, ConfirmResult::Confirmed);
     }
 
     #[test]
     fn test_confirm_bulk_quiet_skips_confirmation() {
-        let items = vec![("abc123", "Task 1"), ("def456", "Task 2")];
-        let result = confirm_bulk_operation("delete", &items, false, true).unwrap();
+        let items = vec![
+            BulkItem::new("abc123", "Task 1"),
+            BulkItem::new("def456", "Task 2"),
+        ];
+        let result = confirm_bulk_operation(
+            "delete",
+            &items,
+            DateStyle::Relative,
+            IdStyle::Short,
+            false,
+            true,
+        )
+        .unwrap();
         assert_eq!(result, ConfirmResult::Confirmed);
     }
 
     #[test]
     fn test_confirm_bulk_force_and_quiet_skips_confirmation() {
         let items = vec![
-            ("abc123", "Task 1"),
-            ("def456", "Task 2"),
-            ("ghi789", "Task 3"),
+            BulkItem::new("abc123", "Task 1"),
+            BulkItem::new("def456", "Task 2"),
+            BulkItem::new("ghi789", "Task 3"),
         ];
-        let result = confirm_bulk_operation("complete", &items, true, true).unwrap();
+        let result = confirm_bulk_operation(
+            "complete",
+            &items,
+            DateStyle::Relative,
+            IdStyle::Short,
+            true,
+            true,
+        )
+        .unwrap();
         assert_eq!(result, ConfirmResult::Confirmed);
     }
 
@@ -221,9 +925,428 @@ mod tests {
     #[test]
     fn test_confirm_bulk_non_tty_proceeds() {
         // In tests, stdin is typically not a TTY, so this should auto-confirm
-        let items = vec![("abc123", "Task 1"), ("def456", "Task 2")];
-        let result = confirm_bulk_operation("reopen", &items, false, false).unwrap();
+        let items = vec![
+            BulkItem::new("abc123", "Task 1"),
+            BulkItem::new("def456", "Task 2"),
+        ];
+        let result = confirm_bulk_operation(
+            "reopen",
+            &items,
+            DateStyle::Relative,
+            IdStyle::Short,
+            false,
+            false,
+        )
+        .unwrap();
         // Non-TTY stdin should auto-confirm
         assert_eq!(result, ConfirmResult::Confirmed);
     }
+
+    #[test]
+    fn test_bulk_preview_shows_priority_and_due() {
+        let due = Due {
+            date: "2099-03-07".to_string(),
+            datetime: None,
+            is_recurring: false,
+            string: None,
+            timezone: None,
+            lang: None,
+        };
+        let items = vec![
+            BulkItem {
+                priority: 4,
+                due: Some(&due),
+                ..BulkItem::new("abc123def", "Pay rent")
+            },
+            BulkItem::new("def456", "Water plants"),
+        ];
+
+        let preview = format_bulk_preview("delete", &items, DateStyle::Relative, IdStyle::Short);
+        let lines: Vec<&str> = preview.lines().collect();
+
+        assert_eq!(lines[0], "About to delete 2 tasks:");
+        assert!(lines[1].contains("Pri") && lines[1].contains("Due"));
+        assert!(lines[2].starts_with("  abc123  p1  "));
+        assert!(lines[2].contains("Mar 07"));
+        assert!(lines[2].ends_with("Pay rent"));
+        assert!(lines[3].starts_with("  def456  p4  "));
+        assert!(lines[3].ends_with("Water plants"));
+    }
+
+    #[test]
+    fn test_bulk_preview_follows_id_and_date_styles() {
+        let due = Due {
+            date: "2099-03-07".to_string(),
+            datetime: None,
+            is_recurring: false,
+            string: None,
+            timezone: None,
+            lang: None,
+        };
+        let items = vec![
+            BulkItem {
+                due: Some(&due),
+                ..BulkItem::new("abc123def", "Pay rent")
+            },
+            BulkItem::new("def456", "Water plants"),
+        ];
+
+        let preview = format_bulk_preview("delete", &items, DateStyle::Absolute, IdStyle::Full);
+        let lines: Vec<&str> = preview.lines().collect();
+
+        assert!(lines[2].starts_with("  abc123def  p4   2099-03-07  "));
+        assert!(lines[3].starts_with("  def456     p4   "));
+    }
+
+    #[test]
+    fn test_confirm_destructive_non_tty_refuses_without_force() {
+        let items = vec![BulkItem::new("abc123", "Task 1")];
+        let result = confirm_destructive_operation(
+            "delete",
+            &items,
+            DateStyle::Relative,
+            IdStyle::Short,
+            false,
+            false,
+        );
+        match result {
+            Err(CommandError::Config(msg)) => assert!(msg.contains("--force")),
+            other => panic!("Expected refusal, got: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_confirm_destructive_force_skips_confirmation() {
+        let items = vec![
+            BulkItem::new("abc123", "Task 1"),
+            BulkItem::new("def456", "Task 2"),
+        ];
+        let result = confirm_destructive_operation(
+            "delete",
+            &items,
+            DateStyle::Relative,
+            IdStyle::Short,
+            true,
+            false,
+        )
+        .unwrap();
+        assert_eq!(result, ConfirmResult::Confirmed);
+    }
+
+    #[test]
+    fn test_filter_result_cap_refuses_over_limit() {
+        let err = check_filter_result_cap("delete", 51, DEFAULT_MAX_FILTER_RESULTS, false)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("51 tasks"));
+        assert!(err.contains("--max-results 51"));
+
+        assert!(check_filter_result_cap("delete", 50, DEFAULT_MAX_FILTER_RESULTS, false).is_ok());
+    }
+
+    #[test]
+    fn test_filter_result_cap_raised_or_forced() {
+        assert!(check_filter_result_cap("reopen", 120, 120, false).is_ok());
+        assert!(check_filter_result_cap("reopen", 120, 50, true).is_ok());
+    }
+
+    fn cache_with_user_lang(lang: Option<&str>) -> Cache {
+        let mut cache = Cache::new();
+        cache.user = Some(todoist_api_rs::sync::User {
+            id: "user-1".to_string(),
+            email: None,
+            full_name: None,
+            tz_info: None,
+            inbox_project_id: None,
+            start_page: None,
+            start_day: None,
+            date_format: None,
+            time_format: None,
+            lang: lang.map(String::from),
+            is_premium: false,
+        });
+        cache
+    }
+
+    #[test]
+    fn test_due_arg_includes_lang_when_configured() {
+        let arg = due_arg("lunes", Some("es"), None);
+        assert_eq!(arg, serde_json::json!({"string": "lunes", "lang": "es"}));
+    }
+
+    #[test]
+    fn test_due_arg_omits_lang_when_unset() {
+        let arg = due_arg("tomorrow", None, None);
+        assert_eq!(arg, serde_json::json!({"string": "tomorrow"}));
+        assert!(arg.get("lang").is_none());
+        assert!(arg.get("timezone").is_none());
+    }
+
+    #[test]
+    fn test_due_arg_includes_timezone() {
+        let tz = validate_due_tz("America/Los_Angeles").unwrap();
+        let arg = due_arg("tomorrow 9am", None, Some(&tz));
+        assert_eq!(
+            arg,
+            serde_json::json!({"string": "tomorrow 9am", "timezone": "America/Los_Angeles"})
+        );
+    }
+
+    #[test]
+    fn test_validate_due_tz_rejects_unknown_zone() {
+        let err = validate_due_tz("Mars/Olympus_Mons").unwrap_err();
+        assert!(err.to_string().contains("Unknown timezone"));
+        assert!(validate_due_tz("UTC").is_ok());
+    }
+
+    #[test]
+    fn test_resolve_due_lang_prefers_explicit() {
+        let cache = cache_with_user_lang(Some("de"));
+        assert_eq!(resolve_due_lang(Some("es"), &cache).as_deref(), Some("es"));
+    }
+
+    #[test]
+    fn test_resolve_due_lang_falls_back_to_user() {
+        let cache = cache_with_user_lang(Some("de"));
+        assert_eq!(resolve_due_lang(None, &cache).as_deref(), Some("de"));
+        assert_eq!(resolve_due_lang(None, &Cache::new()), None);
+    }
+
+    /// Runs `f` with `TODOIST_API_BASE_URL` set to `value` and `TD_CONFIG`
+    /// pointing at a missing file, restoring both afterwards.
+    fn with_base_url_env<T>(value: Option<&str>, f: impl FnOnce() -> T) -> T {
+        use std::env;
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let original_url = env::var(API_BASE_URL_ENV).ok();
+        let original_config = env::var("TD_CONFIG").ok();
+        env::set_var("TD_CONFIG", dir.path().join("config.toml"));
+        match value {
+            Some(v) => env::set_var(API_BASE_URL_ENV, v),
+            None => env::remove_var(API_BASE_URL_ENV),
+        }
+
+        let result = f();
+
+        match original_url {
+            Some(v) => env::set_var(API_BASE_URL_ENV, v),
+            None => env::remove_var(API_BASE_URL_ENV),
+        }
+        match original_config {
+            Some(v) => env::set_var("TD_CONFIG", v),
+            None => env::remove_var("TD_CONFIG"),
+        }
+        result
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_api_base_url_override_unset_uses_default_host() {
+        use clap::Parser;
+
+        let ctx = with_base_url_env(None, || CommandContext::from_cli(&Cli::parse_from(["td"])));
+        assert!(ctx.base_url.is_none());
+        let client = ctx.client("test-token").unwrap();
+        assert!(client.base_url().starts_with("https://api.todoist.com"));
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_from_cli_takes_profile_from_flag() {
+        use clap::Parser;
+
+        let ctx = CommandContext::from_cli(&Cli::parse_from(["td", "--profile", " work "]));
+        assert_eq!(ctx.profile.as_deref(), Some("work"));
+        let ctx = CommandContext::from_cli(&Cli::parse_from(["td", "--profile", ""]));
+        assert!(ctx.profile.is_none());
+    }
+
+    #[test]
+    fn test_from_cli_takes_compact_json_from_flag() {
+        use clap::Parser;
+
+        let ctx = CommandContext::from_cli(&Cli::parse_from(["td", "--json", "--compact"]));
+        assert!(ctx.compact_json);
+        let ctx = CommandContext::from_cli(&Cli::parse_from(["td", "--json"]));
+        assert!(!ctx.compact_json);
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_cache_store_is_per_profile() {
+        use clap::Parser;
+
+        let store_for = |args: &[&str]| {
+            CommandContext::from_cli(&Cli::parse_from(args))
+                .cache_store()
+                .unwrap()
+                .path()
+                .clone()
+        };
+        let work = store_for(&["td", "--profile", "work"]);
+        let home = store_for(&["td", "--profile", "home"]);
+        assert_ne!(work, home);
+        assert_ne!(work, CacheStore::default_path().unwrap());
+
+        let ctx = CommandContext::from_cli(&Cli::parse_from(["td", "--profile", "../x"]));
+        assert!(ctx.cache_store().is_err());
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_api_base_url_override_ignores_empty_value() {
+        assert_eq!(
+            with_base_url_env(Some("  "), || api_base_url_override(None)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_resolve_use_colors_auto_follows_tty_and_no_color() {
+        assert!(resolve_use_colors(ColorChoice::Auto, false, true, false));
+        assert!(!resolve_use_colors(ColorChoice::Auto, false, false, false));
+        assert!(!resolve_use_colors(ColorChoice::Auto, false, true, true));
+    }
+
+    #[test]
+    fn test_resolve_use_colors_always_and_never() {
+        // always forces colors even when piped or NO_COLOR is set
+        assert!(resolve_use_colors(ColorChoice::Always, false, false, true));
+        assert!(!resolve_use_colors(ColorChoice::Never, false, true, false));
+    }
+
+    #[test]
+    fn test_resolve_use_colors_no_color_flag_means_never() {
+        assert!(!resolve_use_colors(ColorChoice::Auto, true, true, false));
+        assert!(!resolve_use_colors(ColorChoice::Always, true, true, false));
+    }
+
+    #[test]
+    fn test_resolve_output_format_uses_config_default() {
+        assert_eq!(
+            resolve_output_format(false, false, None, Some("json")),
+            OutputFormat::Json
+        );
+        assert_eq!(
+            resolve_output_format(false, false, None, Some("csv")),
+            OutputFormat::Csv
+        );
+        assert_eq!(
+            resolve_output_format(false, false, None, None),
+            OutputFormat::Table
+        );
+        assert_eq!(
+            resolve_output_format(false, false, None, Some("yaml")),
+            OutputFormat::Table
+        );
+    }
+
+    #[test]
+    fn test_resolve_output_format_flags_override_config() {
+        assert_eq!(
+            resolve_output_format(false, false, Some(OutputFormat::Table), Some("json")),
+            OutputFormat::Table
+        );
+        assert_eq!(
+            resolve_output_format(true, false, None, Some("csv")),
+            OutputFormat::Json
+        );
+
+        use clap::Parser;
+        let cli = Cli::parse_from(["td", "--format", "csv", "list"]);
+        assert_eq!(cli.format, Some(OutputFormat::Csv));
+        assert!(Cli::try_parse_from(["td", "--json", "--format", "csv"]).is_err());
+    }
+
+    #[test]
+    fn test_table_flag_overrides_json_config_default() {
+        use clap::Parser;
+        let cli = Cli::parse_from(["td", "--table", "list"]);
+        assert!(cli.table);
+        assert_eq!(
+            resolve_output_format(cli.json, cli.table, cli.format, Some("json")),
+            OutputFormat::Table
+        );
+
+        assert!(Cli::try_parse_from(["td", "--table", "--json", "list"]).is_err());
+        assert!(Cli::try_parse_from(["td", "--table", "--format", "csv", "list"]).is_err());
+    }
+
+    #[test]
+    fn test_format_sync_event_modes() {
+        let event = SyncEvent::FullSyncFallback;
+
+        assert_eq!(
+            format_sync_event(&event, OutputFormat::Table, false).as_deref(),
+            Some("Warning: Sync token invalid, performing full sync to recover.")
+        );
+
+        let json: serde_json::Value =
+            serde_json::from_str(&format_sync_event(&event, OutputFormat::Json, false).unwrap())
+                .unwrap();
+        assert_eq!(json["warning"], "sync_token_invalid");
+        assert!(json["message"].as_str().unwrap().contains("full sync"));
+
+        assert_eq!(format_sync_event(&event, OutputFormat::Table, true), None);
+        assert_eq!(format_sync_event(&event, OutputFormat::Json, true), None);
+    }
+
+    #[test]
+    fn test_read_description_from_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("notes.md");
+        let body = format!("# Meeting notes\n\n{}\n", "- point\n".repeat(10_000));
+        std::fs::write(&path, &body).unwrap();
+
+        let description = read_description(None, Some(&path)).unwrap();
+        assert_eq!(description.as_deref(), body.strip_suffix('\n'));
+    }
+
+    #[test]
+    fn test_read_description_passes_inline_value_through() {
+        assert_eq!(
+            read_description(Some("inline"), None).unwrap().as_deref(),
+            Some("inline")
+        );
+        assert_eq!(read_description(None, None).unwrap(), None);
+    }
+
+    #[test]
+    fn test_read_description_missing_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("missing.md");
+
+        let err = read_description(None, Some(&path)).unwrap_err();
+        assert!(err.to_string().contains("missing.md"));
+    }
+
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn test_api_base_url_env_routes_requests() {
+        use clap::Parser;
+        use todoist_api_rs::sync::SyncRequest;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/sync"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "sync_token": "token",
+                "full_sync": true
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let base_url = format!("{}/", mock_server.uri());
+        let ctx = with_base_url_env(Some(&base_url), || {
+            CommandContext::from_cli(&Cli::parse_from(["td"]))
+        });
+        assert_eq!(ctx.base_url.as_deref(), Some(mock_server.uri().as_str()));
+
+        let client = ctx.client("test-token").unwrap();
+        let response = client.sync(SyncRequest::full_sync()).await.unwrap();
+        assert_eq!(response.sync_token, "token");
+    }
 }
@@ -0,0 +1,101 @@
+//! Open command implementation.
+//!
+//! Opens a task in the Todoist web app using the platform's default browser.
+//! Uses resolve_item_arg() to accept IDs, ID prefixes, or content substrings
+//! with auto-sync fallback.
+
+use std::process::Command;
+
+use super::{CommandContext, CommandError, Result};
+use crate::output::helpers::task_url;
+
+/// Options for the open command.
+#[derive(Debug)]
+pub struct OpenOptions {
+    /// Task reference (full ID, ID prefix, or content substring).
+    pub task_id: String,
+    /// Print the URL instead of launching a browser.
+    pub print: bool,
+}
+
+/// Executes the open command.
+///
+/// # Arguments
+///
+/// * `ctx` - Command context with output settings
+/// * `opts` - Open command options
+/// * `token` - API token
+///
+/// # Errors
+///
+/// Returns an error if the task is not found or the browser cannot be launched.
+pub async fn execute(ctx: &CommandContext, opts: &OpenOptions, token: &str) -> Result<()> {
+    let mut manager = ctx.sync_manager(token)?;
+
+    let item = manager
+        .resolve_item_arg(&opts.task_id, None)
+        .await
+        .map_err(|e| CommandError::Config(e.to_string()))?;
+    let task_id = item.id.clone();
+    let url = task_url(&task_id);
+
+    if !opts.print {
+        let status = launcher_command(&url).status().map_err(|e| {
+            CommandError::Config(format!("Failed to launch browser for {url}: {e}"))
+        })?;
+        if !status.success() {
+            return Err(CommandError::Config(format!(
+                "Browser launcher exited with {status} for {url}"
+            )));
+        }
+    }
+
+    if ctx.output_format.is_json() {
+        let output = serde_json::json!({ "id": task_id, "url": url });
+        println!("{}", crate::output::to_json(&output, ctx.compact_json)?);
+    } else if opts.print {
+        println!("{url}");
+    } else if !ctx.quiet {
+        println!("Opened {url}");
+    }
+
+    Ok(())
+}
+
+/// Builds the command that opens `url` in the default browser.
+fn launcher_command(url: &str) -> Command {
+    if cfg!(target_os = "macos") {
+        let mut command = Command::new("open");
+        command.arg(url);
+        command
+    } else if cfg!(target_os = "windows") {
+        // `start` treats the first quoted argument as a window title
+        let mut command = Command::new("cmd");
+        command.args(["/C", "start", "", url]);
+        command
+    } else {
+        let mut command = Command::new("xdg-open");
+        command.arg(url);
+        command
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_launcher_command_passes_task_url() {
+        let url = task_url("6Jf8VQXxpwv56VQ7");
+        let command = launcher_command(&url);
+
+        let args: Vec<_> = command.get_args().collect();
+        assert_eq!(
+            args.last().and_then(|arg| arg.to_str()),
+            Some("https://todoist.com/app/task/6Jf8VQXxpwv56VQ7")
+        );
+        if cfg!(target_os = "linux") {
+            assert_eq!(command.get_program(), "xdg-open");
+        }
+    }
+}
@@ -3,22 +3,32 @@
 //! Lists and manages projects via the Sync API.
 //! Uses SyncManager::execute_commands() to automatically update the cache.
 
-use todoist_api_rs::client::TodoistClient;
-use todoist_api_rs::sync::{Project, SyncCommand, SyncCommandType};
-use todoist_cache_rs::{Cache, CacheStore, SyncManager};
+use std::path::{Path, PathBuf};
 
-use super::{CommandContext, CommandError, Result};
-use crate::output::{format_created_project, format_projects_json, format_projects_table};
+use serde::Deserialize;
+use todoist_api_rs::sync::{Item, Project, SyncCommand, SyncCommandType};
+use todoist_cache_rs::filter::{Filter, FilterContext, FilterEvaluator, FilterParser};
+use todoist_cache_rs::Cache;
+
+use super::{due_arg, ensure_fresh, CommandContext, CommandError, Result};
+use crate::output::{
+    format_created_project, format_projects_csv, format_projects_json, format_projects_table,
+    OutputFormat,
+};
 
 /// Options for the projects list command.
 #[derive(Debug, Default)]
 pub struct ProjectsListOptions {
     /// Show as tree hierarchy.
     pub tree: bool,
+    /// Maximum tree depth to render (1 = root projects only).
+    pub depth: Option<usize>,
     /// Include archived projects.
     pub archived: bool,
     /// Limit results.
     pub limit: Option<u32>,
+    /// Count completed tasks in addition to open ones.
+    pub include_completed: bool,
 }
 
 /// Executes the projects list command.
@@ -34,17 +44,9 @@ pub struct ProjectsListOptions {
 /// Returns an error if syncing fails.
 pub async fn execute(ctx: &CommandContext, opts: &ProjectsListOptions, token: &str) -> Result<()> {
     // Initialize sync manager
-    let client = todoist_api_rs::client::TodoistClient::new(token)?;
-    let store = CacheStore::new()?;
-    let mut manager = SyncManager::new(client, store)?;
+    let mut manager = ctx.sync_manager(token)?;
 
-    // Only sync if explicitly requested with --sync flag
-    if ctx.sync_first {
-        if ctx.verbose {
-            eprintln!("Syncing with Todoist...");
-        }
-        manager.sync().await?;
-    }
+    ensure_fresh(ctx, &mut manager).await?;
 
     let cache = manager.cache();
 
@@ -55,12 +57,30 @@ pub async fn execute(ctx: &CommandContext, opts: &ProjectsListOptions, token: &s
     let projects = apply_limit(projects, opts);
 
     // Output
-    if ctx.json_output {
-        let output = format_projects_json(&projects)?;
-        println!("{output}");
-    } else if !ctx.quiet {
-        let output = format_projects_table(&projects, cache, ctx.use_colors, opts.tree);
-        print!("{output}");
+    match ctx.output_format {
+        OutputFormat::Json => {
+            let output =
+                format_projects_json(&projects, cache, opts.include_completed, ctx.compact_json)?;
+            println!("{output}");
+        }
+        OutputFormat::Csv => print!(
+            "{}",
+            format_projects_csv(&projects, cache, opts.include_completed)
+        ),
+        OutputFormat::Table if !ctx.quiet => {
+            let output = format_projects_table(
+                &projects,
+                cache,
+                ctx.use_colors,
+                opts.tree,
+                opts.depth,
+                opts.include_completed,
+                ctx.id_style,
+            );
+            print!("{output}");
+            ctx.print_sync_footer(cache);
+        }
+        OutputFormat::Table => {}
     }
 
     Ok(())
@@ -114,6 +134,176 @@ pub struct ProjectsAddOptions {
     pub parent: Option<String>,
     /// Mark as favorite.
     pub favorite: bool,
+    /// JSON template with sections and tasks to create in the new project.
+    pub from_template: Option<PathBuf>,
+}
+
+/// A project template for `projects add --from-template`.
+///
+/// ```json
+/// {
+///   "sections": [
+///     { "name": "Backlog", "tasks": [{ "content": "Collect ideas", "priority": 2 }] },
+///     { "name": "Done" }
+///   ],
+///   "tasks": [{ "content": "Kickoff", "due": "next monday", "labels": ["meeting"] }]
+/// }
+/// ```
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProjectTemplate {
+    /// Sections to create, in order.
+    #[serde(default)]
+    pub sections: Vec<TemplateSection>,
+    /// Tasks created outside any section.
+    #[serde(default)]
+    pub tasks: Vec<TemplateTask>,
+}
+
+/// A section in a [`ProjectTemplate`].
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TemplateSection {
+    /// Section name.
+    pub name: String,
+    /// Tasks created in this section.
+    #[serde(default)]
+    pub tasks: Vec<TemplateTask>,
+}
+
+/// A task in a [`ProjectTemplate`].
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TemplateTask {
+    /// Task content/title.
+    pub content: String,
+    /// Task description.
+    pub description: Option<String>,
+    /// Priority level (1=highest, 4=lowest).
+    pub priority: Option<u8>,
+    /// Due date (natural language or ISO).
+    pub due: Option<String>,
+    /// Labels to attach.
+    #[serde(default)]
+    pub labels: Vec<String>,
+}
+
+impl ProjectTemplate {
+    /// Reads and validates a template file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be read, isn't a valid template,
+    /// or fails [`validate`](Self::validate).
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents = std::fs::read_to_string(path).map_err(|e| {
+            CommandError::Config(format!(
+                "Failed to read template '{}': {}",
+                path.display(),
+                e
+            ))
+        })?;
+        let template: Self = serde_json::from_str(&contents).map_err(|e| {
+            CommandError::Config(format!("Invalid template '{}': {}", path.display(), e))
+        })?;
+        template.validate()?;
+        Ok(template)
+    }
+
+    /// Checks that section names are present and unique and that every task
+    /// has content and a priority between 1 and 4.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CommandError::Config` describing the first problem found.
+    pub fn validate(&self) -> Result<()> {
+        let invalid = |msg: String| Err(CommandError::Config(format!("Invalid template: {msg}")));
+
+        let mut names: Vec<String> = Vec::new();
+        for section in &self.sections {
+            let name = section.name.trim().to_lowercase();
+            if name.is_empty() {
+                return invalid("section name is empty".to_string());
+            }
+            if names.contains(&name) {
+                return invalid(format!("duplicate section '{}'", section.name));
+            }
+            names.push(name);
+        }
+
+        let tasks = self
+            .tasks
+            .iter()
+            .chain(self.sections.iter().flat_map(|s| &s.tasks));
+        for task in tasks {
+            if task.content.trim().is_empty() {
+                return invalid("task content is empty".to_string());
+            }
+            if let Some(priority) = task.priority.filter(|p| !(1..=4).contains(p)) {
+                return invalid(format!(
+                    "task '{}' has priority {priority}; use 1 (highest) to 4",
+                    task.content
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Builds the `section_add` and `item_add` commands for a template.
+///
+/// Everything references the new project by `project_temp_id`, and tasks in
+/// a section reference that section's temp ID, so the whole structure can
+/// go in the same batch as the `project_add`.
+pub fn build_template_commands(
+    project_temp_id: &str,
+    template: &ProjectTemplate,
+) -> Vec<SyncCommand> {
+    let mut commands = Vec::new();
+    let add_task = |task: &TemplateTask, section_temp_id: Option<&str>| {
+        let mut args = serde_json::json!({
+            "content": task.content,
+            "project_id": project_temp_id,
+        });
+        if let Some(section_temp_id) = section_temp_id {
+            args["section_id"] = serde_json::json!(section_temp_id);
+        }
+        if let Some(ref description) = task.description {
+            args["description"] = serde_json::json!(description);
+        }
+        if let Some(priority) = task.priority {
+            // Convert user priority (1=highest) to API priority (4=highest)
+            args["priority"] = serde_json::json!(5 - priority as i32);
+        }
+        if let Some(ref due) = task.due {
+            args["due"] = due_arg(due, None, None);
+        }
+        if !task.labels.is_empty() {
+            args["labels"] = serde_json::json!(task.labels);
+        }
+        let temp_id = uuid::Uuid::new_v4().to_string();
+        SyncCommand::with_temp_id(SyncCommandType::ItemAdd, &temp_id, args)
+    };
+
+    for task in &template.tasks {
+        commands.push(add_task(task, None));
+    }
+    for (order, section) in (1..).zip(&template.sections) {
+        let section_temp_id = uuid::Uuid::new_v4().to_string();
+        commands.push(SyncCommand::with_temp_id(
+            SyncCommandType::SectionAdd,
+            &section_temp_id,
+            serde_json::json!({
+                "name": section.name,
+                "project_id": project_temp_id,
+                "section_order": order,
+            }),
+        ));
+        for task in &section.tasks {
+            commands.push(add_task(task, Some(&section_temp_id)));
+        }
+    }
+    commands
 }
 
 /// Result of a successful project add operation.
@@ -131,6 +321,10 @@ pub struct ProjectAddResult {
     pub parent_name: Option<String>,
     /// Whether the project is a favorite.
     pub is_favorite: bool,
+    /// Real IDs of sections created from a template.
+    pub section_ids: Vec<String>,
+    /// Real IDs of tasks created from a template.
+    pub task_ids: Vec<String>,
 }
 
 /// Executes the projects add command.
@@ -149,10 +343,15 @@ pub async fn execute_add(
     opts: &ProjectsAddOptions,
     token: &str,
 ) -> Result<()> {
+    // Validate the template before touching the cache or network
+    let template = opts
+        .from_template
+        .as_deref()
+        .map(ProjectTemplate::load)
+        .transpose()?;
+
     // Initialize sync manager (loads cache from disk)
-    let client = TodoistClient::new(token)?;
-    let store = CacheStore::new()?;
-    let mut manager = SyncManager::new(client, store)?;
+    let mut manager = ctx.sync_manager(token)?;
 
     // Resolve parent project name to ID if provided (extract owned data before mutation)
     let (parent_id, parent_name) = if let Some(ref parent_ref) = opts.parent {
@@ -200,12 +399,25 @@ pub async fn execute_add(
         args["is_favorite"] = serde_json::json!(true);
     }
 
-    // Create the command
-    let command = SyncCommand::with_temp_id(SyncCommandType::ProjectAdd, &temp_id, args);
+    // Create the command, followed by any template sections and tasks
+    let mut commands = vec![SyncCommand::with_temp_id(
+        SyncCommandType::ProjectAdd,
+        &temp_id,
+        args,
+    )];
+    let template_commands = template
+        .as_ref()
+        .map(|template| build_template_commands(&temp_id, template))
+        .unwrap_or_default();
+    let template_temp_ids: Vec<(SyncCommandType, String)> = template_commands
+        .iter()
+        .filter_map(|c| Some((c.command_type, c.temp_id.clone()?)))
+        .collect();
+    commands.extend(template_commands);
 
-    // Execute the command via SyncManager
-    // This sends the command, applies the response to cache, and saves to disk
-    let response = manager.execute_commands(vec![command]).await?;
+    // Execute the commands via SyncManager in one batch
+    // This sends the commands, applies the response to cache, and saves to disk
+    let response = manager.execute_commands(commands).await?;
 
     // Check for errors
     if response.has_errors() {
@@ -227,6 +439,13 @@ pub async fn execute_add(
             CommandError::Config("Project created but no ID returned in response".to_string())
         })?
         .clone();
+    let created_ids = |kind: SyncCommandType| -> Vec<String> {
+        template_temp_ids
+            .iter()
+            .filter(|(command_type, _)| *command_type == kind)
+            .filter_map(|(_, temp_id)| response.real_id(temp_id).cloned())
+            .collect()
+    };
 
     let result = ProjectAddResult {
         id: real_id,
@@ -235,11 +454,13 @@ pub async fn execute_add(
         parent_id,
         parent_name,
         is_favorite: opts.favorite,
+        section_ids: created_ids(SyncCommandType::SectionAdd),
+        task_ids: created_ids(SyncCommandType::ItemAdd),
     };
 
     // Output
-    if ctx.json_output {
-        let output = format_created_project(&result)?;
+    if ctx.output_format.is_json() {
+        let output = format_created_project(&result, ctx.compact_json)?;
         println!("{output}");
     } else if !ctx.quiet {
         if ctx.verbose {
@@ -253,6 +474,21 @@ pub async fn execute_add(
             if result.is_favorite {
                 println!("  Favorite: yes");
             }
+            if template.is_some() {
+                println!(
+                    "  From template: {} sections, {} tasks",
+                    result.section_ids.len(),
+                    result.task_ids.len()
+                );
+            }
+        } else if template.is_some() {
+            println!(
+                "Created: {} ({}) with {} sections and {} tasks",
+                result.name,
+                &result.id[..6.min(result.id.len())],
+                result.section_ids.len(),
+                result.task_ids.len()
+            );
         } else {
             println!(
                 "Created: {} ({})",
@@ -307,6 +543,12 @@ pub struct ProjectsShowOptions {
     pub sections: bool,
     /// List tasks in this project.
     pub tasks: bool,
+    /// List only tasks matching this filter expression (implies `tasks`).
+    pub filter: Option<String>,
+    /// Count completed tasks in addition to open ones.
+    pub include_completed: bool,
+    /// List recently completed tasks.
+    pub completed: bool,
 }
 
 /// Result data for the projects show command.
@@ -319,10 +561,14 @@ pub struct ProjectsShowResult<'a> {
     pub task_count: usize,
     /// Number of sections in this project.
     pub section_count: usize,
+    /// Completed task count reported by the server (if known).
+    pub completed_count: Option<u32>,
     /// Sections in this project (if requested).
     pub sections: Vec<&'a todoist_api_rs::sync::Section>,
     /// Tasks in this project (if requested).
     pub tasks: Vec<&'a todoist_api_rs::sync::Item>,
+    /// Recently completed tasks, most recent first (if requested).
+    pub completed_tasks: Vec<&'a todoist_api_rs::sync::Item>,
 }
 
 /// Executes the projects show command.
@@ -341,18 +587,17 @@ pub async fn execute_show(
     opts: &ProjectsShowOptions,
     token: &str,
 ) -> Result<()> {
+    // Parse the filter first so a malformed one fails before any sync
+    let filter = opts
+        .filter
+        .as_deref()
+        .map(FilterParser::parse)
+        .transpose()?;
+
     // Initialize sync manager
-    let client = TodoistClient::new(token)?;
-    let store = CacheStore::new()?;
-    let mut manager = SyncManager::new(client, store)?;
+    let mut manager = ctx.sync_manager(token)?;
 
-    // Only sync if explicitly requested with --sync flag
-    if ctx.sync_first {
-        if ctx.verbose {
-            eprintln!("Syncing with Todoist...");
-        }
-        manager.sync().await?;
-    }
+    ensure_fresh(ctx, &mut manager).await?;
 
     let cache = manager.cache();
 
@@ -368,31 +613,24 @@ pub async fn execute_show(
             .map(|p| p.name.clone())
     });
 
-    // Count active tasks in this project
-    let task_count = cache
-        .items
-        .iter()
-        .filter(|i| i.project_id == project.id && !i.is_deleted && !i.checked)
-        .count();
+    let task_count = count_project_tasks(cache, &project.id, opts.include_completed);
 
     // Get sections for this project
-    let all_sections: Vec<&todoist_api_rs::sync::Section> = cache
-        .sections
-        .iter()
-        .filter(|s| s.project_id == project.id && !s.is_deleted)
-        .collect();
+    let all_sections = cache.sections_in_project(&project.id, true);
     let section_count = all_sections.len();
 
     // Only include sections if requested
     let sections = if opts.sections { all_sections } else { vec![] };
 
     // Get tasks for this project if requested
-    let tasks: Vec<&todoist_api_rs::sync::Item> = if opts.tasks {
-        cache
-            .items
-            .iter()
-            .filter(|i| i.project_id == project.id && !i.is_deleted && !i.checked)
-            .collect()
+    let tasks = if opts.tasks || filter.is_some() {
+        project_tasks(cache, &project.id, filter.as_ref())
+    } else {
+        vec![]
+    };
+
+    let completed_tasks = if opts.completed {
+        recent_completed_tasks(cache, &project.id, RECENT_COMPLETIONS_LIMIT)
     } else {
         vec![]
     };
@@ -402,22 +640,75 @@ pub async fn execute_show(
         parent_name,
         task_count,
         section_count,
+        completed_count: cache.project_completed_count(&project.id),
         sections,
         tasks,
+        completed_tasks,
     };
 
     // Output
-    if ctx.json_output {
-        let output = crate::output::format_project_details_json(&result)?;
+    if ctx.output_format.is_json() {
+        let output = crate::output::format_project_details_json(&result, ctx.compact_json)?;
         println!("{output}");
     } else if !ctx.quiet {
-        let output = crate::output::format_project_details_table(&result, ctx.use_colors);
+        let output = crate::output::format_project_details_table(
+            &result,
+            ctx.use_colors,
+            ctx.date_style,
+            ctx.id_style,
+        );
         print!("{output}");
     }
 
     Ok(())
 }
 
+/// Maximum number of completed tasks listed by `projects show --completed`.
+const RECENT_COMPLETIONS_LIMIT: usize = 10;
+
+/// Returns up to `limit` completed tasks in a project, most recently
+/// completed first.
+///
+/// Only completed tasks still held in the cache are considered. Tasks without
+/// a completion time sort last.
+fn recent_completed_tasks<'a>(
+    cache: &'a Cache,
+    project_id: &str,
+    limit: usize,
+) -> Vec<&'a todoist_api_rs::sync::Item> {
+    let mut completed = super::completed::completed_items(cache, Some(project_id));
+    completed.truncate(limit);
+    completed
+}
+
+/// Returns a project's open tasks, narrowed to those matching `filter`.
+///
+/// The filter is evaluated against the whole cache, so project and section
+/// names in it resolve as they do for `td list`.
+fn project_tasks<'a>(cache: &'a Cache, project_id: &str, filter: Option<&Filter>) -> Vec<&'a Item> {
+    let mut tasks: Vec<&Item> = cache
+        .items_in_project(project_id, false)
+        .into_iter()
+        .filter(|i| !i.checked)
+        .collect();
+    if let Some(filter) = filter {
+        let context = FilterContext::from_cache(cache);
+        let evaluator = FilterEvaluator::new(filter, &context);
+        tasks.retain(|i| evaluator.matches(i));
+    }
+    tasks
+}
+
+/// Counts a project's non-deleted tasks, including completed ones only when
+/// `include_completed` is set.
+fn count_project_tasks(cache: &Cache, project_id: &str, include_completed: bool) -> usize {
+    cache
+        .items_in_project(project_id, false)
+        .into_iter()
+        .filter(|i| include_completed || !i.checked)
+        .count()
+}
+
 /// Finds a project by full ID or unique prefix.
 fn find_project_by_id_or_prefix<'a>(cache: &'a Cache, id: &str) -> Result<&'a Project> {
     // First try exact match
@@ -469,6 +760,10 @@ pub struct ProjectsEditOptions {
     pub favorite: Option<bool>,
     /// View style (list, board).
     pub view_style: Option<String>,
+    /// Move under a parent project (name or ID).
+    pub parent: Option<String>,
+    /// Promote to a top-level project.
+    pub no_parent: bool,
 }
 
 /// Result of a successful project edit operation.
@@ -499,26 +794,28 @@ pub async fn execute_edit(
     token: &str,
 ) -> Result<()> {
     // Check if any options were provided
-    if opts.name.is_none()
-        && opts.color.is_none()
-        && opts.favorite.is_none()
-        && opts.view_style.is_none()
-    {
+    let has_updates = opts.name.is_some()
+        || opts.color.is_some()
+        || opts.favorite.is_some()
+        || opts.view_style.is_some();
+    if !has_updates && opts.parent.is_none() && !opts.no_parent {
         return Err(CommandError::Config(
-            "No changes specified. Use --name, --color, --favorite, or --view-style.".to_string(),
+            "No changes specified. Use --name, --color, --favorite, --view-style, --parent, or --no-parent.".to_string(),
         ));
     }
 
     // Initialize sync manager (loads cache from disk)
-    let client = TodoistClient::new(token)?;
-    let store = CacheStore::new()?;
-    let mut manager = SyncManager::new(client, store)?;
+    let mut manager = ctx.sync_manager(token)?;
 
     // Find the project by ID or prefix and extract owned data before mutation
-    let (project_id, project_name) = {
+    let (project_id, project_name, current_parent_id) = {
         let cache = manager.cache();
         let project = find_project_by_id_or_prefix(cache, &opts.project_id)?;
-        (project.id.clone(), project.name.clone())
+        (
+            project.id.clone(),
+            project.name.clone(),
+            project.parent_id.clone(),
+        )
     };
 
     // Validate color if provided
@@ -566,12 +863,59 @@ pub async fn execute_edit(
         updated_fields.push("view_style".to_string());
     }
 
-    // Create the command
-    let command = SyncCommand::new(SyncCommandType::ProjectUpdate, args);
+    let mut commands = Vec::new();
+    if has_updates {
+        commands.push(SyncCommand::new(SyncCommandType::ProjectUpdate, args));
+    }
 
-    // Execute the command via SyncManager
-    // This sends the command, applies the response to cache, and saves to disk
-    let response = manager.execute_commands(vec![command]).await?;
+    // Build project_move command if re-parenting or promoting to top-level
+    if opts.parent.is_some() || opts.no_parent {
+        let new_parent_id = match opts.parent {
+            Some(ref parent) => Some(
+                manager
+                    .resolve_project(parent)
+                    .await
+                    .map_err(|e| CommandError::Config(e.to_string()))?
+                    .id
+                    .clone(),
+            ),
+            None => None,
+        };
+
+        if let Some(move_args) = build_project_move_args(
+            manager.cache(),
+            &project_id,
+            current_parent_id.as_deref(),
+            new_parent_id.as_deref(),
+        )? {
+            updated_fields.push(if new_parent_id.is_some() {
+                "parent".to_string()
+            } else {
+                "parent (removed)".to_string()
+            });
+            commands.push(SyncCommand::new(SyncCommandType::ProjectMove, move_args));
+        }
+    }
+
+    if commands.is_empty() {
+        if !ctx.quiet {
+            if ctx.output_format.is_json() {
+                let output = serde_json::json!({
+                    "status": "no_changes",
+                    "id": project_id,
+                    "message": "No changes specified"
+                });
+                println!("{}", crate::output::to_json(&output, ctx.compact_json)?);
+            } else {
+                println!("No changes specified for project {}", project_name);
+            }
+        }
+        return Ok(());
+    }
+
+    // Execute the commands via SyncManager
+    // This sends the commands, applies the response to cache, and saves to disk
+    let response = manager.execute_commands(commands).await?;
 
     // Check for errors
     if response.has_errors() {
@@ -593,8 +937,8 @@ pub async fn execute_edit(
     };
 
     // Output
-    if ctx.json_output {
-        let output = crate::output::format_edited_project(&result)?;
+    if ctx.output_format.is_json() {
+        let output = crate::output::format_edited_project(&result, ctx.compact_json)?;
         println!("{output}");
     } else if !ctx.quiet {
         if ctx.verbose {
@@ -609,10 +953,115 @@ pub async fn execute_edit(
     Ok(())
 }
 
+// ============================================================================
+// Projects Favorite Command
+// ============================================================================
+
+/// Options for the projects favorite command.
+#[derive(Debug)]
+pub struct ProjectsFavoriteOptions {
+    /// Project ID (full ID or prefix).
+    pub project_id: String,
+    /// Favorite state to set. `None` flips the cached state.
+    pub favorite: Option<bool>,
+}
+
+/// Executes the projects favorite command.
+///
+/// Resolves the target state from the cache, then applies it like
+/// `projects edit --favorite`.
+///
+/// # Errors
+///
+/// Returns an error if project lookup fails or the API returns an error.
+pub async fn execute_favorite(
+    ctx: &CommandContext,
+    opts: &ProjectsFavoriteOptions,
+    token: &str,
+) -> Result<()> {
+    let favorite = {
+        let manager = ctx.sync_manager(token)?;
+        favorite_target(manager.cache(), opts)?
+    };
+
+    let edit_opts = ProjectsEditOptions {
+        project_id: opts.project_id.clone(),
+        favorite: Some(favorite),
+        name: None,
+        color: None,
+        view_style: None,
+        parent: None,
+        no_parent: false,
+    };
+    execute_edit(ctx, &edit_opts, token).await
+}
+
+/// Returns the favorite state to set: the requested one, or the opposite of
+/// the project's cached state.
+fn favorite_target(cache: &Cache, opts: &ProjectsFavoriteOptions) -> Result<bool> {
+    let project = find_project_by_id_or_prefix(cache, &opts.project_id)?;
+    Ok(opts.favorite.unwrap_or(!project.is_favorite))
+}
+
 // ============================================================================
 // Projects Archive Command
 // ============================================================================
 
+/// Builds `project_move` arguments for changing a project's parent.
+///
+/// With `new_parent_id` set, the project is moved under that parent; with
+/// `None` it is promoted to the top level. Returns `Ok(None)` when the
+/// project is already in the requested position.
+///
+/// # Errors
+///
+/// Returns an error if the move would make the project its own ancestor.
+fn build_project_move_args(
+    cache: &Cache,
+    project_id: &str,
+    current_parent_id: Option<&str>,
+    new_parent_id: Option<&str>,
+) -> Result<Option<serde_json::Value>> {
+    if current_parent_id == new_parent_id {
+        return Ok(None);
+    }
+    if let Some(parent_id) = new_parent_id {
+        if is_project_ancestor_or_self(cache, project_id, parent_id) {
+            return Err(CommandError::Config(format!(
+                "Cannot move project {} under {}: a project cannot be nested under itself or its own subprojects",
+                &project_id[..6.min(project_id.len())],
+                &parent_id[..6.min(parent_id.len())]
+            )));
+        }
+    }
+    Ok(Some(serde_json::json!({
+        "id": project_id,
+        "parent_id": new_parent_id,
+    })))
+}
+
+/// Returns true if `project_id` is `candidate_id` or one of its ancestors.
+///
+/// Walks the cached parent chain upward from `candidate_id`.
+fn is_project_ancestor_or_self(cache: &Cache, project_id: &str, candidate_id: &str) -> bool {
+    let mut current = Some(candidate_id);
+    // Bound the walk by the number of projects to stay safe on corrupt (cyclic) caches
+    for _ in 0..=cache.projects.len() {
+        match current {
+            Some(id) if id == project_id => return true,
+            Some(id) => {
+                current = cache
+                    .projects
+                    .iter()
+                    .find(|p| p.id == id)
+                    .and_then(|p| p.parent_id.as_deref())
+            }
+            None => return false,
+        }
+    }
+    false
+}
+
 /// Options for the projects archive command.
 #[derive(Debug)]
 pub struct ProjectsArchiveOptions {
@@ -648,9 +1097,7 @@ pub async fn execute_archive(
     token: &str,
 ) -> Result<()> {
     // Initialize sync manager (loads cache from disk)
-    let client = TodoistClient::new(token)?;
-    let store = CacheStore::new()?;
-    let mut manager = SyncManager::new(client, store)?;
+    let mut manager = ctx.sync_manager(token)?;
 
     // Find the project by ID or prefix and extract owned data before mutation
     let (project_id, project_name, is_archived, is_inbox) = {
@@ -724,8 +1171,8 @@ pub async fn execute_archive(
     };
 
     // Output
-    if ctx.json_output {
-        let output = crate::output::format_archived_project(&result)?;
+    if ctx.output_format.is_json() {
+        let output = crate::output::format_archived_project(&result, ctx.compact_json)?;
         println!("{output}");
     } else if !ctx.quiet {
         if ctx.verbose {
@@ -776,9 +1223,7 @@ pub async fn execute_unarchive(
     token: &str,
 ) -> Result<()> {
     // Initialize sync manager (loads cache from disk)
-    let client = TodoistClient::new(token)?;
-    let store = CacheStore::new()?;
-    let mut manager = SyncManager::new(client, store)?;
+    let mut manager = ctx.sync_manager(token)?;
 
     // Find the project by ID or prefix (include archived projects) and extract owned data
     let (project_id, project_name, is_archived) = {
@@ -830,8 +1275,8 @@ pub async fn execute_unarchive(
     };
 
     // Output
-    if ctx.json_output {
-        let output = crate::output::format_unarchived_project(&result)?;
+    if ctx.output_format.is_json() {
+        let output = crate::output::format_unarchived_project(&result, ctx.compact_json)?;
         println!("{output}");
     } else if !ctx.quiet {
         if ctx.verbose {
@@ -884,9 +1329,7 @@ pub async fn execute_delete(
     token: &str,
 ) -> Result<()> {
     // Initialize sync manager (loads cache from disk)
-    let client = TodoistClient::new(token)?;
-    let store = CacheStore::new()?;
-    let mut manager = SyncManager::new(client, store)?;
+    let mut manager = ctx.sync_manager(token)?;
 
     // Find the project by ID or prefix (include archived projects since they can be deleted)
     // Extract owned data before mutation
@@ -952,8 +1395,8 @@ pub async fn execute_delete(
     };
 
     // Output
-    if ctx.json_output {
-        let output = crate::output::format_deleted_project(&result)?;
+    if ctx.output_format.is_json() {
+        let output = crate::output::format_deleted_project(&result, ctx.compact_json)?;
         println!("{output}");
     } else if !ctx.quiet {
         if ctx.verbose {
@@ -1015,6 +1458,7 @@ fn find_project_by_id_or_prefix_include_archived<'a>(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_support::item;
 
     #[test]
     fn test_projects_list_options_defaults() {
@@ -1029,8 +1473,10 @@ mod tests {
     fn test_projects_list_options_with_values() {
         let opts = ProjectsListOptions {
             tree: true,
+            depth: Some(2),
             archived: true,
             limit: Some(10),
+            include_completed: false,
         };
 
         assert!(opts.tree);
@@ -1045,6 +1491,7 @@ mod tests {
             color: Some("blue".to_string()),
             parent: Some("Parent".to_string()),
             favorite: true,
+            from_template: None,
         };
 
         assert_eq!(opts.name, "Test Project");
@@ -1070,6 +1517,9 @@ mod tests {
             project_id: "abc123".to_string(),
             sections: false,
             tasks: false,
+            filter: None,
+            include_completed: false,
+            completed: false,
         };
 
         assert_eq!(opts.project_id, "abc123");
@@ -1083,6 +1533,9 @@ mod tests {
             project_id: "project-123-abc".to_string(),
             sections: true,
             tasks: true,
+            filter: None,
+            include_completed: false,
+            completed: false,
         };
 
         assert_eq!(opts.project_id, "project-123-abc");
@@ -1090,6 +1543,100 @@ mod tests {
         assert!(opts.tasks);
     }
 
+    #[test]
+    fn test_count_project_tasks_include_completed() {
+        let mut cache = make_test_cache_with_projects();
+        let project_id = cache.projects[0].id.clone();
+        cache.items = vec![
+            item("open", "Task open").project(&project_id).build(),
+            item("done", "Task done")
+                .project(&project_id)
+                .checked()
+                .build(),
+            item("gone", "Task gone")
+                .project(&project_id)
+                .checked()
+                .deleted()
+                .build(),
+        ];
+
+        assert_eq!(count_project_tasks(&cache, &project_id, false), 1);
+        assert_eq!(count_project_tasks(&cache, &project_id, true), 2);
+    }
+
+    #[test]
+    fn test_project_tasks_filter_narrows_to_subset() {
+        let mut cache = make_test_cache_with_projects();
+        let project_id = cache.projects[0].id.clone();
+        let today = chrono::Local::now().date_naive();
+        let make = |id: &str, project_id: &str, due: Option<chrono::NaiveDate>, priority: i32| {
+            let task = item(id, &format!("Task {id}"))
+                .project(project_id)
+                .priority(priority);
+            match due {
+                Some(date) => task.due(&date.format("%Y-%m-%d").to_string()),
+                None => task,
+            }
+            .build()
+        };
+        let yesterday = today - chrono::Duration::days(1);
+        cache.items = vec![
+            make("late", &project_id, Some(yesterday), 1),
+            make("late-urgent", &project_id, Some(yesterday), 4),
+            make("current", &project_id, Some(today), 4),
+            make("undated", &project_id, None, 1),
+            make("elsewhere", "other-project", Some(yesterday), 4),
+        ];
+        cache.rebuild_indexes();
+
+        let ids = |filter: Option<&str>| -> Vec<String> {
+            let filter = filter.map(|f| FilterParser::parse(f).unwrap());
+            project_tasks(&cache, &project_id, filter.as_ref())
+                .into_iter()
+                .map(|i| i.id.clone())
+                .collect()
+        };
+
+        assert_eq!(ids(None), vec!["late", "late-urgent", "current", "undated"]);
+        assert_eq!(ids(Some("overdue")), vec!["late", "late-urgent"]);
+        assert_eq!(ids(Some("overdue & p1")), vec!["late-urgent"]);
+        assert_eq!(ids(Some("no date")), vec!["undated"]);
+    }
+
+    #[test]
+    fn test_recent_completed_tasks_from_cache() {
+        let mut cache = make_test_cache_with_projects();
+        let project_id = cache.projects[0].id.clone();
+        let done = |id: &str, completed_at: &str| {
+            item(id, &format!("Task {id}"))
+                .project(&project_id)
+                .completed_at(completed_at)
+                .build()
+        };
+        cache.items = vec![
+            item("open", "Task open").project(&project_id).build(),
+            done("older", "2025-01-10T10:00:00Z"),
+            item("untimed", "Task untimed")
+                .project(&project_id)
+                .checked()
+                .build(),
+            done("newer", "2025-01-15T10:00:00Z"),
+            item("elsewhere", "Task elsewhere")
+                .project("other-project")
+                .completed_at("2025-01-20T10:00:00Z")
+                .build(),
+        ];
+
+        let ids = |limit| -> Vec<String> {
+            recent_completed_tasks(&cache, &project_id, limit)
+                .iter()
+                .map(|i| i.id.clone())
+                .collect()
+        };
+        assert_eq!(ids(10), vec!["newer", "older", "untimed"]);
+        assert_eq!(ids(1), vec!["newer"]);
+    }
+
     #[test]
     fn test_find_project_by_id_or_prefix_exact_match() {
         let cache = make_test_cache_with_projects();
@@ -1135,6 +1682,40 @@ mod tests {
     }
 
     // Helper function to create a test cache with projects
+    #[test]
+    fn test_favorite_target_toggles_cached_state() {
+        let mut cache = make_test_cache_with_projects();
+        let opts = ProjectsFavoriteOptions {
+            project_id: "proj-123".to_string(),
+            favorite: None,
+        };
+        assert!(favorite_target(&cache, &opts).unwrap());
+
+        cache.projects[0].is_favorite = true;
+        assert!(!favorite_target(&cache, &opts).unwrap());
+    }
+
+    #[test]
+    fn test_favorite_target_off_forces_unfavorite() {
+        let cache = make_test_cache_with_projects();
+        let opts = ProjectsFavoriteOptions {
+            project_id: "proj-123-abc".to_string(),
+            favorite: Some(false),
+        };
+        // Already unfavorited: --off keeps it that way instead of flipping
+        assert!(!favorite_target(&cache, &opts).unwrap());
+    }
+
+    #[test]
+    fn test_favorite_target_unknown_project() {
+        let cache = make_test_cache_with_projects();
+        let opts = ProjectsFavoriteOptions {
+            project_id: "missing".to_string(),
+            favorite: None,
+        };
+        assert!(favorite_target(&cache, &opts).is_err());
+    }
+
     fn make_test_cache_with_projects() -> Cache {
         Cache::with_data(
             "test".to_string(),
@@ -1173,6 +1754,74 @@ mod tests {
         )
     }
 
+    fn make_project_tree_cache() -> Cache {
+        let mut child = make_test_project("child", "Child");
+        child.parent_id = Some("root".to_string());
+        let mut grandchild = make_test_project("grandchild", "Grandchild");
+        grandchild.parent_id = Some("child".to_string());
+        Cache::with_data(
+            "test".to_string(),
+            None,
+            None,
+            vec![],
+            vec![
+                make_test_project("root", "Root"),
+                child,
+                grandchild,
+                make_test_project("other", "Other"),
+            ],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            None,
+        )
+    }
+
+    #[test]
+    fn test_project_move_reparents_project() {
+        let cache = make_project_tree_cache();
+        let args = build_project_move_args(&cache, "grandchild", Some("child"), Some("other"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(args["id"], "grandchild");
+        assert_eq!(args["parent_id"], "other");
+
+        // Same parent: nothing to do
+        let args = build_project_move_args(&cache, "child", Some("root"), Some("root")).unwrap();
+        assert!(args.is_none());
+    }
+
+    #[test]
+    fn test_project_move_promotes_to_top_level() {
+        let cache = make_project_tree_cache();
+        let args = build_project_move_args(&cache, "child", Some("root"), None)
+            .unwrap()
+            .unwrap();
+        assert_eq!(args["id"], "child");
+        assert!(args["parent_id"].is_null());
+
+        // Already top-level: nothing to do
+        let args = build_project_move_args(&cache, "root", None, None).unwrap();
+        assert!(args.is_none());
+    }
+
+    #[test]
+    fn test_project_move_rejects_cycles() {
+        let cache = make_project_tree_cache();
+
+        // Under itself
+        let result = build_project_move_args(&cache, "root", None, Some("root"));
+        assert!(result.is_err());
+
+        // Under its own descendant
+        let result = build_project_move_args(&cache, "root", None, Some("grandchild"));
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("cannot be nested"));
+    }
+
     fn make_test_project(id: &str, name: &str) -> Project {
         Project {
             id: id.to_string(),
@@ -1202,6 +1851,8 @@ mod tests {
             color: Some("blue".to_string()),
             favorite: Some(true),
             view_style: Some("board".to_string()),
+            parent: None,
+            no_parent: false,
         };
 
         assert_eq!(opts.project_id, "proj-123");
@@ -1219,6 +1870,8 @@ mod tests {
             color: None,
             favorite: None,
             view_style: Some("list".to_string()),
+            parent: Some("Work".to_string()),
+            no_parent: false,
         };
 
         assert_eq!(opts.project_id, "proj-456");
@@ -1326,4 +1979,66 @@ mod tests {
         assert_eq!(result.id, "proj-789");
         assert_eq!(result.name, "Deleted Project");
     }
+
+    #[test]
+    fn test_build_template_commands_links_sections_and_tasks() {
+        let template: ProjectTemplate = serde_json::from_str(
+            r#"{
+                "sections": [
+                    {"name": "Backlog", "tasks": [
+                        {"content": "Collect ideas", "priority": 1},
+                        {"content": "Triage", "labels": ["review"]}
+                    ]},
+                    {"name": "Done"}
+                ],
+                "tasks": [{"content": "Kickoff", "description": "Agenda"}]
+            }"#,
+        )
+        .unwrap();
+        template.validate().unwrap();
+
+        let commands = build_template_commands("proj-tmp", &template);
+        let types: Vec<_> = commands.iter().map(|c| c.command_type).collect();
+        assert_eq!(
+            types,
+            vec![
+                SyncCommandType::ItemAdd,
+                SyncCommandType::SectionAdd,
+                SyncCommandType::ItemAdd,
+                SyncCommandType::ItemAdd,
+                SyncCommandType::SectionAdd,
+            ]
+        );
+        assert!(commands.iter().all(|c| c.temp_id.is_some()));
+        assert!(commands.iter().all(|c| c.args["project_id"] == "proj-tmp"));
+
+        let backlog_id = commands[1].temp_id.clone().unwrap();
+        assert_eq!(commands[1].args["name"], "Backlog");
+        assert_eq!(commands[1].args["section_order"], 1);
+        assert_eq!(commands[4].args["name"], "Done");
+        assert_eq!(commands[4].args["section_order"], 2);
+
+        assert!(commands[0].args.get("section_id").is_none());
+        assert_eq!(commands[0].args["description"], "Agenda");
+        assert_eq!(commands[2].args["section_id"], backlog_id.as_str());
+        assert_eq!(commands[2].args["priority"], 4);
+        assert_eq!(commands[3].args["section_id"], backlog_id.as_str());
+        assert_eq!(commands[3].args["labels"], serde_json::json!(["review"]));
+    }
+
+    #[test]
+    fn test_project_template_validation() {
+        let parse = |json: &str| serde_json::from_str::<ProjectTemplate>(json).unwrap();
+
+        let duplicate = parse(r#"{"sections": [{"name": "A"}, {"name": "a"}]}"#);
+        assert!(duplicate.validate().is_err());
+
+        let bad_priority = parse(r#"{"tasks": [{"content": "x", "priority": 5}]}"#);
+        assert!(bad_priority.validate().is_err());
+
+        let empty_content = parse(r#"{"sections": [{"name": "A", "tasks": [{"content": " "}]}]}"#);
+        assert!(empty_content.validate().is_err());
+
+        assert!(serde_json::from_str::<ProjectTemplate>(r#"{"task": []}"#).is_err());
+    }
 }
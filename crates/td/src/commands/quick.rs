@@ -1,12 +1,18 @@
 //! Quick add command implementation.
 //!
 //! Creates a new task using the Quick Add REST API with server-side NLP parsing.
+//! A `--note` is sent in the Quick Add request itself. A `--file` is uploaded
+//! once the task exists and attached in a separate `note_add` comment, so a
+//! failed upload leaves the task in place and is reported as an error.
+
+use std::path::PathBuf;
 
 use todoist_api_rs::client::TodoistClient;
 use todoist_api_rs::quick_add::{QuickAddRequest, QuickAddResponse};
-use todoist_cache_rs::{CacheStore, SyncManager};
+use todoist_api_rs::sync::{SyncCommand, SyncCommandType};
+use todoist_cache_rs::SyncManager;
 
-use super::{CommandContext, Result};
+use super::{CommandContext, CommandError, Result};
 
 /// Options for the quick add command.
 #[derive(Debug)]
@@ -15,8 +21,11 @@ pub struct QuickOptions {
     pub text: String,
     /// Whether to add default reminder when task has due time.
     pub auto_reminder: bool,
-    /// Optional note/comment to attach to the task.
+    /// Optional note/comment to attach to the task, sent in the same
+    /// request as the task.
     pub note: Option<String>,
+    /// Optional file to upload and attach to the task as a comment.
+    pub file: Option<PathBuf>,
 }
 
 /// Result of a successful quick add operation.
@@ -66,7 +75,7 @@ impl QuickResult {
 ///
 /// Returns an error if the API call fails.
 pub async fn execute(ctx: &CommandContext, opts: &QuickOptions, token: &str) -> Result<()> {
-    let client = TodoistClient::new(token)?;
+    let client = ctx.client(token)?;
 
     // Build the quick add request
     let mut request =
@@ -80,17 +89,31 @@ pub async fn execute(ctx: &CommandContext, opts: &QuickOptions, token: &str) ->
         request = request.with_note(note);
     }
 
+    // Read the attachment up front so a bad path fails before the task exists
+    let attachment = opts.file.as_deref().map(read_attachment).transpose()?;
+
     // Execute the quick add
     let response = client.quick_add(request).await?;
 
     // Try to resolve project name from cache for better output
-    let project_name = resolve_project_name(token, &response).await;
+    let project_name = resolve_project_name(ctx, &client, &response).await;
 
     let result = QuickResult::from_response(response, project_name);
 
+    if let Some((file_name, contents)) = attachment {
+        attach_file(ctx, token, &result.id, &file_name, contents)
+            .await
+            .map_err(|e| {
+                CommandError::Config(format!(
+                    "Created task {}, but attaching {file_name} failed: {e}",
+                    result.id
+                ))
+            })?;
+    }
+
     // Output
-    if ctx.json_output {
-        let output = crate::output::format_quick_add_result(&result)?;
+    if ctx.output_format.is_json() {
+        let output = crate::output::format_quick_add_result(&result, ctx.compact_json)?;
         println!("{output}");
     } else if !ctx.quiet {
         if ctx.verbose {
@@ -108,6 +131,12 @@ pub async fn execute(ctx: &CommandContext, opts: &QuickOptions, token: &str) ->
             if result.priority < 4 {
                 println!("  Priority: p{}", result.priority);
             }
+            if opts.note.is_some() {
+                println!("  Note: added");
+            }
+            if let Some(ref file) = opts.file {
+                println!("  Attachment: {}", file.display());
+            }
         } else {
             println!(
                 "Created: {} ({})",
@@ -120,17 +149,64 @@ pub async fn execute(ctx: &CommandContext, opts: &QuickOptions, token: &str) ->
     Ok(())
 }
 
+/// Reads a file to attach, returning its name and contents.
+fn read_attachment(path: &std::path::Path) -> Result<(String, Vec<u8>)> {
+    let contents = std::fs::read(path)
+        .map_err(|e| CommandError::Config(format!("Failed to read {}: {e}", path.display())))?;
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| path.display().to_string());
+    Ok((file_name, contents))
+}
+
+/// Uploads a file and attaches it to the task in a new comment.
+async fn attach_file(
+    ctx: &CommandContext,
+    token: &str,
+    task_id: &str,
+    file_name: &str,
+    contents: Vec<u8>,
+) -> Result<()> {
+    let mut manager = ctx.sync_manager(token)?;
+    let file_attachment = manager.client().upload_file(file_name, contents).await?;
+
+    let temp_id = uuid::Uuid::new_v4().to_string();
+    let command = SyncCommand::with_temp_id(
+        SyncCommandType::NoteAdd,
+        &temp_id,
+        serde_json::json!({
+            "item_id": task_id,
+            "content": file_name,
+            "file_attachment": file_attachment,
+        }),
+    );
+    let response = manager.execute_commands(vec![command]).await?;
+    if let Some((_, error)) = response.errors().first() {
+        return Err(CommandError::Config(format!(
+            "Error {}: {}",
+            error.error_code, error.error
+        )));
+    }
+    Ok(())
+}
+
 /// Attempts to resolve the project name from cache.
-async fn resolve_project_name(token: &str, response: &QuickAddResponse) -> Option<String> {
+///
+/// Reuses the command's client, so its connection pool is shared.
+async fn resolve_project_name(
+    ctx: &CommandContext,
+    client: &TodoistClient,
+    response: &QuickAddResponse,
+) -> Option<String> {
     // Try to get from resolved_project_name first
     if let Some(ref name) = response.resolved_project_name {
         return Some(name.clone());
     }
 
     // Fall back to looking up in cache
-    let client = TodoistClient::new(token).ok()?;
-    let store = CacheStore::new().ok()?;
-    let manager = SyncManager::new(client, store).ok()?;
+    let store = ctx.cache_store().ok()?;
+    let manager = SyncManager::new(client.clone(), store).ok()?;
     let cache = manager.cache();
 
     let project_id = response.api_project_id();
@@ -152,11 +228,13 @@ mod tests {
             text: "Buy milk tomorrow".to_string(),
             auto_reminder: false,
             note: None,
+            file: None,
         };
 
         assert_eq!(opts.text, "Buy milk tomorrow");
         assert!(!opts.auto_reminder);
         assert!(opts.note.is_none());
+        assert!(opts.file.is_none());
     }
 
     #[test]
@@ -165,6 +243,7 @@ mod tests {
             text: "Meeting at 3pm #Work".to_string(),
             auto_reminder: true,
             note: Some("Bring laptop".to_string()),
+            file: Some(PathBuf::from("agenda.pdf")),
         };
 
         assert_eq!(opts.text, "Meeting at 3pm #Work");
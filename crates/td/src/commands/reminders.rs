@@ -3,14 +3,16 @@
 //! Lists and manages reminders via the Sync API.
 //! Uses SyncManager::execute_commands() to automatically update the cache.
 
-use todoist_api_rs::client::TodoistClient;
 use todoist_api_rs::models::ReminderType;
-use todoist_api_rs::sync::{Reminder, SyncCommand, SyncCommandType};
-use todoist_cache_rs::{Cache, CacheStore, SyncManager};
+use todoist_api_rs::sync::{DueSpec, Reminder, SyncCommand, SyncCommandType};
+use todoist_cache_rs::Cache;
 
-use super::{CommandContext, CommandError, Result};
+use super::{ensure_fresh, parse_duration_minutes, CommandContext, CommandError, Result};
 use crate::output::helpers::{MINUTES_PER_DAY, MINUTES_PER_HOUR};
-use crate::output::{format_created_reminder, format_reminders_json, format_reminders_table};
+use crate::output::{
+    format_created_reminder, format_reminders_csv, format_reminders_json, format_reminders_table,
+    OutputFormat,
+};
 
 /// Options for the reminders list command.
 #[derive(Debug, Default)]
@@ -39,17 +41,9 @@ pub async fn execute(ctx: &CommandContext, opts: &RemindersListOptions, token: &
     }
 
     // Initialize sync manager
-    let client = TodoistClient::new(token)?;
-    let store = CacheStore::new()?;
-    let mut manager = SyncManager::new(client, store)?;
+    let mut manager = ctx.sync_manager(token)?;
 
-    // Only sync if explicitly requested with --sync flag
-    if ctx.sync_first {
-        if ctx.verbose {
-            eprintln!("Syncing with Todoist...");
-        }
-        manager.sync().await?;
-    }
+    ensure_fresh(ctx, &mut manager).await?;
 
     let cache = manager.cache();
 
@@ -71,12 +65,17 @@ pub async fn execute(ctx: &CommandContext, opts: &RemindersListOptions, token: &
         .map(|i| i.content.clone());
 
     // Output
-    if ctx.json_output {
-        let output = format_reminders_json(&reminders, cache)?;
-        println!("{output}");
-    } else if !ctx.quiet {
-        let output = format_reminders_table(&reminders, task_name.as_deref(), ctx.use_colors);
-        print!("{output}");
+    match ctx.output_format {
+        OutputFormat::Json => {
+            let output = format_reminders_json(&reminders, cache, ctx.compact_json)?;
+            println!("{output}");
+        }
+        OutputFormat::Csv => print!("{}", format_reminders_csv(&reminders, cache)),
+        OutputFormat::Table if !ctx.quiet => {
+            let output = format_reminders_table(&reminders, task_name.as_deref(), ctx.use_colors);
+            print!("{output}");
+        }
+        OutputFormat::Table => {}
     }
 
     Ok(())
@@ -139,8 +138,12 @@ pub struct RemindersAddOptions {
     pub task: String,
     /// Absolute due date/time for the reminder.
     pub due: Option<String>,
-    /// Minutes before task due time (for relative reminders).
-    pub offset: Option<i32>,
+    /// Time before the task's due time (for relative reminders): minutes
+    /// (`30`) or a duration like `45m`, `1h`, `2d`.
+    pub offset: Option<String>,
+    /// Offset from the `default_reminder_offset` config, used when neither
+    /// `due` nor `offset` is given.
+    pub default_offset: Option<i32>,
 }
 
 /// Result of a successful reminder add operation.
@@ -160,6 +163,37 @@ pub struct ReminderAddResult {
     pub minute_offset: Option<i32>,
 }
 
+/// Returns the relative offset in minutes: `--offset` wins over the
+/// configured default, and neither applies when `--due` is given.
+///
+/// # Errors
+///
+/// Returns an error if `--offset` is not a valid offset.
+fn effective_offset(opts: &RemindersAddOptions) -> Result<Option<i32>> {
+    if opts.due.is_some() {
+        return Ok(None);
+    }
+    match opts.offset {
+        Some(ref offset) => parse_offset_minutes(offset).map(Some),
+        None => Ok(opts.default_offset),
+    }
+}
+
+/// Parses an `--offset` value into minutes.
+///
+/// A bare number is minutes; `m`, `h`, `d` and `w` suffixes give minutes,
+/// hours, days and weeks (e.g., `45m`, `1h`, `2d`, `1w`).
+fn parse_offset_minutes(input: &str) -> Result<i32> {
+    parse_duration_minutes(input)
+        .and_then(|minutes| i32::try_from(minutes).ok())
+        .ok_or_else(|| {
+            CommandError::Config(format!(
+                "Invalid --offset value '{}'. Use minutes (30) or a duration like 45m, 1h, 2d, 1w.",
+                input.trim()
+            ))
+        })
+}
+
 /// Executes the reminders add command.
 ///
 /// # Arguments
@@ -177,17 +211,16 @@ pub async fn execute_add(
     opts: &RemindersAddOptions,
     token: &str,
 ) -> Result<()> {
-    // Require at least one of --due or --offset
-    if opts.due.is_none() && opts.offset.is_none() {
+    // Require --due or an offset (explicit or from config)
+    let offset = effective_offset(opts)?;
+    if opts.due.is_none() && offset.is_none() {
         return Err(CommandError::Config(
-            "Either --due or --offset is required to create a reminder.".to_string(),
+            "Either --due or --offset is required to create a reminder (or set default_reminder_offset in the config).".to_string(),
         ));
     }
 
     // Initialize sync manager (loads cache from disk)
-    let client = TodoistClient::new(token)?;
-    let store = CacheStore::new()?;
-    let mut manager = SyncManager::new(client, store)?;
+    let mut manager = ctx.sync_manager(token)?;
 
     // Resolve task ID and get task name before mutation
     let (task_id, task_name) = {
@@ -204,16 +237,13 @@ pub async fn execute_add(
     // Determine reminder type based on provided options
     let (reminder_type, args) = if let Some(ref due) = opts.due {
         // Absolute reminder
-        let due_obj = serde_json::json!({
-            "date": due,
-        });
         let args = serde_json::json!({
             "item_id": task_id,
             "type": "absolute",
-            "due": due_obj,
+            "due": DueSpec::from_date(due.as_str()),
         });
         (ReminderType::Absolute, args)
-    } else if let Some(offset) = opts.offset {
+    } else if let Some(offset) = offset {
         // Relative reminder
         let args = serde_json::json!({
             "item_id": task_id,
@@ -260,12 +290,12 @@ pub async fn execute_add(
         task_name,
         reminder_type,
         due: opts.due.clone(),
-        minute_offset: opts.offset,
+        minute_offset: offset,
     };
 
     // Output
-    if ctx.json_output {
-        let output = format_created_reminder(&result)?;
+    if ctx.output_format.is_json() {
+        let output = format_created_reminder(&result, ctx.compact_json)?;
         println!("{output}");
     } else if !ctx.quiet {
         let task_display = result.task_name.as_deref().unwrap_or(&result.task_id);
@@ -327,8 +357,12 @@ fn format_offset(minutes: i32) -> String {
 /// Options for the reminders delete command.
 #[derive(Debug)]
 pub struct RemindersDeleteOptions {
-    /// Reminder ID (full ID or prefix).
-    pub reminder_id: String,
+    /// Reminder ID (full ID or prefix); unused with `all`.
+    pub reminder_id: Option<String>,
+    /// Task (ID or prefix) whose reminders `all` deletes.
+    pub task: Option<String>,
+    /// Delete every reminder of `task`.
+    pub all: bool,
     /// Skip confirmation.
     pub force: bool,
 }
@@ -363,53 +397,74 @@ pub async fn execute_delete(
     token: &str,
 ) -> Result<()> {
     // Initialize sync manager (loads cache from disk)
-    let client = TodoistClient::new(token)?;
-    let store = CacheStore::new()?;
-    let mut manager = SyncManager::new(client, store)?;
+    let mut manager = ctx.sync_manager(token)?;
 
-    // Find the reminder by ID or prefix and extract owned data before mutation
-    let (reminder_id, task_id, reminder_type, reminder_offset, reminder_due, task_name) = {
+    // Find the reminders and extract owned data before mutation
+    let targets: Vec<(ReminderDeleteResult, String)> = {
         let cache = manager.cache();
-        let reminder = find_reminder_by_id_or_prefix(cache, &opts.reminder_id)?;
-        let r_id = reminder.id.clone();
-        let t_id = reminder.item_id.clone();
-        let r_type = reminder.reminder_type;
-        let r_offset = reminder.minute_offset;
-        let r_due = reminder.due.clone();
-        let t_name = cache
-            .items
-            .iter()
-            .find(|i| i.id == t_id)
-            .map(|i| i.content.clone());
-        (r_id, t_id, r_type, r_offset, r_due, t_name)
+        reminders_to_delete(cache, opts)?
+            .into_iter()
+            .map(|reminder| {
+                let task_name = cache
+                    .items
+                    .iter()
+                    .find(|i| i.id == reminder.item_id)
+                    .map(|i| i.content.clone());
+                let description = format_reminder_description(
+                    reminder.reminder_type,
+                    reminder.minute_offset,
+                    reminder.due.as_ref(),
+                );
+                let result = ReminderDeleteResult {
+                    id: reminder.id.clone(),
+                    task_id: reminder.item_id.clone(),
+                    task_name,
+                    reminder_type: reminder.reminder_type,
+                };
+                (result, description)
+            })
+            .collect()
     };
 
     // Confirm if not forced
     if !opts.force && !ctx.quiet {
-        let task_display = task_name.as_deref().unwrap_or(&task_id);
-        let reminder_desc =
-            format_reminder_description(reminder_type, reminder_offset, reminder_due.as_ref());
-        eprintln!(
-            "Delete reminder '{}' for task '{}'?",
-            reminder_desc, task_display
-        );
+        let (first, description) = &targets[0];
+        let task_display = first.task_name.as_deref().unwrap_or(&first.task_id);
+        if opts.all {
+            eprintln!(
+                "Delete all {} reminder(s) for task '{}'?",
+                targets.len(),
+                task_display
+            );
+            for (_, description) in &targets {
+                eprintln!("  - {description}");
+            }
+        } else {
+            eprintln!(
+                "Delete reminder '{}' for task '{}'?",
+                description, task_display
+            );
+        }
         eprintln!("Use --force to skip this confirmation.");
         return Err(CommandError::Config(
             "Operation cancelled. Use --force to confirm.".to_string(),
         ));
     }
 
-    // Build the reminder_delete command arguments
-    let args = serde_json::json!({
-        "id": reminder_id,
-    });
-
-    // Create the command
-    let command = SyncCommand::new(SyncCommandType::ReminderDelete, args);
+    // Build one reminder_delete command per reminder
+    let commands: Vec<SyncCommand> = targets
+        .iter()
+        .map(|(result, _)| {
+            SyncCommand::new(
+                SyncCommandType::ReminderDelete,
+                serde_json::json!({ "id": result.id }),
+            )
+        })
+        .collect();
 
-    // Execute the command via SyncManager
-    // This sends the command, applies the response to cache, and saves to disk
-    let response = manager.execute_commands(vec![command]).await?;
+    // Execute the commands via SyncManager
+    // This sends the commands, applies the response to cache, and saves to disk
+    let response = manager.execute_commands(commands).await?;
 
     // Check for errors
     if response.has_errors() {
@@ -424,31 +479,60 @@ pub async fn execute_delete(
         }
     }
 
-    let result = ReminderDeleteResult {
-        id: reminder_id,
-        task_id,
-        task_name,
-        reminder_type,
-    };
+    let results: Vec<ReminderDeleteResult> =
+        targets.into_iter().map(|(result, _)| result).collect();
 
     // Output
-    if ctx.json_output {
-        let output = crate::output::format_deleted_reminder(&result)?;
+    if ctx.output_format.is_json() {
+        let output = if opts.all {
+            crate::output::format_deleted_reminders(&results, ctx.compact_json)?
+        } else {
+            crate::output::format_deleted_reminder(&results[0], ctx.compact_json)?
+        };
         println!("{output}");
     } else if !ctx.quiet {
-        let task_display = result.task_name.as_deref().unwrap_or(&result.task_id);
-        if ctx.verbose {
-            println!("Deleted reminder: {} ({})", result.reminder_type, result.id);
-            println!("  Task: {}", task_display);
-        } else {
-            let prefix = &result.id[..6.min(result.id.len())];
-            println!("Deleted reminder ({}) from task: {}", prefix, task_display);
+        for result in &results {
+            let task_display = result.task_name.as_deref().unwrap_or(&result.task_id);
+            if ctx.verbose {
+                println!("Deleted reminder: {} ({})", result.reminder_type, result.id);
+                println!("  Task: {}", task_display);
+            } else {
+                let prefix = &result.id[..6.min(result.id.len())];
+                println!("Deleted reminder ({}) from task: {}", prefix, task_display);
+            }
         }
     }
 
     Ok(())
 }
 
+/// Returns the reminders a delete targets: the one named by ID or prefix,
+/// or with `all`, every cached reminder of the task.
+///
+/// # Errors
+///
+/// Returns an error if the reminder or task can't be resolved, or if the
+/// task has no reminders.
+fn reminders_to_delete<'a>(
+    cache: &'a Cache,
+    opts: &RemindersDeleteOptions,
+) -> Result<Vec<&'a Reminder>> {
+    if !opts.all {
+        let id = opts.reminder_id.as_deref().unwrap_or_default();
+        return Ok(vec![find_reminder_by_id_or_prefix(cache, id)?]);
+    }
+
+    let task = opts.task.as_deref().unwrap_or_default();
+    let task_id = resolve_task_id(cache, task)?;
+    let reminders = filter_reminders(cache, &task_id);
+    if reminders.is_empty() {
+        return Err(CommandError::Config(format!(
+            "Task {task} has no reminders"
+        )));
+    }
+    Ok(reminders)
+}
+
 /// Finds a reminder by full ID or unique prefix.
 fn find_reminder_by_id_or_prefix<'a>(cache: &'a Cache, id: &str) -> Result<&'a Reminder> {
     // First try exact match
@@ -723,6 +807,7 @@ mod tests {
             task: "task-123".to_string(),
             due: Some("2025-01-26T10:00:00".to_string()),
             offset: None,
+            default_offset: None,
         };
 
         assert_eq!(opts.task, "task-123");
@@ -735,12 +820,67 @@ mod tests {
         let opts = RemindersAddOptions {
             task: "task-456".to_string(),
             due: None,
-            offset: Some(30),
+            offset: Some("30".to_string()),
+            default_offset: None,
         };
 
         assert_eq!(opts.task, "task-456");
         assert!(opts.due.is_none());
-        assert_eq!(opts.offset, Some(30));
+        assert_eq!(opts.offset.as_deref(), Some("30"));
+    }
+
+    #[test]
+    fn test_effective_offset_uses_config_default() {
+        let opts = RemindersAddOptions {
+            task: "task-1".to_string(),
+            due: None,
+            offset: None,
+            default_offset: Some(30),
+        };
+        assert_eq!(effective_offset(&opts).unwrap(), Some(30));
+    }
+
+    #[test]
+    fn test_effective_offset_flag_overrides_config() {
+        let mut opts = RemindersAddOptions {
+            task: "task-1".to_string(),
+            due: None,
+            offset: Some("10".to_string()),
+            default_offset: Some(30),
+        };
+        assert_eq!(effective_offset(&opts).unwrap(), Some(10));
+
+        // An absolute reminder ignores the configured offset
+        opts.offset = None;
+        opts.due = Some("2025-01-26T10:00:00".to_string());
+        assert_eq!(effective_offset(&opts).unwrap(), None);
+
+        opts.due = None;
+        opts.default_offset = None;
+        assert_eq!(effective_offset(&opts).unwrap(), None);
+    }
+
+    #[test]
+    fn test_parse_offset_minutes_bare_number() {
+        assert_eq!(parse_offset_minutes("30").unwrap(), 30);
+        assert_eq!(parse_offset_minutes("0").unwrap(), 0);
+    }
+
+    #[test]
+    fn test_parse_offset_minutes_units() {
+        assert_eq!(parse_offset_minutes("45m").unwrap(), 45);
+        assert_eq!(parse_offset_minutes("1h").unwrap(), 60);
+        assert_eq!(parse_offset_minutes("2d").unwrap(), 2 * 24 * 60);
+        assert_eq!(parse_offset_minutes(" 2H ").unwrap(), 120);
+        assert_eq!(parse_offset_minutes("1w").unwrap(), 7 * 24 * 60);
+    }
+
+    #[test]
+    fn test_parse_offset_minutes_rejects_invalid() {
+        for input in ["h", "-5", "1.5h", "soon", "", "999999999w"] {
+            let err = parse_offset_minutes(input).unwrap_err();
+            assert!(err.to_string().contains("--offset"), "{input}");
+        }
     }
 
     #[test]
@@ -821,22 +961,26 @@ mod tests {
     #[test]
     fn test_reminders_delete_options() {
         let opts = RemindersDeleteOptions {
-            reminder_id: "reminder-123".to_string(),
+            reminder_id: Some("reminder-123".to_string()),
+            task: None,
+            all: false,
             force: false,
         };
 
-        assert_eq!(opts.reminder_id, "reminder-123");
+        assert_eq!(opts.reminder_id.as_deref(), Some("reminder-123"));
         assert!(!opts.force);
     }
 
     #[test]
     fn test_reminders_delete_options_with_force() {
         let opts = RemindersDeleteOptions {
-            reminder_id: "reminder-456".to_string(),
+            reminder_id: Some("reminder-456".to_string()),
+            task: None,
+            all: false,
             force: true,
         };
 
-        assert_eq!(opts.reminder_id, "reminder-456");
+        assert_eq!(opts.reminder_id.as_deref(), Some("reminder-456"));
         assert!(opts.force);
     }
 
@@ -855,6 +999,60 @@ mod tests {
         assert_eq!(result.reminder_type, ReminderType::Relative);
     }
 
+    fn delete_options(reminder_id: Option<&str>, task: Option<&str>) -> RemindersDeleteOptions {
+        RemindersDeleteOptions {
+            reminder_id: reminder_id.map(String::from),
+            task: task.map(String::from),
+            all: task.is_some(),
+            force: true,
+        }
+    }
+
+    fn target_ids(cache: &Cache, opts: &RemindersDeleteOptions) -> Vec<String> {
+        reminders_to_delete(cache, opts)
+            .unwrap()
+            .iter()
+            .map(|r| r.id.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_reminders_to_delete_all_for_task() {
+        let mut cache = make_test_cache();
+        let mut other = cache.reminders[0].clone();
+        other.id = "reminder-other".to_string();
+        other.item_id = "task-2".to_string();
+        let mut gone = cache.reminders[0].clone();
+        gone.id = "reminder-gone".to_string();
+        gone.is_deleted = true;
+        cache.reminders.extend([other, gone]);
+
+        assert_eq!(
+            target_ids(&cache, &delete_options(None, Some("task-1"))),
+            vec!["reminder-1", "reminder-2"]
+        );
+    }
+
+    #[test]
+    fn test_reminders_to_delete_all_errors_without_reminders() {
+        let mut cache = make_test_cache();
+        cache.reminders.clear();
+        let err = reminders_to_delete(&cache, &delete_options(None, Some("task-1"))).unwrap_err();
+        assert!(err.to_string().contains("Task task-1 has no reminders"));
+
+        assert!(reminders_to_delete(&cache, &delete_options(None, Some("nope"))).is_err());
+    }
+
+    #[test]
+    fn test_reminders_to_delete_single_by_id() {
+        let cache = make_test_cache();
+        assert_eq!(
+            target_ids(&cache, &delete_options(Some("reminder-2"), None)),
+            vec!["reminder-2"]
+        );
+        assert!(reminders_to_delete(&cache, &delete_options(Some("missing"), None)).is_err());
+    }
+
     #[test]
     fn test_find_reminder_by_id_or_prefix_exact_match() {
         let cache = make_test_cache();
@@ -2,19 +2,28 @@
 //!
 //! Reopens completed task(s) via the Sync API's `item_uncomplete` command.
 //! Uses SyncManager::execute_commands() to automatically update the cache.
-//! Uses resolve_item_by_prefix() for smart lookups with auto-sync fallback.
+//! Uses resolve_item_arg() to accept IDs, ID prefixes, or content substrings
+//! with auto-sync fallback, or `--filter` to select completed tasks by filter
+//! expression.
 
-use todoist_api_rs::client::TodoistClient;
 use todoist_api_rs::sync::{SyncCommand, SyncCommandType};
-use todoist_cache_rs::{CacheStore, SyncManager};
+use todoist_cache_rs::filter::{FilterContext, FilterEvaluator, FilterParser};
+use todoist_cache_rs::Cache;
 
-use super::{confirm_bulk_operation, CommandContext, CommandError, ConfirmResult, Result};
+use super::{
+    check_filter_result_cap, confirm_bulk_operation, BulkItem, CommandContext, CommandError,
+    ConfirmResult, Result,
+};
 
 /// Options for the reopen command.
 #[derive(Debug)]
 pub struct ReopenOptions {
-    /// Task IDs (full IDs or prefixes).
+    /// Task references (full IDs, ID prefixes, or content substrings).
     pub task_ids: Vec<String>,
+    /// Filter expression selecting completed tasks to reopen (instead of task_ids).
+    pub filter: Option<String>,
+    /// Most tasks a `filter` run may reopen without `force`.
+    pub max_results: usize,
     /// Skip confirmation for multiple tasks.
     pub force: bool,
 }
@@ -45,31 +54,46 @@ pub struct ReopenResult {
 /// Returns an error if syncing fails, task lookup fails, or the API returns an error.
 pub async fn execute(ctx: &CommandContext, opts: &ReopenOptions, token: &str) -> Result<()> {
     // Initialize sync manager (loads cache from disk)
-    let client = TodoistClient::new(token)?;
-    let store = CacheStore::new()?;
-    let mut manager = SyncManager::new(client, store)?;
-
-    // Resolve all task IDs using smart lookup (cache-first with auto-sync fallback)
-    // require_checked=Some(true) to only find completed tasks (reopen only makes sense for completed tasks)
-    let mut resolved_items: Vec<(String, String)> = Vec::new();
-    for task_id in &opts.task_ids {
-        let item = manager
-            .resolve_item_by_prefix(task_id, Some(true))
-            .await
-            .map_err(|e| CommandError::Config(e.to_string()))?;
-        resolved_items.push((item.id.clone(), item.content.clone()));
-    }
+    let mut manager = ctx.sync_manager(token)?;
+
+    let resolved_items: Vec<(String, String)> = if let Some(ref filter) = opts.filter {
+        let items = select_filter_targets(manager.cache(), filter)?;
+        if items.is_empty() {
+            if !ctx.quiet {
+                eprintln!("No completed tasks match filter: {filter}");
+            }
+            return Ok(());
+        }
+        check_filter_result_cap("reopen", items.len(), opts.max_results, opts.force)?;
+        items
+    } else {
+        // Resolve all task references (ID, prefix, or content) with auto-sync fallback
+        // require_checked=Some(true) to only find completed tasks (reopen only makes sense for completed tasks)
+        let mut items = Vec::new();
+        for task_id in &opts.task_ids {
+            let item = manager
+                .resolve_item_arg(task_id, Some(true))
+                .await
+                .map_err(|e| CommandError::Config(e.to_string()))?;
+            items.push((item.id.clone(), item.content.clone()));
+        }
+        items
+    };
 
     // Prompt for confirmation if multiple tasks
-    let items_for_confirm: Vec<(&str, &str)> = resolved_items
+    let items_for_confirm: Vec<BulkItem> = resolved_items
         .iter()
-        .map(|(id, content)| {
-            let id_prefix = &id[..6.min(id.len())];
-            (id_prefix, content.as_str())
-        })
+        .map(|(id, content)| BulkItem::resolve(manager.cache(), id, content))
         .collect();
 
-    match confirm_bulk_operation("reopen", &items_for_confirm, opts.force, ctx.quiet)? {
+    match confirm_bulk_operation(
+        "reopen",
+        &items_for_confirm,
+        ctx.date_style,
+        ctx.id_style,
+        opts.force,
+        ctx.quiet,
+    )? {
         ConfirmResult::Confirmed => {}
         ConfirmResult::Aborted => {
             if !ctx.quiet {
@@ -133,8 +157,8 @@ pub async fn execute(ctx: &CommandContext, opts: &ReopenOptions, token: &str) ->
     }
 
     // Output results
-    if ctx.json_output {
-        let output = format_reopen_results_json(&results)?;
+    if ctx.output_format.is_json() {
+        let output = format_reopen_results_json(&results, ctx.compact_json)?;
         println!("{output}");
     } else if !ctx.quiet {
         for result in &results {
@@ -165,8 +189,27 @@ pub async fn execute(ctx: &CommandContext, opts: &ReopenOptions, token: &str) ->
     Ok(())
 }
 
+/// Returns (id, content) for every completed task matching the filter expression.
+///
+/// Only completed tasks still held in the cache are candidates: the cache
+/// keeps tasks completed via `td done` (or seen completed during a sync) until
+/// they're deleted, but the Sync API doesn't send older completed tasks.
+fn select_filter_targets(cache: &Cache, filter_expr: &str) -> Result<Vec<(String, String)>> {
+    let filter = FilterParser::parse(filter_expr)?;
+    let context = FilterContext::from_cache(cache);
+    let evaluator = FilterEvaluator::new(&filter, &context);
+
+    Ok(cache
+        .items
+        .iter()
+        .filter(|i| !i.is_deleted && i.checked)
+        .filter(|i| evaluator.matches(i))
+        .map(|i| (i.id.clone(), i.content.clone()))
+        .collect())
+}
+
 /// Formats reopen results as JSON.
-fn format_reopen_results_json(results: &[ReopenResult]) -> Result<String> {
+fn format_reopen_results_json(results: &[ReopenResult], compact: bool) -> Result<String> {
     use serde::Serialize;
 
     #[derive(Serialize)]
@@ -216,17 +259,21 @@ fn format_reopen_results_json(results: &[ReopenResult]) -> Result<String> {
         failed,
     };
 
-    serde_json::to_string_pretty(&output).map_err(CommandError::from)
+    crate::output::to_json(&output, compact).map_err(CommandError::from)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::commands::DEFAULT_MAX_FILTER_RESULTS;
+    use crate::test_support::item;
 
     #[test]
     fn test_reopen_options_single_task() {
         let opts = ReopenOptions {
             task_ids: vec!["abc123".to_string()],
+            filter: None,
+            max_results: DEFAULT_MAX_FILTER_RESULTS,
             force: false,
         };
 
@@ -242,6 +289,8 @@ mod tests {
                 "def456".to_string(),
                 "ghi789".to_string(),
             ],
+            filter: None,
+            max_results: DEFAULT_MAX_FILTER_RESULTS,
             force: true,
         };
 
@@ -275,9 +324,9 @@ mod tests {
         assert!(result.error.is_some());
     }
 
-    // Note: Tests for item lookup by prefix are now in SyncManager tests
-    // (resolve_item_by_prefix covers exact match, prefix match, not found,
-    // ambiguous, deleted items, and completion status filtering)
+    // Note: Tests for item lookup are in SyncManager tests
+    // (resolve_item_arg covers exact match, prefix match, content substring,
+    // not found, ambiguous, and completion status filtering)
 
     #[test]
     fn test_format_reopen_results_json() {
@@ -296,11 +345,58 @@ mod tests {
             },
         ];
 
-        let json = format_reopen_results_json(&results).unwrap();
+        let json = format_reopen_results_json(&results, false).unwrap();
         assert!(json.contains("\"total_reopened\": 1"));
         assert!(json.contains("\"total_failed\": 1"));
         assert!(json.contains("Task 1"));
         assert!(json.contains("Task 2"));
         assert!(json.contains("Not found"));
     }
+
+    #[test]
+    fn test_select_filter_targets_only_completed_matches() {
+        let deleted = item("id-5", "Gone")
+            .labels(&["recurring"])
+            .checked()
+            .deleted()
+            .build();
+
+        let cache = Cache::with_data(
+            "token".to_string(),
+            None,
+            None,
+            vec![
+                item("id-1", "Water plants")
+                    .labels(&["recurring"])
+                    .checked()
+                    .build(),
+                item("id-2", "Pay rent").labels(&["recurring"]).build(),
+                item("id-3", "One-off").checked().build(),
+                item("id-4", "Stretch")
+                    .labels(&["recurring"])
+                    .checked()
+                    .build(),
+                deleted,
+            ],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            None,
+        );
+
+        let targets = select_filter_targets(&cache, "completed & @recurring").unwrap();
+        let ids: Vec<&str> = targets.iter().map(|(id, _)| id.as_str()).collect();
+        assert_eq!(ids, vec!["id-1", "id-4"]);
+
+        // Active tasks are never candidates, even if they match
+        let targets = select_filter_targets(&cache, "@recurring | no labels").unwrap();
+        let ids: Vec<&str> = targets.iter().map(|(id, _)| id.as_str()).collect();
+        assert_eq!(ids, vec!["id-1", "id-3", "id-4"]);
+
+        assert!(select_filter_targets(&cache, "completed &").is_err());
+    }
 }
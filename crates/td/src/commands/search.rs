@@ -0,0 +1,232 @@
+//! Search command implementation.
+//!
+//! Finds open tasks whose title or description contains a query, using the
+//! local cache. Results are ranked by relevance unless another sort is given.
+
+use std::cmp::Ordering;
+
+use todoist_api_rs::sync::Item;
+use todoist_cache_rs::Cache;
+
+use super::{ensure_fresh, CommandContext, Result};
+use crate::cli::SearchSort;
+use crate::output::{format_items_csv, format_items_json, format_items_table, OutputFormat};
+
+/// Options for the search command.
+#[derive(Debug)]
+pub struct SearchOptions {
+    /// Text to search for (case-insensitive).
+    pub query: String,
+    /// Result ordering.
+    pub sort: SearchSort,
+    /// Limit results.
+    pub limit: u32,
+    /// Show all matches (no limit).
+    pub all: bool,
+}
+
+/// How well a task matches a search query, from weakest to strongest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Relevance {
+    /// Only the description contains the query.
+    DescriptionSubstring,
+    /// The title contains the query inside a word.
+    TitleSubstring,
+    /// A word in the title starts with the query.
+    TitlePrefix,
+    /// The query appears in the title as whole words.
+    TitleExact,
+}
+
+/// Executes the search command.
+///
+/// # Arguments
+///
+/// * `ctx` - Command context with output settings
+/// * `opts` - Search command options
+/// * `token` - API token
+///
+/// # Errors
+///
+/// Returns an error if syncing fails.
+pub async fn execute(ctx: &CommandContext, opts: &SearchOptions, token: &str) -> Result<()> {
+    let mut manager = ctx.sync_manager(token)?;
+
+    ensure_fresh(ctx, &mut manager).await?;
+
+    let cache = manager.cache();
+    let mut items = search_items(cache, &opts.query, &opts.sort);
+    if !opts.all {
+        items.truncate(opts.limit as usize);
+    }
+
+    match ctx.output_format {
+        OutputFormat::Json => {
+            let output = format_items_json(&items, cache, ctx.compact_json)?;
+            println!("{output}");
+        }
+        OutputFormat::Csv => print!("{}", format_items_csv(&items, cache)),
+        OutputFormat::Table if !ctx.quiet => {
+            let output = format_items_table(
+                &items,
+                cache,
+                ctx.use_colors,
+                false,
+                true,
+                ctx.date_style,
+                ctx.id_style,
+            );
+            ctx.print_table(&output);
+            ctx.print_sync_footer(cache);
+        }
+        OutputFormat::Table => {}
+    }
+
+    Ok(())
+}
+
+/// Returns open tasks matching `query`, ordered by `sort`.
+fn search_items<'a>(cache: &'a Cache, query: &str, sort: &SearchSort) -> Vec<&'a Item> {
+    let query = query.trim().to_lowercase();
+    let mut matches: Vec<(&Item, Relevance)> = cache
+        .items
+        .iter()
+        .filter(|item| !item.is_deleted && !item.checked)
+        .filter_map(|item| relevance(item, &query).map(|score| (item, score)))
+        .collect();
+
+    match sort {
+        SearchSort::Relevance => {
+            matches.sort_by(|(a, a_score), (b, b_score)| {
+                b_score.cmp(a_score).then_with(|| compare_due(a, b))
+            });
+        }
+        SearchSort::Due => matches.sort_by(|(a, _), (b, _)| compare_due(a, b)),
+        SearchSort::Priority => {
+            // Higher API priority (4) = higher user priority (p1)
+            matches.sort_by_key(|(item, _)| std::cmp::Reverse(item.priority));
+        }
+    }
+
+    matches.into_iter().map(|(item, _)| item).collect()
+}
+
+/// Scores how well `item` matches a lowercased query, or `None` if it doesn't.
+pub fn relevance(item: &Item, query: &str) -> Option<Relevance> {
+    if query.is_empty() {
+        return None;
+    }
+
+    let title = item.content.to_lowercase();
+    let mut best = None;
+    for (start, _) in title.match_indices(query) {
+        let end = start + query.len();
+        let starts_word = !title[..start]
+            .chars()
+            .next_back()
+            .is_some_and(char::is_alphanumeric);
+        let ends_word = !title[end..]
+            .chars()
+            .next()
+            .is_some_and(char::is_alphanumeric);
+        let score = match (starts_word, ends_word) {
+            (true, true) => Relevance::TitleExact,
+            (true, false) => Relevance::TitlePrefix,
+            _ => Relevance::TitleSubstring,
+        };
+        best = best.max(Some(score));
+    }
+
+    best.or_else(|| {
+        item.description
+            .to_lowercase()
+            .contains(query)
+            .then_some(Relevance::DescriptionSubstring)
+    })
+}
+
+/// Orders by due date, with tasks without a due date last.
+fn compare_due(a: &Item, b: &Item) -> Ordering {
+    let a_date = a.due.as_ref().map(|d| d.date.as_str());
+    let b_date = b.due.as_ref().map(|d| d.date.as_str());
+    match (a_date, b_date) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+        (Some(a), Some(b)) => a.cmp(b),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::item;
+
+    fn search_ids(items: Vec<Item>, query: &str) -> Vec<String> {
+        let mut cache = Cache::new();
+        cache.items = items;
+        search_items(&cache, query, &SearchSort::Relevance)
+            .into_iter()
+            .map(|item| item.id.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_relevance_levels() {
+        let score = |content: &str, description: &str| {
+            relevance(
+                &item("1", content).description(description).build(),
+                "report",
+            )
+        };
+
+        assert_eq!(score("Write report", ""), Some(Relevance::TitleExact));
+        assert_eq!(score("Report", ""), Some(Relevance::TitleExact));
+        assert_eq!(score("Reporting tools", ""), Some(Relevance::TitlePrefix));
+        assert_eq!(
+            score("Misreported hours", ""),
+            Some(Relevance::TitleSubstring)
+        );
+        assert_eq!(
+            score("Email Alice", "Attach the report"),
+            Some(Relevance::DescriptionSubstring)
+        );
+        assert_eq!(score("Email Alice", "Attach the slides"), None);
+    }
+
+    #[test]
+    fn test_title_match_outranks_description_match() {
+        let ids = search_ids(
+            vec![
+                item("desc", "Email Alice")
+                    .description("Send her the budget")
+                    .build(),
+                item("title", "Budget review").build(),
+            ],
+            "budget",
+        );
+        assert_eq!(ids, vec!["title", "desc"]);
+    }
+
+    #[test]
+    fn test_relevance_ties_break_by_due_date() {
+        let later = item("later", "Budget for Q3").due("2025-03-01").build();
+        let sooner = item("sooner", "Budget for Q2").due("2025-02-01").build();
+        let undated = item("undated", "Budget for Q4").build();
+
+        let ids = search_ids(vec![undated, later, sooner], "budget");
+        assert_eq!(ids, vec!["sooner", "later", "undated"]);
+    }
+
+    #[test]
+    fn test_search_skips_completed_and_deleted() {
+        let done = item("done", "Budget").checked().build();
+        let deleted = item("deleted", "Budget").deleted().build();
+
+        let ids = search_ids(
+            vec![done, deleted, item("open", "Budget").build()],
+            "budget",
+        );
+        assert_eq!(ids, vec!["open"]);
+    }
+}
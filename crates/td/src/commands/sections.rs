@@ -3,12 +3,13 @@
 //! Lists and manages sections via the Sync API.
 //! Uses SyncManager::execute_commands() to automatically update the cache.
 
-use todoist_api_rs::client::TodoistClient;
 use todoist_api_rs::sync::{Section, SyncCommand, SyncCommandType};
-use todoist_cache_rs::{Cache, CacheStore, SyncManager};
+use todoist_cache_rs::Cache;
 
-use super::{CommandContext, CommandError, Result};
-use crate::output::{format_sections_json, format_sections_table};
+use super::{ensure_fresh, CommandContext, CommandError, Result};
+use crate::output::{
+    format_sections_csv, format_sections_json, format_sections_table, OutputFormat,
+};
 
 /// Options for the sections list command.
 #[derive(Debug, Default)]
@@ -32,17 +33,9 @@ pub struct SectionsListOptions {
 /// Returns an error if syncing fails.
 pub async fn execute(ctx: &CommandContext, opts: &SectionsListOptions, token: &str) -> Result<()> {
     // Initialize sync manager
-    let client = TodoistClient::new(token)?;
-    let store = CacheStore::new()?;
-    let mut manager = SyncManager::new(client, store)?;
+    let mut manager = ctx.sync_manager(token)?;
 
-    // Only sync if explicitly requested with --sync flag
-    if ctx.sync_first {
-        if ctx.verbose {
-            eprintln!("Syncing with Todoist...");
-        }
-        manager.sync().await?;
-    }
+    ensure_fresh(ctx, &mut manager).await?;
 
     let cache = manager.cache();
 
@@ -60,12 +53,18 @@ pub async fn execute(ctx: &CommandContext, opts: &SectionsListOptions, token: &s
     let sections = apply_limit(sections, opts);
 
     // Output
-    if ctx.json_output {
-        let output = format_sections_json(&sections, cache)?;
-        println!("{output}");
-    } else if !ctx.quiet {
-        let output = format_sections_table(&sections, cache, ctx.use_colors);
-        print!("{output}");
+    match ctx.output_format {
+        OutputFormat::Json => {
+            let output = format_sections_json(&sections, cache, ctx.compact_json)?;
+            println!("{output}");
+        }
+        OutputFormat::Csv => print!("{}", format_sections_csv(&sections, cache)),
+        OutputFormat::Table if !ctx.quiet => {
+            let output = format_sections_table(&sections, cache, ctx.use_colors);
+            print!("{output}");
+            ctx.print_sync_footer(cache);
+        }
+        OutputFormat::Table => {}
     }
 
     Ok(())
@@ -117,18 +116,10 @@ fn resolve_project_id(cache: &Cache, project: &str) -> Result<String> {
 
 /// Filters sections (excludes deleted, optionally by project).
 fn filter_sections<'a>(cache: &'a Cache, project_id: Option<&str>) -> Vec<&'a Section> {
-    let mut sections: Vec<&Section> = cache
-        .sections
-        .iter()
-        .filter(|s| !s.is_deleted)
-        .filter(|s| {
-            if let Some(pid) = project_id {
-                s.project_id == pid
-            } else {
-                true
-            }
-        })
-        .collect();
+    let mut sections: Vec<&Section> = match project_id {
+        Some(pid) => cache.sections_in_project(pid, true),
+        None => cache.sections.iter().filter(|s| !s.is_deleted).collect(),
+    };
 
     // Sort by section_order for consistent display
     sections.sort_by_key(|s| s.section_order);
@@ -156,6 +147,10 @@ pub struct SectionsAddOptions {
     pub name: String,
     /// Project ID or name (required).
     pub project: String,
+    /// Place the new section before this sibling (name or ID).
+    pub before: Option<String>,
+    /// Place the new section after this sibling (name or ID).
+    pub after: Option<String>,
 }
 
 /// Result of a successful section add operation.
@@ -188,9 +183,7 @@ pub async fn execute_add(
     token: &str,
 ) -> Result<()> {
     // Initialize sync manager (loads cache from disk)
-    let client = TodoistClient::new(token)?;
-    let store = CacheStore::new()?;
-    let mut manager = SyncManager::new(client, store)?;
+    let mut manager = ctx.sync_manager(token)?;
 
     // Resolve project name to ID and extract owned data before mutation
     let (project_id, project_name) = {
@@ -204,19 +197,51 @@ pub async fn execute_add(
         (pid, pname)
     };
 
+    // Work out where the section goes among its siblings, if asked to
+    let placement = match (&opts.before, &opts.after) {
+        (Some(reference), _) => Some((reference, Placement::Before)),
+        (None, Some(reference)) => Some((reference, Placement::After)),
+        (None, None) => None,
+    };
+    let mut commands = Vec::new();
+    let mut section_order = None;
+    if let Some((reference, placement)) = placement {
+        let siblings = sibling_sections(manager.cache(), &project_id);
+        let anchor = resolve_sibling_section(&siblings, reference)?;
+        let (order, moves) = plan_section_order(&siblings, &anchor.id, placement);
+        if !moves.is_empty() {
+            let sections: Vec<_> = moves
+                .iter()
+                .map(|(id, order)| serde_json::json!({ "id": id, "section_order": order }))
+                .collect();
+            commands.push(SyncCommand::new(
+                SyncCommandType::SectionReorder,
+                serde_json::json!({ "sections": sections }),
+            ));
+        }
+        section_order = Some(order);
+    }
+
     // Build the section_add command arguments
     let temp_id = uuid::Uuid::new_v4().to_string();
-    let args = serde_json::json!({
+    let mut args = serde_json::json!({
         "name": opts.name,
         "project_id": project_id,
     });
+    if let Some(order) = section_order {
+        args["section_order"] = serde_json::json!(order);
+    }
 
     // Create the command
-    let command = SyncCommand::with_temp_id(SyncCommandType::SectionAdd, &temp_id, args);
+    commands.push(SyncCommand::with_temp_id(
+        SyncCommandType::SectionAdd,
+        &temp_id,
+        args,
+    ));
 
-    // Execute the command via SyncManager
-    // This sends the command, applies the response to cache, and saves to disk
-    let response = manager.execute_commands(vec![command]).await?;
+    // Execute the commands via SyncManager
+    // This sends the commands, applies the response to cache, and saves to disk
+    let response = manager.execute_commands(commands).await?;
 
     // Check for errors
     if response.has_errors() {
@@ -247,9 +272,11 @@ pub async fn execute_add(
     };
 
     // Output
-    if ctx.json_output {
-        let output = crate::output::format_created_section(&result)?;
+    if ctx.output_format.is_json() {
+        let output = crate::output::format_created_section(&result, ctx.compact_json)?;
         println!("{output}");
+    } else if ctx.output_format == OutputFormat::Csv {
+        print!("{}", crate::output::format_created_section_csv(&result));
     } else if !ctx.quiet {
         if ctx.verbose {
             println!("Created section: {} ({})", result.name, result.id);
@@ -269,6 +296,82 @@ pub async fn execute_add(
     Ok(())
 }
 
+/// Where a new section goes relative to an existing one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Placement {
+    Before,
+    After,
+}
+
+/// Returns the live sections of a project in display order.
+fn sibling_sections<'a>(cache: &'a Cache, project_id: &str) -> Vec<&'a Section> {
+    let mut siblings = cache.sections_in_project(project_id, false);
+    siblings.sort_by(|a, b| a.section_order.cmp(&b.section_order).then(a.id.cmp(&b.id)));
+    siblings
+}
+
+/// Resolves `reference` (ID, unique ID prefix, or name) among `siblings`.
+fn resolve_sibling_section<'a>(siblings: &[&'a Section], reference: &str) -> Result<&'a Section> {
+    if let Some(section) = siblings.iter().find(|s| s.id == reference) {
+        return Ok(section);
+    }
+
+    let prefix_matches: Vec<&Section> = siblings
+        .iter()
+        .copied()
+        .filter(|s| s.id.starts_with(reference))
+        .collect();
+    if prefix_matches.len() == 1 {
+        return Ok(prefix_matches[0]);
+    }
+
+    let name_matches: Vec<&Section> = siblings
+        .iter()
+        .copied()
+        .filter(|s| s.name.eq_ignore_ascii_case(reference))
+        .collect();
+    match name_matches.len() {
+        1 => Ok(name_matches[0]),
+        0 if prefix_matches.is_empty() => Err(CommandError::Config(format!(
+            "Section not found in project: {reference}"
+        ))),
+        _ => Err(CommandError::Config(format!(
+            "Ambiguous section: '{reference}'. Multiple sections match."
+        ))),
+    }
+}
+
+/// Computes the `section_order` for a new section placed next to `anchor_id`.
+///
+/// Sibling orders are renumbered 1..=n around the new section so placement
+/// works even when existing orders are tied or gapless. Returns the new
+/// section's order and the `(id, order)` pairs of siblings that must move.
+fn plan_section_order(
+    siblings: &[&Section],
+    anchor_id: &str,
+    placement: Placement,
+) -> (i32, Vec<(String, i32)>) {
+    let anchor_index = siblings
+        .iter()
+        .position(|s| s.id == anchor_id)
+        .unwrap_or(siblings.len());
+    let insert_at = match placement {
+        Placement::Before => anchor_index,
+        Placement::After => (anchor_index + 1).min(siblings.len()),
+    };
+
+    let mut moves = Vec::new();
+    for (index, section) in siblings.iter().enumerate() {
+        let slot = if index < insert_at { index } else { index + 1 };
+        let order = slot as i32 + 1;
+        if section.section_order != order {
+            moves.push((section.id.clone(), order));
+        }
+    }
+
+    (insert_at as i32 + 1, moves)
+}
+
 // ============================================================================
 // Sections Edit Command
 // ============================================================================
@@ -317,9 +420,7 @@ pub async fn execute_edit(
     }
 
     // Initialize sync manager (loads cache from disk)
-    let client = TodoistClient::new(token)?;
-    let store = CacheStore::new()?;
-    let mut manager = SyncManager::new(client, store)?;
+    let mut manager = ctx.sync_manager(token)?;
 
     // Find the section by ID or prefix and extract owned data before mutation
     let (section_id, section_name) = {
@@ -367,9 +468,11 @@ pub async fn execute_edit(
     };
 
     // Output
-    if ctx.json_output {
-        let output = crate::output::format_edited_section(&result)?;
+    if ctx.output_format.is_json() {
+        let output = crate::output::format_edited_section(&result, ctx.compact_json)?;
         println!("{output}");
+    } else if ctx.output_format == OutputFormat::Csv {
+        print!("{}", crate::output::format_edited_section_csv(&result));
     } else if !ctx.quiet {
         if ctx.verbose {
             println!("Updated section: {} ({})", result.name, result.id);
@@ -456,9 +559,7 @@ pub async fn execute_delete(
     token: &str,
 ) -> Result<()> {
     // Initialize sync manager (loads cache from disk)
-    let client = TodoistClient::new(token)?;
-    let store = CacheStore::new()?;
-    let mut manager = SyncManager::new(client, store)?;
+    let mut manager = ctx.sync_manager(token)?;
 
     // Find the section by ID or prefix and extract owned data before mutation
     let (section_id, section_name) = {
@@ -512,9 +613,11 @@ pub async fn execute_delete(
     };
 
     // Output
-    if ctx.json_output {
-        let output = crate::output::format_deleted_section(&result)?;
+    if ctx.output_format.is_json() {
+        let output = crate::output::format_deleted_section(&result, ctx.compact_json)?;
         println!("{output}");
+    } else if ctx.output_format == OutputFormat::Csv {
+        print!("{}", crate::output::format_deleted_section_csv(&result));
     } else if !ctx.quiet {
         if ctx.verbose {
             println!("Deleted section: {} ({})", result.name, result.id);
@@ -555,6 +658,8 @@ mod tests {
         let opts = SectionsAddOptions {
             name: "Groceries".to_string(),
             project: "Shopping".to_string(),
+            before: None,
+            after: None,
         };
 
         assert_eq!(opts.name, "Groceries");
@@ -751,4 +856,73 @@ mod tests {
             updated_at: None,
         }
     }
+
+    fn ordered_siblings() -> Vec<Section> {
+        ["todo", "doing", "done"]
+            .iter()
+            .enumerate()
+            .map(|(i, name)| Section {
+                section_order: i as i32 + 1,
+                ..make_test_section(&format!("sec-{name}"), name, "project-1")
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_plan_section_order_before() {
+        let sections = ordered_siblings();
+        let siblings: Vec<&Section> = sections.iter().collect();
+
+        let (order, moves) = plan_section_order(&siblings, "sec-doing", Placement::Before);
+        assert_eq!(order, 2);
+        assert_eq!(
+            moves,
+            vec![("sec-doing".to_string(), 3), ("sec-done".to_string(), 4)]
+        );
+    }
+
+    #[test]
+    fn test_plan_section_order_after_last_moves_nothing() {
+        let sections = ordered_siblings();
+        let siblings: Vec<&Section> = sections.iter().collect();
+
+        let (order, moves) = plan_section_order(&siblings, "sec-done", Placement::After);
+        assert_eq!(order, 4);
+        assert!(moves.is_empty());
+    }
+
+    #[test]
+    fn test_plan_section_order_renumbers_tied_orders() {
+        // Sections created without an explicit order all share order 0
+        let cache = make_cache_with_ambiguous_section_ids();
+        let siblings = sibling_sections(&cache, "project-1");
+
+        let (order, moves) = plan_section_order(&siblings, "section-aaa-111", Placement::After);
+        assert_eq!(order, 2);
+        assert_eq!(
+            moves,
+            vec![
+                ("section-aaa-111".to_string(), 1),
+                ("section-aaa-222".to_string(), 3),
+                ("section-bbb-333".to_string(), 4),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_resolve_sibling_section_by_name_or_prefix() {
+        let sections = ordered_siblings();
+        let siblings: Vec<&Section> = sections.iter().collect();
+
+        assert_eq!(
+            resolve_sibling_section(&siblings, "DOING").unwrap().id,
+            "sec-doing"
+        );
+        assert_eq!(
+            resolve_sibling_section(&siblings, "sec-to").unwrap().id,
+            "sec-todo"
+        );
+        assert!(resolve_sibling_section(&siblings, "sec-do").is_err());
+        assert!(resolve_sibling_section(&siblings, "backlog").is_err());
+    }
 }
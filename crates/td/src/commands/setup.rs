@@ -6,14 +6,19 @@
 //! 3. Asks where to store token (keyring, config file, or env var)
 //! 4. Writes config file with chosen settings
 //! 5. Performs initial sync after setup
+//!
+//! `td setup --token <TOKEN>` skips the prompts: it validates the token with
+//! a minimal sync and saves it to the chosen storage backend.
 
 use std::io::{self, IsTerminal};
 
 use dialoguer::{Input, Select};
 use owo_colors::OwoColorize;
-use todoist_cache_rs::{CacheStore, SyncManager};
+use todoist_api_rs::client::TodoistClient;
+use todoist_api_rs::error::{ApiError, Error as ApiClientError};
+use todoist_api_rs::sync::SyncRequest;
 
-use super::config::{get_config_path, load_config, Config};
+use super::config::{get_config_path, load_config_for, Config};
 use super::keyring;
 use super::{CommandContext, CommandError, Result};
 
@@ -41,16 +46,94 @@ impl TokenStorage {
     }
 }
 
+/// Options for the setup command.
+#[derive(Debug)]
+pub struct SetupOptions {
+    /// Token to configure non-interactively (from --token; never from TODOIST_TOKEN).
+    pub token: Option<String>,
+    /// Where to store the token in non-interactive mode.
+    pub storage: TokenStorage,
+}
+
+/// Executes the setup command.
+///
+/// Without a token this runs the interactive wizard. With a token it
+/// validates the token against the API and saves it without prompting.
+///
+/// # Errors
+///
+/// Returns an error if the token is rejected by the API (nothing is saved),
+/// the keyring is unavailable for keyring storage, or the config can't be written.
+pub async fn execute(ctx: &CommandContext, opts: &SetupOptions) -> Result<()> {
+    let Some(ref token) = opts.token else {
+        return run_setup(ctx).await.map(|_| ());
+    };
+
+    let token = token.trim();
+    let client = ctx.client(token)?;
+    let profile = ctx.profile.as_deref();
+    validate_and_save(&client, token, opts.storage, profile).await?;
+
+    let config_path = get_config_path(profile)?;
+    if ctx.output_format.is_json() {
+        let output = serde_json::json!({
+            "status": "configured",
+            "storage": opts.storage.as_str(),
+            "config_path": config_path.display().to_string(),
+        });
+        println!("{}", crate::output::to_json(&output, ctx.compact_json)?);
+    } else if !ctx.quiet {
+        match opts.storage {
+            TokenStorage::Keyring => println!("Token validated and stored in OS keychain."),
+            _ => println!("Token validated and saved to: {}", config_path.display()),
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates the token, then saves it. Nothing is persisted if validation fails.
+async fn validate_and_save(
+    client: &TodoistClient,
+    token: &str,
+    storage: TokenStorage,
+    profile: Option<&str>,
+) -> Result<()> {
+    if storage == TokenStorage::Keyring && !keyring::is_available() {
+        return Err(CommandError::Config(
+            "OS keyring is not available. Use --storage config instead.".to_string(),
+        ));
+    }
+
+    validate_token(client).await?;
+    save_setup_config(token, storage, profile)
+}
+
+/// Checks a token with a minimal sync that only requests the user resource.
+async fn validate_token(client: &TodoistClient) -> Result<()> {
+    let request = SyncRequest::full_sync().with_resource_types(vec!["user".to_string()]);
+    match client.sync(request).await {
+        Ok(_) => Ok(()),
+        Err(ApiClientError::Api(ApiError::Auth { .. })) => Err(CommandError::Config(
+            "Invalid API token: Todoist rejected it. Check the token and try again.".to_string(),
+        )),
+        Err(e) => Err(CommandError::Config(format!(
+            "Token validation failed: {}",
+            e
+        ))),
+    }
+}
+
 /// Checks if this is a first run (no token configured anywhere).
 #[allow(dead_code)]
-pub fn is_first_run(cli_token: Option<&String>) -> bool {
+pub fn is_first_run(cli_token: Option<&String>, profile: Option<&str>) -> bool {
     // If token provided via flag/env, not a first run
     if cli_token.is_some() {
         return false;
     }
 
     // Check config file for token
-    match load_config() {
+    match load_config_for(profile) {
         Ok(config) => config.token.is_none(),
         Err(_) => true, // Config doesn't exist or is invalid
     }
@@ -62,10 +145,7 @@ pub fn is_first_run(cli_token: Option<&String>) -> bool {
 pub async fn run_setup(ctx: &CommandContext) -> Result<String> {
     // Check if we're in a terminal
     if !io::stdin().is_terminal() {
-        return Err(CommandError::Config(
-            "No API token configured. Set TODOIST_TOKEN environment variable or run interactively."
-                .to_string(),
-        ));
+        return Err(CommandError::MissingToken);
     }
 
     // Welcome message
@@ -114,9 +194,7 @@ pub async fn run_setup(ctx: &CommandContext) -> Result<String> {
         println!("Validating token...");
     }
 
-    let client = todoist_api_rs::client::TodoistClient::new(&token)?;
-    let store = CacheStore::new()?;
-    let mut manager = SyncManager::new(client, store)?;
+    let mut manager = ctx.sync_manager(&token)?;
 
     // Try full sync to validate
     match manager.full_sync().await {
@@ -185,12 +263,12 @@ pub async fn run_setup(ctx: &CommandContext) -> Result<String> {
     };
 
     // Save config
-    save_setup_config(&token, storage)?;
+    save_setup_config(&token, storage, ctx.profile.as_deref())?;
 
     // Final message
     if !ctx.quiet {
         println!();
-        let config_path = get_config_path()?;
+        let config_path = get_config_path(ctx.profile.as_deref())?;
         match storage {
             TokenStorage::Keyring => {
                 if ctx.use_colors {
@@ -233,10 +311,10 @@ pub async fn run_setup(ctx: &CommandContext) -> Result<String> {
 }
 
 /// Saves the configuration after setup.
-fn save_setup_config(token: &str, storage: TokenStorage) -> Result<()> {
+fn save_setup_config(token: &str, storage: TokenStorage, profile: Option<&str>) -> Result<()> {
     use std::fs;
 
-    let path = get_config_path()?;
+    let path = get_config_path(profile)?;
 
     // Ensure directory exists
     if let Some(parent) = path.parent() {
@@ -247,7 +325,7 @@ fn save_setup_config(token: &str, storage: TokenStorage) -> Result<()> {
 
     // If using keyring, store the token there
     if storage == TokenStorage::Keyring {
-        keyring::store_token(token)?;
+        keyring::set_token(token, profile)?;
     }
 
     // Build config (don't store token in config if using keyring or env)
@@ -296,7 +374,7 @@ mod tests {
     fn test_is_first_run_with_token_flag() {
         // When token is provided via flag, not a first run
         let token = Some(String::from("test-token"));
-        assert!(!is_first_run(token.as_ref()));
+        assert!(!is_first_run(token.as_ref(), None));
     }
 
     #[test]
@@ -313,7 +391,7 @@ mod tests {
         env::set_var("TD_CONFIG", config_path.to_str().unwrap());
 
         // No token provided, no config file - should be first run
-        assert!(is_first_run(None));
+        assert!(is_first_run(None, None));
 
         // Restore
         if let Some(val) = original {
@@ -342,7 +420,7 @@ mod tests {
         env::set_var("TD_CONFIG", config_path.to_str().unwrap());
 
         // Config has token - not a first run
-        assert!(!is_first_run(None));
+        assert!(!is_first_run(None, None));
 
         // Restore
         if let Some(val) = original {
@@ -365,7 +443,7 @@ mod tests {
         let original = env::var("TD_CONFIG").ok();
         env::set_var("TD_CONFIG", config_path.to_str().unwrap());
 
-        let result = save_setup_config("test-token-12345", TokenStorage::Config);
+        let result = save_setup_config("test-token-12345", TokenStorage::Config, None);
         assert!(result.is_ok());
 
         // Verify file exists and contains token
@@ -394,7 +472,7 @@ mod tests {
         let original = env::var("TD_CONFIG").ok();
         env::set_var("TD_CONFIG", config_path.to_str().unwrap());
 
-        let result = save_setup_config("test-token-12345", TokenStorage::Env);
+        let result = save_setup_config("test-token-12345", TokenStorage::Env, None);
         assert!(result.is_ok());
 
         // Verify file exists but does NOT contain token
@@ -425,7 +503,7 @@ mod tests {
         let original = env::var("TD_CONFIG").ok();
         env::set_var("TD_CONFIG", config_path.to_str().unwrap());
 
-        save_setup_config("test-token", TokenStorage::Config).unwrap();
+        save_setup_config("test-token", TokenStorage::Config, None).unwrap();
 
         // Verify permissions are 0600
         let metadata = fs::metadata(&config_path).unwrap();
@@ -439,4 +517,105 @@ mod tests {
             env::remove_var("TD_CONFIG");
         }
     }
+
+    fn mock_client(base_url: String) -> TodoistClient {
+        todoist_api_rs::client::TodoistClientBuilder::new("test-token-12345678901")
+            .base_url(base_url)
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_validate_and_save_persists_valid_token() {
+        use std::env;
+        use std::fs;
+        use tempfile::TempDir;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/sync"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "sync_token": "token",
+                "full_sync": true,
+                "user": {"id": "user-1"}
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.toml");
+        let original = env::var("TD_CONFIG").ok();
+        env::set_var("TD_CONFIG", config_path.to_str().unwrap());
+
+        let client = mock_client(mock_server.uri());
+        let result = validate_and_save(
+            &client,
+            "test-token-12345678901",
+            TokenStorage::Config,
+            None,
+        )
+        .await;
+        let content = fs::read_to_string(&config_path);
+
+        if let Some(val) = original {
+            env::set_var("TD_CONFIG", val);
+        } else {
+            env::remove_var("TD_CONFIG");
+        }
+
+        assert!(result.is_ok(), "Expected Ok, got: {:?}", result);
+        let content = content.unwrap();
+        assert!(content.contains("token = \"test-token-12345678901\""));
+        assert!(content.contains("token_storage = \"config\""));
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_validate_and_save_rejects_invalid_token() {
+        use std::env;
+        use tempfile::TempDir;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/sync"))
+            .respond_with(ResponseTemplate::new(401).set_body_string("Unauthorized"))
+            .mount(&mock_server)
+            .await;
+
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.toml");
+        let original = env::var("TD_CONFIG").ok();
+        env::set_var("TD_CONFIG", config_path.to_str().unwrap());
+
+        let client = mock_client(mock_server.uri());
+        let result = validate_and_save(
+            &client,
+            "test-token-12345678901",
+            TokenStorage::Config,
+            None,
+        )
+        .await;
+        let persisted = config_path.exists();
+
+        if let Some(val) = original {
+            env::set_var("TD_CONFIG", val);
+        } else {
+            env::remove_var("TD_CONFIG");
+        }
+
+        match result {
+            Err(CommandError::Config(msg)) => assert!(msg.contains("Invalid API token")),
+            other => panic!("Expected invalid token error, got: {:?}", other),
+        }
+        assert!(
+            !persisted,
+            "config must not be written for an invalid token"
+        );
+    }
 }
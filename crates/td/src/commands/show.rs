@@ -2,21 +2,30 @@
 //!
 //! Displays detailed information about a task from the local cache.
 
+use chrono::{DateTime, Utc};
 use todoist_api_rs::sync::{Item, Note, Reminder};
-use todoist_cache_rs::{Cache, CacheStore, SyncManager};
+use todoist_cache_rs::Cache;
 
-use super::{CommandContext, CommandError, Result};
-use crate::output::{format_item_details_json, format_item_details_table};
+use super::{ensure_fresh, CommandContext, CommandError, Result};
+use crate::output::{
+    format_item_details_json, format_item_details_table, format_items_csv, OutputFormat,
+};
 
 /// Options for the show command.
 #[derive(Debug)]
 pub struct ShowOptions {
-    /// Task ID (full ID or prefix).
+    /// Task ID, ID prefix, or content text.
     pub task_id: String,
     /// Include comments.
     pub comments: bool,
     /// Include reminders.
     pub reminders: bool,
+    /// Include comments, reminders, and the full subtask tree.
+    pub full: bool,
+    /// Print the cached item as-is, serialized as JSON.
+    pub raw: bool,
+    /// Include a timeline of the task's history.
+    pub history: bool,
 }
 
 /// Result data for the show command.
@@ -33,7 +42,7 @@ pub struct ShowResult<'a> {
     pub comments: Vec<&'a Note>,
     /// Reminders for this task.
     pub reminders: Vec<&'a Reminder>,
-    /// Subtasks of this task.
+    /// Subtasks of this task (all descendants, depth-first, with `full`).
     pub subtasks: Vec<&'a Item>,
     /// Assignee name (resolved from responsible_uid).
     pub assignee_name: Option<String>,
@@ -41,6 +50,30 @@ pub struct ShowResult<'a> {
     pub assignee_email: Option<String>,
     /// Name of the user who assigned the task.
     pub assigned_by_name: Option<String>,
+    /// Timeline of the task's history, oldest first (with `history`).
+    pub history: Vec<HistoryEvent<'a>>,
+}
+
+/// One entry in a task's history timeline.
+#[derive(Debug)]
+pub struct HistoryEvent<'a> {
+    /// When it happened, as stored in the cache.
+    pub timestamp: &'a str,
+    /// What happened.
+    pub kind: HistoryEventKind<'a>,
+}
+
+/// The kind of a [`HistoryEvent`].
+#[derive(Debug)]
+pub enum HistoryEventKind<'a> {
+    /// The task was created (`added_at`).
+    Created,
+    /// The task was last changed (`updated_at`).
+    Updated,
+    /// The task was completed (`completed_at`).
+    Completed,
+    /// A comment was posted.
+    Comment(&'a Note),
 }
 
 /// Executes the show command.
@@ -56,23 +89,51 @@ pub struct ShowResult<'a> {
 /// Returns an error if syncing fails or if the task is not found.
 pub async fn execute(ctx: &CommandContext, opts: &ShowOptions, token: &str) -> Result<()> {
     // Initialize sync manager
-    let client = todoist_api_rs::client::TodoistClient::new(token)?;
-    let store = CacheStore::new()?;
-    let mut manager = SyncManager::new(client, store)?;
-
-    // Only sync if explicitly requested with --sync flag
-    if ctx.sync_first {
-        if ctx.verbose {
-            eprintln!("Syncing with Todoist...");
-        }
-        manager.sync().await?;
-    }
+    let mut manager = ctx.sync_manager(token)?;
+
+    ensure_fresh(ctx, &mut manager).await?;
+
+    // Resolve task by ID, ID prefix, or content (with auto-sync fallback)
+    let item_id = manager
+        .resolve_item_arg(&opts.task_id, None)
+        .await
+        .map_err(|e| CommandError::Config(e.to_string()))?
+        .id
+        .clone();
 
     let cache = manager.cache();
+    let item = cache
+        .find_item(&item_id)
+        .ok_or_else(|| CommandError::Config(format!("Task not found: {}", opts.task_id)))?;
 
-    // Find the task by ID or prefix
-    let item = find_item_by_id_or_prefix(cache, &opts.task_id)?;
+    if opts.raw {
+        println!("{}", format_item_raw_json(item, ctx.compact_json)?);
+        return Ok(());
+    }
 
+    let result = build_show_result(cache, item, opts);
+
+    // Output
+    match ctx.output_format {
+        OutputFormat::Json => {
+            let output = format_item_details_json(&result, ctx.compact_json)?;
+            println!("{output}");
+        }
+        // Same columns as `td list`, so rows from both can be combined
+        OutputFormat::Csv => print!("{}", format_items_csv(&[item], cache)),
+        OutputFormat::Table if !ctx.quiet => {
+            let output = format_item_details_table(&result, ctx.use_colors, ctx.date_style);
+            print!("{output}");
+            ctx.print_sync_footer(cache);
+        }
+        OutputFormat::Table => {}
+    }
+
+    Ok(())
+}
+
+/// Gathers the related data shown alongside a task.
+fn build_show_result<'a>(cache: &'a Cache, item: &'a Item, opts: &ShowOptions) -> ShowResult<'a> {
     // Get related data
     let project_name = cache
         .projects
@@ -89,7 +150,7 @@ pub async fn execute(ctx: &CommandContext, opts: &ShowOptions, token: &str) -> R
     });
 
     // Get comments for this task if requested
-    let comments: Vec<&Note> = if opts.comments {
+    let comments: Vec<&Note> = if opts.comments || opts.full {
         cache
             .notes
             .iter()
@@ -100,7 +161,7 @@ pub async fn execute(ctx: &CommandContext, opts: &ShowOptions, token: &str) -> R
     };
 
     // Get reminders for this task if requested
-    let reminders: Vec<&Reminder> = if opts.reminders {
+    let reminders: Vec<&Reminder> = if opts.reminders || opts.full {
         cache
             .reminders
             .iter()
@@ -110,12 +171,12 @@ pub async fn execute(ctx: &CommandContext, opts: &ShowOptions, token: &str) -> R
         vec![]
     };
 
-    // Get subtasks
-    let subtasks: Vec<&Item> = cache
-        .items
-        .iter()
-        .filter(|i| i.parent_id.as_ref() == Some(&item.id) && !i.is_deleted && !i.checked)
-        .collect();
+    // Get subtasks (direct children, or the whole tree with --full)
+    let subtasks: Vec<&Item> = if opts.full {
+        collect_subtask_tree(cache, &item.id)
+    } else {
+        open_children(cache, &item.id).collect()
+    };
 
     // Resolve assignee
     let (assignee_name, assignee_email) = item
@@ -156,7 +217,13 @@ pub async fn execute(ctx: &CommandContext, opts: &ShowOptions, token: &str) -> R
         }
     });
 
-    let result = ShowResult {
+    let history = if opts.history {
+        build_history(cache, item)
+    } else {
+        vec![]
+    };
+
+    ShowResult {
         item,
         project_name,
         section_name,
@@ -167,57 +234,86 @@ pub async fn execute(ctx: &CommandContext, opts: &ShowOptions, token: &str) -> R
         assignee_name,
         assignee_email,
         assigned_by_name,
+        history,
+    }
+}
+
+/// Builds a task's history timeline from cached timestamps, oldest first.
+///
+/// The cache only keeps the latest `updated_at`, so there is at most one
+/// update event, and none when it equals the creation time. Events with a
+/// missing or unparseable timestamp are left out. Events at the same instant
+/// keep the order created, comments, updated, completed.
+fn build_history<'a>(cache: &'a Cache, item: &'a Item) -> Vec<HistoryEvent<'a>> {
+    let parse = |ts: &str| DateTime::parse_from_rfc3339(ts).map(|dt| dt.with_timezone(&Utc));
+
+    let mut events: Vec<(DateTime<Utc>, HistoryEvent<'a>)> = Vec::new();
+    let mut push = |timestamp: Option<&'a String>, kind: HistoryEventKind<'a>| {
+        if let Some(timestamp) = timestamp {
+            if let Ok(at) = parse(timestamp) {
+                events.push((at, HistoryEvent { timestamp, kind }));
+            }
+        }
     };
 
-    // Output
-    if ctx.json_output {
-        let output = format_item_details_json(&result)?;
-        println!("{output}");
-    } else if !ctx.quiet {
-        let output = format_item_details_table(&result, ctx.use_colors);
-        print!("{output}");
+    push(item.added_at.as_ref(), HistoryEventKind::Created);
+    for note in cache
+        .notes
+        .iter()
+        .filter(|n| n.item_id == item.id && !n.is_deleted)
+    {
+        push(note.posted_at.as_ref(), HistoryEventKind::Comment(note));
+    }
+    let created = item.added_at.as_deref().and_then(|ts| parse(ts).ok());
+    let updated = item.updated_at.as_deref().and_then(|ts| parse(ts).ok());
+    if updated.is_some() && updated != created {
+        push(item.updated_at.as_ref(), HistoryEventKind::Updated);
+    }
+    if item.checked {
+        push(item.completed_at.as_ref(), HistoryEventKind::Completed);
     }
 
-    Ok(())
+    events.sort_by_key(|(at, _)| *at);
+    events.into_iter().map(|(_, event)| event).collect()
 }
 
-/// Finds an item by full ID or unique prefix.
-fn find_item_by_id_or_prefix<'a>(cache: &'a Cache, id: &str) -> Result<&'a Item> {
-    // First try exact match
-    if let Some(item) = cache.items.iter().find(|i| i.id == id && !i.is_deleted) {
-        return Ok(item);
-    }
-
-    // Try prefix match
-    let matches: Vec<&Item> = cache
+/// Returns the open, non-deleted direct children of a task.
+fn open_children<'a>(cache: &'a Cache, parent_id: &'a str) -> impl Iterator<Item = &'a Item> {
+    cache
         .items
         .iter()
-        .filter(|i| i.id.starts_with(id) && !i.is_deleted)
-        .collect();
-
-    match matches.len() {
-        0 => Err(CommandError::Config(format!("Task not found: {id}"))),
-        1 => Ok(matches[0]),
-        _ => {
-            // Ambiguous prefix - provide helpful error message
-            let mut msg =
-                format!("Ambiguous task ID \"{id}\"\n\nMultiple tasks match this prefix:");
-            for item in matches.iter().take(5) {
-                let prefix = &item.id[..6.min(item.id.len())];
-                msg.push_str(&format!("\n  {}  {}", prefix, item.content));
-            }
-            if matches.len() > 5 {
-                msg.push_str(&format!("\n  ... and {} more", matches.len() - 5));
-            }
-            msg.push_str("\n\nPlease use a longer prefix.");
-            Err(CommandError::Config(msg))
+        .filter(move |i| i.parent_id.as_deref() == Some(parent_id) && !i.is_deleted && !i.checked)
+}
+
+/// Returns every open descendant of a task, depth-first, so each subtask
+/// directly follows its parent.
+fn collect_subtask_tree<'a>(cache: &'a Cache, root_id: &'a str) -> Vec<&'a Item> {
+    let mut out = Vec::new();
+    let mut stack: Vec<&Item> = open_children(cache, root_id).collect();
+    stack.reverse();
+    // Bound the walk by the number of items to stay safe on corrupt (cyclic) caches
+    while let Some(item) = stack.pop() {
+        if out.len() > cache.items.len() {
+            break;
         }
+        out.push(item);
+        let mut children: Vec<&Item> = open_children(cache, &item.id).collect();
+        children.reverse();
+        stack.extend(children);
     }
+    out
+}
+
+/// Serializes the cached item with all of its fields, exactly as stored.
+fn format_item_raw_json(item: &Item, compact: bool) -> Result<String> {
+    Ok(crate::output::to_json(item, compact)?)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::output::DateStyle;
+    use crate::test_support;
 
     #[test]
     fn test_show_options_defaults() {
@@ -225,6 +321,9 @@ mod tests {
             task_id: "abc123".to_string(),
             comments: false,
             reminders: false,
+            full: false,
+            raw: false,
+            history: false,
         };
 
         assert_eq!(opts.task_id, "abc123");
@@ -238,55 +337,258 @@ mod tests {
             task_id: "abc123def456".to_string(),
             comments: true,
             reminders: true,
+            full: true,
+            raw: true,
+            history: true,
         };
 
         assert_eq!(opts.task_id, "abc123def456");
         assert!(opts.comments);
         assert!(opts.reminders);
+        assert!(opts.raw);
     }
 
+    // Note: Tests for item lookup are in SyncManager tests
+    // (resolve_item_arg covers exact match, prefix match, content substring,
+    // not found, ambiguous, and completion status filtering)
+
     #[test]
-    fn test_find_item_by_id_or_prefix_exact_match() {
-        let cache = make_test_cache();
-        let result = find_item_by_id_or_prefix(&cache, "item-123-abc");
-        assert!(result.is_ok());
-        assert_eq!(result.unwrap().id, "item-123-abc");
+    fn test_format_item_raw_json_round_trips() {
+        let mut item = make_test_item("item-123-abc", "Test task");
+        item.child_order = 7;
+        item.day_order = 3;
+        item.added_by_uid = Some("user-1".to_string());
+        item.labels = vec!["work".to_string()];
+
+        let output = format_item_raw_json(&item, false).unwrap();
+        let parsed: Item = serde_json::from_str(&output).unwrap();
+        assert_eq!(parsed, item);
+
+        let value: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(value["child_order"], 7);
+        assert_eq!(value["day_order"], 3);
+        assert_eq!(value["added_by_uid"], "user-1");
+    }
+
+    fn make_task_tree_cache() -> Cache {
+        let mut child = make_test_item("child", "Child");
+        child.parent_id = Some("root".to_string());
+        let mut grandchild = make_test_item("grandchild", "Grandchild");
+        grandchild.parent_id = Some("child".to_string());
+        let note: Note = serde_json::from_value(serde_json::json!({
+            "id": "note-1",
+            "item_id": "root",
+            "content": "A comment",
+        }))
+        .unwrap();
+        let reminder: Reminder = serde_json::from_value(serde_json::json!({
+            "id": "rem-1",
+            "item_id": "root",
+            "type": "relative",
+            "minute_offset": 30,
+        }))
+        .unwrap();
+        Cache::with_data(
+            "test".to_string(),
+            None,
+            None,
+            vec![make_test_item("root", "Root"), child, grandchild],
+            vec![],
+            vec![],
+            vec![],
+            vec![note],
+            vec![],
+            vec![reminder],
+            vec![],
+            None,
+        )
+    }
+
+    fn show_options(full: bool) -> ShowOptions {
+        ShowOptions {
+            task_id: "root".to_string(),
+            comments: false,
+            reminders: false,
+            full,
+            raw: false,
+            history: false,
+        }
     }
 
     #[test]
-    fn test_find_item_by_id_or_prefix_unique_prefix() {
-        let cache = make_test_cache();
-        let result = find_item_by_id_or_prefix(&cache, "item-123");
-        assert!(result.is_ok());
-        assert_eq!(result.unwrap().id, "item-123-abc");
+    fn test_build_show_result_full_includes_everything() {
+        let cache = make_task_tree_cache();
+        let item = cache.items.iter().find(|i| i.id == "root").unwrap();
+        let result = build_show_result(&cache, item, &show_options(true));
+
+        assert_eq!(result.comments.len(), 1);
+        assert_eq!(result.reminders.len(), 1);
+        let subtask_ids: Vec<&str> = result.subtasks.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(subtask_ids, vec!["child", "grandchild"]);
+
+        let json: serde_json::Value =
+            serde_json::from_str(&format_item_details_json(&result, false).unwrap()).unwrap();
+        assert_eq!(json["comments"].as_array().unwrap().len(), 1);
+        assert_eq!(json["reminders"].as_array().unwrap().len(), 1);
+        assert_eq!(json["subtasks"][1]["parent_id"], "child");
+        assert_eq!(
+            json["url"],
+            format!("https://todoist.com/app/task/{}", result.item.id)
+        );
     }
 
     #[test]
-    fn test_find_item_by_id_or_prefix_not_found() {
+    fn test_format_item_details_table_keeps_multiline_content() {
+        let item = make_test_item("multi", "First line\nsecond line");
         let cache = make_test_cache();
-        let result = find_item_by_id_or_prefix(&cache, "nonexistent");
-        assert!(result.is_err());
-        let err = result.unwrap_err().to_string();
-        assert!(err.contains("Task not found"));
+        let result = build_show_result(&cache, &item, &show_options(false));
+
+        let output = format_item_details_table(&result, false, DateStyle::Relative);
+        assert!(output.contains("Task: First line\nsecond line\n"));
     }
 
     #[test]
-    fn test_find_item_by_id_or_prefix_ambiguous() {
-        let cache = make_cache_with_ambiguous_ids();
-        let result = find_item_by_id_or_prefix(&cache, "item-");
-        assert!(result.is_err());
-        let err = result.unwrap_err().to_string();
-        assert!(err.contains("Ambiguous"));
+    fn test_build_show_result_default_only_direct_subtasks() {
+        let cache = make_task_tree_cache();
+        let item = cache.items.iter().find(|i| i.id == "root").unwrap();
+        let result = build_show_result(&cache, item, &show_options(false));
+
+        assert!(result.comments.is_empty());
+        assert!(result.reminders.is_empty());
+        let subtask_ids: Vec<&str> = result.subtasks.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(subtask_ids, vec!["child"]);
     }
 
     #[test]
-    fn test_find_item_by_id_or_prefix_ignores_deleted() {
+    fn test_show_assignee_resolution() {
         let mut cache = make_test_cache();
-        // Mark the item as deleted
-        cache.items[0].is_deleted = true;
+        cache.collaborators = vec![todoist_api_rs::sync::Collaborator {
+            email: Some("alice@example.com".to_string()),
+            ..test_support::collaborator("u-alice", Some("Alice"))
+        }];
+
+        let mut item = make_test_item("assigned", "Shared task");
+        item.responsible_uid = Some("u-alice".to_string());
+        let result = build_show_result(&cache, &item, &show_options(false));
+        let output = format_item_details_table(&result, false, DateStyle::Relative);
+        assert!(output.contains("Assigned to: Alice (alice@example.com)\n"));
+
+        // Unknown uids show the raw id
+        item.responsible_uid = Some("u-gone".to_string());
+        let result = build_show_result(&cache, &item, &show_options(false));
+        let output = format_item_details_table(&result, false, DateStyle::Relative);
+        assert!(output.contains("Assigned to: u-gone\n"));
+
+        item.responsible_uid = None;
+        let result = build_show_result(&cache, &item, &show_options(false));
+        let output = format_item_details_table(&result, false, DateStyle::Relative);
+        assert!(!output.contains("Assigned to"));
+    }
 
-        let result = find_item_by_id_or_prefix(&cache, "item-123");
-        assert!(result.is_err());
+    fn make_history_cache() -> Cache {
+        let note = |id: &str, posted_at: &str| -> Note {
+            serde_json::from_value(serde_json::json!({
+                "id": id,
+                "item_id": "item-123-abc",
+                "content": format!("Comment {id}"),
+                "posted_at": posted_at,
+            }))
+            .unwrap()
+        };
+        let mut cache = make_test_cache();
+        let item = &mut cache.items[0];
+        item.added_at = Some("2025-01-10T09:00:00Z".to_string());
+        item.updated_at = Some("2025-01-16T09:00:00Z".to_string());
+        item.completed_at = Some("2025-01-15T09:00:00Z".to_string());
+        item.checked = true;
+        // Stored out of order
+        cache.notes = vec![
+            note("late", "2025-01-14T09:00:00Z"),
+            note("early", "2025-01-11T09:00:00Z"),
+        ];
+        cache
+    }
+
+    fn history_names(result: &ShowResult) -> Vec<String> {
+        result
+            .history
+            .iter()
+            .map(|event| match event.kind {
+                HistoryEventKind::Created => "created".to_string(),
+                HistoryEventKind::Updated => "updated".to_string(),
+                HistoryEventKind::Completed => "completed".to_string(),
+                HistoryEventKind::Comment(note) => format!("comment:{}", note.id),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_history_orders_events_chronologically() {
+        let cache = make_history_cache();
+        let item = &cache.items[0];
+        let opts = ShowOptions {
+            history: true,
+            ..show_options(false)
+        };
+        let result = build_show_result(&cache, item, &opts);
+
+        assert_eq!(
+            history_names(&result),
+            vec![
+                "created",
+                "comment:early",
+                "comment:late",
+                "completed",
+                "updated"
+            ]
+        );
+
+        let json: serde_json::Value =
+            serde_json::from_str(&format_item_details_json(&result, false).unwrap()).unwrap();
+        let events: Vec<&str> = json["history"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|e| e["event"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            events,
+            vec!["created", "comment", "comment", "completed", "updated"]
+        );
+        assert_eq!(json["history"][1]["comment_id"], "early");
+        assert_eq!(json["history"][0]["at"], "2025-01-10T09:00:00Z");
+
+        let output = format_item_details_table(&result, false, DateStyle::Relative);
+        let history = &output[output.find("History:\n").expect("history section")..];
+        let early = history.find("Comment: Comment early").unwrap();
+        let late = history.find("Comment: Comment late").unwrap();
+        assert!(history.find("Created").unwrap() < early);
+        assert!(early < late);
+        assert!(late < history.find("Completed").unwrap());
+        assert!(history.find("Completed").unwrap() < history.find("Updated").unwrap());
+    }
+
+    #[test]
+    fn test_history_skips_redundant_and_missing_events() {
+        let mut cache = make_history_cache();
+        cache.notes.clear();
+        let item = &mut cache.items[0];
+        // Never edited since creation, and reopened after completion
+        item.updated_at = item.added_at.clone();
+        item.checked = false;
+        let item = &cache.items[0];
+        let opts = ShowOptions {
+            history: true,
+            ..show_options(false)
+        };
+        let result = build_show_result(&cache, item, &opts);
+        assert_eq!(history_names(&result), vec!["created"]);
+
+        // Without --history there is no timeline
+        let result = build_show_result(&cache, item, &show_options(false));
+        assert!(result.history.is_empty());
+        let output = format_item_details_table(&result, false, DateStyle::Relative);
+        assert!(!output.contains("History:"));
     }
 
     // Helper function to create a test cache
@@ -307,27 +609,6 @@ mod tests {
         )
     }
 
-    fn make_cache_with_ambiguous_ids() -> Cache {
-        Cache::with_data(
-            "test".to_string(),
-            None,
-            None,
-            vec![
-                make_test_item("item-aaa-111", "Task 1"),
-                make_test_item("item-aaa-222", "Task 2"),
-                make_test_item("item-bbb-333", "Task 3"),
-            ],
-            vec![],
-            vec![],
-            vec![],
-            vec![],
-            vec![],
-            vec![],
-            vec![],
-            None,
-        )
-    }
-
     fn make_test_item(id: &str, content: &str) -> todoist_api_rs::sync::Item {
         todoist_api_rs::sync::Item {
             id: id.to_string(),
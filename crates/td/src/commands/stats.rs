@@ -0,0 +1,668 @@
+//! Stats command implementation.
+//!
+//! Shows task statistics computed from the local cache. With `--server`,
+//! also fetches completion history from the Todoist stats endpoint, since
+//! the Sync API only returns active tasks and local completion counts are
+//! incomplete.
+
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::{Local, NaiveDate};
+use todoist_api_rs::stats::ProductivityStats;
+use todoist_cache_rs::Cache;
+
+use super::labels::label_usage;
+use super::{ensure_fresh, CommandContext, Result};
+use crate::output::helpers::format_fields_csv;
+use crate::output::OutputFormat;
+
+/// Options for the stats command.
+#[derive(Debug)]
+pub struct StatsOptions {
+    /// Also fetch completion stats from the server.
+    pub server: bool,
+    /// Break active task counts down by project.
+    pub by_project: bool,
+    /// Break active task counts down by label.
+    pub by_label: bool,
+}
+
+/// Statistics computed purely from the local cache.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CacheStats {
+    /// Number of active (not completed, not deleted) tasks.
+    pub active_tasks: usize,
+    /// Number of completed tasks still present in the cache.
+    pub completed_tasks: usize,
+    /// Number of active tasks that are overdue.
+    pub overdue: usize,
+    /// Number of active tasks due today.
+    pub due_today: usize,
+    /// Number of active tasks without a due date.
+    pub no_due_date: usize,
+    /// Active task counts by priority, indexed p1..p4.
+    pub by_priority: [usize; 4],
+    /// Number of active (non-archived) projects.
+    pub projects: usize,
+    /// Number of labels.
+    pub labels: usize,
+    /// Number of sections.
+    pub sections: usize,
+    /// Active task counts per project name, highest count first.
+    pub by_project: Vec<(String, usize)>,
+    /// Active task counts per label, highest count first. A task with
+    /// several labels is counted under each of them.
+    pub by_label: Vec<(String, usize)>,
+}
+
+/// Completion statistics derived from the server stats endpoint.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServerStats {
+    /// Tasks completed today.
+    pub completed_today: i64,
+    /// Tasks completed in the current week.
+    pub completed_this_week: i64,
+    /// Tasks completed, all time.
+    pub completed_total: i64,
+    /// Karma score.
+    pub karma: Option<f64>,
+    /// Karma trend ("up", "down", or "-").
+    pub karma_trend: Option<String>,
+    /// Daily completed-task goal.
+    pub daily_goal: Option<i32>,
+    /// Current daily goal streak, in days.
+    pub current_streak: Option<i32>,
+}
+
+impl ServerStats {
+    /// Builds server stats from the API response, using `today` to pick the
+    /// current day's completion count.
+    pub fn from_productivity_stats(stats: &ProductivityStats, today: NaiveDate) -> Self {
+        let goals = stats.goals.as_ref();
+        Self {
+            completed_today: stats.completed_on(&today.format("%Y-%m-%d").to_string()),
+            completed_this_week: stats.completed_this_week(),
+            completed_total: stats.completed_count,
+            karma: stats.karma,
+            karma_trend: stats.karma_trend.clone(),
+            daily_goal: goals.map(|g| g.daily_goal),
+            current_streak: goals
+                .and_then(|g| g.current_daily_streak.as_ref())
+                .map(|s| s.count),
+        }
+    }
+}
+
+/// Executes the stats command.
+///
+/// # Arguments
+///
+/// * `ctx` - Command context with output settings
+/// * `opts` - Stats command options
+/// * `token` - API token
+///
+/// # Errors
+///
+/// Returns an error if syncing fails or the server stats request fails.
+pub async fn execute(ctx: &CommandContext, opts: &StatsOptions, token: &str) -> Result<()> {
+    // Initialize sync manager
+    let mut manager = ctx.sync_manager(token)?;
+
+    // Sync if needed
+    ensure_fresh(ctx, &mut manager).await?;
+
+    let today = Local::now().date_naive();
+    let local = compute_cache_stats(manager.cache(), today);
+    let breakdowns = Breakdowns {
+        by_project: opts.by_project,
+        by_label: opts.by_label,
+    };
+
+    let server = if opts.server {
+        if ctx.verbose {
+            eprintln!("Fetching productivity stats from Todoist...");
+        }
+        let stats = manager.client().get_productivity_stats().await?;
+        Some(ServerStats::from_productivity_stats(&stats, today))
+    } else {
+        None
+    };
+
+    // Output
+    match ctx.output_format {
+        OutputFormat::Json => {
+            let output = format_stats_json(&local, server.as_ref(), breakdowns, ctx.compact_json)?;
+            println!("{output}");
+        }
+        OutputFormat::Csv => print!("{}", format_stats_csv(&local, server.as_ref(), breakdowns)?),
+        OutputFormat::Table if !ctx.quiet => {
+            let output = format_stats_table(&local, server.as_ref(), breakdowns, ctx.use_colors);
+            print!("{output}");
+        }
+        OutputFormat::Table => {}
+    }
+
+    Ok(())
+}
+
+/// Which breakdowns to include in the output.
+#[derive(Debug, Clone, Copy, Default)]
+struct Breakdowns {
+    by_project: bool,
+    by_label: bool,
+}
+
+/// Turns counts into a list sorted by descending count, then by name.
+fn sorted_counts(counts: HashMap<String, usize>) -> Vec<(String, usize)> {
+    let mut counts: Vec<(String, usize)> = counts.into_iter().collect();
+    counts.sort_by(|(a_name, a), (b_name, b)| {
+        b.cmp(a)
+            .then_with(|| a_name.to_lowercase().cmp(&b_name.to_lowercase()))
+    });
+    counts
+}
+
+/// Collects counts into a JSON-friendly map. Two projects can share a name,
+/// so counts for the same key are summed rather than overwritten.
+fn counts_map(counts: &[(String, usize)]) -> BTreeMap<String, usize> {
+    let mut map = BTreeMap::new();
+    for (name, count) in counts {
+        *map.entry(name.clone()).or_default() += count;
+    }
+    map
+}
+
+/// Computes task statistics from the cache.
+pub fn compute_cache_stats(cache: &Cache, today: NaiveDate) -> CacheStats {
+    let mut stats = CacheStats {
+        active_tasks: 0,
+        completed_tasks: 0,
+        overdue: 0,
+        due_today: 0,
+        no_due_date: 0,
+        by_priority: [0; 4],
+        projects: cache
+            .projects
+            .iter()
+            .filter(|p| !p.is_deleted && !p.is_archived)
+            .count(),
+        labels: cache.labels.iter().filter(|l| !l.is_deleted).count(),
+        sections: cache.sections.iter().filter(|s| !s.is_deleted).count(),
+        by_project: Vec::new(),
+        by_label: Vec::new(),
+    };
+    let mut by_project: HashMap<String, usize> = HashMap::new();
+
+    for item in cache.items.iter().filter(|i| !i.is_deleted) {
+        if item.checked {
+            stats.completed_tasks += 1;
+            continue;
+        }
+
+        stats.active_tasks += 1;
+
+        // API priority 4 = p1 (highest), 1 = p4 (lowest)
+        let index = (4 - item.priority.clamp(1, 4)) as usize;
+        stats.by_priority[index] += 1;
+
+        let project_name = cache
+            .find_project(&item.project_id)
+            .map_or(item.project_id.as_str(), |p| p.name.as_str());
+        *by_project.entry(project_name.to_string()).or_default() += 1;
+
+        let Some(due) = &item.due else {
+            stats.no_due_date += 1;
+            continue;
+        };
+
+        if let Ok(due_date) = NaiveDate::parse_from_str(&due.date, "%Y-%m-%d") {
+            if due_date < today {
+                stats.overdue += 1;
+            } else if due_date == today {
+                stats.due_today += 1;
+            }
+        }
+    }
+
+    stats.by_project = sorted_counts(by_project);
+    stats.by_label = sorted_counts(
+        label_usage(cache)
+            .into_iter()
+            .map(|(name, count)| (name.to_string(), count))
+            .collect(),
+    );
+    stats
+}
+
+/// Formats stats as JSON, with local and server figures kept separate.
+fn format_stats_json(
+    local: &CacheStats,
+    server: Option<&ServerStats>,
+    breakdowns: Breakdowns,
+    compact: bool,
+) -> std::result::Result<String, serde_json::Error> {
+    crate::output::to_json(&stats_report(local, server, breakdowns), compact)
+}
+
+/// Formats stats as `field,value` CSV rows named after the JSON fields
+/// (`local.active_tasks`, `server.karma`, ...).
+fn format_stats_csv(
+    local: &CacheStats,
+    server: Option<&ServerStats>,
+    breakdowns: Breakdowns,
+) -> std::result::Result<String, serde_json::Error> {
+    format_fields_csv(&stats_report(local, server, breakdowns))
+}
+
+/// Builds the serializable stats report shared by the JSON and CSV output.
+fn stats_report(
+    local: &CacheStats,
+    server: Option<&ServerStats>,
+    breakdowns: Breakdowns,
+) -> impl serde::Serialize {
+    use serde::Serialize;
+
+    #[derive(Serialize)]
+    struct StatsOutput {
+        local: LocalOutput,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        server: Option<ServerOutput>,
+    }
+
+    #[derive(Serialize)]
+    struct LocalOutput {
+        active_tasks: usize,
+        completed_tasks: usize,
+        overdue: usize,
+        due_today: usize,
+        no_due_date: usize,
+        by_priority: PriorityOutput,
+        projects: usize,
+        labels: usize,
+        sections: usize,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        by_project: Option<BTreeMap<String, usize>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        by_label: Option<BTreeMap<String, usize>>,
+    }
+
+    #[derive(Serialize)]
+    struct PriorityOutput {
+        p1: usize,
+        p2: usize,
+        p3: usize,
+        p4: usize,
+    }
+
+    #[derive(Serialize)]
+    struct ServerOutput {
+        completed_today: i64,
+        completed_this_week: i64,
+        completed_total: i64,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        karma: Option<f64>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        karma_trend: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        daily_goal: Option<i32>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        current_streak: Option<i32>,
+    }
+
+    StatsOutput {
+        local: LocalOutput {
+            active_tasks: local.active_tasks,
+            completed_tasks: local.completed_tasks,
+            overdue: local.overdue,
+            due_today: local.due_today,
+            no_due_date: local.no_due_date,
+            by_priority: PriorityOutput {
+                p1: local.by_priority[0],
+                p2: local.by_priority[1],
+                p3: local.by_priority[2],
+                p4: local.by_priority[3],
+            },
+            projects: local.projects,
+            labels: local.labels,
+            sections: local.sections,
+            by_project: breakdowns.by_project.then(|| counts_map(&local.by_project)),
+            by_label: breakdowns.by_label.then(|| counts_map(&local.by_label)),
+        },
+        server: server.map(|s| ServerOutput {
+            completed_today: s.completed_today,
+            completed_this_week: s.completed_this_week,
+            completed_total: s.completed_total,
+            karma: s.karma,
+            karma_trend: s.karma_trend.clone(),
+            daily_goal: s.daily_goal,
+            current_streak: s.current_streak,
+        }),
+    }
+}
+
+/// Formats stats as a human-readable table.
+fn format_stats_table(
+    local: &CacheStats,
+    server: Option<&ServerStats>,
+    breakdowns: Breakdowns,
+    use_colors: bool,
+) -> String {
+    use owo_colors::OwoColorize;
+
+    let mut output = String::new();
+
+    let heading = |text: &str| -> String {
+        if use_colors {
+            format!("{}\n", text.bold())
+        } else {
+            format!("{}\n", text)
+        }
+    };
+
+    // Local figures
+    output.push_str(&heading("Tasks (local cache)"));
+    output.push_str(&format!("  Active:       {}\n", local.active_tasks));
+    output.push_str(&format!("  Overdue:      {}\n", local.overdue));
+    output.push_str(&format!("  Due today:    {}\n", local.due_today));
+    output.push_str(&format!("  No due date:  {}\n", local.no_due_date));
+    output.push_str(&format!(
+        "  By priority:  p1 {}, p2 {}, p3 {}, p4 {}\n",
+        local.by_priority[0], local.by_priority[1], local.by_priority[2], local.by_priority[3]
+    ));
+    output.push_str(&format!("  Completed:    {}", local.completed_tasks));
+    if server.is_none() {
+        output.push_str(" (cache only; use --server for full history)");
+    }
+    output.push('\n');
+    output.push_str(&format!(
+        "  Projects: {}  Labels: {}  Sections: {}\n",
+        local.projects, local.labels, local.sections
+    ));
+
+    // Breakdowns
+    let mut breakdown = |title: &str, counts: &[(String, usize)]| {
+        output.push('\n');
+        output.push_str(&heading(title));
+        if counts.is_empty() {
+            output.push_str("  (none)\n");
+        }
+        for (name, count) in counts {
+            output.push_str(&format!("  {:>5}  {}\n", count, name));
+        }
+    };
+    if breakdowns.by_project {
+        breakdown("Active tasks by project", &local.by_project);
+    }
+    if breakdowns.by_label {
+        breakdown("Active tasks by label", &local.by_label);
+    }
+
+    // Server figures
+    if let Some(server) = server {
+        output.push('\n');
+        output.push_str(&heading("Completed (server)"));
+        output.push_str(&format!("  Today:        {}\n", server.completed_today));
+        output.push_str(&format!("  This week:    {}\n", server.completed_this_week));
+        output.push_str(&format!("  All time:     {}\n", server.completed_total));
+        if let Some(karma) = server.karma {
+            match server.karma_trend.as_deref() {
+                Some(trend) if trend != "-" => {
+                    output.push_str(&format!("  Karma:        {:.0} ({})\n", karma, trend))
+                }
+                _ => output.push_str(&format!("  Karma:        {:.0}\n", karma)),
+            }
+        }
+        if let Some(streak) = server.current_streak {
+            let day_word = if streak == 1 { "day" } else { "days" };
+            match server.daily_goal {
+                Some(goal) => output.push_str(&format!(
+                    "  Streak:       {} {} (goal {}/day)\n",
+                    streak, day_word, goal
+                )),
+                None => output.push_str(&format!("  Streak:       {} {}\n", streak, day_word)),
+            }
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{item, project};
+
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 1, 15).unwrap()
+    }
+
+    fn make_test_cache() -> Cache {
+        let items = vec![
+            item("1", "Task 1").priority(4).due("2025-01-10").build(), // p1, overdue
+            item("2", "Task 2").priority(3).due("2025-01-15").build(), // p2, today
+            item("3", "Task 3").build(),                               // p4, no due
+            item("4", "Task 4").due("2025-02-01").build(),             // p4, future
+            item("5", "Task 5")
+                .priority(2)
+                .due("2025-01-14")
+                .checked()
+                .build(), // completed
+        ];
+        Cache::with_data(
+            "token".to_string(),
+            None,
+            None,
+            items,
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            None,
+        )
+    }
+
+    fn make_server_stats() -> ServerStats {
+        let json = serde_json::json!({
+            "completed_count": 512,
+            "days_items": [
+                {"date": "2025-01-15", "total_completed": 6},
+                {"date": "2025-01-14", "total_completed": 2}
+            ],
+            "week_items": [
+                {"from": "2025-01-13", "to": "2025-01-19", "total_completed": 8}
+            ],
+            "karma": 9000.0,
+            "karma_trend": "up",
+            "goals": {
+                "daily_goal": 5,
+                "weekly_goal": 25,
+                "current_daily_streak": {"count": 4}
+            }
+        });
+        let stats: ProductivityStats = serde_json::from_value(json).unwrap();
+        ServerStats::from_productivity_stats(&stats, today())
+    }
+
+    #[test]
+    fn test_compute_cache_stats() {
+        let cache = make_test_cache();
+        let stats = compute_cache_stats(&cache, today());
+
+        assert_eq!(stats.active_tasks, 4);
+        assert_eq!(stats.completed_tasks, 1);
+        assert_eq!(stats.overdue, 1);
+        assert_eq!(stats.due_today, 1);
+        assert_eq!(stats.no_due_date, 1);
+        assert_eq!(stats.by_priority, [1, 1, 0, 2]);
+    }
+
+    #[test]
+    fn test_server_stats_from_response() {
+        let server = make_server_stats();
+
+        assert_eq!(server.completed_today, 6);
+        assert_eq!(server.completed_this_week, 8);
+        assert_eq!(server.completed_total, 512);
+        assert_eq!(server.karma, Some(9000.0));
+        assert_eq!(server.daily_goal, Some(5));
+        assert_eq!(server.current_streak, Some(4));
+    }
+
+    #[test]
+    fn test_format_stats_json_local_only() {
+        let local = compute_cache_stats(&make_test_cache(), today());
+        let json = format_stats_json(&local, None, Breakdowns::default(), false).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(parsed["local"]["active_tasks"], 4);
+        assert_eq!(parsed["local"]["by_priority"]["p1"], 1);
+        assert!(parsed.get("server").is_none());
+    }
+
+    #[test]
+    fn test_format_stats_csv_uses_json_field_names() {
+        let local = compute_cache_stats(&make_test_cache(), today());
+        let csv = format_stats_csv(&local, None, Breakdowns::default()).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(lines[0], "field,value");
+        assert!(lines.contains(&"local.active_tasks,4"));
+        assert!(lines.contains(&"local.by_priority.p1,1"));
+        assert!(!csv.contains("server."));
+    }
+
+    #[test]
+    fn test_format_stats_json_with_server() {
+        let local = compute_cache_stats(&make_test_cache(), today());
+        let server = make_server_stats();
+        let json = format_stats_json(&local, Some(&server), Breakdowns::default(), false).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        // Local and server completion figures are reported side by side
+        assert_eq!(parsed["local"]["completed_tasks"], 1);
+        assert_eq!(parsed["server"]["completed_total"], 512);
+        assert_eq!(parsed["server"]["completed_today"], 6);
+    }
+
+    #[test]
+    fn test_format_stats_table_labels_sources() {
+        let local = compute_cache_stats(&make_test_cache(), today());
+
+        let output = format_stats_table(&local, None, Breakdowns::default(), false);
+        assert!(output.contains("Tasks (local cache)"));
+        assert!(output.contains("use --server"));
+        assert!(!output.contains("Completed (server)"));
+
+        let server = make_server_stats();
+        let output = format_stats_table(&local, Some(&server), Breakdowns::default(), false);
+        assert!(output.contains("Completed (server)"));
+        assert!(output.contains("All time:     512"));
+        assert!(output.contains("Karma:        9000 (up)"));
+        assert!(output.contains("Streak:       4 days (goal 5/day)"));
+        assert!(!output.contains("use --server"));
+    }
+
+    fn make_breakdown_cache() -> Cache {
+        let task = |id: &str, project: &str, labels: &[&str]| {
+            item(id, &format!("Task {id}"))
+                .project(project)
+                .labels(labels)
+        };
+        let items = vec![
+            task("1", "proj-work", &["urgent", "email"]).build(),
+            task("2", "proj-work", &["email"]).build(),
+            task("3", "proj-work", &[]).build(),
+            task("4", "proj-home", &["urgent"]).build(),
+            task("5", "proj-home", &["urgent"]).checked().build(), // completed, not counted
+        ];
+        Cache::with_data(
+            "token".to_string(),
+            None,
+            None,
+            items,
+            vec![
+                project("proj-work", "Work").build(),
+                project("proj-home", "Home").build(),
+            ],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            None,
+        )
+    }
+
+    #[test]
+    fn test_compute_cache_stats_by_project() {
+        let stats = compute_cache_stats(&make_breakdown_cache(), today());
+
+        assert_eq!(
+            stats.by_project,
+            vec![("Work".to_string(), 3), ("Home".to_string(), 1)]
+        );
+    }
+
+    #[test]
+    fn test_compute_cache_stats_by_label_counts_each_label() {
+        let stats = compute_cache_stats(&make_breakdown_cache(), today());
+
+        // Task 1 has both labels and is counted under each; ties sort by name
+        assert_eq!(
+            stats.by_label,
+            vec![("email".to_string(), 2), ("urgent".to_string(), 2)]
+        );
+    }
+
+    #[test]
+    fn test_compute_cache_stats_unknown_project_falls_back_to_id() {
+        let stats = compute_cache_stats(&make_test_cache(), today());
+
+        assert_eq!(stats.by_project, vec![("proj-1".to_string(), 4)]);
+        assert!(stats.by_label.is_empty());
+    }
+
+    #[test]
+    fn test_format_stats_json_breakdowns() {
+        let local = compute_cache_stats(&make_breakdown_cache(), today());
+
+        let json = format_stats_json(&local, None, Breakdowns::default(), false).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert!(parsed["local"].get("by_project").is_none());
+        assert!(parsed["local"].get("by_label").is_none());
+
+        let breakdowns = Breakdowns {
+            by_project: true,
+            by_label: true,
+        };
+        let json = format_stats_json(&local, None, breakdowns, false).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed["local"]["by_project"]["Work"], 3);
+        assert_eq!(parsed["local"]["by_project"]["Home"], 1);
+        assert_eq!(parsed["local"]["by_label"]["urgent"], 2);
+        assert_eq!(parsed["local"]["by_label"]["email"], 2);
+    }
+
+    #[test]
+    fn test_format_stats_table_breakdowns() {
+        let local = compute_cache_stats(&make_breakdown_cache(), today());
+
+        let output = format_stats_table(&local, None, Breakdowns::default(), false);
+        assert!(!output.contains("Active tasks by project"));
+
+        let breakdowns = Breakdowns {
+            by_project: true,
+            by_label: false,
+        };
+        let output = format_stats_table(&local, None, breakdowns, false);
+        assert!(output.contains("Active tasks by project"));
+        assert!(!output.contains("Active tasks by label"));
+        let work = output.find("      3  Work").unwrap();
+        let home = output.find("      1  Home").unwrap();
+        assert!(work < home);
+    }
+}
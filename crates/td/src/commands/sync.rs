@@ -1,23 +1,49 @@
 //! Sync command implementation.
 //!
-//! Force sync local cache with Todoist. Supports full sync with --full flag.
+//! Force sync local cache with Todoist. Supports full sync with --full flag
+//! and selective sync of specific resource types with --resources.
+//! `--prune-archived` drops archived projects and sections afterwards, and
+//! `--from-token` replays an incremental sync from a given sync token, and
+//! `--dump-response` writes the raw response to a file before applying it.
+//! `--quiet-if-fresh` skips the sync entirely while the cache is fresh.
+//! `--full --reset` discards the cache and rebuilds it from scratch.
+
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
 
 use chrono::Utc;
-use todoist_cache_rs::{CacheStore, SyncManager};
+use todoist_cache_rs::SYNC_RESOURCE_TYPES;
 
-use super::{CommandContext, Result};
+use super::{CommandContext, CommandError, ConfirmResult, Result};
 
 /// Options for the sync command.
 #[derive(Debug)]
 pub struct SyncOptions {
     /// Force full sync (ignore cache).
     pub full: bool,
+    /// Comma-separated resource types to fetch (e.g. "items,labels").
+    /// `None` syncs everything.
+    pub resources: Option<String>,
+    /// Remove archived projects and sections from the cache after syncing.
+    pub prune_archived: bool,
+    /// Replay an incremental sync from this token instead of the cached one.
+    pub from_token: Option<String>,
+    /// Write the raw sync response to this file before applying it.
+    pub dump_response: Option<PathBuf>,
+    /// Skip the sync and print nothing when the cache is not stale.
+    pub quiet_if_fresh: bool,
+    /// Discard the cache before the full sync instead of merging into it.
+    pub reset: bool,
+    /// Skip the confirmation prompt for `reset`.
+    pub force: bool,
 }
 
 /// Summary of a sync operation.
 pub struct SyncSummary {
     /// Whether this was a full sync.
     pub full_sync: bool,
+    /// Resource types fetched, if this was a selective sync.
+    pub resources: Option<Vec<String>>,
     /// Number of tasks in cache after sync.
     pub tasks: usize,
     /// Number of projects in cache after sync.
@@ -32,6 +58,8 @@ pub struct SyncSummary {
     pub reminders: usize,
     /// Number of filters in cache after sync.
     pub filters: usize,
+    /// Number of archived projects and sections pruned, if pruning was requested.
+    pub pruned: Option<usize>,
 }
 
 /// Executes the sync command.
@@ -46,14 +74,41 @@ pub struct SyncSummary {
 ///
 /// Returns an error if syncing fails.
 pub async fn execute(ctx: &CommandContext, opts: &SyncOptions, token: &str) -> Result<()> {
-    // Initialize sync manager
-    let client = todoist_api_rs::client::TodoistClient::new(token)?;
-    let store = CacheStore::new()?;
-    let mut manager = SyncManager::new(client, store)?;
+    // Validate resource types before touching the network
+    let resources = opts
+        .resources
+        .as_deref()
+        .map(parse_resource_types)
+        .transpose()?;
+
+    // --reset never reads the cache file, so it also recovers from a corrupt one
+    let mut manager = if opts.reset {
+        let stdin_is_tty = std::io::stdin().is_terminal();
+        if confirm_reset(opts.force, stdin_is_tty)? == ConfirmResult::Aborted {
+            if !ctx.quiet {
+                eprintln!("Aborted.");
+            }
+            return Ok(());
+        }
+        ctx.sync_manager_discarding_cache(token)?
+    } else {
+        ctx.sync_manager(token)?
+    };
+
+    // A fresh cache leaves nothing to do or report
+    if opts.quiet_if_fresh && !manager.needs_sync(Utc::now()) {
+        return Ok(());
+    }
 
     // Show what we're doing
     if ctx.verbose {
-        if opts.full {
+        if let Some(ref types) = resources {
+            eprintln!("Syncing resources: {}...", types.join(", "));
+        } else if let Some(ref sync_token) = opts.from_token {
+            eprintln!("Performing incremental sync from token {sync_token}...");
+        } else if opts.reset {
+            eprintln!("Discarding cache and performing full sync...");
+        } else if opts.full {
             eprintln!("Performing full sync...");
         } else {
             eprintln!("Performing incremental sync...");
@@ -61,20 +116,47 @@ pub async fn execute(ctx: &CommandContext, opts: &SyncOptions, token: &str) -> R
     }
 
     // Perform sync
-    let cache = if opts.full {
-        manager.full_sync().await?
+    if let Some(ref types) = resources {
+        manager.sync_resources(types).await?;
+    } else if let Some(ref path) = opts.dump_response {
+        let from_token = if opts.full {
+            Some("*")
+        } else {
+            opts.from_token.as_deref()
+        };
+        let (response, body) = manager.sync_raw(from_token).await?;
+        write_response_dump(path, &body)?;
+        if ctx.verbose {
+            eprintln!("Wrote sync response to {}", path.display());
+        }
+        manager.apply_response(&response).await?;
+    } else if let Some(ref sync_token) = opts.from_token {
+        manager.sync_from_token(sync_token).await?;
+    } else if opts.reset {
+        manager.rebuild().await?;
+    } else if opts.full {
+        manager.full_sync().await?;
+    } else {
+        manager.sync().await?;
+    }
+
+    let pruned = if opts.prune_archived {
+        Some(manager.prune_archived().await?)
     } else {
-        manager.sync().await?
+        None
     };
+    let cache = manager.cache();
 
     // Build summary
     let summary = SyncSummary {
-        full_sync: opts.full
-            || cache.full_sync_date_utc.is_some_and(|d| {
-                // Check if this sync updated the full_sync_date to "now"
-                let now = Utc::now();
-                (now - d).num_seconds().abs() < 5
-            }),
+        full_sync: resources.is_none()
+            && (opts.full
+                || cache.full_sync_date_utc.is_some_and(|d| {
+                    // Check if this sync updated the full_sync_date to "now"
+                    let now = Utc::now();
+                    (now - d).num_seconds().abs() < 5
+                })),
+        resources,
         tasks: cache
             .items
             .iter()
@@ -87,11 +169,12 @@ pub async fn execute(ctx: &CommandContext, opts: &SyncOptions, token: &str) -> R
             + cache.project_notes.iter().filter(|n| !n.is_deleted).count(),
         reminders: cache.reminders.iter().filter(|r| !r.is_deleted).count(),
         filters: cache.filters.iter().filter(|f| !f.is_deleted).count(),
+        pruned,
     };
 
     // Output
-    if ctx.json_output {
-        let output = format_sync_json(&summary)?;
+    if ctx.output_format.is_json() {
+        let output = format_sync_json(&summary, ctx.compact_json)?;
         println!("{output}");
     } else if !ctx.quiet {
         let output = format_sync_table(&summary, ctx.use_colors);
@@ -101,15 +184,102 @@ pub async fn execute(ctx: &CommandContext, opts: &SyncOptions, token: &str) -> R
     Ok(())
 }
 
+/// Asks before `--reset` throws away the local cache.
+///
+/// Non-interactive sessions are refused unless `force` is set, since there is
+/// no one to answer the prompt.
+fn confirm_reset(force: bool, stdin_is_tty: bool) -> Result<ConfirmResult> {
+    if force {
+        return Ok(ConfirmResult::Confirmed);
+    }
+    if !stdin_is_tty {
+        return Err(CommandError::Config(
+            "Refusing to reset the cache without confirmation in non-interactive mode. Use --force to proceed.".to_string(),
+        ));
+    }
+
+    let confirmed = dialoguer::Confirm::new()
+        .with_prompt(
+            "Discard the local cache (including any unsaved local changes) and rebuild it?",
+        )
+        .default(false)
+        .interact()
+        .map_err(|e| {
+            CommandError::Io(std::io::Error::other(format!(
+                "Failed to read input: {}",
+                e
+            )))
+        })?;
+
+    if confirmed {
+        Ok(ConfirmResult::Confirmed)
+    } else {
+        Ok(ConfirmResult::Aborted)
+    }
+}
+
+/// Writes a sync response body to `path` unchanged.
+///
+/// The response never carries the API token (it is sent as a request
+/// header), so the file holds only what Todoist returned.
+fn write_response_dump(path: &Path, body: &str) -> Result<()> {
+    std::fs::write(path, body).map_err(|e| {
+        CommandError::Config(format!(
+            "Failed to write sync response to {}: {e}",
+            path.display()
+        ))
+    })
+}
+
+/// Parses a comma-separated list of resource types.
+///
+/// Names are trimmed and lowercased, duplicates are dropped, and each name
+/// must be one of the known Todoist resource types.
+fn parse_resource_types(input: &str) -> Result<Vec<String>> {
+    let mut types: Vec<String> = Vec::new();
+
+    for part in input.split(',') {
+        let name = part.trim().to_lowercase();
+        if name.is_empty() {
+            continue;
+        }
+        if !SYNC_RESOURCE_TYPES.contains(&name.as_str()) {
+            return Err(CommandError::Config(format!(
+                "Unknown resource type '{}'. Valid types: {}",
+                name,
+                SYNC_RESOURCE_TYPES.join(", ")
+            )));
+        }
+        if !types.contains(&name) {
+            types.push(name);
+        }
+    }
+
+    if types.is_empty() {
+        return Err(CommandError::Config(
+            "No resource types given. Use e.g. --resources items,labels".to_string(),
+        ));
+    }
+
+    Ok(types)
+}
+
 /// Formats the sync summary as JSON.
-fn format_sync_json(summary: &SyncSummary) -> std::result::Result<String, serde_json::Error> {
+fn format_sync_json(
+    summary: &SyncSummary,
+    compact: bool,
+) -> std::result::Result<String, serde_json::Error> {
     use serde::Serialize;
 
     #[derive(Serialize)]
     struct SyncOutput {
         status: &'static str,
         sync_type: &'static str,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        resources: Option<Vec<String>>,
         summary: SummaryOutput,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pruned_archived: Option<usize>,
     }
 
     #[derive(Serialize)]
@@ -125,11 +295,14 @@ fn format_sync_json(summary: &SyncSummary) -> std::result::Result<String, serde_
 
     let output = SyncOutput {
         status: "success",
-        sync_type: if summary.full_sync {
+        sync_type: if summary.resources.is_some() {
+            "selective"
+        } else if summary.full_sync {
             "full"
         } else {
             "incremental"
         },
+        resources: summary.resources.clone(),
         summary: SummaryOutput {
             tasks: summary.tasks,
             projects: summary.projects,
@@ -139,9 +312,10 @@ fn format_sync_json(summary: &SyncSummary) -> std::result::Result<String, serde_
             reminders: summary.reminders,
             filters: summary.filters,
         },
+        pruned_archived: summary.pruned,
     };
 
-    serde_json::to_string_pretty(&output)
+    crate::output::to_json(&output, compact)
 }
 
 /// Formats the sync summary as a human-readable table.
@@ -151,12 +325,11 @@ fn format_sync_table(summary: &SyncSummary, use_colors: bool) -> String {
     let mut output = String::new();
 
     // Header
-    let sync_type = if summary.full_sync {
-        "Full"
-    } else {
-        "Incremental"
+    let header = match summary.resources {
+        Some(ref types) => format!("Selective sync completed ({})", types.join(", ")),
+        None if summary.full_sync => "Full sync completed".to_string(),
+        None => "Incremental sync completed".to_string(),
     };
-    let header = format!("{} sync completed", sync_type);
     if use_colors {
         output.push_str(&format!("{}\n\n", header.green().bold()));
     } else {
@@ -173,6 +346,13 @@ fn format_sync_table(summary: &SyncSummary, use_colors: bool) -> String {
     output.push_str(&format!("  Reminders: {}\n", summary.reminders));
     output.push_str(&format!("  Filters:   {}\n", summary.filters));
 
+    if let Some(pruned) = summary.pruned {
+        output.push_str(&format!(
+            "\nPruned {} archived project(s)/section(s)\n",
+            pruned
+        ));
+    }
+
     output
 }
 
@@ -182,20 +362,49 @@ mod tests {
 
     #[test]
     fn test_sync_options_defaults() {
-        let opts = SyncOptions { full: false };
+        let opts = SyncOptions {
+            full: false,
+            resources: None,
+            prune_archived: false,
+            from_token: None,
+            dump_response: None,
+            quiet_if_fresh: false,
+            reset: false,
+            force: false,
+        };
         assert!(!opts.full);
+        assert!(opts.resources.is_none());
     }
 
     #[test]
     fn test_sync_options_full() {
-        let opts = SyncOptions { full: true };
+        let opts = SyncOptions {
+            full: true,
+            resources: None,
+            prune_archived: false,
+            from_token: None,
+            dump_response: None,
+            quiet_if_fresh: false,
+            reset: false,
+            force: false,
+        };
         assert!(opts.full);
     }
 
+    #[test]
+    fn test_confirm_reset_requires_force_when_non_interactive() {
+        assert!(confirm_reset(false, false).is_err());
+        assert_eq!(
+            confirm_reset(true, false).unwrap(),
+            ConfirmResult::Confirmed
+        );
+    }
+
     #[test]
     fn test_format_sync_json_incremental() {
         let summary = SyncSummary {
             full_sync: false,
+            resources: None,
             tasks: 10,
             projects: 3,
             labels: 5,
@@ -203,9 +412,10 @@ mod tests {
             comments: 1,
             reminders: 0,
             filters: 2,
+            pruned: None,
         };
 
-        let json = format_sync_json(&summary).unwrap();
+        let json = format_sync_json(&summary, false).unwrap();
         let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
 
         assert_eq!(parsed["status"], "success");
@@ -218,6 +428,7 @@ mod tests {
     fn test_format_sync_json_full() {
         let summary = SyncSummary {
             full_sync: true,
+            resources: None,
             tasks: 25,
             projects: 5,
             labels: 8,
@@ -225,9 +436,10 @@ mod tests {
             comments: 3,
             reminders: 2,
             filters: 1,
+            pruned: None,
         };
 
-        let json = format_sync_json(&summary).unwrap();
+        let json = format_sync_json(&summary, false).unwrap();
         let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
 
         assert_eq!(parsed["status"], "success");
@@ -239,6 +451,7 @@ mod tests {
     fn test_format_sync_table_incremental() {
         let summary = SyncSummary {
             full_sync: false,
+            resources: None,
             tasks: 10,
             projects: 3,
             labels: 5,
@@ -246,6 +459,7 @@ mod tests {
             comments: 1,
             reminders: 0,
             filters: 2,
+            pruned: None,
         };
 
         let output = format_sync_table(&summary, false);
@@ -258,6 +472,7 @@ mod tests {
     fn test_format_sync_table_full() {
         let summary = SyncSummary {
             full_sync: true,
+            resources: None,
             tasks: 25,
             projects: 5,
             labels: 8,
@@ -265,10 +480,111 @@ mod tests {
             comments: 3,
             reminders: 2,
             filters: 1,
+            pruned: None,
         };
 
         let output = format_sync_table(&summary, false);
         assert!(output.contains("Full sync completed"));
         assert!(output.contains("Tasks:     25"));
     }
+
+    #[test]
+    fn test_parse_resource_types() {
+        let types = parse_resource_types("items, Labels,items").unwrap();
+        assert_eq!(types, vec!["items", "labels"]);
+    }
+
+    #[test]
+    fn test_parse_resource_types_rejects_unknown() {
+        let result = parse_resource_types("items,widgets");
+        assert!(result.is_err());
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("widgets"));
+        assert!(err.contains("items"));
+    }
+
+    #[test]
+    fn test_parse_resource_types_rejects_empty() {
+        assert!(parse_resource_types(" , ").is_err());
+    }
+
+    #[test]
+    fn test_format_sync_selective() {
+        let summary = SyncSummary {
+            full_sync: false,
+            resources: Some(vec!["items".to_string(), "labels".to_string()]),
+            tasks: 10,
+            projects: 3,
+            labels: 5,
+            sections: 2,
+            comments: 1,
+            reminders: 0,
+            filters: 2,
+            pruned: None,
+        };
+
+        let output = format_sync_table(&summary, false);
+        assert!(output.contains("Selective sync completed (items, labels)"));
+
+        let json = format_sync_json(&summary, false).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed["sync_type"], "selective");
+        assert_eq!(parsed["resources"][0], "items");
+    }
+
+    #[test]
+    fn test_format_sync_reports_pruned_archived() {
+        let summary = SyncSummary {
+            full_sync: false,
+            resources: None,
+            tasks: 10,
+            projects: 3,
+            labels: 5,
+            sections: 2,
+            comments: 1,
+            reminders: 0,
+            filters: 2,
+            pruned: Some(4),
+        };
+
+        let output = format_sync_table(&summary, false);
+        assert!(output.contains("Pruned 4 archived project(s)/section(s)"));
+
+        let json = format_sync_json(&summary, false).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed["pruned_archived"], 4);
+    }
+
+    #[test]
+    fn test_format_sync_omits_pruned_when_not_requested() {
+        let summary = SyncSummary {
+            full_sync: false,
+            resources: None,
+            tasks: 0,
+            projects: 0,
+            labels: 0,
+            sections: 0,
+            comments: 0,
+            reminders: 0,
+            filters: 0,
+            pruned: None,
+        };
+
+        assert!(!format_sync_table(&summary, false).contains("Pruned"));
+        let json = format_sync_json(&summary, false).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert!(parsed.get("pruned_archived").is_none());
+    }
+
+    #[test]
+    fn test_write_response_dump_keeps_body_verbatim() {
+        // Unknown fields and unparseable items must survive the dump
+        let body = r#"{"sync_token":"dump_token_123","new_field":1,"items":[{"id":"item-a","priority":"high"}]}"#;
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("response.json");
+
+        write_response_dump(&path, body).unwrap();
+
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), body);
+    }
 }
@@ -2,11 +2,13 @@
 //!
 //! Shows today's agenda: tasks due today and optionally overdue/upcoming tasks.
 
-use chrono::{Local, NaiveDate, Utc};
+use chrono::{Local, NaiveDate};
 use todoist_api_rs::sync::Item;
-use todoist_cache_rs::{Cache, CacheStore, SyncManager};
+use todoist_cache_rs::Cache;
 
-use super::{CommandContext, Result};
+use super::{ensure_fresh, CommandContext, Result};
+use crate::output::helpers::format_due_styled;
+use crate::output::{format_items_csv, DateStyle, OutputFormat};
 
 /// Options for the today command.
 #[derive(Debug)]
@@ -15,6 +17,8 @@ pub struct TodayOptions {
     pub include_overdue: bool,
     /// Include tasks due within N days.
     pub include_upcoming: Option<u32>,
+    /// Print a task count and total estimated duration.
+    pub summary: bool,
 }
 
 /// Result of the today command containing categorized tasks.
@@ -27,6 +31,10 @@ pub struct TodayResult<'a> {
     pub upcoming: Vec<&'a Item>,
     /// Number of days for upcoming tasks.
     pub upcoming_days: Option<u32>,
+    /// Whether overdue tasks were requested.
+    pub include_overdue: bool,
+    /// The local date the tasks were categorized against.
+    pub date: NaiveDate,
 }
 
 /// Executes the today command.
@@ -42,40 +50,104 @@ pub struct TodayResult<'a> {
 /// Returns an error if syncing fails.
 pub async fn execute(ctx: &CommandContext, opts: &TodayOptions, token: &str) -> Result<()> {
     // Initialize sync manager
-    let client = todoist_api_rs::client::TodoistClient::new(token)?;
-    let store = CacheStore::new()?;
-    let mut manager = SyncManager::new(client, store)?;
+    let mut manager = ctx.sync_manager(token)?;
 
     // Sync if needed
-    let now = Utc::now();
-    if manager.needs_sync(now) {
-        if ctx.verbose {
-            eprintln!("Syncing with Todoist...");
-        }
-        manager.sync().await?;
-    }
+    ensure_fresh(ctx, &mut manager).await?;
 
     let cache = manager.cache();
 
-    // Categorize tasks
-    let result = categorize_tasks(cache, opts);
+    // Categorize tasks against the date at execution start, so every line
+    // agrees on "today" even if the clock passes midnight while printing
+    let result = categorize_tasks(cache, opts, Local::now().date_naive());
 
     // Output
-    if ctx.json_output {
-        let output = format_today_json(&result, cache)?;
-        println!("{output}");
-    } else if !ctx.quiet {
-        let output = format_today_table(&result, cache, ctx.use_colors);
-        print!("{output}");
+    match ctx.output_format {
+        OutputFormat::Json => {
+            let output = format_today_json(&result, cache, opts.summary, ctx.compact_json)?;
+            println!("{output}");
+        }
+        OutputFormat::Csv => {
+            // One flat list in display order: overdue, today, upcoming
+            let items: Vec<&Item> = result
+                .overdue
+                .iter()
+                .chain(&result.today)
+                .chain(&result.upcoming)
+                .copied()
+                .collect();
+            print!("{}", format_items_csv(&items, cache));
+        }
+        OutputFormat::Table if !ctx.quiet => {
+            let output = format_today_table(&result, cache, ctx.use_colors, ctx.date_style);
+            ctx.print_table(&output);
+            if opts.summary {
+                println!("{}", format_summary(&result));
+            }
+            ctx.print_sync_footer(cache);
+        }
+        OutputFormat::Table => {}
     }
 
     Ok(())
 }
 
-/// Categorizes tasks into overdue, today, and upcoming.
-fn categorize_tasks<'a>(cache: &'a Cache, opts: &TodayOptions) -> TodayResult<'a> {
-    let local_today = Local::now().date_naive();
+impl TodayResult<'_> {
+    /// Iterates over all tasks in the result (overdue, today, then upcoming).
+    fn all_tasks(&self) -> impl Iterator<Item = &&Item> {
+        self.overdue
+            .iter()
+            .chain(self.today.iter())
+            .chain(self.upcoming.iter())
+    }
+
+    /// Returns the total number of tasks across all categories.
+    fn task_count(&self) -> usize {
+        self.overdue.len() + self.today.len() + self.upcoming.len()
+    }
+}
+
+/// Sums the estimated durations of the given tasks, in minutes.
+///
+/// Tasks without a duration contribute zero.
+fn total_duration_minutes<'a>(items: impl IntoIterator<Item = &'a &'a Item>) -> i64 {
+    items
+        .into_iter()
+        .filter_map(|item| item.duration.as_ref())
+        .map(|d| i64::from(d.as_minutes()))
+        .sum()
+}
+
+/// Formats a number of minutes as a compact duration (e.g., "4h 30m").
+fn format_duration_minutes(minutes: i64) -> String {
+    let hours = minutes / 60;
+    let mins = minutes % 60;
+    match (hours, mins) {
+        (0, m) => format!("{m}m"),
+        (h, 0) => format!("{h}h"),
+        (h, m) => format!("{h}h {m}m"),
+    }
+}
 
+/// Formats the summary line (e.g., "8 tasks, 4h 30m estimated").
+fn format_summary(result: &TodayResult) -> String {
+    let count = result.task_count();
+    let task_word = if count == 1 { "task" } else { "tasks" };
+    let minutes = total_duration_minutes(result.all_tasks());
+    format!(
+        "{} {}, {} estimated",
+        count,
+        task_word,
+        format_duration_minutes(minutes)
+    )
+}
+
+/// Categorizes tasks into overdue, today, and upcoming relative to `local_today`.
+fn categorize_tasks<'a>(
+    cache: &'a Cache,
+    opts: &TodayOptions,
+    local_today: NaiveDate,
+) -> TodayResult<'a> {
     let mut overdue: Vec<&Item> = Vec::new();
     let mut today: Vec<&Item> = Vec::new();
     let mut upcoming: Vec<&Item> = Vec::new();
@@ -127,7 +199,7 @@ fn categorize_tasks<'a>(cache: &'a Cache, opts: &TodayOptions) -> TodayResult<'a
     };
 
     overdue.sort_by(sort_by_due_and_priority);
-    today.sort_by(|a, b| b.priority.cmp(&a.priority)); // Just by priority for today
+    today.sort_by_key(|b| std::cmp::Reverse(b.priority)); // Just by priority for today
     upcoming.sort_by(sort_by_due_and_priority);
 
     TodayResult {
@@ -135,23 +207,37 @@ fn categorize_tasks<'a>(cache: &'a Cache, opts: &TodayOptions) -> TodayResult<'a
         today,
         upcoming,
         upcoming_days: opts.include_upcoming,
+        include_overdue: opts.include_overdue,
+        date: local_today,
     }
 }
 
 /// Formats the today result as JSON.
+///
+/// Tasks are grouped into `overdue`, `today` and `upcoming` arrays. The
+/// `overdue` array is present only when overdue tasks were requested and
+/// `upcoming` only when `--upcoming` was given, so an empty array means
+/// "nothing in this section" rather than "section not requested".
 fn format_today_json(
     result: &TodayResult,
     cache: &Cache,
+    summary: bool,
+    compact: bool,
 ) -> std::result::Result<String, serde_json::Error> {
     use serde::Serialize;
 
     #[derive(Serialize)]
     struct TodayOutput<'a> {
-        overdue: Vec<TaskOutput<'a>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        overdue: Option<Vec<TaskOutput<'a>>>,
         today: Vec<TaskOutput<'a>>,
-        #[serde(skip_serializing_if = "Vec::is_empty")]
-        upcoming: Vec<TaskOutput<'a>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        upcoming: Option<Vec<TaskOutput<'a>>>,
         total_count: usize,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        task_count: Option<usize>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        total_duration_minutes: Option<i64>,
     }
 
     #[derive(Serialize)]
@@ -190,33 +276,36 @@ fn format_today_json(
         }
     }
 
+    fn to_outputs<'a>(items: &[&'a Item], cache: &'a Cache) -> Vec<TaskOutput<'a>> {
+        items.iter().map(|i| to_task_output(i, cache)).collect()
+    }
+
     let output = TodayOutput {
         overdue: result
-            .overdue
-            .iter()
-            .map(|i| to_task_output(i, cache))
-            .collect(),
-        today: result
-            .today
-            .iter()
-            .map(|i| to_task_output(i, cache))
-            .collect(),
+            .include_overdue
+            .then(|| to_outputs(&result.overdue, cache)),
+        today: to_outputs(&result.today, cache),
         upcoming: result
-            .upcoming
-            .iter()
-            .map(|i| to_task_output(i, cache))
-            .collect(),
-        total_count: result.overdue.len() + result.today.len() + result.upcoming.len(),
+            .upcoming_days
+            .map(|_| to_outputs(&result.upcoming, cache)),
+        total_count: result.task_count(),
+        task_count: summary.then(|| result.task_count()),
+        total_duration_minutes: summary.then(|| total_duration_minutes(result.all_tasks())),
     };
 
-    serde_json::to_string_pretty(&output)
+    crate::output::to_json(&output, compact)
 }
 
 /// Formats the today result as a human-readable table.
-fn format_today_table(result: &TodayResult, cache: &Cache, use_colors: bool) -> String {
+fn format_today_table(
+    result: &TodayResult,
+    cache: &Cache,
+    use_colors: bool,
+    date_style: DateStyle,
+) -> String {
     use owo_colors::OwoColorize;
 
-    let total = result.overdue.len() + result.today.len() + result.upcoming.len();
+    let total = result.task_count();
 
     if total == 0 {
         return "No tasks for today.\n".to_string();
@@ -242,7 +331,13 @@ fn format_today_table(result: &TodayResult, cache: &Cache, use_colors: bool) ->
             output.push_str(&format!("{}\n", section_header));
         }
         for item in &result.overdue {
-            output.push_str(&format_task_line(item, cache, use_colors));
+            output.push_str(&format_task_line(
+                item,
+                cache,
+                result.date,
+                use_colors,
+                date_style,
+            ));
         }
         output.push('\n');
     }
@@ -256,7 +351,13 @@ fn format_today_table(result: &TodayResult, cache: &Cache, use_colors: bool) ->
             output.push_str(&format!("{}\n", section_header));
         }
         for item in &result.today {
-            output.push_str(&format_task_line(item, cache, use_colors));
+            output.push_str(&format_task_line(
+                item,
+                cache,
+                result.date,
+                use_colors,
+                date_style,
+            ));
         }
         output.push('\n');
     }
@@ -274,7 +375,13 @@ fn format_today_table(result: &TodayResult, cache: &Cache, use_colors: bool) ->
             output.push_str(&format!("{}\n", section_header));
         }
         for item in &result.upcoming {
-            output.push_str(&format_task_line(item, cache, use_colors));
+            output.push_str(&format_task_line(
+                item,
+                cache,
+                result.date,
+                use_colors,
+                date_style,
+            ));
         }
     }
 
@@ -282,11 +389,20 @@ fn format_today_table(result: &TodayResult, cache: &Cache, use_colors: bool) ->
 }
 
 /// Formats a single task line for the today view.
-fn format_task_line(item: &Item, cache: &Cache, use_colors: bool) -> String {
+fn format_task_line(
+    item: &Item,
+    cache: &Cache,
+    local_today: NaiveDate,
+    use_colors: bool,
+    date_style: DateStyle,
+) -> String {
     use owo_colors::OwoColorize;
 
     let priority = format_priority(item.priority, use_colors);
-    let due = format_due_for_today(item.due.as_ref(), use_colors);
+    let due = match date_style {
+        DateStyle::Relative => format_due_for_today(item.due.as_ref(), local_today, use_colors),
+        DateStyle::Absolute => format_due_styled(item.due.as_ref(), date_style, use_colors),
+    };
     let project_name = cache
         .projects
         .iter()
@@ -326,8 +442,12 @@ fn format_priority(api_priority: i32, use_colors: bool) -> String {
     }
 }
 
-/// Formats due date for the today view.
-fn format_due_for_today(due: Option<&todoist_api_rs::sync::Due>, use_colors: bool) -> String {
+/// Formats due date for the today view, relative to `local_today`.
+fn format_due_for_today(
+    due: Option<&todoist_api_rs::sync::Due>,
+    local_today: NaiveDate,
+    use_colors: bool,
+) -> String {
     use owo_colors::OwoColorize;
 
     let Some(due) = due else {
@@ -338,7 +458,6 @@ fn format_due_for_today(due: Option<&todoist_api_rs::sync::Due>, use_colors: boo
         return due.date.clone();
     };
 
-    let local_today = Local::now().date_naive();
     let tomorrow = local_today + chrono::Duration::days(1);
 
     // Format the date part
@@ -399,12 +518,14 @@ fn truncate_str(s: &str, max_len: usize) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_support::item;
 
     #[test]
     fn test_today_options_defaults() {
         let opts = TodayOptions {
             include_overdue: true,
             include_upcoming: None,
+            summary: false,
         };
 
         assert!(opts.include_overdue);
@@ -416,6 +537,7 @@ mod tests {
         let opts = TodayOptions {
             include_overdue: true,
             include_upcoming: Some(3),
+            summary: false,
         };
 
         assert!(opts.include_overdue);
@@ -430,6 +552,165 @@ mod tests {
         assert_eq!(format_priority(1, false), "p4");
     }
 
+    #[test]
+    fn test_total_duration_minutes_mixed() {
+        use todoist_api_rs::sync::Duration;
+
+        let a = item("1", "Write report")
+            .duration(Duration::minutes(90))
+            .build();
+        let b = item("2", "Plan trip").duration(Duration::days(1)).build();
+        let c = item("3", "No estimate").build();
+        let d = item("4", "Quick call")
+            .duration(Duration::minutes(15))
+            .build();
+
+        let items = [&a, &b, &c, &d];
+        assert_eq!(total_duration_minutes(items.iter()), 90 + 24 * 60 + 15);
+    }
+
+    #[test]
+    fn test_total_duration_minutes_none_set() {
+        let a = item("1", "Task A").build();
+        let b = item("2", "Task B").build();
+        let items = [&a, &b];
+        assert_eq!(total_duration_minutes(items.iter()), 0);
+    }
+
+    #[test]
+    fn test_format_duration_minutes() {
+        assert_eq!(format_duration_minutes(0), "0m");
+        assert_eq!(format_duration_minutes(45), "45m");
+        assert_eq!(format_duration_minutes(120), "2h");
+        assert_eq!(format_duration_minutes(270), "4h 30m");
+    }
+
+    #[test]
+    fn test_format_summary_across_categories() {
+        use todoist_api_rs::sync::Duration;
+
+        let a = item("1", "Overdue task")
+            .duration(Duration::minutes(30))
+            .build();
+        let b = item("2", "Today task").build();
+        let c = item("3", "Upcoming task")
+            .duration(Duration::minutes(60))
+            .build();
+
+        let result = TodayResult {
+            overdue: vec![&a],
+            today: vec![&b],
+            upcoming: vec![&c],
+            upcoming_days: Some(3),
+            include_overdue: true,
+            date: Local::now().date_naive(),
+        };
+        assert_eq!(format_summary(&result), "3 tasks, 1h 30m estimated");
+    }
+
+    #[test]
+    fn test_format_today_json_summary_fields() {
+        use todoist_api_rs::sync::Duration;
+
+        let cache = Cache::new();
+        let a = item("1", "Task A").duration(Duration::minutes(25)).build();
+        let b = item("2", "Task B").build();
+        let result = TodayResult {
+            overdue: vec![],
+            today: vec![&a, &b],
+            upcoming: vec![],
+            upcoming_days: None,
+            include_overdue: true,
+            date: Local::now().date_naive(),
+        };
+
+        let json = format_today_json(&result, &cache, true, false).unwrap();
+        assert!(json.contains("\"task_count\": 2"));
+        assert!(json.contains("\"total_duration_minutes\": 25"));
+
+        let json = format_today_json(&result, &cache, false, false).unwrap();
+        assert!(!json.contains("total_duration_minutes"));
+    }
+
+    #[test]
+    fn test_format_today_json_buckets_by_due_date() {
+        let local_today = Local::now().date_naive();
+        let due_on = |offset: i64| {
+            (local_today + chrono::Duration::days(offset))
+                .format("%Y-%m-%d")
+                .to_string()
+        };
+
+        let mut cache = Cache::new();
+        for (id, offset) in [("late", -2), ("now", 0), ("soon", 2), ("later", 10)] {
+            cache.items.push(item(id, id).due(&due_on(offset)).build());
+        }
+        cache.items.push(item("undated", "undated").build());
+
+        let opts = TodayOptions {
+            include_overdue: true,
+            include_upcoming: Some(3),
+            summary: false,
+        };
+        let result = categorize_tasks(&cache, &opts, local_today);
+        let json: serde_json::Value =
+            serde_json::from_str(&format_today_json(&result, &cache, false, false).unwrap())
+                .unwrap();
+        let ids = |key: &str| -> Vec<String> {
+            json[key]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|t| t["id"].as_str().unwrap().to_string())
+                .collect()
+        };
+        assert_eq!(ids("overdue"), vec!["late"]);
+        assert_eq!(ids("today"), vec!["now"]);
+        assert_eq!(ids("upcoming"), vec!["soon"]);
+        assert_eq!(json["total_count"], 3);
+
+        // Sections that weren't requested are omitted entirely
+        let opts = TodayOptions {
+            include_overdue: false,
+            include_upcoming: None,
+            summary: false,
+        };
+        let result = categorize_tasks(&cache, &opts, local_today);
+        let json: serde_json::Value =
+            serde_json::from_str(&format_today_json(&result, &cache, false, false).unwrap())
+                .unwrap();
+        assert!(json.get("overdue").is_none());
+        assert!(json.get("upcoming").is_none());
+        assert_eq!(json["today"].as_array().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_categorize_tasks_rolls_over_at_midnight() {
+        let day = NaiveDate::from_ymd_opt(2026, 3, 9).unwrap();
+        let mut cache = Cache::new();
+        cache
+            .items
+            .push(item("1", "Pay rent").due("2026-03-09").build());
+        let opts = TodayOptions {
+            include_overdue: true,
+            include_upcoming: None,
+            summary: false,
+        };
+
+        // Each run evaluates against the date it is given, not one captured earlier
+        let before = categorize_tasks(&cache, &opts, day);
+        assert_eq!(before.today.len(), 1);
+        assert!(before.overdue.is_empty());
+
+        let after = categorize_tasks(&cache, &opts, day.succ_opt().unwrap());
+        assert!(after.today.is_empty());
+        assert_eq!(after.overdue.len(), 1);
+        assert_eq!(
+            format_due_for_today(after.overdue[0].due.as_ref(), after.date, false),
+            "Yesterday"
+        );
+    }
+
     #[test]
     fn test_truncate_str() {
         assert_eq!(truncate_str("short", 10), "short");
@@ -3,11 +3,14 @@
 //! This module provides trait-based dispatch for CLI commands, replacing
 //! the large match statement in main.rs with a more maintainable structure.
 
+use std::path::PathBuf;
+
 use crate::cli::{
-    Cli, Commands, CommentsCommands, ConfigCommands, FiltersCommands, LabelsCommands,
-    ProjectsCommands, RemindersCommands, SectionsCommands,
+    CacheCommands, Cli, Commands, CommentsCommands, ConfigCommands, FiltersCommands,
+    LabelsCommands, ProjectsCommands, RemindersCommands, SearchSort, SectionsCommands,
 };
 use crate::commands::{self, CommandContext, CommandError, Result};
+use crate::interrupt;
 
 /// Trait for commands that can be executed without authentication.
 pub trait NoAuthCommand {
@@ -26,6 +29,9 @@ pub trait AuthCommand {
 pub enum NoAuthDispatch<'a> {
     Config(&'a Option<ConfigCommands>),
     Completions(&'a crate::cli::Shell),
+    Complete(crate::cli::CompleteResource),
+    CacheInfo,
+    Info,
     Help,
 }
 
@@ -36,6 +42,11 @@ impl<'a> NoAuthDispatch<'a> {
         match &cli.command {
             Some(Commands::Config { command }) => Some(Self::Config(command)),
             Some(Commands::Completions { shell }) => Some(Self::Completions(shell)),
+            Some(Commands::Complete { resource }) => Some(Self::Complete(*resource)),
+            Some(Commands::Cache {
+                command: CacheCommands::Info,
+            }) => Some(Self::CacheInfo),
+            Some(Commands::Info) => Some(Self::Info),
             None => Some(Self::Help),
             _ => None,
         }
@@ -49,6 +60,9 @@ impl NoAuthCommand for NoAuthDispatch<'_> {
             Self::Completions(shell) => {
                 commands::completions::execute(shell).map_err(CommandError::Io)
             }
+            Self::Complete(resource) => commands::completions::execute_complete(ctx, *resource),
+            Self::CacheInfo => commands::cache::execute_info(ctx),
+            Self::Info => commands::info::execute(ctx),
             Self::Help => {
                 if !ctx.quiet {
                     println!("td - Todoist CLI");
@@ -86,34 +100,60 @@ fn dispatch_config(ctx: &CommandContext, command: &Option<ConfigCommands>) -> Re
 pub enum AuthDispatch<'a> {
     List {
         filter: &'a Option<String>,
+        strict_filter: bool,
         project: &'a Option<String>,
-        label: &'a Option<String>,
+        label: &'a [String],
+        label_match: crate::cli::LabelMatch,
         priority: Option<u8>,
         section: &'a Option<String>,
         overdue: bool,
         no_due: bool,
+        due: &'a Option<String>,
         assigned_to: &'a Option<String>,
+        changed_since: &'a Option<String>,
+        flat: bool,
+        no_header: bool,
+        ids: bool,
+        fields: &'a [crate::output::TaskField],
         limit: u32,
         all: bool,
         cursor: &'a Option<String>,
         sort: &'a Option<crate::cli::SortField>,
         reverse: bool,
+        no_due_first: bool,
     },
     Add {
         content: &'a str,
         project: &'a Option<String>,
         priority: Option<u8>,
         due: &'a Option<String>,
+        lang: &'a Option<String>,
+        due_tz: &'a Option<String>,
         labels: &'a [String],
+        create_labels: bool,
         section: &'a Option<String>,
         parent: &'a Option<String>,
         description: &'a Option<String>,
+        description_file: &'a Option<std::path::PathBuf>,
         assign: &'a Option<String>,
+        remind: &'a Option<String>,
+        remind_default: bool,
+        at_top: bool,
+        at_bottom: bool,
+        parse: bool,
+        dry_run: bool,
     },
     Show {
         task_id: &'a str,
         comments: bool,
         reminders: bool,
+        full: bool,
+        raw: bool,
+        history: bool,
+    },
+    Open {
+        task_id: &'a str,
+        print: bool,
     },
     Edit {
         task_id: &'a str,
@@ -121,39 +161,81 @@ pub enum AuthDispatch<'a> {
         project: &'a Option<String>,
         priority: Option<u8>,
         due: &'a Option<String>,
+        lang: &'a Option<String>,
+        due_tz: &'a Option<String>,
         no_due: bool,
+        recurring: &'a Option<String>,
+        no_recurring: bool,
         labels: &'a [String],
+        label_list: &'a Option<String>,
         add_label: &'a Option<String>,
         remove_label: &'a Option<String>,
+        create_labels: bool,
         section: &'a Option<String>,
         description: &'a Option<String>,
+        description_file: &'a Option<std::path::PathBuf>,
+        append_description: &'a Option<String>,
+        prepend_description: &'a Option<String>,
         assign: &'a Option<String>,
         unassign: bool,
+        parent: &'a Option<String>,
+        no_parent: bool,
     },
     Done {
         task_ids: &'a [String],
         all_occurrences: bool,
         force: bool,
+        subtasks: bool,
     },
     Reopen {
         task_ids: &'a [String],
+        filter: &'a Option<String>,
+        max_results: Option<usize>,
         force: bool,
     },
     Delete {
         task_ids: &'a [String],
+        filter: &'a Option<String>,
+        max_results: Option<usize>,
         force: bool,
+        keep_subtasks: bool,
     },
     Today {
         include_overdue: bool,
         include_upcoming: Option<u32>,
+        summary: bool,
     },
     Quick {
         text: &'a str,
         auto_reminder: bool,
         note: &'a Option<String>,
+        file: &'a Option<PathBuf>,
+    },
+    Search {
+        query: &'a str,
+        sort: &'a SearchSort,
+        limit: u32,
+        all: bool,
+    },
+    Completed {
+        project: &'a Option<String>,
+        limit: u32,
+        all: bool,
+    },
+    Stats {
+        server: bool,
+        by_project: bool,
+        by_label: bool,
     },
     Sync {
         full: bool,
+        resources: &'a Option<String>,
+        prune_archived: bool,
+        from_token: &'a Option<String>,
+        dump_response: &'a Option<std::path::PathBuf>,
+        quiet_if_fresh: bool,
+        reset: bool,
+        force: bool,
     },
     ConfigEdit,
     Projects(&'a Option<ProjectsCommands>),
@@ -165,6 +247,8 @@ pub enum AuthDispatch<'a> {
     Comments {
         task: &'a Option<String>,
         project: &'a Option<String>,
+        oldest_first: bool,
+        full: bool,
         command: &'a Option<CommentsCommands>,
     },
     Reminders {
@@ -172,6 +256,7 @@ pub enum AuthDispatch<'a> {
         command: &'a Option<RemindersCommands>,
     },
     Filters(&'a Option<FiltersCommands>),
+    Cache(&'a CacheCommands),
     Collaborators {
         project: &'a str,
     },
@@ -184,62 +269,111 @@ impl<'a> AuthDispatch<'a> {
         match &cli.command {
             Some(Commands::List {
                 filter,
+                strict_filter,
                 project,
                 label,
+                label_match,
                 priority,
                 section,
                 overdue,
+                due,
                 no_due,
                 assigned_to,
+                changed_since,
+                flat,
+                no_header,
+                ids,
+                fields,
                 limit,
                 all,
                 cursor,
                 sort,
                 reverse,
+                no_due_first,
+                no_due_last: _,
             }) => Some(Self::List {
                 filter,
+                strict_filter: *strict_filter,
                 project,
                 label,
+                label_match: *label_match,
                 priority: *priority,
                 section,
+                due,
                 overdue: *overdue,
                 no_due: *no_due,
                 assigned_to,
+                changed_since,
+                flat: *flat,
+                no_header: *no_header,
+                ids: *ids,
+                fields,
                 limit: *limit,
                 all: *all,
                 cursor,
                 sort,
                 reverse: *reverse,
+                no_due_first: *no_due_first,
             }),
             Some(Commands::Add {
                 content,
                 project,
                 priority,
                 due,
+                lang,
+                due_tz,
                 label,
+                create_labels,
                 section,
                 parent,
                 description,
+                description_file,
                 assign,
+                remind,
+                remind_default,
+                at_top,
+                at_bottom,
+                parse,
+                dry_run,
             }) => Some(Self::Add {
                 content,
                 project,
                 priority: *priority,
                 due,
+                lang,
+                due_tz,
                 labels: label,
+                create_labels: *create_labels,
                 section,
                 parent,
                 description,
+                description_file,
                 assign,
+                remind,
+                remind_default: *remind_default,
+                at_top: *at_top,
+                at_bottom: *at_bottom,
+                parse: *parse,
+                dry_run: *dry_run,
             }),
             Some(Commands::Show {
                 task_id,
                 comments,
                 reminders,
+                full,
+                raw,
+                history,
             }) => Some(Self::Show {
                 task_id,
                 comments: *comments,
                 reminders: *reminders,
+                full: *full,
+                raw: *raw,
+                history: *history,
+            }),
+            Some(Commands::Open { task_id, print }) => Some(Self::Open {
+                task_id,
+                print: *print,
             }),
             Some(Commands::Edit {
                 task_id,
@@ -247,63 +381,154 @@ impl<'a> AuthDispatch<'a> {
                 project,
                 priority,
                 due,
+                lang,
+                due_tz,
                 no_due,
+                recurring,
+                no_recurring,
                 label,
+                labels: label_list,
                 add_label,
                 remove_label,
+                create_labels,
                 section,
                 description,
+                description_file,
+                append_description,
+                prepend_description,
                 assign,
                 unassign,
+                parent,
+                no_parent,
             }) => Some(Self::Edit {
                 task_id,
                 content,
                 project,
                 priority: *priority,
                 due,
+                lang,
+                due_tz,
                 no_due: *no_due,
+                recurring,
+                no_recurring: *no_recurring,
                 labels: label,
+                label_list,
                 add_label,
                 remove_label,
+                create_labels: *create_labels,
                 section,
                 description,
+                description_file,
+                append_description,
+                prepend_description,
                 assign,
                 unassign: *unassign,
+                parent,
+                no_parent: *no_parent,
             }),
             Some(Commands::Done {
                 task_ids,
                 all_occurrences,
                 force,
+                subtasks,
             }) => Some(Self::Done {
                 task_ids,
                 all_occurrences: *all_occurrences,
                 force: *force,
+                subtasks: *subtasks,
             }),
-            Some(Commands::Reopen { task_ids, force }) => Some(Self::Reopen {
+            Some(Commands::Reopen {
+                task_ids,
+                filter,
+                max_results,
+                force,
+            }) => Some(Self::Reopen {
                 task_ids,
+                filter,
+                max_results: *max_results,
                 force: *force,
             }),
-            Some(Commands::Delete { task_ids, force }) => Some(Self::Delete {
+            Some(Commands::Delete {
+                task_ids,
+                filter,
+                max_results,
+                force,
+                keep_subtasks,
+            }) => Some(Self::Delete {
                 task_ids,
+                filter,
+                max_results: *max_results,
                 force: *force,
+                keep_subtasks: *keep_subtasks,
             }),
             Some(Commands::Today {
                 no_overdue,
                 include_upcoming,
+                summary,
             }) => Some(Self::Today {
                 include_overdue: !no_overdue,
                 include_upcoming: *include_upcoming,
+                summary: *summary,
             }),
             Some(Commands::Quick {
                 text,
                 auto_reminder,
                 note,
+                file,
             }) => Some(Self::Quick {
                 text,
                 auto_reminder: *auto_reminder,
                 note,
+                file,
+            }),
+            Some(Commands::Search {
+                query,
+                sort,
+                limit,
+                all,
+            }) => Some(Self::Search {
+                query,
+                sort,
+                limit: *limit,
+                all: *all,
+            }),
+            Some(Commands::Completed {
+                project,
+                limit,
+                all,
+            }) => Some(Self::Completed {
+                project,
+                limit: *limit,
+                all: *all,
+            }),
+            Some(Commands::Stats {
+                server,
+                by_project,
+                by_label,
+            }) => Some(Self::Stats {
+                server: *server,
+                by_project: *by_project,
+                by_label: *by_label,
+            }),
+            Some(Commands::Sync {
+                full,
+                resources,
+                prune_archived,
+                from_token,
+                dump_response,
+                quiet_if_fresh,
+                reset,
+                force,
+            }) => Some(Self::Sync {
+                full: *full,
+                resources,
+                prune_archived: *prune_archived,
+                from_token,
+                dump_response,
+                quiet_if_fresh: *quiet_if_fresh,
+                reset: *reset,
+                force: *force,
             }),
-            Some(Commands::Sync { full }) => Some(Self::Sync { full: *full }),
             Some(Commands::Config {
                 command: Some(ConfigCommands::Edit),
             }) => Some(Self::ConfigEdit),
@@ -315,17 +540,28 @@ impl<'a> AuthDispatch<'a> {
             Some(Commands::Comments {
                 task,
                 project,
+                newest_first: _,
+                oldest_first,
+                full,
                 command,
             }) => Some(Self::Comments {
                 task,
                 project,
+                oldest_first: *oldest_first,
+                full: *full,
                 command,
             }),
             Some(Commands::Reminders { task, command }) => Some(Self::Reminders { task, command }),
             Some(Commands::Filters { command }) => Some(Self::Filters(command)),
+            Some(Commands::Cache { command }) => Some(Self::Cache(command)),
             Some(Commands::Collaborators { project }) => Some(Self::Collaborators { project }),
             // Already handled by NoAuthDispatch
-            Some(Commands::Config { .. }) | Some(Commands::Completions { .. }) | None => None,
+            Some(Commands::Config { .. })
+            | Some(Commands::Completions { .. })
+            | Some(Commands::Complete { .. })
+            | Some(Commands::Info)
+            | Some(Commands::Setup { .. })
+            | None => None,
         }
     }
 }
@@ -335,33 +571,51 @@ impl AuthCommand for AuthDispatch<'_> {
         match self {
             Self::List {
                 filter,
+                strict_filter,
                 project,
                 label,
+                label_match,
                 priority,
                 section,
                 overdue,
+                due,
                 no_due,
                 assigned_to,
+                changed_since,
+                flat,
+                no_header,
+                ids,
+                fields,
                 limit,
                 all,
                 cursor,
                 sort,
                 reverse,
+                no_due_first,
             } => {
                 let opts = commands::list::ListOptions {
                     filter: (*filter).clone(),
+                    strict_filter: *strict_filter,
                     project: (*project).clone(),
-                    label: (*label).clone(),
+                    labels: label.to_vec(),
+                    label_match: *label_match,
+                    due: (*due).clone(),
                     priority: *priority,
                     section: (*section).clone(),
                     overdue: *overdue,
                     no_due: *no_due,
                     assigned_to: (*assigned_to).clone(),
+                    changed_since: (*changed_since).clone(),
+                    flat: *flat,
+                    no_header: *no_header,
+                    ids: *ids,
+                    fields: fields.to_vec(),
                     limit: *limit,
                     all: *all,
                     cursor: (*cursor).clone(),
                     sort: (*sort).clone(),
                     reverse: *reverse,
+                    no_due_first: *no_due_first,
                 };
                 commands::list::execute(ctx, &opts, token).await
             }
@@ -371,22 +625,51 @@ impl AuthCommand for AuthDispatch<'_> {
                 project,
                 priority,
                 due,
+                lang,
+                due_tz,
                 labels,
+                create_labels,
                 section,
                 parent,
                 description,
+                description_file,
                 assign,
+                remind,
+                remind_default,
+                at_top,
+                at_bottom,
+                parse,
+                dry_run,
             } => {
                 let opts = commands::add::AddOptions {
                     content: (*content).to_string(),
                     project: (*project).clone(),
+                    default_project: ctx.config.add_default_project.clone(),
                     priority: *priority,
                     due: (*due).clone(),
+                    lang: (*lang).clone().or_else(|| ctx.config.due_lang.clone()),
+                    due_tz: (*due_tz).clone(),
                     labels: (*labels).to_vec(),
+                    create_labels: *create_labels,
                     section: (*section).clone(),
                     parent: (*parent).clone(),
-                    description: (*description).clone(),
+                    description: commands::read_description(
+                        description.as_deref(),
+                        description_file.as_deref(),
+                    )?,
                     assign: (*assign).clone(),
+                    remind: if *remind_default {
+                        Some(commands::add::default_remind_spec(
+                            ctx.config.default_reminder_offset,
+                        )?)
+                    } else {
+                        (*remind).clone()
+                    },
+                    at_top: *at_top,
+                    at_bottom: *at_bottom,
+                    parse: *parse,
+                    parse_tokens: ctx.config.add_parse_tokens.unwrap_or(true),
+                    dry_run: *dry_run,
                 };
                 commands::add::execute(ctx, &opts, token).await
             }
@@ -395,29 +678,54 @@ impl AuthCommand for AuthDispatch<'_> {
                 task_id,
                 comments,
                 reminders,
+                full,
+                raw,
+                history,
             } => {
                 let opts = commands::show::ShowOptions {
                     task_id: (*task_id).to_string(),
                     comments: *comments,
                     reminders: *reminders,
+                    full: *full,
+                    raw: *raw,
+                    history: *history,
                 };
                 commands::show::execute(ctx, &opts, token).await
             }
 
+            Self::Open { task_id, print } => {
+                let opts = commands::open::OpenOptions {
+                    task_id: (*task_id).to_string(),
+                    print: *print,
+                };
+                commands::open::execute(ctx, &opts, token).await
+            }
+
             Self::Edit {
                 task_id,
                 content,
                 project,
                 priority,
                 due,
+                lang,
+                due_tz,
                 no_due,
+                recurring,
+                no_recurring,
                 labels,
+                label_list,
                 add_label,
                 remove_label,
+                create_labels,
                 section,
                 description,
+                description_file,
+                append_description,
+                prepend_description,
                 assign,
                 unassign,
+                parent,
+                no_parent,
             } => {
                 let opts = commands::edit::EditOptions {
                     task_id: (*task_id).to_string(),
@@ -425,14 +733,30 @@ impl AuthCommand for AuthDispatch<'_> {
                     project: (*project).clone(),
                     priority: *priority,
                     due: (*due).clone(),
+                    lang: (*lang).clone().or_else(|| ctx.config.due_lang.clone()),
+                    due_tz: (*due_tz).clone(),
                     no_due: *no_due,
-                    labels: (*labels).to_vec(),
+                    recurring: (*recurring).clone(),
+                    no_recurring: *no_recurring,
+                    labels: match label_list {
+                        Some(list) => Some(commands::edit::read_label_list(list)?),
+                        None if labels.is_empty() => None,
+                        None => Some((*labels).to_vec()),
+                    },
                     add_label: (*add_label).clone(),
                     remove_label: (*remove_label).clone(),
+                    create_labels: *create_labels,
                     section: (*section).clone(),
-                    description: (*description).clone(),
+                    description: commands::read_description(
+                        description.as_deref(),
+                        description_file.as_deref(),
+                    )?,
+                    append_description: (*append_description).clone(),
+                    prepend_description: (*prepend_description).clone(),
                     assign: (*assign).clone(),
                     unassign: *unassign,
+                    parent: (*parent).clone(),
+                    no_parent: *no_parent,
                 };
                 commands::edit::execute(ctx, &opts, token).await
             }
@@ -441,27 +765,49 @@ impl AuthCommand for AuthDispatch<'_> {
                 task_ids,
                 all_occurrences,
                 force,
+                subtasks,
             } => {
                 let opts = commands::done::DoneOptions {
                     task_ids: (*task_ids).to_vec(),
                     all_occurrences: *all_occurrences,
                     force: *force,
+                    subtasks: *subtasks,
                 };
                 commands::done::execute(ctx, &opts, token).await
             }
 
-            Self::Reopen { task_ids, force } => {
+            Self::Reopen {
+                task_ids,
+                filter,
+                max_results,
+                force,
+            } => {
                 let opts = commands::reopen::ReopenOptions {
                     task_ids: (*task_ids).to_vec(),
+                    filter: (*filter).clone(),
+                    max_results: max_results
+                        .or(ctx.config.bulk.max_results)
+                        .unwrap_or(commands::DEFAULT_MAX_FILTER_RESULTS),
                     force: *force,
                 };
                 commands::reopen::execute(ctx, &opts, token).await
             }
 
-            Self::Delete { task_ids, force } => {
+            Self::Delete {
+                task_ids,
+                filter,
+                max_results,
+                force,
+                keep_subtasks,
+            } => {
                 let opts = commands::delete::DeleteOptions {
                     task_ids: (*task_ids).to_vec(),
+                    filter: (*filter).clone(),
+                    max_results: max_results
+                        .or(ctx.config.bulk.max_results)
+                        .unwrap_or(commands::DEFAULT_MAX_FILTER_RESULTS),
                     force: *force,
+                    keep_subtasks: *keep_subtasks,
                 };
                 commands::delete::execute(ctx, &opts, token).await
             }
@@ -469,30 +815,93 @@ impl AuthCommand for AuthDispatch<'_> {
             Self::Today {
                 include_overdue,
                 include_upcoming,
+                summary,
             } => {
                 let opts = commands::today::TodayOptions {
                     include_overdue: *include_overdue,
                     include_upcoming: *include_upcoming,
+                    summary: *summary,
                 };
                 commands::today::execute(ctx, &opts, token).await
             }
 
+            Self::Search {
+                query,
+                sort,
+                limit,
+                all,
+            } => {
+                let opts = commands::search::SearchOptions {
+                    query: (*query).to_string(),
+                    sort: (*sort).clone(),
+                    limit: *limit,
+                    all: *all,
+                };
+                commands::search::execute(ctx, &opts, token).await
+            }
+
             Self::Quick {
                 text,
                 auto_reminder,
                 note,
+                file,
             } => {
                 let opts = commands::quick::QuickOptions {
                     text: (*text).to_string(),
                     auto_reminder: *auto_reminder,
                     note: (*note).clone(),
+                    file: (*file).clone(),
                 };
                 commands::quick::execute(ctx, &opts, token).await
             }
 
-            Self::Sync { full } => {
-                let opts = commands::sync::SyncOptions { full: *full };
-                commands::sync::execute(ctx, &opts, token).await
+            Self::Completed {
+                project,
+                limit,
+                all,
+            } => {
+                let opts = commands::completed::CompletedOptions {
+                    project: (*project).clone(),
+                    limit: *limit,
+                    all: *all,
+                };
+                commands::completed::execute(ctx, &opts, token).await
+            }
+
+            Self::Stats {
+                server,
+                by_project,
+                by_label,
+            } => {
+                let opts = commands::stats::StatsOptions {
+                    server: *server,
+                    by_project: *by_project,
+                    by_label: *by_label,
+                };
+                commands::stats::execute(ctx, &opts, token).await
+            }
+
+            Self::Sync {
+                full,
+                resources,
+                prune_archived,
+                from_token,
+                dump_response,
+                quiet_if_fresh,
+                reset,
+                force,
+            } => {
+                let opts = commands::sync::SyncOptions {
+                    full: *full,
+                    resources: (*resources).clone(),
+                    prune_archived: *prune_archived,
+                    from_token: (*from_token).clone(),
+                    dump_response: (*dump_response).clone(),
+                    quiet_if_fresh: *quiet_if_fresh,
+                    reset: *reset,
+                    force: *force,
+                };
+                interrupt::until_interrupted(commands::sync::execute(ctx, &opts, token)).await
             }
 
             Self::ConfigEdit => commands::config::execute_edit(ctx).await,
@@ -505,12 +914,15 @@ impl AuthCommand for AuthDispatch<'_> {
             Self::Comments {
                 task,
                 project,
+                oldest_first,
+                full,
                 command,
-            } => dispatch_comments(ctx, task, project, command, token).await,
+            } => dispatch_comments(ctx, task, project, *oldest_first, *full, command, token).await,
             Self::Reminders { task, command } => {
                 dispatch_reminders(ctx, task, command, token).await
             }
             Self::Filters(command) => dispatch_filters(ctx, command, token).await,
+            Self::Cache(command) => dispatch_cache(ctx, command, token).await,
             Self::Collaborators { project } => {
                 let opts = commands::collaborators::CollaboratorsOptions {
                     project: (*project).to_string(),
@@ -521,6 +933,17 @@ impl AuthCommand for AuthDispatch<'_> {
     }
 }
 
+/// Maps `--on`/`--off` to an explicit favorite state; neither means toggle.
+fn favorite_flag(on: bool, off: bool) -> Option<bool> {
+    if on {
+        Some(true)
+    } else if off {
+        Some(false)
+    } else {
+        None
+    }
+}
+
 async fn dispatch_projects(
     ctx: &CommandContext,
     command: &Option<ProjectsCommands>,
@@ -529,13 +952,17 @@ async fn dispatch_projects(
     match command {
         Some(ProjectsCommands::List {
             tree,
+            depth,
             archived,
             limit,
+            include_completed,
         }) => {
             let opts = commands::projects::ProjectsListOptions {
                 tree: *tree,
+                depth: depth.map(usize::from),
                 archived: *archived,
                 limit: *limit,
+                include_completed: *include_completed,
             };
             commands::projects::execute(ctx, &opts, token).await
         }
@@ -544,12 +971,14 @@ async fn dispatch_projects(
             color,
             parent,
             favorite,
+            from_template,
         }) => {
             let opts = commands::projects::ProjectsAddOptions {
                 name: name.clone(),
                 color: color.clone(),
                 parent: parent.clone(),
                 favorite: *favorite,
+                from_template: from_template.clone(),
             };
             commands::projects::execute_add(ctx, &opts, token).await
         }
@@ -557,11 +986,17 @@ async fn dispatch_projects(
             project_id,
             sections,
             tasks,
+            filter,
+            include_completed,
+            completed,
         }) => {
             let opts = commands::projects::ProjectsShowOptions {
                 project_id: project_id.clone(),
                 sections: *sections,
                 tasks: *tasks,
+                filter: filter.clone(),
+                include_completed: *include_completed,
+                completed: *completed,
             };
             commands::projects::execute_show(ctx, &opts, token).await
         }
@@ -571,6 +1006,8 @@ async fn dispatch_projects(
             color,
             favorite,
             view_style,
+            parent,
+            no_parent,
         }) => {
             let opts = commands::projects::ProjectsEditOptions {
                 project_id: project_id.clone(),
@@ -578,9 +1015,22 @@ async fn dispatch_projects(
                 color: color.clone(),
                 favorite: *favorite,
                 view_style: view_style.clone(),
+                parent: parent.clone(),
+                no_parent: *no_parent,
             };
             commands::projects::execute_edit(ctx, &opts, token).await
         }
+        Some(ProjectsCommands::Favorite {
+            project_id,
+            on,
+            off,
+        }) => {
+            let opts = commands::projects::ProjectsFavoriteOptions {
+                project_id: project_id.clone(),
+                favorite: favorite_flag(*on, *off),
+            };
+            commands::projects::execute_favorite(ctx, &opts, token).await
+        }
         Some(ProjectsCommands::Archive { project_id, force }) => {
             let opts = commands::projects::ProjectsArchiveOptions {
                 project_id: project_id.clone(),
@@ -602,11 +1052,7 @@ async fn dispatch_projects(
             commands::projects::execute_delete(ctx, &opts, token).await
         }
         None => {
-            let opts = commands::projects::ProjectsListOptions {
-                tree: false,
-                archived: false,
-                limit: None,
-            };
+            let opts = commands::projects::ProjectsListOptions::default();
             commands::projects::execute(ctx, &opts, token).await
         }
     }
@@ -618,7 +1064,14 @@ async fn dispatch_labels(
     token: &str,
 ) -> Result<()> {
     match command {
-        Some(LabelsCommands::List) | None => {
+        Some(LabelsCommands::List { sort }) => {
+            let opts = commands::labels::LabelsListOptions {
+                sort: *sort,
+                ..Default::default()
+            };
+            commands::labels::execute(ctx, &opts, token).await
+        }
+        None => {
             let opts = commands::labels::LabelsListOptions::default();
             commands::labels::execute(ctx, &opts, token).await
         }
@@ -626,11 +1079,15 @@ async fn dispatch_labels(
             name,
             color,
             favorite,
+            force,
+            to_tasks,
         }) => {
             let opts = commands::labels::LabelsAddOptions {
                 name: name.clone(),
                 color: color.clone(),
                 favorite: *favorite,
+                force: *force,
+                to_tasks: to_tasks.clone(),
             };
             commands::labels::execute_add(ctx, &opts, token).await
         }
@@ -639,19 +1096,35 @@ async fn dispatch_labels(
             name,
             color,
             favorite,
+            force,
         }) => {
             let opts = commands::labels::LabelsEditOptions {
                 label_id: label_id.clone(),
                 name: name.clone(),
                 color: color.clone(),
                 favorite: *favorite,
+                force: *force,
             };
             commands::labels::execute_edit(ctx, &opts, token).await
         }
-        Some(LabelsCommands::Delete { label_id, force }) => {
+        Some(LabelsCommands::Favorite { label_id, on, off }) => {
+            let opts = commands::labels::LabelsFavoriteOptions {
+                label_id: label_id.clone(),
+                favorite: favorite_flag(*on, *off),
+            };
+            commands::labels::execute_favorite(ctx, &opts, token).await
+        }
+        Some(LabelsCommands::Delete {
+            label_id,
+            force,
+            reassign,
+            remove,
+        }) => {
             let opts = commands::labels::LabelsDeleteOptions {
                 label_id: label_id.clone(),
                 force: *force,
+                reassign: reassign.clone(),
+                remove: *remove,
             };
             commands::labels::execute_delete(ctx, &opts, token).await
         }
@@ -675,10 +1148,14 @@ async fn dispatch_sections(
         Some(SectionsCommands::Add {
             name,
             project: proj,
+            before,
+            after,
         }) => {
             let opts = commands::sections::SectionsAddOptions {
                 name: name.clone(),
                 project: proj.clone(),
+                before: before.clone(),
+                after: after.clone(),
             };
             commands::sections::execute_add(ctx, &opts, token).await
         }
@@ -703,6 +1180,8 @@ async fn dispatch_comments(
     ctx: &CommandContext,
     task: &Option<String>,
     project: &Option<String>,
+    oldest_first: bool,
+    full: bool,
     command: &Option<CommentsCommands>,
     token: &str,
 ) -> Result<()> {
@@ -711,6 +1190,8 @@ async fn dispatch_comments(
             let opts = commands::comments::CommentsListOptions {
                 task: task.clone(),
                 project: project.clone(),
+                oldest_first,
+                full,
             };
             commands::comments::execute(ctx, &opts, token).await
         }
@@ -728,22 +1209,36 @@ async fn dispatch_comments(
             };
             commands::comments::execute_add(ctx, &opts, token).await
         }
-        Some(CommentsCommands::Edit { comment_id, text }) => {
+        Some(CommentsCommands::Edit {
+            comment_id,
+            task,
+            index,
+            text,
+        }) => {
             let opts = commands::comments::CommentsEditOptions {
                 comment_id: comment_id.clone(),
+                task: task.clone(),
+                index: *index,
                 content: text.clone(),
             };
             commands::comments::execute_edit(ctx, &opts, token).await
         }
-        Some(CommentsCommands::Delete { comment_id, force }) => {
+        Some(CommentsCommands::Delete {
+            comment_id,
+            task,
+            index,
+            force,
+        }) => {
             let opts = commands::comments::CommentsDeleteOptions {
                 comment_id: comment_id.clone(),
+                task: task.clone(),
+                index: *index,
                 force: *force,
             };
             commands::comments::execute_delete(ctx, &opts, token).await
         }
         Some(CommentsCommands::Attach { .. }) | Some(CommentsCommands::Download { .. }) => {
-            if ctx.json_output {
+            if ctx.output_format.is_json() {
                 println!(
                     "{}",
                     serde_json::json!({
@@ -778,13 +1273,21 @@ async fn dispatch_reminders(
             let opts = commands::reminders::RemindersAddOptions {
                 task: add_task.clone(),
                 due: due.clone(),
-                offset: *offset,
+                offset: offset.clone(),
+                default_offset: ctx.config.default_reminder_offset,
             };
             commands::reminders::execute_add(ctx, &opts, token).await
         }
-        Some(RemindersCommands::Delete { reminder_id, force }) => {
+        Some(RemindersCommands::Delete {
+            reminder_id,
+            task,
+            all,
+            force,
+        }) => {
             let opts = commands::reminders::RemindersDeleteOptions {
                 reminder_id: reminder_id.clone(),
+                task: task.clone(),
+                all: *all,
                 force: *force,
             };
             commands::reminders::execute_delete(ctx, &opts, token).await
@@ -798,7 +1301,14 @@ async fn dispatch_filters(
     token: &str,
 ) -> Result<()> {
     match command {
-        Some(FiltersCommands::List) | None => {
+        Some(FiltersCommands::List { favorites_only }) => {
+            let opts = commands::filters::FiltersListOptions {
+                favorites_only: *favorites_only,
+                ..Default::default()
+            };
+            commands::filters::execute(ctx, &opts, token).await
+        }
+        None => {
             let opts = commands::filters::FiltersListOptions::default();
             commands::filters::execute(ctx, &opts, token).await
         }
@@ -807,18 +1317,21 @@ async fn dispatch_filters(
             query,
             color,
             favorite,
+            strict,
         }) => {
             let opts = commands::filters::FiltersAddOptions {
                 name: name.clone(),
                 query: query.clone(),
                 color: color.clone(),
                 favorite: *favorite,
+                strict: *strict,
             };
             commands::filters::execute_add(ctx, &opts, token).await
         }
-        Some(FiltersCommands::Show { filter_id }) => {
+        Some(FiltersCommands::Show { filter_id, preview }) => {
             let opts = commands::filters::FiltersShowOptions {
                 filter_id: filter_id.clone(),
+                preview: *preview,
             };
             commands::filters::execute_show(ctx, &opts, token).await
         }
@@ -828,6 +1341,7 @@ async fn dispatch_filters(
             query,
             color,
             favorite,
+            strict,
         }) => {
             let opts = commands::filters::FiltersEditOptions {
                 filter_id: filter_id.clone(),
@@ -835,9 +1349,14 @@ async fn dispatch_filters(
                 query: query.clone(),
                 color: color.clone(),
                 favorite: *favorite,
+                strict: *strict,
             };
             commands::filters::execute_edit(ctx, &opts, token).await
         }
+        Some(FiltersCommands::Favorite { filter_id, off }) => {
+            let opts = commands::filters::favorite_edit_options(filter_id, !off);
+            commands::filters::execute_edit(ctx, &opts, token).await
+        }
         Some(FiltersCommands::Delete { filter_id, force }) => {
             let opts = commands::filters::FiltersDeleteOptions {
                 filter_id: filter_id.clone(),
@@ -848,6 +1367,19 @@ async fn dispatch_filters(
     }
 }
 
+async fn dispatch_cache(ctx: &CommandContext, command: &CacheCommands, token: &str) -> Result<()> {
+    match command {
+        CacheCommands::Info => commands::cache::execute_info(ctx),
+        CacheCommands::Dedup { dry_run, force } => {
+            let opts = commands::cache::CacheDedupOptions {
+                dry_run: *dry_run,
+                force: *force,
+            };
+            commands::cache::execute_dedup(ctx, &opts, token).await
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -868,6 +1400,39 @@ mod tests {
         assert!(matches!(dispatch, Some(NoAuthDispatch::Completions(_))));
     }
 
+    #[test]
+    fn test_no_auth_dispatch_cache_info() {
+        let cli = Cli::parse_from(["td", "cache", "info", "--json"]);
+        let dispatch = NoAuthDispatch::try_from_cli(&cli);
+        assert!(matches!(dispatch, Some(NoAuthDispatch::CacheInfo)));
+    }
+
+    #[test]
+    fn test_complete_is_no_auth() {
+        let cli = Cli::parse_from(["td", "__complete", "projects"]);
+        let dispatch = NoAuthDispatch::try_from_cli(&cli);
+        assert!(matches!(
+            dispatch,
+            Some(NoAuthDispatch::Complete(
+                crate::cli::CompleteResource::Projects
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_no_auth_dispatch_info() {
+        let cli = Cli::parse_from(["td", "info"]);
+        let dispatch = NoAuthDispatch::try_from_cli(&cli);
+        assert!(matches!(dispatch, Some(NoAuthDispatch::Info)));
+    }
+
+    #[test]
+    fn test_favorite_flag() {
+        assert_eq!(favorite_flag(false, false), None);
+        assert_eq!(favorite_flag(true, false), Some(true));
+        assert_eq!(favorite_flag(false, true), Some(false));
+    }
+
     #[test]
     fn test_no_auth_dispatch_help() {
         let cli = Cli::parse_from(["td"]);
@@ -0,0 +1,164 @@
+//! Loading of `KEY=VALUE` env files (e.g., `.env.local`) for `--env-file`.
+//!
+//! Values from the file only fill in variables that are not already set, so
+//! the real environment always takes precedence.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+use crate::commands::{CommandError, Result};
+
+/// Parses env file contents into key/value pairs.
+///
+/// Blank lines and `#` comments are skipped, an `export ` prefix is allowed,
+/// and values may be wrapped in single or double quotes.
+fn parse_env_file(contents: &str) -> Result<Vec<(String, String)>> {
+    let mut vars = Vec::new();
+
+    for (index, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let line = line.strip_prefix("export ").unwrap_or(line);
+        let (key, value) = line
+            .split_once('=')
+            .map(|(k, v)| (k.trim(), v.trim()))
+            .filter(|(k, _)| !k.is_empty() && !k.contains(char::is_whitespace))
+            .ok_or_else(|| {
+                CommandError::Config(format!(
+                    "Invalid env file line {}: expected KEY=VALUE",
+                    index + 1
+                ))
+            })?;
+
+        let value = [('"', '"'), ('\'', '\'')]
+            .iter()
+            .find_map(|(open, close)| {
+                value
+                    .strip_prefix(*open)
+                    .and_then(|v| v.strip_suffix(*close))
+            })
+            .unwrap_or(value);
+
+        vars.push((key.to_string(), value.to_string()));
+    }
+
+    Ok(vars)
+}
+
+/// Loads an env file into the process environment.
+///
+/// Variables that are already set are left untouched. Returns the number of
+/// variables that were set from the file.
+///
+/// Call this before starting any threads (including the async runtime), since
+/// it modifies the process environment.
+///
+/// # Errors
+///
+/// Returns an error if the file can't be read or contains an invalid line.
+pub fn load_env_file(path: &Path) -> Result<usize> {
+    let contents = fs::read_to_string(path).map_err(|e| {
+        CommandError::Config(format!("Failed to read env file {}: {e}", path.display()))
+    })?;
+
+    let mut loaded = 0;
+    for (key, value) in parse_env_file(&contents)? {
+        if env::var_os(&key).is_none() {
+            env::set_var(&key, value);
+            loaded += 1;
+        }
+    }
+    Ok(loaded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::Cli;
+    use clap::Parser;
+    use serial_test::serial;
+    use tempfile::TempDir;
+
+    fn write_env_file(dir: &TempDir, contents: &str) -> std::path::PathBuf {
+        let path = dir.path().join(".env.local");
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    /// Runs `f` with `TODOIST_TOKEN` set to `value`, restoring it afterwards.
+    fn with_token_env<T>(value: Option<&str>, f: impl FnOnce() -> T) -> T {
+        let original = env::var("TODOIST_TOKEN").ok();
+        match value {
+            Some(v) => env::set_var("TODOIST_TOKEN", v),
+            None => env::remove_var("TODOIST_TOKEN"),
+        }
+        let result = f();
+        match original {
+            Some(v) => env::set_var("TODOIST_TOKEN", v),
+            None => env::remove_var("TODOIST_TOKEN"),
+        }
+        result
+    }
+
+    #[test]
+    fn test_parse_env_file() {
+        let vars = parse_env_file(
+            "# local settings\n\nTODOIST_TOKEN=abc123\nexport TD_CONFIG=\"/tmp/td config.toml\"\nEMPTY=\nQUOTED='x'\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            vars,
+            vec![
+                ("TODOIST_TOKEN".to_string(), "abc123".to_string()),
+                ("TD_CONFIG".to_string(), "/tmp/td config.toml".to_string()),
+                ("EMPTY".to_string(), String::new()),
+                ("QUOTED".to_string(), "x".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_env_file_rejects_invalid_line() {
+        let err = parse_env_file("TODOIST_TOKEN=abc\nnot a pair\n").unwrap_err();
+        assert!(err.to_string().contains("line 2"));
+    }
+
+    #[test]
+    #[serial]
+    fn test_env_file_token_used_when_env_unset() {
+        let dir = TempDir::new().unwrap();
+        let path = write_env_file(&dir, "TODOIST_TOKEN=file-token\n");
+
+        let cli = with_token_env(None, || {
+            assert_eq!(load_env_file(&path).unwrap(), 1);
+            Cli::try_parse_from(["td", "--env-file", path.to_str().unwrap(), "sync"]).unwrap()
+        });
+
+        assert_eq!(cli.token.as_deref(), Some("file-token"));
+    }
+
+    #[test]
+    #[serial]
+    fn test_env_file_token_ignored_when_env_set() {
+        let dir = TempDir::new().unwrap();
+        let path = write_env_file(&dir, "TODOIST_TOKEN=file-token\n");
+
+        let cli = with_token_env(Some("env-token"), || {
+            assert_eq!(load_env_file(&path).unwrap(), 0);
+            Cli::try_parse_from(["td", "--env-file", path.to_str().unwrap(), "sync"]).unwrap()
+        });
+
+        assert_eq!(cli.token.as_deref(), Some("env-token"));
+    }
+
+    #[test]
+    fn test_load_env_file_missing() {
+        let err = load_env_file(Path::new("/tmp/td-test-nonexistent/.env")).unwrap_err();
+        assert!(err.to_string().contains("Failed to read env file"));
+    }
+}
@@ -0,0 +1,95 @@
+//! Ctrl-C handling for long-running commands.
+//!
+//! Cache saves go through a temp file and a rename, so dropping a command's
+//! future at an `.await` point leaves either the old cache or the complete
+//! new one on disk. Racing the command against SIGINT therefore skips any
+//! pending save cleanly instead of letting the process die mid-write.
+
+use std::future::Future;
+
+use crate::commands::{CommandError, Result};
+
+/// Runs `command` until it finishes or the user presses Ctrl-C.
+///
+/// # Errors
+///
+/// Returns [`CommandError::Interrupted`] if SIGINT arrives first, otherwise
+/// the command's own result.
+pub async fn until_interrupted<T>(command: impl Future<Output = Result<T>>) -> Result<T> {
+    race_interrupt(command, async {
+        // If the handler can't be installed, never fire and let the command finish
+        if tokio::signal::ctrl_c().await.is_err() {
+            std::future::pending::<()>().await;
+        }
+    })
+    .await
+}
+
+/// Races `command` against `interrupt`, dropping the command if the
+/// interrupt completes first.
+async fn race_interrupt<T>(
+    command: impl Future<Output = Result<T>>,
+    interrupt: impl Future<Output = ()>,
+) -> Result<T> {
+    tokio::select! {
+        result = command => result,
+        () = interrupt => Err(CommandError::Interrupted),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use todoist_cache_rs::{Cache, CacheStore};
+
+    async fn yield_times(n: usize) {
+        for _ in 0..n {
+            tokio::task::yield_now().await;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_command_result_passes_through() {
+        let result = race_interrupt(async { Ok(42) }, std::future::pending()).await;
+        assert_eq!(result.unwrap(), 42);
+    }
+
+    #[tokio::test]
+    async fn test_interrupt_returns_interrupted() {
+        let result: Result<()> = race_interrupt(std::future::pending(), async {}).await;
+        assert!(matches!(result, Err(CommandError::Interrupted)));
+    }
+
+    #[tokio::test]
+    async fn test_interrupt_during_save_never_leaves_partial_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut old = Cache::new();
+        old.sync_token = "old-token".to_string();
+        let mut new = Cache::new();
+        new.sync_token = "new-token".to_string();
+
+        // Interrupt at every point from "response received" through the save.
+        // Each round gets its own file: a cancelled temp-file write may still
+        // finish on a blocking thread after the race is decided.
+        for delay in 0..32 {
+            let store = CacheStore::with_path(dir.path().join(format!("cache-{delay}.json")));
+            store.save(&old).unwrap();
+
+            let _ = race_interrupt(
+                async {
+                    tokio::task::yield_now().await;
+                    store.save_async(&new).await?;
+                    Ok(())
+                },
+                yield_times(delay),
+            )
+            .await;
+
+            let on_disk = store.load().expect("cache must stay readable");
+            assert!(
+                on_disk == old || on_disk == new,
+                "interrupt after {delay} yields left an unexpected cache"
+            );
+        }
+    }
+}
@@ -1,42 +1,90 @@
-use clap::Parser;
 use std::process::ExitCode;
 
 mod cli;
 mod commands;
 mod dispatch;
+mod env_file;
+mod interrupt;
 mod output;
+mod pager;
+#[cfg(test)]
+mod test_support;
 
 use cli::Cli;
-use commands::config::load_config;
+use commands::keyring::KeyringError;
 use commands::{CommandContext, CommandError};
 use dispatch::{AuthCommand, AuthDispatch, NoAuthCommand, NoAuthDispatch};
+use todoist_cache_rs::filter::FilterError;
+
+fn main() -> ExitCode {
+    let mut cli = Cli::parse_args();
+
+    // Env-backed flags (like --token) are read while parsing, so parse again
+    // once the env file has filled in any unset variables. This has to happen
+    // before the runtime starts: setting variables is only safe while the
+    // process is still single-threaded.
+    if let Some(path) = cli.env_file.clone() {
+        if let Err(e) = env_file::load_env_file(&path) {
+            return report_error(&cli, &e);
+        }
+        cli = Cli::parse_args();
+    }
 
-#[tokio::main]
-async fn main() -> ExitCode {
-    let cli = Cli::parse();
+    let runtime = match tokio::runtime::Runtime::new() {
+        Ok(runtime) => runtime,
+        Err(e) => return report_error(&cli, &CommandError::Io(e)),
+    };
+    let timeout_secs = CommandContext::from_cli(&cli).timeout_secs;
 
-    match run(&cli).await {
+    match runtime
+        .block_on(run(&cli))
+        .map_err(|e| e.with_timeout(timeout_secs))
+    {
         Ok(()) => ExitCode::SUCCESS,
-        Err(e) => {
-            if cli.json {
-                let error_json = serde_json::json!({
-                    "error": {
-                        "code": error_code(&e),
-                        "message": e.to_string(),
-                    }
-                });
-                eprintln!("{}", serde_json::to_string_pretty(&error_json).unwrap());
-            } else {
-                eprintln!("Error: {e}");
+        Err(e) => report_error(&cli, &e),
+    }
+}
+
+/// Prints an error (as JSON with `--json`) and returns its exit code.
+fn report_error(cli: &Cli, e: &CommandError) -> ExitCode {
+    let ctx = CommandContext::from_cli(cli);
+    if ctx.output_format.is_json() {
+        let error_json = serde_json::json!({
+            "error": {
+                "code": error_code(e),
+                "message": e.to_string(),
             }
-            error_exit_code(&e)
+        });
+        eprintln!(
+            "{}",
+            output::to_json(&error_json, ctx.compact_json).unwrap()
+        );
+    } else {
+        eprintln!("Error: {e}");
+        if let Some(hint) = error_hint(e) {
+            eprintln!("\nHint: {hint}");
         }
     }
+    error_exit_code(e)
 }
 
 async fn run(cli: &Cli) -> commands::Result<()> {
     let ctx = CommandContext::from_cli(cli);
 
+    // Setup configures the token itself, so it runs before token resolution
+    if let Some(cli::Commands::Setup { storage }) = &cli.command {
+        // A token from TODOIST_TOKEN is never persisted; only an explicit
+        // --token skips the wizard.
+        let opts = commands::setup::SetupOptions {
+            token: cli.token.clone().filter(|_| !cli.token_from_env),
+            storage: match storage {
+                cli::SetupStorage::Keyring => commands::setup::TokenStorage::Keyring,
+                cli::SetupStorage::Config => commands::setup::TokenStorage::Config,
+            },
+        };
+        return commands::setup::execute(&ctx, &opts).await;
+    }
+
     // Try no-auth commands first (config, completions, help)
     if let Some(dispatch) = NoAuthDispatch::try_from_cli(cli) {
         // Special case: config edit requires async context
@@ -64,7 +112,7 @@ async fn run(cli: &Cli) -> commands::Result<()> {
     }
 
     // Fallback for any unhandled commands
-    if cli.json {
+    if ctx.output_format.is_json() {
         println!(
             "{}",
             serde_json::json!({
@@ -83,11 +131,30 @@ fn error_code(e: &CommandError) -> &'static str {
     match e {
         CommandError::Sync(_) => "SYNC_ERROR",
         CommandError::CacheStore(_) => "CACHE_ERROR",
+        CommandError::Filter(FilterError::EmptyExpression) => "EMPTY_FILTER",
         CommandError::Filter(_) => "FILTER_ERROR",
         CommandError::Api(_) => "API_ERROR",
         CommandError::Config(_) => "CONFIG_ERROR",
         CommandError::Io(_) => "IO_ERROR",
         CommandError::Json(_) => "JSON_ERROR",
+        CommandError::Timeout(_) => "TIMEOUT",
+        CommandError::MissingToken => "NO_TOKEN",
+        CommandError::Interrupted => "INTERRUPTED",
+    }
+}
+
+/// Returns guidance to print after the error message, if any.
+fn error_hint(e: &CommandError) -> Option<&'static str> {
+    match e {
+        CommandError::Filter(FilterError::EmptyExpression) => Some(
+            "the filter is empty. Pass a query such as --filter \"today | overdue\", or omit --filter to list all tasks.",
+        ),
+        CommandError::Sync(todoist_cache_rs::SyncError::Cache(e)) | CommandError::CacheStore(e)
+            if e.is_corrupt() =>
+        {
+            Some("the cache file can't be read. Run `td sync --full --reset --force` to discard it and sync again.")
+        }
+        _ => None,
     }
 }
 
@@ -105,6 +172,10 @@ fn error_exit_code(e: &CommandError) -> ExitCode {
         CommandError::CacheStore(_) => ExitCode::from(5),
         CommandError::Io(_) => ExitCode::from(3),
         CommandError::Json(_) => ExitCode::from(1),
+        CommandError::Timeout(_) => ExitCode::from(4),
+        CommandError::MissingToken => ExitCode::from(6),
+        // Conventional 128 + SIGINT
+        CommandError::Interrupted => ExitCode::from(130),
     }
 }
 
@@ -125,27 +196,35 @@ fn resolve_token_optional(cli: &Cli) -> commands::Result<Option<String>> {
     }
 
     // 2. Try config file and check storage method
-    match load_config() {
-        Ok(config) => {
-            // 3. If token_storage == "keyring", try keyring
-            if config.token_storage.as_deref() == Some("keyring") {
-                if let Some(token) = commands::keyring::get_token()? {
-                    return Ok(Some(token));
-                }
-            }
+    // An unreadable config file loads as the default, which has no token.
+    let ctx = CommandContext::from_cli(cli);
+    let profile = ctx.profile.as_deref();
+    resolve_stored_token(&ctx.config, || commands::keyring::get_token(profile))
+}
 
-            // 4. Fall back to config file token
-            if let Some(token) = config.token {
-                return Ok(Some(token));
+/// Resolves the token from the keyring (via `get_token`, if configured) or
+/// the config file.
+///
+/// A missing keyring backend is not fatal: a warning is printed and the
+/// config file token is used instead.
+fn resolve_stored_token(
+    config: &commands::config::Config,
+    get_token: impl FnOnce() -> Result<String, KeyringError>,
+) -> commands::Result<Option<String>> {
+    // 3. If token_storage == "keyring", try keyring
+    if config.token_storage.as_deref() == Some("keyring") {
+        match get_token() {
+            Ok(token) => return Ok(Some(token)),
+            Err(KeyringError::NotFound) => {}
+            Err(e @ KeyringError::Unavailable { .. }) => {
+                eprintln!("Warning: {e}. Falling back to the config file token.");
             }
-        }
-        Err(_) => {
-            // Config loading failed, continue
+            Err(e) => return Err(e.into()),
         }
     }
 
-    // No token found
-    Ok(None)
+    // 4. Fall back to config file token
+    Ok(config.token.clone())
 }
 
 /// Resolves the API token, running first-run setup if needed.
@@ -153,11 +232,25 @@ fn resolve_token_optional(cli: &Cli) -> commands::Result<Option<String>> {
 /// If no token is found and we're in an interactive terminal,
 /// runs the setup wizard to configure the token.
 async fn resolve_token(cli: &Cli) -> commands::Result<String> {
+    use std::io::IsTerminal;
+
+    resolve_token_or_setup(cli, std::io::stdin().is_terminal()).await
+}
+
+/// Resolves the token, falling back to interactive setup only on a terminal.
+///
+/// Without a terminal there's nobody to prompt, so a missing token fails
+/// fast with [`CommandError::MissingToken`].
+async fn resolve_token_or_setup(cli: &Cli, stdin_is_tty: bool) -> commands::Result<String> {
     // First, try the normal resolution chain
     if let Some(token) = resolve_token_optional(cli)? {
         return Ok(token);
     }
 
+    if !stdin_is_tty {
+        return Err(CommandError::MissingToken);
+    }
+
     // No token found - check if we should run setup
     let ctx = CommandContext::from_cli(cli);
 
@@ -168,6 +261,7 @@ async fn resolve_token(cli: &Cli) -> commands::Result<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use clap::Parser;
     use cli::Commands;
     use serial_test::serial;
     use std::env;
@@ -178,23 +272,45 @@ mod tests {
             verbose: false,
             quiet: false,
             json: false,
+            table: false,
+            format: None,
+            color: cli::ColorChoice::Auto,
             no_color: false,
+            plain_dates: false,
+            full_ids: false,
+            no_pager: false,
+            compact: false,
+            profile: None,
             token,
+            token_from_env: false,
             sync: false,
+            timeout: None,
+            retry_on_conflict: false,
+            env_file: None,
             command: Some(Commands::List {
                 filter: None,
+                strict_filter: false,
                 project: None,
-                label: None,
+                label: vec![],
+                label_match: cli::LabelMatch::All,
                 priority: None,
                 section: None,
                 overdue: false,
                 no_due: false,
+                due: None,
                 assigned_to: None,
+                changed_since: None,
+                flat: false,
+                no_header: false,
+                ids: false,
+                fields: vec![],
                 limit: 50,
                 all: false,
                 cursor: None,
                 sort: None,
                 reverse: false,
+                no_due_first: false,
+                no_due_last: false,
             }),
         }
     }
@@ -317,4 +433,175 @@ mod tests {
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), Some("flag-token".to_string()));
     }
+
+    #[tokio::test]
+    async fn test_timeout_flag_yields_timeout_error_and_exit_code() {
+        use std::time::Duration;
+        use todoist_api_rs::sync::SyncRequest;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/sync"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_delay(Duration::from_secs(3))
+                    .set_body_json(serde_json::json!({
+                        "sync_token": "token",
+                        "full_sync": true
+                    })),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let cli = Cli::try_parse_from(["td", "--timeout", "1", "sync"]).unwrap();
+        let ctx = CommandContext::from_cli(&cli);
+        assert_eq!(ctx.timeout_secs, 1);
+
+        let client = ctx
+            .client_builder("test-token")
+            .base_url(mock_server.uri())
+            .build()
+            .unwrap();
+        let err = client
+            .sync(SyncRequest::full_sync())
+            .await
+            .map_err(CommandError::from)
+            .unwrap_err()
+            .with_timeout(ctx.timeout_secs);
+
+        assert!(matches!(err, CommandError::Timeout(1)), "got: {err:?}");
+        assert_eq!(err.to_string(), "request timed out after 1s");
+        assert_eq!(error_code(&err), "TIMEOUT");
+        assert_eq!(error_exit_code(&err), ExitCode::from(4));
+        assert_ne!(
+            error_exit_code(&err),
+            error_exit_code(&CommandError::Io(std::io::Error::other("disk full")))
+        );
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_resolve_token_without_tty_returns_missing_token() {
+        let original_token = env::var("TODOIST_TOKEN").ok();
+        env::remove_var("TODOIST_TOKEN");
+        let original_config = env::var("TD_CONFIG").ok();
+        env::set_var("TD_CONFIG", "/tmp/td-test-nonexistent/config.toml");
+
+        let cli = cli_with_token(None);
+        let result = resolve_token_or_setup(&cli, false).await;
+
+        if let Some(val) = original_token {
+            env::set_var("TODOIST_TOKEN", val);
+        }
+        match original_config {
+            Some(val) => env::set_var("TD_CONFIG", val),
+            None => env::remove_var("TD_CONFIG"),
+        }
+
+        let err = result.unwrap_err();
+        assert!(matches!(err, CommandError::MissingToken), "got: {err:?}");
+        assert_eq!(
+            err.to_string(),
+            "no token; set TODOIST_TOKEN or run td setup"
+        );
+        assert_eq!(error_code(&err), "NO_TOKEN");
+        assert_eq!(error_exit_code(&err), ExitCode::from(6));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_token_without_tty_uses_available_token() {
+        let cli = cli_with_token(Some("flag-token".to_string()));
+        let token = resolve_token_or_setup(&cli, false).await.unwrap();
+        assert_eq!(token, "flag-token");
+    }
+
+    #[test]
+    fn test_empty_filter_error_has_code_and_hint() {
+        let err = CommandError::Filter(FilterError::EmptyExpression);
+        assert_eq!(error_code(&err), "EMPTY_FILTER");
+        assert_eq!(error_exit_code(&err), ExitCode::from(1));
+        assert!(error_hint(&err).unwrap().contains("--filter"));
+
+        let err = CommandError::Filter(FilterError::unexpected_token("&", 0));
+        assert_eq!(error_code(&err), "FILTER_ERROR");
+        assert!(error_hint(&err).is_none());
+    }
+
+    #[test]
+    fn test_corrupt_cache_error_hints_at_reset() {
+        let source = serde_json::from_str::<serde_json::Value>("{ not json").unwrap_err();
+        let err = CommandError::CacheStore(todoist_cache_rs::CacheStoreError::Deserialize {
+            path: "cache.json".into(),
+            source,
+        });
+        assert!(error_hint(&err).unwrap().contains("td sync --full --reset"));
+
+        let err = CommandError::CacheStore(todoist_cache_rs::CacheStoreError::ReadError {
+            path: "cache.json".into(),
+            source: std::io::Error::from(std::io::ErrorKind::PermissionDenied),
+        });
+        assert!(error_hint(&err).is_none());
+    }
+
+    #[test]
+    fn test_timeout_flag_defaults_and_rejects_zero() {
+        let cli = Cli::try_parse_from(["td", "sync"]).unwrap();
+        assert_eq!(CommandContext::from_cli(&cli).timeout_secs, 30);
+        assert!(Cli::try_parse_from(["td", "--timeout", "0", "sync"]).is_err());
+    }
+
+    fn keyring_config(token: Option<&str>) -> commands::config::Config {
+        commands::config::Config {
+            token: token.map(String::from),
+            token_storage: Some("keyring".to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_resolve_stored_token_prefers_keyring() {
+        use commands::keyring::{get_token_with, MockKeyring};
+
+        let config = keyring_config(Some("config-token"));
+        let backend = MockKeyring::Token("keyring-token".to_string());
+        let result = resolve_stored_token(&config, || get_token_with(&backend)).unwrap();
+        assert_eq!(result, Some("keyring-token".to_string()));
+    }
+
+    #[test]
+    fn test_resolve_stored_token_falls_back_when_keyring_unavailable() {
+        use commands::keyring::{get_token_with, MockKeyring};
+
+        let config = keyring_config(Some("config-token"));
+        for backend in [MockKeyring::Unavailable, MockKeyring::PlatformFailure] {
+            let result = resolve_stored_token(&config, || get_token_with(&backend)).unwrap();
+            assert_eq!(result, Some("config-token".to_string()));
+        }
+
+        // No config token either: nothing found, but no hard error
+        let config = keyring_config(None);
+        let result =
+            resolve_stored_token(&config, || get_token_with(&MockKeyring::Unavailable)).unwrap();
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_resolve_stored_token_falls_back_when_keyring_empty() {
+        use commands::keyring::{get_token_with, MockKeyring};
+
+        let config = keyring_config(Some("config-token"));
+        let result = resolve_stored_token(&config, || get_token_with(&MockKeyring::Empty)).unwrap();
+        assert_eq!(result, Some("config-token".to_string()));
+    }
+
+    #[test]
+    fn test_resolve_stored_token_propagates_other_keyring_errors() {
+        use commands::keyring::{get_token_with, MockKeyring};
+
+        let config = keyring_config(Some("config-token"));
+        let result = resolve_stored_token(&config, || get_token_with(&MockKeyring::BadEncoding));
+        assert!(matches!(result, Err(CommandError::Config(_))));
+    }
 }
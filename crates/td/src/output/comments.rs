@@ -8,7 +8,7 @@ use crate::commands::comments::{
     Comment, CommentAddResult, CommentDeleteResult, CommentEditResult,
 };
 
-use super::helpers::{format_datetime, truncate_id, truncate_str};
+use super::helpers::{csv_row, format_datetime, truncate_id, truncate_str};
 
 /// JSON output structure for comments list command.
 #[derive(Serialize)]
@@ -33,26 +33,12 @@ pub struct CommentListOutput<'a> {
 pub fn format_comments_json(
     comments: &[Comment],
     cache: &Cache,
+    compact: bool,
 ) -> Result<String, serde_json::Error> {
     let comments_output: Vec<CommentListOutput> = comments
         .iter()
         .map(|c| {
-            let (parent_type, parent_name) = if c.is_task_comment() {
-                let task_name = cache
-                    .items
-                    .iter()
-                    .find(|i| i.id == c.parent_id())
-                    .map(|i| i.content.as_str());
-                ("task", task_name)
-            } else {
-                let project_name = cache
-                    .projects
-                    .iter()
-                    .find(|p| p.id == c.parent_id())
-                    .map(|p| p.name.as_str());
-                ("project", project_name)
-            };
-
+            let (parent_type, parent_name) = comment_parent(c, cache);
             CommentListOutput {
                 id: c.id(),
                 content: c.content(),
@@ -68,7 +54,51 @@ pub fn format_comments_json(
         comments: comments_output,
     };
 
-    serde_json::to_string_pretty(&output)
+    super::to_json(&output, compact)
+}
+
+/// Formats comments as CSV with a header row.
+pub fn format_comments_csv(comments: &[Comment], cache: &Cache) -> String {
+    let mut output = csv_row([
+        "id",
+        "posted_at",
+        "parent_type",
+        "parent_id",
+        "parent_name",
+        "content",
+    ]);
+    for comment in comments {
+        let (parent_type, parent_name) = comment_parent(comment, cache);
+        output.push_str(&csv_row([
+            comment.id(),
+            comment.posted_at().unwrap_or(""),
+            parent_type,
+            comment.parent_id(),
+            parent_name.unwrap_or(""),
+            comment.content(),
+        ]));
+    }
+    output
+}
+
+/// Returns whether a comment belongs to a task or a project, and that
+/// parent's name if it is in the cache.
+fn comment_parent<'a>(comment: &Comment, cache: &'a Cache) -> (&'static str, Option<&'a str>) {
+    if comment.is_task_comment() {
+        let task_name = cache
+            .items
+            .iter()
+            .find(|i| i.id == comment.parent_id())
+            .map(|i| i.content.as_str());
+        ("task", task_name)
+    } else {
+        let project_name = cache
+            .projects
+            .iter()
+            .find(|p| p.id == comment.parent_id())
+            .map(|p| p.name.as_str());
+        ("project", project_name)
+    }
 }
 
 /// JSON output structure for a created comment.
@@ -83,7 +113,10 @@ pub struct CreatedCommentOutput<'a> {
 }
 
 /// Formats a created comment as JSON.
-pub fn format_created_comment(result: &CommentAddResult) -> Result<String, serde_json::Error> {
+pub fn format_created_comment(
+    result: &CommentAddResult,
+    compact: bool,
+) -> Result<String, serde_json::Error> {
     let parent_type = if result.is_task_comment {
         "task"
     } else {
@@ -97,7 +130,7 @@ pub fn format_created_comment(result: &CommentAddResult) -> Result<String, serde
         parent_name: result.parent_name.as_deref(),
     };
 
-    serde_json::to_string_pretty(&output)
+    super::to_json(&output, compact)
 }
 
 /// JSON output structure for an edited comment.
@@ -112,7 +145,10 @@ pub struct EditedCommentOutput<'a> {
 }
 
 /// Formats an edited comment as JSON.
-pub fn format_edited_comment(result: &CommentEditResult) -> Result<String, serde_json::Error> {
+pub fn format_edited_comment(
+    result: &CommentEditResult,
+    compact: bool,
+) -> Result<String, serde_json::Error> {
     let parent_type = if result.is_task_comment {
         "task"
     } else {
@@ -126,7 +162,7 @@ pub fn format_edited_comment(result: &CommentEditResult) -> Result<String, serde
         parent_name: result.parent_name.as_deref(),
     };
 
-    serde_json::to_string_pretty(&output)
+    super::to_json(&output, compact)
 }
 
 /// JSON output structure for a deleted comment.
@@ -142,7 +178,10 @@ pub struct DeletedCommentOutput<'a> {
 }
 
 /// Formats a deleted comment as JSON.
-pub fn format_deleted_comment(result: &CommentDeleteResult) -> Result<String, serde_json::Error> {
+pub fn format_deleted_comment(
+    result: &CommentDeleteResult,
+    compact: bool,
+) -> Result<String, serde_json::Error> {
     let parent_type = if result.is_task_comment {
         "task"
     } else {
@@ -157,13 +196,14 @@ pub fn format_deleted_comment(result: &CommentDeleteResult) -> Result<String, se
         status: "deleted",
     };
 
-    serde_json::to_string_pretty(&output)
+    super::to_json(&output, compact)
 }
 
 /// Formats comments as a table.
 pub fn format_comments_table(
     comments: &[Comment],
     parent_name: Option<&str>,
+    full: bool,
     use_colors: bool,
 ) -> String {
     if comments.is_empty() {
@@ -198,6 +238,20 @@ pub fn format_comments_table(
         let posted = comment.posted_at().map(format_datetime).unwrap_or_default();
         let posted_display = truncate_str(&posted, 20);
 
+        if full {
+            // Full bodies: continuation lines align under the content column
+            let mut lines = comment.content().lines();
+            let first = lines.next().unwrap_or("");
+            output.push_str(&format!(
+                "{:<8} {:<20} {}\n",
+                id_prefix, posted_display, first
+            ));
+            for line in lines {
+                output.push_str(&format!("{:<30}{}\n", "", line));
+            }
+            continue;
+        }
+
         // Truncate content to first line and max 50 chars for table view
         let content_first_line = comment.content().lines().next().unwrap_or("");
         let content_display = truncate_str(content_first_line, 50);
@@ -5,10 +5,10 @@ use serde::Serialize;
 use todoist_api_rs::sync::Filter;
 
 use crate::commands::filters::{
-    FilterAddResult, FilterDeleteResult, FilterEditResult, FilterShowResult,
+    FilterAddResult, FilterDeleteResult, FilterEditResult, FilterMatches, FilterShowResult,
 };
 
-use super::helpers::{truncate_id, truncate_str};
+use super::helpers::{csv_row, truncate_id, truncate_str};
 
 /// JSON output structure for filters list command.
 #[derive(Serialize)]
@@ -29,7 +29,10 @@ pub struct FilterOutput<'a> {
 }
 
 /// Formats filters as JSON.
-pub fn format_filters_json(filters: &[&Filter]) -> Result<String, serde_json::Error> {
+pub fn format_filters_json(
+    filters: &[&Filter],
+    compact: bool,
+) -> Result<String, serde_json::Error> {
     let filters_output: Vec<FilterOutput> = filters
         .iter()
         .map(|f| FilterOutput {
@@ -46,7 +49,23 @@ pub fn format_filters_json(filters: &[&Filter]) -> Result<String, serde_json::Er
         filters: filters_output,
     };
 
-    serde_json::to_string_pretty(&output)
+    super::to_json(&output, compact)
+}
+
+/// Formats filters as CSV with a header row.
+pub fn format_filters_csv(filters: &[&Filter]) -> String {
+    let mut output = csv_row(["id", "name", "query", "color", "is_favorite", "item_order"]);
+    for filter in filters {
+        output.push_str(&csv_row([
+            filter.id.as_str(),
+            &filter.name,
+            &filter.query,
+            filter.color.as_deref().unwrap_or(""),
+            &filter.is_favorite.to_string(),
+            &filter.item_order.to_string(),
+        ]));
+    }
+    output
 }
 
 /// Formats filters as a table.
@@ -101,7 +120,10 @@ pub struct CreatedFilterOutput<'a> {
 }
 
 /// Formats a created filter as JSON.
-pub fn format_created_filter(result: &FilterAddResult) -> Result<String, serde_json::Error> {
+pub fn format_created_filter(
+    result: &FilterAddResult,
+    compact: bool,
+) -> Result<String, serde_json::Error> {
     let output = CreatedFilterOutput {
         id: &result.id,
         name: &result.name,
@@ -110,7 +132,7 @@ pub fn format_created_filter(result: &FilterAddResult) -> Result<String, serde_j
         is_favorite: result.is_favorite,
     };
 
-    serde_json::to_string_pretty(&output)
+    super::to_json(&output, compact)
 }
 
 /// JSON output structure for filter details (filters show command).
@@ -123,10 +145,43 @@ pub struct FilterDetailsOutput<'a> {
     pub color: Option<&'a str>,
     pub is_favorite: bool,
     pub item_order: i32,
+    /// Open cached tasks the query matches; absent when it can't be evaluated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub match_count: Option<usize>,
+    /// Why the query couldn't be evaluated locally.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub match_error: Option<&'a str>,
+    /// The first matching tasks (with `--preview`).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub preview: Vec<PreviewTaskOutput<'a>>,
+}
+
+/// JSON output structure for a task in a filter preview.
+#[derive(Serialize)]
+pub struct PreviewTaskOutput<'a> {
+    pub id: &'a str,
+    pub content: &'a str,
 }
 
 /// Formats filter details as JSON (filters show command).
-pub fn format_filter_details_json(result: &FilterShowResult) -> Result<String, serde_json::Error> {
+pub fn format_filter_details_json(
+    result: &FilterShowResult,
+    compact: bool,
+) -> Result<String, serde_json::Error> {
+    let (match_count, match_error, preview) = match &result.matches {
+        FilterMatches::Evaluated { count, preview } => (
+            Some(*count),
+            None,
+            preview
+                .iter()
+                .map(|i| PreviewTaskOutput {
+                    id: &i.id,
+                    content: &i.content,
+                })
+                .collect(),
+        ),
+        FilterMatches::Unsupported(e) => (None, Some(e.as_str()), Vec::new()),
+    };
     let output = FilterDetailsOutput {
         id: &result.filter.id,
         name: &result.filter.name,
@@ -134,9 +189,12 @@ pub fn format_filter_details_json(result: &FilterShowResult) -> Result<String, s
         color: result.filter.color.as_deref(),
         is_favorite: result.filter.is_favorite,
         item_order: result.filter.item_order,
+        match_count,
+        match_error,
+        preview,
     };
 
-    serde_json::to_string_pretty(&output)
+    super::to_json(&output, compact)
 }
 
 /// Formats filter details as a human-readable table (filters show command).
@@ -175,6 +233,27 @@ pub fn format_filter_details_table(result: &FilterShowResult, use_colors: bool)
     // Order
     output.push_str(&format!("Order: {}\n", result.filter.item_order));
 
+    // Matches
+    match &result.matches {
+        FilterMatches::Evaluated { count, preview } => {
+            let noun = if *count == 1 { "task" } else { "tasks" };
+            output.push_str(&format!("Matches: {count} open {noun} in cache\n"));
+            for item in preview {
+                output.push_str(&format!(
+                    "  {} {}\n",
+                    truncate_id(&item.id),
+                    truncate_str(&item.content, 60)
+                ));
+            }
+            if preview.len() < *count && !preview.is_empty() {
+                output.push_str(&format!("  ... and {} more\n", count - preview.len()));
+            }
+        }
+        FilterMatches::Unsupported(_) => {
+            output.push_str("Matches: unknown (query uses syntax td can't evaluate locally)\n");
+        }
+    }
+
     output
 }
 
@@ -187,14 +266,17 @@ pub struct EditedFilterOutput<'a> {
 }
 
 /// Formats an edited filter as JSON.
-pub fn format_edited_filter(result: &FilterEditResult) -> Result<String, serde_json::Error> {
+pub fn format_edited_filter(
+    result: &FilterEditResult,
+    compact: bool,
+) -> Result<String, serde_json::Error> {
     let output = EditedFilterOutput {
         id: &result.id,
         name: &result.name,
         updated_fields: &result.updated_fields,
     };
 
-    serde_json::to_string_pretty(&output)
+    super::to_json(&output, compact)
 }
 
 /// JSON output structure for a deleted filter.
@@ -206,12 +288,15 @@ pub struct DeletedFilterOutput<'a> {
 }
 
 /// Formats a deleted filter as JSON.
-pub fn format_deleted_filter(result: &FilterDeleteResult) -> Result<String, serde_json::Error> {
+pub fn format_deleted_filter(
+    result: &FilterDeleteResult,
+    compact: bool,
+) -> Result<String, serde_json::Error> {
     let output = DeletedFilterOutput {
         id: &result.id,
         name: &result.name,
         status: "deleted",
     };
 
-    serde_json::to_string_pretty(&output)
+    super::to_json(&output, compact)
 }
@@ -1,11 +1,17 @@
 //! Common helper functions for output formatting.
 
-use chrono::{Local, NaiveDate};
+use chrono::{DateTime, Local, NaiveDate, Utc};
 use owo_colors::OwoColorize;
+use todoist_api_rs::sync::{Due, Label};
+
+use super::{DateStyle, IdStyle};
 
 /// Number of characters to show when displaying truncated IDs.
 pub const ID_DISPLAY_LENGTH: usize = 6;
 
+/// Base URL of a task in the Todoist web app.
+pub const TASK_URL_BASE: &str = "https://todoist.com/app/task/";
+
 /// Number of minutes in one hour.
 pub const MINUTES_PER_HOUR: i32 = 60;
 
@@ -17,11 +23,56 @@ pub const MINUTES_PER_DAY: i32 = 1440;
 /// Uses character-based (not byte-based) truncation to safely handle
 /// multi-byte UTF-8 characters.
 pub fn truncate_id(id: &str) -> String {
-    let char_count = id.chars().count();
-    if char_count > ID_DISPLAY_LENGTH {
-        id.chars().take(ID_DISPLAY_LENGTH).collect()
-    } else {
-        id.to_string()
+    truncate_id_to(id, ID_DISPLAY_LENGTH)
+}
+
+/// Truncates an ID to `len` characters for display.
+///
+/// Pair with [`unique_id_prefix_len`] so prefixes in a listing stay
+/// unambiguous.
+pub fn truncate_id_to(id: &str, len: usize) -> String {
+    id.chars().take(len).collect()
+}
+
+/// Returns the shortest prefix length at which every distinct ID in `ids`
+/// is unique, like git short hashes.
+///
+/// The result is at least [`ID_DISPLAY_LENGTH`] and at most the length of
+/// the longest ID.
+pub fn unique_id_prefix_len<'a>(ids: impl IntoIterator<Item = &'a str>) -> usize {
+    let mut ids: Vec<Vec<char>> = ids.into_iter().map(|id| id.chars().collect()).collect();
+    ids.sort();
+    ids.dedup();
+
+    let longest = ids.iter().map(Vec::len).max().unwrap_or(0);
+    let needed = ids
+        .windows(2)
+        .map(|pair| {
+            let common = pair[0]
+                .iter()
+                .zip(&pair[1])
+                .take_while(|(a, b)| a == b)
+                .count();
+            common + 1
+        })
+        .max()
+        .unwrap_or(0);
+
+    needed.min(longest).max(ID_DISPLAY_LENGTH)
+}
+
+/// Returns how many characters of each ID to display for `style`.
+///
+/// Short style defers to [`unique_id_prefix_len`]; full style returns the
+/// length of the longest ID so nothing is truncated.
+pub fn id_display_len<'a>(ids: impl IntoIterator<Item = &'a str>, style: IdStyle) -> usize {
+    match style {
+        IdStyle::Short => unique_id_prefix_len(ids),
+        IdStyle::Full => ids
+            .into_iter()
+            .map(|id| id.chars().count())
+            .max()
+            .unwrap_or(0),
     }
 }
 
@@ -39,6 +90,17 @@ pub fn truncate_str(s: &str, max_len: usize) -> String {
     }
 }
 
+/// Flattens text onto one line for table rows.
+///
+/// Newlines, tabs and other control characters (including terminal escape
+/// sequences' ESC) are replaced with spaces so they can't break the fixed
+/// columns or change the terminal state. Detail views show text unchanged.
+pub fn sanitize_single_line(s: &str) -> String {
+    s.chars()
+        .map(|c| if c.is_control() { ' ' } else { c })
+        .collect()
+}
+
 /// Formats priority for display.
 pub fn format_priority(api_priority: i32, use_colors: bool) -> String {
     let user_priority = 5 - api_priority;
@@ -102,16 +164,148 @@ pub fn format_due(due_date: Option<&String>, use_colors: bool) -> String {
     }
 }
 
-/// Formats labels for display.
-pub fn format_labels(labels: &[String], max_len: usize) -> String {
+/// Formats a due date for display in the given [`DateStyle`].
+///
+/// Relative style matches [`format_due`]; absolute style renders
+/// `YYYY-MM-DD`, plus `HH:MM` for timed due dates.
+pub fn format_due_styled(due: Option<&Due>, style: DateStyle, use_colors: bool) -> String {
+    match style {
+        DateStyle::Relative => format_due(due.map(|d| &d.date), use_colors),
+        DateStyle::Absolute => {
+            let Some(due) = due else {
+                return String::new();
+            };
+            let display = format_due_absolute(due);
+            match due_naive_date(due) {
+                Some(date) if use_colors => color_due(display, date),
+                _ => display,
+            }
+        }
+    }
+}
+
+/// Formats a due date as `YYYY-MM-DD`, plus ` HH:MM` when timed.
+pub fn format_due_absolute(due: &Due) -> String {
+    let date = due_naive_date(due)
+        .map(|d| d.format("%Y-%m-%d").to_string())
+        .unwrap_or_else(|| due.date.clone());
+    match due_time(due) {
+        Some(hm) => format!("{date} {hm}"),
+        None => date,
+    }
+}
+
+/// Parses the calendar date of a due date.
+fn due_naive_date(due: &Due) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(&due.date, "%Y-%m-%d").ok()
+}
+
+/// Extracts `HH:MM` from a timed due date's datetime, if any.
+pub fn due_time(due: &Due) -> Option<String> {
+    let time_part = due.datetime.as_deref()?.split('T').nth(1)?;
+    let time_clean = time_part.trim_end_matches('Z');
+    let hm: String = time_clean.split(':').take(2).collect::<Vec<_>>().join(":");
+    (!hm.is_empty()).then_some(hm)
+}
+
+/// Colors a due date display red when overdue and yellow when due today.
+fn color_due(display: String, date: NaiveDate) -> String {
+    let today = Local::now().date_naive();
+    if date < today {
+        display.red().to_string()
+    } else if date == today {
+        display.yellow().to_string()
+    } else {
+        display
+    }
+}
+
+/// Returns the RGB value for a Todoist palette color name (e.g., "berry_red").
+pub fn todoist_color_rgb(name: &str) -> Option<(u8, u8, u8)> {
+    let rgb = match name {
+        "berry_red" => (0xb8, 0x25, 0x6f),
+        "red" => (0xdb, 0x40, 0x35),
+        "orange" => (0xff, 0x99, 0x33),
+        "yellow" => (0xfa, 0xd0, 0x00),
+        "olive_green" => (0xaf, 0xb8, 0x3b),
+        "lime_green" => (0x7e, 0xcc, 0x49),
+        "green" => (0x29, 0x94, 0x38),
+        "mint_green" => (0x6a, 0xcc, 0xbc),
+        "teal" => (0x15, 0x8f, 0xad),
+        "sky_blue" => (0x14, 0xaa, 0xf5),
+        "light_blue" => (0x96, 0xc3, 0xeb),
+        "blue" => (0x40, 0x73, 0xff),
+        "grape" => (0x88, 0x4d, 0xff),
+        "violet" => (0xaf, 0x38, 0xeb),
+        "lavender" => (0xeb, 0x96, 0xeb),
+        "magenta" => (0xe0, 0x51, 0x94),
+        "salmon" => (0xff, 0x8d, 0x85),
+        "charcoal" => (0x80, 0x80, 0x80),
+        "grey" => (0xb8, 0xb8, 0xb8),
+        "taupe" => (0xcc, 0xac, 0x93),
+        _ => return None,
+    };
+    Some(rgb)
+}
+
+/// Formats labels for display, truncated to `max_len` visible characters.
+///
+/// With colors, each `@label` is drawn in its cached label's palette color.
+/// Labels missing from `known` (or with an unknown color) stay uncolored.
+pub fn format_labels(
+    labels: &[String],
+    known: &[Label],
+    max_len: usize,
+    use_colors: bool,
+) -> String {
     if labels.is_empty() {
         return String::new();
     }
 
     let formatted: Vec<String> = labels.iter().map(|l| format!("@{l}")).collect();
     let joined = formatted.join(" ");
+    let display = truncate_str(&joined, max_len);
 
-    truncate_str(&joined, max_len)
+    if !use_colors {
+        return display;
+    }
+
+    // Color each label's visible portion; truncation only cuts the tail
+    let truncated = display != joined;
+    let mut budget = if truncated {
+        display.chars().count().saturating_sub(3)
+    } else {
+        display.chars().count()
+    };
+    let mut output = String::new();
+    for (i, (name, text)) in labels.iter().zip(&formatted).enumerate() {
+        if i > 0 {
+            if budget == 0 {
+                break;
+            }
+            output.push(' ');
+            budget -= 1;
+        }
+        let visible: String = text.chars().take(budget).collect();
+        budget -= visible.chars().count();
+
+        let rgb = known
+            .iter()
+            .find(|l| &l.name == name && !l.is_deleted)
+            .and_then(|l| l.color.as_deref())
+            .and_then(todoist_color_rgb);
+        match rgb {
+            Some((r, g, b)) => output.push_str(&visible.truecolor(r, g, b).to_string()),
+            None => output.push_str(&visible),
+        }
+        if budget == 0 {
+            break;
+        }
+    }
+    if truncated {
+        output.push_str("...");
+    }
+    output
 }
 
 /// Formats priority for verbose display (show command).
@@ -137,13 +331,15 @@ pub fn format_priority_verbose(api_priority: i32, use_colors: bool) -> String {
 }
 
 /// Formats a due date for verbose display (show command).
-pub fn format_due_verbose(due: &todoist_api_rs::sync::Due, use_colors: bool) -> String {
+pub fn format_due_verbose(due: &Due, style: DateStyle, use_colors: bool) -> String {
     // Try to parse and format the date nicely
     let mut result = if let Ok(date) = NaiveDate::parse_from_str(&due.date, "%Y-%m-%d") {
         let today = Local::now().date_naive();
         let tomorrow = today + chrono::Duration::days(1);
 
-        let date_str = if date == today {
+        let date_str = if style == DateStyle::Absolute {
+            date.format("%Y-%m-%d").to_string()
+        } else if date == today {
             "Today".to_string()
         } else if date == tomorrow {
             "Tomorrow".to_string()
@@ -170,16 +366,8 @@ pub fn format_due_verbose(due: &todoist_api_rs::sync::Due, use_colors: bool) ->
     };
 
     // Add time if available
-    if let Some(ref datetime) = due.datetime {
-        // Try to extract time from datetime string
-        if let Some(time_part) = datetime.split('T').nth(1) {
-            // Strip timezone info and format nicely
-            let time_clean = time_part.trim_end_matches('Z');
-            let hm: String = time_clean.split(':').take(2).collect::<Vec<_>>().join(":");
-            if !hm.is_empty() {
-                result.push_str(&format!(" at {}", hm));
-            }
-        }
+    if let Some(hm) = due_time(due) {
+        result.push_str(&format!(" at {}", hm));
     }
 
     // Add recurring indicator
@@ -206,6 +394,117 @@ pub fn format_datetime(datetime: &str) -> String {
     }
 }
 
+/// Formats how long ago `added_at` was, relative to `now`, as a compact
+/// duration ("3d", "2w", "4mo", "1y").
+///
+/// Returns an empty string when `added_at` is missing or unparseable.
+pub fn format_age(added_at: Option<&str>, now: DateTime<Utc>) -> String {
+    let Some(added) = added_at.and_then(|s| DateTime::parse_from_rfc3339(s).ok()) else {
+        return String::new();
+    };
+
+    let days = (now - added.with_timezone(&Utc)).num_days().max(0);
+    if days < 14 {
+        format!("{days}d")
+    } else if days < 60 {
+        format!("{}w", days / 7)
+    } else if days < 365 {
+        format!("{}mo", days / 30)
+    } else {
+        format!("{}y", days / 365)
+    }
+}
+
+/// Formats cache freshness for a footer: "last synced 7m ago", or
+/// "never synced" when `age` is `None`.
+pub fn format_sync_age(age: Option<chrono::Duration>) -> String {
+    let Some(age) = age else {
+        return "never synced".to_string();
+    };
+
+    let minutes = age.num_minutes();
+    let ago = if minutes < 1 {
+        "just now".to_string()
+    } else if minutes < 60 {
+        format!("{minutes}m ago")
+    } else if minutes < MINUTES_PER_DAY as i64 {
+        format!("{}h ago", age.num_hours())
+    } else {
+        format!("{}d ago", age.num_days())
+    };
+    format!("last synced {ago}")
+}
+
+/// Formats one CSV record, quoting fields that contain commas, quotes, or
+/// line breaks (RFC 4180). The returned line ends with `\n`.
+pub fn csv_row<S: AsRef<str>>(fields: impl IntoIterator<Item = S>) -> String {
+    let fields: Vec<String> = fields
+        .into_iter()
+        .map(|field| {
+            let field = field.as_ref();
+            if field.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field.to_string()
+            }
+        })
+        .collect();
+    format!("{}\n", fields.join(","))
+}
+
+/// Formats a single record (a detail view or report) as `field,value` CSV rows.
+///
+/// The record is serialized the same way as its JSON output and flattened:
+/// nested objects use dotted field names (`local.by_priority.p1`), and lists
+/// of plain values are joined with `;` like task labels are.
+pub fn format_fields_csv<T: serde::Serialize + ?Sized>(
+    record: &T,
+) -> Result<String, serde_json::Error> {
+    fn flatten(prefix: &str, value: &serde_json::Value, output: &mut String) {
+        use serde_json::Value;
+
+        let field = |key: &str| {
+            if prefix.is_empty() {
+                key.to_string()
+            } else {
+                format!("{prefix}.{key}")
+            }
+        };
+        let scalar = |value: &Value| match value {
+            Value::Null => String::new(),
+            Value::String(s) => s.clone(),
+            other => other.to_string(),
+        };
+
+        match value {
+            Value::Object(map) => {
+                for (key, value) in map {
+                    flatten(&field(key), value, output);
+                }
+            }
+            Value::Array(values) if values.iter().all(|v| !v.is_object() && !v.is_array()) => {
+                let joined: Vec<String> = values.iter().map(scalar).collect();
+                output.push_str(&csv_row([prefix, &joined.join(";")]));
+            }
+            Value::Array(values) => {
+                for (index, value) in values.iter().enumerate() {
+                    flatten(&field(&index.to_string()), value, output);
+                }
+            }
+            other => output.push_str(&csv_row([prefix, &scalar(other)])),
+        }
+    }
+
+    let mut output = csv_row(["field", "value"]);
+    flatten("", &serde_json::to_value(record)?, &mut output);
+    Ok(output)
+}
+
+/// Returns the Todoist web app URL for a task.
+pub fn task_url(task_id: &str) -> String {
+    format!("{TASK_URL_BASE}{task_id}")
+}
+
 /// Formats a reminder for display.
 pub fn format_reminder(reminder: &todoist_api_rs::sync::Reminder) -> String {
     use todoist_api_rs::models::ReminderType;
@@ -243,6 +542,131 @@ pub fn format_reminder(reminder: &todoist_api_rs::sync::Reminder) -> String {
 mod tests {
     use super::*;
 
+    fn make_due(date: NaiveDate, datetime: Option<&str>) -> Due {
+        Due {
+            date: date.format("%Y-%m-%d").to_string(),
+            datetime: datetime.map(String::from),
+            is_recurring: false,
+            string: None,
+            timezone: None,
+            lang: None,
+        }
+    }
+
+    #[test]
+    fn test_csv_row_quotes_special_fields() {
+        assert_eq!(csv_row(["a", "b c", ""]), "a,b c,\n");
+        assert_eq!(
+            csv_row(["x,y", "say \"hi\"", "two\nlines"]),
+            "\"x,y\",\"say \"\"hi\"\"\",\"two\nlines\"\n"
+        );
+    }
+
+    #[test]
+    fn test_format_fields_csv_flattens_nested_records() {
+        let record = serde_json::json!({
+            "name": "td",
+            "counts": { "tasks": 3, "labels": null },
+            "formats": ["table", "csv"],
+            "groups": [{ "keep": "a" }],
+        });
+        assert_eq!(
+            format_fields_csv(&record).unwrap(),
+            "field,value\n\
+             counts.labels,\n\
+             counts.tasks,3\n\
+             formats,table;csv\n\
+             groups.0.keep,a\n\
+             name,td\n"
+        );
+    }
+
+    #[test]
+    fn test_task_url() {
+        assert_eq!(
+            task_url("6Jf8VQXxpwv56VQ7"),
+            "https://todoist.com/app/task/6Jf8VQXxpwv56VQ7"
+        );
+    }
+
+    #[test]
+    fn test_format_age_scales() {
+        let now = DateTime::parse_from_rfc3339("2025-06-30T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let age = |added: &str| format_age(Some(added), now);
+
+        assert_eq!(age("2025-06-30T08:00:00Z"), "0d");
+        assert_eq!(age("2025-06-27T12:00:00.000000Z"), "3d");
+        assert_eq!(age("2025-06-16T12:00:00Z"), "2w");
+        assert_eq!(age("2025-03-01T12:00:00Z"), "4mo");
+        assert_eq!(age("2023-06-01T12:00:00Z"), "2y");
+    }
+
+    #[test]
+    fn test_format_age_missing_or_invalid() {
+        let now = Utc::now();
+        assert_eq!(format_age(None, now), "");
+        assert_eq!(format_age(Some("not a date"), now), "");
+    }
+
+    #[test]
+    fn test_format_sync_age() {
+        let age = |secs: i64| format_sync_age(Some(chrono::Duration::seconds(secs)));
+
+        assert_eq!(age(20), "last synced just now");
+        assert_eq!(age(7 * 60 + 30), "last synced 7m ago");
+        assert_eq!(age(3 * 3600 + 59 * 60), "last synced 3h ago");
+        assert_eq!(age(2 * 86400 + 3600), "last synced 2d ago");
+    }
+
+    #[test]
+    fn test_format_sync_age_never_synced() {
+        assert_eq!(format_sync_age(None), "never synced");
+    }
+
+    #[test]
+    fn test_format_due_styled_relative_vs_absolute() {
+        let today = Local::now().date_naive();
+        let due = make_due(today, None);
+
+        assert_eq!(
+            format_due_styled(Some(&due), DateStyle::Relative, false),
+            "Today"
+        );
+        assert_eq!(
+            format_due_styled(Some(&due), DateStyle::Absolute, false),
+            today.format("%Y-%m-%d").to_string()
+        );
+        assert_eq!(format_due_styled(None, DateStyle::Absolute, false), "");
+    }
+
+    #[test]
+    fn test_format_due_styled_absolute_includes_time() {
+        let date = NaiveDate::from_ymd_opt(2025, 3, 9).unwrap();
+        let due = make_due(date, Some("2025-03-09T14:30:00Z"));
+
+        assert_eq!(
+            format_due_styled(Some(&due), DateStyle::Absolute, false),
+            "2025-03-09 14:30"
+        );
+    }
+
+    #[test]
+    fn test_format_due_verbose_absolute() {
+        let today = Local::now().date_naive();
+        let due = make_due(today, None);
+
+        assert_eq!(
+            format_due_verbose(&due, DateStyle::Relative, false),
+            "Today"
+        );
+        assert_eq!(
+            format_due_verbose(&due, DateStyle::Absolute, false),
+            today.format("%Y-%m-%d").to_string()
+        );
+    }
+
     #[test]
     fn test_truncate_id() {
         assert_eq!(truncate_id("abcdef"), "abcdef");
@@ -250,6 +674,26 @@ mod tests {
         assert_eq!(truncate_id("abc"), "abc");
     }
 
+    #[test]
+    fn test_unique_id_prefix_len_expands_on_collision() {
+        let ids = ["abcdef12", "abcdef34", "zzzzzz99"];
+        let len = unique_id_prefix_len(ids);
+        assert_eq!(len, 7);
+        assert_eq!(truncate_id_to(ids[0], len), "abcdef1");
+        assert_eq!(truncate_id_to(ids[1], len), "abcdef3");
+    }
+
+    #[test]
+    fn test_unique_id_prefix_len_bounds() {
+        // No collisions: keep the default length
+        assert_eq!(unique_id_prefix_len(["abcdef12", "ghijkl34"]), 6);
+        assert_eq!(unique_id_prefix_len(Vec::<&str>::new()), 6);
+        // One ID is a prefix of another: capped at the longest ID
+        assert_eq!(unique_id_prefix_len(["abcdefgh", "abcdefghij"]), 9);
+        // Duplicates are not collisions
+        assert_eq!(unique_id_prefix_len(["abcdefgh", "abcdefgh"]), 6);
+    }
+
     #[test]
     fn test_truncate_id_utf8_emoji() {
         // Emoji are multi-byte UTF-8 characters (4 bytes each)
@@ -270,6 +714,15 @@ mod tests {
         assert_eq!(truncate_id("你好世界测试"), "你好世界测试");
     }
 
+    #[test]
+    fn test_sanitize_single_line() {
+        assert_eq!(sanitize_single_line("plain"), "plain");
+        assert_eq!(sanitize_single_line("one\ntwo"), "one two");
+        assert_eq!(sanitize_single_line("a\r\n\tb"), "a   b");
+        assert_eq!(sanitize_single_line("\u{1b}[31mred"), " [31mred");
+        assert_eq!(sanitize_single_line("🎉 ok"), "🎉 ok");
+    }
+
     #[test]
     fn test_truncate_str() {
         assert_eq!(truncate_str("short", 10), "short");
@@ -314,11 +767,58 @@ mod tests {
 
     #[test]
     fn test_format_labels() {
-        assert_eq!(format_labels(&[], 15), "");
-        assert_eq!(format_labels(&["urgent".to_string()], 15), "@urgent");
+        assert_eq!(format_labels(&[], &[], 15, false), "");
+        assert_eq!(
+            format_labels(&["urgent".to_string()], &[], 15, false),
+            "@urgent"
+        );
         assert_eq!(
-            format_labels(&["a".to_string(), "b".to_string()], 15),
+            format_labels(&["a".to_string(), "b".to_string()], &[], 15, false),
             "@a @b"
         );
     }
+
+    fn make_label(name: &str, color: &str) -> Label {
+        Label {
+            id: format!("label-{name}"),
+            name: name.to_string(),
+            color: Some(color.to_string()),
+            item_order: 0,
+            is_deleted: false,
+            is_favorite: false,
+        }
+    }
+
+    #[test]
+    fn test_format_labels_colors_known_labels() {
+        let known = vec![make_label("urgent", "red")];
+        let labels = vec!["urgent".to_string(), "misc".to_string()];
+
+        let output = format_labels(&labels, &known, 30, true);
+        assert_eq!(
+            output,
+            format!("{} @misc", "@urgent".truecolor(0xdb, 0x40, 0x35))
+        );
+        // Only the known label gets an escape sequence
+        assert_eq!(output.matches("\x1b[38;2;").count(), 1);
+        assert!(output.contains("\x1b[38;2;219;64;53m@urgent"));
+
+        // --no-color renders plain text
+        assert_eq!(format_labels(&labels, &known, 30, false), "@urgent @misc");
+    }
+
+    #[test]
+    fn test_format_labels_colored_truncation_matches_plain() {
+        let known = vec![make_label("important", "blue")];
+        let labels = vec!["important".to_string(), "later".to_string()];
+
+        let plain = format_labels(&labels, &known, 15, false);
+        assert_eq!(plain, "@important @...");
+
+        let colored = format_labels(&labels, &known, 15, true);
+        assert_eq!(
+            colored,
+            format!("{} @...", "@important".truecolor(0x40, 0x73, 0xff))
+        );
+    }
 }
@@ -6,7 +6,7 @@ use todoist_api_rs::sync::Label;
 
 use crate::commands::labels::{LabelAddResult, LabelDeleteResult, LabelEditResult};
 
-use super::helpers::{truncate_id, truncate_str};
+use super::helpers::{csv_row, truncate_id, truncate_str};
 
 /// JSON output structure for labels list command.
 #[derive(Serialize)]
@@ -26,7 +26,7 @@ pub struct LabelOutput<'a> {
 }
 
 /// Formats labels as JSON.
-pub fn format_labels_json(labels: &[&Label]) -> Result<String, serde_json::Error> {
+pub fn format_labels_json(labels: &[&Label], compact: bool) -> Result<String, serde_json::Error> {
     let labels_output: Vec<LabelOutput> = labels
         .iter()
         .map(|l| LabelOutput {
@@ -42,7 +42,22 @@ pub fn format_labels_json(labels: &[&Label]) -> Result<String, serde_json::Error
         labels: labels_output,
     };
 
-    serde_json::to_string_pretty(&output)
+    super::to_json(&output, compact)
+}
+
+/// Formats labels as CSV with the same fields as [`format_labels_json`].
+pub fn format_labels_csv(labels: &[&Label]) -> String {
+    let mut output = csv_row(["id", "name", "color", "is_favorite", "item_order"]);
+    for label in labels {
+        output.push_str(&csv_row([
+            label.id.as_str(),
+            &label.name,
+            label.color.as_deref().unwrap_or(""),
+            &label.is_favorite.to_string(),
+            &label.item_order.to_string(),
+        ]));
+    }
+    output
 }
 
 /// Formats labels as a table.
@@ -99,18 +114,29 @@ pub struct CreatedLabelOutput<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub color: Option<&'a str>,
     pub is_favorite: bool,
+    pub created: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub labeled_task_ids: Vec<&'a str>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub skipped_task_ids: Vec<&'a str>,
 }
 
 /// Formats a created label as JSON.
-pub fn format_created_label(result: &LabelAddResult) -> Result<String, serde_json::Error> {
+pub fn format_created_label(
+    result: &LabelAddResult,
+    compact: bool,
+) -> Result<String, serde_json::Error> {
     let output = CreatedLabelOutput {
         id: &result.id,
         name: &result.name,
         color: result.color.as_deref(),
         is_favorite: result.is_favorite,
+        created: result.created,
+        labeled_task_ids: result.labeled_task_ids.iter().map(String::as_str).collect(),
+        skipped_task_ids: result.skipped_task_ids.iter().map(String::as_str).collect(),
     };
 
-    serde_json::to_string_pretty(&output)
+    super::to_json(&output, compact)
 }
 
 /// JSON output structure for an edited label.
@@ -122,14 +148,17 @@ pub struct EditedLabelOutput<'a> {
 }
 
 /// Formats an edited label as JSON.
-pub fn format_edited_label(result: &LabelEditResult) -> Result<String, serde_json::Error> {
+pub fn format_edited_label(
+    result: &LabelEditResult,
+    compact: bool,
+) -> Result<String, serde_json::Error> {
     let output = EditedLabelOutput {
         id: &result.id,
         name: &result.name,
         updated_fields: &result.updated_fields,
     };
 
-    serde_json::to_string_pretty(&output)
+    super::to_json(&output, compact)
 }
 
 /// JSON output structure for a deleted label.
@@ -138,15 +167,28 @@ pub struct DeletedLabelOutput<'a> {
     pub id: &'a str,
     pub name: &'a str,
     pub status: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reassigned_to: Option<&'a str>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub retagged_task_ids: Vec<&'a str>,
 }
 
 /// Formats a deleted label as JSON.
-pub fn format_deleted_label(result: &LabelDeleteResult) -> Result<String, serde_json::Error> {
+pub fn format_deleted_label(
+    result: &LabelDeleteResult,
+    compact: bool,
+) -> Result<String, serde_json::Error> {
     let output = DeletedLabelOutput {
         id: &result.id,
         name: &result.name,
         status: "deleted",
+        reassigned_to: result.reassigned_to.as_deref(),
+        retagged_task_ids: result
+            .retagged_task_ids
+            .iter()
+            .map(String::as_str)
+            .collect(),
     };
 
-    serde_json::to_string_pretty(&output)
+    super::to_json(&output, compact)
 }
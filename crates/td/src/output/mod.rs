@@ -21,46 +21,168 @@ mod reminders;
 mod sections;
 mod tasks;
 
+use serde::Serialize;
+
+/// Serializes `value` as JSON, minified when `compact` (`--compact`) and pretty otherwise.
+///
+/// Every JSON formatter goes through this so the setting applies everywhere.
+pub fn to_json<T: Serialize + ?Sized>(value: &T, compact: bool) -> serde_json::Result<String> {
+    if compact {
+        serde_json::to_string(value)
+    } else {
+        serde_json::to_string_pretty(value)
+    }
+}
+
+/// How table output renders dates.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DateStyle {
+    /// Relative labels such as "Today" or "2 days ago".
+    #[default]
+    Relative,
+    /// Absolute `YYYY-MM-DD`, plus `HH:MM` when the due date has a time.
+    Absolute,
+}
+
+/// How table output renders IDs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum IdStyle {
+    /// The shortest prefix that keeps every listed ID unique.
+    #[default]
+    Short,
+    /// Complete IDs, for copying into scripts.
+    Full,
+}
+
+/// Optional columns for task tables, chosen with `list --fields`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum TaskField {
+    /// The responsible collaborator's name ("me" for you); blank when unassigned.
+    Assignee,
+    /// How long ago the task was created ("3d", "2w", "4mo").
+    Age,
+}
+
+/// Output format for command results.
+///
+/// Set with `--format` (or `--json`) or the `output.format` config key.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable tables and messages.
+    #[default]
+    Table,
+    /// JSON for scripting.
+    Json,
+    /// Comma-separated values: one row per record for lists, one row for a
+    /// created task, and `field,value` rows for detail views and reports.
+    Csv,
+}
+
+impl OutputFormat {
+    /// Valid names for `--format` and `output.format`.
+    pub const NAMES: &'static [&'static str] = &["table", "json", "csv"];
+
+    /// Parses a format name as used in the config file.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "table" => Some(Self::Table),
+            "json" => Some(Self::Json),
+            "csv" => Some(Self::Csv),
+            _ => None,
+        }
+    }
+
+    /// Returns true for JSON output.
+    pub fn is_json(self) -> bool {
+        self == Self::Json
+    }
+}
+
 // Re-export all public functions from submodules
 
 // Tasks
 pub use tasks::{
-    format_created_item, format_item_details_json, format_item_details_table, format_items_json,
-    format_items_table, format_quick_add_result,
+    format_completed_items_csv, format_completed_items_json, format_completed_items_table,
+    format_created_item, format_created_item_csv, format_item_details_json,
+    format_item_details_table, format_items_csv, format_items_json, format_items_table,
+    format_items_table_with_fields, format_quick_add_result, TaskTableOptions,
 };
 
 // Projects
 pub use projects::{
     format_archived_project, format_created_project, format_deleted_project, format_edited_project,
-    format_project_details_json, format_project_details_table, format_projects_json,
-    format_projects_table, format_unarchived_project,
+    format_project_details_json, format_project_details_table, format_projects_csv,
+    format_projects_json, format_projects_table, format_unarchived_project,
 };
 
 // Labels
 pub use labels::{
-    format_created_label, format_deleted_label, format_edited_label, format_labels_json,
-    format_labels_table,
+    format_created_label, format_deleted_label, format_edited_label, format_labels_csv,
+    format_labels_json, format_labels_table,
 };
 
 // Sections
 pub use sections::{
-    format_created_section, format_deleted_section, format_edited_section, format_sections_json,
-    format_sections_table,
+    format_created_section, format_created_section_csv, format_deleted_section,
+    format_deleted_section_csv, format_edited_section, format_edited_section_csv,
+    format_sections_csv, format_sections_json, format_sections_table,
 };
 
 // Comments
 pub use comments::{
-    format_comments_json, format_comments_table, format_created_comment, format_deleted_comment,
-    format_edited_comment,
+    format_comments_csv, format_comments_json, format_comments_table, format_created_comment,
+    format_deleted_comment, format_edited_comment,
 };
 
 // Reminders
 pub use reminders::{
-    format_created_reminder, format_deleted_reminder, format_reminders_json, format_reminders_table,
+    format_created_reminder, format_deleted_reminder, format_deleted_reminders,
+    format_reminders_csv, format_reminders_json, format_reminders_table,
 };
 
 // Filters
 pub use filters::{
     format_created_filter, format_deleted_filter, format_edited_filter, format_filter_details_json,
-    format_filter_details_table, format_filters_json, format_filters_table,
+    format_filter_details_table, format_filters_csv, format_filters_json, format_filters_table,
 };
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> serde_json::Value {
+        serde_json::json!({
+            "tasks": [
+                {"id": "1", "content": "Buy milk", "labels": ["errand", "home"]},
+                {"id": "2", "content": "Call mom", "due": null}
+            ],
+            "count": 2
+        })
+    }
+
+    #[test]
+    fn test_to_json_compact_has_no_extra_whitespace() {
+        let compact = to_json(&sample(), true).unwrap();
+        assert!(!compact.contains('\n'));
+        assert!(!compact.contains(": "));
+        assert!(!compact.contains(", "));
+        assert!(compact.contains("\"content\":\"Buy milk\""));
+    }
+
+    #[test]
+    fn test_to_json_pretty_is_indented() {
+        let pretty = to_json(&sample(), false).unwrap();
+        assert!(pretty.contains('\n'));
+        assert!(pretty.contains("  \"count\": 2"));
+    }
+
+    #[test]
+    fn test_to_json_compact_and_pretty_parse_to_same_value() {
+        let compact = to_json(&sample(), true).unwrap();
+        let pretty = to_json(&sample(), false).unwrap();
+        let compact: serde_json::Value = serde_json::from_str(&compact).unwrap();
+        let pretty: serde_json::Value = serde_json::from_str(&pretty).unwrap();
+        assert_eq!(compact, pretty);
+        assert_eq!(compact, sample());
+    }
+}
@@ -12,7 +12,11 @@ use crate::commands::projects::{
     ProjectUnarchiveResult, ProjectsShowResult,
 };
 
-use super::helpers::{format_due, format_priority, truncate_id, truncate_str};
+use super::helpers::{
+    csv_row, format_datetime, format_due_styled, format_priority, id_display_len, truncate_id_to,
+    truncate_str,
+};
+use super::{DateStyle, IdStyle};
 
 /// JSON output structure for a created project.
 #[derive(Serialize)]
@@ -26,10 +30,17 @@ pub struct CreatedProjectOutput<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parent_name: Option<&'a str>,
     pub is_favorite: bool,
+    #[serde(skip_serializing_if = "<[String]>::is_empty")]
+    pub section_ids: &'a [String],
+    #[serde(skip_serializing_if = "<[String]>::is_empty")]
+    pub task_ids: &'a [String],
 }
 
 /// Formats a created project as JSON.
-pub fn format_created_project(result: &ProjectAddResult) -> Result<String, serde_json::Error> {
+pub fn format_created_project(
+    result: &ProjectAddResult,
+    compact: bool,
+) -> Result<String, serde_json::Error> {
     let output = CreatedProjectOutput {
         id: &result.id,
         name: &result.name,
@@ -37,9 +48,11 @@ pub fn format_created_project(result: &ProjectAddResult) -> Result<String, serde
         parent_id: result.parent_id.as_deref(),
         parent_name: result.parent_name.as_deref(),
         is_favorite: result.is_favorite,
+        section_ids: &result.section_ids,
+        task_ids: &result.task_ids,
     };
 
-    serde_json::to_string_pretty(&output)
+    super::to_json(&output, compact)
 }
 
 /// JSON output structure for an edited project.
@@ -51,14 +64,17 @@ pub struct EditedProjectOutput<'a> {
 }
 
 /// Formats an edited project as JSON.
-pub fn format_edited_project(result: &ProjectEditResult) -> Result<String, serde_json::Error> {
+pub fn format_edited_project(
+    result: &ProjectEditResult,
+    compact: bool,
+) -> Result<String, serde_json::Error> {
     let output = EditedProjectOutput {
         id: &result.id,
         name: &result.name,
         updated_fields: &result.updated_fields,
     };
 
-    serde_json::to_string_pretty(&output)
+    super::to_json(&output, compact)
 }
 
 /// JSON output structure for an archived project.
@@ -70,14 +86,17 @@ pub struct ArchivedProjectOutput<'a> {
 }
 
 /// Formats an archived project as JSON.
-pub fn format_archived_project(result: &ProjectArchiveResult) -> Result<String, serde_json::Error> {
+pub fn format_archived_project(
+    result: &ProjectArchiveResult,
+    compact: bool,
+) -> Result<String, serde_json::Error> {
     let output = ArchivedProjectOutput {
         id: &result.id,
         name: &result.name,
         status: "archived",
     };
 
-    serde_json::to_string_pretty(&output)
+    super::to_json(&output, compact)
 }
 
 /// JSON output structure for an unarchived project.
@@ -91,6 +110,7 @@ pub struct UnarchivedProjectOutput<'a> {
 /// Formats an unarchived project as JSON.
 pub fn format_unarchived_project(
     result: &ProjectUnarchiveResult,
+    compact: bool,
 ) -> Result<String, serde_json::Error> {
     let output = UnarchivedProjectOutput {
         id: &result.id,
@@ -98,7 +118,7 @@ pub fn format_unarchived_project(
         status: "unarchived",
     };
 
-    serde_json::to_string_pretty(&output)
+    super::to_json(&output, compact)
 }
 
 /// JSON output structure for a deleted project.
@@ -110,14 +130,17 @@ pub struct DeletedProjectOutput<'a> {
 }
 
 /// Formats a deleted project as JSON.
-pub fn format_deleted_project(result: &ProjectDeleteResult) -> Result<String, serde_json::Error> {
+pub fn format_deleted_project(
+    result: &ProjectDeleteResult,
+    compact: bool,
+) -> Result<String, serde_json::Error> {
     let output = DeletedProjectOutput {
         id: &result.id,
         name: &result.name,
         status: "deleted",
     };
 
-    serde_json::to_string_pretty(&output)
+    super::to_json(&output, compact)
 }
 
 /// JSON output structure for projects list command.
@@ -144,7 +167,15 @@ pub struct ProjectOutput<'a> {
 }
 
 /// Formats projects as JSON.
-pub fn format_projects_json(projects: &[&Project]) -> Result<String, serde_json::Error> {
+///
+/// Task counts include completed tasks when `include_completed` is set.
+pub fn format_projects_json(
+    projects: &[&Project],
+    cache: &Cache,
+    include_completed: bool,
+    compact: bool,
+) -> Result<String, serde_json::Error> {
+    let task_counts = count_tasks_per_project(cache, include_completed);
     let projects_output: Vec<ProjectOutput> = projects
         .iter()
         .map(|p| ProjectOutput {
@@ -156,7 +187,7 @@ pub fn format_projects_json(projects: &[&Project]) -> Result<String, serde_json:
             is_archived: p.is_archived,
             is_inbox: p.inbox_project,
             view_style: p.view_style.as_deref(),
-            task_count: 0, // We don't have task count in this context
+            task_count: task_counts.get(&p.id).copied().unwrap_or(0),
         })
         .collect();
 
@@ -164,15 +195,60 @@ pub fn format_projects_json(projects: &[&Project]) -> Result<String, serde_json:
         projects: projects_output,
     };
 
-    serde_json::to_string_pretty(&output)
+    super::to_json(&output, compact)
+}
+
+/// Formats projects as CSV with the same fields as [`format_projects_json`].
+pub fn format_projects_csv(
+    projects: &[&Project],
+    cache: &Cache,
+    include_completed: bool,
+) -> String {
+    let task_counts = count_tasks_per_project(cache, include_completed);
+    let mut output = csv_row([
+        "id",
+        "name",
+        "color",
+        "parent_id",
+        "is_favorite",
+        "is_archived",
+        "is_inbox",
+        "view_style",
+        "task_count",
+    ]);
+    for project in projects {
+        output.push_str(&csv_row([
+            project.id.as_str(),
+            &project.name,
+            project.color.as_deref().unwrap_or(""),
+            project.parent_id.as_deref().unwrap_or(""),
+            &project.is_favorite.to_string(),
+            &project.is_archived.to_string(),
+            &project.inbox_project.to_string(),
+            project.view_style.as_deref().unwrap_or(""),
+            &task_counts
+                .get(&project.id)
+                .copied()
+                .unwrap_or(0)
+                .to_string(),
+        ]));
+    }
+    output
 }
 
 /// Formats projects as a table.
+///
+/// Task counts include completed tasks when `include_completed` is set.
+/// In tree view, `max_depth` limits how many levels are rendered; projects
+/// on the last level note how many descendants were hidden.
 pub fn format_projects_table(
     projects: &[&Project],
     cache: &Cache,
     use_colors: bool,
     tree: bool,
+    max_depth: Option<usize>,
+    include_completed: bool,
+    id_style: IdStyle,
 ) -> String {
     if projects.is_empty() {
         return "No projects found.\n".to_string();
@@ -182,17 +258,36 @@ pub fn format_projects_table(
 
     if tree {
         // Tree view: show hierarchy with indentation
-        output.push_str(&format_projects_tree(projects, cache, use_colors));
+        output.push_str(&format_projects_tree(
+            projects,
+            cache,
+            use_colors,
+            max_depth,
+            include_completed,
+            id_style,
+        ));
     } else {
         // Flat view: simple table
-        output.push_str(&format_projects_flat(projects, cache, use_colors));
+        output.push_str(&format_projects_flat(
+            projects,
+            cache,
+            use_colors,
+            include_completed,
+            id_style,
+        ));
     }
 
     output
 }
 
 /// Formats projects as a flat table.
-fn format_projects_flat(projects: &[&Project], cache: &Cache, use_colors: bool) -> String {
+fn format_projects_flat(
+    projects: &[&Project],
+    cache: &Cache,
+    use_colors: bool,
+    include_completed: bool,
+    id_style: IdStyle,
+) -> String {
     let mut output = String::new();
 
     // Header
@@ -208,11 +303,12 @@ fn format_projects_flat(projects: &[&Project], cache: &Cache, use_colors: bool)
     }
 
     // Count tasks per project
-    let task_counts = count_tasks_per_project(cache);
+    let task_counts = count_tasks_per_project(cache, include_completed);
 
     // Projects
+    let id_len = id_display_len(projects.iter().map(|p| p.id.as_str()), id_style);
     for project in projects {
-        let id_prefix = truncate_id(&project.id);
+        let id_prefix = truncate_id_to(&project.id, id_len);
         let fav = if project.is_favorite {
             if use_colors {
                 "★".yellow().to_string()
@@ -242,7 +338,17 @@ fn format_projects_flat(projects: &[&Project], cache: &Cache, use_colors: bool)
 }
 
 /// Formats projects as a tree with indentation.
-fn format_projects_tree(projects: &[&Project], cache: &Cache, use_colors: bool) -> String {
+///
+/// With `max_depth`, only that many levels are shown (1 = roots only) and
+/// the deepest shown projects get a "(+k more)" suffix for hidden descendants.
+fn format_projects_tree(
+    projects: &[&Project],
+    cache: &Cache,
+    use_colors: bool,
+    max_depth: Option<usize>,
+    include_completed: bool,
+    id_style: IdStyle,
+) -> String {
     let mut output = String::new();
 
     // Build parent-child relationships
@@ -260,26 +366,28 @@ fn format_projects_tree(projects: &[&Project], cache: &Cache, use_colors: bool)
     }
 
     // Count tasks per project
-    let task_counts = count_tasks_per_project(cache);
+    let task_counts = count_tasks_per_project(cache, include_completed);
 
-    // Recursively print tree starting from root projects
-    fn print_tree(
-        output: &mut String,
-        parent_id: Option<&str>,
-        children_map: &HashMap<Option<&str>, Vec<&Project>>,
-        task_counts: &HashMap<String, usize>,
-        depth: usize,
+    /// Everything `print_tree` needs that stays the same at every level.
+    struct TreeRender<'a> {
+        children_map: HashMap<Option<&'a str>, Vec<&'a Project>>,
+        task_counts: HashMap<String, usize>,
+        max_depth: Option<usize>,
         use_colors: bool,
-    ) {
-        if let Some(children) = children_map.get(&parent_id) {
+        id_len: usize,
+    }
+
+    // Recursively print tree starting from root projects
+    fn print_tree(output: &mut String, tree: &TreeRender, parent_id: Option<&str>, depth: usize) {
+        if let Some(children) = tree.children_map.get(&parent_id) {
             for project in children {
                 let indent = "  ".repeat(depth);
                 let prefix = if depth > 0 { "└─ " } else { "" };
                 let fav = if project.is_favorite { "★ " } else { "" };
-                let task_count = task_counts.get(&project.id).copied().unwrap_or(0);
-                let id_prefix = truncate_id(&project.id);
+                let task_count = tree.task_counts.get(&project.id).copied().unwrap_or(0);
+                let id_prefix = truncate_id_to(&project.id, tree.id_len);
 
-                let name_display = if use_colors {
+                let name_display = if tree.use_colors {
                     if project.inbox_project {
                         project.name.cyan().to_string()
                     } else if project.is_favorite {
@@ -291,43 +399,67 @@ fn format_projects_tree(projects: &[&Project], cache: &Cache, use_colors: bool)
                     format!("{}{}", fav, project.name)
                 };
 
-                let line = format!(
+                let mut line = format!(
                     "{}{}{} ({}) [{}]",
                     indent, prefix, name_display, task_count, id_prefix
                 );
+
+                // Stop descending at the depth limit, noting what was hidden
+                if tree.max_depth.is_some_and(|max| depth + 1 >= max) {
+                    let hidden = count_descendants(&project.id, &tree.children_map);
+                    if hidden > 0 {
+                        let more = format!(" (+{hidden} more)");
+                        if tree.use_colors {
+                            line.push_str(&more.dimmed().to_string());
+                        } else {
+                            line.push_str(&more);
+                        }
+                    }
+                    output.push_str(&line);
+                    output.push('\n');
+                    continue;
+                }
+
                 output.push_str(&line);
                 output.push('\n');
 
                 // Recursively print children
-                print_tree(
-                    output,
-                    Some(&project.id),
-                    children_map,
-                    task_counts,
-                    depth + 1,
-                    use_colors,
-                );
+                print_tree(output, tree, Some(&project.id), depth + 1);
             }
         }
     }
 
-    print_tree(
-        &mut output,
-        None,
-        &children_map,
-        &task_counts,
-        0,
+    let tree = TreeRender {
+        children_map,
+        task_counts,
+        max_depth,
         use_colors,
-    );
+        id_len: id_display_len(projects.iter().map(|p| p.id.as_str()), id_style),
+    };
+    print_tree(&mut output, &tree, None, 0);
 
     output
 }
 
-/// Counts tasks per project.
-fn count_tasks_per_project(cache: &Cache) -> HashMap<String, usize> {
+/// Counts all descendants of a project in a parent-to-children map.
+fn count_descendants(
+    project_id: &str,
+    children_map: &HashMap<Option<&str>, Vec<&Project>>,
+) -> usize {
+    children_map.get(&Some(project_id)).map_or(0, |children| {
+        children
+            .iter()
+            .map(|child| 1 + count_descendants(&child.id, children_map))
+            .sum()
+    })
+}
+
+/// Counts non-deleted tasks per project, skipping completed tasks unless
+/// `include_completed` is set.
+fn count_tasks_per_project(cache: &Cache, include_completed: bool) -> HashMap<String, usize> {
     let mut counts = HashMap::new();
     for item in &cache.items {
-        if !item.is_deleted && !item.checked {
+        if !item.is_deleted && (include_completed || !item.checked) {
             *counts.entry(item.project_id.clone()).or_insert(0) += 1;
         }
     }
@@ -379,10 +511,14 @@ pub struct ProjectDetailsOutput<'a> {
     pub view_style: Option<&'a str>,
     pub task_count: usize,
     pub section_count: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_count: Option<u32>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub sections: Vec<SectionOutput<'a>>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub tasks: Vec<ProjectTaskOutput<'a>>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub completed_tasks: Vec<CompletedTaskOutput<'a>>,
 }
 
 /// JSON output for a section in project details.
@@ -405,12 +541,40 @@ pub struct ProjectTaskOutput<'a> {
     pub section_id: Option<&'a str>,
 }
 
+/// JSON output for a recently completed task in project details.
+#[derive(Serialize)]
+pub struct CompletedTaskOutput<'a> {
+    pub id: &'a str,
+    pub content: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<&'a str>,
+}
+
 /// Formats project details as JSON (projects show command).
 pub fn format_project_details_json(
     result: &ProjectsShowResult,
+    compact: bool,
 ) -> Result<String, serde_json::Error> {
-    let sections: Vec<SectionOutput> = result
+    // Match the table view: sections by section_order, tasks grouped by section
+    // (unsectioned first, then in section order) and ordered by child_order.
+    let mut sorted_sections = result.sections.clone();
+    sorted_sections.sort_by_key(|s| s.section_order);
+
+    let section_orders: HashMap<&str, i32> = result
         .sections
+        .iter()
+        .map(|s| (s.id.as_str(), s.section_order))
+        .collect();
+    let mut sorted_tasks = result.tasks.clone();
+    sorted_tasks.sort_by_key(|t| {
+        let section_key = t.section_id.as_deref().map(|id| {
+            let order = section_orders.get(id).copied().unwrap_or(i32::MAX);
+            (order, id)
+        });
+        (section_key, t.child_order)
+    });
+
+    let sections: Vec<SectionOutput> = sorted_sections
         .iter()
         .map(|s| SectionOutput {
             id: &s.id,
@@ -419,8 +583,7 @@ pub fn format_project_details_json(
         })
         .collect();
 
-    let tasks: Vec<ProjectTaskOutput> = result
-        .tasks
+    let tasks: Vec<ProjectTaskOutput> = sorted_tasks
         .iter()
         .map(|t| ProjectTaskOutput {
             id: &t.id,
@@ -444,15 +607,30 @@ pub fn format_project_details_json(
         view_style: result.project.view_style.as_deref(),
         task_count: result.task_count,
         section_count: result.section_count,
+        completed_count: result.completed_count,
         sections,
         tasks,
+        completed_tasks: result
+            .completed_tasks
+            .iter()
+            .map(|t| CompletedTaskOutput {
+                id: &t.id,
+                content: &t.content,
+                completed_at: t.completed_at.as_deref(),
+            })
+            .collect(),
     };
 
-    serde_json::to_string_pretty(&output)
+    super::to_json(&output, compact)
 }
 
 /// Formats project details as a human-readable table (projects show command).
-pub fn format_project_details_table(result: &ProjectsShowResult, use_colors: bool) -> String {
+pub fn format_project_details_table(
+    result: &ProjectsShowResult,
+    use_colors: bool,
+    date_style: DateStyle,
+    id_style: IdStyle,
+) -> String {
     let mut output = String::new();
 
     // Project header
@@ -514,14 +692,18 @@ pub fn format_project_details_table(result: &ProjectsShowResult, use_colors: boo
     // Task and section counts
     output.push_str(&format!("Tasks: {}\n", result.task_count));
     output.push_str(&format!("Sections: {}\n", result.section_count));
+    if let Some(completed) = result.completed_count {
+        output.push_str(&format!("Completed: {}\n", completed));
+    }
 
     // Sections list (if requested)
     if !result.sections.is_empty() {
         output.push_str(&format!("\nSections ({}):\n", result.sections.len()));
         let mut sorted_sections = result.sections.clone();
         sorted_sections.sort_by_key(|s| s.section_order);
+        let id_len = id_display_len(sorted_sections.iter().map(|s| s.id.as_str()), id_style);
         for section in &sorted_sections {
-            let id_prefix = truncate_id(&section.id);
+            let id_prefix = truncate_id_to(&section.id, id_len);
             output.push_str(&format!("  {} {}\n", id_prefix, section.name));
         }
     }
@@ -529,10 +711,11 @@ pub fn format_project_details_table(result: &ProjectsShowResult, use_colors: boo
     // Tasks list (if requested)
     if !result.tasks.is_empty() {
         output.push_str(&format!("\nTasks ({}):\n", result.tasks.len()));
+        let id_len = id_display_len(result.tasks.iter().map(|t| t.id.as_str()), id_style);
         for task in &result.tasks {
-            let id_prefix = truncate_id(&task.id);
+            let id_prefix = truncate_id_to(&task.id, id_len);
             let priority = format_priority(task.priority, use_colors);
-            let due = format_due(task.due.as_ref().map(|d| &d.date), use_colors);
+            let due = format_due_styled(task.due.as_ref(), date_style, use_colors);
             let due_str = if due.is_empty() {
                 String::new()
             } else {
@@ -545,5 +728,374 @@ pub fn format_project_details_table(result: &ProjectsShowResult, use_colors: boo
         }
     }
 
+    // Recently completed tasks (if requested)
+    if !result.completed_tasks.is_empty() {
+        output.push_str(&format!(
+            "\nRecently completed ({}):\n",
+            result.completed_tasks.len()
+        ));
+        let id_len = id_display_len(
+            result.completed_tasks.iter().map(|t| t.id.as_str()),
+            id_style,
+        );
+        for task in &result.completed_tasks {
+            let id_prefix = truncate_id_to(&task.id, id_len);
+            let completed_str = task
+                .completed_at
+                .as_deref()
+                .map(|c| format!(" [{}]", format_datetime(c)))
+                .unwrap_or_default();
+            output.push_str(&format!(
+                "  {} {}{}\n",
+                id_prefix, task.content, completed_str
+            ));
+        }
+    }
+
     output
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use todoist_api_rs::sync::Section;
+
+    use crate::test_support::{item, project};
+
+    #[test]
+    fn test_format_projects_csv() {
+        let project = project("proj-1", "Work").build();
+        let csv = format_projects_csv(&[&project], &Cache::new(), false);
+        assert_eq!(
+            csv,
+            "id,name,color,parent_id,is_favorite,is_archived,is_inbox,view_style,task_count\n\
+             proj-1,Work,,,false,false,false,,0\n"
+        );
+    }
+
+    fn make_test_section(id: &str, order: i32) -> Section {
+        Section {
+            id: id.to_string(),
+            name: format!("Section {id}"),
+            project_id: "proj-1".to_string(),
+            section_order: order,
+            is_collapsed: false,
+            is_deleted: false,
+            is_archived: false,
+            archived_at: None,
+            added_at: None,
+            updated_at: None,
+        }
+    }
+
+    fn ids(value: &serde_json::Value, key: &str) -> Vec<String> {
+        value[key]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|v| v["id"].as_str().unwrap().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_project_details_json_sorts_sections_by_order() {
+        let project = project("proj-1", "Work").build();
+        let s_a = make_test_section("sec-a", 3);
+        let s_b = make_test_section("sec-b", 1);
+        let s_c = make_test_section("sec-c", 2);
+        let result = ProjectsShowResult {
+            project: &project,
+            parent_name: None,
+            task_count: 0,
+            section_count: 3,
+            completed_count: None,
+            sections: vec![&s_a, &s_b, &s_c],
+            tasks: vec![],
+            completed_tasks: vec![],
+        };
+
+        let json = format_project_details_json(&result, false).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(ids(&value, "sections"), vec!["sec-b", "sec-c", "sec-a"]);
+    }
+
+    #[test]
+    fn test_project_details_json_sorts_tasks_by_section_then_child_order() {
+        let project = project("proj-1", "Work").build();
+        let s_first = make_test_section("sec-z", 1);
+        let s_second = make_test_section("sec-a", 2);
+        let t1 = item("t1", "Task t1")
+            .section("sec-a")
+            .child_order(2)
+            .build();
+        let t2 = item("t2", "Task t2")
+            .section("sec-z")
+            .child_order(5)
+            .build();
+        let t3 = item("t3", "Task t3").child_order(4).build();
+        let t4 = item("t4", "Task t4")
+            .section("sec-a")
+            .child_order(1)
+            .build();
+        let t5 = item("t5", "Task t5").child_order(1).build();
+        let t6 = item("t6", "Task t6").section("sec-z").build();
+        let result = ProjectsShowResult {
+            project: &project,
+            parent_name: None,
+            task_count: 6,
+            section_count: 2,
+            completed_count: None,
+            sections: vec![&s_second, &s_first],
+            tasks: vec![&t1, &t2, &t3, &t4, &t5, &t6],
+            completed_tasks: vec![],
+        };
+
+        let json = format_project_details_json(&result, false).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(
+            ids(&value, "tasks"),
+            vec!["t5", "t3", "t6", "t2", "t4", "t1"]
+        );
+    }
+
+    #[test]
+    fn test_project_details_show_completed_count() {
+        let project = project("proj-1", "Work").build();
+        let result = ProjectsShowResult {
+            project: &project,
+            parent_name: None,
+            task_count: 2,
+            section_count: 0,
+            completed_count: Some(17),
+            sections: vec![],
+            tasks: vec![],
+            completed_tasks: vec![],
+        };
+
+        let json = format_project_details_json(&result, false).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["completed_count"], 17);
+
+        let table =
+            format_project_details_table(&result, false, DateStyle::Relative, IdStyle::Short);
+        assert!(table.contains("Completed: 17\n"));
+    }
+
+    #[test]
+    fn test_project_details_completed_tasks_section() {
+        let project = project("proj-1", "Work").build();
+        let done = item("done-1", "Task done-1")
+            .completed_at("2025-01-15T10:00:00Z")
+            .build();
+        let result = ProjectsShowResult {
+            project: &project,
+            parent_name: None,
+            task_count: 0,
+            section_count: 0,
+            completed_count: None,
+            sections: vec![],
+            tasks: vec![],
+            completed_tasks: vec![&done],
+        };
+
+        let json = format_project_details_json(&result, false).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(ids(&value, "completed_tasks"), vec!["done-1"]);
+        assert_eq!(
+            value["completed_tasks"][0]["completed_at"],
+            "2025-01-15T10:00:00Z"
+        );
+
+        let table =
+            format_project_details_table(&result, false, DateStyle::Relative, IdStyle::Short);
+        assert!(table.contains("Recently completed (1):\n  done-1 Task done-1 ["));
+    }
+
+    #[test]
+    fn test_project_details_omit_unknown_completed_count() {
+        let project = project("proj-1", "Work").build();
+        let result = ProjectsShowResult {
+            project: &project,
+            parent_name: None,
+            task_count: 0,
+            section_count: 0,
+            completed_count: None,
+            sections: vec![],
+            tasks: vec![],
+            completed_tasks: vec![],
+        };
+
+        let json = format_project_details_json(&result, false).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert!(value.get("completed_count").is_none());
+        assert!(
+            !format_project_details_table(&result, false, DateStyle::Relative, IdStyle::Short)
+                .contains("Completed:")
+        );
+    }
+
+    /// Cache with two open, one completed and one deleted task in "Work",
+    /// one open task in "Home", and an empty project.
+    fn make_task_count_cache() -> Cache {
+        let done = item("t3", "Task t3").child_order(3).checked().build();
+        let deleted = item("t4", "Task t4").child_order(4).deleted().build();
+        let other = item("t5", "Task t5")
+            .project("proj-2")
+            .child_order(1)
+            .build();
+        Cache::with_data(
+            "test".to_string(),
+            None,
+            None,
+            vec![
+                item("t1", "Task t1").child_order(1).build(),
+                item("t2", "Task t2").child_order(2).build(),
+                done,
+                deleted,
+                other,
+            ],
+            vec![
+                project("proj-1", "Work").build(),
+                project("proj-2", "Home").build(),
+                project("proj-3", "Empty").build(),
+            ],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            None,
+        )
+    }
+
+    #[test]
+    fn test_projects_json_task_counts_match_table() {
+        let cache = make_task_count_cache();
+        let projects: Vec<&Project> = cache.projects.iter().collect();
+
+        let json = format_projects_json(&projects, &cache, false, false).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        let json_counts: Vec<(String, u64)> = value["projects"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|p| {
+                (
+                    p["name"].as_str().unwrap().to_string(),
+                    p["task_count"].as_u64().unwrap(),
+                )
+            })
+            .collect();
+
+        // Table rows: ID, Name, Tasks (no favorites or colors)
+        let table =
+            format_projects_table(&projects, &cache, false, false, None, false, IdStyle::Short);
+        let table_counts: Vec<(String, u64)> = table
+            .lines()
+            .skip(1)
+            .map(|line| {
+                let cols: Vec<&str> = line.split_whitespace().collect();
+                (cols[1].to_string(), cols[2].parse().unwrap())
+            })
+            .collect();
+
+        assert_eq!(json_counts, table_counts);
+        assert_eq!(
+            json_counts,
+            vec![
+                ("Work".to_string(), 2),
+                ("Home".to_string(), 1),
+                ("Empty".to_string(), 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_projects_task_counts_include_completed() {
+        let cache = make_task_count_cache();
+        let projects: Vec<&Project> = cache.projects.iter().collect();
+
+        let open = count_tasks_per_project(&cache, false);
+        assert_eq!(open.get("proj-1"), Some(&2));
+
+        let all = count_tasks_per_project(&cache, true);
+        assert_eq!(all.get("proj-1"), Some(&3));
+        assert_eq!(all.get("proj-2"), Some(&1));
+        assert_eq!(all.get("proj-3"), None);
+
+        let json = format_projects_json(&projects, &cache, true, false).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["projects"][0]["task_count"], 3);
+
+        let table =
+            format_projects_table(&projects, &cache, false, true, None, true, IdStyle::Short);
+        assert!(table.contains("Work (3)"));
+        let table =
+            format_projects_table(&projects, &cache, false, true, None, false, IdStyle::Short);
+        assert!(table.contains("Work (2)"));
+    }
+
+    fn make_nested_cache() -> Cache {
+        // Work > Team > Sprint > Retro, plus a sibling Home root
+        let child = |id: &str, name: &str, parent: &str| project(id, name).parent(parent).build();
+        let mut cache = Cache::new();
+        cache.projects = vec![
+            project("proj-work", "Work").build(),
+            child("proj-team", "Team", "proj-work"),
+            child("proj-sprint", "Sprint", "proj-team"),
+            child("proj-retro", "Retro", "proj-sprint"),
+            child("proj-ops", "Ops", "proj-work"),
+            project("proj-home", "Home").child_order(1).build(),
+        ];
+        cache
+    }
+
+    fn tree_lines(cache: &Cache, max_depth: Option<usize>) -> Vec<String> {
+        let projects: Vec<&Project> = cache.projects.iter().collect();
+        format_projects_table(
+            &projects,
+            cache,
+            false,
+            true,
+            max_depth,
+            false,
+            IdStyle::Short,
+        )
+        .lines()
+        .map(|line| {
+            // Drop the "[id]" column, keeping any "(+k more)" suffix
+            let (name, rest) = line.split_once(" [").unwrap();
+            let suffix = rest.split_once(']').unwrap().1;
+            format!("{name}{suffix}")
+        })
+        .collect()
+    }
+
+    #[test]
+    fn test_projects_tree_depth_one_shows_only_roots() {
+        let cache = make_nested_cache();
+        assert_eq!(
+            tree_lines(&cache, Some(1)),
+            vec!["Work (0) (+4 more)", "Home (0)"]
+        );
+    }
+
+    #[test]
+    fn test_projects_tree_depth_limits_levels() {
+        let cache = make_nested_cache();
+        assert_eq!(
+            tree_lines(&cache, Some(2)),
+            vec![
+                "Work (0)",
+                "  └─ Team (0) (+2 more)",
+                "  └─ Ops (0)",
+                "Home (0)",
+            ]
+        );
+        assert_eq!(tree_lines(&cache, None).len(), 6);
+        assert_eq!(tree_lines(&cache, Some(4)), tree_lines(&cache, None));
+    }
+}
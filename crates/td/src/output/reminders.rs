@@ -8,7 +8,7 @@ use todoist_cache_rs::Cache;
 
 use crate::commands::reminders::{ReminderAddResult, ReminderDeleteResult};
 
-use super::helpers::{format_reminder, truncate_id};
+use super::helpers::{csv_row, format_reminder, truncate_id};
 use super::tasks::DueOutput;
 
 /// JSON output structure for reminders list command.
@@ -35,6 +35,7 @@ pub struct ReminderListOutput<'a> {
 pub fn format_reminders_json(
     reminders: &[&Reminder],
     cache: &Cache,
+    compact: bool,
 ) -> Result<String, serde_json::Error> {
     let reminders_output: Vec<ReminderListOutput> = reminders
         .iter()
@@ -67,7 +68,40 @@ pub fn format_reminders_json(
         reminders: reminders_output,
     };
 
-    serde_json::to_string_pretty(&output)
+    super::to_json(&output, compact)
+}
+
+/// Formats reminders as CSV with a header row.
+pub fn format_reminders_csv(reminders: &[&Reminder], cache: &Cache) -> String {
+    let mut output = csv_row([
+        "id",
+        "item_id",
+        "task_name",
+        "reminder_type",
+        "due_date",
+        "due_datetime",
+        "minute_offset",
+    ]);
+    for reminder in reminders {
+        let task_name = cache
+            .items
+            .iter()
+            .find(|i| i.id == reminder.item_id)
+            .map(|i| i.content.as_str());
+        let due = reminder.due.as_ref();
+        output.push_str(&csv_row([
+            reminder.id.as_str(),
+            &reminder.item_id,
+            task_name.unwrap_or(""),
+            &reminder.reminder_type.to_string(),
+            due.map_or("", |d| d.date.as_str()),
+            due.and_then(|d| d.datetime.as_deref()).unwrap_or(""),
+            &reminder
+                .minute_offset
+                .map_or(String::new(), |m| m.to_string()),
+        ]));
+    }
+    output
 }
 
 /// Formats reminders as a table.
@@ -133,7 +167,10 @@ pub struct CreatedReminderOutput<'a> {
 }
 
 /// Formats a created reminder as JSON.
-pub fn format_created_reminder(result: &ReminderAddResult) -> Result<String, serde_json::Error> {
+pub fn format_created_reminder(
+    result: &ReminderAddResult,
+    compact: bool,
+) -> Result<String, serde_json::Error> {
     let output = CreatedReminderOutput {
         id: &result.id,
         task_id: &result.task_id,
@@ -143,7 +180,7 @@ pub fn format_created_reminder(result: &ReminderAddResult) -> Result<String, ser
         minute_offset: result.minute_offset,
     };
 
-    serde_json::to_string_pretty(&output)
+    super::to_json(&output, compact)
 }
 
 /// JSON output structure for a deleted reminder.
@@ -157,15 +194,32 @@ pub struct DeletedReminderOutput<'a> {
     pub status: &'static str,
 }
 
+impl<'a> DeletedReminderOutput<'a> {
+    fn new(result: &'a ReminderDeleteResult) -> Self {
+        Self {
+            id: &result.id,
+            task_id: &result.task_id,
+            task_name: result.task_name.as_deref(),
+            reminder_type: result.reminder_type,
+            status: "deleted",
+        }
+    }
+}
+
 /// Formats a deleted reminder as JSON.
-pub fn format_deleted_reminder(result: &ReminderDeleteResult) -> Result<String, serde_json::Error> {
-    let output = DeletedReminderOutput {
-        id: &result.id,
-        task_id: &result.task_id,
-        task_name: result.task_name.as_deref(),
-        reminder_type: result.reminder_type,
-        status: "deleted",
-    };
+pub fn format_deleted_reminder(
+    result: &ReminderDeleteResult,
+    compact: bool,
+) -> Result<String, serde_json::Error> {
+    super::to_json(&DeletedReminderOutput::new(result), compact)
+}
 
-    serde_json::to_string_pretty(&output)
+/// Formats reminders deleted together (`reminders delete --all`) as a JSON array.
+pub fn format_deleted_reminders(
+    results: &[ReminderDeleteResult],
+    compact: bool,
+) -> Result<String, serde_json::Error> {
+    let output: Vec<DeletedReminderOutput> =
+        results.iter().map(DeletedReminderOutput::new).collect();
+    super::to_json(&output, compact)
 }
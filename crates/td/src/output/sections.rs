@@ -7,7 +7,7 @@ use todoist_cache_rs::Cache;
 
 use crate::commands::sections::{SectionAddResult, SectionDeleteResult, SectionEditResult};
 
-use super::helpers::{truncate_id, truncate_str};
+use super::helpers::{csv_row, truncate_id, truncate_str};
 
 /// JSON output structure for sections list command.
 #[derive(Serialize)]
@@ -31,6 +31,7 @@ pub struct SectionListOutput<'a> {
 pub fn format_sections_json(
     sections: &[&Section],
     cache: &Cache,
+    compact: bool,
 ) -> Result<String, serde_json::Error> {
     let sections_output: Vec<SectionListOutput> = sections
         .iter()
@@ -56,7 +57,7 @@ pub fn format_sections_json(
         sections: sections_output,
     };
 
-    serde_json::to_string_pretty(&output)
+    super::to_json(&output, compact)
 }
 
 /// Formats sections as a table.
@@ -109,6 +110,34 @@ pub fn format_sections_table(sections: &[&Section], cache: &Cache, use_colors: b
     output
 }
 
+/// Formats sections as CSV with the same fields as [`format_sections_json`].
+pub fn format_sections_csv(sections: &[&Section], cache: &Cache) -> String {
+    let mut output = csv_row([
+        "id",
+        "name",
+        "project_id",
+        "project_name",
+        "section_order",
+        "is_archived",
+    ]);
+    for section in sections {
+        let project_name = cache
+            .projects
+            .iter()
+            .find(|p| p.id == section.project_id)
+            .map(|p| p.name.as_str());
+        output.push_str(&csv_row([
+            section.id.as_str(),
+            &section.name,
+            &section.project_id,
+            project_name.unwrap_or(""),
+            &section.section_order.to_string(),
+            &section.is_archived.to_string(),
+        ]));
+    }
+    output
+}
+
 /// JSON output structure for a created section.
 #[derive(Serialize)]
 pub struct CreatedSectionOutput<'a> {
@@ -120,7 +149,10 @@ pub struct CreatedSectionOutput<'a> {
 }
 
 /// Formats a created section as JSON.
-pub fn format_created_section(result: &SectionAddResult) -> Result<String, serde_json::Error> {
+pub fn format_created_section(
+    result: &SectionAddResult,
+    compact: bool,
+) -> Result<String, serde_json::Error> {
     let output = CreatedSectionOutput {
         id: &result.id,
         name: &result.name,
@@ -128,7 +160,19 @@ pub fn format_created_section(result: &SectionAddResult) -> Result<String, serde
         project_name: result.project_name.as_deref(),
     };
 
-    serde_json::to_string_pretty(&output)
+    super::to_json(&output, compact)
+}
+
+/// Formats a created section as a one-row CSV.
+pub fn format_created_section_csv(result: &SectionAddResult) -> String {
+    let mut output = csv_row(["id", "name", "project_id", "project_name"]);
+    output.push_str(&csv_row([
+        result.id.as_str(),
+        &result.name,
+        &result.project_id,
+        result.project_name.as_deref().unwrap_or(""),
+    ]));
+    output
 }
 
 /// JSON output structure for an edited section.
@@ -140,14 +184,29 @@ pub struct EditedSectionOutput<'a> {
 }
 
 /// Formats an edited section as JSON.
-pub fn format_edited_section(result: &SectionEditResult) -> Result<String, serde_json::Error> {
+pub fn format_edited_section(
+    result: &SectionEditResult,
+    compact: bool,
+) -> Result<String, serde_json::Error> {
     let output = EditedSectionOutput {
         id: &result.id,
         name: &result.name,
         updated_fields: &result.updated_fields,
     };
 
-    serde_json::to_string_pretty(&output)
+    super::to_json(&output, compact)
+}
+
+/// Formats an edited section as a one-row CSV; updated fields are joined
+/// with `;`.
+pub fn format_edited_section_csv(result: &SectionEditResult) -> String {
+    let mut output = csv_row(["id", "name", "updated_fields"]);
+    output.push_str(&csv_row([
+        result.id.as_str(),
+        &result.name,
+        &result.updated_fields.join(";"),
+    ]));
+    output
 }
 
 /// JSON output structure for a deleted section.
@@ -159,12 +218,22 @@ pub struct DeletedSectionOutput<'a> {
 }
 
 /// Formats a deleted section as JSON.
-pub fn format_deleted_section(result: &SectionDeleteResult) -> Result<String, serde_json::Error> {
+pub fn format_deleted_section(
+    result: &SectionDeleteResult,
+    compact: bool,
+) -> Result<String, serde_json::Error> {
     let output = DeletedSectionOutput {
         id: &result.id,
         name: &result.name,
         status: "deleted",
     };
 
-    serde_json::to_string_pretty(&output)
+    super::to_json(&output, compact)
+}
+
+/// Formats a deleted section as a one-row CSV.
+pub fn format_deleted_section_csv(result: &SectionDeleteResult) -> String {
+    let mut output = csv_row(["id", "name", "status"]);
+    output.push_str(&csv_row([result.id.as_str(), &result.name, "deleted"]));
+    output
 }
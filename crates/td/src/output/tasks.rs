@@ -1,5 +1,7 @@
 //! Task output formatting.
 
+use std::collections::{HashMap, HashSet};
+
 use owo_colors::OwoColorize;
 use serde::Serialize;
 use todoist_api_rs::sync::{Collaborator, Item};
@@ -7,12 +9,14 @@ use todoist_cache_rs::Cache;
 
 use crate::commands::add::AddResult;
 use crate::commands::quick::QuickResult;
-use crate::commands::show::ShowResult;
+use crate::commands::show::{HistoryEvent, HistoryEventKind, ShowResult};
 
 use super::helpers::{
-    format_datetime, format_due, format_due_verbose, format_priority, format_priority_verbose,
-    format_reminder, truncate_id, truncate_str,
+    csv_row, format_age, format_datetime, format_due_styled, format_due_verbose, format_labels,
+    format_priority, format_priority_verbose, format_reminder, id_display_len,
+    sanitize_single_line, task_url, truncate_id_to, truncate_str,
 };
+use super::{DateStyle, IdStyle, TaskField};
 
 /// JSON output structure for list command.
 #[derive(Serialize)]
@@ -45,6 +49,27 @@ pub struct CreatedItemOutput<'a> {
     pub content: &'a str,
     pub project_id: &'a str,
     pub project_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reminder_id: Option<&'a str>,
+    /// Fields of the created item as stored in the cache.
+    #[serde(flatten)]
+    pub item: Option<CreatedItemDetails<'a>>,
+}
+
+/// Created item fields read back from the cache after `item_add`.
+#[derive(Serialize)]
+pub struct CreatedItemDetails<'a> {
+    pub description: &'a str,
+    /// User priority (1=highest).
+    pub priority: u8,
+    /// API priority (4=highest).
+    pub api_priority: i32,
+    pub due: Option<DueOutput<'a>>,
+    pub labels: &'a [String],
+    pub section_id: Option<&'a str>,
+    pub parent_id: Option<&'a str>,
+    pub child_order: i32,
+    pub url: String,
 }
 
 /// JSON output structure for a quick add result.
@@ -66,6 +91,7 @@ pub struct QuickAddOutput<'a> {
 #[derive(Serialize)]
 pub struct TaskDetailsOutput<'a> {
     pub id: &'a str,
+    pub url: String,
     pub content: &'a str,
     pub description: &'a str,
     pub priority: u8,
@@ -90,6 +116,19 @@ pub struct TaskDetailsOutput<'a> {
     pub reminders: Vec<ReminderOutput>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub subtasks: Vec<SubtaskOutput<'a>>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub history: Vec<HistoryEventOutput<'a>>,
+}
+
+/// JSON output for a task history event.
+#[derive(Serialize)]
+pub struct HistoryEventOutput<'a> {
+    pub at: &'a str,
+    pub event: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment_id: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<&'a str>,
 }
 
 /// JSON output for due date.
@@ -135,10 +174,16 @@ pub struct SubtaskOutput<'a> {
     pub id: &'a str,
     pub content: &'a str,
     pub checked: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<&'a str>,
 }
 
 /// Formats items as JSON.
-pub fn format_items_json(items: &[&Item], cache: &Cache) -> Result<String, serde_json::Error> {
+pub fn format_items_json(
+    items: &[&Item],
+    cache: &Cache,
+    compact: bool,
+) -> Result<String, serde_json::Error> {
     let current_user_id = cache.user.as_ref().map(|u| u.id.as_str());
 
     let tasks: Vec<TaskOutput> = items
@@ -178,23 +223,105 @@ pub fn format_items_json(items: &[&Item], cache: &Cache) -> Result<String, serde
         has_more: false, // Pagination not implemented yet
     };
 
-    serde_json::to_string_pretty(&output)
+    super::to_json(&output, compact)
+}
+
+/// Formats items as CSV with the same fields as [`format_items_json`].
+///
+/// Labels are joined with `;`.
+pub fn format_items_csv(items: &[&Item], cache: &Cache) -> String {
+    let current_user_id = cache.user.as_ref().map(|u| u.id.as_str());
+    let mut output = csv_row([
+        "id",
+        "content",
+        "description",
+        "priority",
+        "due",
+        "project_id",
+        "project_name",
+        "section_id",
+        "labels",
+        "assignee",
+    ]);
+
+    for item in items {
+        let project_name = cache
+            .projects
+            .iter()
+            .find(|p| p.id == item.project_id)
+            .map(|p| p.name.as_str());
+        let assignee = resolve_assignee_display(
+            item.responsible_uid.as_deref(),
+            current_user_id,
+            &cache.collaborators,
+        );
+
+        output.push_str(&csv_row([
+            item.id.as_str(),
+            &item.content,
+            &item.description,
+            // Convert API priority (4=highest) to user priority (1=highest)
+            &(5 - item.priority).to_string(),
+            item.due.as_ref().map_or("", |d| d.date.as_str()),
+            &item.project_id,
+            project_name.unwrap_or(""),
+            item.section_id.as_deref().unwrap_or(""),
+            &item.labels.join(";"),
+            assignee.as_deref().unwrap_or(""),
+        ]));
+    }
+
+    output
 }
 
 /// Formats a created item as JSON.
-pub fn format_created_item(result: &AddResult) -> Result<String, serde_json::Error> {
+pub fn format_created_item(result: &AddResult, compact: bool) -> Result<String, serde_json::Error> {
     let output = CreatedItemOutput {
         id: &result.id,
         content: &result.content,
         project_id: &result.project_id,
         project_name: result.project_name.as_deref(),
+        reminder_id: result.reminder_id.as_deref(),
+        item: result.item.as_ref().map(|item| CreatedItemDetails {
+            description: &item.description,
+            // Convert API priority (4=highest) to user priority (1=highest)
+            priority: (5 - item.priority) as u8,
+            api_priority: item.priority,
+            due: item.due.as_ref().map(|d| DueOutput {
+                date: &d.date,
+                datetime: d.datetime.as_deref(),
+                string: d.string.as_deref(),
+                is_recurring: d.is_recurring,
+            }),
+            labels: &item.labels,
+            section_id: item.section_id.as_deref(),
+            parent_id: item.parent_id.as_deref(),
+            child_order: item.child_order,
+            url: task_url(&item.id),
+        }),
     };
 
-    serde_json::to_string_pretty(&output)
+    super::to_json(&output, compact)
+}
+
+/// Formats a created task as a one-row CSV.
+pub fn format_created_item_csv(result: &AddResult) -> String {
+    let mut output = csv_row(["id", "content", "project_id", "project_name", "reminder_id"]);
+    output.push_str(&csv_row([
+        result.id.as_str(),
+        &result.content,
+        &result.project_id,
+        result.project_name.as_deref().unwrap_or(""),
+        result.reminder_id.as_deref().unwrap_or(""),
+    ]));
+    output
 }
 
 /// Formats a quick add result as JSON.
-pub fn format_quick_add_result(result: &QuickResult) -> Result<String, serde_json::Error> {
+pub fn format_quick_add_result(
+    result: &QuickResult,
+    compact: bool,
+) -> Result<String, serde_json::Error> {
     let output = QuickAddOutput {
         id: &result.id,
         content: &result.content,
@@ -205,11 +332,14 @@ pub fn format_quick_add_result(result: &QuickResult) -> Result<String, serde_jso
         labels: &result.labels,
     };
 
-    serde_json::to_string_pretty(&output)
+    super::to_json(&output, compact)
 }
 
 /// Formats item details as JSON (show command).
-pub fn format_item_details_json(result: &ShowResult) -> Result<String, serde_json::Error> {
+pub fn format_item_details_json(
+    result: &ShowResult,
+    compact: bool,
+) -> Result<String, serde_json::Error> {
     let due = result.item.due.as_ref().map(|d| DueOutput {
         date: &d.date,
         datetime: d.datetime.as_deref(),
@@ -250,11 +380,13 @@ pub fn format_item_details_json(result: &ShowResult) -> Result<String, serde_jso
             id: &i.id,
             content: &i.content,
             checked: i.checked,
+            parent_id: i.parent_id.as_deref(),
         })
         .collect();
 
     let output = TaskDetailsOutput {
         id: &result.item.id,
+        url: task_url(&result.item.id),
         content: &result.item.content,
         description: &result.item.description,
         // Convert API priority (4=highest) to user priority (1=highest)
@@ -274,13 +406,66 @@ pub fn format_item_details_json(result: &ShowResult) -> Result<String, serde_jso
         comments,
         reminders,
         subtasks,
+        history: result.history.iter().map(history_event_output).collect(),
+    };
+
+    super::to_json(&output, compact)
+}
+
+/// Converts a history event to its JSON form.
+fn history_event_output<'a>(event: &HistoryEvent<'a>) -> HistoryEventOutput<'a> {
+    let (comment_id, content) = match event.kind {
+        HistoryEventKind::Comment(note) => (Some(note.id.as_str()), Some(note.content.as_str())),
+        _ => (None, None),
     };
+    HistoryEventOutput {
+        at: event.timestamp,
+        event: history_event_name(&event.kind),
+        comment_id,
+        content,
+    }
+}
 
-    serde_json::to_string_pretty(&output)
+/// Returns the JSON name of a history event kind.
+fn history_event_name(kind: &HistoryEventKind) -> &'static str {
+    match kind {
+        HistoryEventKind::Created => "created",
+        HistoryEventKind::Updated => "updated",
+        HistoryEventKind::Completed => "completed",
+        HistoryEventKind::Comment(_) => "comment",
+    }
+}
+
+/// Formats a task history timeline, one event per line.
+///
+/// Comments show their first line, truncated to fit.
+fn format_history(events: &[HistoryEvent]) -> String {
+    let mut output = String::new();
+    for event in events {
+        let description = match event.kind {
+            HistoryEventKind::Created => "Created".to_string(),
+            HistoryEventKind::Updated => "Updated".to_string(),
+            HistoryEventKind::Completed => "Completed".to_string(),
+            HistoryEventKind::Comment(note) => {
+                let first_line = note.content.lines().next().unwrap_or_default();
+                format!("Comment: {}", truncate_str(first_line, 60))
+            }
+        };
+        output.push_str(&format!(
+            "  {}  {}\n",
+            format_datetime(event.timestamp),
+            description
+        ));
+    }
+    output
 }
 
 /// Formats item details as a human-readable table (show command).
-pub fn format_item_details_table(result: &ShowResult, use_colors: bool) -> String {
+pub fn format_item_details_table(
+    result: &ShowResult,
+    use_colors: bool,
+    date_style: DateStyle,
+) -> String {
     let mut output = String::new();
 
     // Task header
@@ -293,6 +478,7 @@ pub fn format_item_details_table(result: &ShowResult, use_colors: bool) -> Strin
 
     // ID
     output.push_str(&format!("ID: {}\n", result.item.id));
+    output.push_str(&format!("URL: {}\n", task_url(&result.item.id)));
 
     // Project
     if let Some(ref project_name) = result.project_name {
@@ -310,7 +496,7 @@ pub fn format_item_details_table(result: &ShowResult, use_colors: bool) -> Strin
 
     // Due date
     if let Some(ref due) = result.item.due {
-        let due_display = format_due_verbose(due, use_colors);
+        let due_display = format_due_verbose(due, date_style, use_colors);
         output.push_str(&format!("Due: {}\n", due_display));
     }
 
@@ -337,7 +523,16 @@ pub fn format_item_details_table(result: &ShowResult, use_colors: bool) -> Strin
 
     // Created at
     if let Some(ref created) = result.item.added_at {
-        output.push_str(&format!("Created: {}\n", format_datetime(created)));
+        let age = format_age(Some(created), chrono::Utc::now());
+        if age.is_empty() {
+            output.push_str(&format!("Created: {}\n", format_datetime(created)));
+        } else {
+            output.push_str(&format!(
+                "Created: {} ({} ago)\n",
+                format_datetime(created),
+                age
+            ));
+        }
     }
 
     // Description
@@ -353,7 +548,8 @@ pub fn format_item_details_table(result: &ShowResult, use_colors: bool) -> Strin
         output.push_str(&format!("\nSubtasks ({}):\n", result.subtasks.len()));
         for subtask in &result.subtasks {
             let checkbox = if subtask.checked { "[x]" } else { "[ ]" };
-            output.push_str(&format!("  {} {}\n", checkbox, subtask.content));
+            let indent = "  ".repeat(subtask_depth(subtask, result));
+            output.push_str(&format!("  {}{} {}\n", indent, checkbox, subtask.content));
         }
     }
 
@@ -385,22 +581,275 @@ pub fn format_item_details_table(result: &ShowResult, use_colors: bool) -> Strin
         }
     }
 
+    // History
+    if !result.history.is_empty() {
+        output.push_str("\nHistory:\n");
+        output.push_str(&format_history(&result.history));
+    }
+
     output
 }
 
 /// Formats items as a table.
-pub fn format_items_table(items: &[&Item], cache: &Cache, use_colors: bool) -> String {
+///
+/// When `nested` is true, subtasks are indented beneath their parent. A
+/// subtask whose parent isn't in `items` is shown at the top level.
+/// With `show_header` false the column header row is omitted; rows are
+/// padded to fixed widths either way. Due dates follow `date_style` and IDs
+/// follow `id_style`.
+pub fn format_items_table(
+    items: &[&Item],
+    cache: &Cache,
+    use_colors: bool,
+    nested: bool,
+    show_header: bool,
+    date_style: DateStyle,
+    id_style: IdStyle,
+) -> String {
+    format_items_table_with_fields(
+        items,
+        cache,
+        use_colors,
+        &TaskTableOptions {
+            nested,
+            show_header,
+            date_style,
+            id_style,
+            fields: &[],
+        },
+    )
+}
+
+/// Layout of a task table from [`format_items_table_with_fields`].
+#[derive(Debug, Clone, Copy)]
+pub struct TaskTableOptions<'a> {
+    /// Indent subtasks beneath their parent.
+    pub nested: bool,
+    /// Print the column header row.
+    pub show_header: bool,
+    /// How due dates are rendered.
+    pub date_style: DateStyle,
+    /// How IDs are rendered.
+    pub id_style: IdStyle,
+    /// Extra columns, shown before the content.
+    pub fields: &'a [TaskField],
+}
+
+/// Formats items as a table with optional extra columns.
+///
+/// Like [`format_items_table`], plus a column for each of `options.fields`
+/// before the content (assignee, then age). With an assignee column, the
+/// inline `[@name]` suffix on the content is dropped.
+pub fn format_items_table_with_fields(
+    items: &[&Item],
+    cache: &Cache,
+    use_colors: bool,
+    options: &TaskTableOptions,
+) -> String {
     if items.is_empty() {
         return "No tasks found.\n".to_string();
     }
 
-    let current_user_id = cache.user.as_ref().map(|u| u.id.as_str());
+    let TaskTableOptions {
+        nested,
+        show_header,
+        date_style,
+        id_style,
+        fields,
+    } = *options;
+
+    let show_assignee = fields.contains(&TaskField::Assignee);
+    let show_age = fields.contains(&TaskField::Age);
     let mut output = String::new();
 
     // Header
+    if show_header {
+        let assignee = if show_assignee {
+            format!("{:<15} ", "Assignee")
+        } else {
+            String::new()
+        };
+        let age = if show_age {
+            format!("{:<5} ", "Age")
+        } else {
+            String::new()
+        };
+        let header = format!(
+            "{:<8} {:<4} {:<12} {:<15} {:<15} {}{}{}",
+            "ID", "Pri", "Due", "Project", "Labels", assignee, age, "Content"
+        );
+        if use_colors {
+            output.push_str(&format!("{}\n", header.dimmed()));
+        } else {
+            output.push_str(&header);
+            output.push('\n');
+        }
+    }
+
+    // Items, with ID prefixes long enough to tell every listed task apart
+    let row = RowContext {
+        cache,
+        current_user_id: cache.user.as_ref().map(|u| u.id.as_str()),
+        use_colors,
+        date_style,
+        id_len: id_display_len(items.iter().map(|i| i.id.as_str()), id_style),
+        show_assignee,
+        // One clock reading, so every row's age is measured from the same instant
+        age_now: show_age.then(chrono::Utc::now),
+    };
+    if nested {
+        for (item, depth) in nest_items(items) {
+            output.push_str(&format_item_row(item, &row, depth));
+        }
+    } else {
+        for item in items {
+            output.push_str(&format_item_row(item, &row, 0));
+        }
+    }
+
+    output
+}
+
+/// Returns how many levels below the shown task's direct children a subtask sits.
+fn subtask_depth(subtask: &Item, result: &ShowResult) -> usize {
+    let mut depth = 0;
+    let mut parent = subtask.parent_id.as_deref();
+    while let Some(parent_id) = parent {
+        if parent_id == result.item.id || depth >= result.subtasks.len() {
+            break;
+        }
+        depth += 1;
+        parent = result
+            .subtasks
+            .iter()
+            .find(|s| s.id == parent_id)
+            .and_then(|s| s.parent_id.as_deref());
+    }
+    depth
+}
+
+/// Orders items so each subtask follows its parent, paired with its depth.
+///
+/// Roots keep their relative order from `items`, as do siblings. Items whose
+/// parent is not in `items` are treated as roots.
+fn nest_items<'a>(items: &[&'a Item]) -> Vec<(&'a Item, usize)> {
+    let ids: HashSet<&str> = items.iter().map(|i| i.id.as_str()).collect();
+
+    // Build parent-child relationships within the result set
+    let mut children_map: HashMap<Option<&str>, Vec<&Item>> = HashMap::new();
+    for item in items {
+        let parent = item
+            .parent_id
+            .as_deref()
+            .filter(|p| ids.contains(p) && *p != item.id);
+        children_map.entry(parent).or_default().push(item);
+    }
+
+    fn walk<'a>(
+        out: &mut Vec<(&'a Item, usize)>,
+        parent_id: Option<&str>,
+        children_map: &HashMap<Option<&str>, Vec<&'a Item>>,
+        visited: &mut HashSet<&'a str>,
+        depth: usize,
+    ) {
+        if let Some(children) = children_map.get(&parent_id) {
+            for item in children {
+                if !visited.insert(item.id.as_str()) {
+                    continue;
+                }
+                out.push((item, depth));
+                walk(out, Some(&item.id), children_map, visited, depth + 1);
+            }
+        }
+    }
+
+    let mut out = Vec::with_capacity(items.len());
+    let mut visited = HashSet::new();
+    walk(&mut out, None, &children_map, &mut visited, 0);
+
+    // Parent cycles leave items unreachable from any root; show them flat
+    for item in items {
+        if visited.insert(item.id.as_str()) {
+            out.push((item, 0));
+        }
+    }
+
+    out
+}
+
+/// Formats a single task row, indenting the content by `depth` and showing
+/// the first `id_len` characters of the ID.
+/// JSON output structure for a completed task.
+#[derive(Serialize)]
+pub struct CompletedItemOutput<'a> {
+    pub id: &'a str,
+    pub content: &'a str,
+    pub completed_at: Option<&'a str>,
+    pub project_id: &'a str,
+    pub project_name: Option<&'a str>,
+}
+
+/// Formats completed items as JSON (completed command).
+pub fn format_completed_items_json(
+    items: &[&Item],
+    cache: &Cache,
+    compact: bool,
+) -> Result<String, serde_json::Error> {
+    let tasks: Vec<CompletedItemOutput> = items
+        .iter()
+        .map(|item| CompletedItemOutput {
+            id: &item.id,
+            content: &item.content,
+            completed_at: item.completed_at.as_deref(),
+            project_id: &item.project_id,
+            project_name: cache
+                .projects
+                .iter()
+                .find(|p| p.id == item.project_id)
+                .map(|p| p.name.as_str()),
+        })
+        .collect();
+    super::to_json(&tasks, compact)
+}
+
+/// Formats completed items as CSV with the same fields as
+/// [`format_completed_items_json`].
+pub fn format_completed_items_csv(items: &[&Item], cache: &Cache) -> String {
+    let mut output = csv_row([
+        "id",
+        "content",
+        "completed_at",
+        "project_id",
+        "project_name",
+    ]);
+    for item in items {
+        let project_name = cache
+            .projects
+            .iter()
+            .find(|p| p.id == item.project_id)
+            .map(|p| p.name.as_str());
+        output.push_str(&csv_row([
+            item.id.as_str(),
+            &item.content,
+            item.completed_at.as_deref().unwrap_or(""),
+            &item.project_id,
+            project_name.unwrap_or(""),
+        ]));
+    }
+    output
+}
+
+/// Formats completed items as a table (completed command).
+pub fn format_completed_items_table(
+    items: &[&Item],
+    cache: &Cache,
+    use_colors: bool,
+    id_style: IdStyle,
+) -> String {
+    let mut output = String::new();
     let header = format!(
-        "{:<8} {:<4} {:<12} {:<15} {:<15} {}",
-        "ID", "Pri", "Due", "Project", "Labels", "Content"
+        "{:<8} {:<16} {:<15} {}",
+        "ID", "Completed", "Project", "Content"
     );
     if use_colors {
         output.push_str(&format!("{}\n", header.dimmed()));
@@ -409,40 +858,113 @@ pub fn format_items_table(items: &[&Item], cache: &Cache, use_colors: bool) -> S
         output.push('\n');
     }
 
-    // Items
+    let id_len = id_display_len(items.iter().map(|i| i.id.as_str()), id_style);
     for item in items {
-        let id_prefix = truncate_id(&item.id);
-        let priority = format_priority(item.priority, use_colors);
-        let due = format_due(item.due.as_ref().map(|d| &d.date), use_colors);
+        let completed = item
+            .completed_at
+            .as_deref()
+            .map(format_datetime)
+            .unwrap_or_else(|| "-".to_string());
         let project = cache
             .projects
             .iter()
             .find(|p| p.id == item.project_id)
             .map(|p| truncate_str(&p.name, 15))
             .unwrap_or_default();
-        let labels = super::helpers::format_labels(&item.labels, 15);
+        output.push_str(&format!(
+            "{:<8} {:<16} {:<15} {}\n",
+            truncate_id_to(&item.id, id_len),
+            completed,
+            project,
+            sanitize_single_line(&item.content)
+        ));
+    }
+    output
+}
 
-        let assignee = resolve_assignee_display(
-            item.responsible_uid.as_deref(),
-            current_user_id,
-            &cache.collaborators,
-        );
+/// Settings shared by every row of a task table.
+struct RowContext<'a> {
+    cache: &'a Cache,
+    current_user_id: Option<&'a str>,
+    use_colors: bool,
+    date_style: DateStyle,
+    id_len: usize,
+    show_assignee: bool,
+    /// When set, an age column is shown, measured from this time.
+    age_now: Option<chrono::DateTime<chrono::Utc>>,
+}
 
-        let content_display = if let Some(ref name) = assignee {
-            format!("{} [@{}]", item.content, name)
-        } else {
-            item.content.to_string()
-        };
+fn format_item_row(item: &Item, row: &RowContext, depth: usize) -> String {
+    let RowContext {
+        cache,
+        current_user_id,
+        use_colors,
+        date_style,
+        id_len,
+        show_assignee,
+        age_now,
+    } = *row;
+    let id_prefix = truncate_id_to(&item.id, id_len);
+    let priority = format_priority(item.priority, use_colors);
+    let due = format_due_styled(item.due.as_ref(), date_style, use_colors);
+    let project = cache
+        .projects
+        .iter()
+        .find(|p| p.id == item.project_id)
+        .map(|p| truncate_str(&p.name, 15))
+        .unwrap_or_default();
+    // Pad by visible width; color escapes would throw off `{:<15}`
+    let labels = format_labels(&item.labels, &cache.labels, 15, use_colors);
+    let labels_width = format_labels(&item.labels, &cache.labels, 15, false)
+        .chars()
+        .count();
+    let labels = format!(
+        "{labels}{}",
+        " ".repeat(15usize.saturating_sub(labels_width))
+    );
 
-        let line = format!(
-            "{:<8} {:<4} {:<12} {:<15} {:<15} {}",
-            id_prefix, priority, due, project, labels, content_display
-        );
-        output.push_str(&line);
-        output.push('\n');
-    }
+    let assignee = resolve_assignee_display(
+        item.responsible_uid.as_deref(),
+        current_user_id,
+        &cache.collaborators,
+    );
 
-    output
+    let content = sanitize_single_line(&item.content);
+    let (assignee_column, content_display) = match assignee {
+        _ if show_assignee => (
+            format!(
+                "{:<15} ",
+                truncate_str(assignee.as_deref().unwrap_or(""), 15)
+            ),
+            content,
+        ),
+        Some(ref name) => (String::new(), format!("{} [@{}]", content, name)),
+        None => (String::new(), content),
+    };
+
+    let age_column = match age_now {
+        Some(now) => format!("{:<5} ", format_age(item.added_at.as_deref(), now)),
+        None => String::new(),
+    };
+
+    let indent = if depth > 0 {
+        format!("{}└─ ", "  ".repeat(depth - 1))
+    } else {
+        String::new()
+    };
+
+    format!(
+        "{:<8} {:<4} {:<12} {:<15} {} {}{}{}{}\n",
+        id_prefix,
+        priority,
+        due,
+        project,
+        labels,
+        assignee_column,
+        age_column,
+        indent,
+        content_display
+    )
 }
 
 /// Resolves a responsible_uid to a display name.
@@ -466,3 +988,438 @@ fn resolve_assignee_display(
         .and_then(|c| c.full_name.clone())
         .or_else(|| Some(uid.to_string()))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{collaborator, item};
+
+    #[test]
+    fn test_format_created_item_includes_item_fields() {
+        let item = item("6Jf8VQXxpwv56VQ7", "Pay rent")
+            .parent("parent-1")
+            .section("sec-1")
+            .description("Transfer before noon")
+            .priority(4)
+            .labels(&["bills"])
+            .with_due(todoist_api_rs::models::Due {
+                string: Some("Feb 1".to_string()),
+                ..todoist_api_rs::models::Due::from_date("2025-02-01")
+            })
+            .build();
+        let result = AddResult {
+            id: item.id.clone(),
+            content: item.content.clone(),
+            project_id: item.project_id.clone(),
+            project_name: Some("Home".to_string()),
+            reminder_id: None,
+            item: Some(item),
+        };
+
+        let json = format_created_item(&result, false).unwrap();
+        let compact = format_created_item(&result, true).unwrap();
+        assert!(json.contains('\n'));
+        assert!(!compact.contains('\n'));
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            value,
+            serde_json::from_str::<serde_json::Value>(&compact).unwrap()
+        );
+        assert_eq!(value["id"], "6Jf8VQXxpwv56VQ7");
+        assert_eq!(value["content"], "Pay rent");
+        assert_eq!(value["project_name"], "Home");
+        assert_eq!(value["description"], "Transfer before noon");
+        assert_eq!(value["priority"], 1);
+        assert_eq!(value["api_priority"], 4);
+        assert_eq!(value["due"]["date"], "2025-02-01");
+        assert_eq!(value["due"]["string"], "Feb 1");
+        assert_eq!(value["labels"], serde_json::json!(["bills"]));
+        assert_eq!(value["section_id"], "sec-1");
+        assert_eq!(value["parent_id"], "parent-1");
+        assert_eq!(
+            value["url"],
+            "https://todoist.com/app/task/6Jf8VQXxpwv56VQ7"
+        );
+        assert!(value.get("reminder_id").is_none());
+    }
+
+    #[test]
+    fn test_format_created_item_without_cached_item() {
+        let result = AddResult {
+            id: "task-1".to_string(),
+            content: "Pay rent".to_string(),
+            project_id: "proj-1".to_string(),
+            project_name: None,
+            reminder_id: None,
+            item: None,
+        };
+
+        let json = format_created_item(&result, false).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["id"], "task-1");
+        assert!(value.get("due").is_none());
+    }
+
+    fn nested_ids(items: &[&Item]) -> Vec<(String, usize)> {
+        nest_items(items)
+            .into_iter()
+            .map(|(item, depth)| (item.id.clone(), depth))
+            .collect()
+    }
+
+    #[test]
+    fn test_format_completed_items_json() {
+        let item = item("done-1", "Task done-1")
+            .completed_at("2025-01-15T10:00:00Z")
+            .build();
+
+        let json = format_completed_items_json(&[&item], &Cache::new(), false).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value[0]["id"], "done-1");
+        assert_eq!(value[0]["completed_at"], "2025-01-15T10:00:00Z");
+        assert_eq!(value[0]["project_id"], "proj-1");
+    }
+
+    #[test]
+    fn test_format_items_csv() {
+        let item = item("task-1", "Buy milk, eggs")
+            .labels(&["errand", "home"])
+            .priority(4)
+            .build();
+
+        let csv = format_items_csv(&[&item], &Cache::new());
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(
+            lines[0],
+            "id,content,description,priority,due,project_id,project_name,section_id,labels,assignee"
+        );
+        assert_eq!(
+            lines[1],
+            "task-1,\"Buy milk, eggs\",,1,,proj-1,,,errand;home,"
+        );
+    }
+
+    #[test]
+    fn test_format_completed_items_csv() {
+        let item = item("done-1", "Ship it")
+            .completed_at("2025-01-15T10:00:00Z")
+            .build();
+
+        let csv = format_completed_items_csv(&[&item], &Cache::new());
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], "id,content,completed_at,project_id,project_name");
+        assert_eq!(lines[1], "done-1,Ship it,2025-01-15T10:00:00Z,proj-1,");
+    }
+
+    #[test]
+    fn test_nest_items_parent_with_two_children() {
+        let child_a = item("child-a", "Task child-a").parent("parent").build();
+        let other = item("other", "Task other").build();
+        let parent = item("parent", "Task parent").build();
+        let child_b = item("child-b", "Task child-b").parent("parent").build();
+        let items = vec![&child_a, &other, &parent, &child_b];
+
+        assert_eq!(
+            nested_ids(&items),
+            vec![
+                ("other".to_string(), 0),
+                ("parent".to_string(), 0),
+                ("child-a".to_string(), 1),
+                ("child-b".to_string(), 1),
+            ]
+        );
+
+        let table = format_items_table(
+            &items,
+            &Cache::new(),
+            false,
+            true,
+            true,
+            DateStyle::Relative,
+            IdStyle::Short,
+        );
+        let lines: Vec<&str> = table.lines().collect();
+        assert!(lines[2].ends_with("Task parent"));
+        assert!(lines[3].ends_with("└─ Task child-a"));
+        assert!(lines[4].ends_with("└─ Task child-b"));
+    }
+
+    #[test]
+    fn test_nest_items_orphan_at_top_level() {
+        let orphan = item("orphan", "Task orphan").parent("not-listed").build();
+        let root = item("root", "Task root").build();
+        let items = vec![&orphan, &root];
+
+        assert_eq!(
+            nested_ids(&items),
+            vec![("orphan".to_string(), 0), ("root".to_string(), 0)]
+        );
+
+        let table = format_items_table(
+            &items,
+            &Cache::new(),
+            false,
+            true,
+            true,
+            DateStyle::Relative,
+            IdStyle::Short,
+        );
+        assert!(!table.contains("└─"));
+    }
+
+    #[test]
+    fn test_format_items_table_plain_dates() {
+        let today = chrono::Local::now().date_naive();
+        let item = item("dated", "Task dated")
+            .due(&today.format("%Y-%m-%d").to_string())
+            .build();
+        let items = vec![&item];
+
+        let relative = format_items_table(
+            &items,
+            &Cache::new(),
+            false,
+            false,
+            false,
+            DateStyle::Relative,
+            IdStyle::Short,
+        );
+        assert!(relative.contains("Today"));
+
+        let absolute = format_items_table(
+            &items,
+            &Cache::new(),
+            false,
+            false,
+            false,
+            DateStyle::Absolute,
+            IdStyle::Short,
+        );
+        assert!(!absolute.contains("Today"));
+        assert!(absolute.contains(&today.format("%Y-%m-%d").to_string()));
+    }
+
+    #[test]
+    fn test_format_items_table_flattens_multiline_content() {
+        let item = item("multi", "First line\nsecond line\x1b[2J").build();
+        let items = vec![&item];
+
+        let table = format_items_table(
+            &items,
+            &Cache::new(),
+            false,
+            false,
+            true,
+            DateStyle::Relative,
+            IdStyle::Short,
+        );
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines.len(), 2, "header plus one row: {table:?}");
+        assert!(lines[1].ends_with("First line second line [2J"));
+        assert!(!table.contains('\x1b'));
+    }
+
+    #[test]
+    fn test_format_items_table_flat_does_not_indent() {
+        let parent = item("parent", "Task parent").build();
+        let child = item("child", "Task child").parent("parent").build();
+        let items = vec![&child, &parent];
+
+        let table = format_items_table(
+            &items,
+            &Cache::new(),
+            false,
+            false,
+            true,
+            DateStyle::Relative,
+            IdStyle::Short,
+        );
+        let lines: Vec<&str> = table.lines().collect();
+        assert!(lines[1].ends_with("Task child"));
+        assert!(lines[2].ends_with("Task parent"));
+        assert!(!table.contains("└─"));
+    }
+
+    #[test]
+    fn test_resolve_assignee_display() {
+        let collaborators = vec![
+            collaborator("u-alice", Some("Alice")),
+            collaborator("u-anon", None),
+        ];
+
+        assert_eq!(
+            resolve_assignee_display(Some("u-alice"), Some("u-me"), &collaborators),
+            Some("Alice".to_string())
+        );
+        assert_eq!(
+            resolve_assignee_display(Some("u-me"), Some("u-me"), &collaborators),
+            Some("me".to_string())
+        );
+        // Unknown uids and nameless collaborators fall back to the raw id
+        assert_eq!(
+            resolve_assignee_display(Some("u-gone"), Some("u-me"), &collaborators),
+            Some("u-gone".to_string())
+        );
+        assert_eq!(
+            resolve_assignee_display(Some("u-anon"), None, &collaborators),
+            Some("u-anon".to_string())
+        );
+        assert_eq!(
+            resolve_assignee_display(None, Some("u-me"), &collaborators),
+            None
+        );
+    }
+
+    #[test]
+    fn test_format_items_table_assignee_column() {
+        let mut cache = Cache::new();
+        cache.collaborators = vec![collaborator("u-alice", Some("Alice"))];
+        let assigned = item("assigned", "Task assigned")
+            .responsible("u-alice")
+            .build();
+        let unknown = item("unknown", "Task unknown")
+            .responsible("u-gone")
+            .build();
+        let unassigned = item("unassigned", "Task unassigned").build();
+        let items = vec![&assigned, &unknown, &unassigned];
+
+        let table = format_items_table_with_fields(
+            &items,
+            &cache,
+            false,
+            &TaskTableOptions {
+                nested: false,
+                show_header: true,
+                date_style: DateStyle::Relative,
+                id_style: IdStyle::Full,
+                fields: &[TaskField::Assignee],
+            },
+        );
+        let lines: Vec<&str> = table.lines().collect();
+        assert!(lines[0].ends_with("Assignee        Content"));
+        assert!(lines[1].ends_with("Alice           Task assigned"));
+        assert!(lines[2].ends_with("u-gone          Task unknown"));
+        assert!(lines[3].ends_with("                Task unassigned"));
+        // The column replaces the inline suffix
+        assert!(!table.contains("[@"));
+
+        let table = format_items_table(
+            &items,
+            &cache,
+            false,
+            false,
+            true,
+            DateStyle::Relative,
+            IdStyle::Full,
+        );
+        assert!(!table.contains("Assignee"));
+        assert!(table.contains("Task assigned [@Alice]"));
+    }
+
+    #[test]
+    fn test_format_items_table_age_column() {
+        let old = item("old", "Task old")
+            .added_at(&(chrono::Utc::now() - chrono::Duration::days(21)).to_rfc3339())
+            .build();
+        let undated = item("undated", "Task undated").build();
+        let items = vec![&old, &undated];
+
+        let table = format_items_table_with_fields(
+            &items,
+            &Cache::new(),
+            false,
+            &TaskTableOptions {
+                nested: false,
+                show_header: true,
+                date_style: DateStyle::Relative,
+                id_style: IdStyle::Full,
+                fields: &[TaskField::Age],
+            },
+        );
+        let lines: Vec<&str> = table.lines().collect();
+        assert!(lines[0].ends_with("Age   Content"));
+        assert!(lines[1].ends_with("3w    Task old"));
+        assert!(lines[2].ends_with("      Task undated"));
+    }
+
+    #[test]
+    fn test_format_items_table_without_header() {
+        let parent = item("parent", "Task parent").build();
+        let child = item("child", "Task child").parent("parent").build();
+        let items = vec![&parent, &child];
+
+        let with_header = format_items_table(
+            &items,
+            &Cache::new(),
+            false,
+            true,
+            true,
+            DateStyle::Relative,
+            IdStyle::Short,
+        );
+        let without_header = format_items_table(
+            &items,
+            &Cache::new(),
+            false,
+            true,
+            false,
+            DateStyle::Relative,
+            IdStyle::Short,
+        );
+
+        assert!(with_header.starts_with("ID "));
+        assert!(!without_header.contains("Content"));
+        let rows: Vec<&str> = with_header.lines().skip(1).collect();
+        assert_eq!(without_header.lines().collect::<Vec<_>>(), rows);
+    }
+
+    #[test]
+    fn test_format_items_table_expands_colliding_id_prefixes() {
+        let first = item("6X7rM8aa", "Task 6X7rM8aa").build();
+        let second = item("6X7rM8bb", "Task 6X7rM8bb").build();
+        let items = vec![&first, &second];
+
+        let table = format_items_table(
+            &items,
+            &Cache::new(),
+            false,
+            false,
+            false,
+            DateStyle::Relative,
+            IdStyle::Short,
+        );
+        let lines: Vec<&str> = table.lines().collect();
+        assert!(lines[0].starts_with("6X7rM8a "));
+        assert!(lines[1].starts_with("6X7rM8b "));
+
+        // Without a collision the default 6-character prefix is kept
+        let items = vec![&first];
+        let table = format_items_table(
+            &items,
+            &Cache::new(),
+            false,
+            false,
+            false,
+            DateStyle::Relative,
+            IdStyle::Short,
+        );
+        assert!(table.starts_with("6X7rM8 "));
+    }
+
+    #[test]
+    fn test_format_items_table_full_ids() {
+        let item = item("6X7rM8aaBBccDDee", "Task 6X7rM8aaBBccDDee").build();
+        let items = vec![&item];
+
+        let table = format_items_table(
+            &items,
+            &Cache::new(),
+            false,
+            false,
+            false,
+            DateStyle::Relative,
+            IdStyle::Full,
+        );
+        assert!(table.starts_with("6X7rM8aaBBccDDee "));
+    }
+}
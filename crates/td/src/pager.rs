@@ -0,0 +1,130 @@
+//! Paging of long table output.
+//!
+//! Table output taller than the terminal is piped through `$PAGER` (default
+//! `less -R`, so colors survive). Paging only happens for table output on a
+//! TTY; JSON, CSV and redirected output are always written straight to stdout.
+
+use std::io::{IsTerminal, Write};
+use std::process::{Command, Stdio};
+
+use crate::output::OutputFormat;
+
+/// Pager used when `$PAGER` is unset or empty.
+const DEFAULT_PAGER: &str = "less -R";
+
+/// Decides whether output of `line_count` lines should be paged.
+///
+/// `term_height` is `None` when the terminal size can't be determined, in
+/// which case output is never paged.
+pub fn should_page(
+    enabled: bool,
+    output_format: OutputFormat,
+    stdout_is_tty: bool,
+    line_count: usize,
+    term_height: Option<usize>,
+) -> bool {
+    enabled
+        && output_format == OutputFormat::Table
+        && stdout_is_tty
+        && term_height.is_some_and(|height| line_count > height)
+}
+
+/// Returns the pager command line from `$PAGER`, or `less -R`.
+fn pager_command() -> String {
+    std::env::var("PAGER")
+        .ok()
+        .filter(|p| !p.trim().is_empty())
+        .unwrap_or_else(|| DEFAULT_PAGER.to_string())
+}
+
+/// Returns the terminal height in rows, if stdout is a terminal.
+fn terminal_height() -> Option<usize> {
+    console::Term::stdout()
+        .size_checked()
+        .map(|(rows, _)| usize::from(rows))
+}
+
+/// Prints table output, through the pager when it doesn't fit on screen.
+///
+/// Falls back to printing directly if the pager can't be started.
+pub fn print_paged(enabled: bool, output_format: OutputFormat, output: &str) {
+    let stdout_is_tty = std::io::stdout().is_terminal();
+    let term_height = if enabled && stdout_is_tty {
+        terminal_height()
+    } else {
+        None
+    };
+    if !should_page(
+        enabled,
+        output_format,
+        stdout_is_tty,
+        output.lines().count(),
+        term_height,
+    ) || run_pager(&pager_command(), output).is_err()
+    {
+        print!("{output}");
+    }
+}
+
+/// Writes `output` to the pager's stdin and waits for the user to quit it.
+fn run_pager(command: &str, output: &str) -> std::io::Result<()> {
+    let mut parts = command.split_whitespace();
+    let program = parts.next().unwrap_or("less");
+    let mut child = Command::new(program)
+        .args(parts)
+        .stdin(Stdio::piped())
+        .spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        // The user may quit the pager before reading everything; a broken
+        // pipe here is expected and not an error.
+        let _ = stdin.write_all(output.as_bytes());
+    }
+    child.wait()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_should_page_only_when_output_exceeds_terminal() {
+        assert!(should_page(true, OutputFormat::Table, true, 50, Some(40)));
+        assert!(!should_page(true, OutputFormat::Table, true, 40, Some(40)));
+        assert!(!should_page(true, OutputFormat::Table, true, 10, Some(40)));
+    }
+
+    #[test]
+    fn test_should_page_never_without_tty_or_size() {
+        assert!(!should_page(
+            true,
+            OutputFormat::Table,
+            false,
+            500,
+            Some(40)
+        ));
+        assert!(!should_page(true, OutputFormat::Table, true, 500, None));
+    }
+
+    #[test]
+    fn test_should_page_never_for_machine_formats() {
+        assert!(!should_page(true, OutputFormat::Json, true, 500, Some(40)));
+        assert!(!should_page(true, OutputFormat::Csv, true, 500, Some(40)));
+    }
+
+    #[test]
+    fn test_should_page_respects_disable() {
+        assert!(!should_page(
+            false,
+            OutputFormat::Table,
+            true,
+            500,
+            Some(40)
+        ));
+    }
+
+    #[test]
+    fn test_run_pager_missing_program_is_error() {
+        assert!(run_pager("td-no-such-pager-binary", "hello\n").is_err());
+    }
+}
@@ -0,0 +1,203 @@
+//! Shared fixtures for unit tests.
+//!
+//! Builders start from a minimal, valid model (open task in `proj-1`,
+//! priority 1, no due date) and only set what a test cares about:
+//!
+//! ```ignore
+//! let task = item("t1", "Pay rent").project("bills").due("2025-02-01").build();
+//! ```
+
+use tempfile::TempDir;
+use todoist_api_rs::client::TodoistClient;
+use todoist_api_rs::models::{Due, Duration};
+use todoist_api_rs::sync::{Collaborator, Item, Project};
+use todoist_cache_rs::{Cache, CacheStore, SyncManager};
+
+/// Starts building a task with the given id and content.
+pub fn item(id: &str, content: &str) -> ItemBuilder {
+    ItemBuilder(Item {
+        id: id.to_string(),
+        user_id: None,
+        project_id: "proj-1".to_string(),
+        content: content.to_string(),
+        description: String::new(),
+        priority: 1,
+        due: None,
+        deadline: None,
+        parent_id: None,
+        child_order: 0,
+        section_id: None,
+        day_order: 0,
+        is_collapsed: false,
+        labels: vec![],
+        added_by_uid: None,
+        assigned_by_uid: None,
+        responsible_uid: None,
+        checked: false,
+        is_deleted: false,
+        added_at: None,
+        updated_at: None,
+        completed_at: None,
+        duration: None,
+    })
+}
+
+/// Builder returned by [`item`].
+pub struct ItemBuilder(Item);
+
+impl ItemBuilder {
+    pub fn project(mut self, project_id: &str) -> Self {
+        self.0.project_id = project_id.to_string();
+        self
+    }
+
+    pub fn section(mut self, section_id: &str) -> Self {
+        self.0.section_id = Some(section_id.to_string());
+        self
+    }
+
+    pub fn parent(mut self, parent_id: &str) -> Self {
+        self.0.parent_id = Some(parent_id.to_string());
+        self
+    }
+
+    pub fn child_order(mut self, child_order: i32) -> Self {
+        self.0.child_order = child_order;
+        self
+    }
+
+    pub fn description(mut self, description: &str) -> Self {
+        self.0.description = description.to_string();
+        self
+    }
+
+    pub fn priority(mut self, priority: i32) -> Self {
+        self.0.priority = priority;
+        self
+    }
+
+    pub fn labels(mut self, labels: &[&str]) -> Self {
+        self.0.labels = labels.iter().map(|l| l.to_string()).collect();
+        self
+    }
+
+    /// Sets a plain (date-only, non-recurring) due date.
+    pub fn due(self, date: &str) -> Self {
+        self.with_due(Due::from_date(date))
+    }
+
+    pub fn with_due(mut self, due: Due) -> Self {
+        self.0.due = Some(due);
+        self
+    }
+
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.0.duration = Some(duration);
+        self
+    }
+
+    pub fn responsible(mut self, uid: &str) -> Self {
+        self.0.responsible_uid = Some(uid.to_string());
+        self
+    }
+
+    pub fn added_at(mut self, added_at: &str) -> Self {
+        self.0.added_at = Some(added_at.to_string());
+        self
+    }
+
+    pub fn checked(mut self) -> Self {
+        self.0.checked = true;
+        self
+    }
+
+    pub fn deleted(mut self) -> Self {
+        self.0.is_deleted = true;
+        self
+    }
+
+    pub fn updated_at(mut self, updated_at: &str) -> Self {
+        self.0.updated_at = Some(updated_at.to_string());
+        self
+    }
+
+    /// Marks the task completed at the given timestamp.
+    pub fn completed_at(mut self, completed_at: &str) -> Self {
+        self.0.checked = true;
+        self.0.completed_at = Some(completed_at.to_string());
+        self
+    }
+
+    pub fn build(self) -> Item {
+        self.0
+    }
+}
+
+/// Starts building a (non-inbox, unarchived) project.
+pub fn project(id: &str, name: &str) -> ProjectBuilder {
+    ProjectBuilder(Project {
+        id: id.to_string(),
+        name: name.to_string(),
+        color: None,
+        parent_id: None,
+        child_order: 0,
+        is_collapsed: false,
+        is_favorite: false,
+        is_deleted: false,
+        is_archived: false,
+        inbox_project: false,
+        view_style: None,
+        shared: false,
+        can_assign_tasks: false,
+        folder_id: None,
+        created_at: None,
+        updated_at: None,
+    })
+}
+
+/// Builder returned by [`project`].
+pub struct ProjectBuilder(Project);
+
+impl ProjectBuilder {
+    pub fn parent(mut self, parent_id: &str) -> Self {
+        self.0.parent_id = Some(parent_id.to_string());
+        self
+    }
+
+    pub fn child_order(mut self, child_order: i32) -> Self {
+        self.0.child_order = child_order;
+        self
+    }
+
+    pub fn inbox(mut self) -> Self {
+        self.0.inbox_project = true;
+        self
+    }
+
+    pub fn build(self) -> Project {
+        self.0
+    }
+}
+
+/// A collaborator with an optional display name and nothing else.
+pub fn collaborator(id: &str, full_name: Option<&str>) -> Collaborator {
+    Collaborator {
+        id: id.to_string(),
+        email: None,
+        full_name: full_name.map(String::from),
+        timezone: None,
+        image_id: None,
+    }
+}
+
+/// A `SyncManager` whose cache file holds `cache`, talking to `base_url`.
+///
+/// The cache lives in a temporary directory; keep the returned `TempDir`
+/// alive for as long as the manager is used.
+pub fn sync_manager(cache: &Cache, base_url: &str) -> (SyncManager, TempDir) {
+    let dir = tempfile::tempdir().unwrap();
+    let store = CacheStore::with_path(dir.path().join("cache.json"));
+    store.save(cache).unwrap();
+    let client = TodoistClient::with_base_url("test-token", base_url).unwrap();
+    (SyncManager::new(client, store).unwrap(), dir)
+}
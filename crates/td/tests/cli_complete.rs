@@ -0,0 +1,97 @@
+//! `td __complete` output for dynamic shell completion, read from a
+//! pre-seeded cache without any network access.
+
+mod common;
+
+use std::path::Path;
+use std::process::Output;
+
+use tempfile::TempDir;
+use todoist_api_rs::sync::{Label, Project};
+use todoist_cache_rs::Cache;
+
+fn make_project(id: &str, name: &str, is_archived: bool) -> Project {
+    Project {
+        id: id.to_string(),
+        name: name.to_string(),
+        color: None,
+        parent_id: None,
+        child_order: 0,
+        is_collapsed: false,
+        shared: false,
+        can_assign_tasks: false,
+        is_deleted: false,
+        is_archived,
+        is_favorite: false,
+        view_style: None,
+        inbox_project: false,
+        folder_id: None,
+        created_at: None,
+        updated_at: None,
+    }
+}
+
+fn make_label(id: &str, name: &str) -> Label {
+    Label {
+        id: id.to_string(),
+        name: name.to_string(),
+        color: None,
+        item_order: 0,
+        is_deleted: false,
+        is_favorite: false,
+    }
+}
+
+/// Writes a cache where the `td` binary looks for it under `sandbox`.
+fn seed_cache(sandbox: &Path) {
+    let mut cache = Cache::new();
+    cache.projects = vec![
+        make_project("p1", "Work", false),
+        make_project("p2", "Home Renovation", false),
+        make_project("p3", "Old Stuff", true),
+    ];
+    cache.labels = vec![make_label("l1", "urgent"), make_label("l2", "deep-work")];
+    common::cache_store(sandbox).save(&cache).unwrap();
+}
+
+fn run_td(sandbox: &Path, args: &[&str]) -> Output {
+    common::td_command(sandbox)
+        .args(args)
+        .env_remove("TODOIST_TOKEN")
+        // Nothing listens here, so any request would fail the command
+        .env("TODOIST_API_BASE_URL", "http://127.0.0.1:9")
+        .output()
+        .expect("failed to run td")
+}
+
+#[test]
+fn test_complete_prints_cached_names() {
+    let sandbox = TempDir::new().unwrap();
+    seed_cache(sandbox.path());
+
+    let output = run_td(sandbox.path(), &["__complete", "projects"]);
+    assert!(
+        output.status.success(),
+        "td __complete failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "Home Renovation\nWork\n"
+    );
+
+    let output = run_td(sandbox.path(), &["__complete", "labels"]);
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "deep-work\nurgent\n"
+    );
+}
+
+#[test]
+fn test_complete_without_cache_prints_nothing() {
+    let sandbox = TempDir::new().unwrap();
+
+    let output = run_td(sandbox.path(), &["__complete", "sections"]);
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+}
@@ -0,0 +1,86 @@
+//! `td list` behavior of the `td` binary against a mock API server.
+
+mod common;
+
+use chrono::{Duration, Utc};
+use common::{cache_store, full_sync_response, run_td};
+use tempfile::TempDir;
+use todoist_cache_rs::Cache;
+use wiremock::matchers::{any, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_invalid_filter_fails_before_syncing() {
+    let server = MockServer::start().await;
+    Mock::given(any())
+        .respond_with(ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let sandbox = TempDir::new().unwrap();
+    let output = run_td(
+        sandbox.path(),
+        &server.uri(),
+        &["list", "--sync", "--filter", "(today & p1"],
+    );
+
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("filter"), "unexpected stderr: {stderr}");
+    server.verify().await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_reads_within_stale_window_sync_once() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(full_sync_response()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let sandbox = TempDir::new().unwrap();
+    for _ in 0..2 {
+        let output = run_td(sandbox.path(), &server.uri(), &["list"]);
+        assert!(
+            output.status.success(),
+            "td list failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+    server.verify().await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_stale_read_falls_back_to_cache_when_sync_fails() {
+    let server = MockServer::start().await;
+    Mock::given(any())
+        .respond_with(ResponseTemplate::new(500))
+        .mount(&server)
+        .await;
+
+    let sandbox = TempDir::new().unwrap();
+    let mut cache = Cache::new();
+    cache.sync_token = "old_token".to_string();
+    cache.last_sync = Some(Utc::now() - Duration::hours(1));
+    cache_store(sandbox.path()).save(&cache).unwrap();
+
+    let output = run_td(sandbox.path(), &server.uri(), &["list"]);
+    assert!(
+        output.status.success(),
+        "td list failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("showing cached data"),
+        "unexpected stderr: {stderr}"
+    );
+
+    // An explicit --sync still has to succeed
+    let output = run_td(sandbox.path(), &server.uri(), &["list", "--sync"]);
+    assert!(!output.status.success());
+}
@@ -0,0 +1,161 @@
+//! `td quick` behavior of the `td` binary against a mock API server.
+
+mod common;
+
+use common::run_td;
+use tempfile::TempDir;
+use wiremock::matchers::{body_partial_json, body_string_contains, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn quick_add_response() -> serde_json::Value {
+    serde_json::json!({
+        "id": "task-1",
+        "project_id": "inbox-1",
+        "content": "Pack bag",
+        "priority": 1,
+        "resolved_project_name": "Inbox"
+    })
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_quick_note_sent_with_task_in_one_request() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/tasks/quick"))
+        .and(body_partial_json(serde_json::json!({
+            "text": "Pack bag tomorrow",
+            "note": "Passport is in the drawer"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(quick_add_response()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let sandbox = TempDir::new().unwrap();
+    let output = run_td(
+        sandbox.path(),
+        &server.uri(),
+        &[
+            "quick",
+            "Pack bag tomorrow",
+            "--note",
+            "Passport is in the drawer",
+        ],
+    );
+    assert!(
+        output.status.success(),
+        "td quick failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    // The note rides along with the task; no separate comment request is made
+    let requests = server.received_requests().await.unwrap();
+    assert_eq!(requests.len(), 1);
+    server.verify().await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_quick_without_note_sends_no_note_field() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/tasks/quick"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(quick_add_response()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let sandbox = TempDir::new().unwrap();
+    let output = run_td(sandbox.path(), &server.uri(), &["quick", "Pack bag"]);
+    assert!(output.status.success());
+
+    let requests = server.received_requests().await.unwrap();
+    let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+    assert_eq!(body["text"], "Pack bag");
+    assert!(body.get("note").is_none());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_quick_file_is_uploaded_and_attached_to_created_task() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/tasks/quick"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(quick_add_response()))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/uploads"))
+        .and(body_string_contains("filename=\"packing.txt\""))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "resource_type": "file",
+            "file_name": "packing.txt",
+            "file_url": "https://files.example/packing.txt",
+            "upload_state": "completed"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .and(body_string_contains("note_add"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "sync_token": "token-after-note",
+            "full_sync": false,
+            "sync_status": {},
+            "temp_id_mapping": {}
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let sandbox = TempDir::new().unwrap();
+    let file = sandbox.path().join("packing.txt");
+    std::fs::write(&file, "socks").unwrap();
+
+    let output = run_td(
+        sandbox.path(),
+        &server.uri(),
+        &["quick", "Pack bag", "--file", file.to_str().unwrap()],
+    );
+    assert!(
+        output.status.success(),
+        "td quick failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let requests = server.received_requests().await.unwrap();
+    let sync = requests
+        .iter()
+        .find(|r| r.url.path() == "/sync")
+        .expect("note_add request");
+    let form: std::collections::HashMap<String, String> =
+        serde_urlencoded::from_bytes(&sync.body).unwrap();
+    let commands: serde_json::Value = serde_json::from_str(&form["commands"]).unwrap();
+    assert_eq!(commands[0]["type"], "note_add");
+    assert_eq!(commands[0]["args"]["item_id"], "task-1");
+    assert_eq!(
+        commands[0]["args"]["file_attachment"]["file_url"],
+        "https://files.example/packing.txt"
+    );
+    server.verify().await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_quick_missing_file_fails_before_creating_task() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(quick_add_response()))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let sandbox = TempDir::new().unwrap();
+    let missing = sandbox.path().join("missing.txt");
+    let output = run_td(
+        sandbox.path(),
+        &server.uri(),
+        &["quick", "Pack bag", "--file", missing.to_str().unwrap()],
+    );
+    assert!(!output.status.success());
+    server.verify().await;
+}
@@ -0,0 +1,194 @@
+//! `--quiet` and `--quiet-if-fresh` behavior of the `td` binary against a
+//! mock API server.
+
+mod common;
+
+use common::run_td;
+use tempfile::TempDir;
+use wiremock::matchers::{body_string_contains, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// A full sync response containing just the Inbox project.
+fn full_sync_response() -> serde_json::Value {
+    let mut response = common::full_sync_response();
+    response["projects"] = serde_json::json!([
+        {
+            "id": "inbox-1",
+            "name": "Inbox",
+            "child_order": 0,
+            "is_collapsed": false,
+            "is_favorite": false,
+            "is_deleted": false,
+            "is_archived": false,
+            "inbox_project": true,
+            "shared": false,
+            "can_assign_tasks": false
+        }
+    ]);
+    response
+}
+
+/// Answers an `item_add` command, mapping its temp ID to `task-123`.
+struct ItemAddResponder;
+
+impl wiremock::Respond for ItemAddResponder {
+    fn respond(&self, request: &wiremock::Request) -> ResponseTemplate {
+        let body = String::from_utf8_lossy(&request.body);
+        let commands: serde_json::Value = body
+            .split('&')
+            .find_map(|pair| pair.strip_prefix("commands="))
+            .and_then(|value| serde_json::from_str(&percent_decode(value)).ok())
+            .expect("request should carry commands");
+        let command = &commands[0];
+
+        ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "sync_token": "after_add_token",
+            "full_sync": false,
+            "items": [
+                {
+                    "id": "task-123",
+                    "project_id": "inbox-1",
+                    "content": command["args"]["content"],
+                    "description": "",
+                    "priority": 1,
+                    "child_order": 1,
+                    "day_order": 0,
+                    "is_collapsed": false,
+                    "labels": [],
+                    "checked": false,
+                    "is_deleted": false
+                }
+            ],
+            "sync_status": { command["uuid"].as_str().unwrap(): "ok" },
+            "temp_id_mapping": { command["temp_id"].as_str().unwrap(): "task-123" }
+        }))
+    }
+}
+
+/// Decodes a form-urlencoded value.
+fn percent_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => decoded.push(b' '),
+            b'%' if i + 2 < bytes.len() => {
+                let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).unwrap();
+                decoded.push(u8::from_str_radix(hex, 16).unwrap());
+                i += 2;
+            }
+            byte => decoded.push(byte),
+        }
+        i += 1;
+    }
+    String::from_utf8(decoded).unwrap()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_quiet_add_prints_nothing_and_succeeds() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .and(body_string_contains("commands="))
+        .respond_with(ItemAddResponder)
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(full_sync_response()))
+        .mount(&server)
+        .await;
+
+    let sandbox = TempDir::new().unwrap();
+    let output = run_td(
+        sandbox.path(),
+        &server.uri(),
+        &["add", "Buy milk", "--project", "Inbox", "--quiet"],
+    );
+
+    assert!(
+        output.status.success(),
+        "td add failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(
+        output.stdout.is_empty(),
+        "unexpected stdout: {}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_quiet_still_reports_failure() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(full_sync_response()))
+        .mount(&server)
+        .await;
+
+    let sandbox = TempDir::new().unwrap();
+    let output = run_td(
+        sandbox.path(),
+        &server.uri(),
+        &["add", "Buy milk", "--project", "Nowhere", "--quiet"],
+    );
+
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+    assert!(!output.stderr.is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_sync_quiet_if_fresh_only_syncs_stale_cache() {
+    let sandbox = TempDir::new().unwrap();
+
+    // No cache yet, so the first run is stale: it syncs and reports
+    let stale_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(full_sync_response()))
+        .expect(1)
+        .mount(&stale_server)
+        .await;
+
+    let output = run_td(
+        sandbox.path(),
+        &stale_server.uri(),
+        &["sync", "--quiet-if-fresh"],
+    );
+    assert!(
+        output.status.success(),
+        "td sync failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(!output.stdout.is_empty());
+    stale_server.verify().await;
+
+    // The cache was just synced, so the second run makes no requests
+    let fresh_server = MockServer::start().await;
+    Mock::given(wiremock::matchers::any())
+        .respond_with(ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&fresh_server)
+        .await;
+
+    let output = run_td(
+        sandbox.path(),
+        &fresh_server.uri(),
+        &["sync", "--quiet-if-fresh"],
+    );
+    assert!(
+        output.status.success(),
+        "td sync failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(
+        output.stdout.is_empty(),
+        "unexpected stdout: {}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+    fresh_server.verify().await;
+}
@@ -0,0 +1,61 @@
+//! `td setup` token handling against a mock API server.
+
+mod common;
+
+use std::process::Stdio;
+
+use common::td_command;
+use tempfile::TempDir;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn mock_server() -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(common::full_sync_response()))
+        .mount(&server)
+        .await;
+    server
+}
+
+#[tokio::test]
+async fn test_setup_saves_token_given_as_flag() {
+    let server = mock_server().await;
+    let sandbox = TempDir::new().unwrap();
+
+    let output = td_command(sandbox.path())
+        .args(["setup", "--token", "flag-token", "--storage", "config"])
+        .env_remove("TODOIST_TOKEN")
+        .env("TODOIST_API_BASE_URL", server.uri())
+        .stdin(Stdio::null())
+        .output()
+        .unwrap();
+
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let config = std::fs::read_to_string(sandbox.path().join("td-config.toml")).unwrap();
+    assert!(config.contains("flag-token"));
+}
+
+#[tokio::test]
+async fn test_setup_does_not_persist_token_from_environment() {
+    let server = mock_server().await;
+    let sandbox = TempDir::new().unwrap();
+
+    // Without a terminal the wizard can't prompt, so setup fails instead of
+    // quietly saving the environment's token.
+    let output = td_command(sandbox.path())
+        .arg("setup")
+        .env("TODOIST_TOKEN", "env-token")
+        .env("TODOIST_API_BASE_URL", server.uri())
+        .stdin(Stdio::null())
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(6));
+    assert!(!sandbox.path().join("td-config.toml").exists());
+}
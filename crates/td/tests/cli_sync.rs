@@ -0,0 +1,133 @@
+//! `td sync` behavior of the `td` binary against a mock API server.
+
+mod common;
+
+use std::path::Path;
+
+use common::{cache_store, full_sync_response, run_td};
+use tempfile::TempDir;
+use todoist_cache_rs::Cache;
+use wiremock::matchers::{any, body_string_contains, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Writes a cache holding a user record, which a full sync response without
+/// one leaves in place.
+fn seed_cache(sandbox: &Path) {
+    let mut cache = Cache::new();
+    cache.sync_token = "old_token".to_string();
+    cache.user = Some(serde_json::from_value(serde_json::json!({"id": "user-1"})).unwrap());
+    cache_store(sandbox).save(&cache).unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_sync_reset_rebuilds_from_empty_cache() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .and(body_string_contains("sync_token=*"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(full_sync_response()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let sandbox = TempDir::new().unwrap();
+    seed_cache(sandbox.path());
+
+    let output = run_td(
+        sandbox.path(),
+        &server.uri(),
+        &["sync", "--full", "--reset", "--force"],
+    );
+    assert!(
+        output.status.success(),
+        "td sync failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let cache = cache_store(sandbox.path()).load().unwrap();
+    assert_eq!(cache.sync_token, "full_sync_token");
+    assert!(cache.user.is_none());
+    server.verify().await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_sync_reset_refuses_without_force_when_non_interactive() {
+    let server = MockServer::start().await;
+    Mock::given(any())
+        .respond_with(ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let sandbox = TempDir::new().unwrap();
+    seed_cache(sandbox.path());
+
+    let output = run_td(
+        sandbox.path(),
+        &server.uri(),
+        &["sync", "--full", "--reset"],
+    );
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("--force"), "unexpected stderr: {stderr}");
+
+    let cache = cache_store(sandbox.path()).load().unwrap();
+    assert!(cache.user.is_some());
+    server.verify().await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_sync_reset_recovers_from_corrupt_cache() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .and(body_string_contains("sync_token=*"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(full_sync_response()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let sandbox = TempDir::new().unwrap();
+    let store = cache_store(sandbox.path());
+    std::fs::create_dir_all(store.path().parent().unwrap()).unwrap();
+    std::fs::write(store.path(), "{ not json").unwrap();
+
+    let output = run_td(
+        sandbox.path(),
+        &server.uri(),
+        &["sync", "--full", "--reset", "--force"],
+    );
+    assert!(
+        output.status.success(),
+        "td sync failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let cache = store.load().unwrap();
+    assert_eq!(cache.sync_token, "full_sync_token");
+    server.verify().await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_corrupt_cache_error_suggests_reset() {
+    let server = MockServer::start().await;
+    Mock::given(any())
+        .respond_with(ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let sandbox = TempDir::new().unwrap();
+    let store = cache_store(sandbox.path());
+    std::fs::create_dir_all(store.path().parent().unwrap()).unwrap();
+    std::fs::write(store.path(), "{ not json").unwrap();
+
+    let output = run_td(sandbox.path(), &server.uri(), &["sync"]);
+    assert_eq!(output.status.code(), Some(5));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("td sync --full --reset --force"),
+        "unexpected stderr: {stderr}"
+    );
+    server.verify().await;
+}
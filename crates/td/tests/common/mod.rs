@@ -0,0 +1,61 @@
+//! Helpers shared by the `td` binary integration tests.
+//!
+//! Each test runs `td` inside a sandbox directory: config, cache and `HOME`
+//! all point into it, so tests never touch the real user's files.
+
+#![allow(dead_code)]
+
+use std::path::Path;
+use std::process::{Command, Output};
+
+use todoist_cache_rs::CacheStore;
+
+/// A `td` command whose config, cache and home live under `sandbox`.
+pub fn td_command(sandbox: &Path) -> Command {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_td"));
+    command
+        .env("TD_CONFIG", sandbox.join("td-config.toml"))
+        .env("HOME", sandbox)
+        .env("XDG_CONFIG_HOME", sandbox.join("xdg-config"))
+        .env("XDG_CACHE_HOME", sandbox.join("xdg-cache"))
+        .env("NO_COLOR", "1");
+    command
+}
+
+/// Runs `td` in `sandbox` with a test token against the API at `base_url`.
+pub fn run_td(sandbox: &Path, base_url: &str, args: &[&str]) -> Output {
+    td_command(sandbox)
+        .args(args)
+        .env("TODOIST_TOKEN", "test-token")
+        .env("TODOIST_API_BASE_URL", base_url)
+        .output()
+        .expect("failed to run td")
+}
+
+/// The cache store the `td` binary uses inside `sandbox`.
+pub fn cache_store(sandbox: &Path) -> CacheStore {
+    CacheStore::with_path(sandbox.join("xdg-cache").join("td").join("cache.json"))
+}
+
+/// An empty full sync response.
+pub fn full_sync_response() -> serde_json::Value {
+    serde_json::json!({
+        "sync_token": "full_sync_token",
+        "full_sync": true,
+        "items": [],
+        "projects": [],
+        "labels": [],
+        "sections": [],
+        "notes": [],
+        "project_notes": [],
+        "reminders": [],
+        "filters": [],
+        "collaborators": [],
+        "collaborator_states": [],
+        "live_notifications": [],
+        "sync_status": {},
+        "temp_id_mapping": {},
+        "completed_info": [],
+        "locations": []
+    })
+}
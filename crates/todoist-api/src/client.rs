@@ -8,16 +8,17 @@ use serde::{de::DeserializeOwned, Serialize};
 use crate::error::Result;
 use crate::quick_add::{QuickAddRequest, QuickAddResponse};
 use crate::retry::{
-    execute_empty_with_retry, execute_with_retry, RetryConfig, DEFAULT_INITIAL_BACKOFF_SECS,
-    DEFAULT_MAX_BACKOFF_SECS, DEFAULT_MAX_RETRIES,
+    execute_empty_with_retry, execute_text_with_retry, execute_with_retry, RetryConfig,
+    DEFAULT_INITIAL_BACKOFF_SECS, DEFAULT_MAX_BACKOFF_SECS, DEFAULT_MAX_RETRIES,
 };
-use crate::sync::{SyncRequest, SyncResponse};
+use crate::stats::ProductivityStats;
+use crate::sync::{FileAttachment, SyncRequest, SyncResponse};
 
 /// Base URL for the Todoist API v1.
 const BASE_URL: &str = "https://api.todoist.com/api/v1";
 
 /// Default request timeout in seconds.
-const DEFAULT_TIMEOUT_SECS: u64 = 30;
+pub const DEFAULT_TIMEOUT_SECS: u64 = 30;
 
 /// Builder for creating a [`TodoistClient`] with custom configuration.
 ///
@@ -142,6 +143,9 @@ impl TodoistClientBuilder {
 ///
 /// For optimal performance, create a single client instance and share it
 /// (via `Arc` or cloning) across tasks rather than creating new clients.
+/// The HTTP client is built once in [`TodoistClientBuilder::build`]; clones
+/// are cheap and share its connection pool, so keep-alive connections are
+/// reused across requests.
 ///
 /// ```
 /// use std::sync::Arc;
@@ -205,6 +209,14 @@ impl TodoistClient {
         &self.token
     }
 
+    /// Replaces the API token used for subsequent requests.
+    ///
+    /// Lets long-lived consumers rotate credentials without rebuilding the
+    /// client. Requests already in flight keep the token they started with.
+    pub fn set_token(&mut self, token: impl Into<String>) {
+        self.token = token.into();
+    }
+
     /// Returns a reference to the underlying HTTP client.
     pub fn http_client(&self) -> &reqwest::Client {
         &self.http_client
@@ -411,6 +423,37 @@ impl TodoistClient {
         .await
     }
 
+    /// Performs a sync request and also returns the response body exactly as
+    /// the server sent it.
+    ///
+    /// Useful for debugging: the body keeps fields the [`SyncResponse`] model
+    /// doesn't know about and resources it failed to parse.
+    pub async fn sync_with_body(&self, request: SyncRequest) -> Result<(SyncResponse, String)> {
+        let url = format!("{}/sync", self.base_url);
+        let http_client = self.http_client.clone();
+        let token = self.token.clone();
+        let form_body = request.to_form_body();
+
+        let body = execute_text_with_retry(&self.retry_config, || {
+            let url = url.clone();
+            let http_client = http_client.clone();
+            let token = token.clone();
+            let form_body = form_body.clone();
+            async move {
+                http_client
+                    .post(&url)
+                    .bearer_auth(&token)
+                    .header("Content-Type", "application/x-www-form-urlencoded")
+                    .body(form_body)
+                    .send()
+                    .await
+                    .map_err(crate::error::Error::Http)
+            }
+        })
+        .await?;
+        Ok((serde_json::from_str(&body)?, body))
+    }
+
     /// Creates a task using the Quick Add endpoint with NLP parsing.
     ///
     /// The Quick Add endpoint parses natural language input to extract project,
@@ -457,6 +500,87 @@ impl TodoistClient {
         })
         .await
     }
+
+    /// Uploads a file so it can be attached to a comment.
+    ///
+    /// The file is sent as `multipart/form-data` to `/uploads`. Pass the
+    /// returned [`FileAttachment`] as the `file_attachment` of a `note_add`
+    /// command to attach it; an upload that is never attached is discarded
+    /// by Todoist.
+    ///
+    /// # Arguments
+    /// * `file_name` - The name shown for the attachment
+    /// * `contents` - The file's bytes
+    pub async fn upload_file(&self, file_name: &str, contents: Vec<u8>) -> Result<FileAttachment> {
+        let url = format!("{}/uploads", self.base_url);
+        let http_client = self.http_client.clone();
+        let token = self.token.clone();
+        let boundary = format!("td-{}", uuid::Uuid::new_v4().simple());
+        let body = multipart_file_body(&boundary, file_name, &contents);
+
+        execute_with_retry(&self.retry_config, || {
+            let url = url.clone();
+            let http_client = http_client.clone();
+            let token = token.clone();
+            let body = body.clone();
+            let content_type = format!("multipart/form-data; boundary={boundary}");
+            async move {
+                http_client
+                    .post(&url)
+                    .bearer_auth(&token)
+                    .header("Content-Type", content_type)
+                    .body(body)
+                    .send()
+                    .await
+                    .map_err(crate::error::Error::Http)
+            }
+        })
+        .await
+    }
+
+    /// Fetches productivity stats (completion history, karma, goals).
+    ///
+    /// Unlike the Sync API, which only returns active tasks, this endpoint
+    /// reports server-side completion counts.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use todoist_api_rs::client::TodoistClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = TodoistClient::new("your-api-token").unwrap();
+    ///     let stats = client.get_productivity_stats().await.unwrap();
+    ///     println!("Completed {} tasks", stats.completed_count);
+    /// }
+    /// ```
+    pub async fn get_productivity_stats(&self) -> Result<ProductivityStats> {
+        self.get("/tasks/completed/stats").await
+    }
+}
+
+/// Builds a `multipart/form-data` body with a single `file` part.
+fn multipart_file_body(boundary: &str, file_name: &str, contents: &[u8]) -> Vec<u8> {
+    // Quotes and line breaks would end the header value early
+    let file_name: String = file_name
+        .chars()
+        .map(|c| {
+            if matches!(c, '"' | '\r' | '\n') {
+                '_'
+            } else {
+                c
+            }
+        })
+        .collect();
+    let mut body = format!(
+        "--{boundary}\r\n\
+         Content-Disposition: form-data; name=\"file\"; filename=\"{file_name}\"\r\n\
+         Content-Type: application/octet-stream\r\n\r\n"
+    )
+    .into_bytes();
+    body.extend_from_slice(contents);
+    body.extend_from_slice(format!("\r\n--{boundary}--\r\n").as_bytes());
+    body
 }
 
 impl fmt::Debug for TodoistClient {
@@ -25,6 +25,14 @@ fn test_todoist_client_stores_token() {
     assert_eq!(client.token(), token);
 }
 
+// Test: set_token replaces the stored token
+#[test]
+fn test_todoist_client_set_token() {
+    let mut client = TodoistClient::new("old-token").unwrap();
+    client.set_token("new-token");
+    assert_eq!(client.token(), "new-token");
+}
+
 // Test: TodoistClient should hold a reqwest client internally
 #[test]
 fn test_todoist_client_has_http_client() {
@@ -682,4 +690,113 @@ mod wiremock_tests {
             Ok(_) => panic!("Expected error, got success"),
         }
     }
+
+    #[tokio::test]
+    async fn test_upload_file_sends_multipart_and_parses_attachment() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/uploads"))
+            .and(header("Authorization", "Bearer test-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "resource_type": "file",
+                "file_name": "notes.txt",
+                "file_size": 5,
+                "file_type": "text/plain",
+                "file_url": "https://files.example/notes.txt",
+                "upload_state": "completed"
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = TodoistClient::with_base_url("test-token", mock_server.uri()).unwrap();
+        let attachment = client
+            .upload_file("notes.txt", b"hello".to_vec())
+            .await
+            .unwrap();
+        assert_eq!(attachment.file_name.as_deref(), Some("notes.txt"));
+        assert_eq!(
+            attachment.file_url.as_deref(),
+            Some("https://files.example/notes.txt")
+        );
+
+        let requests = mock_server.received_requests().await.unwrap();
+        let content_type = requests[0].headers["content-type"].to_str().unwrap();
+        let boundary = content_type
+            .strip_prefix("multipart/form-data; boundary=")
+            .unwrap();
+        let body = String::from_utf8(requests[0].body.clone()).unwrap();
+        assert!(body.starts_with(&format!("--{boundary}\r\n")));
+        assert!(body.contains("name=\"file\"; filename=\"notes.txt\""));
+        assert!(body.contains("\r\n\r\nhello\r\n"));
+        assert!(body.ends_with(&format!("--{boundary}--\r\n")));
+    }
+}
+
+mod connection_reuse_tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    /// Starts a keep-alive HTTP/1.1 server answering `{}` to every request.
+    ///
+    /// Returns its base URL and a counter of accepted TCP connections.
+    fn start_counting_server() -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let connections = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&connections);
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else { break };
+                counter.fetch_add(1, Ordering::SeqCst);
+                std::thread::spawn(move || {
+                    let mut reader = BufReader::new(stream.try_clone().unwrap());
+                    loop {
+                        // Read one request's headers (GET requests have no body)
+                        let mut line = String::new();
+                        loop {
+                            line.clear();
+                            match reader.read_line(&mut line) {
+                                Ok(0) | Err(_) => return,
+                                Ok(_) if line == "\r\n" => break,
+                                Ok(_) => {}
+                            }
+                        }
+                        let response = "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: 2\r\n\r\n{}";
+                        if stream.write_all(response.as_bytes()).is_err() {
+                            return;
+                        }
+                    }
+                });
+            }
+        });
+        (url, connections)
+    }
+
+    #[tokio::test]
+    async fn test_sequential_requests_reuse_one_connection() {
+        let (url, connections) = start_counting_server();
+        let client = TodoistClient::with_base_url("test-token", url).unwrap();
+
+        for _ in 0..3 {
+            let _: serde_json::Value = client.get("/ping").await.unwrap();
+        }
+
+        assert_eq!(connections.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_cloned_client_shares_connection_pool() {
+        let (url, connections) = start_counting_server();
+        let client = TodoistClient::with_base_url("test-token", url).unwrap();
+        let clone = client.clone();
+
+        let _: serde_json::Value = client.get("/ping").await.unwrap();
+        let _: serde_json::Value = clone.get("/ping").await.unwrap();
+
+        assert_eq!(connections.load(Ordering::SeqCst), 1);
+    }
 }
@@ -17,4 +17,5 @@ pub mod models;
 pub mod prelude;
 pub mod quick_add;
 mod retry;
+pub mod stats;
 pub mod sync;
@@ -14,6 +14,7 @@
 //! // - Error, ApiError, Result (error handling)
 //! // - SyncRequest, SyncResponse, SyncCommand (sync API)
 //! // - QuickAddRequest, QuickAddResponse (quick add API)
+//! // - ProductivityStats (stats API)
 //! // - Item, Project, Label, Section, etc. (data models)
 //! ```
 
@@ -31,6 +32,7 @@ pub use crate::sync::{
     // Response
     CommandError,
     CommandResult,
+    CompletedInfo,
     FileAttachment,
     Filter,
     Item,
@@ -51,5 +53,8 @@ pub use crate::sync::{
 // Quick Add types
 pub use crate::quick_add::{QuickAddRequest, QuickAddResponse};
 
+// Stats types
+pub use crate::stats::ProductivityStats;
+
 // Common model types
 pub use crate::models::{Deadline, Due, Duration, DurationUnit, LocationTrigger, ReminderType};
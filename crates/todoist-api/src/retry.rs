@@ -116,6 +116,32 @@ pub(crate) async fn handle_empty_response_with_retry(
     Err(parse_error_response(response).await)
 }
 
+/// Handles a response whose body is kept as text, returning a retry
+/// decision or error.
+pub(crate) async fn handle_text_response_with_retry(
+    response: reqwest::Response,
+    attempt: u32,
+    max_retries: u32,
+) -> Result<RetryDecision<String>> {
+    let status = response.status();
+
+    if status.is_success() {
+        return Ok(RetryDecision::Success(response.text().await?));
+    }
+
+    // Check for rate limiting (429)
+    if status.as_u16() == 429 && attempt < max_retries {
+        let retry_after = response
+            .headers()
+            .get("retry-after")
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse::<u64>().ok());
+        return Ok(RetryDecision::Retry { retry_after });
+    }
+
+    Err(parse_error_response(response).await)
+}
+
 /// Parses an error response into our error types.
 pub(crate) async fn parse_error_response(response: reqwest::Response) -> Error {
     let status = response.status();
@@ -219,3 +245,28 @@ where
 
     Err(Error::Api(ApiError::RateLimit { retry_after: None }))
 }
+
+/// Executes a request with retry logic, returning the response body as text.
+pub(crate) async fn execute_text_with_retry<F, Fut>(
+    config: &RetryConfig,
+    mut make_request: F,
+) -> Result<String>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = Result<reqwest::Response>>,
+{
+    for attempt in 0..=config.max_retries {
+        let response = make_request().await?;
+
+        match handle_text_response_with_retry(response, attempt, config.max_retries).await {
+            Ok(RetryDecision::Success(body)) => return Ok(body),
+            Ok(RetryDecision::Retry { retry_after }) => {
+                let backoff = config.calculate_backoff(attempt, retry_after);
+                sleep(backoff).await;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+
+    Err(Error::Api(ApiError::RateLimit { retry_after: None }))
+}
@@ -0,0 +1,179 @@
+//! Productivity stats types for the Todoist API.
+//!
+//! The stats endpoint (`GET /api/v1/tasks/completed/stats`) reports completion
+//! history that is not available through the Sync API, since sync responses
+//! only include active (uncompleted) tasks.
+
+use serde::{Deserialize, Serialize};
+
+/// Productivity stats for the authenticated user.
+///
+/// Only the fields the CLI uses are modeled; unknown fields are ignored.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProductivityStats {
+    /// Total number of tasks completed, all time.
+    #[serde(default)]
+    pub completed_count: i64,
+
+    /// Completion counts for recent days, most recent first.
+    #[serde(default)]
+    pub days_items: Vec<DayStats>,
+
+    /// Completion counts for recent weeks, most recent first.
+    #[serde(default)]
+    pub week_items: Vec<WeekStats>,
+
+    /// Current karma score.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub karma: Option<f64>,
+
+    /// Karma trend: "up", "down", or "-".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub karma_trend: Option<String>,
+
+    /// Daily/weekly goals and streaks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub goals: Option<Goals>,
+}
+
+/// Completed task count for a single day.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DayStats {
+    /// The day, in YYYY-MM-DD format.
+    pub date: String,
+
+    /// Number of tasks completed on this day.
+    #[serde(default)]
+    pub total_completed: i64,
+}
+
+/// Completed task count for a single week.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WeekStats {
+    /// First day of the week, in YYYY-MM-DD format.
+    pub from: String,
+
+    /// Last day of the week, in YYYY-MM-DD format.
+    pub to: String,
+
+    /// Number of tasks completed during this week.
+    #[serde(default)]
+    pub total_completed: i64,
+}
+
+/// Productivity goals and streaks.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Goals {
+    /// Daily completed-task goal.
+    #[serde(default)]
+    pub daily_goal: i32,
+
+    /// Weekly completed-task goal.
+    #[serde(default)]
+    pub weekly_goal: i32,
+
+    /// Current streak of days meeting the daily goal.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_daily_streak: Option<Streak>,
+
+    /// Longest streak of days meeting the daily goal.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_daily_streak: Option<Streak>,
+}
+
+/// A run of consecutive days (or weeks) meeting a goal.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Streak {
+    /// Length of the streak.
+    #[serde(default)]
+    pub count: i32,
+
+    /// First day of the streak, in YYYY-MM-DD format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start: Option<String>,
+
+    /// Last day of the streak, in YYYY-MM-DD format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end: Option<String>,
+}
+
+impl ProductivityStats {
+    /// Returns the number of tasks completed on the given day (YYYY-MM-DD).
+    ///
+    /// Returns 0 if the day is not present in `days_items`.
+    pub fn completed_on(&self, date: &str) -> i64 {
+        self.days_items
+            .iter()
+            .find(|d| d.date == date)
+            .map(|d| d.total_completed)
+            .unwrap_or(0)
+    }
+
+    /// Returns the number of tasks completed in the most recent week.
+    pub fn completed_this_week(&self) -> i64 {
+        self.week_items
+            .first()
+            .map(|w| w.total_completed)
+            .unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_productivity_stats_deserialize_full() {
+        let json = r#"{
+            "completed_count": 1234,
+            "days_items": [
+                {"date": "2025-01-15", "total_completed": 7, "items": []},
+                {"date": "2025-01-14", "total_completed": 3, "items": []}
+            ],
+            "week_items": [
+                {"from": "2025-01-13", "to": "2025-01-19", "total_completed": 10, "items": []}
+            ],
+            "karma": 15230.0,
+            "karma_trend": "up",
+            "karma_last_update": 50.0,
+            "goals": {
+                "daily_goal": 5,
+                "weekly_goal": 25,
+                "current_daily_streak": {"count": 3, "start": "2025-01-13", "end": "2025-01-15"},
+                "max_daily_streak": {"count": 12, "start": "2024-11-01", "end": "2024-11-12"}
+            }
+        }"#;
+
+        let stats: ProductivityStats = serde_json::from_str(json).unwrap();
+        assert_eq!(stats.completed_count, 1234);
+        assert_eq!(stats.days_items.len(), 2);
+        assert_eq!(stats.karma, Some(15230.0));
+        assert_eq!(stats.karma_trend.as_deref(), Some("up"));
+
+        let goals = stats.goals.unwrap();
+        assert_eq!(goals.daily_goal, 5);
+        assert_eq!(goals.current_daily_streak.unwrap().count, 3);
+    }
+
+    #[test]
+    fn test_productivity_stats_deserialize_minimal() {
+        let stats: ProductivityStats = serde_json::from_str("{}").unwrap();
+        assert_eq!(stats.completed_count, 0);
+        assert!(stats.days_items.is_empty());
+        assert!(stats.goals.is_none());
+    }
+
+    #[test]
+    fn test_completed_on_and_this_week() {
+        let json = r#"{
+            "completed_count": 10,
+            "days_items": [{"date": "2025-01-15", "total_completed": 4}],
+            "week_items": [{"from": "2025-01-13", "to": "2025-01-19", "total_completed": 9}]
+        }"#;
+        let stats: ProductivityStats = serde_json::from_str(json).unwrap();
+
+        assert_eq!(stats.completed_on("2025-01-15"), 4);
+        assert_eq!(stats.completed_on("2025-01-01"), 0);
+        assert_eq!(stats.completed_this_week(), 9);
+    }
+}
@@ -1,5 +1,6 @@
 //! Sync API request types.
 
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 /// Valid command types for the Todoist Sync API.
@@ -124,6 +125,134 @@ pub enum SyncCommandType {
     FilterUpdateOrders,
 }
 
+/// The `due` argument for commands such as `item_add`, `item_update`, and
+/// `reminder_add`.
+///
+/// A due date is given either as a natural-language `string` that Todoist
+/// parses server-side, or as an explicit `date`. Timed due dates go in
+/// `date` as a floating `YYYY-MM-DDTHH:MM:SS` value, or in `datetime` as a
+/// fixed UTC time. The constructors set exactly one of the three; unset
+/// fields are omitted from the JSON.
+///
+/// See: <https://developer.todoist.com/sync/v9/#due-dates>
+///
+/// # Examples
+///
+/// ```
+/// use todoist_api_rs::sync::DueSpec;
+///
+/// let due = DueSpec::from_string("every monday").with_lang("en");
+/// let json = serde_json::to_value(&due).unwrap();
+/// assert_eq!(json, serde_json::json!({"string": "every monday", "lang": "en"}));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct DueSpec {
+    /// Explicit date (`YYYY-MM-DD`) or floating datetime (`YYYY-MM-DDTHH:MM:SS`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
+
+    /// Fixed timed due date in UTC (`YYYY-MM-DDTHH:MM:SSZ`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub datetime: Option<String>,
+
+    /// Natural-language due string (e.g., "tomorrow at 9am").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub string: Option<String>,
+
+    /// IANA timezone that pins a timed due date (e.g., "Europe/Berlin").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+
+    /// Language of `string` (e.g., "es" for "lunes").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lang: Option<String>,
+
+    /// Whether the due date repeats. Todoist infers this from `string`, so it
+    /// is usually left unset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_recurring: Option<bool>,
+}
+
+impl DueSpec {
+    /// Creates a due date from a `YYYY-MM-DD` or floating
+    /// `YYYY-MM-DDTHH:MM:SS` value, passed through as-is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use todoist_api_rs::sync::DueSpec;
+    ///
+    /// let json = serde_json::to_value(DueSpec::from_date("2025-01-15")).unwrap();
+    /// assert_eq!(json, serde_json::json!({"date": "2025-01-15"}));
+    /// ```
+    pub fn from_date(date: impl Into<String>) -> Self {
+        Self {
+            date: Some(date.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Creates an all-day due date.
+    pub fn from_naive_date(date: NaiveDate) -> Self {
+        Self::from_date(date.format("%Y-%m-%d").to_string())
+    }
+
+    /// Creates a timed due date as a floating datetime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use todoist_api_rs::sync::DueSpec;
+    ///
+    /// let at = NaiveDate::from_ymd_opt(2025, 1, 15).unwrap().and_hms_opt(9, 30, 0).unwrap();
+    /// let json = serde_json::to_value(DueSpec::from_datetime(at)).unwrap();
+    /// assert_eq!(json, serde_json::json!({"date": "2025-01-15T09:30:00"}));
+    /// ```
+    pub fn from_datetime(datetime: NaiveDateTime) -> Self {
+        Self::from_date(datetime.format("%Y-%m-%dT%H:%M:%S").to_string())
+    }
+
+    /// Creates a timed due date fixed to an instant, sent as a UTC `datetime`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use todoist_api_rs::sync::DueSpec;
+    ///
+    /// let at = Utc.with_ymd_and_hms(2025, 1, 15, 8, 30, 0).unwrap();
+    /// let json = serde_json::to_value(DueSpec::from_utc_datetime(at)).unwrap();
+    /// assert_eq!(json, serde_json::json!({"datetime": "2025-01-15T08:30:00Z"}));
+    /// ```
+    pub fn from_utc_datetime(datetime: DateTime<Utc>) -> Self {
+        Self {
+            datetime: Some(datetime.format("%Y-%m-%dT%H:%M:%SZ").to_string()),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a due date from a natural-language string.
+    pub fn from_string(string: impl Into<String>) -> Self {
+        Self {
+            string: Some(string.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the language `string` is written in.
+    pub fn with_lang(mut self, lang: impl Into<String>) -> Self {
+        self.lang = Some(lang.into());
+        self
+    }
+
+    /// Pins the due date to a timezone.
+    pub fn with_timezone(mut self, timezone: impl Into<String>) -> Self {
+        self.timezone = Some(timezone.into());
+        self
+    }
+}
+
 /// Request body for the Sync API endpoint.
 ///
 /// The Sync API uses `application/x-www-form-urlencoded` format, where
@@ -1063,4 +1192,70 @@ mod tests {
         assert_eq!(cmd.command_type, SyncCommandType::FilterDelete);
         assert_eq!(cmd.args["id"], "filter-123");
     }
+
+    #[test]
+    fn test_due_spec_from_date() {
+        let json = serde_json::to_value(DueSpec::from_date("2025-01-15")).unwrap();
+        assert_eq!(json, serde_json::json!({"date": "2025-01-15"}));
+
+        let date = NaiveDate::from_ymd_opt(2025, 1, 15).unwrap();
+        let json = serde_json::to_value(DueSpec::from_naive_date(date)).unwrap();
+        assert_eq!(json, serde_json::json!({"date": "2025-01-15"}));
+    }
+
+    #[test]
+    fn test_due_spec_from_datetime() {
+        let at = NaiveDate::from_ymd_opt(2025, 1, 15)
+            .unwrap()
+            .and_hms_opt(9, 30, 0)
+            .unwrap();
+        let json = serde_json::to_value(DueSpec::from_datetime(at)).unwrap();
+        assert_eq!(json, serde_json::json!({"date": "2025-01-15T09:30:00"}));
+    }
+
+    #[test]
+    fn test_due_spec_from_utc_datetime() {
+        let at = NaiveDate::from_ymd_opt(2025, 1, 15)
+            .unwrap()
+            .and_hms_opt(8, 30, 0)
+            .unwrap()
+            .and_utc();
+        let due = DueSpec::from_utc_datetime(at).with_timezone("Europe/Berlin");
+        let json = serde_json::to_value(&due).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"datetime": "2025-01-15T08:30:00Z", "timezone": "Europe/Berlin"})
+        );
+    }
+
+    #[test]
+    fn test_due_spec_from_string() {
+        let json = serde_json::to_value(DueSpec::from_string("tomorrow")).unwrap();
+        assert_eq!(json, serde_json::json!({"string": "tomorrow"}));
+    }
+
+    #[test]
+    fn test_due_spec_with_lang_and_timezone() {
+        let due = DueSpec::from_string("lunes 9am")
+            .with_lang("es")
+            .with_timezone("Europe/Madrid");
+        let json = serde_json::to_value(&due).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"string": "lunes 9am", "lang": "es", "timezone": "Europe/Madrid"})
+        );
+    }
+
+    #[test]
+    fn test_due_spec_recurring_flag() {
+        let due = DueSpec {
+            is_recurring: Some(true),
+            ..DueSpec::from_string("every day")
+        };
+        let json = serde_json::to_value(&due).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"string": "every day", "is_recurring": true})
+        );
+    }
 }
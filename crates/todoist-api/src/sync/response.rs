@@ -2,7 +2,8 @@
 
 use std::collections::HashMap;
 
-use serde::{Deserialize, Serialize};
+use serde::de::{self, DeserializeOwned};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 // Re-export common types that are used by sync API consumers
 pub use crate::models::{Deadline, Due, Duration, DurationUnit, LocationTrigger, ReminderType};
@@ -49,7 +50,29 @@ pub use crate::models::{Deadline, Due, Duration, DurationUnit, LocationTrigger,
 /// let response: SyncResponse = serde_json::from_str(json).unwrap();
 /// assert_eq!(response.real_id("temp-123"), Some(&"real-id-456".to_string()));
 /// ```
+///
+/// ## Skip malformed resources
+///
+/// A resource that fails to parse is dropped and recorded in
+/// `skipped_resources` instead of failing the whole response.
+///
+/// ```
+/// use todoist_api_rs::sync::SyncResponse;
+///
+/// let json = r#"{
+///     "sync_token": "token",
+///     "labels": [
+///         {"id": "label-1", "name": "work"},
+///         {"id": "label-2"}
+///     ]
+/// }"#;
+///
+/// let response: SyncResponse = serde_json::from_str(json).unwrap();
+/// assert_eq!(response.labels.len(), 1);
+/// assert_eq!(response.skipped_resources[0].id.as_deref(), Some("label-2"));
+/// ```
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
 pub struct SyncResponse {
     /// New sync token for subsequent incremental syncs.
     pub sync_token: String,
@@ -140,13 +163,113 @@ pub struct SyncResponse {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub stats: Option<serde_json::Value>,
 
-    /// Completed info for projects/sections.
+    /// Completed task counts for projects, sections, and parent tasks.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub completed_info: Vec<serde_json::Value>,
+    pub completed_info: Vec<CompletedInfo>,
 
     /// Location-based reminders.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub locations: Vec<serde_json::Value>,
+
+    /// Resources that were present in the response but failed to parse.
+    #[serde(skip)]
+    pub skipped_resources: Vec<SkippedResource>,
+}
+
+/// A resource object dropped from a [`SyncResponse`] because it failed to
+/// parse, e.g. after an incompatible change to the API's schema.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkippedResource {
+    /// The response array the resource came from (e.g. "items").
+    pub resource: String,
+    /// The resource's ID, if it had a readable one.
+    pub id: Option<String>,
+    /// The parse error.
+    pub error: String,
+}
+
+impl Serialize for SyncResponse {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SyncResponse::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for SyncResponse {
+    /// Deserializes the response, skipping resource objects that fail to
+    /// parse rather than rejecting the whole response.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut value = serde_json::Value::deserialize(deserializer)?;
+        let mut skipped = Vec::new();
+
+        let items = take_lenient(&mut value, "items", &mut skipped);
+        let projects = take_lenient(&mut value, "projects", &mut skipped);
+        let labels = take_lenient(&mut value, "labels", &mut skipped);
+        let sections = take_lenient(&mut value, "sections", &mut skipped);
+        let notes = take_lenient(&mut value, "notes", &mut skipped);
+        let project_notes = take_lenient(&mut value, "project_notes", &mut skipped);
+        let reminders = take_lenient(&mut value, "reminders", &mut skipped);
+        let filters = take_lenient(&mut value, "filters", &mut skipped);
+        let collaborators = take_lenient(&mut value, "collaborators", &mut skipped);
+        let collaborator_states = take_lenient(&mut value, "collaborator_states", &mut skipped);
+        let completed_info = take_lenient(&mut value, "completed_info", &mut skipped);
+
+        let mut response = SyncResponse::deserialize(value).map_err(de::Error::custom)?;
+        response.items = items;
+        response.projects = projects;
+        response.labels = labels;
+        response.sections = sections;
+        response.notes = notes;
+        response.project_notes = project_notes;
+        response.reminders = reminders;
+        response.filters = filters;
+        response.collaborators = collaborators;
+        response.collaborator_states = collaborator_states;
+        response.completed_info = completed_info;
+        response.skipped_resources = skipped;
+        Ok(response)
+    }
+}
+
+/// Removes the array at `key` from a response object and parses each
+/// element, recording the ones that fail in `skipped`.
+///
+/// Anything other than an array is left in place so the strict parse
+/// reports it.
+fn take_lenient<T: DeserializeOwned>(
+    value: &mut serde_json::Value,
+    key: &str,
+    skipped: &mut Vec<SkippedResource>,
+) -> Vec<T> {
+    let Some(object) = value.as_object_mut() else {
+        return Vec::new();
+    };
+    if !object.get(key).is_some_and(serde_json::Value::is_array) {
+        return Vec::new();
+    }
+    let Some(serde_json::Value::Array(elements)) = object.remove(key) else {
+        return Vec::new();
+    };
+
+    elements
+        .into_iter()
+        .filter_map(|element| {
+            let id = element
+                .get("id")
+                .and_then(serde_json::Value::as_str)
+                .map(str::to_string);
+            match serde_json::from_value(element) {
+                Ok(parsed) => Some(parsed),
+                Err(e) => {
+                    skipped.push(SkippedResource {
+                        resource: key.to_string(),
+                        id,
+                        error: e.to_string(),
+                    });
+                    None
+                }
+            }
+        })
+        .collect()
 }
 
 /// Result of a command execution.
@@ -656,6 +779,10 @@ pub struct User {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub time_format: Option<i32>,
 
+    /// Interface language code (e.g., "en", "es").
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lang: Option<String>,
+
     /// Whether user has premium.
     #[serde(default)]
     pub is_premium: bool,
@@ -668,6 +795,42 @@ impl User {
     }
 }
 
+/// Completed task count for a project, section, or parent task.
+///
+/// Exactly one of `project_id`, `section_id`, or `item_id` is set.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompletedInfo {
+    /// Project the count applies to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+
+    /// Section the count applies to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub section_id: Option<String>,
+
+    /// Parent task the count applies to (completed subtasks).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub item_id: Option<String>,
+
+    /// Number of completed tasks.
+    #[serde(default)]
+    pub completed_items: u32,
+
+    /// Number of archived sections (projects only).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_sections: Option<u32>,
+}
+
+impl CompletedInfo {
+    /// Returns true if this entry is for the same project, section, or task
+    /// as `other`.
+    pub fn same_target(&self, other: &CompletedInfo) -> bool {
+        self.project_id == other.project_id
+            && self.section_id == other.section_id
+            && self.item_id == other.item_id
+    }
+}
+
 /// A collaborator on a shared project.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Collaborator {
@@ -742,6 +905,49 @@ mod tests {
         assert!(response.projects.is_empty());
     }
 
+    #[test]
+    fn test_sync_response_skips_malformed_item() {
+        let json = r#"{
+            "sync_token": "token123",
+            "full_sync": true,
+            "some_future_field": {"nested": true},
+            "items": [
+                {"id": "item-1", "project_id": "proj-1", "content": "Good"},
+                {"id": "item-2", "project_id": "proj-1", "content": 42},
+                {"id": "item-3", "project_id": "proj-1", "content": "Also good"}
+            ],
+            "projects": [{"id": "proj-1", "name": "Inbox"}]
+        }"#;
+
+        let response: SyncResponse = serde_json::from_str(json).unwrap();
+        let ids: Vec<&str> = response.items.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(ids, vec!["item-1", "item-3"]);
+        assert_eq!(response.projects.len(), 1);
+        assert_eq!(response.skipped_resources.len(), 1);
+        assert_eq!(response.skipped_resources[0].resource, "items");
+        assert_eq!(response.skipped_resources[0].id.as_deref(), Some("item-2"));
+    }
+
+    #[test]
+    fn test_sync_response_still_requires_sync_token() {
+        let json = r#"{"full_sync": true, "items": []}"#;
+        assert!(serde_json::from_str::<SyncResponse>(json).is_err());
+    }
+
+    #[test]
+    fn test_sync_response_serialize_round_trip() {
+        let json = r#"{
+            "sync_token": "token123",
+            "full_sync": false,
+            "labels": [{"id": "label-1", "name": "work"}]
+        }"#;
+
+        let response: SyncResponse = serde_json::from_str(json).unwrap();
+        let reparsed: SyncResponse =
+            serde_json::from_str(&serde_json::to_string(&response).unwrap()).unwrap();
+        assert_eq!(reparsed, response);
+    }
+
     #[test]
     fn test_sync_response_deserialize_with_items() {
         let json = r#"{
@@ -844,6 +1050,68 @@ mod tests {
         assert_eq!(errors[0].0, "cmd-2");
     }
 
+    #[test]
+    fn test_sync_response_real_id_without_mapping() {
+        let json = r#"{"sync_token": "token", "full_sync": false}"#;
+
+        let response: SyncResponse = serde_json::from_str(json).unwrap();
+        assert!(response.temp_id_mapping.is_empty());
+        assert_eq!(response.real_id("temp-1"), None);
+    }
+
+    #[test]
+    fn test_sync_response_errors_skips_ok_statuses() {
+        let json = r#"{
+            "sync_token": "token",
+            "full_sync": false,
+            "sync_status": {
+                "cmd-1": {"error_code": 15, "error": "Invalid temporary id"},
+                "cmd-2": "ok",
+                "cmd-3": {"error_code": 22, "error": "Item not found"}
+            }
+        }"#;
+
+        let response: SyncResponse = serde_json::from_str(json).unwrap();
+        let mut errors = response.errors();
+        errors.sort_by(|a, b| a.0.cmp(b.0));
+
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].0, "cmd-1");
+        assert_eq!(errors[0].1.error_code, 15);
+        assert_eq!(errors[1].0, "cmd-3");
+        assert_eq!(errors[1].1.error, "Item not found");
+    }
+
+    #[test]
+    fn test_sync_response_deserialize_completed_info() {
+        let json = r#"{
+            "sync_token": "token",
+            "full_sync": false,
+            "completed_info": [
+                {"project_id": "proj-1", "completed_items": 12, "archived_sections": 1},
+                {"section_id": "sec-1", "completed_items": 3},
+                {"item_id": "item-1", "completed_items": 2}
+            ]
+        }"#;
+
+        let response: SyncResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(response.completed_info.len(), 3);
+        assert_eq!(
+            response.completed_info[0].project_id.as_deref(),
+            Some("proj-1")
+        );
+        assert_eq!(response.completed_info[0].completed_items, 12);
+        assert_eq!(response.completed_info[0].archived_sections, Some(1));
+        assert_eq!(
+            response.completed_info[1].section_id.as_deref(),
+            Some("sec-1")
+        );
+        assert_eq!(
+            response.completed_info[2].item_id.as_deref(),
+            Some("item-1")
+        );
+    }
+
     #[test]
     fn test_sync_response_no_errors() {
         let json = r#"{
@@ -0,0 +1,64 @@
+//! Integration tests for the productivity stats endpoint.
+//!
+//! These tests use wiremock to mock the Todoist API responses.
+
+use todoist_api_rs::client::TodoistClient;
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Test: Stats response is parsed into ProductivityStats
+#[tokio::test]
+async fn test_get_productivity_stats() {
+    let mock_server = MockServer::start().await;
+
+    let response_json = serde_json::json!({
+        "completed_count": 512,
+        "days_items": [
+            {"date": "2025-01-15", "total_completed": 6, "items": []}
+        ],
+        "week_items": [
+            {"from": "2025-01-13", "to": "2025-01-19", "total_completed": 21, "items": []}
+        ],
+        "karma": 9000.0,
+        "karma_trend": "up",
+        "goals": {
+            "daily_goal": 5,
+            "weekly_goal": 25,
+            "current_daily_streak": {"count": 4, "start": "2025-01-12", "end": "2025-01-15"}
+        }
+    });
+
+    Mock::given(method("GET"))
+        .and(path("/tasks/completed/stats"))
+        .and(header("Authorization", "Bearer test-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(response_json))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = TodoistClient::with_base_url("test-token", mock_server.uri()).unwrap();
+    let stats = client.get_productivity_stats().await.unwrap();
+
+    assert_eq!(stats.completed_count, 512);
+    assert_eq!(stats.completed_on("2025-01-15"), 6);
+    assert_eq!(stats.completed_this_week(), 21);
+    assert_eq!(stats.goals.unwrap().current_daily_streak.unwrap().count, 4);
+}
+
+/// Test: API errors are surfaced
+#[tokio::test]
+async fn test_get_productivity_stats_unauthorized() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/tasks/completed/stats"))
+        .respond_with(ResponseTemplate::new(401).set_body_string("Unauthorized"))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = TodoistClient::with_base_url("bad-token", mock_server.uri()).unwrap();
+    let result = client.get_productivity_stats().await;
+
+    assert!(result.is_err());
+}
@@ -88,6 +88,31 @@ async fn test_sync_full_sync() {
     assert_eq!(response.labels[0].name, "urgent");
 }
 
+/// Test: sync_with_body returns the body untouched alongside the parsed response
+#[tokio::test]
+async fn test_sync_with_body_returns_raw_body() {
+    let mock_server = MockServer::start().await;
+
+    let body = r#"{"sync_token":"raw-token","full_sync":true,"future_field":{"a":1},"items":[{"id":"item-bad","priority":"high"}]}"#;
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(body, "application/json"))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = TodoistClient::with_base_url("test-token", mock_server.uri()).unwrap();
+    let (response, raw) = client
+        .sync_with_body(SyncRequest::full_sync())
+        .await
+        .unwrap();
+
+    assert_eq!(raw, body);
+    assert_eq!(response.sync_token, "raw-token");
+    assert!(response.items.is_empty());
+    assert_eq!(response.skipped_resources.len(), 1);
+}
+
 /// Test: Incremental sync uses the provided sync_token
 #[tokio::test]
 async fn test_sync_incremental() {
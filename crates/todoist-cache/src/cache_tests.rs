@@ -50,6 +50,19 @@ fn test_cache_needs_full_sync() {
     assert!(!cache.needs_full_sync());
 }
 
+#[test]
+fn test_cache_last_sync_age() {
+    let now = "2025-01-15T10:00:00Z".parse::<DateTime<Utc>>().unwrap();
+    let mut cache = Cache::new();
+    assert_eq!(cache.last_sync_age(now), None);
+
+    cache.mark_fresh(now - chrono::Duration::minutes(7));
+    assert_eq!(cache.last_sync_age(now), Some(chrono::Duration::minutes(7)));
+
+    cache.mark_fresh(now + chrono::Duration::minutes(1));
+    assert_eq!(cache.last_sync_age(now), Some(chrono::Duration::zero()));
+}
+
 #[test]
 fn test_cache_serde_roundtrip_empty() {
     let cache = Cache::new();
@@ -191,8 +204,10 @@ fn test_cache_serde_roundtrip_with_data() {
             start_day: None,
             date_format: None,
             time_format: None,
+            lang: None,
             is_premium: false,
         }),
+        completed_info: vec![],
         indexes: CacheIndexes::default(),
     };
 
@@ -271,6 +286,7 @@ fn test_cache_clone() {
         collaborators: vec![],
         collaborator_states: vec![],
         user: None,
+        completed_info: vec![],
         indexes: CacheIndexes::default(),
     };
 
@@ -439,6 +455,7 @@ mod test_helpers {
             start_day: None,
             date_format: None,
             time_format: None,
+            lang: None,
             is_premium: false,
         }
     }
@@ -473,6 +490,7 @@ mod test_helpers {
             stats: None,
             completed_info: vec![],
             locations: vec![],
+            skipped_resources: vec![],
         }
     }
 }
@@ -712,6 +730,29 @@ fn test_apply_incremental_sync_adds_new_items() {
     assert!(cache.items.iter().any(|i| i.id == "item-2"));
 }
 
+#[test]
+fn test_apply_incremental_sync_keeps_indexes_when_nothing_changed() {
+    use test_helpers::*;
+
+    let mut cache = Cache::new();
+    cache.sync_token = "old_token".to_string();
+    cache.items = vec![make_item("item-1", "Existing", false)];
+    cache.rebuild_indexes();
+    // Pushed without a rebuild, so only a rebuild makes it findable
+    cache.items.push(make_item("item-2", "Unindexed", false));
+
+    let mut response = make_sync_response(false, "new_token");
+    response.items = vec![make_item("item-1", "Existing", false)];
+    cache.apply_sync_response(&response);
+    assert_eq!(cache.sync_token, "new_token");
+    assert!(cache.find_item("item-2").is_none());
+
+    let mut response = make_sync_response(false, "newer_token");
+    response.items = vec![make_item("item-1", "Edited", false)];
+    cache.apply_mutation_response(&response);
+    assert!(cache.find_item("item-2").is_some());
+}
+
 #[test]
 fn test_incremental_sync_adds_new_collaborator() {
     use test_helpers::*;
@@ -775,6 +816,92 @@ fn test_incremental_sync_removes_deleted_collaborator_state() {
     assert_eq!(cache.collaborator_states[0].user_id, "user-1");
 }
 
+#[test]
+fn test_prune_archived_removes_archived_projects_and_sections() {
+    use test_helpers::*;
+
+    let mut cache = Cache::new();
+    cache.sync_token = "token-1".to_string();
+    let mut archived_project = make_project("proj-old", "Old", false);
+    archived_project.is_archived = true;
+    let mut archived_section = make_section("sec-old", "Done", false);
+    archived_section.is_archived = true;
+    cache.projects = vec![make_project("proj-1", "Work", false), archived_project];
+    cache.sections = vec![archived_section, make_section("sec-1", "Doing", false)];
+    cache.items = vec![make_item("item-1", "Task", false)];
+    cache.rebuild_indexes();
+
+    assert_eq!(cache.prune_archived(), 2);
+
+    assert_eq!(cache.sync_token, "token-1");
+    assert_eq!(cache.projects.len(), 1);
+    assert_eq!(cache.sections.len(), 1);
+    assert_eq!(cache.items.len(), 1);
+    assert!(cache.find_project("proj-1").is_some());
+    assert!(cache.find_project("proj-old").is_none());
+    assert!(cache.find_section("sec-1", None).is_some());
+    assert!(cache.find_section("sec-old", None).is_none());
+}
+
+#[test]
+fn test_prune_archived_without_archived_resources_is_noop() {
+    use test_helpers::*;
+
+    let mut cache = Cache::new();
+    cache.projects = vec![make_project("proj-1", "Work", false)];
+    cache.sections = vec![make_section("sec-1", "Doing", false)];
+    cache.rebuild_indexes();
+
+    assert_eq!(cache.prune_archived(), 0);
+    assert_eq!(cache.projects.len(), 1);
+    assert_eq!(cache.sections.len(), 1);
+}
+
+fn completed_info(project_id: &str, completed_items: u32) -> CompletedInfo {
+    CompletedInfo {
+        project_id: Some(project_id.to_string()),
+        section_id: None,
+        item_id: None,
+        completed_items,
+        archived_sections: None,
+    }
+}
+
+#[test]
+fn test_full_sync_stores_completed_info() {
+    use test_helpers::*;
+
+    let mut cache = Cache::new();
+    cache.completed_info = vec![completed_info("stale", 9)];
+
+    let mut response = make_sync_response(true, "token");
+    response.completed_info = vec![completed_info("proj-1", 12)];
+
+    cache.apply_sync_response(&response);
+
+    assert_eq!(cache.completed_info.len(), 1);
+    assert_eq!(cache.project_completed_count("proj-1"), Some(12));
+    assert_eq!(cache.project_completed_count("stale"), None);
+}
+
+#[test]
+fn test_incremental_sync_updates_completed_info() {
+    use test_helpers::*;
+
+    let mut cache = Cache::new();
+    cache.completed_info = vec![completed_info("proj-1", 12), completed_info("proj-2", 4)];
+
+    let mut response = make_sync_response(false, "token");
+    response.completed_info = vec![completed_info("proj-1", 13), completed_info("proj-3", 1)];
+
+    cache.apply_sync_response(&response);
+
+    assert_eq!(cache.completed_info.len(), 3);
+    assert_eq!(cache.project_completed_count("proj-1"), Some(13));
+    assert_eq!(cache.project_completed_count("proj-2"), Some(4));
+    assert_eq!(cache.project_completed_count("proj-3"), Some(1));
+}
+
 #[test]
 fn test_apply_incremental_sync_updates_existing_items() {
     use test_helpers::*;
@@ -1267,6 +1394,39 @@ fn test_apply_mutation_response_does_not_update_full_sync_date() {
     assert_eq!(cache.full_sync_date_utc, Some(original_date));
 }
 
+#[test]
+fn test_merge_resources_skips_identical_items() {
+    use crate::merge::merge_resources;
+    use test_helpers::*;
+
+    let mut items = vec![
+        make_item("item-1", "Buy milk", false),
+        make_item("item-2", "Walk dog", false),
+    ];
+
+    let identical = vec![make_item("item-1", "Buy milk", false)];
+    let changed = merge_resources(&mut items, &identical, |i| &i.id, |i| i.is_deleted);
+    assert_eq!(changed, 0);
+
+    let mut differing = make_item("item-1", "Buy oat milk", false);
+    differing.priority = 4;
+    let incoming = vec![
+        differing,
+        make_item("item-2", "Walk dog", false),
+        make_item("item-3", "New task", false),
+    ];
+    let changed = merge_resources(&mut items, &incoming, |i| &i.id, |i| i.is_deleted);
+    assert_eq!(changed, 2);
+    assert_eq!(items.len(), 3);
+    assert_eq!(items[0].content, "Buy oat milk");
+    assert_eq!(items[1].content, "Walk dog");
+
+    let deleted = vec![make_item("item-2", "Walk dog", true)];
+    let changed = merge_resources(&mut items, &deleted, |i| &i.id, |i| i.is_deleted);
+    assert_eq!(changed, 1);
+    assert!(!items.iter().any(|i| i.id == "item-2"));
+}
+
 #[test]
 fn test_apply_mutation_response_adds_new_item() {
     use test_helpers::*;
@@ -1523,3 +1683,587 @@ fn test_apply_mutation_response_mixed_operations() {
     assert!(cache.projects.iter().any(|p| p.id == "proj-1"));
     assert!(cache.projects.iter().any(|p| p.id == "proj-2"));
 }
+
+// ==================== Breadcrumb Tests ====================
+
+#[test]
+fn test_breadcrumb_nested_project_with_section() {
+    use test_helpers::*;
+
+    let work = make_project("proj-work", "Work", false);
+    let mut backend = make_project("proj-backend", "Backend", false);
+    backend.parent_id = Some("proj-work".to_string());
+    let mut section = make_section("sec-1", "In Progress", false);
+    section.project_id = "proj-backend".to_string();
+    let mut item = make_item("item-1", "Fix bug", false);
+    item.project_id = "proj-backend".to_string();
+    item.section_id = Some("sec-1".to_string());
+
+    let mut cache = Cache::new();
+    cache.projects = vec![backend, work];
+    cache.sections = vec![section];
+    cache.items = vec![item.clone()];
+    cache.rebuild_indexes();
+
+    assert_eq!(cache.breadcrumb(&item), "Work / Backend / In Progress");
+}
+
+#[test]
+fn test_breadcrumb_inbox_without_section() {
+    use test_helpers::*;
+
+    let mut inbox = make_project("proj-inbox", "Inbox", false);
+    inbox.inbox_project = true;
+    let mut item = make_item("item-1", "Buy milk", false);
+    item.project_id = "proj-inbox".to_string();
+
+    let mut cache = Cache::new();
+    cache.projects = vec![inbox];
+    cache.items = vec![item.clone()];
+    cache.rebuild_indexes();
+
+    assert_eq!(cache.breadcrumb(&item), "Inbox");
+}
+
+#[test]
+fn test_breadcrumb_missing_project_and_section() {
+    use test_helpers::*;
+
+    let mut item = make_item("item-1", "Orphan", false);
+    item.project_id = "proj-missing".to_string();
+    item.section_id = Some("sec-missing".to_string());
+
+    let cache = Cache::new();
+    assert_eq!(cache.breadcrumb(&item), "");
+
+    let mut with_section = Cache::new();
+    let mut section = make_section("sec-1", "Later", false);
+    section.project_id = "proj-missing".to_string();
+    with_section.sections = vec![section];
+    with_section.rebuild_indexes();
+    item.section_id = Some("sec-1".to_string());
+    assert_eq!(with_section.breadcrumb(&item), "Later");
+}
+
+#[test]
+fn test_breadcrumb_parent_cycle_terminates() {
+    use test_helpers::*;
+
+    let mut a = make_project("proj-a", "A", false);
+    a.parent_id = Some("proj-b".to_string());
+    let mut b = make_project("proj-b", "B", false);
+    b.parent_id = Some("proj-a".to_string());
+    let mut item = make_item("item-1", "Loop", false);
+    item.project_id = "proj-a".to_string();
+
+    let mut cache = Cache::new();
+    cache.projects = vec![a, b];
+    cache.rebuild_indexes();
+
+    // Terminates rather than looping forever
+    assert!(cache.breadcrumb(&item).ends_with("A"));
+}
+
+#[test]
+fn test_children_of_returns_direct_subtasks_in_order() {
+    use test_helpers::*;
+
+    let child = |id: &str, parent: &str, order: i32| {
+        let mut item = make_item(id, id, false);
+        item.parent_id = Some(parent.to_string());
+        item.child_order = order;
+        item
+    };
+    let mut done = child("done", "parent", 0);
+    done.checked = true;
+    let mut deleted = child("deleted", "parent", 1);
+    deleted.is_deleted = true;
+
+    let mut cache = Cache::new();
+    cache.items = vec![
+        make_item("parent", "Parent", false),
+        child("second", "parent", 3),
+        child("grandchild", "first", 0),
+        child("first", "parent", 2),
+        done,
+        deleted,
+    ];
+
+    let ids: Vec<&str> = cache
+        .children_of("parent")
+        .iter()
+        .map(|i| i.id.as_str())
+        .collect();
+    assert_eq!(ids, vec!["done", "first", "second"]);
+    assert!(cache.children_of("second").is_empty());
+}
+
+fn make_nested_project_cache() -> Cache {
+    use test_helpers::*;
+
+    // work > team > sprint, plus an unrelated home project
+    let project = |id: &str, parent: Option<&str>| Project {
+        parent_id: parent.map(String::from),
+        ..make_project(id, id, false)
+    };
+    let item = |id: &str, project_id: &str| {
+        let mut item = make_item(id, id, false);
+        item.project_id = project_id.to_string();
+        item
+    };
+    let mut done = item("done-in-team", "team");
+    done.checked = true;
+    let mut deleted = item("deleted-in-work", "work");
+    deleted.is_deleted = true;
+
+    let mut cache = Cache::new();
+    cache.projects = vec![
+        project("work", None),
+        project("team", Some("work")),
+        project("sprint", Some("team")),
+        project("home", None),
+    ];
+    cache.items = vec![
+        item("in-work", "work"),
+        item("in-team", "team"),
+        item("in-sprint", "sprint"),
+        item("in-home", "home"),
+        done,
+        deleted,
+    ];
+    cache
+}
+
+fn ids(items: Vec<&Item>) -> Vec<&str> {
+    items.iter().map(|i| i.id.as_str()).collect()
+}
+
+#[test]
+fn test_items_in_project_direct_only() {
+    let cache = make_nested_project_cache();
+    assert_eq!(ids(cache.items_in_project("work", false)), vec!["in-work"]);
+    assert_eq!(
+        ids(cache.items_in_project("team", false)),
+        vec!["in-team", "done-in-team"]
+    );
+    assert!(cache.items_in_project("missing", false).is_empty());
+}
+
+#[test]
+fn test_items_in_project_includes_nested_subprojects() {
+    let cache = make_nested_project_cache();
+    assert_eq!(
+        ids(cache.items_in_project("work", true)),
+        vec!["in-work", "in-team", "in-sprint", "done-in-team"]
+    );
+    assert_eq!(
+        ids(cache.items_in_project("team", true)),
+        vec!["in-team", "in-sprint", "done-in-team"]
+    );
+    assert_eq!(ids(cache.items_in_project("home", true)), vec!["in-home"]);
+}
+
+#[test]
+fn test_sections_in_project_excludes_other_projects_and_deleted() {
+    use test_helpers::*;
+
+    let in_project = |id: &str, project_id: &str| Section {
+        project_id: project_id.to_string(),
+        ..make_section(id, id, false)
+    };
+    let mut archived = in_project("archived", "proj-1");
+    archived.is_archived = true;
+
+    let mut cache = Cache::new();
+    cache.sections = vec![
+        in_project("todo", "proj-1"),
+        in_project("elsewhere", "proj-2"),
+        make_section("deleted", "deleted", true),
+        archived,
+        in_project("done", "proj-1"),
+    ];
+
+    let section_ids = |sections: Vec<&Section>| -> Vec<String> {
+        sections.iter().map(|s| s.id.clone()).collect()
+    };
+    assert_eq!(
+        section_ids(cache.sections_in_project("proj-1", false)),
+        vec!["todo", "done"]
+    );
+    assert_eq!(
+        section_ids(cache.sections_in_project("proj-1", true)),
+        vec!["todo", "archived", "done"]
+    );
+    assert_eq!(
+        section_ids(cache.sections_in_project("proj-2", true)),
+        vec!["elsewhere"]
+    );
+    assert!(cache.sections_in_project("missing", true).is_empty());
+}
+
+#[test]
+fn test_find_duplicates_groups_same_project_content() {
+    use test_helpers::*;
+
+    let mut exact = make_item("item-2", "Buy milk", false);
+    exact.project_id = "proj-1".to_string();
+    let mut case_differs = make_item("item-3", "  buy MILK ", false);
+    case_differs.project_id = "proj-1".to_string();
+    let mut original = make_item("item-1", "Buy milk", false);
+    original.project_id = "proj-1".to_string();
+    let mut unique = make_item("item-4", "Walk dog", false);
+    unique.project_id = "proj-1".to_string();
+
+    let mut cache = Cache::new();
+    cache.items = vec![original, unique, exact, case_differs];
+
+    let groups = cache.find_duplicates();
+    assert_eq!(groups.len(), 1);
+    let ids: Vec<&str> = groups[0].iter().map(|i| i.id.as_str()).collect();
+    assert_eq!(ids, vec!["item-1", "item-2", "item-3"]);
+}
+
+#[test]
+fn test_find_duplicates_ignores_other_projects_and_inactive() {
+    use test_helpers::*;
+
+    let mut work = make_item("item-1", "Review PR", false);
+    work.project_id = "proj-work".to_string();
+    let mut home = make_item("item-2", "Review PR", false);
+    home.project_id = "proj-home".to_string();
+    let mut done = make_item("item-3", "Review PR", false);
+    done.project_id = "proj-work".to_string();
+    done.checked = true;
+    let mut deleted = make_item("item-4", "review pr", true);
+    deleted.project_id = "proj-work".to_string();
+
+    let mut cache = Cache::new();
+    cache.items = vec![work, home, done, deleted];
+
+    assert!(cache.find_duplicates().is_empty());
+}
+
+#[test]
+fn test_find_duplicates_respects_parents() {
+    use test_helpers::*;
+
+    let mut parent_a = make_item("parent-a", "Trip", false);
+    parent_a.project_id = "proj-1".to_string();
+    let mut parent_b = make_item("parent-b", "Trip", false);
+    parent_b.project_id = "proj-1".to_string();
+    let mut child_a = make_item("child-a", "Pack bags", false);
+    child_a.project_id = "proj-1".to_string();
+    child_a.parent_id = Some("parent-a".to_string());
+    let mut child_b = make_item("child-b", "Pack bags", false);
+    child_b.project_id = "proj-1".to_string();
+    child_b.parent_id = Some("parent-b".to_string());
+    let mut child_a_copy = make_item("child-a2", "pack bags", false);
+    child_a_copy.project_id = "proj-1".to_string();
+    child_a_copy.parent_id = Some("parent-a".to_string());
+
+    let mut cache = Cache::new();
+    cache.items = vec![parent_a, parent_b, child_a, child_b, child_a_copy];
+
+    // Parents with subtasks are skipped; children only match siblings.
+    let groups = cache.find_duplicates();
+    assert_eq!(groups.len(), 1);
+    let ids: Vec<&str> = groups[0].iter().map(|i| i.id.as_str()).collect();
+    assert_eq!(ids, vec!["child-a", "child-a2"]);
+}
+
+#[test]
+fn test_apply_items_adds_updates_and_deletes() {
+    use test_helpers::*;
+
+    let mut cache = Cache::new();
+    cache.sync_token = "token-1".to_string();
+    cache.items = vec![
+        make_item("item-1", "Old", false),
+        make_item("item-2", "Doomed", false),
+    ];
+    cache.rebuild_indexes();
+
+    let changed = cache.apply_items(&[
+        make_item("item-1", "New", false),
+        make_item("item-2", "Doomed", true),
+        make_item("item-3", "Added", false),
+    ]);
+
+    assert_eq!(changed, 3);
+    let contents: Vec<&str> = cache.items.iter().map(|i| i.content.as_str()).collect();
+    assert_eq!(contents, vec!["New", "Added"]);
+    assert_eq!(cache.find_item("item-3").unwrap().content, "Added");
+    assert!(cache.find_item("item-2").is_none());
+    assert_eq!(cache.sync_token, "token-1");
+}
+
+#[test]
+fn test_apply_items_without_changes_returns_zero() {
+    use test_helpers::*;
+
+    let mut cache = Cache::new();
+    cache.items = vec![make_item("item-1", "Same", false)];
+
+    let changed = cache.apply_items(&[
+        make_item("item-1", "Same", false),
+        make_item("item-9", "Never cached", true),
+    ]);
+
+    assert_eq!(changed, 0);
+    assert_eq!(cache.items.len(), 1);
+}
+
+#[test]
+fn test_apply_projects_adds_updates_and_deletes() {
+    use test_helpers::*;
+
+    let mut cache = Cache::new();
+    cache.projects = vec![
+        make_project("proj-1", "Old", false),
+        make_project("proj-2", "Doomed", false),
+    ];
+    cache.rebuild_indexes();
+
+    let changed = cache.apply_projects(&[
+        make_project("proj-1", "Renamed", false),
+        make_project("proj-2", "Doomed", true),
+        make_project("proj-3", "Added", false),
+    ]);
+
+    assert_eq!(changed, 3);
+    let names: Vec<&str> = cache.projects.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(names, vec!["Renamed", "Added"]);
+    assert_eq!(cache.find_project("renamed").unwrap().id, "proj-1");
+    assert!(cache.find_project("Doomed").is_none());
+}
+
+#[test]
+fn test_apply_labels_adds_updates_and_deletes() {
+    use test_helpers::*;
+
+    let mut cache = Cache::new();
+    cache.labels = vec![
+        make_label("label-1", "old", false),
+        make_label("label-2", "doomed", false),
+    ];
+    cache.rebuild_indexes();
+
+    let changed = cache.apply_labels(&[
+        make_label("label-1", "renamed", false),
+        make_label("label-2", "doomed", true),
+        make_label("label-3", "added", false),
+    ]);
+
+    assert_eq!(changed, 3);
+    let names: Vec<&str> = cache.labels.iter().map(|l| l.name.as_str()).collect();
+    assert_eq!(names, vec!["renamed", "added"]);
+    assert_eq!(cache.find_label("added").unwrap().id, "label-3");
+    assert!(cache.find_label("doomed").is_none());
+}
+
+#[test]
+fn test_apply_sections_adds_updates_and_deletes() {
+    use test_helpers::*;
+
+    let mut cache = Cache::new();
+    cache.sections = vec![
+        make_section("sec-1", "Old", false),
+        make_section("sec-2", "Doomed", false),
+    ];
+    cache.rebuild_indexes();
+
+    let changed = cache.apply_sections(&[
+        make_section("sec-1", "Renamed", false),
+        make_section("sec-2", "Doomed", true),
+        make_section("sec-3", "Added", false),
+    ]);
+
+    assert_eq!(changed, 3);
+    let names: Vec<&str> = cache.sections.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(names, vec!["Renamed", "Added"]);
+    assert_eq!(cache.find_section("sec-3", None).unwrap().name, "Added");
+    assert!(cache.find_section("sec-2", None).is_none());
+}
+
+#[test]
+fn test_apply_notes_adds_updates_and_deletes() {
+    use test_helpers::*;
+
+    let mut cache = Cache::new();
+    cache.notes = vec![
+        make_note("note-1", "Old", false),
+        make_note("note-2", "Doomed", false),
+    ];
+
+    let changed = cache.apply_notes(&[
+        make_note("note-1", "Edited", false),
+        make_note("note-2", "Doomed", true),
+        make_note("note-3", "Added", false),
+    ]);
+
+    assert_eq!(changed, 3);
+    let contents: Vec<&str> = cache.notes.iter().map(|n| n.content.as_str()).collect();
+    assert_eq!(contents, vec!["Edited", "Added"]);
+}
+
+#[test]
+fn test_apply_project_notes_adds_updates_and_deletes() {
+    let note = |id: &str, content: &str, is_deleted: bool| ProjectNote {
+        id: id.to_string(),
+        project_id: "proj-1".to_string(),
+        content: content.to_string(),
+        posted_at: None,
+        is_deleted,
+        posted_uid: None,
+        file_attachment: None,
+    };
+
+    let mut cache = Cache::new();
+    cache.project_notes = vec![note("pn-1", "Old", false), note("pn-2", "Doomed", false)];
+
+    let changed = cache.apply_project_notes(&[
+        note("pn-1", "Edited", false),
+        note("pn-2", "Doomed", true),
+        note("pn-3", "Added", false),
+    ]);
+
+    assert_eq!(changed, 3);
+    let contents: Vec<&str> = cache
+        .project_notes
+        .iter()
+        .map(|n| n.content.as_str())
+        .collect();
+    assert_eq!(contents, vec!["Edited", "Added"]);
+}
+
+#[test]
+fn test_apply_reminders_adds_updates_and_deletes() {
+    use test_helpers::*;
+
+    let mut cache = Cache::new();
+    cache.reminders = vec![make_reminder("rem-1", false), make_reminder("rem-2", false)];
+
+    let mut updated = make_reminder("rem-1", false);
+    updated.minute_offset = Some(60);
+    let changed = cache.apply_reminders(&[
+        updated,
+        make_reminder("rem-2", true),
+        make_reminder("rem-3", false),
+    ]);
+
+    assert_eq!(changed, 3);
+    let ids: Vec<&str> = cache.reminders.iter().map(|r| r.id.as_str()).collect();
+    assert_eq!(ids, vec!["rem-1", "rem-3"]);
+    assert_eq!(cache.reminders[0].minute_offset, Some(60));
+}
+
+#[test]
+fn test_apply_filters_adds_updates_and_deletes() {
+    use test_helpers::*;
+
+    let mut cache = Cache::new();
+    cache.filters = vec![
+        make_filter("filter-1", "Old", false),
+        make_filter("filter-2", "Doomed", false),
+    ];
+
+    let changed = cache.apply_filters(&[
+        make_filter("filter-1", "Renamed", false),
+        make_filter("filter-2", "Doomed", true),
+        make_filter("filter-3", "Added", false),
+    ]);
+
+    assert_eq!(changed, 3);
+    let names: Vec<&str> = cache.filters.iter().map(|f| f.name.as_str()).collect();
+    assert_eq!(names, vec!["Renamed", "Added"]);
+}
+
+#[test]
+fn test_apply_collaborators_adds_and_updates() {
+    use test_helpers::*;
+
+    let mut cache = Cache::new();
+    cache.collaborators = vec![make_collaborator("user-1", "Old Name", "a@example.com")];
+
+    let changed = cache.apply_collaborators(&[
+        make_collaborator("user-1", "New Name", "a@example.com"),
+        make_collaborator("user-2", "Bob", "b@example.com"),
+    ]);
+
+    assert_eq!(changed, 2);
+    let names: Vec<&str> = cache
+        .collaborators
+        .iter()
+        .filter_map(|c| c.full_name.as_deref())
+        .collect();
+    assert_eq!(names, vec!["New Name", "Bob"]);
+}
+
+#[test]
+fn test_inbox_project_prefers_flagged_project() {
+    use test_helpers::*;
+
+    let mut flagged = make_project("proj-flagged", "Home", false);
+    flagged.inbox_project = true;
+    let mut user = make_user("user-1");
+    user.inbox_project_id = Some("proj-user".to_string());
+
+    let mut cache = Cache::new();
+    cache.projects = vec![
+        make_project("proj-named", "Inbox", false),
+        make_project("proj-user", "Mine", false),
+        flagged,
+    ];
+    cache.user = Some(user);
+
+    assert_eq!(cache.inbox_project().unwrap().id, "proj-flagged");
+}
+
+#[test]
+fn test_inbox_project_falls_back_to_user_inbox_id() {
+    use test_helpers::*;
+
+    let mut user = make_user("user-1");
+    user.inbox_project_id = Some("proj-user".to_string());
+
+    let mut cache = Cache::new();
+    cache.projects = vec![
+        make_project("proj-named", "Inbox", false),
+        make_project("proj-user", "Mine", false),
+    ];
+    cache.user = Some(user);
+
+    assert_eq!(cache.inbox_project().unwrap().id, "proj-user");
+}
+
+#[test]
+fn test_inbox_project_falls_back_to_name() {
+    use test_helpers::*;
+
+    let mut user = make_user("user-1");
+    user.inbox_project_id = Some("proj-gone".to_string());
+
+    let mut cache = Cache::new();
+    cache.projects = vec![
+        make_project("proj-gone", "Old inbox", true),
+        make_project("proj-other", "inbox ideas", false),
+        make_project("proj-named", "Inbox", false),
+    ];
+    cache.user = Some(user);
+
+    assert_eq!(cache.inbox_project().unwrap().id, "proj-named");
+}
+
+#[test]
+fn test_inbox_project_none_when_nothing_matches() {
+    use test_helpers::*;
+
+    let mut flagged = make_project("proj-deleted", "Inbox", true);
+    flagged.inbox_project = true;
+
+    let mut cache = Cache::new();
+    cache.projects = vec![flagged, make_project("proj-work", "Work", false)];
+    cache.user = Some(make_user("user-1"));
+
+    assert!(cache.inbox_project().is_none());
+}
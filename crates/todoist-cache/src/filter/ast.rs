@@ -1,5 +1,7 @@
 //! Abstract Syntax Tree (AST) for filter expressions.
 
+use std::fmt;
+
 /// Target for assignment filters.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum AssignedTarget {
@@ -30,6 +32,12 @@ pub enum Filter {
     /// Matches items without any due date set.
     NoDate,
 
+    /// Matches all-day items: a due date but no due time.
+    NoTime,
+
+    /// Matches items whose due date includes a time.
+    HasTime,
+
     /// Matches items due within the next 7 days (including today).
     Next7Days,
 
@@ -73,6 +81,13 @@ pub enum Filter {
     /// Matches items in the specified section.
     Section(String),
 
+    // ==================== Saved Filters ====================
+    /// Matches items matching the query of the named saved filter.
+    ///
+    /// Expanded with [`FilterContext::expand_saved_filters`](super::FilterContext::expand_saved_filters)
+    /// before evaluation.
+    SavedFilter(String),
+
     // ==================== Assignment Filters ====================
     /// Matches items assigned to someone.
     AssignedTo(AssignedTarget),
@@ -86,6 +101,10 @@ pub enum Filter {
     /// Matches items that have no assignee.
     NoAssignee,
 
+    // ==================== Status Filters ====================
+    /// Matches completed (checked) items.
+    Completed,
+
     // ==================== Boolean Operators ====================
     /// Logical AND of two filters.
     And(Box<Filter>, Box<Filter>),
@@ -139,4 +158,308 @@ impl Filter {
     pub fn negate(inner: Filter) -> Self {
         Filter::Not(Box::new(inner))
     }
+
+    /// Returns the filter for a user-facing priority level (1 = highest),
+    /// or `None` if `level` is outside 1-4.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_cache_rs::filter::Filter;
+    ///
+    /// assert_eq!(Filter::priority(1), Some(Filter::Priority1));
+    /// assert_eq!(Filter::priority(5), None);
+    /// ```
+    pub fn priority(level: u8) -> Option<Self> {
+        match level {
+            1 => Some(Filter::Priority1),
+            2 => Some(Filter::Priority2),
+            3 => Some(Filter::Priority3),
+            4 => Some(Filter::Priority4),
+            _ => None,
+        }
+    }
+
+    /// Combines filters with AND, left to right. Returns `None` if empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_cache_rs::filter::Filter;
+    ///
+    /// let filter = Filter::all([Filter::Today, Filter::Priority1]).unwrap();
+    /// assert_eq!(filter, Filter::and(Filter::Today, Filter::Priority1));
+    /// ```
+    pub fn all(filters: impl IntoIterator<Item = Filter>) -> Option<Self> {
+        filters.into_iter().reduce(Filter::and)
+    }
+
+    /// Combines filters with OR, left to right. Returns `None` if empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_cache_rs::filter::Filter;
+    ///
+    /// let filter = Filter::any([Filter::Today, Filter::Overdue]).unwrap();
+    /// assert_eq!(filter, Filter::or(Filter::Today, Filter::Overdue));
+    /// ```
+    pub fn any(filters: impl IntoIterator<Item = Filter>) -> Option<Self> {
+        filters.into_iter().reduce(Filter::or)
+    }
+
+    /// Renders the filter as a canonical query string.
+    ///
+    /// The output re-parses to an equal `Filter`. Parentheses are only added
+    /// where operator precedence or left-associativity requires them, and
+    /// names are quoted when they contain characters the lexer would split on.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_cache_rs::filter::{Filter, FilterParser};
+    ///
+    /// let filter = FilterParser::parse("(today|overdue)&@work").unwrap();
+    /// assert_eq!(filter.to_query_string(), "(today | overdue) & @work");
+    /// ```
+    pub fn to_query_string(&self) -> String {
+        self.to_string()
+    }
+
+    /// Binding strength of the node: OR < AND < NOT < atoms.
+    fn precedence(&self) -> u8 {
+        match self {
+            Filter::Or(_, _) => 1,
+            Filter::And(_, _) => 2,
+            Filter::Not(_) => 3,
+            _ => 4,
+        }
+    }
+
+    /// Writes the filter, wrapping it in parentheses if it binds more loosely
+    /// than `min_precedence`.
+    fn fmt_with_precedence(&self, f: &mut fmt::Formatter<'_>, min_precedence: u8) -> fmt::Result {
+        if self.precedence() < min_precedence {
+            write!(f, "(")?;
+            self.fmt_with_precedence(f, 0)?;
+            return write!(f, ")");
+        }
+
+        match self {
+            // Binary operators are left-associative, so the right operand
+            // needs parentheses if it is the same operator.
+            Filter::Or(left, right) => {
+                left.fmt_with_precedence(f, 1)?;
+                write!(f, " | ")?;
+                right.fmt_with_precedence(f, 2)
+            }
+            Filter::And(left, right) => {
+                left.fmt_with_precedence(f, 2)?;
+                write!(f, " & ")?;
+                right.fmt_with_precedence(f, 3)
+            }
+            Filter::Not(inner) => {
+                write!(f, "!")?;
+                inner.fmt_with_precedence(f, 3)
+            }
+            Filter::Today => write!(f, "today"),
+            Filter::Tomorrow => write!(f, "tomorrow"),
+            Filter::Overdue => write!(f, "overdue"),
+            Filter::NoDate => write!(f, "no date"),
+            Filter::NoTime => write!(f, "no time"),
+            Filter::HasTime => write!(f, "has time"),
+            Filter::Next7Days => write!(f, "7 days"),
+            Filter::SpecificDate { month, day } => {
+                write!(f, "{} {}", month_abbreviation(*month), day)
+            }
+            Filter::Priority1 => write!(f, "p1"),
+            Filter::Priority2 => write!(f, "p2"),
+            Filter::Priority3 => write!(f, "p3"),
+            Filter::Priority4 => write!(f, "p4"),
+            Filter::Label(name) => write!(f, "@{}", quote_name(name)),
+            Filter::NoLabels => write!(f, "no labels"),
+            Filter::Project(name) => write!(f, "#{}", quote_name(name)),
+            Filter::ProjectWithSubprojects(name) => write!(f, "##{}", quote_name(name)),
+            Filter::Section(name) => write!(f, "/{}", quote_name(name)),
+            Filter::SavedFilter(name) => write!(f, "filter:{}", quote_name(name)),
+            Filter::AssignedTo(target) => write!(f, "assigned to: {}", target),
+            Filter::AssignedBy(target) => write!(f, "assigned by: {}", target),
+            Filter::Assigned => write!(f, "assigned"),
+            Filter::NoAssignee => write!(f, "no assignee"),
+            Filter::Completed => write!(f, "completed"),
+        }
+    }
+}
+
+/// Builds a filter by ANDing predicates together.
+///
+/// Each method adds one predicate to the filter built so far; [`or`] is the
+/// exception and ORs its argument with everything before it. This maps
+/// naturally onto optional command-line flags: add a predicate per flag that
+/// was given, then call [`build`], which returns `None` when no predicate
+/// was added.
+///
+/// [`or`]: FilterBuilder::or
+/// [`build`]: FilterBuilder::build
+///
+/// # Example
+///
+/// ```
+/// use todoist_cache_rs::filter::{FilterBuilder, FilterParser};
+///
+/// let filter = FilterBuilder::new()
+///     .project("Work")
+///     .priority(1)
+///     .and_not(FilterParser::parse("@waiting").unwrap())
+///     .build()
+///     .unwrap();
+/// assert_eq!(filter.to_query_string(), "#Work & p1 & !@waiting");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FilterBuilder {
+    filter: Option<Filter>,
+}
+
+impl FilterBuilder {
+    /// Creates an empty builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// ANDs `filter` with the predicates added so far.
+    pub fn and(mut self, filter: Filter) -> Self {
+        self.filter = Some(match self.filter.take() {
+            Some(existing) => Filter::and(existing, filter),
+            None => filter,
+        });
+        self
+    }
+
+    /// ANDs the negation of `filter` with the predicates added so far.
+    pub fn and_not(self, filter: Filter) -> Self {
+        self.and(Filter::negate(filter))
+    }
+
+    /// ORs `filter` with everything added so far.
+    pub fn or(mut self, filter: Filter) -> Self {
+        self.filter = Some(match self.filter.take() {
+            Some(existing) => Filter::or(existing, filter),
+            None => filter,
+        });
+        self
+    }
+
+    /// Requires the `label` label.
+    pub fn label(self, label: impl Into<String>) -> Self {
+        self.and(Filter::Label(label.into()))
+    }
+
+    /// Requires the item to be in `project` (exact match).
+    pub fn project(self, project: impl Into<String>) -> Self {
+        self.and(Filter::Project(project.into()))
+    }
+
+    /// Requires the item to be in `section`.
+    pub fn section(self, section: impl Into<String>) -> Self {
+        self.and(Filter::Section(section.into()))
+    }
+
+    /// Requires a user-facing priority level (1 = highest).
+    ///
+    /// Levels outside 1-4 add nothing.
+    pub fn priority(self, level: u8) -> Self {
+        match Filter::priority(level) {
+            Some(filter) => self.and(filter),
+            None => self,
+        }
+    }
+
+    /// Requires the item to be overdue.
+    pub fn overdue(self) -> Self {
+        self.and(Filter::Overdue)
+    }
+
+    /// Requires the item to have no due date.
+    pub fn no_date(self) -> Self {
+        self.and(Filter::NoDate)
+    }
+
+    /// Returns the combined filter, or `None` if nothing was added.
+    pub fn build(self) -> Option<Filter> {
+        self.filter
+    }
+}
+
+impl fmt::Display for Filter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_with_precedence(f, 0)
+    }
+}
+
+impl fmt::Display for AssignedTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AssignedTarget::Me => write!(f, "me"),
+            AssignedTarget::Others => write!(f, "others"),
+            AssignedTarget::User(name) => {
+                // Targets run until the next operator and are trimmed
+                let needs_quotes = name.is_empty()
+                    || name.trim() != name
+                    || name.contains(['&', '|', '(', ')', '"', '\'', '\\']);
+                if needs_quotes {
+                    write!(f, "{}", quoted(name))
+                } else {
+                    write!(f, "{}", name)
+                }
+            }
+        }
+    }
+}
+
+/// Returns the short month name used in specific-date filters.
+fn month_abbreviation(month: u32) -> &'static str {
+    match month {
+        1 => "Jan",
+        2 => "Feb",
+        3 => "Mar",
+        4 => "Apr",
+        5 => "May",
+        6 => "Jun",
+        7 => "Jul",
+        8 => "Aug",
+        9 => "Sep",
+        10 => "Oct",
+        11 => "Nov",
+        12 => "Dec",
+        _ => "???",
+    }
+}
+
+/// Quotes a label/project/section name if the lexer would not read it back
+/// verbatim as a bare word.
+fn quote_name(name: &str) -> String {
+    let needs_quotes = name.is_empty()
+        || name.starts_with(['"', '\'', '#'])
+        || name
+            .chars()
+            .any(|c| c.is_whitespace() || matches!(c, '&' | '|' | '(' | ')'));
+    if needs_quotes {
+        quoted(name)
+    } else {
+        name.to_string()
+    }
+}
+
+/// Wraps a string in double quotes, escaping quotes and backslashes.
+fn quoted(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        if c == '"' || c == '\\' {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out.push('"');
+    out
 }
@@ -54,6 +54,34 @@ pub enum FilterError {
         position: usize,
     },
 
+    /// The filter names a project, section, label, or saved filter that doesn't exist.
+    ///
+    /// Only reported by [`FilterEvaluator::check_references`](super::FilterEvaluator::check_references);
+    /// normal evaluation treats unknown names as matching nothing.
+    #[error("unknown {kind} '{name}' in filter")]
+    UnknownReference {
+        /// What was referenced: "project", "section", "label", or "saved filter".
+        kind: &'static str,
+        /// The name as written in the filter.
+        name: String,
+    },
+
+    /// A saved filter refers back to itself, directly or through others.
+    #[error("saved filter '{name}' refers back to itself")]
+    CyclicFilterReference {
+        /// The saved filter where the cycle was detected.
+        name: String,
+    },
+
+    /// A referenced saved filter's own query doesn't parse.
+    #[error("saved filter '{name}' has an invalid query: {error}")]
+    InvalidSavedFilter {
+        /// The saved filter's name.
+        name: String,
+        /// The error from parsing its query.
+        error: Box<FilterError>,
+    },
+
     /// Unknown characters were encountered during lexing.
     #[error("unknown character(s) in filter: {}", format_lexer_errors(.errors))]
     UnknownCharacters {
@@ -105,6 +133,19 @@ impl FilterError {
         FilterError::UnclosedParenthesis { position }
     }
 
+    /// Creates an unknown reference error.
+    pub fn unknown_reference(kind: &'static str, name: impl Into<String>) -> Self {
+        FilterError::UnknownReference {
+            kind,
+            name: name.into(),
+        }
+    }
+
+    /// Creates a cyclic saved filter reference error.
+    pub fn cyclic_filter_reference(name: impl Into<String>) -> Self {
+        FilterError::CyclicFilterReference { name: name.into() }
+    }
+
     /// Creates an unknown keyword error with position.
     pub fn unknown_keyword(keyword: impl Into<String>, position: usize) -> Self {
         FilterError::UnknownKeyword {
@@ -52,10 +52,16 @@
 //! let matches = evaluator.matches(&item);
 //! ```
 
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet, VecDeque};
+
 use chrono::{Datelike, Local, NaiveDate};
-use todoist_api_rs::sync::{Collaborator, Item, Label, Project, Section};
+use todoist_api_rs::sync::{Collaborator, Filter as SavedFilter, Item, Label, Project, Section};
 
 use super::ast::{AssignedTarget, Filter};
+use super::error::{FilterError, FilterResult};
+use super::parser::FilterParser;
+use crate::Cache;
 
 /// Context for filter evaluation.
 ///
@@ -68,6 +74,12 @@ pub struct FilterContext<'a> {
     labels: &'a [Label],
     collaborators: &'a [Collaborator],
     current_user_id: Option<&'a str>,
+    saved_filters: &'a [SavedFilter],
+    /// Every non-deleted project's descendant project IDs, computed once.
+    descendants: HashMap<&'a str, Vec<&'a str>>,
+    /// Number of name lookups made, so tests can check they aren't per item.
+    #[cfg(test)]
+    name_lookups: std::cell::Cell<usize>,
 }
 
 impl<'a> FilterContext<'a> {
@@ -85,9 +97,40 @@ impl<'a> FilterContext<'a> {
             labels,
             collaborators: &[],
             current_user_id: None,
+            saved_filters: &[],
+            descendants: project_descendants(projects),
+            #[cfg(test)]
+            name_lookups: std::cell::Cell::new(0),
         }
     }
 
+    /// Counts a name lookup (test builds only).
+    fn record_lookup(&self) {
+        #[cfg(test)]
+        self.name_lookups.set(self.name_lookups.get() + 1);
+    }
+
+    /// Creates a filter context covering everything in `cache`.
+    ///
+    /// Wires up projects, sections, labels, saved filters, collaborators and
+    /// the cached user's ID, so assignment filters such as `assigned to: me`
+    /// and `filter:` references work too. Use [`new`](Self::new) when
+    /// filtering against other data.
+    pub fn from_cache(cache: &'a Cache) -> Self {
+        Self::new(&cache.projects, &cache.sections, &cache.labels)
+            .with_assignment_context(
+                &cache.collaborators,
+                cache.user.as_ref().map(|u| u.id.as_str()),
+            )
+            .with_saved_filters(&cache.filters)
+    }
+
+    /// Sets the saved filters that `filter:name` references resolve against.
+    pub fn with_saved_filters(mut self, saved_filters: &'a [SavedFilter]) -> Self {
+        self.saved_filters = saved_filters;
+        self
+    }
+
     /// Sets collaborators and current user for assignment filter evaluation.
     pub fn with_assignment_context(
         mut self,
@@ -101,6 +144,7 @@ impl<'a> FilterContext<'a> {
 
     /// Finds a collaborator by name (case-insensitive substring match).
     fn find_collaborator_by_name(&self, name: &str) -> Option<&Collaborator> {
+        self.record_lookup();
         let name_lower = name.to_lowercase();
         self.collaborators.iter().find(|c| {
             c.full_name
@@ -116,6 +160,7 @@ impl<'a> FilterContext<'a> {
     ///
     /// Only returns non-deleted projects.
     pub fn find_project_by_name(&self, name: &str) -> Option<&Project> {
+        self.record_lookup();
         let name_lower = name.to_lowercase();
         self.projects
             .iter()
@@ -130,30 +175,112 @@ impl<'a> FilterContext<'a> {
         };
 
         let mut ids = vec![root_project.id.as_str()];
-        self.collect_subproject_ids(&root_project.id, &mut ids);
+        ids.extend_from_slice(self.descendant_project_ids(&root_project.id));
         ids
     }
 
-    /// Recursively collects all subproject IDs for a given parent project.
-    fn collect_subproject_ids<'b>(&'b self, parent_id: &str, ids: &mut Vec<&'b str>) {
-        for project in self.projects.iter() {
-            if project.parent_id.as_deref() == Some(parent_id) && !project.is_deleted {
-                ids.push(&project.id);
-                self.collect_subproject_ids(&project.id, ids);
-            }
-        }
+    /// Returns the IDs of all non-deleted subprojects below `project_id`, at
+    /// any depth, nearest first.
+    pub fn descendant_project_ids(&self, project_id: &str) -> &[&'a str] {
+        self.descendants
+            .get(project_id)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
     }
 
     /// Finds a section by name (case-insensitive).
     ///
     /// Only returns non-deleted sections.
     pub fn find_section_by_name(&self, name: &str) -> Option<&Section> {
+        self.record_lookup();
         let name_lower = name.to_lowercase();
         self.sections
             .iter()
             .find(|s| !s.is_deleted && s.name.to_lowercase() == name_lower)
     }
 
+    /// Finds a saved filter by name (case-insensitive).
+    ///
+    /// Only returns non-deleted filters.
+    pub fn find_saved_filter_by_name(&self, name: &str) -> Option<&SavedFilter> {
+        let name_lower = name.to_lowercase();
+        self.saved_filters
+            .iter()
+            .find(|f| !f.is_deleted && f.name.to_lowercase() == name_lower)
+    }
+
+    /// Replaces every `filter:name` reference with the saved filter's parsed
+    /// query, recursively.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FilterError::UnknownReference`] for a saved filter that
+    /// doesn't exist, [`FilterError::InvalidSavedFilter`] if its query doesn't
+    /// parse, and [`FilterError::CyclicFilterReference`] if expanding it
+    /// would reach the same saved filter again.
+    pub fn expand_saved_filters(&self, filter: &Filter) -> FilterResult<Filter> {
+        self.expand_with_stack(filter, &mut Vec::new(), false)
+    }
+
+    /// Like [`expand_saved_filters`](Self::expand_saved_filters), but each
+    /// reference that can't be expanded is left in place, where it matches
+    /// nothing, while the other references are still expanded.
+    fn expand_saved_filters_lenient(&self, filter: &Filter) -> Filter {
+        self.expand_with_stack(filter, &mut Vec::new(), true)
+            .unwrap_or_else(|_| filter.clone())
+    }
+
+    fn expand_with_stack(
+        &self,
+        filter: &Filter,
+        stack: &mut Vec<String>,
+        lenient: bool,
+    ) -> FilterResult<Filter> {
+        match filter {
+            Filter::SavedFilter(name) => match self.expand_reference(name, stack, lenient) {
+                Err(_) if lenient => Ok(filter.clone()),
+                result => result,
+            },
+            Filter::And(left, right) => Ok(Filter::and(
+                self.expand_with_stack(left, stack, lenient)?,
+                self.expand_with_stack(right, stack, lenient)?,
+            )),
+            Filter::Or(left, right) => Ok(Filter::or(
+                self.expand_with_stack(left, stack, lenient)?,
+                self.expand_with_stack(right, stack, lenient)?,
+            )),
+            Filter::Not(inner) => Ok(Filter::negate(
+                self.expand_with_stack(inner, stack, lenient)?,
+            )),
+            other => Ok(other.clone()),
+        }
+    }
+
+    /// Expands a single `filter:name` reference.
+    fn expand_reference(
+        &self,
+        name: &str,
+        stack: &mut Vec<String>,
+        lenient: bool,
+    ) -> FilterResult<Filter> {
+        let saved = self
+            .find_saved_filter_by_name(name)
+            .ok_or_else(|| FilterError::unknown_reference("saved filter", name))?;
+        let key = saved.name.to_lowercase();
+        if stack.contains(&key) {
+            return Err(FilterError::cyclic_filter_reference(&saved.name));
+        }
+        let parsed =
+            FilterParser::parse(&saved.query).map_err(|e| FilterError::InvalidSavedFilter {
+                name: saved.name.clone(),
+                error: Box::new(e),
+            })?;
+        stack.push(key);
+        let expanded = self.expand_with_stack(&parsed, stack, lenient);
+        stack.pop();
+        expanded
+    }
+
     /// Checks if a label name exists (case-insensitive).
     ///
     /// Only considers non-deleted labels.
@@ -168,11 +295,140 @@ impl<'a> FilterContext<'a> {
 /// Evaluates a parsed filter against items.
 ///
 /// The evaluator takes a reference to a parsed [`Filter`] and a [`FilterContext`],
-/// then can test whether items match the filter criteria.
+/// then can test whether items match the filter criteria. Project, section,
+/// label and collaborator names are resolved once, when the evaluator is
+/// created, so matching each item only compares IDs.
 #[derive(Debug)]
 pub struct FilterEvaluator<'a> {
-    filter: &'a Filter,
+    filter: Cow<'a, Filter>,
+    plan: Plan,
     context: &'a FilterContext<'a>,
+    /// The date relative filters (`today`, `overdue`, ...) compare against.
+    today: NaiveDate,
+}
+
+/// A filter compiled against a [`FilterContext`], with every name resolved.
+#[derive(Debug, Clone, PartialEq)]
+enum Plan {
+    /// A node that needs no name resolution, evaluated as written.
+    Node(Filter),
+    /// The item's project is one of these (empty for an unknown project).
+    InProjects(HashSet<String>),
+    /// The item's section is this one (`None` for an unknown section).
+    InSection(Option<String>),
+    /// The item has this label, already lowercased.
+    HasLabel(String),
+    /// The item's responsible user matches.
+    AssignedTo(Assignee),
+    /// The user who assigned the item matches.
+    AssignedBy(Assignee),
+    And(Box<Plan>, Box<Plan>),
+    Or(Box<Plan>, Box<Plan>),
+    Not(Box<Plan>),
+}
+
+/// A resolved assignment target.
+#[derive(Debug, Clone, PartialEq)]
+enum Assignee {
+    /// This user (`None` when the user is unknown, which matches nothing).
+    Is(Option<String>),
+    /// Anyone but this user (`None` when the current user is unknown, which
+    /// matches nothing).
+    IsNot(Option<String>),
+}
+
+impl Assignee {
+    fn resolve(target: &AssignedTarget, context: &FilterContext<'_>) -> Self {
+        let current_user = context.current_user_id.map(String::from);
+        match target {
+            AssignedTarget::Me => Self::Is(current_user),
+            AssignedTarget::Others => Self::IsNot(current_user),
+            AssignedTarget::User(name) => Self::Is(
+                context
+                    .find_collaborator_by_name(name)
+                    .map(|c| c.id.clone()),
+            ),
+        }
+    }
+
+    fn matches(&self, uid: Option<&str>) -> bool {
+        match self {
+            Self::Is(Some(id)) => uid == Some(id.as_str()),
+            Self::IsNot(Some(id)) => uid.is_some_and(|uid| uid != id),
+            Self::Is(None) | Self::IsNot(None) => false,
+        }
+    }
+}
+
+/// Maps each non-deleted project's ID to its descendants' IDs, nearest first.
+///
+/// A deleted project hides its whole subtree. Parent cycles in malformed data
+/// are cut off, and a project is never its own descendant.
+fn project_descendants(projects: &[Project]) -> HashMap<&str, Vec<&str>> {
+    let mut children: HashMap<&str, Vec<&str>> = HashMap::new();
+    for project in projects.iter().filter(|p| !p.is_deleted) {
+        if let Some(parent_id) = project.parent_id.as_deref() {
+            children.entry(parent_id).or_default().push(&project.id);
+        }
+    }
+
+    projects
+        .iter()
+        .filter(|p| !p.is_deleted)
+        .map(|root| {
+            let root_id = root.id.as_str();
+            let mut seen: HashSet<&str> = HashSet::from([root_id]);
+            let mut ids = Vec::new();
+            let mut queue = VecDeque::from([root_id]);
+            while let Some(id) = queue.pop_front() {
+                for &child in children.get(id).into_iter().flatten() {
+                    if seen.insert(child) {
+                        ids.push(child);
+                        queue.push_back(child);
+                    }
+                }
+            }
+            (root_id, ids)
+        })
+        .collect()
+}
+
+impl Plan {
+    /// Resolves the names in `filter` against `context`.
+    fn compile(filter: &Filter, context: &FilterContext<'_>) -> Self {
+        match filter {
+            Filter::Project(name) => Self::InProjects(
+                context
+                    .find_project_by_name(name)
+                    .map(|p| p.id.clone())
+                    .into_iter()
+                    .collect(),
+            ),
+            Filter::ProjectWithSubprojects(name) => Self::InProjects(
+                context
+                    .get_project_ids_with_subprojects(name)
+                    .into_iter()
+                    .map(String::from)
+                    .collect(),
+            ),
+            Filter::Section(name) => {
+                Self::InSection(context.find_section_by_name(name).map(|s| s.id.clone()))
+            }
+            Filter::Label(name) => Self::HasLabel(name.to_lowercase()),
+            Filter::AssignedTo(target) => Self::AssignedTo(Assignee::resolve(target, context)),
+            Filter::AssignedBy(target) => Self::AssignedBy(Assignee::resolve(target, context)),
+            Filter::And(left, right) => Self::And(
+                Box::new(Self::compile(left, context)),
+                Box::new(Self::compile(right, context)),
+            ),
+            Filter::Or(left, right) => Self::Or(
+                Box::new(Self::compile(left, context)),
+                Box::new(Self::compile(right, context)),
+            ),
+            Filter::Not(inner) => Self::Not(Box::new(Self::compile(inner, context))),
+            other => Self::Node(other.clone()),
+        }
+    }
 }
 
 impl<'a> FilterEvaluator<'a> {
@@ -182,13 +438,72 @@ impl<'a> FilterEvaluator<'a> {
     ///
     /// * `filter` - The parsed filter to evaluate
     /// * `context` - The context containing projects, sections, and labels
+    ///
+    /// `filter:name` references are expanded against the context's saved
+    /// filters up front. A reference that can't be expanded (unknown, cyclic,
+    /// or with an invalid query) matches nothing; the others still work.
     pub fn new(filter: &'a Filter, context: &'a FilterContext<'a>) -> Self {
-        Self { filter, context }
+        let filter: Cow<'a, Filter> = Cow::Owned(context.expand_saved_filters_lenient(filter));
+        let plan = Plan::compile(&filter, context);
+        Self {
+            filter,
+            plan,
+            context,
+            today: Local::now().date_naive(),
+        }
+    }
+
+    /// Evaluates relative date filters as if today were `today`.
+    ///
+    /// [`new`](Self::new) reads the local date once; this overrides it,
+    /// e.g. for tests or to keep several evaluators on the same day.
+    pub fn with_today(mut self, today: NaiveDate) -> Self {
+        self.today = today;
+        self
     }
 
     /// Returns true if the item matches the filter.
     pub fn matches(&self, item: &Item) -> bool {
-        self.evaluate_filter(self.filter, item)
+        self.evaluate_plan(&self.plan, item)
+    }
+
+    /// Checks that every saved filter, project, section, and label named in
+    /// the filter exists in the context.
+    ///
+    /// Evaluation itself is lenient: an unknown name just matches nothing.
+    /// Call this first for a strict mode that reports typos instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error from
+    /// [`FilterContext::expand_saved_filters`] if a saved filter reference
+    /// can't be expanded, otherwise [`FilterError::UnknownReference`] for the
+    /// first unknown name, in left-to-right order.
+    pub fn check_references(&self) -> FilterResult<()> {
+        let expanded = self.context.expand_saved_filters(&self.filter)?;
+        self.check_filter_references(&expanded)
+    }
+
+    fn check_filter_references(&self, filter: &Filter) -> FilterResult<()> {
+        match filter {
+            Filter::Project(name) | Filter::ProjectWithSubprojects(name)
+                if self.context.find_project_by_name(name).is_none() =>
+            {
+                Err(FilterError::unknown_reference("project", name))
+            }
+            Filter::Section(name) if self.context.find_section_by_name(name).is_none() => {
+                Err(FilterError::unknown_reference("section", name))
+            }
+            Filter::Label(name) if !self.context.label_exists(name) => {
+                Err(FilterError::unknown_reference("label", name))
+            }
+            Filter::And(left, right) | Filter::Or(left, right) => {
+                self.check_filter_references(left)?;
+                self.check_filter_references(right)
+            }
+            Filter::Not(inner) => self.check_filter_references(inner),
+            _ => Ok(()),
+        }
     }
 
     /// Filters a slice of items, returning only those that match.
@@ -211,7 +526,26 @@ impl<'a> FilterEvaluator<'a> {
         result
     }
 
-    /// Evaluates a filter expression against an item.
+    /// Evaluates a compiled plan against an item.
+    fn evaluate_plan(&self, plan: &Plan, item: &Item) -> bool {
+        match plan {
+            Plan::Node(filter) => self.evaluate_filter(filter, item),
+            Plan::InProjects(ids) => ids.contains(&item.project_id),
+            Plan::InSection(id) => id.is_some() && item.section_id == *id,
+            Plan::HasLabel(label_lower) => self.has_label(item, label_lower),
+            Plan::AssignedTo(assignee) => assignee.matches(item.responsible_uid.as_deref()),
+            Plan::AssignedBy(assignee) => assignee.matches(item.assigned_by_uid.as_deref()),
+            Plan::And(left, right) => {
+                self.evaluate_plan(left, item) && self.evaluate_plan(right, item)
+            }
+            Plan::Or(left, right) => {
+                self.evaluate_plan(left, item) || self.evaluate_plan(right, item)
+            }
+            Plan::Not(inner) => !self.evaluate_plan(inner, item),
+        }
+    }
+
+    /// Evaluates a filter node that needs no name resolution against an item.
     fn evaluate_filter(&self, filter: &Filter, item: &Item) -> bool {
         match filter {
             // Date filters
@@ -219,6 +553,8 @@ impl<'a> FilterEvaluator<'a> {
             Filter::Tomorrow => self.is_due_tomorrow(item),
             Filter::Overdue => self.is_overdue(item),
             Filter::NoDate => self.has_no_date(item),
+            Filter::NoTime => item.due.as_ref().is_some_and(|due| due.datetime.is_none()),
+            Filter::HasTime => item.due.as_ref().is_some_and(|due| due.datetime.is_some()),
             Filter::Next7Days => self.is_due_within_7_days(item),
             Filter::SpecificDate { month, day } => self.is_due_on_specific_date(item, *month, *day),
 
@@ -231,30 +567,28 @@ impl<'a> FilterEvaluator<'a> {
             Filter::Priority4 => item.priority == 1,
 
             // Label filters
-            Filter::Label(name) => self.has_label(item, name),
             Filter::NoLabels => self.has_no_labels(item),
 
-            // Project filters
-            Filter::Project(name) => self.in_project(item, name),
-            Filter::ProjectWithSubprojects(name) => self.in_project_or_subproject(item, name),
-
-            // Section filter
-            Filter::Section(name) => self.in_section(item, name),
+            // Only left unexpanded when the reference is unknown or cyclic
+            Filter::SavedFilter(_) => false,
 
             // Assignment filters
-            Filter::AssignedTo(target) => self.is_assigned_to(item, target),
-            Filter::AssignedBy(target) => self.is_assigned_by(item, target),
             Filter::Assigned => item.responsible_uid.is_some(),
             Filter::NoAssignee => item.responsible_uid.is_none(),
 
-            // Boolean operators
-            Filter::And(left, right) => {
-                self.evaluate_filter(left, item) && self.evaluate_filter(right, item)
-            }
-            Filter::Or(left, right) => {
-                self.evaluate_filter(left, item) || self.evaluate_filter(right, item)
-            }
-            Filter::Not(inner) => !self.evaluate_filter(inner, item),
+            // Status filters
+            Filter::Completed => item.checked,
+
+            // Compiled into dedicated plan nodes by `Plan::compile`
+            Filter::Label(_)
+            | Filter::Project(_)
+            | Filter::ProjectWithSubprojects(_)
+            | Filter::Section(_)
+            | Filter::AssignedTo(_)
+            | Filter::AssignedBy(_)
+            | Filter::And(..)
+            | Filter::Or(..)
+            | Filter::Not(_) => unreachable!("resolved by Plan::compile"),
         }
     }
 
@@ -264,9 +598,8 @@ impl<'a> FilterEvaluator<'a> {
             return false;
         };
 
-        let today = Local::now().date_naive();
         self.parse_due_date(&due.date)
-            .is_some_and(|due_date| due_date == today)
+            .is_some_and(|due_date| due_date == self.today)
     }
 
     /// Checks if the item is due tomorrow.
@@ -275,7 +608,7 @@ impl<'a> FilterEvaluator<'a> {
             return false;
         };
 
-        let tomorrow = Local::now().date_naive() + chrono::Duration::days(1);
+        let tomorrow = self.today + chrono::Duration::days(1);
         self.parse_due_date(&due.date)
             .is_some_and(|due_date| due_date == tomorrow)
     }
@@ -291,9 +624,8 @@ impl<'a> FilterEvaluator<'a> {
             return false;
         };
 
-        let today = Local::now().date_naive();
         self.parse_due_date(&due.date)
-            .is_some_and(|due_date| due_date < today)
+            .is_some_and(|due_date| due_date < self.today)
     }
 
     /// Checks if the item has no due date.
@@ -307,11 +639,10 @@ impl<'a> FilterEvaluator<'a> {
             return false;
         };
 
-        let today = Local::now().date_naive();
-        let end_date = today + chrono::Duration::days(7);
+        let end_date = self.today + chrono::Duration::days(7);
 
         self.parse_due_date(&due.date)
-            .is_some_and(|due_date| due_date >= today && due_date < end_date)
+            .is_some_and(|due_date| due_date >= self.today && due_date < end_date)
     }
 
     /// Checks if the item is due on a specific month and day.
@@ -331,8 +662,7 @@ impl<'a> FilterEvaluator<'a> {
     }
 
     /// Checks if the item has the specified label (case-insensitive).
-    fn has_label(&self, item: &Item, label_name: &str) -> bool {
-        let label_lower = label_name.to_lowercase();
+    fn has_label(&self, item: &Item, label_lower: &str) -> bool {
         item.labels.iter().any(|l| l.to_lowercase() == label_lower)
     }
 
@@ -340,82 +670,6 @@ impl<'a> FilterEvaluator<'a> {
     fn has_no_labels(&self, item: &Item) -> bool {
         item.labels.is_empty()
     }
-
-    /// Checks if the item is in the specified project (case-insensitive).
-    fn in_project(&self, item: &Item, project_name: &str) -> bool {
-        self.context
-            .find_project_by_name(project_name)
-            .is_some_and(|project| project.id == item.project_id)
-    }
-
-    /// Checks if the item is in the specified project or any of its subprojects.
-    fn in_project_or_subproject(&self, item: &Item, project_name: &str) -> bool {
-        let project_ids = self.context.get_project_ids_with_subprojects(project_name);
-        project_ids.contains(&item.project_id.as_str())
-    }
-
-    /// Checks if the item is in the specified section (case-insensitive).
-    fn in_section(&self, item: &Item, section_name: &str) -> bool {
-        let Some(section_id) = &item.section_id else {
-            return false;
-        };
-
-        self.context
-            .find_section_by_name(section_name)
-            .is_some_and(|section| &section.id == section_id)
-    }
-
-    /// Checks if the item is assigned to the specified target.
-    fn is_assigned_to(&self, item: &Item, target: &AssignedTarget) -> bool {
-        match target {
-            AssignedTarget::Me => {
-                let Some(current_uid) = self.context.current_user_id else {
-                    return false;
-                };
-                item.responsible_uid.as_deref() == Some(current_uid)
-            }
-            AssignedTarget::Others => {
-                let Some(current_uid) = self.context.current_user_id else {
-                    return false;
-                };
-                item.responsible_uid
-                    .as_ref()
-                    .is_some_and(|uid| uid != current_uid)
-            }
-            AssignedTarget::User(name) => {
-                let Some(collaborator) = self.context.find_collaborator_by_name(name) else {
-                    return false;
-                };
-                item.responsible_uid.as_deref() == Some(collaborator.id.as_str())
-            }
-        }
-    }
-
-    /// Checks if the item was assigned by the specified target.
-    fn is_assigned_by(&self, item: &Item, target: &AssignedTarget) -> bool {
-        match target {
-            AssignedTarget::Me => {
-                let Some(current_uid) = self.context.current_user_id else {
-                    return false;
-                };
-                item.assigned_by_uid.as_deref() == Some(current_uid)
-            }
-            AssignedTarget::Others => {
-                let Some(current_uid) = self.context.current_user_id else {
-                    return false;
-                };
-                item.assigned_by_uid
-                    .as_ref()
-                    .is_some_and(|uid| uid != current_uid)
-            }
-            AssignedTarget::User(name) => {
-                let Some(collaborator) = self.context.find_collaborator_by_name(name) else {
-                    return false;
-                };
-                item.assigned_by_uid.as_deref() == Some(collaborator.id.as_str())
-            }
-        }
-    }
 }
 
 #[cfg(test)]
@@ -1,9 +1,10 @@
 //! Tests for filter evaluation.
 
 use super::*;
-use chrono::Local;
 use todoist_api_rs::models::Due;
 
+use crate::filter::{FilterBuilder, FilterParser};
+
 // ==================== Test Helpers ====================
 
 fn make_item(id: &str, content: &str) -> Item {
@@ -92,24 +93,37 @@ fn make_label(id: &str, name: &str) -> Label {
     }
 }
 
+fn make_saved_filter(id: &str, name: &str, query: &str) -> SavedFilter {
+    SavedFilter {
+        id: id.to_string(),
+        name: name.to_string(),
+        query: query.to_string(),
+        color: None,
+        item_order: 0,
+        is_deleted: false,
+        is_favorite: false,
+    }
+}
+
+/// The fixed "today" date-filter tests evaluate against.
+fn today() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, 3, 14).unwrap()
+}
+
 fn today_str() -> String {
-    Local::now().format("%Y-%m-%d").to_string()
+    days_from_today_str(0)
 }
 
 fn tomorrow_str() -> String {
-    (Local::now() + chrono::Duration::days(1))
-        .format("%Y-%m-%d")
-        .to_string()
+    days_from_today_str(1)
 }
 
 fn yesterday_str() -> String {
-    (Local::now() - chrono::Duration::days(1))
-        .format("%Y-%m-%d")
-        .to_string()
+    days_from_today_str(-1)
 }
 
 fn days_from_today_str(days: i64) -> String {
-    (Local::now() + chrono::Duration::days(days))
+    (today() + chrono::Duration::days(days))
         .format("%Y-%m-%d")
         .to_string()
 }
@@ -120,7 +134,7 @@ fn days_from_today_str(days: i64) -> String {
 fn test_filter_today_matches() {
     let context = FilterContext::new(&[], &[], &[]);
     let filter = Filter::Today;
-    let evaluator = FilterEvaluator::new(&filter, &context);
+    let evaluator = FilterEvaluator::new(&filter, &context).with_today(today());
 
     let mut item = make_item("1", "Task");
     item.due = Some(make_due(&today_str()));
@@ -132,7 +146,7 @@ fn test_filter_today_matches() {
 fn test_filter_today_no_match_tomorrow() {
     let context = FilterContext::new(&[], &[], &[]);
     let filter = Filter::Today;
-    let evaluator = FilterEvaluator::new(&filter, &context);
+    let evaluator = FilterEvaluator::new(&filter, &context).with_today(today());
 
     let mut item = make_item("1", "Task");
     item.due = Some(make_due(&tomorrow_str()));
@@ -154,7 +168,7 @@ fn test_filter_today_no_match_no_due() {
 fn test_filter_tomorrow_matches() {
     let context = FilterContext::new(&[], &[], &[]);
     let filter = Filter::Tomorrow;
-    let evaluator = FilterEvaluator::new(&filter, &context);
+    let evaluator = FilterEvaluator::new(&filter, &context).with_today(today());
 
     let mut item = make_item("1", "Task");
     item.due = Some(make_due(&tomorrow_str()));
@@ -166,7 +180,7 @@ fn test_filter_tomorrow_matches() {
 fn test_filter_tomorrow_no_match_today() {
     let context = FilterContext::new(&[], &[], &[]);
     let filter = Filter::Tomorrow;
-    let evaluator = FilterEvaluator::new(&filter, &context);
+    let evaluator = FilterEvaluator::new(&filter, &context).with_today(today());
 
     let mut item = make_item("1", "Task");
     item.due = Some(make_due(&today_str()));
@@ -178,7 +192,7 @@ fn test_filter_tomorrow_no_match_today() {
 fn test_filter_overdue_matches() {
     let context = FilterContext::new(&[], &[], &[]);
     let filter = Filter::Overdue;
-    let evaluator = FilterEvaluator::new(&filter, &context);
+    let evaluator = FilterEvaluator::new(&filter, &context).with_today(today());
 
     let mut item = make_item("1", "Task");
     item.due = Some(make_due(&yesterday_str()));
@@ -190,7 +204,7 @@ fn test_filter_overdue_matches() {
 fn test_filter_overdue_no_match_today() {
     let context = FilterContext::new(&[], &[], &[]);
     let filter = Filter::Overdue;
-    let evaluator = FilterEvaluator::new(&filter, &context);
+    let evaluator = FilterEvaluator::new(&filter, &context).with_today(today());
 
     let mut item = make_item("1", "Task");
     item.due = Some(make_due(&today_str()));
@@ -202,7 +216,7 @@ fn test_filter_overdue_no_match_today() {
 fn test_filter_overdue_no_match_completed() {
     let context = FilterContext::new(&[], &[], &[]);
     let filter = Filter::Overdue;
-    let evaluator = FilterEvaluator::new(&filter, &context);
+    let evaluator = FilterEvaluator::new(&filter, &context).with_today(today());
 
     let mut item = make_item("1", "Task");
     item.due = Some(make_due(&yesterday_str()));
@@ -231,11 +245,39 @@ fn test_filter_no_date_matches() {
     assert!(evaluator.matches(&item));
 }
 
+#[test]
+fn test_filter_no_time_and_has_time() {
+    let context = FilterContext::new(&[], &[], &[]);
+    let no_time = Filter::NoTime;
+    let has_time = Filter::HasTime;
+    let no_time_eval = FilterEvaluator::new(&no_time, &context).with_today(today());
+    let has_time_eval = FilterEvaluator::new(&has_time, &context).with_today(today());
+
+    // All-day item: due date without a time
+    let mut all_day = make_item("1", "All-day task");
+    all_day.due = Some(make_due(&today_str()));
+    assert!(no_time_eval.matches(&all_day));
+    assert!(!has_time_eval.matches(&all_day));
+
+    // Timed item: due datetime set
+    let mut timed = make_item("2", "Timed task");
+    let mut due = make_due(&today_str());
+    due.datetime = Some(format!("{}T09:30:00", today_str()));
+    timed.due = Some(due);
+    assert!(!no_time_eval.matches(&timed));
+    assert!(has_time_eval.matches(&timed));
+
+    // Undated item matches neither
+    let undated = make_item("3", "Someday task");
+    assert!(!no_time_eval.matches(&undated));
+    assert!(!has_time_eval.matches(&undated));
+}
+
 #[test]
 fn test_filter_no_date_no_match_with_due() {
     let context = FilterContext::new(&[], &[], &[]);
     let filter = Filter::NoDate;
-    let evaluator = FilterEvaluator::new(&filter, &context);
+    let evaluator = FilterEvaluator::new(&filter, &context).with_today(today());
 
     let mut item = make_item("1", "Task");
     item.due = Some(make_due(&today_str()));
@@ -247,7 +289,7 @@ fn test_filter_no_date_no_match_with_due() {
 fn test_filter_7_days_matches_today() {
     let context = FilterContext::new(&[], &[], &[]);
     let filter = Filter::Next7Days;
-    let evaluator = FilterEvaluator::new(&filter, &context);
+    let evaluator = FilterEvaluator::new(&filter, &context).with_today(today());
 
     let mut item = make_item("1", "Task");
     item.due = Some(make_due(&today_str()));
@@ -259,7 +301,7 @@ fn test_filter_7_days_matches_today() {
 fn test_filter_7_days_matches_in_5_days() {
     let context = FilterContext::new(&[], &[], &[]);
     let filter = Filter::Next7Days;
-    let evaluator = FilterEvaluator::new(&filter, &context);
+    let evaluator = FilterEvaluator::new(&filter, &context).with_today(today());
 
     let mut item = make_item("1", "Task");
     item.due = Some(make_due(&days_from_today_str(5)));
@@ -271,7 +313,7 @@ fn test_filter_7_days_matches_in_5_days() {
 fn test_filter_7_days_matches_in_6_days() {
     let context = FilterContext::new(&[], &[], &[]);
     let filter = Filter::Next7Days;
-    let evaluator = FilterEvaluator::new(&filter, &context);
+    let evaluator = FilterEvaluator::new(&filter, &context).with_today(today());
 
     let mut item = make_item("1", "Task");
     item.due = Some(make_due(&days_from_today_str(6)));
@@ -283,7 +325,7 @@ fn test_filter_7_days_matches_in_6_days() {
 fn test_filter_7_days_no_match_in_7_days() {
     let context = FilterContext::new(&[], &[], &[]);
     let filter = Filter::Next7Days;
-    let evaluator = FilterEvaluator::new(&filter, &context);
+    let evaluator = FilterEvaluator::new(&filter, &context).with_today(today());
 
     let mut item = make_item("1", "Task");
     item.due = Some(make_due(&days_from_today_str(7)));
@@ -296,7 +338,7 @@ fn test_filter_7_days_no_match_in_7_days() {
 fn test_filter_7_days_no_match_in_10_days() {
     let context = FilterContext::new(&[], &[], &[]);
     let filter = Filter::Next7Days;
-    let evaluator = FilterEvaluator::new(&filter, &context);
+    let evaluator = FilterEvaluator::new(&filter, &context).with_today(today());
 
     let mut item = make_item("1", "Task");
     item.due = Some(make_due(&days_from_today_str(10)));
@@ -308,7 +350,7 @@ fn test_filter_7_days_no_match_in_10_days() {
 fn test_filter_7_days_no_match_overdue() {
     let context = FilterContext::new(&[], &[], &[]);
     let filter = Filter::Next7Days;
-    let evaluator = FilterEvaluator::new(&filter, &context);
+    let evaluator = FilterEvaluator::new(&filter, &context).with_today(today());
 
     let mut item = make_item("1", "Task");
     item.due = Some(make_due(&yesterday_str()));
@@ -512,6 +554,21 @@ fn test_filter_label_matches() {
     assert!(evaluator.matches(&item));
 }
 
+#[test]
+fn test_filter_quoted_multi_word_label_matches() {
+    let labels = vec![make_label("l1", "Deep Work")];
+    let context = FilterContext::new(&[], &[], &labels);
+    let filter = FilterParser::parse("@\"deep work\"").unwrap();
+    let evaluator = FilterEvaluator::new(&filter, &context);
+
+    let mut item = make_item("1", "Task");
+    item.labels = vec!["Deep Work".to_string()];
+    assert!(evaluator.matches(&item));
+
+    item.labels = vec!["Deep".to_string()];
+    assert!(!evaluator.matches(&item));
+}
+
 #[test]
 fn test_filter_label_case_insensitive() {
     let labels = vec![make_label("l1", "Urgent")];
@@ -704,6 +761,53 @@ fn test_filter_project_not_found() {
     assert!(!evaluator.matches(&item));
 }
 
+#[test]
+fn test_check_references_rejects_unknown_project() {
+    let projects = vec![make_project("proj-1", "Work", None)];
+    let context = FilterContext::new(&projects, &[], &[]);
+    let filter = FilterParser::parse("#NonExistent & p1").unwrap();
+    let evaluator = FilterEvaluator::new(&filter, &context);
+
+    assert_eq!(
+        evaluator.check_references(),
+        Err(FilterError::unknown_reference("project", "NonExistent"))
+    );
+}
+
+#[test]
+fn test_check_references_rejects_unknown_section_and_label() {
+    let projects = vec![make_project("proj-1", "Work", None)];
+    let sections = vec![make_section("sec-1", "Planning", "proj-1")];
+    let labels = vec![make_label("label-1", "urgent")];
+    let context = FilterContext::new(&projects, &sections, &labels);
+
+    let filter = FilterParser::parse("/Planning & !/Backlog").unwrap();
+    assert_eq!(
+        FilterEvaluator::new(&filter, &context).check_references(),
+        Err(FilterError::unknown_reference("section", "Backlog"))
+    );
+
+    let filter = FilterParser::parse("@urgent | @urgnet").unwrap();
+    assert_eq!(
+        FilterEvaluator::new(&filter, &context).check_references(),
+        Err(FilterError::unknown_reference("label", "urgnet"))
+    );
+}
+
+#[test]
+fn test_check_references_accepts_known_names() {
+    let projects = vec![make_project("proj-1", "Work", None)];
+    let sections = vec![make_section("sec-1", "Planning", "proj-1")];
+    let labels = vec![make_label("label-1", "urgent")];
+    let context = FilterContext::new(&projects, &sections, &labels);
+    let filter = FilterParser::parse("(##work | /planning) & @URGENT & today").unwrap();
+
+    assert_eq!(
+        FilterEvaluator::new(&filter, &context).check_references(),
+        Ok(())
+    );
+}
+
 #[test]
 fn test_filter_project_with_subprojects_matches_parent() {
     let projects = vec![
@@ -847,7 +951,7 @@ fn test_filter_section_no_match_no_section() {
 fn test_filter_and_both_true() {
     let context = FilterContext::new(&[], &[], &[]);
     let filter = Filter::and(Filter::Today, Filter::Priority1);
-    let evaluator = FilterEvaluator::new(&filter, &context);
+    let evaluator = FilterEvaluator::new(&filter, &context).with_today(today());
 
     let mut item = make_item("1", "Task");
     item.due = Some(make_due(&today_str()));
@@ -860,7 +964,7 @@ fn test_filter_and_both_true() {
 fn test_filter_and_one_false() {
     let context = FilterContext::new(&[], &[], &[]);
     let filter = Filter::and(Filter::Today, Filter::Priority1);
-    let evaluator = FilterEvaluator::new(&filter, &context);
+    let evaluator = FilterEvaluator::new(&filter, &context).with_today(today());
 
     let mut item = make_item("1", "Task");
     item.due = Some(make_due(&today_str()));
@@ -873,7 +977,7 @@ fn test_filter_and_one_false() {
 fn test_filter_and_both_false() {
     let context = FilterContext::new(&[], &[], &[]);
     let filter = Filter::and(Filter::Today, Filter::Priority1);
-    let evaluator = FilterEvaluator::new(&filter, &context);
+    let evaluator = FilterEvaluator::new(&filter, &context).with_today(today());
 
     let mut item = make_item("1", "Task");
     item.due = Some(make_due(&tomorrow_str()));
@@ -886,7 +990,7 @@ fn test_filter_and_both_false() {
 fn test_filter_or_both_true() {
     let context = FilterContext::new(&[], &[], &[]);
     let filter = Filter::or(Filter::Today, Filter::Overdue);
-    let evaluator = FilterEvaluator::new(&filter, &context);
+    let evaluator = FilterEvaluator::new(&filter, &context).with_today(today());
 
     let mut item = make_item("1", "Task");
     item.due = Some(make_due(&today_str()));
@@ -898,7 +1002,7 @@ fn test_filter_or_both_true() {
 fn test_filter_or_one_true() {
     let context = FilterContext::new(&[], &[], &[]);
     let filter = Filter::or(Filter::Today, Filter::Overdue);
-    let evaluator = FilterEvaluator::new(&filter, &context);
+    let evaluator = FilterEvaluator::new(&filter, &context).with_today(today());
 
     let mut item = make_item("1", "Task");
     item.due = Some(make_due(&yesterday_str()));
@@ -910,7 +1014,7 @@ fn test_filter_or_one_true() {
 fn test_filter_or_both_false() {
     let context = FilterContext::new(&[], &[], &[]);
     let filter = Filter::or(Filter::Today, Filter::Overdue);
-    let evaluator = FilterEvaluator::new(&filter, &context);
+    let evaluator = FilterEvaluator::new(&filter, &context).with_today(today());
 
     let mut item = make_item("1", "Task");
     item.due = Some(make_due(&tomorrow_str()));
@@ -922,7 +1026,7 @@ fn test_filter_or_both_false() {
 fn test_filter_not_inverts_true() {
     let context = FilterContext::new(&[], &[], &[]);
     let filter = Filter::negate(Filter::NoDate);
-    let evaluator = FilterEvaluator::new(&filter, &context);
+    let evaluator = FilterEvaluator::new(&filter, &context).with_today(today());
 
     let mut item = make_item("1", "Task");
     item.due = Some(make_due(&today_str()));
@@ -950,7 +1054,7 @@ fn test_filter_complex_today_or_overdue_and_p1() {
         Filter::or(Filter::Today, Filter::Overdue),
         Filter::Priority1,
     );
-    let evaluator = FilterEvaluator::new(&filter, &context);
+    let evaluator = FilterEvaluator::new(&filter, &context).with_today(today());
 
     // Today + p1 = match
     let mut item1 = make_item("1", "Task 1");
@@ -1016,7 +1120,7 @@ fn test_filter_not_with_complex_expression() {
     // !(today & p1) - items that are NOT both today and p1
     let context = FilterContext::new(&[], &[], &[]);
     let filter = Filter::negate(Filter::and(Filter::Today, Filter::Priority1));
-    let evaluator = FilterEvaluator::new(&filter, &context);
+    let evaluator = FilterEvaluator::new(&filter, &context).with_today(today());
 
     // Today + p1 = no match (because NOT)
     let mut item1 = make_item("1", "Task 1");
@@ -1037,6 +1141,178 @@ fn test_filter_not_with_complex_expression() {
     assert!(evaluator.matches(&item3));
 }
 
+#[test]
+fn test_filter_precedence_changes_result_set() {
+    // "today | p1 & overdue" must mean "today | (p1 & overdue)", not
+    // "(today | p1) & overdue"; the two groupings select different items.
+    let context = FilterContext::new(&[], &[], &[]);
+
+    // Due today, p4: matches only the correct grouping
+    let mut today_p4 = make_item("1", "Today p4");
+    today_p4.due = Some(make_due(&today_str()));
+    today_p4.priority = 1;
+
+    // Overdue, p1: matches both groupings
+    let mut overdue_p1 = make_item("2", "Overdue p1");
+    overdue_p1.due = Some(make_due(&yesterday_str()));
+    overdue_p1.priority = 4;
+
+    // Overdue, p4: matches neither grouping
+    let mut overdue_p4 = make_item("3", "Overdue p4");
+    overdue_p4.due = Some(make_due(&yesterday_str()));
+    overdue_p4.priority = 1;
+
+    let items = vec![today_p4, overdue_p1, overdue_p4];
+
+    let parsed = crate::filter::FilterParser::parse("today | p1 & overdue").unwrap();
+    let ids: Vec<&str> = FilterEvaluator::new(&parsed, &context)
+        .with_today(today())
+        .filter_items(&items)
+        .iter()
+        .map(|i| i.id.as_str())
+        .collect();
+    assert_eq!(ids, vec!["1", "2"]);
+
+    let wrong = Filter::and(
+        Filter::or(Filter::Today, Filter::Priority1),
+        Filter::Overdue,
+    );
+    let wrong_ids: Vec<&str> = FilterEvaluator::new(&wrong, &context)
+        .with_today(today())
+        .filter_items(&items)
+        .iter()
+        .map(|i| i.id.as_str())
+        .collect();
+    assert_eq!(wrong_ids, vec!["2"]);
+}
+
+// ==================== Constructor and Builder Tests ====================
+
+/// Items covering priorities, labels, projects and due dates for comparing
+/// constructed filters against their parsed equivalents.
+fn constructor_test_items() -> Vec<Item> {
+    let mut items = Vec::new();
+    for (i, (priority, label, project_id, due)) in [
+        (4, Some("work"), "proj-work", Some(today_str())),
+        (4, None, "proj-work", None),
+        (1, Some("home"), "proj-home", Some(yesterday_str())),
+        (2, Some("work"), "proj-home", Some(tomorrow_str())),
+        (1, None, "proj-work", Some(yesterday_str())),
+        (3, Some("waiting"), "proj-work", None),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let mut item = make_item(&i.to_string(), "Task");
+        item.priority = priority;
+        item.labels = label.map(|l| vec![l.to_string()]).unwrap_or_default();
+        item.project_id = project_id.to_string();
+        item.due = due.as_deref().map(make_due);
+        items.push(item);
+    }
+    items
+}
+
+/// Asserts that `built` matches exactly the same items as parsing `query`.
+fn assert_same_matches(built: &Filter, query: &str) {
+    let projects = vec![
+        make_project("proj-work", "Work", None),
+        make_project("proj-home", "Home", None),
+    ];
+    let labels = vec![
+        make_label("l1", "work"),
+        make_label("l2", "home"),
+        make_label("l3", "waiting"),
+    ];
+    let context = FilterContext::new(&projects, &[], &labels);
+    let parsed = FilterParser::parse(query).unwrap();
+    let items = constructor_test_items();
+
+    let ids = |filter: &Filter| -> Vec<String> {
+        FilterEvaluator::new(filter, &context)
+            .with_today(today())
+            .filter_items(&items)
+            .iter()
+            .map(|i| i.id.clone())
+            .collect()
+    };
+    assert_eq!(ids(built), ids(&parsed), "mismatch for {query:?}");
+}
+
+#[test]
+fn test_constructors_match_parsed_equivalents() {
+    let cases = [
+        (
+            Filter::and(Filter::Today, Filter::Label("work".to_string())),
+            "today & @work",
+        ),
+        (
+            Filter::or(Filter::Overdue, Filter::Priority1),
+            "overdue | p1",
+        ),
+        (Filter::negate(Filter::NoDate), "!no date"),
+        (
+            Filter::negate(Filter::or(Filter::Overdue, Filter::NoDate)),
+            "!(overdue | no date)",
+        ),
+        (
+            Filter::all([Filter::Project("Work".to_string()), Filter::Priority4]).unwrap(),
+            "#Work & p4",
+        ),
+        (
+            Filter::any([Filter::Priority1, Filter::Priority2, Filter::Priority3]).unwrap(),
+            "p1 | p2 | p3",
+        ),
+    ];
+
+    for (built, query) in &cases {
+        assert_eq!(
+            built,
+            &FilterParser::parse(query).unwrap(),
+            "AST for {query:?}"
+        );
+        assert_same_matches(built, query);
+    }
+}
+
+#[test]
+fn test_filter_builder_matches_parsed_equivalents() {
+    let built = FilterBuilder::new()
+        .project("Work")
+        .priority(4)
+        .and_not(Filter::Label("waiting".to_string()))
+        .build()
+        .unwrap();
+    assert_eq!(
+        built,
+        FilterParser::parse("#Work & p4 & !@waiting").unwrap()
+    );
+    assert_same_matches(&built, "#Work & p4 & !@waiting");
+
+    let built = FilterBuilder::new()
+        .overdue()
+        .or(Filter::NoDate)
+        .label("work")
+        .build()
+        .unwrap();
+    assert_same_matches(&built, "(overdue | no date) & @work");
+
+    let built = FilterBuilder::new()
+        .no_date()
+        .section("Backlog")
+        .build()
+        .unwrap();
+    assert_eq!(built.to_query_string(), "no date & /Backlog");
+}
+
+#[test]
+fn test_filter_builder_empty_and_invalid_priority() {
+    assert_eq!(FilterBuilder::new().build(), None);
+    assert_eq!(FilterBuilder::new().priority(0).priority(5).build(), None);
+    assert_eq!(Filter::all(Vec::new()), None);
+    assert_eq!(Filter::any(Vec::new()), None);
+}
+
 // ==================== Filter Items Tests ====================
 
 #[test]
@@ -1138,6 +1414,60 @@ fn test_context_get_project_ids_with_subprojects() {
     assert!(!ids.contains(&"proj-4"));
 }
 
+#[test]
+fn test_context_precomputes_three_level_descendants() {
+    let projects = vec![
+        make_project("root", "Root", None),
+        make_project("mid-a", "Mid A", Some("root")),
+        make_project("mid-b", "Mid B", Some("root")),
+        make_project("leaf", "Leaf", Some("mid-a")),
+        make_project("other", "Other", None),
+    ];
+    let context = FilterContext::new(&projects, &[], &[]);
+
+    assert_eq!(
+        context.descendant_project_ids("root"),
+        ["mid-a", "mid-b", "leaf"]
+    );
+    assert_eq!(context.descendant_project_ids("mid-a"), ["leaf"]);
+    assert!(context.descendant_project_ids("leaf").is_empty());
+    assert!(context.descendant_project_ids("missing").is_empty());
+
+    let filter = FilterParser::parse("##Root").unwrap();
+    let evaluator = FilterEvaluator::new(&filter, &context);
+    for (project_id, expected) in [
+        ("root", true),
+        ("mid-a", true),
+        ("mid-b", true),
+        ("leaf", true),
+        ("other", false),
+    ] {
+        let mut item = make_item("1", "Task");
+        item.project_id = project_id.to_string();
+        assert_eq!(evaluator.matches(&item), expected, "{project_id}");
+    }
+}
+
+#[test]
+fn test_context_descendants_survive_parent_cycle() {
+    let projects = vec![
+        make_project("a", "A", Some("c")),
+        make_project("b", "B", Some("a")),
+        make_project("c", "C", Some("b")),
+    ];
+    let context = FilterContext::new(&projects, &[], &[]);
+
+    assert_eq!(context.descendant_project_ids("a"), ["b", "c"]);
+    let ids = context.get_project_ids_with_subprojects("B");
+    assert_eq!(ids, vec!["b", "c", "a"]);
+
+    let filter = FilterParser::parse("##A").unwrap();
+    let evaluator = FilterEvaluator::new(&filter, &context);
+    let mut item = make_item("1", "Task");
+    item.project_id = "c".to_string();
+    assert!(evaluator.matches(&item));
+}
+
 #[test]
 fn test_context_find_section_by_name() {
     let sections = vec![
@@ -1388,6 +1718,83 @@ fn test_eval_no_assignee() {
     assert!(evaluator.matches(&item));
 }
 
+#[test]
+fn test_eval_completed() {
+    let context = FilterContext::new(&[], &[], &[]);
+    let filter = Filter::Completed;
+    let evaluator = FilterEvaluator::new(&filter, &context).with_today(today());
+
+    let mut item = make_item("1", "Task");
+    assert!(!evaluator.matches(&item));
+
+    item.checked = true;
+    assert!(evaluator.matches(&item));
+}
+
+/// Builds a cache-like mix of active and completed items for status tests.
+fn make_mixed_status_items() -> Vec<Item> {
+    let mut done_p1 = make_item("done-p1", "Done urgent");
+    done_p1.priority = 4;
+    done_p1.checked = true;
+    let mut done_today = make_item("done-today", "Done today");
+    done_today.due = Some(make_due(&today_str()));
+    done_today.checked = true;
+    let mut active_p1 = make_item("active-p1", "Open urgent");
+    active_p1.priority = 4;
+    let mut active_today = make_item("active-today", "Open today");
+    active_today.due = Some(make_due(&today_str()));
+    let done_plain = Item {
+        checked: true,
+        ..make_item("done-plain", "Done plain")
+    };
+
+    vec![done_p1, done_today, active_p1, active_today, done_plain]
+}
+
+fn matching_ids(query: &str, items: &[Item]) -> Vec<String> {
+    let context = FilterContext::new(&[], &[], &[]);
+    let filter = crate::filter::FilterParser::parse(query).unwrap();
+    let evaluator = FilterEvaluator::new(&filter, &context).with_today(today());
+    items
+        .iter()
+        .filter(|i| evaluator.matches(i))
+        .map(|i| i.id.clone())
+        .collect()
+}
+
+#[test]
+fn test_eval_completed_and_priority() {
+    let items = make_mixed_status_items();
+    assert_eq!(matching_ids("completed & p1", &items), vec!["done-p1"]);
+}
+
+#[test]
+fn test_eval_not_completed_and_today() {
+    let items = make_mixed_status_items();
+    assert_eq!(
+        matching_ids("!completed & today", &items),
+        vec!["active-today"]
+    );
+    // Without the gate, today matches completed items too
+    assert_eq!(
+        matching_ids("today", &items),
+        vec!["done-today", "active-today"]
+    );
+}
+
+#[test]
+fn test_eval_completed_alone() {
+    let items = make_mixed_status_items();
+    assert_eq!(
+        matching_ids("completed", &items),
+        vec!["done-p1", "done-today", "done-plain"]
+    );
+    assert_eq!(
+        matching_ids("!completed", &items),
+        vec!["active-p1", "active-today"]
+    );
+}
+
 #[test]
 fn test_eval_no_assignee_when_assigned() {
     let context = FilterContext::new(&[], &[], &[]);
@@ -1435,3 +1842,278 @@ fn test_eval_not_assigned_unassigned_item() {
     let item = make_item("1", "Task");
     assert!(evaluator.matches(&item));
 }
+
+#[test]
+fn test_from_cache_matches_manual_context() {
+    let mut cache = crate::Cache::new();
+    cache.projects = vec![
+        make_project("p1", "Work", None),
+        make_project("p2", "Work Sub", Some("p1")),
+        make_project("p3", "Home", None),
+    ];
+    cache.sections = vec![make_section("s1", "Planning", "p1")];
+    cache.labels = vec![make_label("l1", "urgent")];
+    cache.items = (1..=6)
+        .map(|n| {
+            let mut item = make_item(&n.to_string(), "Task");
+            item.project_id = ["p1", "p2", "p3"][n % 3].to_string();
+            if n % 2 == 0 {
+                item.labels = vec!["urgent".to_string()];
+            }
+            if n == 3 {
+                item.section_id = Some("s1".to_string());
+            }
+            item
+        })
+        .collect();
+
+    let filter = FilterParser::parse("##Work & @urgent | /Planning").unwrap();
+    let manual = FilterContext::new(&cache.projects, &cache.sections, &cache.labels);
+    let from_cache = FilterContext::from_cache(&cache);
+
+    let ids = |context: &FilterContext| -> Vec<String> {
+        FilterEvaluator::new(&filter, context)
+            .filter_items(&cache.items)
+            .iter()
+            .map(|i| i.id.clone())
+            .collect()
+    };
+    assert!(!ids(&manual).is_empty());
+    assert_eq!(ids(&from_cache), ids(&manual));
+}
+
+#[test]
+fn test_from_cache_uses_cached_user_for_assignment() {
+    let mut cache = crate::Cache::new();
+    cache.user = Some(serde_json::from_value(serde_json::json!({"id": "user1"})).unwrap());
+    cache.collaborators = vec![make_collaborator("user1", "Me", "me@example.com")];
+
+    let context = FilterContext::from_cache(&cache);
+    let filter = Filter::AssignedTo(AssignedTarget::Me);
+    let evaluator = FilterEvaluator::new(&filter, &context);
+
+    let mut item = make_item("1", "Task");
+    item.responsible_uid = Some("user1".to_string());
+    assert!(evaluator.matches(&item));
+}
+
+// ==================== Saved Filter Tests ====================
+
+#[test]
+fn test_saved_filter_reference_expands_query() {
+    let saved = vec![
+        make_saved_filter("f-1", "My Today", "today | overdue"),
+        make_saved_filter("f-2", "Focus", "filter:\"my today\" & p1"),
+    ];
+    let labels = vec![make_label("label-1", "work")];
+    let context = FilterContext::new(&[], &[], &labels).with_saved_filters(&saved);
+
+    let filter = FilterParser::parse("filter:Focus & @work").unwrap();
+    assert_eq!(
+        context.expand_saved_filters(&filter).unwrap(),
+        FilterParser::parse("(today | overdue) & p1 & @work").unwrap()
+    );
+
+    let evaluator = FilterEvaluator::new(&filter, &context).with_today(today());
+    assert_eq!(evaluator.check_references(), Ok(()));
+
+    let mut matching = make_item("1", "Ship it");
+    matching.due = Some(make_due(&today_str()));
+    matching.priority = 4;
+    matching.labels = vec!["work".to_string()];
+    let mut low_priority = matching.clone();
+    low_priority.priority = 1;
+
+    assert!(evaluator.matches(&matching));
+    assert!(!evaluator.matches(&low_priority));
+}
+
+#[test]
+fn test_saved_filter_cyclic_reference_is_rejected() {
+    let saved = vec![
+        make_saved_filter("f-1", "A", "p1 | filter:B"),
+        make_saved_filter("f-2", "B", "today & filter:a"),
+        make_saved_filter("f-3", "Self", "filter:Self"),
+    ];
+    let context = FilterContext::new(&[], &[], &[]).with_saved_filters(&saved);
+
+    let filter = FilterParser::parse("filter:A").unwrap();
+    assert_eq!(
+        context.expand_saved_filters(&filter),
+        Err(FilterError::cyclic_filter_reference("A"))
+    );
+    let filter = FilterParser::parse("filter:self").unwrap();
+    assert_eq!(
+        FilterEvaluator::new(&filter, &context).check_references(),
+        Err(FilterError::cyclic_filter_reference("Self"))
+    );
+
+    // Lenient evaluation treats the broken reference as matching nothing
+    let mut item = make_item("1", "Task");
+    item.priority = 4;
+    assert!(!FilterEvaluator::new(&filter, &context).matches(&item));
+}
+
+#[test]
+fn test_saved_filter_unknown_reference() {
+    let context = FilterContext::new(&[], &[], &[]);
+    let filter = FilterParser::parse("filter:Missing | p1").unwrap();
+    let evaluator = FilterEvaluator::new(&filter, &context);
+
+    assert_eq!(
+        evaluator.check_references(),
+        Err(FilterError::unknown_reference("saved filter", "Missing"))
+    );
+    let mut item = make_item("1", "Task");
+    item.priority = 4;
+    assert!(evaluator.matches(&item));
+}
+
+#[test]
+fn test_saved_filter_broken_reference_leaves_others_expanded() {
+    let saved = vec![
+        make_saved_filter("f-1", "Urgent", "p1"),
+        make_saved_filter("f-2", "Broken", "p1 &"),
+    ];
+    let context = FilterContext::new(&[], &[], &[]).with_saved_filters(&saved);
+    let filter = FilterParser::parse("filter:Broken | filter:Missing | filter:Urgent").unwrap();
+    let evaluator = FilterEvaluator::new(&filter, &context);
+
+    let mut urgent = make_item("1", "Task");
+    urgent.priority = 4;
+    assert!(evaluator.matches(&urgent));
+    assert!(!evaluator.matches(&make_item("2", "Task")));
+
+    // Only the first failing reference is reported, by its own name
+    assert!(matches!(
+        evaluator.check_references(),
+        Err(FilterError::InvalidSavedFilter { ref name, .. }) if name == "Broken"
+    ));
+}
+
+// ==================== Compiled Plan Tests ====================
+
+/// Straightforward tree-walk that resolves names for every item, used as the
+/// reference the compiled plan must agree with.
+fn reference_matches(filter: &Filter, context: &FilterContext<'_>, item: &Item) -> bool {
+    match filter {
+        Filter::Project(name) => context
+            .find_project_by_name(name)
+            .is_some_and(|p| p.id == item.project_id),
+        Filter::ProjectWithSubprojects(name) => context
+            .get_project_ids_with_subprojects(name)
+            .contains(&item.project_id.as_str()),
+        Filter::Section(name) => item.section_id.as_ref().is_some_and(|sid| {
+            context
+                .find_section_by_name(name)
+                .is_some_and(|s| &s.id == sid)
+        }),
+        Filter::Label(name) => item
+            .labels
+            .iter()
+            .any(|l| l.to_lowercase() == name.to_lowercase()),
+        Filter::AssignedTo(AssignedTarget::User(name)) => context
+            .find_collaborator_by_name(name)
+            .is_some_and(|c| item.responsible_uid.as_deref() == Some(c.id.as_str())),
+        Filter::Priority1 => item.priority == 4,
+        Filter::And(l, r) => {
+            reference_matches(l, context, item) && reference_matches(r, context, item)
+        }
+        Filter::Or(l, r) => {
+            reference_matches(l, context, item) || reference_matches(r, context, item)
+        }
+        Filter::Not(inner) => !reference_matches(inner, context, item),
+        other => panic!("reference evaluator does not handle {other:?}"),
+    }
+}
+
+fn make_plan_fixture() -> (Vec<Project>, Vec<Section>, Vec<Collaborator>, Vec<Item>) {
+    let projects = vec![
+        make_project("p-work", "Work", None),
+        make_project("p-client", "Client", Some("p-work")),
+        make_project("p-home", "Home", None),
+    ];
+    let sections = vec![
+        make_section("s-backlog", "Backlog", "p-work"),
+        make_section("s-chores", "Chores", "p-home"),
+    ];
+    let collaborators = vec![make_collaborator("u-alice", "Alice", "alice@example.com")];
+
+    let project_ids = ["p-work", "p-client", "p-home", "p-unknown"];
+    let section_ids = [None, Some("s-backlog"), Some("s-chores")];
+    let labels = [vec![], vec!["Urgent"], vec!["urgent", "email"]];
+    let items = (0..72)
+        .map(|i| {
+            let mut item = make_item(&i.to_string(), "Task");
+            item.project_id = project_ids[i % 4].to_string();
+            item.section_id = section_ids[(i / 4) % 3].map(String::from);
+            item.labels = labels[(i / 12) % 3].iter().map(|l| l.to_string()).collect();
+            item.responsible_uid = (i % 2 == 0).then(|| "u-alice".to_string());
+            item.priority = if (i / 36) % 2 == 0 { 4 } else { 1 };
+            item
+        })
+        .collect();
+    (projects, sections, collaborators, items)
+}
+
+#[test]
+fn test_compiled_plan_matches_reference_evaluation() {
+    let (projects, sections, collaborators, items) = make_plan_fixture();
+    let context =
+        FilterContext::new(&projects, &sections, &[]).with_assignment_context(&collaborators, None);
+
+    for query in [
+        "#Work",
+        "##Work",
+        "#Nope",
+        "/Backlog",
+        "/Nope",
+        "@urgent",
+        "assigned to: Alice",
+        "assigned to: Nobody",
+        "##Work & !/Backlog",
+        "(#Home | @urgent) & p1",
+        "!(##Work | /Chores) | assigned to: alice & @email",
+    ] {
+        let filter = FilterParser::parse(query).unwrap();
+        let evaluator = FilterEvaluator::new(&filter, &context);
+        for item in &items {
+            assert_eq!(
+                evaluator.matches(item),
+                reference_matches(&filter, &context, item),
+                "query {query:?} disagrees on item {}",
+                item.id
+            );
+        }
+    }
+}
+
+#[test]
+fn test_names_resolved_once_not_per_item() {
+    let (projects, sections, collaborators, items) = make_plan_fixture();
+    let context =
+        FilterContext::new(&projects, &sections, &[]).with_assignment_context(&collaborators, None);
+    let filter =
+        FilterParser::parse("(##Work | #Home) & !/Backlog | assigned to: Alice & @urgent").unwrap();
+
+    let evaluator = FilterEvaluator::new(&filter, &context);
+    let lookups_after_compile = context.name_lookups.get();
+    assert!(lookups_after_compile > 0);
+
+    let many_items: Vec<Item> = items.iter().cycle().take(10_000).cloned().collect();
+    let matched = evaluator.filter_items(&many_items);
+    assert!(!matched.is_empty());
+    assert_eq!(context.name_lookups.get(), lookups_after_compile);
+}
+
+#[test]
+fn test_compile_resolves_project_hierarchy_to_id_set() {
+    let (projects, sections, collaborators, _) = make_plan_fixture();
+    let context =
+        FilterContext::new(&projects, &sections, &[]).with_assignment_context(&collaborators, None);
+    let filter = FilterParser::parse("##Work").unwrap();
+
+    let evaluator = FilterEvaluator::new(&filter, &context);
+    let expected: HashSet<String> = ["p-work", "p-client"].map(String::from).into();
+    assert_eq!(evaluator.plan, Plan::InProjects(expected));
+}
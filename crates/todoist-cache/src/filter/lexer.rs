@@ -58,7 +58,13 @@ pub enum FilterToken {
     /// The `no date` keyword (parsed as two words).
     NoDate,
 
-    /// The `no labels` keyword (parsed as two words).
+    /// The `no time` keyword (parsed as two words).
+    NoTime,
+
+    /// The `has time` keyword (parsed as two words).
+    HasTime,
+
+    /// The `no labels` keyword (parsed as two words; `no label` also works).
     NoLabels,
 
     /// The `7 days` keyword - tasks due within the next 7 days.
@@ -85,6 +91,9 @@ pub enum FilterToken {
     /// A section reference (prefixed with /).
     Section(String),
 
+    /// A saved filter reference (`filter:name` or `filter:"name"`).
+    SavedFilter(String),
+
     // ==================== Assignment ====================
     /// "assigned to: <target>" keyword.
     AssignedTo(String),
@@ -98,6 +107,10 @@ pub enum FilterToken {
     /// "no assignee" keyword.
     NoAssignee,
 
+    // ==================== Status ====================
+    /// The `completed` keyword.
+    Completed,
+
     // ==================== Operators ====================
     /// The AND operator (`&`).
     And,
@@ -115,6 +128,55 @@ pub enum FilterToken {
     CloseParen,
 }
 
+/// Keywords that lex to a fixed token, in canonical spelling.
+///
+/// `Lexer::try_keyword` looks words up here, so a keyword added to this
+/// table is both lexed and listed in [`KEYWORDS`]. A keyword ending in `:`
+/// takes the name that follows it, which fills in the token's empty name.
+const KEYWORD_TOKENS: &[(&str, FilterToken)] = &[
+    ("today", FilterToken::Today),
+    ("tomorrow", FilterToken::Tomorrow),
+    ("overdue", FilterToken::Overdue),
+    ("completed", FilterToken::Completed),
+    ("7 days", FilterToken::Next7Days),
+    ("no date", FilterToken::NoDate),
+    ("no time", FilterToken::NoTime),
+    ("has time", FilterToken::HasTime),
+    ("no labels", FilterToken::NoLabels),
+    ("no assignee", FilterToken::NoAssignee),
+    ("p1", FilterToken::Priority(1)),
+    ("p2", FilterToken::Priority(2)),
+    ("p3", FilterToken::Priority(3)),
+    ("p4", FilterToken::Priority(4)),
+    ("filter:", FilterToken::SavedFilter(String::new())),
+];
+
+/// Alternate spellings accepted for entries of [`KEYWORD_TOKENS`].
+const KEYWORD_ALIASES: &[(&str, FilterToken)] = &[("no label", FilterToken::NoLabels)];
+
+/// The assignment keywords, lexed by the `assigned` arm of `Lexer::try_keyword`
+/// since `to:` and `by:` take a user-supplied target.
+const ASSIGNMENT_KEYWORDS: &[&str] = &["assigned", "assigned to", "assigned by"];
+
+/// Every keyword the lexer recognizes, in canonical spelling.
+pub const KEYWORDS: &[&str] = &keyword_names();
+
+/// Collects the names in [`KEYWORD_TOKENS`] followed by [`ASSIGNMENT_KEYWORDS`].
+const fn keyword_names() -> [&'static str; KEYWORD_TOKENS.len() + ASSIGNMENT_KEYWORDS.len()] {
+    let mut names = [""; KEYWORD_TOKENS.len() + ASSIGNMENT_KEYWORDS.len()];
+    let mut i = 0;
+    while i < KEYWORD_TOKENS.len() {
+        names[i] = KEYWORD_TOKENS[i].0;
+        i += 1;
+    }
+    let mut j = 0;
+    while j < ASSIGNMENT_KEYWORDS.len() {
+        names[i + j] = ASSIGNMENT_KEYWORDS[j];
+        j += 1;
+    }
+    names
+}
+
 /// Lexer for tokenizing filter expressions.
 pub struct Lexer<'a> {
     chars: Peekable<Chars<'a>>,
@@ -324,31 +386,6 @@ impl<'a> Lexer<'a> {
                 })
             }
 
-            // Priority (p1, p2, p3, p4)
-            'p' | 'P' => {
-                let ident = self.read_identifier();
-                let lower = ident.to_lowercase();
-                match lower.as_str() {
-                    "p1" => Some(PositionedToken {
-                        token: FilterToken::Priority(1),
-                        position: token_start,
-                    }),
-                    "p2" => Some(PositionedToken {
-                        token: FilterToken::Priority(2),
-                        position: token_start,
-                    }),
-                    "p3" => Some(PositionedToken {
-                        token: FilterToken::Priority(3),
-                        position: token_start,
-                    }),
-                    "p4" => Some(PositionedToken {
-                        token: FilterToken::Priority(4),
-                        position: token_start,
-                    }),
-                    _ => self.try_keyword(&lower, token_start),
-                }
-            }
-
             // Keywords and identifiers
             _ if c.is_alphabetic() => {
                 let ident = self.read_identifier();
@@ -359,17 +396,11 @@ impl<'a> Lexer<'a> {
             // Number-based keywords (e.g., "7 days")
             '7' => {
                 self.next_char(); // consume '7'
-                self.skip_whitespace();
-                if let Some(&next_c) = self.peek() {
-                    if next_c.is_alphabetic() {
-                        let word = self.read_identifier();
-                        if word.to_lowercase() == "days" {
-                            return Some(PositionedToken {
-                                token: FilterToken::Next7Days,
-                                position: token_start,
-                            });
-                        }
-                    }
+                if let Some(token) = self.keyword_token("7") {
+                    return Some(PositionedToken {
+                        token,
+                        position: token_start,
+                    });
                 }
                 // Just "7" by itself is not valid
                 self.errors.push(LexerError {
@@ -411,49 +442,49 @@ impl<'a> Lexer<'a> {
         }
     }
 
+    /// Looks `word` up in [`KEYWORD_TOKENS`] (and its aliases), reading the
+    /// next word when `word` starts a two-word keyword such as `no date`, or
+    /// the name after the colon for a keyword such as `filter:`.
+    fn keyword_token(&mut self, word: &str) -> Option<FilterToken> {
+        let lookup = |name: &str| {
+            KEYWORD_TOKENS
+                .iter()
+                .chain(KEYWORD_ALIASES)
+                .find(|(keyword, _)| *keyword == name)
+                .map(|(_, token)| token.clone())
+        };
+        if let Some(token) = lookup(word) {
+            return Some(token);
+        }
+        if self.peek() == Some(&':') {
+            if let Some(FilterToken::SavedFilter(_)) = lookup(&format!("{word}:")) {
+                self.next_char();
+                self.skip_whitespace();
+                return Some(FilterToken::SavedFilter(self.read_name()));
+            }
+        }
+        let starts_phrase = KEYWORD_TOKENS
+            .iter()
+            .chain(KEYWORD_ALIASES)
+            .any(|(keyword, _)| {
+                keyword
+                    .split_once(' ')
+                    .is_some_and(|(first, _)| first == word)
+            });
+        if !starts_phrase {
+            return None;
+        }
+        self.skip_whitespace();
+        if !self.peek().is_some_and(|c| c.is_alphabetic()) {
+            return None;
+        }
+        let next_word = self.read_identifier().to_lowercase();
+        lookup(&format!("{word} {next_word}"))
+    }
+
     /// Tries to match a keyword, returns None if not recognized.
     fn try_keyword(&mut self, lower: &str, position: usize) -> Option<PositionedToken> {
         match lower {
-            "today" => Some(PositionedToken {
-                token: FilterToken::Today,
-                position,
-            }),
-            "tomorrow" => Some(PositionedToken {
-                token: FilterToken::Tomorrow,
-                position,
-            }),
-            "overdue" => Some(PositionedToken {
-                token: FilterToken::Overdue,
-                position,
-            }),
-            "no" => {
-                // Check for "no date" or "no labels"
-                self.skip_whitespace();
-                if let Some(&c) = self.peek() {
-                    if c.is_alphabetic() {
-                        let next_word = self.read_identifier();
-                        let lower = next_word.to_lowercase();
-                        if lower == "date" {
-                            return Some(PositionedToken {
-                                token: FilterToken::NoDate,
-                                position,
-                            });
-                        } else if lower == "labels" {
-                            return Some(PositionedToken {
-                                token: FilterToken::NoLabels,
-                                position,
-                            });
-                        } else if lower == "assignee" {
-                            return Some(PositionedToken {
-                                token: FilterToken::NoAssignee,
-                                position,
-                            });
-                        }
-                    }
-                }
-                // Just "no" by itself is not valid, return None
-                None
-            }
             "assigned" => {
                 // Check for "assigned to:" or "assigned by:"
                 self.skip_whitespace();
@@ -491,6 +522,9 @@ impl<'a> Lexer<'a> {
                 })
             }
             _ => {
+                if let Some(token) = self.keyword_token(lower) {
+                    return Some(PositionedToken { token, position });
+                }
                 // Check if it's a month name followed by a day number
                 if let Some(month) = Self::parse_month_name(lower) {
                     self.skip_whitespace();
@@ -546,6 +580,39 @@ impl<'a> Lexer<'a> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_keyword_table_entries_lex_to_their_token() {
+        for (keyword, token) in KEYWORD_TOKENS.iter().chain(KEYWORD_ALIASES) {
+            let tokens = Lexer::new(keyword).tokenize();
+            assert_eq!(tokens, vec![token.clone()], "{keyword:?}");
+            let tokens = Lexer::new(&keyword.to_uppercase()).tokenize();
+            assert_eq!(tokens, vec![token.clone()], "{keyword:?} uppercased");
+        }
+    }
+
+    #[test]
+    fn test_keywords_lists_every_table_entry() {
+        assert_eq!(
+            KEYWORDS.len(),
+            KEYWORD_TOKENS.len() + ASSIGNMENT_KEYWORDS.len()
+        );
+        for (keyword, _) in KEYWORD_TOKENS {
+            assert!(KEYWORDS.contains(keyword), "{keyword:?} missing");
+        }
+        for keyword in ASSIGNMENT_KEYWORDS {
+            assert!(KEYWORDS.contains(keyword), "{keyword:?} missing");
+        }
+    }
+
+    #[test]
+    fn test_keywords_all_tokenize() {
+        for keyword in KEYWORDS {
+            let result = Lexer::new(keyword).tokenize_with_errors();
+            assert!(result.errors.is_empty(), "{keyword:?} had lexer errors");
+            assert_eq!(result.tokens.len(), 1, "{keyword:?} is not one token");
+        }
+    }
+
     #[test]
     fn test_tokenize_today() {
         let tokens = Lexer::new("today").tokenize();
@@ -564,12 +631,27 @@ mod tests {
         assert_eq!(tokens, vec![FilterToken::Overdue]);
     }
 
+    #[test]
+    fn test_tokenize_completed() {
+        let tokens = Lexer::new("Completed").tokenize();
+        assert_eq!(tokens, vec![FilterToken::Completed]);
+    }
+
     #[test]
     fn test_tokenize_no_date() {
         let tokens = Lexer::new("no date").tokenize();
         assert_eq!(tokens, vec![FilterToken::NoDate]);
     }
 
+    #[test]
+    fn test_tokenize_no_time_and_has_time() {
+        let tokens = Lexer::new("no time").tokenize();
+        assert_eq!(tokens, vec![FilterToken::NoTime]);
+
+        let tokens = Lexer::new("Has  Time").tokenize();
+        assert_eq!(tokens, vec![FilterToken::HasTime]);
+    }
+
     #[test]
     fn test_tokenize_no_date_case_insensitive() {
         let tokens = Lexer::new("NO DATE").tokenize();
@@ -633,6 +715,22 @@ mod tests {
         assert_eq!(tokens, vec![FilterToken::Label("urgent".to_string())]);
     }
 
+    #[test]
+    fn test_tokenize_saved_filter() {
+        let tokens = Lexer::new("filter:\"My Today\" & @work").tokenize();
+        assert_eq!(
+            tokens,
+            vec![
+                FilterToken::SavedFilter("My Today".to_string()),
+                FilterToken::And,
+                FilterToken::Label("work".to_string()),
+            ]
+        );
+
+        let tokens = Lexer::new("FILTER:Today").tokenize();
+        assert_eq!(tokens, vec![FilterToken::SavedFilter("Today".to_string())]);
+    }
+
     #[test]
     fn test_tokenize_project() {
         let tokens = Lexer::new("#Work").tokenize();
@@ -674,6 +772,28 @@ mod tests {
 
         let tokens = Lexer::new("!no date").tokenize();
         assert_eq!(tokens, vec![FilterToken::Not, FilterToken::NoDate]);
+
+        let tokens = Lexer::new("!no label").tokenize();
+        assert_eq!(tokens, vec![FilterToken::Not, FilterToken::NoLabels]);
+    }
+
+    #[test]
+    fn test_tokenize_multi_word_keywords_next_to_parentheses() {
+        let tokens = Lexer::new("(no date|overdue)&(no label)").tokenize();
+        assert_eq!(
+            tokens,
+            vec![
+                FilterToken::OpenParen,
+                FilterToken::NoDate,
+                FilterToken::Or,
+                FilterToken::Overdue,
+                FilterToken::CloseParen,
+                FilterToken::And,
+                FilterToken::OpenParen,
+                FilterToken::NoLabels,
+                FilterToken::CloseParen,
+            ]
+        );
     }
 
     #[test]
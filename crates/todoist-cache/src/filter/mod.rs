@@ -10,6 +10,8 @@
 //! - `tomorrow` - Items due tomorrow
 //! - `overdue` - Items past their due date
 //! - `no date` - Items without a due date
+//! - `no time` - Items with a due date but no time (all-day)
+//! - `has time` - Items whose due date includes a time
 //!
 //! ## Priority
 //! - `p1`, `p2`, `p3`, `p4` - Filter by priority level
@@ -24,6 +26,12 @@
 //! ## Sections
 //! - `/section` - Items in a specific section
 //!
+//! ## Saved Filters
+//! - `filter:name` or `filter:"My Filter"` - Items matching a saved filter's query
+//!
+//! ## Status
+//! - `completed` - Completed (checked) items still held in the cache
+//!
 //! ## Boolean Operators
 //! - `&` - AND
 //! - `|` - OR
@@ -56,7 +64,7 @@ mod evaluator;
 mod lexer;
 mod parser;
 
-pub use ast::{AssignedTarget, Filter};
+pub use ast::{AssignedTarget, Filter, FilterBuilder};
 pub use error::{FilterError, FilterResult};
 pub use evaluator::{FilterContext, FilterEvaluator};
 pub use parser::FilterParser;
@@ -2,7 +2,7 @@
 
 use super::ast::{AssignedTarget, Filter};
 use super::error::{FilterError, FilterResult};
-use super::lexer::{FilterToken, Lexer, PositionedToken};
+use super::lexer::{FilterToken, Lexer, PositionedToken, KEYWORDS};
 
 /// Parser for Todoist filter expressions.
 ///
@@ -18,9 +18,9 @@ use super::lexer::{FilterToken, Lexer, PositionedToken};
 /// and_expr   ::= unary_expr ("&" unary_expr)*
 /// unary_expr ::= "!" unary_expr | primary
 /// primary    ::= "(" expression ")" | keyword | identifier
-/// keyword    ::= "today" | "tomorrow" | "overdue" | "no date"
+/// keyword    ::= "today" | "tomorrow" | "overdue" | "no date" | "completed"
 ///              | "p1" | "p2" | "p3" | "p4"
-/// identifier ::= "@" name | "#" name | "##" name | "/" name
+/// identifier ::= "@" name | "#" name | "##" name | "/" name | "filter:" name
 /// ```
 ///
 /// # Operator Precedence (highest to lowest)
@@ -50,6 +50,12 @@ pub struct FilterParser {
 }
 
 impl FilterParser {
+    /// The keywords the parser understands (`today`, `no date`, `p1`, ...).
+    ///
+    /// Month-day dates like `Jan 15` and `@`/`#`/`/` references are not
+    /// listed since they take a user-supplied part.
+    pub const KEYWORDS: &'static [&'static str] = KEYWORDS;
+
     /// Parses a filter expression string into a Filter AST.
     ///
     /// # Arguments
@@ -199,6 +205,8 @@ impl FilterParser {
             FilterToken::Tomorrow => Ok(Filter::Tomorrow),
             FilterToken::Overdue => Ok(Filter::Overdue),
             FilterToken::NoDate => Ok(Filter::NoDate),
+            FilterToken::NoTime => Ok(Filter::NoTime),
+            FilterToken::HasTime => Ok(Filter::HasTime),
             FilterToken::Next7Days => Ok(Filter::Next7Days),
             FilterToken::SpecificDate { month, day } => Ok(Filter::SpecificDate { month, day }),
 
@@ -219,6 +227,7 @@ impl FilterParser {
             FilterToken::Project(name) => Ok(Filter::Project(name)),
             FilterToken::ProjectWithSubprojects(name) => Ok(Filter::ProjectWithSubprojects(name)),
             FilterToken::Section(name) => Ok(Filter::Section(name)),
+            FilterToken::SavedFilter(name) => Ok(Filter::SavedFilter(name)),
 
             // Assignment filters
             FilterToken::AssignedTo(target) => {
@@ -230,6 +239,9 @@ impl FilterParser {
             FilterToken::Assigned => Ok(Filter::Assigned),
             FilterToken::NoAssignee => Ok(Filter::NoAssignee),
 
+            // Status
+            FilterToken::Completed => Ok(Filter::Completed),
+
             // Unexpected tokens
             FilterToken::And => Err(FilterError::unexpected_token("&", position)),
             FilterToken::Or => Err(FilterError::unexpected_token("|", position)),
@@ -110,6 +110,18 @@ fn test_parse_no_date() {
     );
 }
 
+#[test]
+fn test_parse_no_time_and_has_time() {
+    assert_eq!(FilterParser::parse("no time").unwrap(), Filter::NoTime);
+    assert_eq!(FilterParser::parse("HAS TIME").unwrap(), Filter::HasTime);
+    assert_eq!(
+        FilterParser::parse("today & !has time").unwrap(),
+        Filter::and(Filter::Today, Filter::negate(Filter::HasTime))
+    );
+    assert_eq!(Filter::NoTime.to_string(), "no time");
+    assert_eq!(Filter::HasTime.to_string(), "has time");
+}
+
 #[test]
 fn test_parse_no_date_case_insensitive() {
     assert_eq!(
@@ -207,6 +219,61 @@ fn test_parse_no_labels_negation() {
     assert_eq!(filter, Filter::negate(Filter::NoLabels));
 }
 
+#[test]
+fn test_parse_no_label_singular() {
+    let filter = FilterParser::parse("no label").unwrap();
+    assert_eq!(filter, Filter::NoLabels);
+}
+
+#[test]
+fn test_parse_multi_word_keywords_in_parentheses() {
+    let filter = FilterParser::parse("(no date | overdue) & p1").unwrap();
+    assert_eq!(
+        filter,
+        Filter::and(
+            Filter::or(Filter::NoDate, Filter::Overdue),
+            Filter::Priority1
+        )
+    );
+
+    // No spaces around parentheses or operators
+    let filter = FilterParser::parse("(no date|overdue)&p1").unwrap();
+    assert_eq!(
+        filter,
+        Filter::and(
+            Filter::or(Filter::NoDate, Filter::Overdue),
+            Filter::Priority1
+        )
+    );
+
+    let filter = FilterParser::parse("p1 & (today | no label)").unwrap();
+    assert_eq!(
+        filter,
+        Filter::and(
+            Filter::Priority1,
+            Filter::or(Filter::Today, Filter::NoLabels)
+        )
+    );
+}
+
+#[test]
+fn test_parse_multi_word_keywords_after_not() {
+    let filter = FilterParser::parse("!no date & p1").unwrap();
+    assert_eq!(
+        filter,
+        Filter::and(Filter::negate(Filter::NoDate), Filter::Priority1)
+    );
+
+    let filter = FilterParser::parse("!no label").unwrap();
+    assert_eq!(filter, Filter::negate(Filter::NoLabels));
+
+    let filter = FilterParser::parse("!(no date | no labels)").unwrap();
+    assert_eq!(
+        filter,
+        Filter::negate(Filter::or(Filter::NoDate, Filter::NoLabels))
+    );
+}
+
 // ==================== Specific Date Tests ====================
 
 #[test]
@@ -443,6 +510,53 @@ fn test_parse_section() {
     assert_eq!(filter, Filter::Section("Inbox".to_string()));
 }
 
+#[test]
+fn test_parse_quoted_section() {
+    let filter = FilterParser::parse("/\"In Progress\"").unwrap();
+    assert_eq!(filter, Filter::Section("In Progress".to_string()));
+}
+
+// ==================== Quoted Name Tests ====================
+
+#[test]
+fn test_parse_quoted_names_in_expression() {
+    let filter =
+        FilterParser::parse("@\"Deep Work\" & ##'Side Projects' & !/\"In Progress\"").unwrap();
+    assert_eq!(
+        filter,
+        Filter::and(
+            Filter::and(
+                Filter::Label("Deep Work".to_string()),
+                Filter::ProjectWithSubprojects("Side Projects".to_string()),
+            ),
+            Filter::negate(Filter::Section("In Progress".to_string())),
+        )
+    );
+}
+
+#[test]
+fn test_parse_quoted_name_with_operator_characters() {
+    let filter = FilterParser::parse("#\"R&D (2025)\"").unwrap();
+    assert_eq!(filter, Filter::Project("R&D (2025)".to_string()));
+}
+
+#[test]
+fn test_parse_unquoted_name_stops_at_whitespace() {
+    // Single-word forms are unchanged; a multi-word name needs quotes
+    let filter = FilterParser::parse("@Deep & #Work").unwrap();
+    assert_eq!(
+        filter,
+        Filter::and(
+            Filter::Label("Deep".to_string()),
+            Filter::Project("Work".to_string()),
+        )
+    );
+    assert_ne!(
+        FilterParser::parse("@Deep Work").ok(),
+        Some(Filter::Label("Deep Work".to_string()))
+    );
+}
+
 // ==================== Boolean Operator Tests ====================
 
 #[test]
@@ -526,6 +640,66 @@ fn test_parentheses_override_precedence() {
     );
 }
 
+#[test]
+fn test_mixed_operator_precedence_ast_shapes() {
+    let cases = [
+        (
+            "today | p1 & overdue",
+            Filter::or(
+                Filter::Today,
+                Filter::and(Filter::Priority1, Filter::Overdue),
+            ),
+        ),
+        (
+            "p1 & overdue | today",
+            Filter::or(
+                Filter::and(Filter::Priority1, Filter::Overdue),
+                Filter::Today,
+            ),
+        ),
+        (
+            "today | p1 & overdue | no date",
+            Filter::or(
+                Filter::or(
+                    Filter::Today,
+                    Filter::and(Filter::Priority1, Filter::Overdue),
+                ),
+                Filter::NoDate,
+            ),
+        ),
+        (
+            "today & p1 | overdue & p2",
+            Filter::or(
+                Filter::and(Filter::Today, Filter::Priority1),
+                Filter::and(Filter::Overdue, Filter::Priority2),
+            ),
+        ),
+        (
+            "!today | p1 & !overdue",
+            Filter::or(
+                Filter::negate(Filter::Today),
+                Filter::and(Filter::Priority1, Filter::negate(Filter::Overdue)),
+            ),
+        ),
+        (
+            "!(today | p1) & overdue",
+            Filter::and(
+                Filter::negate(Filter::or(Filter::Today, Filter::Priority1)),
+                Filter::Overdue,
+            ),
+        ),
+    ];
+
+    for (input, expected) in cases {
+        assert_eq!(
+            FilterParser::parse(input).unwrap(),
+            expected,
+            "'{}' should parse with ! > & > | precedence",
+            input
+        );
+    }
+}
+
 // ==================== Complex Expression Tests ====================
 
 #[test]
@@ -601,6 +775,20 @@ fn test_error_empty_expression() {
     assert!(matches!(result, Err(FilterError::EmptyExpression)));
 }
 
+#[test]
+fn test_error_empty_expression_any_whitespace() {
+    let result = FilterParser::parse("\t\n  \r\n");
+    assert!(matches!(result, Err(FilterError::EmptyExpression)));
+}
+
+#[test]
+fn test_error_empty_expression_distinct_from_syntax_error() {
+    // A lone operator is a syntax error, not an empty query
+    let result = FilterParser::parse(" & ");
+    assert!(result.is_err());
+    assert!(!matches!(result, Err(FilterError::EmptyExpression)));
+}
+
 #[test]
 fn test_error_unclosed_parenthesis() {
     let result = FilterParser::parse("(today");
@@ -928,6 +1116,25 @@ fn test_parse_no_assignee() {
     assert_eq!(filter, Filter::NoAssignee);
 }
 
+#[test]
+fn test_parse_completed() {
+    let filter = FilterParser::parse("completed & @recurring").unwrap();
+    assert_eq!(
+        filter,
+        Filter::and(Filter::Completed, Filter::Label("recurring".to_string()))
+    );
+    assert_eq!(Filter::Completed.to_string(), "completed");
+}
+
+#[test]
+fn test_parse_not_completed() {
+    let filter = FilterParser::parse("!completed & today").unwrap();
+    assert_eq!(
+        filter,
+        Filter::and(Filter::negate(Filter::Completed), Filter::Today)
+    );
+}
+
 #[test]
 fn test_parse_assigned_case_insensitive() {
     assert_eq!(
@@ -967,3 +1174,120 @@ fn test_parse_not_assigned() {
     let filter = FilterParser::parse("!assigned").unwrap();
     assert_eq!(filter, Filter::negate(Filter::Assigned));
 }
+
+// ==================== Query String Round-Trip Tests ====================
+
+/// Asserts that rendering and re-parsing yields the same AST.
+fn assert_round_trip(filter: &Filter) {
+    let query = filter.to_query_string();
+    let reparsed = FilterParser::parse(&query)
+        .unwrap_or_else(|e| panic!("'{}' failed to re-parse: {}", query, e));
+    assert_eq!(
+        &reparsed, filter,
+        "round trip through '{}' changed the AST",
+        query
+    );
+}
+
+#[test]
+fn test_to_query_string_round_trips_parsed_expressions() {
+    let inputs = [
+        "today",
+        "tomorrow",
+        "overdue",
+        "no date",
+        "7 days",
+        "Jan 15",
+        "december 25",
+        "p1 | p2 | p3 | p4",
+        "@work",
+        "no labels",
+        "#Inbox",
+        "##Work",
+        "/Backlog",
+        "assigned",
+        "no assignee",
+        "assigned to: me",
+        "assigned by: others",
+        "assigned to: Alice Smith",
+        "today & p1",
+        "today | overdue & p1",
+        "(today | overdue) & p1",
+        "!(today | overdue)",
+        "!!no date",
+        "!today & !@waiting",
+        "(p1 | p2) & (#Work | ##Personal) & !/Someday",
+        "@\"deep work\" | #'Home Office'",
+        "(assigned to: Bob) | today",
+        "filter:\"Deep Work\" & @work",
+    ];
+
+    for input in inputs {
+        let filter = FilterParser::parse(input).unwrap();
+        assert_round_trip(&filter);
+    }
+}
+
+#[test]
+fn test_to_query_string_preserves_associativity() {
+    // Right-nested trees need parentheses to survive left-associative parsing
+    let or_right = Filter::or(Filter::Today, Filter::or(Filter::Overdue, Filter::NoDate));
+    assert_eq!(or_right.to_query_string(), "today | (overdue | no date)");
+    assert_round_trip(&or_right);
+
+    let and_right = Filter::and(
+        Filter::Priority1,
+        Filter::and(Filter::Today, Filter::NoLabels),
+    );
+    assert_eq!(and_right.to_query_string(), "p1 & (today & no labels)");
+    assert_round_trip(&and_right);
+
+    let or_left = Filter::or(Filter::or(Filter::Today, Filter::Overdue), Filter::NoDate);
+    assert_eq!(or_left.to_query_string(), "today | overdue | no date");
+}
+
+#[test]
+fn test_to_query_string_parenthesizes_by_precedence() {
+    let filter = Filter::and(
+        Filter::or(Filter::Today, Filter::Overdue),
+        Filter::Priority1,
+    );
+    assert_eq!(filter.to_query_string(), "(today | overdue) & p1");
+
+    let filter = Filter::negate(Filter::and(Filter::Today, Filter::Priority1));
+    assert_eq!(filter.to_query_string(), "!(today & p1)");
+
+    let filter = Filter::or(
+        Filter::and(Filter::Today, Filter::Priority1),
+        Filter::Overdue,
+    );
+    assert_eq!(filter.to_query_string(), "today & p1 | overdue");
+}
+
+#[test]
+fn test_to_query_string_quotes_names() {
+    let cases = [
+        Filter::Label("deep work".to_string()),
+        Filter::Project("Q&A".to_string()),
+        Filter::Project("#hashtag".to_string()),
+        Filter::Section("Say \"hi\"".to_string()),
+        Filter::ProjectWithSubprojects("a (b)".to_string()),
+        Filter::AssignedTo(AssignedTarget::User("R&D team".to_string())),
+    ];
+
+    for filter in &cases {
+        assert_round_trip(filter);
+    }
+
+    assert_eq!(
+        Filter::Label("deep work".to_string()).to_query_string(),
+        "@\"deep work\""
+    );
+    assert_eq!(Filter::Label("work".to_string()).to_query_string(), "@work");
+}
+
+#[test]
+fn test_filter_display_matches_to_query_string() {
+    let filter = FilterParser::parse("today & (@a | @b)").unwrap();
+    assert_eq!(format!("{}", filter), filter.to_query_string());
+}
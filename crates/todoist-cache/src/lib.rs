@@ -34,16 +34,18 @@ mod merge;
 mod store;
 mod sync_manager;
 
-pub use store::{CacheStore, CacheStoreError, Result as CacheStoreResult};
-pub use sync_manager::{Result as SyncResult, SyncError, SyncManager};
+pub use store::{CacheMetadata, CacheStore, CacheStoreError, Result as CacheStoreResult};
+pub use sync_manager::{
+    Result as SyncResult, SyncError, SyncEvent, SyncEventSink, SyncManager, SYNC_RESOURCE_TYPES,
+};
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use todoist_api_rs::sync::{
-    Collaborator, CollaboratorState, Filter, Item, Label, Note, Project, ProjectNote, Reminder,
-    Section, User,
+    Collaborator, CollaboratorState, CompletedInfo, Filter, Item, Label, Note, Project,
+    ProjectNote, Reminder, Section, User,
 };
 
 /// Indexes for O(1) cache lookups.
@@ -161,6 +163,10 @@ pub struct Cache {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user: Option<User>,
 
+    /// Latest completed task counts for projects, sections, and parent tasks.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub completed_info: Vec<CompletedInfo>,
+
     /// Indexes for fast lookups (rebuilt on sync, not serialized).
     #[serde(skip)]
     indexes: CacheIndexes,
@@ -190,6 +196,7 @@ impl Cache {
             collaborators: Vec::new(),
             collaborator_states: Vec::new(),
             user: None,
+            completed_info: Vec::new(),
             indexes: CacheIndexes::default(),
         }
     }
@@ -228,6 +235,7 @@ impl Cache {
             collaborators: Vec::new(),
             collaborator_states: Vec::new(),
             user,
+            completed_info: Vec::new(),
             indexes: CacheIndexes::default(),
         };
         cache.rebuild_indexes();
@@ -330,6 +338,22 @@ impl Cache {
         None
     }
 
+    /// Returns the user's Inbox project.
+    ///
+    /// Checks, in order: a project flagged `inbox_project`, the project named
+    /// by `user.inbox_project_id`, and finally a project named exactly
+    /// "Inbox". Deleted projects are never returned.
+    pub fn inbox_project(&self) -> Option<&Project> {
+        let live = || self.projects.iter().filter(|p| !p.is_deleted);
+        live()
+            .find(|p| p.inbox_project)
+            .or_else(|| {
+                let id = self.user.as_ref()?.inbox_project_id.as_deref()?;
+                live().find(|p| p.id == id)
+            })
+            .or_else(|| live().find(|p| p.name == "Inbox"))
+    }
+
     /// Find a section by ID or name (case-insensitive) within a project. O(1) lookup.
     ///
     /// If `project_id` is provided, returns the section only if it belongs to that project.
@@ -387,6 +411,164 @@ impl Cache {
         None
     }
 
+    /// Returns the direct subtasks of an item, ordered by `child_order`.
+    ///
+    /// Deleted items are skipped; completed ones are included.
+    pub fn children_of(&self, id: &str) -> Vec<&Item> {
+        let mut children: Vec<&Item> = self
+            .items
+            .iter()
+            .filter(|i| !i.is_deleted && i.parent_id.as_deref() == Some(id))
+            .collect();
+        children.sort_by_key(|i| i.child_order);
+        children
+    }
+
+    /// Returns the items in a project, in cache order.
+    ///
+    /// With `include_subprojects`, items in every project nested below it
+    /// are included too. Deleted items are skipped; completed ones are
+    /// returned, so callers filter on `checked` as needed.
+    pub fn items_in_project(&self, project_id: &str, include_subprojects: bool) -> Vec<&Item> {
+        let mut project_ids: HashSet<&str> = HashSet::from([project_id]);
+        if include_subprojects {
+            // Expand level by level until no new subprojects turn up; the
+            // set also guards against parent cycles in malformed data
+            let mut frontier = vec![project_id];
+            while let Some(parent) = frontier.pop() {
+                for project in &self.projects {
+                    if !project.is_deleted
+                        && project.parent_id.as_deref() == Some(parent)
+                        && project_ids.insert(&project.id)
+                    {
+                        frontier.push(&project.id);
+                    }
+                }
+            }
+        }
+
+        self.items
+            .iter()
+            .filter(|i| !i.is_deleted && project_ids.contains(i.project_id.as_str()))
+            .collect()
+    }
+
+    /// Returns the sections in a project, in cache order.
+    ///
+    /// Deleted sections are skipped; archived ones only with `include_archived`.
+    pub fn sections_in_project(&self, project_id: &str, include_archived: bool) -> Vec<&Section> {
+        self.sections
+            .iter()
+            .filter(|s| s.project_id == project_id && !s.is_deleted)
+            .filter(|s| include_archived || !s.is_archived)
+            .collect()
+    }
+
+    /// Returns the completed task count last reported by the server for a
+    /// project, or `None` if no count has been received.
+    pub fn project_completed_count(&self, project_id: &str) -> Option<u32> {
+        self.completed_info
+            .iter()
+            .find(|info| info.project_id.as_deref() == Some(project_id))
+            .map(|info| info.completed_items)
+    }
+
+    /// Returns a breadcrumb for an item, e.g. `"Work / Backend / In Progress"`.
+    ///
+    /// Walks the item's project up its parent chain (root first) and appends
+    /// the section name. Projects or sections missing from the cache are
+    /// skipped, so an item whose project isn't cached yields only its section,
+    /// or an empty string.
+    pub fn breadcrumb(&self, item: &Item) -> String {
+        let mut parts: Vec<&str> = Vec::new();
+
+        let mut project_id = Some(item.project_id.as_str());
+        while let Some(id) = project_id {
+            // Guard against parent cycles in malformed data
+            if parts.len() > self.projects.len() {
+                break;
+            }
+            let Some(project) = self
+                .indexes
+                .projects_by_id
+                .get(id)
+                .and_then(|&idx| self.projects.get(idx))
+            else {
+                break;
+            };
+            parts.push(&project.name);
+            project_id = project.parent_id.as_deref();
+        }
+        parts.reverse();
+
+        if let Some(section) = item
+            .section_id
+            .as_deref()
+            .and_then(|id| self.indexes.sections_by_id.get(id))
+            .and_then(|&idx| self.sections.get(idx))
+        {
+            parts.push(&section.name);
+        }
+
+        parts.join(" / ")
+    }
+
+    /// Groups active items that look like duplicates of each other.
+    ///
+    /// Items are duplicates when they're in the same project under the same
+    /// parent and their content matches after trimming and case folding.
+    /// Items that have subtasks are never grouped, since deleting one would
+    /// take its subtasks with it. Only groups with two or more items are
+    /// returned, in order of first appearance; items within a group keep
+    /// their cache order. Completed and deleted items are ignored.
+    pub fn find_duplicates(&self) -> Vec<Vec<&Item>> {
+        let parents: HashSet<&str> = self
+            .items
+            .iter()
+            .filter(|i| !i.is_deleted)
+            .filter_map(|i| i.parent_id.as_deref())
+            .collect();
+        let mut group_index: HashMap<(&str, Option<&str>, String), usize> = HashMap::new();
+        let mut groups: Vec<Vec<&Item>> = Vec::new();
+
+        for item in self
+            .items
+            .iter()
+            .filter(|i| !i.is_deleted && !i.checked && !parents.contains(i.id.as_str()))
+        {
+            let key = (
+                item.project_id.as_str(),
+                item.parent_id.as_deref(),
+                item.content.trim().to_lowercase(),
+            );
+            match group_index.get(&key) {
+                Some(&idx) => groups[idx].push(item),
+                None => {
+                    group_index.insert(key, groups.len());
+                    groups.push(vec![item]);
+                }
+            }
+        }
+
+        groups.retain(|group| group.len() > 1);
+        groups
+    }
+
+    /// Removes archived projects and sections from the cache.
+    ///
+    /// The sync token and all other resources are left untouched. Returns
+    /// the number of projects and sections removed.
+    pub fn prune_archived(&mut self) -> usize {
+        let before = self.projects.len() + self.sections.len();
+        self.projects.retain(|p| !p.is_archived);
+        self.sections.retain(|s| !s.is_archived);
+        let pruned = before - (self.projects.len() + self.sections.len());
+        if pruned > 0 {
+            self.rebuild_indexes();
+        }
+        pruned
+    }
+
     /// Returns true if the cache has never been synced (sync_token is "*").
     pub fn is_empty(&self) -> bool {
         self.sync_token == "*"
@@ -398,6 +580,28 @@ impl Cache {
         self.sync_token == "*"
     }
 
+    /// Marks the cache as stale by clearing `last_sync`.
+    ///
+    /// The next staleness check will report the cache as stale regardless of
+    /// the configured threshold. The sync token is kept, so the following sync
+    /// is still incremental.
+    pub fn mark_stale(&mut self) {
+        self.last_sync = None;
+    }
+
+    /// Marks the cache as freshly synced at `now`.
+    pub fn mark_fresh(&mut self, now: DateTime<Utc>) {
+        self.last_sync = Some(now);
+    }
+
+    /// Returns how long ago the cache was last synced, or `None` if never.
+    ///
+    /// A `last_sync` in the future (clock skew) counts as zero.
+    pub fn last_sync_age(&self, now: DateTime<Utc>) -> Option<chrono::Duration> {
+        self.last_sync
+            .map(|at| (now - at).max(chrono::Duration::zero()))
+    }
+
     /// Applies a sync response to the cache, merging in changes.
     ///
     /// This method handles both full and incremental sync responses:
@@ -435,6 +639,93 @@ impl Cache {
     pub fn apply_mutation_response(&mut self, response: &todoist_api_rs::sync::SyncResponse) {
         merge::apply_mutation_response(self, response);
     }
+
+    /// Applies a selective sync response that only covers `resource_types`.
+    ///
+    /// Collections for the listed resource types are replaced wholesale; the
+    /// sync token and everything else in the cache are left as-is.
+    pub fn apply_resource_sync_response(
+        &mut self,
+        response: &todoist_api_rs::sync::SyncResponse,
+        resource_types: &[String],
+    ) {
+        merge::apply_resource_sync_response(self, response, resource_types);
+    }
+
+    /// Merges tasks into the cache by ID, as an incremental sync would.
+    ///
+    /// New tasks are added, existing ones replaced, and tasks with
+    /// `is_deleted: true` removed. The sync token is left as-is. Returns the
+    /// number of tasks added, changed or removed.
+    pub fn apply_items(&mut self, items: &[Item]) -> usize {
+        let changed = merge::merge_resources(&mut self.items, items, |i| &i.id, |i| i.is_deleted);
+        self.rebuild_indexes_if(changed)
+    }
+
+    /// Merges projects into the cache by ID. See [`Cache::apply_items`].
+    pub fn apply_projects(&mut self, projects: &[Project]) -> usize {
+        let changed =
+            merge::merge_resources(&mut self.projects, projects, |p| &p.id, |p| p.is_deleted);
+        self.rebuild_indexes_if(changed)
+    }
+
+    /// Merges labels into the cache by ID. See [`Cache::apply_items`].
+    pub fn apply_labels(&mut self, labels: &[Label]) -> usize {
+        let changed = merge::merge_resources(&mut self.labels, labels, |l| &l.id, |l| l.is_deleted);
+        self.rebuild_indexes_if(changed)
+    }
+
+    /// Merges sections into the cache by ID. See [`Cache::apply_items`].
+    pub fn apply_sections(&mut self, sections: &[Section]) -> usize {
+        let changed =
+            merge::merge_resources(&mut self.sections, sections, |s| &s.id, |s| s.is_deleted);
+        self.rebuild_indexes_if(changed)
+    }
+
+    /// Merges task comments into the cache by ID. See [`Cache::apply_items`].
+    pub fn apply_notes(&mut self, notes: &[Note]) -> usize {
+        let changed = merge::merge_resources(&mut self.notes, notes, |n| &n.id, |n| n.is_deleted);
+        self.rebuild_indexes_if(changed)
+    }
+
+    /// Merges project comments into the cache by ID. See [`Cache::apply_items`].
+    pub fn apply_project_notes(&mut self, notes: &[ProjectNote]) -> usize {
+        let changed =
+            merge::merge_resources(&mut self.project_notes, notes, |n| &n.id, |n| n.is_deleted);
+        self.rebuild_indexes_if(changed)
+    }
+
+    /// Merges reminders into the cache by ID. See [`Cache::apply_items`].
+    pub fn apply_reminders(&mut self, reminders: &[Reminder]) -> usize {
+        let changed =
+            merge::merge_resources(&mut self.reminders, reminders, |r| &r.id, |r| r.is_deleted);
+        self.rebuild_indexes_if(changed)
+    }
+
+    /// Merges saved filters into the cache by ID. See [`Cache::apply_items`].
+    pub fn apply_filters(&mut self, filters: &[Filter]) -> usize {
+        let changed =
+            merge::merge_resources(&mut self.filters, filters, |f| &f.id, |f| f.is_deleted);
+        self.rebuild_indexes_if(changed)
+    }
+
+    /// Merges collaborators into the cache by ID.
+    ///
+    /// Collaborators carry no `is_deleted` flag, so they are only ever added
+    /// or replaced, matching the incremental sync path.
+    pub fn apply_collaborators(&mut self, collaborators: &[Collaborator]) -> usize {
+        let changed =
+            merge::merge_resources(&mut self.collaborators, collaborators, |c| &c.id, |_| false);
+        self.rebuild_indexes_if(changed)
+    }
+
+    /// Rebuilds the indexes when `changed` is non-zero, and returns it.
+    fn rebuild_indexes_if(&mut self, changed: usize) -> usize {
+        if changed > 0 {
+            self.rebuild_indexes();
+        }
+        changed
+    }
 }
 
 #[cfg(test)]
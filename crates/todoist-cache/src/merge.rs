@@ -6,7 +6,7 @@
 use std::collections::HashMap;
 
 use chrono::Utc;
-use todoist_api_rs::sync::{CollaboratorState, SyncResponse};
+use todoist_api_rs::sync::{CollaboratorState, CompletedInfo, SyncResponse};
 
 use crate::Cache;
 
@@ -23,7 +23,7 @@ pub(crate) fn apply_sync_response(cache: &mut Cache, response: &SyncResponse) {
 
     // Update sync token
     cache.sync_token = response.sync_token.clone();
-    cache.last_sync = Some(now);
+    cache.mark_fresh(now);
 
     // If this is a full sync, update full_sync_date_utc
     if response.full_sync {
@@ -36,7 +36,7 @@ pub(crate) fn apply_sync_response(cache: &mut Cache, response: &SyncResponse) {
             .or(Some(now));
     }
 
-    if response.full_sync {
+    let indexed_data_changed = if response.full_sync {
         // Full sync: replace all data (filter out deleted items)
         cache.items = response
             .items
@@ -93,75 +93,126 @@ pub(crate) fn apply_sync_response(cache: &mut Cache, response: &SyncResponse) {
             .filter(|state| state.state != "deleted")
             .cloned()
             .collect();
+        cache.completed_info = response.completed_info.clone();
+        true
     } else {
         // Incremental sync: merge changes
-        merge_resources(
-            &mut cache.items,
-            &response.items,
-            |i| &i.id,
-            |i| i.is_deleted,
-        );
-        merge_resources(
-            &mut cache.projects,
-            &response.projects,
-            |p| &p.id,
-            |p| p.is_deleted,
-        );
-        merge_resources(
-            &mut cache.labels,
-            &response.labels,
-            |l| &l.id,
-            |l| l.is_deleted,
-        );
-        merge_resources(
-            &mut cache.sections,
-            &response.sections,
-            |s| &s.id,
-            |s| s.is_deleted,
-        );
-        merge_resources(
-            &mut cache.notes,
-            &response.notes,
-            |n| &n.id,
-            |n| n.is_deleted,
-        );
-        merge_resources(
-            &mut cache.project_notes,
-            &response.project_notes,
-            |n| &n.id,
-            |n| n.is_deleted,
-        );
-        merge_resources(
-            &mut cache.reminders,
-            &response.reminders,
-            |r| &r.id,
-            |r| r.is_deleted,
-        );
-        merge_resources(
-            &mut cache.filters,
-            &response.filters,
-            |f| &f.id,
-            |f| f.is_deleted,
-        );
-        merge_resources(
-            &mut cache.collaborators,
-            &response.collaborators,
-            |c| &c.id,
-            |_| false,
-        );
-        merge_collaborator_states(
-            &mut cache.collaborator_states,
-            &response.collaborator_states,
-        );
-    }
+        merge_incremental(cache, response) > 0
+    };
 
     // User is always replaced if present in response
     if response.user.is_some() {
         cache.user = response.user.clone();
     }
 
-    // Rebuild indexes after applying changes
-    cache.rebuild_indexes();
+    // Rebuild indexes only if the data they cover changed
+    if indexed_data_changed {
+        cache.rebuild_indexes();
+    }
+}
+
+/// Applies a selective (resource-scoped) full sync response to the cache.
+///
+/// Only the collections named in `resource_types` are replaced; all other
+/// cached data is left untouched. The sync token and full sync date are
+/// also preserved, so the next incremental sync still covers every resource
+/// type from the last complete sync point.
+pub(crate) fn apply_resource_sync_response(
+    cache: &mut Cache,
+    response: &SyncResponse,
+    resource_types: &[String],
+) {
+    for resource_type in resource_types {
+        match resource_type.as_str() {
+            "items" => {
+                cache.items = response
+                    .items
+                    .iter()
+                    .filter(|i| !i.is_deleted)
+                    .cloned()
+                    .collect();
+            }
+            "projects" => {
+                cache.projects = response
+                    .projects
+                    .iter()
+                    .filter(|p| !p.is_deleted)
+                    .cloned()
+                    .collect();
+            }
+            "labels" => {
+                cache.labels = response
+                    .labels
+                    .iter()
+                    .filter(|l| !l.is_deleted)
+                    .cloned()
+                    .collect();
+            }
+            "sections" => {
+                cache.sections = response
+                    .sections
+                    .iter()
+                    .filter(|s| !s.is_deleted)
+                    .cloned()
+                    .collect();
+            }
+            "notes" => {
+                cache.notes = response
+                    .notes
+                    .iter()
+                    .filter(|n| !n.is_deleted)
+                    .cloned()
+                    .collect();
+            }
+            "project_notes" => {
+                cache.project_notes = response
+                    .project_notes
+                    .iter()
+                    .filter(|n| !n.is_deleted)
+                    .cloned()
+                    .collect();
+            }
+            "reminders" => {
+                cache.reminders = response
+                    .reminders
+                    .iter()
+                    .filter(|r| !r.is_deleted)
+                    .cloned()
+                    .collect();
+            }
+            "filters" => {
+                cache.filters = response
+                    .filters
+                    .iter()
+                    .filter(|f| !f.is_deleted)
+                    .cloned()
+                    .collect();
+            }
+            "collaborators" => {
+                cache.collaborators = response.collaborators.clone();
+                cache.collaborator_states = response
+                    .collaborator_states
+                    .iter()
+                    .filter(|state| state.state != "deleted")
+                    .cloned()
+                    .collect();
+            }
+            "user" if response.user.is_some() => {
+                cache.user = response.user.clone();
+            }
+            _ => {}
+        }
+    }
+
+    // Notes, reminders, filters and the user aren't indexed
+    if resource_types.iter().any(|t| {
+        matches!(
+            t.as_str(),
+            "items" | "projects" | "labels" | "sections" | "collaborators"
+        )
+    }) {
+        cache.rebuild_indexes();
+    }
 }
 
 /// Applies a mutation response to the cache.
@@ -183,87 +234,102 @@ pub(crate) fn apply_mutation_response(cache: &mut Cache, response: &SyncResponse
 
     // Update sync token - critical for subsequent syncs
     cache.sync_token = response.sync_token.clone();
-    cache.last_sync = Some(now);
+    cache.mark_fresh(now);
 
     // Merge resources using incremental logic (mutations never do full sync)
     // Even if the response has full_sync: true, we treat it as incremental
     // because we're only applying the affected resources from a mutation
-    merge_resources(
+    let changed = merge_incremental(cache, response);
+
+    // User is replaced if present in response
+    if response.user.is_some() {
+        cache.user = response.user.clone();
+    }
+
+    // Rebuild indexes only if the data they cover changed
+    if changed > 0 {
+        cache.rebuild_indexes();
+    }
+}
+
+/// Merges every resource list in `response` into the cache, add/update/delete
+/// by ID.
+///
+/// Returns how many resources were added, replaced, or removed; 0 means the
+/// cache's indexed data is unchanged.
+fn merge_incremental(cache: &mut Cache, response: &SyncResponse) -> usize {
+    let mut changed = 0;
+    changed += merge_resources(
         &mut cache.items,
         &response.items,
         |i| &i.id,
         |i| i.is_deleted,
     );
-    merge_resources(
+    changed += merge_resources(
         &mut cache.projects,
         &response.projects,
         |p| &p.id,
         |p| p.is_deleted,
     );
-    merge_resources(
+    changed += merge_resources(
         &mut cache.labels,
         &response.labels,
         |l| &l.id,
         |l| l.is_deleted,
     );
-    merge_resources(
+    changed += merge_resources(
         &mut cache.sections,
         &response.sections,
         |s| &s.id,
         |s| s.is_deleted,
     );
-    merge_resources(
+    changed += merge_resources(
         &mut cache.notes,
         &response.notes,
         |n| &n.id,
         |n| n.is_deleted,
     );
-    merge_resources(
+    changed += merge_resources(
         &mut cache.project_notes,
         &response.project_notes,
         |n| &n.id,
         |n| n.is_deleted,
     );
-    merge_resources(
+    changed += merge_resources(
         &mut cache.reminders,
         &response.reminders,
         |r| &r.id,
         |r| r.is_deleted,
     );
-    merge_resources(
+    changed += merge_resources(
         &mut cache.filters,
         &response.filters,
         |f| &f.id,
         |f| f.is_deleted,
     );
-    merge_resources(
+    changed += merge_resources(
         &mut cache.collaborators,
         &response.collaborators,
         |c| &c.id,
         |_| false,
     );
-    merge_collaborator_states(
+    changed += merge_collaborator_states(
         &mut cache.collaborator_states,
         &response.collaborator_states,
     );
-
-    // User is replaced if present in response
-    if response.user.is_some() {
-        cache.user = response.user.clone();
-    }
-
-    // Rebuild indexes after applying changes
-    cache.rebuild_indexes();
+    merge_completed_info(&mut cache.completed_info, &response.completed_info);
+    changed
 }
 
 /// Merges collaborator state updates into the cache.
 ///
 /// Collaborator states are uniquely identified by `(project_id, user_id)`.
-/// A state value of `"deleted"` removes the corresponding entry.
+/// A state value of `"deleted"` removes the corresponding entry. Returns
+/// the number of entries added, replaced, or removed.
 fn merge_collaborator_states(
     existing: &mut Vec<CollaboratorState>,
     incoming: &[CollaboratorState],
-) {
+) -> usize {
     let mut index: HashMap<(&str, &str), usize> = HashMap::with_capacity(existing.len());
     for (i, state) in existing.iter().enumerate() {
         index.insert((state.project_id.as_str(), state.user_id.as_str()), i);
@@ -282,12 +348,16 @@ fn merge_collaborator_states(
                 to_remove.push(idx);
             }
         } else if let Some(idx) = pos {
-            updates.push((idx, state));
+            if existing[idx] != *state {
+                updates.push((idx, state));
+            }
         } else {
             inserts.push(state);
         }
     }
 
+    let changed = updates.len() + inserts.len() + to_remove.len();
+
     for (idx, state) in updates {
         existing[idx] = state.clone();
     }
@@ -299,15 +369,33 @@ fn merge_collaborator_states(
     for idx in to_remove.into_iter().rev() {
         existing.remove(idx);
     }
+    changed
+}
+
+/// Merges completed counts into the cache.
+///
+/// Entries are identified by their project, section, or task ID; an incoming
+/// entry replaces the stored one for the same target.
+fn merge_completed_info(existing: &mut Vec<CompletedInfo>, incoming: &[CompletedInfo]) {
+    for info in incoming {
+        match existing.iter_mut().find(|e| e.same_target(info)) {
+            Some(entry) => *entry = info.clone(),
+            None => existing.push(info.clone()),
+        }
+    }
 }
 
 /// Merges a list of resources from a sync response into the cache.
 ///
 /// For each resource in the response:
 /// - If `is_deleted` is true: remove from cache
-/// - If resource exists in cache: update it
+/// - If resource exists in cache: update it, unless it is unchanged
 /// - Otherwise: add it
 ///
+/// Returns the number of resources that were added, replaced, or removed.
+/// Incoming resources equal to the cached copy are skipped, so a redundant
+/// sync returns 0.
+///
 /// Uses a two-phase approach to avoid double cloning of ID strings:
 /// 1. Build index using borrowed references (no cloning)
 /// 2. Categorize incoming items into updates, inserts, and deletions
@@ -320,8 +408,9 @@ pub(crate) fn merge_resources<T, F, D>(
     incoming: &[T],
     get_id: F,
     is_deleted: D,
-) where
-    T: Clone,
+) -> usize
+where
+    T: Clone + PartialEq,
     F: Fn(&T) -> &str,
     D: Fn(&T) -> bool,
 {
@@ -348,14 +437,18 @@ pub(crate) fn merge_resources<T, F, D>(
                 to_remove.push(idx);
             }
         } else if let Some(idx) = pos {
-            // Update existing
-            updates.push((idx, item));
+            // Update existing, skipping identical copies
+            if existing[idx] != *item {
+                updates.push((idx, item));
+            }
         } else {
             // New item
             inserts.push(item);
         }
     }
 
+    let changed = updates.len() + inserts.len() + to_remove.len();
+
     // Phase 3: Apply updates (vec length unchanged)
     for (idx, item) in updates {
         existing[idx] = item.clone();
@@ -370,4 +463,6 @@ pub(crate) fn merge_resources<T, F, D>(
     for idx in to_remove.into_iter().rev() {
         existing.remove(idx);
     }
+
+    changed
 }
@@ -3,6 +3,13 @@
 //! This module provides persistent storage for the Todoist cache using XDG-compliant
 //! paths. The cache is stored as JSON at `~/.cache/td/cache.json`.
 //!
+//! The cache is written as compact JSON by default. Pretty-printed output can
+//! be enabled with [`CacheStore::with_pretty`] for users who keep the cache
+//! under version control or inspect it by hand. Loading accepts either form.
+//! [`CacheStore::with_sorted`] additionally writes every resource list in ID
+//! order, so two saves of the same data produce identical files regardless of
+//! the order the API returned them in.
+//!
 //! Both synchronous and asynchronous I/O methods are provided:
 //! - `save()`, `load()` - Synchronous methods using `std::fs`
 //! - `save_async()`, `load_async()` - Asynchronous methods using `tokio::fs`
@@ -14,7 +21,10 @@ use std::fs;
 use std::io;
 use std::path::PathBuf;
 
+use chrono::{DateTime, Utc};
 use directories::ProjectDirs;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
 use thiserror::Error;
 
 use crate::Cache;
@@ -22,6 +32,9 @@ use crate::Cache;
 /// Default cache filename.
 const CACHE_FILENAME: &str = "cache.json";
 
+/// Directory holding per-profile cache files.
+const PROFILES_DIRNAME: &str = "profiles";
+
 /// Application qualifier (for XDG paths).
 const QUALIFIER: &str = "";
 
@@ -78,14 +91,58 @@ pub enum CacheStoreError {
         source: io::Error,
     },
 
-    /// JSON serialization/deserialization error.
-    #[error("JSON error: {0}")]
-    Json(#[from] serde_json::Error),
+    /// The cache file was read but isn't a valid cache (corrupt or from an
+    /// incompatible version).
+    #[error("JSON error in cache file '{path}': {source}")]
+    Deserialize {
+        /// The path that was read.
+        path: PathBuf,
+        /// The underlying JSON error.
+        #[source]
+        source: serde_json::Error,
+    },
+
+    /// The in-memory cache could not be serialized.
+    #[error("JSON error serializing cache: {0}")]
+    Serialize(#[source] serde_json::Error),
+}
+
+impl CacheStoreError {
+    /// Returns true if the cache file exists but its contents can't be
+    /// parsed, so discarding it and syncing again would recover.
+    ///
+    /// I/O failures (permissions, a full disk, ...) are not corruption and
+    /// return false.
+    pub fn is_corrupt(&self) -> bool {
+        matches!(self, CacheStoreError::Deserialize { .. })
+    }
 }
 
 /// Result type for cache store operations.
 pub type Result<T> = std::result::Result<T, CacheStoreError>;
 
+/// Sync state of a cache file, read without deserializing its resources.
+///
+/// Two metadata values differ when the file was rewritten after a sync, which
+/// lets a long-lived reader notice that another process updated the cache.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct CacheMetadata {
+    /// The stored sync token.
+    pub sync_token: String,
+    /// UTC timestamp of the last successful sync.
+    #[serde(default)]
+    pub last_sync: Option<DateTime<Utc>>,
+}
+
+impl From<&Cache> for CacheMetadata {
+    fn from(cache: &Cache) -> Self {
+        Self {
+            sync_token: cache.sync_token.clone(),
+            last_sync: cache.last_sync,
+        }
+    }
+}
+
 /// Persistent storage for the Todoist cache.
 ///
 /// `CacheStore` handles reading and writing the cache to disk using XDG-compliant
@@ -126,6 +183,10 @@ pub type Result<T> = std::result::Result<T, CacheStoreError>;
 pub struct CacheStore {
     /// Path to the cache file.
     path: PathBuf,
+    /// Whether to write pretty-printed JSON.
+    pretty: bool,
+    /// Whether to sort resource lists by ID before writing.
+    sorted: bool,
 }
 
 impl CacheStore {
@@ -138,14 +199,113 @@ impl CacheStore {
     /// Returns `CacheStoreError::NoCacheDir` if the home directory cannot be determined.
     pub fn new() -> Result<Self> {
         let path = Self::default_path()?;
-        Ok(Self { path })
+        Ok(Self {
+            path,
+            pretty: false,
+            sorted: false,
+        })
+    }
+
+    /// Creates a new `CacheStore` at the default path for `profile`.
+    ///
+    /// Each profile gets its own cache file (see [`Self::default_path_for`]),
+    /// so separate accounts never share synced data. `None` is the same as
+    /// [`Self::new`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `CacheStoreError::NoCacheDir` if the home directory cannot be determined.
+    pub fn for_profile(profile: Option<&str>) -> Result<Self> {
+        Ok(Self::with_path(Self::default_path_for(profile)?))
     }
 
     /// Creates a new `CacheStore` with a custom path.
     ///
     /// This is primarily useful for testing.
     pub fn with_path(path: PathBuf) -> Self {
-        Self { path }
+        Self {
+            path,
+            pretty: false,
+            sorted: false,
+        }
+    }
+
+    /// Sets whether the cache is saved as pretty-printed JSON.
+    ///
+    /// Compact JSON (the default) produces noticeably smaller files. Pretty
+    /// output is easier to read and diff.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use todoist_cache_rs::CacheStore;
+    ///
+    /// let store = CacheStore::new()?.with_pretty(true);
+    /// assert!(store.is_pretty());
+    /// # Ok::<(), todoist_cache_rs::CacheStoreError>(())
+    /// ```
+    pub fn with_pretty(mut self, pretty: bool) -> Self {
+        self.pretty = pretty;
+        self
+    }
+
+    /// Returns true if the cache is saved as pretty-printed JSON.
+    pub fn is_pretty(&self) -> bool {
+        self.pretty
+    }
+
+    /// Sets whether resource lists are sorted by ID when the cache is saved.
+    ///
+    /// Sorting is applied to a copy, so the in-memory cache keeps its order.
+    /// Combined with [`with_pretty`](Self::with_pretty), this gives stable,
+    /// line-oriented diffs between syncs.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use todoist_cache_rs::CacheStore;
+    ///
+    /// let store = CacheStore::new()?.with_sorted(true);
+    /// assert!(store.is_sorted());
+    /// # Ok::<(), todoist_cache_rs::CacheStoreError>(())
+    /// ```
+    pub fn with_sorted(mut self, sorted: bool) -> Self {
+        self.sorted = sorted;
+        self
+    }
+
+    /// Returns true if resource lists are sorted by ID when saved.
+    pub fn is_sorted(&self) -> bool {
+        self.sorted
+    }
+
+    /// Serializes the cache using the configured JSON format.
+    fn serialize(&self, cache: &Cache) -> Result<String> {
+        if self.sorted {
+            let mut sorted = cache.clone();
+            sort_resources(&mut sorted);
+            self.serialize_json(&sorted)
+        } else {
+            self.serialize_json(cache)
+        }
+    }
+
+    /// Writes the cache as pretty or compact JSON.
+    fn serialize_json(&self, cache: &Cache) -> Result<String> {
+        if self.pretty {
+            serde_json::to_string_pretty(cache)
+        } else {
+            serde_json::to_string(cache)
+        }
+        .map_err(CacheStoreError::Serialize)
+    }
+
+    /// Parses the contents of the cache file.
+    fn deserialize<T: DeserializeOwned>(&self, contents: &str) -> Result<T> {
+        serde_json::from_str(contents).map_err(|e| CacheStoreError::Deserialize {
+            path: self.path.clone(),
+            source: e,
+        })
     }
 
     /// Returns the default XDG cache path for the cache file.
@@ -165,6 +325,25 @@ impl CacheStore {
         Ok(cache_dir.join(CACHE_FILENAME))
     }
 
+    /// Returns the default cache path for `profile`.
+    ///
+    /// Profiles are kept under `profiles/<profile>/cache.json` next to the
+    /// default cache file; without a profile this is [`Self::default_path`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `CacheStoreError::NoCacheDir` if the home directory cannot be determined.
+    pub fn default_path_for(profile: Option<&str>) -> Result<PathBuf> {
+        let path = Self::default_path()?;
+        Ok(match profile {
+            Some(profile) => path
+                .with_file_name(PROFILES_DIRNAME)
+                .join(profile)
+                .join(CACHE_FILENAME),
+            None => path,
+        })
+    }
+
     /// Returns the path to the cache file.
     pub fn path(&self) -> &PathBuf {
         &self.path
@@ -175,7 +354,7 @@ impl CacheStore {
     /// # Errors
     ///
     /// - Returns `CacheStoreError::ReadError` if the file cannot be read.
-    /// - Returns `CacheStoreError::Json` if the file contains invalid JSON.
+    /// - Returns `CacheStoreError::Deserialize` if the file isn't a valid cache.
     ///
     /// # Note
     ///
@@ -187,34 +366,68 @@ impl CacheStore {
             path: self.path.clone(),
             source: e,
         })?;
-        let mut cache: Cache = serde_json::from_str(&contents)?;
+        let mut cache: Cache = self.deserialize(&contents)?;
         // Rebuild indexes since they are not serialized
         cache.rebuild_indexes();
         Ok(cache)
     }
 
-    /// Loads the cache from disk, returning a default cache if the file doesn't exist.
+    /// Loads the cache from disk, returning `None` if the file doesn't exist.
+    ///
+    /// Unlike [`load_or_default()`](Self::load_or_default), this lets callers
+    /// tell a missing cache apart from an empty one.
     ///
     /// # Errors
     ///
     /// - Returns `CacheStoreError::ReadError` for I/O errors other than "file not found".
-    /// - Returns `CacheStoreError::Json` if the file contains invalid JSON.
-    pub fn load_or_default(&self) -> Result<Cache> {
+    /// - Returns `CacheStoreError::Deserialize` if the file isn't a valid cache.
+    pub fn try_load(&self) -> Result<Option<Cache>> {
         match self.load() {
-            Ok(cache) => Ok(cache),
+            Ok(cache) => Ok(Some(cache)),
             Err(CacheStoreError::ReadError { ref source, .. })
                 if source.kind() == io::ErrorKind::NotFound =>
             {
-                Ok(Cache::default())
+                Ok(None)
             }
             Err(e) => Err(e),
         }
     }
 
+    /// Loads the cache from disk, returning a default cache if the file doesn't exist.
+    ///
+    /// # Errors
+    ///
+    /// - Returns `CacheStoreError::ReadError` for I/O errors other than "file not found".
+    /// - Returns `CacheStoreError::Deserialize` if the file isn't a valid cache.
+    pub fn load_or_default(&self) -> Result<Cache> {
+        Ok(self.try_load()?.unwrap_or_default())
+    }
+
+    /// Reads only the sync metadata of the cache file.
+    ///
+    /// Resource arrays are skipped rather than deserialized, so this is much
+    /// cheaper than [`load()`](Self::load). Returns `None` if the file doesn't exist.
+    ///
+    /// # Errors
+    ///
+    /// - Returns `CacheStoreError::ReadError` for I/O errors other than "file not found".
+    /// - Returns `CacheStoreError::Deserialize` if the file isn't a valid cache.
+    pub fn load_metadata(&self) -> Result<Option<CacheMetadata>> {
+        match fs::read_to_string(&self.path) {
+            Ok(contents) => Ok(Some(self.deserialize(&contents)?)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(CacheStoreError::ReadError {
+                path: self.path.clone(),
+                source: e,
+            }),
+        }
+    }
+
     /// Saves the cache to disk atomically.
     ///
     /// Creates the parent directory if it doesn't exist. The cache is written
-    /// as pretty-printed JSON for easier debugging.
+    /// as compact JSON unless pretty output was enabled with
+    /// [`with_pretty()`](Self::with_pretty).
     ///
     /// Uses atomic write (tempfile + rename) to prevent corruption if the process
     /// crashes mid-write.
@@ -223,7 +436,7 @@ impl CacheStore {
     ///
     /// - Returns `CacheStoreError::CreateDirError` if the directory cannot be created.
     /// - Returns `CacheStoreError::WriteError` if the file cannot be written.
-    /// - Returns `CacheStoreError::Json` if serialization fails.
+    /// - Returns `CacheStoreError::Serialize` if serialization fails.
     pub fn save(&self, cache: &Cache) -> Result<()> {
         // Ensure parent directory exists
         if let Some(parent) = self.path.parent() {
@@ -233,7 +446,7 @@ impl CacheStore {
             })?;
         }
 
-        let json = serde_json::to_string_pretty(cache)?;
+        let json = self.serialize(cache)?;
 
         // Atomic write: write to temp file, then rename
         // This prevents corruption if the process crashes mid-write
@@ -284,7 +497,7 @@ impl CacheStore {
     /// # Errors
     ///
     /// - Returns `CacheStoreError::ReadError` if the file cannot be read.
-    /// - Returns `CacheStoreError::Json` if the file contains invalid JSON.
+    /// - Returns `CacheStoreError::Deserialize` if the file isn't a valid cache.
     ///
     /// # Note
     ///
@@ -298,39 +511,71 @@ impl CacheStore {
                 source: e,
             }
         })?;
-        let mut cache: Cache = serde_json::from_str(&contents)?;
+        let mut cache: Cache = self.deserialize(&contents)?;
         // Rebuild indexes since they are not serialized
         cache.rebuild_indexes();
         Ok(cache)
     }
 
-    /// Loads the cache from disk asynchronously, returning a default cache if the file doesn't exist.
+    /// Loads the cache from disk asynchronously, returning `None` if the file doesn't exist.
     ///
-    /// This is the async equivalent of [`load_or_default()`](Self::load_or_default).
+    /// This is the async equivalent of [`try_load()`](Self::try_load).
     ///
     /// # Errors
     ///
     /// - Returns `CacheStoreError::ReadError` for I/O errors other than "file not found".
-    /// - Returns `CacheStoreError::Json` if the file contains invalid JSON.
-    pub async fn load_or_default_async(&self) -> Result<Cache> {
+    /// - Returns `CacheStoreError::Deserialize` if the file isn't a valid cache.
+    pub async fn try_load_async(&self) -> Result<Option<Cache>> {
         match self.load_async().await {
-            Ok(cache) => Ok(cache),
+            Ok(cache) => Ok(Some(cache)),
             Err(CacheStoreError::ReadError { ref source, .. })
                 if source.kind() == io::ErrorKind::NotFound =>
             {
-                Ok(Cache::default())
+                Ok(None)
             }
             Err(e) => Err(e),
         }
     }
 
+    /// Loads the cache from disk asynchronously, returning a default cache if the file doesn't exist.
+    ///
+    /// This is the async equivalent of [`load_or_default()`](Self::load_or_default).
+    ///
+    /// # Errors
+    ///
+    /// - Returns `CacheStoreError::ReadError` for I/O errors other than "file not found".
+    /// - Returns `CacheStoreError::Deserialize` if the file isn't a valid cache.
+    pub async fn load_or_default_async(&self) -> Result<Cache> {
+        Ok(self.try_load_async().await?.unwrap_or_default())
+    }
+
+    /// Reads only the sync metadata of the cache file asynchronously.
+    ///
+    /// This is the async equivalent of [`load_metadata()`](Self::load_metadata).
+    ///
+    /// # Errors
+    ///
+    /// - Returns `CacheStoreError::ReadError` for I/O errors other than "file not found".
+    /// - Returns `CacheStoreError::Deserialize` if the file isn't a valid cache.
+    pub async fn load_metadata_async(&self) -> Result<Option<CacheMetadata>> {
+        match tokio::fs::read_to_string(&self.path).await {
+            Ok(contents) => Ok(Some(self.deserialize(&contents)?)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(CacheStoreError::ReadError {
+                path: self.path.clone(),
+                source: e,
+            }),
+        }
+    }
+
     /// Saves the cache to disk asynchronously using atomic write.
     ///
     /// This is the async equivalent of [`save()`](Self::save). Use this method
     /// in async contexts to avoid blocking the tokio runtime.
     ///
     /// Creates the parent directory if it doesn't exist. The cache is written
-    /// as pretty-printed JSON for easier debugging.
+    /// as compact JSON unless pretty output was enabled with
+    /// [`with_pretty()`](Self::with_pretty).
     ///
     /// Uses atomic write (tempfile + rename) to prevent corruption if the process
     /// crashes mid-write.
@@ -339,7 +584,7 @@ impl CacheStore {
     ///
     /// - Returns `CacheStoreError::CreateDirError` if the directory cannot be created.
     /// - Returns `CacheStoreError::WriteError` if the file cannot be written.
-    /// - Returns `CacheStoreError::Json` if serialization fails.
+    /// - Returns `CacheStoreError::Serialize` if serialization fails.
     pub async fn save_async(&self, cache: &Cache) -> Result<()> {
         // Ensure parent directory exists
         if let Some(parent) = self.path.parent() {
@@ -351,7 +596,7 @@ impl CacheStore {
             })?;
         }
 
-        let json = serde_json::to_string_pretty(cache)?;
+        let json = self.serialize(cache)?;
 
         // Atomic write: write to temp file, then rename
         // This prevents corruption if the process crashes mid-write
@@ -392,6 +637,28 @@ impl CacheStore {
     }
 }
 
+/// Sorts every resource list in the cache into a stable order.
+///
+/// Resources are ordered by ID; collaborator states and completed counts,
+/// which have no ID of their own, by the IDs they refer to.
+fn sort_resources(cache: &mut Cache) {
+    cache.items.sort_by(|a, b| a.id.cmp(&b.id));
+    cache.projects.sort_by(|a, b| a.id.cmp(&b.id));
+    cache.labels.sort_by(|a, b| a.id.cmp(&b.id));
+    cache.sections.sort_by(|a, b| a.id.cmp(&b.id));
+    cache.notes.sort_by(|a, b| a.id.cmp(&b.id));
+    cache.project_notes.sort_by(|a, b| a.id.cmp(&b.id));
+    cache.reminders.sort_by(|a, b| a.id.cmp(&b.id));
+    cache.filters.sort_by(|a, b| a.id.cmp(&b.id));
+    cache.collaborators.sort_by(|a, b| a.id.cmp(&b.id));
+    cache
+        .collaborator_states
+        .sort_by(|a, b| (&a.project_id, &a.user_id).cmp(&(&b.project_id, &b.user_id)));
+    cache.completed_info.sort_by(|a, b| {
+        (&a.project_id, &a.section_id, &a.item_id).cmp(&(&b.project_id, &b.section_id, &b.item_id))
+    });
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -427,6 +694,18 @@ mod tests {
         assert_eq!(store.path(), &default_path);
     }
 
+    #[test]
+    fn test_profiles_use_separate_cache_paths() {
+        let default_path = CacheStore::default_path().expect("should get default path");
+        let work = CacheStore::for_profile(Some("work")).expect("should create store");
+        let home = CacheStore::for_profile(Some("home")).expect("should create store");
+
+        assert_ne!(work.path(), home.path());
+        assert_ne!(work.path(), &default_path);
+        assert!(work.path().ends_with("profiles/work/cache.json"));
+        assert_eq!(CacheStore::for_profile(None).unwrap().path(), &default_path);
+    }
+
     #[test]
     fn test_cache_store_with_custom_path() {
         let custom_path = PathBuf::from("/tmp/test/cache.json");
@@ -501,6 +780,111 @@ mod tests {
         assert_eq!(cache.sync_token, "*");
     }
 
+    #[test]
+    fn test_try_load_missing_file_is_none() {
+        let store = CacheStore::with_path(PathBuf::from("/nonexistent/path/to/cache.json"));
+        assert!(store.try_load().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_try_load_present_file_is_some() {
+        use tempfile::tempdir;
+
+        let temp_dir = tempdir().expect("failed to create temp dir");
+        let store = CacheStore::with_path(temp_dir.path().join("cache.json"));
+        let mut cache = Cache::new();
+        cache.sync_token = "saved-token".to_string();
+        store.save(&cache).expect("save failed");
+
+        let loaded = store.try_load().unwrap().expect("cache should exist");
+        assert_eq!(loaded.sync_token, "saved-token");
+    }
+
+    #[test]
+    fn test_try_load_invalid_json_is_err() {
+        use tempfile::tempdir;
+
+        let temp_dir = tempdir().expect("failed to create temp dir");
+        let path = temp_dir.path().join("cache.json");
+        fs::write(&path, "{ not json").expect("failed to write file");
+        let store = CacheStore::with_path(path);
+
+        assert!(matches!(
+            store.try_load(),
+            Err(CacheStoreError::Deserialize { .. })
+        ));
+        assert!(store.load_or_default().is_err());
+    }
+
+    #[test]
+    fn test_load_wrong_shape_is_corrupt() {
+        use tempfile::tempdir;
+
+        let temp_dir = tempdir().expect("failed to create temp dir");
+        let path = temp_dir.path().join("cache.json");
+        fs::write(&path, "[1, 2, 3]").expect("failed to write file");
+        let store = CacheStore::with_path(path.clone());
+
+        let err = store.load().unwrap_err();
+        assert!(err.is_corrupt());
+        match err {
+            CacheStoreError::Deserialize { path: p, .. } => assert_eq!(p, path),
+            other => panic!("expected Deserialize, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_save_with_unusable_parent_is_io_error() {
+        use tempfile::tempdir;
+
+        // The parent "directory" is a regular file, so it can't be created
+        let temp_dir = tempdir().expect("failed to create temp dir");
+        let blocker = temp_dir.path().join("not-a-dir");
+        fs::write(&blocker, "").expect("failed to write file");
+        let store = CacheStore::with_path(blocker.join("cache.json"));
+
+        let err = store.save(&Cache::new()).unwrap_err();
+        assert!(!err.is_corrupt());
+        match err {
+            CacheStoreError::CreateDirError { path, .. } => assert_eq!(path, blocker),
+            other => panic!("expected CreateDirError, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_try_load_directory_is_err() {
+        use tempfile::tempdir;
+
+        // Reading a directory fails with an I/O error other than NotFound
+        let temp_dir = tempdir().expect("failed to create temp dir");
+        let store = CacheStore::with_path(temp_dir.path().to_path_buf());
+
+        assert!(matches!(
+            store.try_load(),
+            Err(CacheStoreError::ReadError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_load_metadata_reads_sync_state() {
+        use tempfile::tempdir;
+
+        let temp_dir = tempdir().expect("failed to create temp dir");
+        let store = CacheStore::with_path(temp_dir.path().join("cache.json"));
+        assert!(store.load_metadata().unwrap().is_none());
+
+        let mut cache = Cache::new();
+        cache.sync_token = "saved-token".to_string();
+        cache.mark_fresh(Utc::now());
+        store.save(&cache).expect("save failed");
+
+        let metadata = store
+            .load_metadata()
+            .unwrap()
+            .expect("metadata should exist");
+        assert_eq!(metadata, CacheMetadata::from(&cache));
+    }
+
     #[test]
     fn test_write_error_includes_file_path() {
         use tempfile::tempdir;
@@ -622,6 +1006,148 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_save_compact_by_default() {
+        use tempfile::tempdir;
+
+        let temp_dir = tempdir().expect("failed to create temp dir");
+        let path = temp_dir.path().join("cache.json");
+        let store = CacheStore::with_path(path.clone());
+        assert!(!store.is_pretty());
+
+        let mut cache = crate::Cache::new();
+        cache.sync_token = "compact-token".to_string();
+        store.save(&cache).expect("save failed");
+
+        let contents = fs::read_to_string(&path).expect("read failed");
+        assert!(!contents.contains('\n'), "compact JSON should be one line");
+
+        let loaded = store.load().expect("load failed");
+        assert_eq!(loaded.sync_token, "compact-token");
+    }
+
+    #[test]
+    fn test_save_pretty_round_trip() {
+        use tempfile::tempdir;
+
+        let temp_dir = tempdir().expect("failed to create temp dir");
+        let path = temp_dir.path().join("cache.json");
+        let store = CacheStore::with_path(path.clone()).with_pretty(true);
+
+        let mut cache = crate::Cache::new();
+        cache.sync_token = "pretty-token".to_string();
+        store.save(&cache).expect("save failed");
+
+        let contents = fs::read_to_string(&path).expect("read failed");
+        assert!(contents.contains('\n'), "pretty JSON should span lines");
+
+        let loaded = store.load().expect("load failed");
+        assert_eq!(loaded.sync_token, "pretty-token");
+    }
+
+    #[test]
+    fn test_compact_is_smaller_and_loads_either_form() {
+        use tempfile::tempdir;
+
+        let temp_dir = tempdir().expect("failed to create temp dir");
+        let compact_path = temp_dir.path().join("compact.json");
+        let pretty_path = temp_dir.path().join("pretty.json");
+
+        let mut cache = crate::Cache::new();
+        cache.sync_token = "size-token".to_string();
+
+        CacheStore::with_path(compact_path.clone())
+            .save(&cache)
+            .expect("compact save failed");
+        CacheStore::with_path(pretty_path.clone())
+            .with_pretty(true)
+            .save(&cache)
+            .expect("pretty save failed");
+
+        let compact_len = fs::metadata(&compact_path).unwrap().len();
+        let pretty_len = fs::metadata(&pretty_path).unwrap().len();
+        assert!(compact_len < pretty_len);
+
+        // A compact store can read a pretty file and vice versa
+        let loaded = CacheStore::with_path(pretty_path).load().unwrap();
+        assert_eq!(loaded.sync_token, "size-token");
+        let loaded = CacheStore::with_path(compact_path)
+            .with_pretty(true)
+            .load()
+            .unwrap();
+        assert_eq!(loaded.sync_token, "size-token");
+    }
+
+    fn make_label(id: &str) -> todoist_api_rs::sync::Label {
+        todoist_api_rs::sync::Label {
+            id: id.to_string(),
+            name: format!("label-{id}"),
+            color: None,
+            item_order: 0,
+            is_deleted: false,
+            is_favorite: false,
+        }
+    }
+
+    fn make_state(project_id: &str, user_id: &str) -> todoist_api_rs::sync::CollaboratorState {
+        todoist_api_rs::sync::CollaboratorState {
+            project_id: project_id.to_string(),
+            user_id: user_id.to_string(),
+            state: "active".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_sorted_save_is_independent_of_order() {
+        use tempfile::tempdir;
+
+        let temp_dir = tempdir().expect("failed to create temp dir");
+        let first_path = temp_dir.path().join("first.json");
+        let second_path = temp_dir.path().join("second.json");
+
+        let mut first = crate::Cache::new();
+        first.labels = vec![make_label("2"), make_label("1"), make_label("3")];
+        first.collaborator_states = vec![make_state("p2", "u1"), make_state("p1", "u2")];
+        let mut second = crate::Cache::new();
+        second.labels = vec![make_label("3"), make_label("2"), make_label("1")];
+        second.collaborator_states = vec![make_state("p1", "u2"), make_state("p2", "u1")];
+
+        let first_store = CacheStore::with_path(first_path.clone()).with_sorted(true);
+        let second_store = CacheStore::with_path(second_path.clone()).with_sorted(true);
+        assert!(first_store.is_sorted());
+        first_store.save(&first).expect("save failed");
+        second_store.save(&second).expect("save failed");
+
+        let first_contents = fs::read_to_string(&first_path).unwrap();
+        assert_eq!(first_contents, fs::read_to_string(&second_path).unwrap());
+
+        // The in-memory cache keeps its original order
+        let ids: Vec<&str> = first.labels.iter().map(|l| l.id.as_str()).collect();
+        assert_eq!(ids, ["2", "1", "3"]);
+
+        let loaded = first_store.load().expect("load failed");
+        let ids: Vec<&str> = loaded.labels.iter().map(|l| l.id.as_str()).collect();
+        assert_eq!(ids, ["1", "2", "3"]);
+    }
+
+    #[test]
+    fn test_unsorted_save_keeps_order() {
+        use tempfile::tempdir;
+
+        let temp_dir = tempdir().expect("failed to create temp dir");
+        let path = temp_dir.path().join("cache.json");
+        let store = CacheStore::with_path(path);
+        assert!(!store.is_sorted());
+
+        let mut cache = crate::Cache::new();
+        cache.labels = vec![make_label("2"), make_label("1")];
+        store.save(&cache).expect("save failed");
+
+        let loaded = store.load().expect("load failed");
+        let ids: Vec<&str> = loaded.labels.iter().map(|l| l.id.as_str()).collect();
+        assert_eq!(ids, ["2", "1"]);
+    }
+
     // ==========================================================================
     // Async I/O Tests
     // ==========================================================================
@@ -646,6 +1172,30 @@ mod tests {
         assert_eq!(loaded.sync_token, "test-token");
     }
 
+    #[tokio::test]
+    async fn test_save_async_respects_pretty() {
+        use tempfile::tempdir;
+
+        let temp_dir = tempdir().expect("failed to create temp dir");
+        let path = temp_dir.path().join("cache.json");
+
+        let cache = crate::Cache::new();
+        CacheStore::with_path(path.clone())
+            .save_async(&cache)
+            .await
+            .expect("save_async failed");
+        let compact = tokio::fs::read_to_string(&path).await.unwrap();
+        assert!(!compact.contains('\n'));
+
+        CacheStore::with_path(path.clone())
+            .with_pretty(true)
+            .save_async(&cache)
+            .await
+            .expect("save_async failed");
+        let pretty = tokio::fs::read_to_string(&path).await.unwrap();
+        assert!(pretty.contains('\n'));
+    }
+
     #[tokio::test]
     async fn test_atomic_write_async() {
         use tempfile::tempdir;
@@ -680,6 +1230,12 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_try_load_async_missing_file() {
+        let store = CacheStore::with_path(PathBuf::from("/nonexistent/path/to/cache.json"));
+        assert!(store.try_load_async().await.unwrap().is_none());
+    }
+
     #[tokio::test]
     async fn test_load_or_default_async_missing_file() {
         let path = PathBuf::from("/nonexistent/path/to/cache.json");
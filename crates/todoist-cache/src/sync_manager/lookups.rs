@@ -705,4 +705,112 @@ impl SyncManager {
             }
         }
     }
+
+    /// Resolves an item (task) from a user-supplied argument, with auto-sync fallback.
+    ///
+    /// This is the lookup used by commands that accept a task reference. The
+    /// argument is matched in order against:
+    /// 1. An exact item ID
+    /// 2. A unique ID prefix
+    /// 3. A unique case-insensitive substring of the task content
+    ///
+    /// If nothing matches in the cache, a sync is performed and the lookup retried.
+    ///
+    /// # Arguments
+    ///
+    /// * `arg` - The item ID, ID prefix, or content substring to search for
+    /// * `require_checked` - If `Some(true)`, only match completed items.
+    ///   If `Some(false)`, only match uncompleted items.
+    ///   If `None`, match any item regardless of completion status.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SyncError::NotFound` if no item matches even after syncing, or if
+    /// the argument is ambiguous (the identifier then lists the candidates).
+    /// Returns `SyncError::Api` if the sync operation fails.
+    pub async fn resolve_item_arg(
+        &mut self,
+        arg: &str,
+        require_checked: Option<bool>,
+    ) -> SyncResult<&Item> {
+        let cache_status = match self.find_item_arg_in_cache(arg, require_checked) {
+            ItemLookupResult::Found(_) => CacheLookupStatus::Found,
+            ItemLookupResult::Ambiguous(msg) => CacheLookupStatus::Ambiguous(msg),
+            ItemLookupResult::NotFound => CacheLookupStatus::NotFound,
+        };
+
+        if let CacheLookupStatus::Ambiguous(msg) = cache_status {
+            return Err(SyncError::NotFound {
+                resource_type: "Item",
+                identifier: msg,
+                suggestion: None,
+            });
+        }
+
+        if matches!(cache_status, CacheLookupStatus::NotFound) {
+            self.sync().await?;
+        }
+
+        match self.find_item_arg_in_cache(arg, require_checked) {
+            ItemLookupResult::Found(item) => Ok(item),
+            ItemLookupResult::Ambiguous(msg) => Err(SyncError::NotFound {
+                resource_type: "Item",
+                identifier: msg,
+                suggestion: None,
+            }),
+            ItemLookupResult::NotFound => Err(SyncError::NotFound {
+                resource_type: "Item",
+                identifier: arg.to_string(),
+                suggestion: None,
+            }),
+        }
+    }
+
+    /// Helper to find an item in the cache by ID, ID prefix, or content substring.
+    fn find_item_arg_in_cache(
+        &self,
+        arg: &str,
+        require_checked: Option<bool>,
+    ) -> ItemLookupResult<'_> {
+        match self.find_item_by_prefix_in_cache(arg, require_checked) {
+            ItemLookupResult::NotFound => {}
+            other => return other,
+        }
+
+        let needle = arg.trim().to_lowercase();
+        if needle.is_empty() {
+            return ItemLookupResult::NotFound;
+        }
+
+        let matches: Vec<&Item> = self
+            .cache()
+            .items
+            .iter()
+            .filter(|i| {
+                !i.is_deleted
+                    && require_checked.is_none_or(|checked| i.checked == checked)
+                    && i.content.to_lowercase().contains(&needle)
+            })
+            .collect();
+
+        match matches.len() {
+            0 => ItemLookupResult::NotFound,
+            1 => ItemLookupResult::Found(matches[0]),
+            _ => {
+                let mut msg = format!(
+                    "Ambiguous task \"{}\"\n\nMultiple tasks match this text:",
+                    arg
+                );
+                for item in matches.iter().take(5) {
+                    let prefix = &item.id[..6.min(item.id.len())];
+                    msg.push_str(&format!("\n  {}  {}", prefix, item.content));
+                }
+                if matches.len() > 5 {
+                    msg.push_str(&format!("\n  ... and {} more", matches.len() - 5));
+                }
+                msg.push_str("\n\nPlease use a task ID prefix or more specific text.");
+                ItemLookupResult::Ambiguous(msg)
+            }
+        }
+    }
 }
@@ -29,9 +29,9 @@ mod lookups;
 
 use chrono::{DateTime, Duration, Utc};
 use todoist_api_rs::client::TodoistClient;
-use todoist_api_rs::sync::{SyncCommand, SyncRequest, SyncResponse};
+use todoist_api_rs::sync::{SkippedResource, SyncCommand, SyncRequest, SyncResponse};
 
-use crate::{Cache, CacheStore, CacheStoreError};
+use crate::{Cache, CacheMetadata, CacheStore, CacheStoreError};
 
 // Re-export lookup utilities for error formatting and tests
 #[cfg(test)]
@@ -41,6 +41,20 @@ pub(crate) use lookups::format_not_found_error;
 /// Default staleness threshold in minutes.
 const DEFAULT_STALE_MINUTES: i64 = 5;
 
+/// Resource types that can be fetched individually with [`SyncManager::sync_resources`].
+pub const SYNC_RESOURCE_TYPES: &[&str] = &[
+    "items",
+    "projects",
+    "labels",
+    "sections",
+    "notes",
+    "project_notes",
+    "reminders",
+    "filters",
+    "collaborators",
+    "user",
+];
+
 /// Errors that can occur during sync operations.
 #[derive(Debug, thiserror::Error)]
 pub enum SyncError {
@@ -78,6 +92,82 @@ pub enum SyncError {
 /// Result type for sync operations.
 pub type Result<T> = std::result::Result<T, SyncError>;
 
+/// A notable event during a sync that callers may want to report.
+///
+/// Events are delivered to the sink set with [`SyncManager::with_event_sink`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SyncEvent {
+    /// The stored sync token was rejected, so a full sync was performed instead.
+    FullSyncFallback,
+    /// Another process rewrote the cache file; its contents were reloaded and
+    /// this sync's changes applied on top before saving.
+    ExternalChangesMerged,
+    /// A command batch was rejected because the sync token was stale; the
+    /// cache was refreshed and the same commands were sent once more.
+    CommandsRetried,
+    /// Resources in the sync response failed to parse and were left out of
+    /// the cache.
+    ResourcesSkipped(Vec<SkippedResource>),
+}
+
+impl SyncEvent {
+    /// Returns a stable machine-readable code for the event.
+    pub fn code(&self) -> &'static str {
+        match self {
+            SyncEvent::FullSyncFallback => "sync_token_invalid",
+            SyncEvent::ExternalChangesMerged => "cache_modified_externally",
+            SyncEvent::CommandsRetried => "commands_retried",
+            SyncEvent::ResourcesSkipped(_) => "resources_skipped",
+        }
+    }
+}
+
+impl std::fmt::Display for SyncEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SyncEvent::FullSyncFallback => {
+                write!(f, "Sync token invalid, performing full sync to recover.")
+            }
+            SyncEvent::ExternalChangesMerged => {
+                write!(
+                    f,
+                    "Cache was updated by another td process; merged its changes before saving."
+                )
+            }
+            SyncEvent::CommandsRetried => {
+                write!(
+                    f,
+                    "Sync token was stale while sending changes; refreshed the cache and retried once."
+                )
+            }
+            SyncEvent::ResourcesSkipped(skipped) => {
+                let list: Vec<String> = skipped
+                    .iter()
+                    .map(|r| match &r.id {
+                        Some(id) => format!("{} {} ({})", r.resource, id, r.error),
+                        None => format!("{} ({})", r.resource, r.error),
+                    })
+                    .collect();
+                write!(
+                    f,
+                    "Skipped {} unreadable resource(s) from the server: {}",
+                    skipped.len(),
+                    list.join("; ")
+                )
+            }
+        }
+    }
+}
+
+/// Callback that receives [`SyncEvent`]s.
+pub type SyncEventSink = Box<dyn Fn(&SyncEvent) + Send>;
+
+/// Prints events to stderr as warnings. Used when no sink is set.
+fn default_event_sink(event: &SyncEvent) {
+    eprintln!("Warning: {event}");
+}
+
 /// Orchestrates synchronization between the Todoist API and local cache.
 ///
 /// `SyncManager` provides methods for syncing data, checking cache staleness,
@@ -123,6 +213,20 @@ pub struct SyncManager {
 
     /// Staleness threshold in minutes.
     stale_minutes: i64,
+
+    /// Receives sync events such as the full-sync fallback.
+    event_sink: SyncEventSink,
+
+    /// Metadata of the cache file as last loaded or saved by this manager.
+    /// `None` if the file did not exist.
+    disk_metadata: Option<CacheMetadata>,
+
+    /// Whether a command batch rejected for a stale sync token is retried.
+    retry_on_conflict: bool,
+
+    /// Whether the cache was handed out through [`cache_mut`](Self::cache_mut)
+    /// since it was last loaded or saved.
+    dirty: bool,
 }
 
 impl SyncManager {
@@ -139,13 +243,7 @@ impl SyncManager {
     ///
     /// Returns an error if loading the cache from disk fails (excluding file not found).
     pub fn new(client: TodoistClient, store: CacheStore) -> Result<Self> {
-        let cache = store.load_or_default()?;
-        Ok(Self {
-            client,
-            store,
-            cache,
-            stale_minutes: DEFAULT_STALE_MINUTES,
-        })
+        Self::with_stale_threshold(client, store, DEFAULT_STALE_MINUTES)
     }
 
     /// Creates a new `SyncManager` with a custom staleness threshold.
@@ -160,20 +258,111 @@ impl SyncManager {
         store: CacheStore,
         stale_minutes: i64,
     ) -> Result<Self> {
-        let cache = store.load_or_default()?;
+        let loaded = store.try_load()?;
+        let disk_metadata = loaded.as_ref().map(CacheMetadata::from);
         Ok(Self {
             client,
             store,
-            cache,
+            cache: loaded.unwrap_or_default(),
             stale_minutes,
+            event_sink: Box::new(default_event_sink),
+            disk_metadata,
+            retry_on_conflict: false,
+            dirty: false,
         })
     }
 
+    /// Creates a `SyncManager` on an empty cache, without reading the cache file.
+    ///
+    /// This is the starting point for [`rebuild`](Self::rebuild) when the file
+    /// on disk can't be loaded (see [`CacheStoreError::is_corrupt`]); the
+    /// file is overwritten by the next save.
+    pub fn discarding_cache(client: TodoistClient, store: CacheStore) -> Self {
+        Self {
+            client,
+            store,
+            cache: Cache::default(),
+            stale_minutes: DEFAULT_STALE_MINUTES,
+            event_sink: Box::new(default_event_sink),
+            disk_metadata: None,
+            retry_on_conflict: false,
+            dirty: false,
+        }
+    }
+
+    /// Routes sync events to `sink` instead of printing them to stderr.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use todoist_api_rs::client::TodoistClient;
+    /// use todoist_cache_rs::{CacheStore, SyncManager};
+    ///
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = TodoistClient::new("token")?;
+    /// let manager = SyncManager::new(client, CacheStore::new()?)?
+    ///     .with_event_sink(|event| log_warning(event.code()));
+    /// # Ok(())
+    /// # }
+    /// # fn log_warning(_: &str) {}
+    /// ```
+    pub fn with_event_sink(mut self, sink: impl Fn(&SyncEvent) + Send + 'static) -> Self {
+        self.event_sink = Box::new(sink);
+        self
+    }
+
+    /// Retries a command batch once when the API rejects its sync token.
+    ///
+    /// Another client changing the account between our last sync and a
+    /// command batch can invalidate the token. With retrying enabled,
+    /// [`execute_commands`](Self::execute_commands) then syncs and resends
+    /// the same commands. Their UUIDs are unchanged, so the API will not
+    /// apply a command twice. Disabled by default.
+    pub fn with_retry_on_conflict(mut self, retry: bool) -> Self {
+        self.retry_on_conflict = retry;
+        self
+    }
+
     /// Returns a reference to the current cache.
     pub fn cache(&self) -> &Cache {
         &self.cache
     }
 
+    /// Returns a mutable reference to the current cache and marks it dirty.
+    ///
+    /// This bypasses sync semantics: changes made here are local only, are
+    /// never sent to Todoist, and are overwritten by the next sync response
+    /// that touches the same resources. If another process rewrites the
+    /// cache file before these changes are saved, the next sync reloads that
+    /// file and the changes are lost. Use it for test data or local-only
+    /// state, and call [`save`](Self::save) to persist; otherwise the next
+    /// operation that writes the cache persists them.
+    ///
+    /// The lookup indexes are not updated for you. After adding, removing,
+    /// or renaming items, projects, sections, labels, or collaborators, call
+    /// [`Cache::rebuild_indexes`] before using lookups such as
+    /// [`Cache::find_item`] or [`Cache::find_project`]; until then they may
+    /// miss new entries or return stale ones.
+    pub fn cache_mut(&mut self) -> &mut Cache {
+        self.dirty = true;
+        &mut self.cache
+    }
+
+    /// Returns true if the cache was changed through
+    /// [`cache_mut`](Self::cache_mut) and not saved since.
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    /// Writes the in-memory cache to disk.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if saving the cache fails.
+    pub async fn save(&mut self) -> Result<()> {
+        self.save_cache().await
+    }
+
     /// Returns a reference to the cache store.
     pub fn store(&self) -> &CacheStore {
         &self.store
@@ -184,6 +373,15 @@ impl SyncManager {
         &self.client
     }
 
+    /// Replaces the API token used for subsequent requests.
+    ///
+    /// The cache and sync token are kept, so a long-lived consumer can rotate
+    /// credentials without reloading anything. The new token must belong to
+    /// the same account; otherwise run a full sync afterwards.
+    pub fn set_token(&mut self, token: impl Into<String>) {
+        self.client.set_token(token);
+    }
+
     /// Returns true if the cache is stale (older than the configured threshold).
     ///
     /// A cache is considered stale if:
@@ -235,41 +433,100 @@ impl SyncManager {
     ///
     /// Returns an error if the API request fails or if saving the cache fails.
     pub async fn sync(&mut self) -> Result<&Cache> {
+        let (response, _) = self.sync_raw(None).await?;
+        self.apply_response(&response).await
+    }
+
+    /// Fetches a sync response without applying it to the cache.
+    ///
+    /// Returns the parsed response together with the body exactly as the
+    /// server sent it.
+    /// With `from_token` set, the stored token is replaced first (`"*"`
+    /// requests a full sync). Full versus incremental sync and the fallback
+    /// on an invalid token work exactly like [`sync`](Self::sync). Pass the
+    /// response to [`apply_response`](Self::apply_response) to update and
+    /// save the cache; until then nothing is written to disk.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails.
+    pub async fn sync_raw(&mut self, from_token: Option<&str>) -> Result<(SyncResponse, String)> {
+        if let Some(sync_token) = from_token {
+            self.cache.sync_token = sync_token.to_string();
+        }
+
         if self.cache.needs_full_sync() {
             // Already need a full sync, just do it
-            let request = SyncRequest::full_sync();
-            let response = self.client.sync(request).await?;
-            self.cache.apply_sync_response(&response);
-            self.store.save_async(&self.cache).await?;
-            return Ok(&self.cache);
+            return Ok(self.client.sync_with_body(SyncRequest::full_sync()).await?);
         }
 
         // Try incremental sync
         let request = SyncRequest::incremental(&self.cache.sync_token);
-        match self.client.sync(request).await {
-            Ok(response) => {
-                self.cache.apply_sync_response(&response);
-                self.store.save_async(&self.cache).await?;
-                Ok(&self.cache)
-            }
+        match self.client.sync_with_body(request).await {
+            Ok(response) => Ok(response),
             Err(e) if e.is_invalid_sync_token() => {
                 // Sync token rejected - fall back to full sync
-                eprintln!("Warning: Sync token invalid, performing full sync to recover.");
+                (self.event_sink)(&SyncEvent::FullSyncFallback);
 
                 // Reset sync token to force full sync
                 self.cache.sync_token = "*".to_string();
 
-                // Perform full sync
-                let request = SyncRequest::full_sync();
-                let response = self.client.sync(request).await?;
-                self.cache.apply_sync_response(&response);
-                self.store.save_async(&self.cache).await?;
-                Ok(&self.cache)
+                Ok(self.client.sync_with_body(SyncRequest::full_sync()).await?)
             }
             Err(e) => Err(e.into()),
         }
     }
 
+    /// Applies a response from [`sync_raw`](Self::sync_raw) and saves the cache.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if saving the cache fails.
+    pub async fn apply_response(&mut self, response: &SyncResponse) -> Result<&Cache> {
+        self.merge_external_changes().await?;
+        self.report_skipped_resources(response);
+        self.cache.apply_sync_response(response);
+        self.save_cache().await?;
+        Ok(&self.cache)
+    }
+
+    /// Syncs only if the cache needs it, otherwise returns it unchanged.
+    ///
+    /// When [`needs_sync`](Self::needs_sync) is false no request is made.
+    /// Otherwise this behaves exactly like [`sync`](Self::sync).
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - The current time to compare against
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a sync is performed and it fails.
+    pub async fn sync_if_needed(&mut self, now: DateTime<Utc>) -> Result<&Cache> {
+        if !self.needs_sync(now) {
+            if self.dirty {
+                self.save_cache().await?;
+            }
+            return Ok(&self.cache);
+        }
+        self.sync().await
+    }
+
+    /// Performs an incremental sync starting from `sync_token`.
+    ///
+    /// The cached data is kept; only the stored token is replaced before
+    /// syncing, so changes since that token are replayed onto the cache.
+    /// An invalid or expired token falls back to a full sync exactly like
+    /// [`sync`](Self::sync).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or if saving the cache fails.
+    pub async fn sync_from_token(&mut self, sync_token: &str) -> Result<&Cache> {
+        let (response, _) = self.sync_raw(Some(sync_token)).await?;
+        self.apply_response(&response).await
+    }
+
     /// Forces a full sync, ignoring the stored sync token.
     ///
     /// This replaces all cached data with fresh data from the server.
@@ -285,12 +542,103 @@ impl SyncManager {
     pub async fn full_sync(&mut self) -> Result<&Cache> {
         let request = SyncRequest::full_sync();
         let response = self.client.sync(request).await?;
+        self.merge_external_changes().await?;
+        self.report_skipped_resources(&response);
         self.cache.apply_sync_response(&response);
-        self.store.save_async(&self.cache).await?;
+        self.save_cache().await?;
+
+        Ok(&self.cache)
+    }
+
+    /// Discards the cache and rebuilds it from a full sync.
+    ///
+    /// Unlike [`full_sync`](Self::full_sync), nothing from the current cache
+    /// survives: state the full sync response doesn't carry (such as the user
+    /// record or unsaved local changes) is dropped along with everything else.
+    /// The response is fetched before the cache is cleared, so a failed
+    /// request leaves the existing cache untouched.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the rebuilt cache.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or if saving the cache fails.
+    pub async fn rebuild(&mut self) -> Result<&Cache> {
+        let request = SyncRequest::full_sync();
+        let response = self.client.sync(request).await?;
+        self.report_skipped_resources(&response);
+        self.cache = Cache::new();
+        self.cache.apply_sync_response(&response);
+        self.save_cache().await?;
+
+        Ok(&self.cache)
+    }
+
+    /// Fetches only the given resource types and replaces them in the cache.
+    ///
+    /// This performs a scoped full sync (`sync_token='*'`) for the listed
+    /// resource types, which is faster than a complete sync when only some
+    /// data needs refreshing. The stored sync token is not advanced, so the
+    /// next regular sync still picks up changes to every resource type.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_types` - Resource type names from [`SYNC_RESOURCE_TYPES`]
+    ///
+    /// # Errors
+    ///
+    /// Returns `SyncError::Validation` if a resource type is unknown, or an
+    /// error if the API request or saving the cache fails.
+    pub async fn sync_resources(&mut self, resource_types: &[String]) -> Result<&Cache> {
+        if resource_types.is_empty() {
+            return Err(SyncError::Validation(
+                "at least one resource type is required".to_string(),
+            ));
+        }
+        if let Some(unknown) = resource_types
+            .iter()
+            .find(|t| !SYNC_RESOURCE_TYPES.contains(&t.as_str()))
+        {
+            return Err(SyncError::Validation(format!(
+                "unknown resource type '{}' (expected one of: {})",
+                unknown,
+                SYNC_RESOURCE_TYPES.join(", ")
+            )));
+        }
+
+        let request = SyncRequest::full_sync().with_resource_types(resource_types.to_vec());
+        let response = self.client.sync(request).await?;
+        self.merge_external_changes().await?;
+        self.report_skipped_resources(&response);
+        self.cache
+            .apply_resource_sync_response(&response, resource_types);
+        self.save_cache().await?;
 
         Ok(&self.cache)
     }
 
+    /// Removes archived projects and sections from the cache and saves it.
+    ///
+    /// The cache is only written when something was removed.
+    ///
+    /// # Returns
+    ///
+    /// The number of projects and sections removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if saving the cache fails.
+    pub async fn prune_archived(&mut self) -> Result<usize> {
+        self.merge_external_changes().await?;
+        let pruned = self.cache.prune_archived();
+        if pruned > 0 || self.dirty {
+            self.save_cache().await?;
+        }
+        Ok(pruned)
+    }
+
     /// Reloads the cache from disk.
     ///
     /// This discards any in-memory changes and loads the cache from disk.
@@ -300,10 +648,53 @@ impl SyncManager {
     ///
     /// Returns an error if reading the cache from disk fails.
     pub fn reload(&mut self) -> Result<&Cache> {
-        self.cache = self.store.load_or_default()?;
+        let loaded = self.store.try_load()?;
+        self.disk_metadata = loaded.as_ref().map(CacheMetadata::from);
+        self.cache = loaded.unwrap_or_default();
+        self.dirty = false;
         Ok(&self.cache)
     }
 
+    /// Reloads the cache if another process rewrote the file since this
+    /// manager last loaded or saved it.
+    ///
+    /// Called right before applying a response, so the response lands on the
+    /// newest on-disk state instead of overwriting it with a stale copy. The
+    /// response's sync token then replaces the file's token; if that token is
+    /// older, the next incremental sync simply replays the difference.
+    async fn merge_external_changes(&mut self) -> Result<()> {
+        let on_disk = self.store.load_metadata_async().await?;
+        if on_disk.is_none() || on_disk == self.disk_metadata {
+            return Ok(());
+        }
+
+        let Some(cache) = self.store.try_load_async().await? else {
+            return Ok(());
+        };
+        self.disk_metadata = Some(CacheMetadata::from(&cache));
+        self.cache = cache;
+        self.dirty = false;
+        (self.event_sink)(&SyncEvent::ExternalChangesMerged);
+        Ok(())
+    }
+
+    /// Reports resources the response dropped because they failed to parse.
+    fn report_skipped_resources(&self, response: &SyncResponse) {
+        if !response.skipped_resources.is_empty() {
+            (self.event_sink)(&SyncEvent::ResourcesSkipped(
+                response.skipped_resources.clone(),
+            ));
+        }
+    }
+
+    /// Saves the cache and records the metadata that was written.
+    async fn save_cache(&mut self) -> Result<()> {
+        self.store.save_async(&self.cache).await?;
+        self.disk_metadata = Some(CacheMetadata::from(&self.cache));
+        self.dirty = false;
+        Ok(())
+    }
+
     /// Executes one or more commands via the Sync API.
     ///
     /// This method sends the commands to the Todoist API, applies the response
@@ -322,6 +713,10 @@ impl SyncManager {
     /// - `temp_id_mapping`: Maps temporary IDs to real IDs for created resources
     /// - Updated resources affected by the commands
     ///
+    /// If the sync token is rejected and
+    /// [`with_retry_on_conflict`](Self::with_retry_on_conflict) is enabled,
+    /// the cache is synced and the commands are sent once more.
+    ///
     /// # Errors
     ///
     /// Returns an error if the API request fails or if saving the cache fails.
@@ -358,22 +753,139 @@ impl SyncManager {
     /// }
     /// ```
     pub async fn execute_commands(&mut self, commands: Vec<SyncCommand>) -> Result<SyncResponse> {
-        // Execute command batches against the current sync token so mutation
-        // responses include incremental resource deltas (including delete tombstones).
-        // Without resource_types, the API only returns sync_status and temp_id_mapping.
-        let request = SyncRequest::incremental(self.cache.sync_token.clone())
-            .with_resource_types(vec!["all".to_string()])
-            .add_commands(commands);
-        let response = self.client.sync(request).await?;
+        let response = self.send_commands(commands, &mut None).await?;
 
-        // Apply the mutation response to update cache with affected resources
+        // Apply the mutation response to update cache with affected resources,
+        // on top of any changes another process saved in the meantime
+        self.merge_external_changes().await?;
+        self.report_skipped_resources(&response);
         self.cache.apply_mutation_response(&response);
 
         // Persist the updated cache asynchronously
-        self.store.save_async(&self.cache).await?;
+        self.save_cache().await?;
+
+        Ok(response)
+    }
+
+    /// Executes commands after applying their expected effect to the cache.
+    ///
+    /// `optimistic_apply` edits the in-memory cache right away, so callers
+    /// can show the result before the API answers. If the request fails, the
+    /// cache is restored to its state before the edit and the error is
+    /// returned. If every command is rejected, the cache is likewise restored
+    /// before the response is applied. On success the response is applied on
+    /// top of the edit.
+    ///
+    /// If only some commands are rejected, the edit can't be split into the
+    /// accepted and rejected parts, so the cache is restored and then synced
+    /// from its previous sync token, which brings in exactly the changes the
+    /// server kept. That costs one extra request.
+    ///
+    /// The optimistic edit is only ever saved together with a successful
+    /// response. Resources added optimistically under a temporary ID are not
+    /// renamed, so prefer this for updates to existing resources.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails, if the resync after a
+    /// partial rejection fails, or if saving the cache fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use todoist_api_rs::client::TodoistClient;
+    /// use todoist_api_rs::sync::{SyncCommand, SyncCommandType};
+    /// use todoist_cache_rs::{CacheStore, SyncManager};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut manager = SyncManager::new(TodoistClient::new("token")?, CacheStore::new()?)?;
+    /// let cmd = SyncCommand::new(
+    ///     SyncCommandType::ItemUpdate,
+    ///     serde_json::json!({"id": "123", "content": "Renamed"}),
+    /// );
+    /// let response = manager
+    ///     .execute_commands_optimistic(vec![cmd], |cache| {
+    ///         if let Some(item) = cache.items.iter_mut().find(|i| i.id == "123") {
+    ///             item.content = "Renamed".to_string();
+    ///         }
+    ///     })
+    ///     .await?;
+    /// assert!(!response.has_errors());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn execute_commands_optimistic(
+        &mut self,
+        commands: Vec<SyncCommand>,
+        optimistic_apply: impl FnOnce(&mut Cache),
+    ) -> Result<SyncResponse> {
+        let mut snapshot = Some(self.cache.clone());
+        optimistic_apply(&mut self.cache);
+        self.cache.rebuild_indexes();
+
+        let response = match self.send_commands(commands, &mut snapshot).await {
+            Ok(response) => response,
+            Err(e) => {
+                if let Some(snapshot) = snapshot {
+                    self.cache = snapshot;
+                }
+                return Err(e);
+            }
+        };
+        if response.has_errors() {
+            if let Some(snapshot) = snapshot {
+                self.cache = snapshot;
+            }
+            if response.sync_status.values().any(|status| status.is_ok()) {
+                // Resync from the pre-edit token to pick up the accepted commands
+                self.sync().await?;
+                return Ok(response);
+            }
+        }
+
+        self.merge_external_changes().await?;
+        self.report_skipped_resources(&response);
+        self.cache.apply_mutation_response(&response);
+        self.save_cache().await?;
 
         Ok(response)
     }
+
+    /// Sends a command batch, retrying once after a sync if the sync token
+    /// is rejected and retries are enabled.
+    ///
+    /// Before that sync, the cache is restored from `snapshot` (if any), so an
+    /// optimistic edit is never synced into or saved with the cache.
+    async fn send_commands(
+        &mut self,
+        commands: Vec<SyncCommand>,
+        snapshot: &mut Option<Cache>,
+    ) -> Result<SyncResponse> {
+        // Execute command batches against the current sync token so mutation
+        // responses include incremental resource deltas (including delete tombstones).
+        // Without resource_types, the API only returns sync_status and temp_id_mapping.
+        let request = SyncRequest::incremental(self.cache.sync_token.clone())
+            .with_resource_types(vec!["all".to_string()])
+            .add_commands(commands.clone());
+        match self.client.sync(request).await {
+            Ok(response) => Ok(response),
+            Err(e) if self.retry_on_conflict && e.is_invalid_sync_token() => {
+                (self.event_sink)(&SyncEvent::CommandsRetried);
+                if let Some(snapshot) = snapshot.take() {
+                    self.cache = snapshot;
+                }
+                self.sync().await?;
+
+                // Same command UUIDs, so the API deduplicates anything it
+                // already applied
+                let request = SyncRequest::incremental(self.cache.sync_token.clone())
+                    .with_resource_types(vec!["all".to_string()])
+                    .add_commands(commands);
+                Ok(self.client.sync(request).await?)
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -69,6 +69,7 @@ fn make_user(id: &str) -> User {
         start_day: None,
         date_format: None,
         time_format: None,
+        lang: None,
         is_premium: false,
     }
 }
@@ -178,6 +179,29 @@ fn test_needs_sync_when_fresh() {
     // needs_sync = false || is_stale(false) = false
 }
 
+#[test]
+fn test_mark_stale_makes_manager_stale() {
+    let mut manager = make_test_manager();
+    let now = Utc::now();
+    manager.cache.mark_fresh(now);
+    assert!(!manager.is_stale(now));
+
+    manager.cache.mark_stale();
+    assert!(manager.cache.last_sync.is_none());
+    assert!(manager.is_stale(now));
+}
+
+#[test]
+fn test_mark_fresh_clears_staleness() {
+    let mut manager = make_test_manager();
+    let now = Utc::now();
+    assert!(manager.is_stale(now));
+
+    manager.cache.mark_fresh(now - Duration::minutes(1));
+    assert!(!manager.is_stale(now));
+    assert!(manager.is_stale(now + Duration::minutes(10)));
+}
+
 // Tests for fuzzy matching suggestions
 
 #[test]
@@ -490,3 +514,25 @@ fn test_is_shared_project_false_only_owner() {
 
     assert!(!manager.is_shared_project("proj-1"));
 }
+
+#[tokio::test]
+async fn test_cache_mut_marks_dirty_and_save_persists() {
+    let client = TodoistClient::with_base_url("test-token", "http://localhost").unwrap();
+    let temp_dir = tempfile::tempdir().unwrap();
+    let store = CacheStore::with_path(temp_dir.path().join("cache.json"));
+    let mut manager = SyncManager::new(client, store).unwrap();
+    assert!(!manager.is_dirty());
+
+    manager
+        .cache_mut()
+        .projects
+        .push(make_project("p1", "Local Only"));
+    assert!(manager.is_dirty());
+
+    manager.save().await.unwrap();
+    assert!(!manager.is_dirty());
+
+    let on_disk = manager.store().load().unwrap();
+    assert_eq!(on_disk.projects.len(), 1);
+    assert_eq!(on_disk.projects[0].name, "Local Only");
+}
@@ -198,7 +198,7 @@ fn test_cache_file_is_pretty_printed() {
     let temp_dir = tempdir().expect("failed to create temp dir");
     let cache_path = temp_dir.path().join("cache.json");
 
-    let store = CacheStore::with_path(cache_path.clone());
+    let store = CacheStore::with_path(cache_path.clone()).with_pretty(true);
 
     let cache = Cache::new();
     store.save(&cache).expect("failed to save");
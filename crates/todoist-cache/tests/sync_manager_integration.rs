@@ -4,7 +4,7 @@
 //! correctly orchestrates sync operations between the API and cache.
 
 use tempfile::tempdir;
-use wiremock::matchers::{body_string_contains, method, path};
+use wiremock::matchers::{body_string_contains, header, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 use todoist_api_rs::client::TodoistClient;
@@ -150,6 +150,46 @@ async fn test_sync_performs_full_sync_when_no_cache() {
     assert_eq!(loaded.items.len(), 2);
 }
 
+#[tokio::test]
+async fn test_sync_raw_does_not_touch_cache_until_applied() {
+    let mock_server = MockServer::start().await;
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    let cache_path = temp_dir.path().join("cache.json");
+
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .and(body_string_contains("sync_token=*"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_full_sync_response()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = TodoistClient::with_base_url("test-token", mock_server.uri()).unwrap();
+    let store = CacheStore::with_path(cache_path.clone());
+    let mut manager = SyncManager::new(client, store).expect("failed to create manager");
+
+    let (response, body) = manager.sync_raw(None).await.expect("sync_raw failed");
+    assert_eq!(response.sync_token, "new_sync_token_abc123");
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&body).unwrap(),
+        mock_full_sync_response()
+    );
+    assert!(manager.cache().items.is_empty());
+    assert!(!cache_path.exists());
+
+    let cache = manager
+        .apply_response(&response)
+        .await
+        .expect("apply_response failed");
+    assert_eq!(cache.sync_token, "new_sync_token_abc123");
+    assert_eq!(cache.items.len(), 2);
+
+    let loaded = CacheStore::with_path(cache_path)
+        .load()
+        .expect("failed to load cache");
+    assert_eq!(loaded.items.len(), 2);
+}
+
 #[tokio::test]
 async fn test_sync_performs_incremental_sync_with_existing_cache() {
     let mock_server = MockServer::start().await;
@@ -246,6 +286,141 @@ async fn test_full_sync_forces_full_sync_even_with_existing_token() {
     assert_eq!(cache.items.len(), 2);
 }
 
+#[tokio::test]
+async fn test_rebuild_starts_from_empty_cache() {
+    let mock_server = MockServer::start().await;
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    let cache_path = temp_dir.path().join("cache.json");
+
+    // Existing cache with state a plain full sync would keep (the response
+    // below carries no user record)
+    let store = CacheStore::with_path(cache_path.clone());
+    let mut existing_cache = Cache::new();
+    existing_cache.sync_token = "existing_token_123".to_string();
+    existing_cache.user = Some(
+        serde_json::from_value(serde_json::json!({"id": "user-1", "full_name": "Old User"}))
+            .expect("failed to build user"),
+    );
+    store.save(&existing_cache).expect("failed to save cache");
+
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .and(body_string_contains("sync_token=*"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_full_sync_response()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = TodoistClient::with_base_url("test-token", mock_server.uri()).unwrap();
+    let mut manager = SyncManager::new(client, CacheStore::with_path(cache_path.clone())).unwrap();
+    assert!(manager.cache().user.is_some());
+
+    let cache = manager.rebuild().await.expect("rebuild failed");
+    assert_eq!(cache.sync_token, "new_sync_token_abc123");
+    assert_eq!(cache.items.len(), 2);
+    assert!(cache.user.is_none());
+
+    // The rebuilt cache is what's on disk
+    let on_disk = CacheStore::with_path(cache_path).load().unwrap();
+    assert!(on_disk.user.is_none());
+    assert_eq!(on_disk.items.len(), 2);
+}
+
+#[tokio::test]
+async fn test_rebuild_keeps_cache_when_sync_fails() {
+    let mock_server = MockServer::start().await;
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    let cache_path = temp_dir.path().join("cache.json");
+
+    let store = CacheStore::with_path(cache_path.clone());
+    let mut existing_cache = Cache::new();
+    existing_cache.sync_token = "existing_token_123".to_string();
+    store.save(&existing_cache).expect("failed to save cache");
+
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .respond_with(ResponseTemplate::new(500))
+        .mount(&mock_server)
+        .await;
+
+    let client = TodoistClient::with_base_url("test-token", mock_server.uri()).unwrap();
+    let mut manager = SyncManager::new(client, CacheStore::with_path(cache_path)).unwrap();
+
+    assert!(manager.rebuild().await.is_err());
+    assert_eq!(manager.cache().sync_token, "existing_token_123");
+}
+
+#[tokio::test]
+async fn test_sync_resources_replaces_only_requested_types() {
+    let mock_server = MockServer::start().await;
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    let cache_path = temp_dir.path().join("cache.json");
+
+    // Existing cache with a sync token and a project that must survive
+    let store = CacheStore::with_path(cache_path.clone());
+    let mut existing_cache = Cache::new();
+    existing_cache.sync_token = "existing_token_123".to_string();
+    existing_cache.projects = vec![todoist_api_rs::sync::Project {
+        id: "proj-keep".to_string(),
+        name: "Keep Me".to_string(),
+        color: None,
+        parent_id: None,
+        child_order: 0,
+        is_collapsed: false,
+        shared: false,
+        can_assign_tasks: false,
+        is_deleted: false,
+        is_archived: false,
+        is_favorite: false,
+        view_style: None,
+        inbox_project: false,
+        folder_id: None,
+        created_at: None,
+        updated_at: None,
+    }];
+    store.save(&existing_cache).expect("failed to save cache");
+
+    // Scoped request: full sync token, only items requested
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .and(body_string_contains("sync_token=*"))
+        .and(body_string_contains("items"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_full_sync_response()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = TodoistClient::with_base_url("test-token", mock_server.uri()).unwrap();
+    let store = CacheStore::with_path(cache_path);
+    let mut manager = SyncManager::new(client, store).expect("failed to create manager");
+
+    let cache = manager
+        .sync_resources(&["items".to_string()])
+        .await
+        .expect("sync_resources failed");
+
+    // Items replaced, projects and sync token untouched
+    assert_eq!(cache.items.len(), 2);
+    assert_eq!(cache.projects.len(), 1);
+    assert_eq!(cache.projects[0].id, "proj-keep");
+    assert_eq!(cache.sync_token, "existing_token_123");
+}
+
+#[tokio::test]
+async fn test_sync_resources_rejects_unknown_type() {
+    let mock_server = MockServer::start().await;
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    let cache_path = temp_dir.path().join("cache.json");
+
+    let client = TodoistClient::with_base_url("test-token", mock_server.uri()).unwrap();
+    let store = CacheStore::with_path(cache_path);
+    let mut manager = SyncManager::new(client, store).expect("failed to create manager");
+
+    let result = manager.sync_resources(&["widgets".to_string()]).await;
+    assert!(result.is_err());
+    assert!(result.unwrap_err().to_string().contains("widgets"));
+}
+
 #[tokio::test]
 async fn test_sync_persists_cache_to_disk() {
     let mock_server = MockServer::start().await;
@@ -340,6 +515,63 @@ async fn test_reload_refreshes_cache_from_disk() {
     assert_eq!(manager.cache().items.len(), 0);
 }
 
+#[tokio::test]
+async fn test_sync_merges_external_write_before_saving() {
+    use chrono::Utc;
+    use std::sync::{Arc, Mutex};
+    use todoist_cache_rs::SyncEvent;
+
+    let mock_server = MockServer::start().await;
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    let cache_path = temp_dir.path().join("cache.json");
+
+    let store = CacheStore::with_path(cache_path.clone());
+    let mut existing_cache = Cache::new();
+    existing_cache.sync_token = "existing_token_123".to_string();
+    existing_cache.last_sync = Some(Utc::now() - chrono::Duration::minutes(10));
+    store.save(&existing_cache).expect("failed to save cache");
+
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .and(body_string_contains("sync_token=existing_token_123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_incremental_sync_response()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = TodoistClient::with_base_url("test-token", mock_server.uri()).unwrap();
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let sink_events = Arc::clone(&events);
+    let mut manager = SyncManager::new(client, CacheStore::with_path(cache_path.clone()))
+        .expect("failed to create manager")
+        .with_event_sink(move |event| sink_events.lock().unwrap().push(event.clone()));
+
+    // Another td process syncs and saves a newer cache after this one loaded
+    let mut external = existing_cache.clone();
+    external.sync_token = "external_token_456".to_string();
+    external.mark_fresh(Utc::now());
+    external.projects = vec![serde_json::from_value(serde_json::json!({
+        "id": "proj-external",
+        "name": "Added elsewhere"
+    }))
+    .unwrap()];
+    store
+        .save(&external)
+        .expect("failed to save external cache");
+
+    manager.sync().await.expect("sync failed");
+
+    // Both the external project and this sync's item survive the save
+    let saved = store.load().expect("failed to load cache");
+    assert!(saved.projects.iter().any(|p| p.id == "proj-external"));
+    assert!(saved.items.iter().any(|i| i.id == "item-3"));
+    assert_eq!(saved.sync_token, "incremental_token_xyz789");
+    assert_eq!(
+        *events.lock().unwrap(),
+        vec![SyncEvent::ExternalChangesMerged]
+    );
+}
+
 #[tokio::test]
 async fn test_is_stale_with_sync_manager() {
     let temp_dir = tempdir().expect("failed to create temp dir");
@@ -382,6 +614,70 @@ async fn test_is_not_stale_when_recently_synced() {
     assert!(!manager.needs_sync(chrono::Utc::now()));
 }
 
+#[tokio::test]
+async fn test_sync_if_needed_skips_request_when_fresh() {
+    let mock_server = MockServer::start().await;
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    let cache_path = temp_dir.path().join("cache.json");
+
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_incremental_sync_response()))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let store = CacheStore::with_path(cache_path.clone());
+    let mut fresh_cache = Cache::new();
+    fresh_cache.sync_token = "fresh_token".to_string();
+    fresh_cache.last_sync = Some(chrono::Utc::now());
+    store.save(&fresh_cache).expect("failed to save cache");
+
+    let client = TodoistClient::with_base_url("test-token", mock_server.uri()).unwrap();
+    let store = CacheStore::with_path(cache_path);
+    let mut manager = SyncManager::new(client, store).expect("failed to create manager");
+
+    let cache = manager
+        .sync_if_needed(chrono::Utc::now())
+        .await
+        .expect("sync_if_needed failed");
+
+    assert_eq!(cache.sync_token, "fresh_token");
+    assert!(cache.items.is_empty());
+}
+
+#[tokio::test]
+async fn test_sync_if_needed_syncs_stale_cache() {
+    let mock_server = MockServer::start().await;
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    let cache_path = temp_dir.path().join("cache.json");
+
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_incremental_sync_response()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let store = CacheStore::with_path(cache_path.clone());
+    let mut stale_cache = Cache::new();
+    stale_cache.sync_token = "old_token".to_string();
+    stale_cache.last_sync = Some(chrono::Utc::now() - chrono::Duration::minutes(10));
+    store.save(&stale_cache).expect("failed to save cache");
+
+    let client = TodoistClient::with_base_url("test-token", mock_server.uri()).unwrap();
+    let store = CacheStore::with_path(cache_path);
+    let mut manager = SyncManager::new(client, store).expect("failed to create manager");
+
+    let cache = manager
+        .sync_if_needed(chrono::Utc::now())
+        .await
+        .expect("sync_if_needed failed");
+
+    assert_eq!(cache.sync_token, "incremental_token_xyz789");
+    assert_eq!(cache.items.len(), 1);
+}
+
 #[tokio::test]
 async fn test_custom_stale_threshold() {
     let temp_dir = tempdir().expect("failed to create temp dir");
@@ -1666,38 +1962,12 @@ async fn test_resolve_item_by_prefix_with_require_checked_filter() {
     // Let's just verify the filter works by checking we get the right items above
 }
 
-// ==================== sync token resilience tests ====================
-
-/// Creates a mock validation error response for invalid sync token.
-fn mock_invalid_sync_token_response() -> ResponseTemplate {
-    ResponseTemplate::new(400).set_body_json(serde_json::json!({
-        "error": "Validation error",
-        "error_code": 34,
-        "error_extra": {},
-        "error_tag": "SYNC_TOKEN_INVALID",
-        "http_code": 400
-    }))
-}
-
-#[tokio::test]
-async fn test_sync_falls_back_to_full_sync_on_invalid_token() {
-    // Test: incremental sync fails with invalid token, automatic full sync fallback
-    // Setup: cache has a sync token, first sync returns invalid token error,
-    // second sync (full) succeeds
-
-    let mock_server = MockServer::start().await;
-    let temp_dir = tempdir().expect("failed to create temp dir");
-    let cache_path = temp_dir.path().join("cache.json");
-
-    // Create cache with existing sync token
-    let store = CacheStore::with_path(cache_path.clone());
-    let mut existing_cache = Cache::new();
-    existing_cache.sync_token = "old_invalid_token".to_string();
-    existing_cache.items = vec![todoist_api_rs::sync::Item {
-        id: "old-item".to_string(),
+fn make_cached_item(id: &str, content: &str, checked: bool) -> todoist_api_rs::sync::Item {
+    todoist_api_rs::sync::Item {
+        id: id.to_string(),
         user_id: None,
         project_id: "proj-1".to_string(),
-        content: "Old task".to_string(),
+        content: content.to_string(),
         description: String::new(),
         priority: 1,
         due: None,
@@ -1711,26 +1981,188 @@ async fn test_sync_falls_back_to_full_sync_on_invalid_token() {
         added_by_uid: None,
         assigned_by_uid: None,
         responsible_uid: None,
-        checked: false,
+        checked,
         is_deleted: false,
         added_at: None,
         updated_at: None,
         completed_at: None,
         duration: None,
-    }];
+    }
+}
+
+#[tokio::test]
+async fn test_resolve_item_arg_prefix_and_content_resolve_same_task() {
+    let mock_server = MockServer::start().await;
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    let cache_path = temp_dir.path().join("cache.json");
+
+    let store = CacheStore::with_path(cache_path.clone());
+    let mut existing_cache = Cache::new();
+    existing_cache.sync_token = "existing_token".to_string();
+    existing_cache.items = vec![
+        make_cached_item("abc123def456", "Buy groceries", false),
+        make_cached_item("xyz789ghi012", "Call dentist", false),
+    ];
     store.save(&existing_cache).expect("failed to save cache");
 
-    // First request: incremental sync with old token -> return invalid token error
+    // No sync expected: everything resolves from cache
     Mock::given(method("POST"))
         .and(path("/sync"))
-        .and(body_string_contains("sync_token=old_invalid_token"))
-        .respond_with(mock_invalid_sync_token_response())
-        .expect(1)
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_empty_sync_response()))
+        .expect(0)
         .mount(&mock_server)
         .await;
 
-    // Second request: full sync (sync_token=*) -> success with fresh data
-    Mock::given(method("POST"))
+    let client = TodoistClient::with_base_url("test-token", mock_server.uri()).unwrap();
+    let store = CacheStore::with_path(cache_path);
+    let mut manager = SyncManager::new(client, store).expect("failed to create manager");
+
+    let by_id = manager
+        .resolve_item_arg("abc123def456", None)
+        .await
+        .expect("exact id should resolve")
+        .id
+        .clone();
+    let by_prefix = manager
+        .resolve_item_arg("abc123", None)
+        .await
+        .expect("prefix should resolve")
+        .id
+        .clone();
+    let by_content = manager
+        .resolve_item_arg("GROCER", None)
+        .await
+        .expect("content substring should resolve")
+        .id
+        .clone();
+
+    assert_eq!(by_id, "abc123def456");
+    assert_eq!(by_prefix, by_id);
+    assert_eq!(by_content, by_id);
+}
+
+#[tokio::test]
+async fn test_resolve_item_arg_reports_ambiguous_content() {
+    let mock_server = MockServer::start().await;
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    let cache_path = temp_dir.path().join("cache.json");
+
+    let store = CacheStore::with_path(cache_path.clone());
+    let mut existing_cache = Cache::new();
+    existing_cache.sync_token = "existing_token".to_string();
+    existing_cache.items = vec![
+        make_cached_item("abc123def456", "Review pull request", false),
+        make_cached_item("xyz789ghi012", "Review budget", false),
+    ];
+    store.save(&existing_cache).expect("failed to save cache");
+
+    let client = TodoistClient::with_base_url("test-token", mock_server.uri()).unwrap();
+    let store = CacheStore::with_path(cache_path);
+    let mut manager = SyncManager::new(client, store).expect("failed to create manager");
+
+    let result = manager.resolve_item_arg("review", None).await;
+    match result {
+        Err(todoist_cache_rs::SyncError::NotFound { identifier, .. }) => {
+            assert!(identifier.contains("Ambiguous task"));
+            assert!(identifier.contains("Review pull request"));
+            assert!(identifier.contains("Review budget"));
+        }
+        other => panic!("Expected ambiguity error, got: {:?}", other.map(|i| &i.id)),
+    }
+}
+
+#[tokio::test]
+async fn test_resolve_item_arg_respects_require_checked() {
+    let mock_server = MockServer::start().await;
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    let cache_path = temp_dir.path().join("cache.json");
+
+    let store = CacheStore::with_path(cache_path.clone());
+    let mut existing_cache = Cache::new();
+    existing_cache.sync_token = "existing_token".to_string();
+    existing_cache.items = vec![
+        make_cached_item("abc123def456", "Write report", true),
+        make_cached_item("xyz789ghi012", "Write tests", false),
+    ];
+    store.save(&existing_cache).expect("failed to save cache");
+
+    let client = TodoistClient::with_base_url("test-token", mock_server.uri()).unwrap();
+    let store = CacheStore::with_path(cache_path);
+    let mut manager = SyncManager::new(client, store).expect("failed to create manager");
+
+    // "write" is ambiguous overall but unique among uncompleted tasks
+    let item = manager
+        .resolve_item_arg("write", Some(false))
+        .await
+        .expect("should resolve the only open match");
+    assert_eq!(item.id, "xyz789ghi012");
+}
+
+// ==================== sync token resilience tests ====================
+
+/// Creates a mock validation error response for invalid sync token.
+fn mock_invalid_sync_token_response() -> ResponseTemplate {
+    ResponseTemplate::new(400).set_body_json(serde_json::json!({
+        "error": "Validation error",
+        "error_code": 34,
+        "error_extra": {},
+        "error_tag": "SYNC_TOKEN_INVALID",
+        "http_code": 400
+    }))
+}
+
+#[tokio::test]
+async fn test_sync_falls_back_to_full_sync_on_invalid_token() {
+    // Test: incremental sync fails with invalid token, automatic full sync fallback
+    // Setup: cache has a sync token, first sync returns invalid token error,
+    // second sync (full) succeeds
+
+    let mock_server = MockServer::start().await;
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    let cache_path = temp_dir.path().join("cache.json");
+
+    // Create cache with existing sync token
+    let store = CacheStore::with_path(cache_path.clone());
+    let mut existing_cache = Cache::new();
+    existing_cache.sync_token = "old_invalid_token".to_string();
+    existing_cache.items = vec![todoist_api_rs::sync::Item {
+        id: "old-item".to_string(),
+        user_id: None,
+        project_id: "proj-1".to_string(),
+        content: "Old task".to_string(),
+        description: String::new(),
+        priority: 1,
+        due: None,
+        deadline: None,
+        parent_id: None,
+        child_order: 0,
+        section_id: None,
+        day_order: 0,
+        is_collapsed: false,
+        labels: vec![],
+        added_by_uid: None,
+        assigned_by_uid: None,
+        responsible_uid: None,
+        checked: false,
+        is_deleted: false,
+        added_at: None,
+        updated_at: None,
+        completed_at: None,
+        duration: None,
+    }];
+    store.save(&existing_cache).expect("failed to save cache");
+
+    // First request: incremental sync with old token -> return invalid token error
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .and(body_string_contains("sync_token=old_invalid_token"))
+        .respond_with(mock_invalid_sync_token_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    // Second request: full sync (sync_token=*) -> success with fresh data
+    Mock::given(method("POST"))
         .and(path("/sync"))
         .and(body_string_contains("sync_token=*")) // "*" is unreserved, no encoding needed
         .respond_with(ResponseTemplate::new(200).set_body_json(mock_full_sync_response()))
@@ -1836,6 +2268,130 @@ async fn test_sync_non_token_errors_propagate() {
     assert_eq!(manager.cache().sync_token, "some_token");
 }
 
+#[tokio::test]
+async fn test_execute_commands_retries_once_on_invalid_token() {
+    // Test: a command batch rejected for a stale token is retried after a
+    // read sync, reusing the same command UUID so the item is added once
+
+    use todoist_api_rs::sync::{SyncCommand, SyncCommandType};
+
+    let mock_server = MockServer::start().await;
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    let cache_path = temp_dir.path().join("cache.json");
+
+    let store = CacheStore::with_path(cache_path.clone());
+    let mut existing_cache = Cache::new();
+    existing_cache.sync_token = "stale_token".to_string();
+    store.save(&existing_cache).expect("failed to save cache");
+
+    // First command request: stale token rejected
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .and(body_string_contains("sync_token=stale_token"))
+        .and(body_string_contains("commands="))
+        .respond_with(mock_invalid_sync_token_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    // Read sync succeeds and hands out a fresh token
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .and(body_string_contains("sync_token=stale_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_incremental_sync_response()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    // Retried command request uses the fresh token
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .and(body_string_contains("sync_token=incremental_token_xyz789"))
+        .and(body_string_contains("commands="))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_command_response()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = TodoistClient::with_base_url("test-token", mock_server.uri()).unwrap();
+    let store = CacheStore::with_path(cache_path.clone());
+    let mut manager = SyncManager::new(client, store)
+        .expect("failed to create manager")
+        .with_retry_on_conflict(true);
+
+    let cmd = SyncCommand::with_temp_id(
+        SyncCommandType::ItemAdd,
+        "temp-item-123",
+        serde_json::json!({"content": "New task from command", "project_id": "proj-1"}),
+    );
+    let uuid = cmd.uuid.clone();
+    let response = manager
+        .execute_commands(vec![cmd])
+        .await
+        .expect("execute_commands should succeed after retry");
+
+    assert_eq!(
+        response.temp_id_mapping.get("temp-item-123"),
+        Some(&"real-item-id-789".to_string())
+    );
+
+    // Both command requests carried the same UUID
+    let requests = mock_server.received_requests().await.unwrap();
+    let command_bodies: Vec<String> = requests
+        .iter()
+        .map(|r| String::from_utf8_lossy(&r.body).into_owned())
+        .filter(|body| body.contains("commands="))
+        .collect();
+    assert_eq!(command_bodies.len(), 2);
+    assert!(command_bodies.iter().all(|body| body.contains(&uuid)));
+
+    // The item was added exactly once, alongside the read sync's item
+    let cache = manager.cache();
+    assert_eq!(
+        cache
+            .items
+            .iter()
+            .filter(|i| i.id == "real-item-id-789")
+            .count(),
+        1
+    );
+    assert!(cache.items.iter().any(|i| i.id == "item-3"));
+    assert_eq!(cache.sync_token, "post_command_token_456");
+}
+
+#[tokio::test]
+async fn test_execute_commands_does_not_retry_by_default() {
+    use todoist_api_rs::sync::{SyncCommand, SyncCommandType};
+
+    let mock_server = MockServer::start().await;
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    let cache_path = temp_dir.path().join("cache.json");
+
+    let store = CacheStore::with_path(cache_path.clone());
+    let mut existing_cache = Cache::new();
+    existing_cache.sync_token = "stale_token".to_string();
+    store.save(&existing_cache).expect("failed to save cache");
+
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .respond_with(mock_invalid_sync_token_response())
+        .expect(1) // No read sync, no retry
+        .mount(&mock_server)
+        .await;
+
+    let client = TodoistClient::with_base_url("test-token", mock_server.uri()).unwrap();
+    let store = CacheStore::with_path(cache_path);
+    let mut manager = SyncManager::new(client, store).expect("failed to create manager");
+
+    let cmd = SyncCommand::new(
+        SyncCommandType::ItemAdd,
+        serde_json::json!({"content": "Test"}),
+    );
+    let result = manager.execute_commands(vec![cmd]).await;
+    assert!(result.is_err());
+    assert_eq!(manager.cache().sync_token, "stale_token");
+}
+
 // ==================== Cache behavior integration tests ====================
 // These tests verify the core cache behavior: mutations update cache immediately
 // without requiring a separate sync call. This is the key UX improvement from
@@ -2207,3 +2763,366 @@ async fn test_edited_item_shows_updated_content_without_sync() {
     assert_eq!(loaded.items[0].content, "Updated content");
     assert_eq!(loaded.items[0].priority, 4);
 }
+
+#[tokio::test]
+async fn test_sync_from_token_sends_supplied_token() {
+    let mock_server = MockServer::start().await;
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    let cache_path = temp_dir.path().join("cache.json");
+
+    let store = CacheStore::with_path(cache_path.clone());
+    let mut existing_cache = Cache::new();
+    existing_cache.sync_token = "current_token".to_string();
+    existing_cache.labels = vec![todoist_api_rs::sync::Label {
+        id: "label-kept".to_string(),
+        name: "kept".to_string(),
+        color: None,
+        item_order: 0,
+        is_deleted: false,
+        is_favorite: false,
+    }];
+    store.save(&existing_cache).expect("failed to save cache");
+
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .and(body_string_contains("sync_token=replay_token_42"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_incremental_sync_response()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = TodoistClient::with_base_url("test-token", mock_server.uri()).unwrap();
+    let store = CacheStore::with_path(cache_path);
+    let mut manager = SyncManager::new(client, store).expect("failed to create manager");
+
+    let cache = manager
+        .sync_from_token("replay_token_42")
+        .await
+        .expect("sync failed");
+
+    // Existing data is kept and the new token is stored
+    assert_eq!(cache.sync_token, "incremental_token_xyz789");
+    assert!(cache.labels.iter().any(|l| l.id == "label-kept"));
+}
+
+#[tokio::test]
+async fn test_sync_from_invalid_token_falls_back_to_full_sync() {
+    let mock_server = MockServer::start().await;
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    let cache_path = temp_dir.path().join("cache.json");
+
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .and(body_string_contains("sync_token=bogus"))
+        .respond_with(ResponseTemplate::new(400).set_body_string("Invalid sync_token"))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .and(body_string_contains("sync_token=*"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_full_sync_response()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = TodoistClient::with_base_url("test-token", mock_server.uri()).unwrap();
+    let store = CacheStore::with_path(cache_path);
+    let mut manager = SyncManager::new(client, store).expect("failed to create manager");
+
+    let cache = manager.sync_from_token("bogus").await.expect("sync failed");
+    assert_eq!(cache.sync_token, "new_sync_token_abc123");
+}
+
+#[tokio::test]
+async fn test_sync_fallback_reports_event_to_sink() {
+    use std::sync::{Arc, Mutex};
+    use todoist_cache_rs::SyncEvent;
+
+    let mock_server = MockServer::start().await;
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    let cache_path = temp_dir.path().join("cache.json");
+
+    let store = CacheStore::with_path(cache_path.clone());
+    let mut existing_cache = Cache::new();
+    existing_cache.sync_token = "expired_token".to_string();
+    store.save(&existing_cache).expect("failed to save cache");
+
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .and(body_string_contains("sync_token=expired_token"))
+        .respond_with(ResponseTemplate::new(400).set_body_string("Invalid sync_token"))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .and(body_string_contains("sync_token=*"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_full_sync_response()))
+        .mount(&mock_server)
+        .await;
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let sink_events = Arc::clone(&events);
+    let client = TodoistClient::with_base_url("test-token", mock_server.uri()).unwrap();
+    let mut manager = SyncManager::new(client, CacheStore::with_path(cache_path))
+        .expect("failed to create manager")
+        .with_event_sink(move |event| sink_events.lock().unwrap().push(event.clone()));
+
+    manager.sync().await.expect("sync failed");
+
+    assert_eq!(*events.lock().unwrap(), vec![SyncEvent::FullSyncFallback]);
+}
+
+#[tokio::test]
+async fn test_sync_reports_skipped_resources_to_sink() {
+    use std::sync::{Arc, Mutex};
+    use todoist_cache_rs::SyncEvent;
+
+    let mock_server = MockServer::start().await;
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    let cache_path = temp_dir.path().join("cache.json");
+
+    let mut response = mock_full_sync_response();
+    response["items"]
+        .as_array_mut()
+        .unwrap()
+        .push(serde_json::json!({"id": "item-bad", "priority": "urgent"}));
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(response))
+        .mount(&mock_server)
+        .await;
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let sink_events = Arc::clone(&events);
+    let client = TodoistClient::with_base_url("test-token", mock_server.uri()).unwrap();
+    let mut manager = SyncManager::new(client, CacheStore::with_path(cache_path))
+        .expect("failed to create manager")
+        .with_event_sink(move |event| sink_events.lock().unwrap().push(event.clone()));
+
+    let cache = manager.sync().await.expect("sync failed");
+    assert_eq!(cache.items.len(), 2);
+
+    let events = events.lock().unwrap();
+    let [SyncEvent::ResourcesSkipped(skipped)] = events.as_slice() else {
+        panic!("expected one ResourcesSkipped event, got {events:?}");
+    };
+    assert_eq!(skipped.len(), 1);
+    assert_eq!(skipped[0].resource, "items");
+    assert_eq!(skipped[0].id.as_deref(), Some("item-bad"));
+    assert!(events[0].to_string().contains("items item-bad"));
+}
+
+#[tokio::test]
+async fn test_set_token_sends_new_authorization_header() {
+    let mock_server = MockServer::start().await;
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    let store = CacheStore::with_path(temp_dir.path().join("cache.json"));
+
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .and(header("Authorization", "Bearer old-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_full_sync_response()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .and(header("Authorization", "Bearer rotated-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_incremental_sync_response()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = TodoistClient::with_base_url("old-token", mock_server.uri()).unwrap();
+    let mut manager = SyncManager::new(client, store).expect("failed to create manager");
+    manager.sync().await.expect("first sync failed");
+
+    manager.set_token("rotated-token");
+    assert_eq!(manager.client().token(), "rotated-token");
+    let cache = manager.sync().await.expect("second sync failed");
+
+    // The cache carried over, and the second sync was incremental
+    assert_eq!(cache.sync_token, "incremental_token_xyz789");
+    assert!(cache.items.iter().any(|i| i.id == "item-1"));
+}
+
+// ==================== optimistic command tests ====================
+
+/// Sets up a manager whose cache holds one task, "Original content".
+async fn optimistic_manager(mock_server: &MockServer, cache_path: &std::path::Path) -> SyncManager {
+    let store = CacheStore::with_path(cache_path.to_path_buf());
+    let mut existing_cache = Cache::new();
+    existing_cache.sync_token = "existing_token".to_string();
+    existing_cache.items = vec![make_cached_item("item-1", "Original content", false)];
+    store.save(&existing_cache).expect("failed to save cache");
+
+    let client = TodoistClient::with_base_url("test-token", mock_server.uri()).unwrap();
+    SyncManager::new(client, CacheStore::with_path(cache_path.to_path_buf()))
+        .expect("failed to create manager")
+}
+
+/// Renames `item-1`, both as a command and as the optimistic cache edit.
+fn rename_command() -> todoist_api_rs::sync::SyncCommand {
+    todoist_api_rs::sync::SyncCommand::new(
+        todoist_api_rs::sync::SyncCommandType::ItemUpdate,
+        serde_json::json!({"id": "item-1", "content": "Renamed"}),
+    )
+}
+
+fn rename_item(cache: &mut Cache) {
+    if let Some(item) = cache.items.iter_mut().find(|i| i.id == "item-1") {
+        item.content = "Renamed".to_string();
+    }
+}
+
+#[tokio::test]
+async fn test_execute_commands_optimistic_rolls_back_rejected_command() {
+    let mock_server = MockServer::start().await;
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    let cache_path = temp_dir.path().join("cache.json");
+    let mut manager = optimistic_manager(&mock_server, &cache_path).await;
+
+    let cmd = rename_command();
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "sync_token": "post_command_token",
+            "full_sync": false,
+            "sync_status": {
+                cmd.uuid.clone(): {"error_code": 22, "error": "Item not found"}
+            },
+            "temp_id_mapping": {}
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let response = manager
+        .execute_commands_optimistic(vec![cmd], rename_item)
+        .await
+        .expect("request should succeed");
+    assert!(response.has_errors());
+
+    // The edit is gone; the response itself still applies
+    assert_eq!(manager.cache().items[0].content, "Original content");
+    assert_eq!(manager.cache().sync_token, "post_command_token");
+    let loaded = CacheStore::with_path(cache_path).load().unwrap();
+    assert_eq!(loaded.items[0].content, "Original content");
+}
+
+#[tokio::test]
+async fn test_execute_commands_optimistic_resyncs_after_partial_rejection() {
+    let mock_server = MockServer::start().await;
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    let cache_path = temp_dir.path().join("cache.json");
+    let mut manager = optimistic_manager(&mock_server, &cache_path).await;
+
+    let rename = rename_command();
+    let raise_priority = todoist_api_rs::sync::SyncCommand::new(
+        todoist_api_rs::sync::SyncCommandType::ItemUpdate,
+        serde_json::json!({"id": "item-1", "priority": 4}),
+    );
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .and(body_string_contains("commands="))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "sync_token": "post_command_token",
+            "full_sync": false,
+            "sync_status": {
+                rename.uuid.clone(): "ok",
+                raise_priority.uuid.clone(): {"error_code": 20, "error": "Invalid priority"}
+            },
+            "temp_id_mapping": {}
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    // The resync replays only the accepted rename
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .and(body_string_contains("sync_token=existing_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "sync_token": "resync_token",
+            "full_sync": false,
+            "items": [{"id": "item-1", "project_id": "proj-1", "content": "Renamed"}]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let response = manager
+        .execute_commands_optimistic(vec![rename, raise_priority], |cache| {
+            rename_item(cache);
+            cache.items[0].priority = 4;
+        })
+        .await
+        .expect("request should succeed");
+    assert!(response.has_errors());
+
+    let item = manager.cache().find_item("item-1").unwrap();
+    assert_eq!(item.content, "Renamed");
+    assert_eq!(item.priority, 1);
+    let loaded = CacheStore::with_path(cache_path).load().unwrap();
+    assert_eq!(loaded.items[0].content, "Renamed");
+    assert_eq!(loaded.items[0].priority, 1);
+    assert_eq!(loaded.sync_token, "resync_token");
+}
+
+#[tokio::test]
+async fn test_execute_commands_optimistic_rolls_back_failed_request() {
+    let mock_server = MockServer::start().await;
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    let cache_path = temp_dir.path().join("cache.json");
+    let mut manager = optimistic_manager(&mock_server, &cache_path).await;
+
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .respond_with(ResponseTemplate::new(500).set_body_string("Internal Server Error"))
+        .mount(&mock_server)
+        .await;
+
+    let result = manager
+        .execute_commands_optimistic(vec![rename_command()], rename_item)
+        .await;
+    assert!(result.is_err());
+
+    assert_eq!(manager.cache().items[0].content, "Original content");
+    assert_eq!(manager.cache().sync_token, "existing_token");
+    let item = manager.cache().find_item("item-1").expect("index rebuilt");
+    assert_eq!(item.content, "Original content");
+    let loaded = CacheStore::with_path(cache_path).load().unwrap();
+    assert_eq!(loaded.items[0].content, "Original content");
+}
+
+#[tokio::test]
+async fn test_execute_commands_optimistic_keeps_accepted_change() {
+    let mock_server = MockServer::start().await;
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    let cache_path = temp_dir.path().join("cache.json");
+    let mut manager = optimistic_manager(&mock_server, &cache_path).await;
+
+    let cmd = rename_command();
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "sync_token": "post_command_token",
+            "full_sync": false,
+            "sync_status": {cmd.uuid.clone(): "ok"},
+            "temp_id_mapping": {}
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let response = manager
+        .execute_commands_optimistic(vec![cmd], rename_item)
+        .await
+        .expect("request should succeed");
+    assert!(!response.has_errors());
+
+    assert_eq!(manager.cache().items[0].content, "Renamed");
+    let loaded = CacheStore::with_path(cache_path).load().unwrap();
+    assert_eq!(loaded.items[0].content, "Renamed");
+    assert_eq!(loaded.sync_token, "post_command_token");
+}